@@ -24,6 +24,12 @@ impl From<boxdd::world::Error> for BoxddPluginError {
     }
 }
 
+impl From<boxdd::StepError> for BoxddPluginError {
+    fn from(value: boxdd::StepError) -> Self {
+        Self::Api(value.into())
+    }
+}
+
 /// Plugin operation associated with a recoverable error message.
 #[derive(Copy, Clone, Debug, Eq, PartialEq)]
 pub enum BoxddOperation {
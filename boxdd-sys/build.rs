@@ -157,6 +157,7 @@ fn main() {
 
     if config.is_docsrs {
         println!("cargo:warning=DOCS_RS detected: skipping native Box2D C build");
+        emit_native_build("docs-rs");
         return;
     }
 
@@ -167,6 +168,7 @@ fn main() {
             );
         }
         println!("cargo:warning=Skipping native Box2D C build due to BOXDD_SYS_SKIP_CC");
+        emit_native_build("skipped");
         return;
     }
 
@@ -175,6 +177,7 @@ fn main() {
     }
 
     if try_link_system(&config.target_arch) {
+        emit_native_build("system");
         return;
     }
 
@@ -182,12 +185,18 @@ fn main() {
         println!(
             "cargo:warning=build-from-source disabled: not compiling vendored Box2D C sources"
         );
+        emit_native_build("none");
         return;
     }
 
     build_box2d_from_source(&config);
 }
 
+/// Record how the native Box2D library was provided so `boxdd_sys::build_info` can report it.
+fn emit_native_build(kind: &str) {
+    println!("cargo:rustc-env=BOXDD_SYS_NATIVE_BUILD={kind}");
+}
+
 fn validate_build_config(config: &BuildConfig) {
     if config.wasm_mode == Some(WasmMode::Provider) && config.target_arch != "wasm32" {
         panic!("BOXDD_SYS_WASM_MODE=provider is only valid for wasm32 targets");
@@ -204,12 +213,14 @@ fn handle_wasm_build(config: &BuildConfig) -> bool {
             println!(
                 "cargo:warning=boxdd-sys is using compile-only WASM mode; Box2D C sources are not linked"
             );
+            emit_native_build("wasm-compile-only");
             true
         }
         WasmMode::Provider => {
             println!(
                 "cargo:warning=boxdd-sys WASM provider mode is active; Box2D symbols are imported from the browser provider module"
             );
+            emit_native_build("wasm-provider");
             true
         }
         WasmMode::Source => {
@@ -343,6 +354,7 @@ fn add_msvc_c_standard_flag(build: &mut cc::Build) {
 }
 
 fn build_box2d_from_source(config: &BuildConfig) {
+    emit_native_build("source");
     let box2d_root = config.manifest_dir.join("third-party").join("box2d");
     let box2d_include = box2d_root.join("include");
     let box2d_src = box2d_root.join("src");
@@ -3,3 +3,24 @@
 #![allow(non_upper_case_globals)]
 
 pub mod ffi;
+
+/// Build-time configuration of this crate, for diagnostics and bug reports.
+pub mod build_info {
+    /// Version of the `boxdd-sys` crate.
+    pub const CRATE_VERSION: &str = env!("CARGO_PKG_VERSION");
+
+    /// How the native Box2D library was provided to the final link.
+    ///
+    /// One of `"source"`, `"system"`, `"wasm-provider"`, `"wasm-compile-only"`, `"skipped"`,
+    /// `"docs-rs"`, or `"none"`.
+    pub const NATIVE_BUILD: &str = env!("BOXDD_SYS_NATIVE_BUILD");
+
+    /// Whether the `simd-avx2` feature was enabled.
+    pub const SIMD_AVX2: bool = cfg!(feature = "simd-avx2");
+
+    /// Whether the `disable-simd` feature was enabled.
+    pub const DISABLE_SIMD: bool = cfg!(feature = "disable-simd");
+
+    /// Whether the `validate` feature was enabled.
+    pub const VALIDATE: bool = cfg!(feature = "validate");
+}
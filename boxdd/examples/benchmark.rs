@@ -2,13 +2,20 @@ use boxdd::prelude::*;
 use std::time::Instant;
 
 // Headless micro-benchmark similar to sample_benchmark: build a moderate scene and time stepping.
+// Pass a worker count to step on a rayon pool: `cargo run --release --example benchmark --features parallel -- 4`.
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let cols = 25usize;
     let rows = 15usize;
     let steps = 300usize;
     let sub_steps = 8i32;
+    let workers: i32 = std::env::args().nth(1).map_or(Ok(1), |s| s.parse())?;
 
-    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, -10.0]).build())?;
+    let mut world = World::new(
+        WorldDef::builder()
+            .gravity([0.0_f32, -10.0])
+            .worker_count(workers)
+            .build(),
+    )?;
 
     // Ground
     let ground = world.create_body_id(BodyBuilder::new().build());
@@ -43,8 +50,15 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let avg_ms = dt.as_secs_f64() * 1000.0 / (steps as f64);
     let c = world.counters();
     println!(
-        "benchmark: bodies={} shapes={} contacts={} joints={} steps={} sub={} avg_ms_per_step={:.3}",
-        c.body_count, c.shape_count, c.contact_count, c.joint_count, steps, sub_steps, avg_ms
+        "benchmark: bodies={} shapes={} contacts={} joints={} steps={} sub={} workers={} avg_ms_per_step={:.3}",
+        c.body_count,
+        c.shape_count,
+        c.contact_count,
+        c.joint_count,
+        steps,
+        sub_steps,
+        world.task_system_worker_count().unwrap_or(1),
+        avg_ms
     );
     Ok(())
 }
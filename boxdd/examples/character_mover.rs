@@ -1,3 +1,4 @@
+use boxdd::character::{CharacterDef, CharacterMover};
 use boxdd::prelude::*;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -45,5 +46,17 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         solved.translation.x, solved.translation.y
     );
     println!("clipped vector: ({:.3}, {:.3})", clipped.x, clipped.y);
+
+    // The same loop, packaged: move-and-slide a capsule for a few frames.
+    let capsule = shapes::capsule([0.0_f32, -0.4], [0.0, 0.4], radius);
+    let mut mover = CharacterMover::new(CharacterDef::new(capsule).position([-2.0_f32, 1.2]));
+    let dt = 1.0 / 60.0;
+    let mut velocity = Vec2::new(4.0, -2.0);
+    for _ in 0..60 {
+        let result = mover.move_and_slide(&world, [velocity.x * dt, velocity.y * dt]);
+        velocity = result.clip(velocity);
+    }
+    let p = mover.position();
+    println!("character after 1s: ({:.3}, {:.3})", p.x, p.y);
     Ok(())
 }
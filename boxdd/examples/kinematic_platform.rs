@@ -11,7 +11,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         &shapes::box_polygon(50.0, 1.0),
     );
 
-    // Kinematic platform moving horizontally
+    // Kinematic platform swinging back and forth along a scripted path
     let platform = world.create_body_id(
         BodyBuilder::new()
             .body_type(BodyType::Kinematic)
@@ -23,7 +23,6 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         &ShapeDef::builder().density(0.0).build(),
         &shapes::box_polygon(2.0, 0.2),
     );
-    world.set_body_linear_velocity(platform, [1.5_f32, 0.0]);
 
     // Dynamic box on top
     let box_id = world.create_body_id(BodyBuilder::new().position([0.0_f32, 4.0]).build());
@@ -33,8 +32,13 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         &shapes::box_polygon(0.3, 0.3),
     );
 
-    for _ in 0..240 {
-        world.step(1.0 / 60.0, 4);
+    let dt = 1.0 / 60.0;
+    for i in 1..=240 {
+        // Box2D derives the platform's velocity from where it should be after this step.
+        let t = i as f32 * dt;
+        let target = Transform::from_pos_angle([3.0 * (0.5 * t).sin(), 3.0], 0.0);
+        world.set_body_target_transform(platform, target, dt, true);
+        world.step(dt, 4);
     }
     let p = world.body_position(box_id);
     println!("box after ride: ({:.2}, {:.2})", p.x, p.y);
@@ -32,14 +32,19 @@ fn main() {
     let scene = boxdd::serialize::SceneSnapshot::take(&world);
     let json = serde_json::to_string_pretty(&scene).expect("serialize scene");
     println!("scene json chars: {}", json.len());
+    // Binary format for save games: smaller and versioned.
+    let bytes = scene.to_bytes();
+    println!("scene binary bytes: {}", bytes.len());
+    let scene = boxdd::serialize::SceneSnapshot::from_bytes(&bytes).expect("decode scene");
 
     // Rebuild world from snapshot
-    let world2 = scene.rebuild();
+    let (world2, map) = scene.rebuild();
 
     // Validate body counts match
     let n1 = world.body_ids().len();
     let n2 = world2.body_ids().len();
     assert_eq!(n1, n2, "body count mismatch after rebuild");
+    assert_eq!(map.bodies.len(), scene.bodies.len());
 
     println!("ok: {} bodies round-tripped", n2);
 }
@@ -1,3 +1,4 @@
+use boxdd::ShapeProxy;
 use boxdd::prelude::*;
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
@@ -27,12 +28,14 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let trans = [3.0_f32, 0.0];
     let hits = world.cast_shape_points(square, 0.02, trans, QueryFilter::default());
     println!("shape cast hits: {}", hits.len());
-    if let Some(min) = hits
-        .iter()
-        .map(|h| h.fraction)
-        .min_by(|a, b| a.partial_cmp(b).unwrap())
-    {
-        println!("earliest collision fraction: {:.3}", min);
+
+    // Same sweep through a reusable proxy, reporting what it hits first.
+    let proxy = ShapeProxy::new(square, 0.02).expect("valid proxy");
+    if let Some(hit) = world.cast_shape_closest(&proxy, trans, QueryFilter::default()) {
+        println!(
+            "earliest collision fraction: {:.3} (body {:?})",
+            hit.fraction, hit.body_id
+        );
     }
     Ok(())
 }
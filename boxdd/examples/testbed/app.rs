@@ -228,11 +228,9 @@ impl TestbedWindow {
         self.physics.ui(ui);
 
         // Debug draw
-        let mut dd = ImguiDebugDraw {
-            ui,
-            pixels_per_meter: self.physics.pixels_per_meter,
-        };
-        let opts = self.physics.debug_draw_options();
+        let camera = self.physics.camera(ui.io().display_size());
+        let mut dd = ImguiDebugDraw { ui, camera };
+        let opts = self.physics.debug_draw_options(&camera);
         self.physics.world.debug_draw(&mut dd, opts);
 
         // Scene-specific overlays (drawn after debug draw so they stay on top)
@@ -3,7 +3,14 @@ use dear_imgui_rs as imgui;
 
 pub struct ImguiDebugDraw<'a> {
     pub ui: &'a imgui::Ui,
-    pub pixels_per_meter: f32,
+    pub camera: bd::Camera2D,
+}
+
+impl ImguiDebugDraw<'_> {
+    fn w2s(&self, v: bd::Vec2) -> [f32; 2] {
+        let p = self.camera.world_to_screen(v);
+        [p.x, p.y]
+    }
 }
 
 fn imgui_color(color: bd::HexColor, alpha: u8) -> u32 {
@@ -13,19 +20,13 @@ fn imgui_color(color: bd::HexColor, alpha: u8) -> u32 {
 impl bd::DebugDraw for ImguiDebugDraw<'_> {
     fn draw_segment(&mut self, p1: bd::Vec2, p2: bd::Vec2, color: bd::HexColor) {
         let dl = self.ui.get_foreground_draw_list();
-        let ds = self.ui.io().display_size();
-        let origin = [ds[0] * 0.5, ds[1] * 0.5];
-        let s = self.pixels_per_meter;
-        let w2s = |v: bd::Vec2| [origin[0] + v.x * s, ds[1] - (origin[1] + v.y * s)];
+        let w2s = |v: bd::Vec2| self.w2s(v);
         let col = imgui_color(color, 0xff);
         dl.add_line(w2s(p1), w2s(p2), col).build();
     }
     fn draw_polygon(&mut self, vertices: &[bd::Vec2], color: bd::HexColor) {
         let dl = self.ui.get_foreground_draw_list();
-        let ds = self.ui.io().display_size();
-        let origin = [ds[0] * 0.5, ds[1] * 0.5];
-        let s = self.pixels_per_meter;
-        let w2s = |v: bd::Vec2| [origin[0] + v.x * s, ds[1] - (origin[1] + v.y * s)];
+        let w2s = |v: bd::Vec2| self.w2s(v);
         let col = imgui_color(color, 0xff);
         for i in 0..vertices.len() {
             let a = w2s(vertices[i]);
@@ -35,10 +36,8 @@ impl bd::DebugDraw for ImguiDebugDraw<'_> {
     }
     fn draw_circle(&mut self, center: bd::Vec2, radius: f32, color: bd::HexColor) {
         let dl = self.ui.get_foreground_draw_list();
-        let ds = self.ui.io().display_size();
-        let origin = [ds[0] * 0.5, ds[1] * 0.5];
-        let s = self.pixels_per_meter;
-        let w2s = |v: bd::Vec2| [origin[0] + v.x * s, ds[1] - (origin[1] + v.y * s)];
+        let s = self.camera.pixels_per_meter;
+        let w2s = |v: bd::Vec2| self.w2s(v);
         let col = imgui_color(color, 0xff);
         dl.add_circle(w2s(center), radius * s, col).thickness(1.0).build();
     }
@@ -53,11 +52,8 @@ impl bd::DebugDraw for ImguiDebugDraw<'_> {
             return;
         }
         let dl = self.ui.get_foreground_draw_list();
-        let ds = self.ui.io().display_size();
-        let origin = [ds[0] * 0.5, ds[1] * 0.5];
-        let s = self.pixels_per_meter;
         let transform = |v: bd::Vec2| xf.transform_point(v);
-        let w2s = |v: bd::Vec2| [origin[0] + v.x * s, ds[1] - (origin[1] + v.y * s)];
+        let w2s = |v: bd::Vec2| self.w2s(v);
         let pts: Vec<[f32; 2]> = vertices.iter().map(|&v| w2s(transform(v))).collect();
         let fill = imgui_color(color, 0x40);
         dl.add_concave_poly_filled(&pts, fill);
@@ -69,11 +65,8 @@ impl bd::DebugDraw for ImguiDebugDraw<'_> {
     }
     fn draw_solid_circle(&mut self, xf: bd::Transform, radius: f32, color: bd::HexColor) {
         let dl = self.ui.get_foreground_draw_list();
-        let ds = self.ui.io().display_size();
-        let origin = [ds[0] * 0.5, ds[1] * 0.5];
-        let s = self.pixels_per_meter;
         let center = xf.position();
-        let w2s = |v: bd::Vec2| [origin[0] + v.x * s, ds[1] - (origin[1] + v.y * s)];
+        let w2s = |v: bd::Vec2| self.w2s(v);
         let fill = imgui_color(color, 0x40);
         let outline = imgui_color(color, 0xff);
         // Approximate filled circle with polygon
@@ -93,10 +86,8 @@ impl bd::DebugDraw for ImguiDebugDraw<'_> {
     fn draw_solid_capsule(&mut self, p1: bd::Vec2, p2: bd::Vec2, radius: f32, color: bd::HexColor) {
         // Approximate: thick line + end circles
         let dl = self.ui.get_foreground_draw_list();
-        let ds = self.ui.io().display_size();
-        let origin = [ds[0] * 0.5, ds[1] * 0.5];
-        let s = self.pixels_per_meter;
-        let w2s = |v: bd::Vec2| [origin[0] + v.x * s, ds[1] - (origin[1] + v.y * s)];
+        let s = self.camera.pixels_per_meter;
+        let w2s = |v: bd::Vec2| self.w2s(v);
         let outline = imgui_color(color, 0xff);
         let fill = imgui_color(color, 0x40);
         dl.add_line(w2s(p1), w2s(p2), fill)
@@ -108,10 +99,7 @@ impl bd::DebugDraw for ImguiDebugDraw<'_> {
     }
     fn draw_transform(&mut self, xf: bd::Transform) {
         let dl = self.ui.get_foreground_draw_list();
-        let ds = self.ui.io().display_size();
-        let origin = [ds[0] * 0.5, ds[1] * 0.5];
-        let s = self.pixels_per_meter;
-        let w2s = |v: bd::Vec2| [origin[0] + v.x * s, ds[1] - (origin[1] + v.y * s)];
+        let w2s = |v: bd::Vec2| self.w2s(v);
         let len = 0.5;
         let rot = xf.rotation();
         let x_axis = rot.rotate_vec(bd::Vec2::new(len, 0.0));
@@ -124,10 +112,7 @@ impl bd::DebugDraw for ImguiDebugDraw<'_> {
     }
     fn draw_point(&mut self, p: bd::Vec2, size: f32, color: bd::HexColor) {
         let dl = self.ui.get_foreground_draw_list();
-        let ds = self.ui.io().display_size();
-        let origin = [ds[0] * 0.5, ds[1] * 0.5];
-        let s = self.pixels_per_meter;
-        let w2s = |v: bd::Vec2| [origin[0] + v.x * s, ds[1] - (origin[1] + v.y * s)];
+        let w2s = |v: bd::Vec2| self.w2s(v);
         let col = imgui_color(color, 0xff);
         // Small dot as tiny polygon (triangle approximation)
         let r = (size.max(2.0)) * 0.5;
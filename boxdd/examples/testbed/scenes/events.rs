@@ -47,23 +47,14 @@ pub fn build(app: &mut super::PhysicsApp, ground: bd::types::BodyId) {
 }
 
 pub fn tick(app: &mut super::PhysicsApp) {
-    let world = &app.world;
-    let scratch = &mut app.scratch;
-
-    world.body_events_into(&mut scratch.body_events);
-    app.ev_moves += scratch.body_events.len();
-
-    world.sensor_events_into(&mut scratch.sensor_events);
-    app.ev_sens_beg += scratch.sensor_events.begin.len();
-    app.ev_sens_end += scratch.sensor_events.end.len();
-
-    world.contact_events_into(&mut scratch.contact_events);
-    app.ev_con_beg += scratch.contact_events.begin.len();
-    app.ev_con_end += scratch.contact_events.end.len();
-    app.ev_con_hit += scratch.contact_events.hit.len();
-
-    world.joint_events_into(&mut scratch.joint_events);
-    app.ev_joint += scratch.joint_events.len();
+    let counts = app.world.event_summary();
+    app.ev_moves += counts.moves;
+    app.ev_sens_beg += counts.sensors_begun;
+    app.ev_sens_end += counts.sensors_ended;
+    app.ev_con_beg += counts.contacts_begun;
+    app.ev_con_end += counts.contacts_ended;
+    app.ev_con_hit += counts.hits;
+    app.ev_joint += counts.joint_events;
 }
 
 pub fn ui_params(app: &mut super::PhysicsApp, ui: &imgui::Ui) {
@@ -205,10 +205,11 @@ pub fn ui_params(app: &mut super::PhysicsApp, ui: &imgui::Ui) {
 pub fn debug_overlay(app: &super::PhysicsApp, ui: &imgui::Ui) {
     let state = &app.manifold;
     let dl = ui.get_foreground_draw_list();
-    let ds = ui.io().display_size();
-    let origin = [ds[0] * 0.5, ds[1] * 0.5];
-    let scale = app.pixels_per_meter;
-    let w2s = |x: f32, y: f32| [origin[0] + x * scale, ds[1] - (origin[1] + y * scale)];
+    let camera = app.camera(ui.io().display_size());
+    let w2s = |x: f32, y: f32| {
+        let p = camera.world_to_screen([x, y]);
+        [p.x, p.y]
+    };
 
     let point = w2s(state.point1_x, state.point1_y);
     dl.add_circle(point, 5.0, 0xffff55ffu32)
@@ -479,7 +479,6 @@ impl MaterialsState {
 // reflects the same hot-path guidance as the public examples and docs.
 #[derive(Default)]
 pub struct TestbedScratch {
-    pub body_events: Vec<bd::BodyMoveEvent>,
     pub sensor_events: bd::SensorEvents,
     pub contact_events: bd::ContactEvents,
     pub joint_events: Vec<bd::JointEvent>,
@@ -488,7 +487,6 @@ pub struct TestbedScratch {
 
 impl TestbedScratch {
     fn reset_runtime(&mut self) {
-        self.body_events.clear();
         self.sensor_events.begin.clear();
         self.sensor_events.end.clear();
         self.contact_events.begin.clear();
@@ -1216,8 +1214,8 @@ impl PhysicsApp {
                 Scene::ContinuousLab if self.cl_mode == 0 => (self.bullet_dt, self.bullet_substeps),
                 _ => (1.0 / 60.0, self.sub_steps),
             };
-            let dt = (base_dt * self.time_scale.max(0.0)).max(0.0);
-            self.world.step(dt, sub);
+            self.world
+                .step_scaled(base_dt, self.time_scale.max(0.0), base_dt / sub as f32);
             self.step_ms = t0.elapsed().as_secs_f32() * 1000.0;
             let c = self.world.counters();
             self.cnt_bodies = c.body_count;
@@ -1398,8 +1396,8 @@ impl PhysicsApp {
             Scene::ContinuousLab if self.cl_mode == 0 => (self.bullet_dt, self.bullet_substeps),
             _ => (1.0 / 60.0, self.sub_steps),
         };
-        let dt = (base_dt * self.time_scale.max(0.0)).max(0.0);
-        self.world.step(dt, sub);
+        self.world
+            .step_scaled(base_dt, self.time_scale.max(0.0), base_dt / sub as f32);
     }
 
     pub fn scene_index(&self) -> usize {
@@ -1416,7 +1414,12 @@ impl PhysicsApp {
             .unwrap_or(Scene::Pyramid)
     }
 
-    pub fn debug_draw_options(&self) -> bd::DebugDrawOptions {
+    /// Camera centered on the world origin, sized to the current display.
+    pub fn camera(&self, display_size: [f32; 2]) -> bd::Camera2D {
+        bd::Camera2D::new([0.0, 0.0], self.pixels_per_meter, display_size)
+    }
+
+    pub fn debug_draw_options(&self, camera: &bd::Camera2D) -> bd::DebugDrawOptions {
         bd::DebugDrawOptions {
             force_scale: self.dd_force_scale,
             joint_scale: self.dd_joint_scale,
@@ -1435,5 +1438,6 @@ impl PhysicsApp {
             draw_islands: self.dd_draw_islands,
             ..Default::default()
         }
+        .culled_to(camera)
     }
 }
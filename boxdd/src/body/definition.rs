@@ -117,22 +117,26 @@ pub(crate) fn assert_body_def_valid(def: &BodyDef) {
     );
 }
 
+/// Field-level form of the body definition checks, shared by creation and
+/// [`BodyBuilder::build_checked`].
+pub(crate) fn validate_body_def(def: &BodyDef) -> crate::core::def_checks::CheckResult {
+    let c = crate::core::def_checks::DefChecks("BodyDef");
+    c.initialized(body_def_cookie_is_valid(def))?;
+    c.require("body_type", body_type_is_known(def.0.type_), || {
+        format!("must be a known body type, got {}", def.0.type_)
+    })?;
+    c.vec2("position", def.position())?;
+    c.rot("rotation", def.rotation())?;
+    c.vec2("linear_velocity", def.linear_velocity())?;
+    c.finite("angular_velocity", def.angular_velocity())?;
+    c.non_negative("linear_damping", def.linear_damping())?;
+    c.non_negative("angular_damping", def.angular_damping())?;
+    c.non_negative("sleep_threshold", def.0.sleepThreshold)?;
+    c.finite("gravity_scale", def.gravity_scale())
+}
+
 pub(crate) fn check_body_def_valid(def: &BodyDef) -> ApiResult<()> {
-    if !body_def_cookie_is_valid(def)
-        || !body_type_is_known(def.0.type_)
-        || !Vec2::from_raw(def.0.position).is_valid()
-        || !crate::Rot::from_raw(def.0.rotation).is_valid()
-        || !Vec2::from_raw(def.0.linearVelocity).is_valid()
-        || !crate::is_valid_float(def.0.angularVelocity)
-        || check_non_negative_finite_body_scalar(def.0.linearDamping).is_err()
-        || check_non_negative_finite_body_scalar(def.0.angularDamping).is_err()
-        || check_non_negative_finite_body_scalar(def.0.sleepThreshold).is_err()
-        || !crate::is_valid_float(def.0.gravityScale)
-    {
-        Err(ApiError::InvalidArgument)
-    } else {
-        Ok(())
-    }
+    validate_body_def(def).map_err(Into::into)
 }
 
 /// Body definition wrapper with builder API.
@@ -349,6 +353,13 @@ impl BodyBuilder {
     pub fn build(self) -> BodyDef {
         self.def
     }
+
+    /// Build the definition, running the same checks as body creation and reporting the first
+    /// failing field. `build()` skips these checks.
+    pub fn build_checked(self) -> Result<BodyDef, crate::error::ValidationError> {
+        validate_body_def(&self.def)?;
+        Ok(self.def)
+    }
 }
 
 impl From<BodyDef> for BodyBuilder {
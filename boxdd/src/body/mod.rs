@@ -11,3 +11,13 @@ pub(crate) use definition::{
     assert_body_def_valid, assert_mass_data_valid, check_body_def_valid, check_mass_data_valid,
 };
 pub(crate) use runtime::*;
+
+/// Body state captured by `World::destroy_body_take` just before the body was destroyed.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug)]
+pub struct BodyFinalState {
+    pub body_type: BodyType,
+    pub transform: crate::Transform,
+    pub linear_velocity: crate::types::Vec2,
+    pub angular_velocity: f32,
+}
@@ -6,7 +6,7 @@ use std::sync::Arc;
 use crate::core::world_core::WorldCore;
 use crate::error::ApiResult;
 use crate::query::Aabb;
-use crate::types::{BodyId, ContactData, JointId, MassData, ShapeId, Vec2};
+use crate::types::{BodyId, ContactData, JointId, MassData, MotionLocks, ShapeId, Vec2};
 use boxdd_sys::ffi;
 
 use super::definition::BodyType;
@@ -524,6 +524,32 @@ impl OwnedBody {
         BodyRuntimeHandle::try_set_bullet(self, flag)
     }
 
+    /// Translation and rotation locks, see [`MotionLocks`].
+    pub fn motion_locks(&self) -> MotionLocks {
+        BodyRuntimeHandle::motion_locks(self)
+    }
+
+    pub fn try_motion_locks(&self) -> ApiResult<MotionLocks> {
+        BodyRuntimeHandle::try_motion_locks(self)
+    }
+
+    pub fn set_motion_locks(&mut self, locks: MotionLocks) {
+        BodyRuntimeHandle::set_motion_locks(self, locks)
+    }
+
+    pub fn try_set_motion_locks(&mut self, locks: MotionLocks) -> ApiResult<()> {
+        BodyRuntimeHandle::try_set_motion_locks(self, locks)
+    }
+
+    /// Wake every body touching this one.
+    pub fn wake_touching(&mut self) {
+        BodyRuntimeHandle::wake_touching(self)
+    }
+
+    pub fn try_wake_touching(&mut self) -> ApiResult<()> {
+        BodyRuntimeHandle::try_wake_touching(self)
+    }
+
     pub fn enable_contact_events(&mut self, flag: bool) {
         BodyRuntimeHandle::enable_contact_events(self, flag)
     }
@@ -687,10 +713,7 @@ impl OwnedBody {
                 self.core
                     .defer_destroy(crate::core::world_core::DeferredDestroy::Body(self.id));
             } else {
-                #[cfg(feature = "serialize")]
-                self.core.cleanup_before_destroy_body(self.id);
-                unsafe { ffi::b2DestroyBody(raw_body_id(self.id)) };
-                let _ = self.core.clear_body_user_data(self.id);
+                self.core.destroy_body_now(self.id);
             }
         }
         self.destroy_on_drop = false;
@@ -711,10 +734,7 @@ impl Drop for OwnedBody {
                 self.core
                     .defer_destroy(crate::core::world_core::DeferredDestroy::Body(self.id));
             } else {
-                #[cfg(feature = "serialize")]
-                self.core.cleanup_before_destroy_body(self.id);
-                unsafe { ffi::b2DestroyBody(raw_body_id(self.id)) };
-                let _ = self.core.clear_body_user_data(self.id);
+                self.core.destroy_body_now(self.id);
             }
         }
     }
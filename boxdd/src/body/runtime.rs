@@ -11,6 +11,7 @@ mod handle;
 mod user_data;
 
 pub(crate) use handle::BodyRuntimeHandle;
+pub(crate) use user_data::*;
 
 #[inline]
 pub(crate) fn raw_body_id(id: BodyId) -> ffi::b2BodyId {
@@ -23,7 +24,7 @@ fn body_world_id_impl(id: BodyId) -> ffi::b2WorldId {
 }
 
 #[inline]
-fn body_is_valid_impl(id: BodyId) -> bool {
+pub(crate) fn body_is_valid_impl(id: BodyId) -> bool {
     unsafe { ffi::b2Body_IsValid(raw_body_id(id)) }
 }
 
@@ -104,11 +105,25 @@ pub(crate) fn body_world_point_velocity_impl<V: Into<Vec2>>(id: BodyId, world_po
 }
 
 #[inline]
-fn body_set_position_and_rotation_impl<V: Into<Vec2>>(id: BodyId, position: V, angle_radians: f32) {
-    let (s, c) = angle_radians.sin_cos();
-    let rotation = ffi::b2Rot { c, s };
-    let position: ffi::b2Vec2 = position.into().into_raw();
-    unsafe { ffi::b2Body_SetTransform(raw_body_id(id), position, rotation) };
+pub(crate) fn body_set_transform_impl(
+    core: &crate::core::world_core::WorldCore,
+    id: BodyId,
+    position: Vec2,
+    rotation: crate::Rot,
+) {
+    core.note_body_change(id);
+    unsafe { ffi::b2Body_SetTransform(raw_body_id(id), position.into_raw(), rotation.into_raw()) };
+}
+
+#[inline]
+pub(crate) fn body_set_position_and_rotation_impl<V: Into<Vec2>>(
+    core: &crate::core::world_core::WorldCore,
+    id: BodyId,
+    position: V,
+    angle_radians: f32,
+) {
+    let rotation = crate::Rot::from_radians(angle_radians);
+    body_set_transform_impl(core, id, position.into(), rotation);
 }
 
 #[inline]
@@ -132,11 +147,20 @@ fn body_set_target_transform_impl(
     unsafe { ffi::b2Body_SetTargetTransform(raw_body_id(id), target.into_raw(), time_step, wake) };
 }
 
+/// Apply `force` at world `point` and record it for force tracking.
 #[inline]
-fn body_apply_force_impl<F: Into<Vec2>, P: Into<Vec2>>(id: BodyId, force: F, point: P, wake: bool) {
-    let force: ffi::b2Vec2 = force.into().into_raw();
-    let point: ffi::b2Vec2 = point.into().into_raw();
-    unsafe { ffi::b2Body_ApplyForce(raw_body_id(id), force, point, wake) };
+pub(crate) fn body_apply_force_impl(
+    core: &crate::core::world_core::WorldCore,
+    id: BodyId,
+    force: Vec2,
+    point: Vec2,
+    wake: bool,
+) {
+    unsafe { ffi::b2Body_ApplyForce(raw_body_id(id), force.into_raw(), point.into_raw(), wake) };
+    core.record_applied(
+        id,
+        crate::core::world_core::Applied::Force(force, Some(point)),
+    );
 }
 
 #[inline]
@@ -156,7 +180,7 @@ fn body_clear_forces_impl(id: BodyId) {
 }
 
 #[inline]
-fn body_apply_linear_impulse_impl<F: Into<Vec2>, P: Into<Vec2>>(
+pub(crate) fn body_apply_linear_impulse_impl<F: Into<Vec2>, P: Into<Vec2>>(
     id: BodyId,
     impulse: F,
     point: P,
@@ -208,6 +232,16 @@ pub(crate) fn body_motion_locks_impl(id: BodyId) -> MotionLocks {
     MotionLocks::from_raw(unsafe { ffi::b2Body_GetMotionLocks(raw_body_id(id)) })
 }
 
+#[inline]
+pub(crate) fn body_set_motion_locks_impl(id: BodyId, locks: MotionLocks) {
+    unsafe { ffi::b2Body_SetMotionLocks(raw_body_id(id), locks.into_raw()) }
+}
+
+#[inline]
+pub(crate) fn body_wake_touching_impl(id: BodyId) {
+    unsafe { ffi::b2Body_WakeTouching(raw_body_id(id)) }
+}
+
 #[inline]
 fn body_set_mass_data_impl(id: BodyId, mass_data: MassData) {
     unsafe { ffi::b2Body_SetMassData(raw_body_id(id), mass_data.into_raw()) };
@@ -282,13 +316,53 @@ pub(crate) fn body_joints_impl(id: BodyId) -> Vec<JointId> {
     }
 }
 
+/// Breadth-first walk over joints starting at `id`, up to `max_depth` joint hops. Static bodies
+/// are reported but not walked through, so mechanisms pinned to the same ground stay separate.
+pub(crate) fn body_connected_bodies_impl(id: BodyId, max_depth: usize) -> Vec<BodyId> {
+    let mut visited = vec![id];
+    let mut seen = std::collections::HashSet::from([id]);
+    let mut frontier = vec![id];
+    let mut joints = Vec::new();
+    let mut depth = 0;
+    while !frontier.is_empty() && depth < max_depth {
+        let mut next = Vec::new();
+        for body in frontier {
+            if body != id && body_type_impl(body) == BodyType::Static {
+                continue;
+            }
+            body_joints_into_impl(body, &mut joints);
+            for &joint in &joints {
+                let a = crate::joints::joint_body_a_id_impl(joint);
+                let other = if a == body {
+                    crate::joints::joint_body_b_id_impl(joint)
+                } else {
+                    a
+                };
+                if seen.insert(other) {
+                    visited.push(other);
+                    next.push(other);
+                }
+            }
+        }
+        frontier = next;
+        depth += 1;
+    }
+    visited
+}
+
 #[inline]
 pub(crate) fn body_type_impl(id: BodyId) -> BodyType {
     BodyType::from_raw(unsafe { ffi::b2Body_GetType(raw_body_id(id)) })
 }
 
 #[inline]
-fn body_set_type_impl(id: BodyId, body_type: BodyType) {
+pub(crate) fn body_set_type_impl(
+    core: &crate::core::world_core::WorldCore,
+    id: BodyId,
+    body_type: BodyType,
+) {
+    core.bump_static_revision();
+    core.mark_gravity_rules_dirty();
     unsafe { ffi::b2Body_SetType(raw_body_id(id), body_type.into_raw()) }
 }
 
@@ -358,12 +432,14 @@ pub(crate) fn body_is_enabled_impl(id: BodyId) -> bool {
 }
 
 #[inline]
-pub(crate) fn body_enable_impl(id: BodyId) {
+pub(crate) fn body_enable_impl(core: &crate::core::world_core::WorldCore, id: BodyId) {
+    core.note_body_change(id);
     unsafe { ffi::b2Body_Enable(raw_body_id(id)) }
 }
 
 #[inline]
-pub(crate) fn body_disable_impl(id: BodyId) {
+pub(crate) fn body_disable_impl(core: &crate::core::world_core::WorldCore, id: BodyId) {
+    core.note_body_change(id);
     unsafe { ffi::b2Body_Disable(raw_body_id(id)) }
 }
 
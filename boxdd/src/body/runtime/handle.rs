@@ -1,7 +1,7 @@
 use super::attachments::*;
 use super::user_data::*;
 use super::*;
-use crate::core::world_core::WorldCore;
+use crate::core::world_core::{Applied, WorldCore};
 use crate::error::{ApiError, ApiResult};
 use crate::types::ContactData;
 use std::ffi::CString;
@@ -210,7 +210,12 @@ pub(crate) trait BodyRuntimeHandle {
 
     fn set_position_and_rotation<V: Into<Vec2>>(&mut self, position: V, angle_radians: f32) {
         self.assert_valid();
-        body_set_position_and_rotation_impl(self.body_id(), position, angle_radians);
+        body_set_position_and_rotation_impl(
+            self.body_world_core(),
+            self.body_id(),
+            position,
+            angle_radians,
+        );
     }
 
     fn try_set_position_and_rotation<V: Into<Vec2>>(
@@ -219,7 +224,12 @@ pub(crate) trait BodyRuntimeHandle {
         angle_radians: f32,
     ) -> ApiResult<()> {
         self.check_valid()?;
-        body_set_position_and_rotation_impl(self.body_id(), position, angle_radians);
+        body_set_position_and_rotation_impl(
+            self.body_world_core(),
+            self.body_id(),
+            position,
+            angle_radians,
+        );
         Ok(())
     }
 
@@ -295,7 +305,8 @@ pub(crate) trait BodyRuntimeHandle {
 
     fn apply_force<F: Into<Vec2>, P: Into<Vec2>>(&mut self, force: F, point: P, wake: bool) {
         self.assert_valid();
-        body_apply_force_impl(self.body_id(), force, point, wake);
+        let (force, point) = (force.into(), point.into());
+        body_apply_force_impl(self.body_world_core(), self.body_id(), force, point, wake);
     }
 
     fn try_apply_force<F: Into<Vec2>, P: Into<Vec2>>(
@@ -305,29 +316,40 @@ pub(crate) trait BodyRuntimeHandle {
         wake: bool,
     ) -> ApiResult<()> {
         self.check_valid()?;
-        body_apply_force_impl(self.body_id(), force, point, wake);
+        let (force, point) = (force.into(), point.into());
+        body_apply_force_impl(self.body_world_core(), self.body_id(), force, point, wake);
         Ok(())
     }
 
     fn apply_force_to_center<V: Into<Vec2>>(&mut self, force: V, wake: bool) {
         self.assert_valid();
+        let force = force.into();
         body_apply_force_to_center_impl(self.body_id(), force, wake);
+        self.body_world_core()
+            .record_applied(self.body_id(), Applied::Force(force, None));
     }
 
     fn try_apply_force_to_center<V: Into<Vec2>>(&mut self, force: V, wake: bool) -> ApiResult<()> {
         self.check_valid()?;
+        let force = force.into();
         body_apply_force_to_center_impl(self.body_id(), force, wake);
+        self.body_world_core()
+            .record_applied(self.body_id(), Applied::Force(force, None));
         Ok(())
     }
 
     fn apply_torque(&mut self, torque: f32, wake: bool) {
         self.assert_valid();
-        body_apply_torque_impl(self.body_id(), torque, wake)
+        body_apply_torque_impl(self.body_id(), torque, wake);
+        self.body_world_core()
+            .record_applied(self.body_id(), Applied::Torque(torque));
     }
 
     fn try_apply_torque(&mut self, torque: f32, wake: bool) -> ApiResult<()> {
         self.check_valid()?;
         body_apply_torque_impl(self.body_id(), torque, wake);
+        self.body_world_core()
+            .record_applied(self.body_id(), Applied::Torque(torque));
         Ok(())
     }
 
@@ -349,7 +371,10 @@ pub(crate) trait BodyRuntimeHandle {
         wake: bool,
     ) {
         self.assert_valid();
+        let (impulse, point) = (impulse.into(), point.into());
         body_apply_linear_impulse_impl(self.body_id(), impulse, point, wake);
+        self.body_world_core()
+            .record_applied(self.body_id(), Applied::LinearImpulse(impulse, Some(point)));
     }
 
     fn try_apply_linear_impulse<F: Into<Vec2>, P: Into<Vec2>>(
@@ -359,13 +384,19 @@ pub(crate) trait BodyRuntimeHandle {
         wake: bool,
     ) -> ApiResult<()> {
         self.check_valid()?;
+        let (impulse, point) = (impulse.into(), point.into());
         body_apply_linear_impulse_impl(self.body_id(), impulse, point, wake);
+        self.body_world_core()
+            .record_applied(self.body_id(), Applied::LinearImpulse(impulse, Some(point)));
         Ok(())
     }
 
     fn apply_linear_impulse_to_center<V: Into<Vec2>>(&mut self, impulse: V, wake: bool) {
         self.assert_valid();
+        let impulse = impulse.into();
         body_apply_linear_impulse_to_center_impl(self.body_id(), impulse, wake);
+        self.body_world_core()
+            .record_applied(self.body_id(), Applied::LinearImpulse(impulse, None));
     }
 
     fn try_apply_linear_impulse_to_center<V: Into<Vec2>>(
@@ -374,18 +405,25 @@ pub(crate) trait BodyRuntimeHandle {
         wake: bool,
     ) -> ApiResult<()> {
         self.check_valid()?;
+        let impulse = impulse.into();
         body_apply_linear_impulse_to_center_impl(self.body_id(), impulse, wake);
+        self.body_world_core()
+            .record_applied(self.body_id(), Applied::LinearImpulse(impulse, None));
         Ok(())
     }
 
     fn apply_angular_impulse(&mut self, impulse: f32, wake: bool) {
         self.assert_valid();
-        body_apply_angular_impulse_impl(self.body_id(), impulse, wake)
+        body_apply_angular_impulse_impl(self.body_id(), impulse, wake);
+        self.body_world_core()
+            .record_applied(self.body_id(), Applied::AngularImpulse(impulse));
     }
 
     fn try_apply_angular_impulse(&mut self, impulse: f32, wake: bool) -> ApiResult<()> {
         self.check_valid()?;
         body_apply_angular_impulse_impl(self.body_id(), impulse, wake);
+        self.body_world_core()
+            .record_applied(self.body_id(), Applied::AngularImpulse(impulse));
         Ok(())
     }
 
@@ -523,12 +561,12 @@ pub(crate) trait BodyRuntimeHandle {
 
     fn set_body_type(&mut self, body_type: BodyType) {
         self.assert_valid();
-        body_set_type_impl(self.body_id(), body_type)
+        body_set_type_impl(self.body_world_core(), self.body_id(), body_type)
     }
 
     fn try_set_body_type(&mut self, body_type: BodyType) -> ApiResult<()> {
         self.check_valid()?;
-        body_set_type_impl(self.body_id(), body_type);
+        body_set_type_impl(self.body_world_core(), self.body_id(), body_type);
         Ok(())
     }
 
@@ -681,23 +719,23 @@ pub(crate) trait BodyRuntimeHandle {
 
     fn enable(&mut self) {
         self.assert_valid();
-        body_enable_impl(self.body_id())
+        body_enable_impl(self.body_world_core(), self.body_id())
     }
 
     fn try_enable(&mut self) -> ApiResult<()> {
         self.check_valid()?;
-        body_enable_impl(self.body_id());
+        body_enable_impl(self.body_world_core(), self.body_id());
         Ok(())
     }
 
     fn disable(&mut self) {
         self.assert_valid();
-        body_disable_impl(self.body_id())
+        body_disable_impl(self.body_world_core(), self.body_id())
     }
 
     fn try_disable(&mut self) -> ApiResult<()> {
         self.check_valid()?;
-        body_disable_impl(self.body_id());
+        body_disable_impl(self.body_world_core(), self.body_id());
         Ok(())
     }
 
@@ -722,6 +760,38 @@ pub(crate) trait BodyRuntimeHandle {
         Ok(())
     }
 
+    fn motion_locks(&self) -> MotionLocks {
+        self.assert_valid();
+        body_motion_locks_impl(self.body_id())
+    }
+
+    fn try_motion_locks(&self) -> ApiResult<MotionLocks> {
+        self.check_valid()?;
+        Ok(body_motion_locks_impl(self.body_id()))
+    }
+
+    fn set_motion_locks(&mut self, locks: MotionLocks) {
+        self.assert_valid();
+        body_set_motion_locks_impl(self.body_id(), locks)
+    }
+
+    fn try_set_motion_locks(&mut self, locks: MotionLocks) -> ApiResult<()> {
+        self.check_valid()?;
+        body_set_motion_locks_impl(self.body_id(), locks);
+        Ok(())
+    }
+
+    fn wake_touching(&mut self) {
+        self.assert_valid();
+        body_wake_touching_impl(self.body_id())
+    }
+
+    fn try_wake_touching(&mut self) -> ApiResult<()> {
+        self.check_valid()?;
+        body_wake_touching_impl(self.body_id());
+        Ok(())
+    }
+
     fn enable_contact_events(&mut self, flag: bool) {
         self.assert_valid();
         body_enable_contact_events_impl(self.body_id(), flag)
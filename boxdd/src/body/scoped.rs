@@ -5,7 +5,7 @@ use std::sync::Arc;
 use crate::core::world_core::WorldCore;
 use crate::error::ApiResult;
 use crate::query::Aabb;
-use crate::types::{BodyId, ContactData, JointId, MassData, ShapeId, Vec2};
+use crate::types::{BodyId, ContactData, JointId, MassData, MotionLocks, ShapeId, Vec2};
 use crate::world::World;
 use boxdd_sys::ffi;
 
@@ -165,6 +165,15 @@ impl<'w> Body<'w> {
     pub fn set_position_and_rotation<V: Into<Vec2>>(&mut self, p: V, angle_radians: f32) {
         BodyRuntimeHandle::set_position_and_rotation(self, p, angle_radians);
     }
+
+    pub fn try_set_position_and_rotation<V: Into<Vec2>>(
+        &mut self,
+        p: V,
+        angle_radians: f32,
+    ) -> ApiResult<()> {
+        BodyRuntimeHandle::try_set_position_and_rotation(self, p, angle_radians)
+    }
+
     pub fn set_linear_velocity<V: Into<Vec2>>(&mut self, v: V) {
         BodyRuntimeHandle::set_linear_velocity(self, v)
     }
@@ -545,6 +554,32 @@ impl<'w> Body<'w> {
         BodyRuntimeHandle::try_set_bullet(self, flag)
     }
 
+    /// Translation and rotation locks, see [`MotionLocks`].
+    pub fn motion_locks(&self) -> MotionLocks {
+        BodyRuntimeHandle::motion_locks(self)
+    }
+
+    pub fn try_motion_locks(&self) -> ApiResult<MotionLocks> {
+        BodyRuntimeHandle::try_motion_locks(self)
+    }
+
+    pub fn set_motion_locks(&mut self, locks: MotionLocks) {
+        BodyRuntimeHandle::set_motion_locks(self, locks)
+    }
+
+    pub fn try_set_motion_locks(&mut self, locks: MotionLocks) -> ApiResult<()> {
+        BodyRuntimeHandle::try_set_motion_locks(self, locks)
+    }
+
+    /// Wake every body touching this one.
+    pub fn wake_touching(&mut self) {
+        BodyRuntimeHandle::wake_touching(self)
+    }
+
+    pub fn try_wake_touching(&mut self) -> ApiResult<()> {
+        BodyRuntimeHandle::try_wake_touching(self)
+    }
+
     pub fn enable_contact_events(&mut self, flag: bool) {
         BodyRuntimeHandle::enable_contact_events(self, flag)
     }
@@ -0,0 +1,115 @@
+//! Build and version introspection for bug reports.
+//!
+//! `build_info()` collects the crate version, the linked Box2D version, the native build
+//! features forwarded to `boxdd-sys`, and how the native library was provided.
+
+use crate::core::math::{Version, version};
+
+/// How the native Box2D library was provided at build time.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum NativeBuild {
+    /// Vendored Box2D C sources compiled by `boxdd-sys`.
+    Source,
+    /// A system library linked via `BOX2D_LIB_DIR` or `pkg-config`.
+    System,
+    /// Box2D symbols imported from a WASM provider module.
+    WasmProvider,
+    /// WASM compile-only mode; Box2D symbols are not linked.
+    WasmCompileOnly,
+    /// Native build skipped via `BOXDD_SYS_SKIP_CC`.
+    Skipped,
+    /// docs.rs build; no native library.
+    DocsRs,
+    /// `build-from-source` disabled and no system library found.
+    None,
+}
+
+impl NativeBuild {
+    fn from_sys(kind: &str) -> Self {
+        match kind {
+            "source" => Self::Source,
+            "system" => Self::System,
+            "wasm-provider" => Self::WasmProvider,
+            "wasm-compile-only" => Self::WasmCompileOnly,
+            "skipped" => Self::Skipped,
+            "docs-rs" => Self::DocsRs,
+            _ => Self::None,
+        }
+    }
+
+    /// Stable lowercase name as reported by `boxdd-sys`.
+    pub const fn as_str(self) -> &'static str {
+        match self {
+            Self::Source => "source",
+            Self::System => "system",
+            Self::WasmProvider => "wasm-provider",
+            Self::WasmCompileOnly => "wasm-compile-only",
+            Self::Skipped => "skipped",
+            Self::DocsRs => "docs-rs",
+            Self::None => "none",
+        }
+    }
+}
+
+impl core::fmt::Display for NativeBuild {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Crate/native version and build configuration.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BuildInfo {
+    /// Version of the `boxdd` crate.
+    pub crate_version: &'static str,
+    /// Version of the `boxdd-sys` crate.
+    pub sys_version: &'static str,
+    /// Box2D version reported by the linked library (`b2GetVersion`).
+    pub box2d_version: Version,
+    /// Whether the `simd-avx2` feature was enabled for the native build.
+    pub simd_avx2: bool,
+    /// Whether the `disable-simd` feature was enabled for the native build.
+    pub disable_simd: bool,
+    /// Whether the `validate` feature was enabled for the native build.
+    pub validate: bool,
+    /// How the native library was provided.
+    pub native_build: NativeBuild,
+}
+
+impl core::fmt::Display for BuildInfo {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let v = self.box2d_version;
+        write!(
+            f,
+            "boxdd {} (boxdd-sys {}), Box2D {}.{}.{}, native build: {}",
+            self.crate_version, self.sys_version, v.major, v.minor, v.revision, self.native_build
+        )?;
+        if self.simd_avx2 {
+            f.write_str(", simd-avx2")?;
+        }
+        if self.disable_simd {
+            f.write_str(", disable-simd")?;
+        }
+        if self.validate {
+            f.write_str(", validate")?;
+        }
+        Ok(())
+    }
+}
+
+/// Collect version and build configuration, e.g. for attaching to bug reports.
+///
+/// Features are reported as requested at build time; system libraries ignore them.
+pub fn build_info() -> BuildInfo {
+    use boxdd_sys::build_info as sys;
+    BuildInfo {
+        crate_version: env!("CARGO_PKG_VERSION"),
+        sys_version: sys::CRATE_VERSION,
+        box2d_version: version(),
+        simd_avx2: sys::SIMD_AVX2,
+        disable_simd: sys::DISABLE_SIMD,
+        validate: sys::VALIDATE,
+        native_build: NativeBuild::from_sys(sys::NATIVE_BUILD),
+    }
+}
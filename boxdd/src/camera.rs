@@ -0,0 +1,160 @@
+//! 2D camera for mapping between world meters and screen pixels.
+//!
+//! Renderers and tools all need the same handful of conversions: world point to pixel, pixel back
+//! to world (for picking), zooming around the mouse cursor, and the visible world rectangle (for
+//! culling). [`Camera2D`] keeps that math in one place.
+//!
+//! ```
+//! use boxdd::{Camera2D, Vec2};
+//! let mut camera = Camera2D::new([0.0, 0.0], 30.0, [800.0, 600.0]);
+//! let screen = camera.world_to_screen([1.0, 1.0]);
+//! assert_eq!(screen, Vec2::new(430.0, 270.0));
+//! assert_eq!(camera.screen_to_world(screen), Vec2::new(1.0, 1.0));
+//!
+//! // Zoom in around the cursor: the world point under it stays put.
+//! camera.zoom_at([600.0, 100.0], 2.0);
+//! # #[cfg(feature = "debug-draw")]
+//! let _opts = boxdd::DebugDrawOptions::default().culled_to(&camera);
+//! ```
+use crate::error::{ApiError, ApiResult};
+use crate::query::Aabb;
+use crate::types::Vec2;
+
+/// World-to-screen mapping for a viewport.
+///
+/// `center` is the world point shown at the middle of the viewport. With `flip_y` (the default)
+/// world +y points up while screen +y points down, which matches most windowing and UI libraries.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Camera2D {
+    /// World point at the middle of the viewport.
+    pub center: Vec2,
+    /// Zoom: screen pixels per world meter.
+    pub pixels_per_meter: f32,
+    /// Flip the y axis so world +y is screen up.
+    pub flip_y: bool,
+    /// Viewport size in pixels.
+    pub viewport_size: Vec2,
+}
+
+impl Default for Camera2D {
+    fn default() -> Self {
+        Self {
+            center: Vec2::ZERO,
+            pixels_per_meter: 30.0,
+            flip_y: true,
+            viewport_size: Vec2::new(1280.0, 720.0),
+        }
+    }
+}
+
+impl Camera2D {
+    /// Camera looking at `center` with the given zoom and viewport size, y flipped.
+    pub fn new<C: Into<Vec2>, S: Into<Vec2>>(
+        center: C,
+        pixels_per_meter: f32,
+        viewport_size: S,
+    ) -> Self {
+        Self {
+            center: center.into(),
+            pixels_per_meter,
+            flip_y: true,
+            viewport_size: viewport_size.into(),
+        }
+    }
+
+    pub fn center<V: Into<Vec2>>(mut self, center: V) -> Self {
+        self.center = center.into();
+        self
+    }
+    pub fn pixels_per_meter(mut self, v: f32) -> Self {
+        self.pixels_per_meter = v;
+        self
+    }
+    pub fn flip_y(mut self, flag: bool) -> Self {
+        self.flip_y = flag;
+        self
+    }
+    pub fn viewport_size<V: Into<Vec2>>(mut self, size: V) -> Self {
+        self.viewport_size = size.into();
+        self
+    }
+
+    #[inline]
+    fn y_sign(&self) -> f32 {
+        if self.flip_y { -1.0 } else { 1.0 }
+    }
+
+    /// Convert a world point (meters) to a screen point (pixels).
+    pub fn world_to_screen<V: Into<Vec2>>(&self, p: V) -> Vec2 {
+        let p = p.into();
+        let s = self.pixels_per_meter;
+        Vec2::new(
+            self.viewport_size.x * 0.5 + (p.x - self.center.x) * s,
+            self.viewport_size.y * 0.5 + self.y_sign() * (p.y - self.center.y) * s,
+        )
+    }
+
+    /// Convert a screen point (pixels) to a world point (meters).
+    ///
+    /// Returns non-finite values if `pixels_per_meter` is zero.
+    pub fn screen_to_world<V: Into<Vec2>>(&self, p: V) -> Vec2 {
+        let p = p.into();
+        let inv = 1.0 / self.pixels_per_meter;
+        Vec2::new(
+            self.center.x + (p.x - self.viewport_size.x * 0.5) * inv,
+            self.center.y + self.y_sign() * (p.y - self.viewport_size.y * 0.5) * inv,
+        )
+    }
+
+    /// Convert a world length to pixels.
+    #[inline]
+    pub fn world_to_screen_length(&self, meters: f32) -> f32 {
+        meters * self.pixels_per_meter
+    }
+
+    /// Move the camera by a screen-space drag delta (pixels), e.g. from a mouse drag.
+    pub fn pan_pixels<V: Into<Vec2>>(&mut self, delta: V) {
+        let d = delta.into();
+        let inv = 1.0 / self.pixels_per_meter;
+        self.center.x -= d.x * inv;
+        self.center.y -= self.y_sign() * d.y * inv;
+    }
+
+    /// Multiply the zoom by `factor`, keeping the world point under `screen_point` fixed.
+    ///
+    /// Panics if `factor` is not finite and positive.
+    pub fn zoom_at<V: Into<Vec2>>(&mut self, screen_point: V, factor: f32) {
+        assert!(
+            factor.is_finite() && factor > 0.0,
+            "zoom factor must be finite and > 0, got {factor}"
+        );
+        self.zoom_at_impl(screen_point.into(), factor);
+    }
+
+    /// Recoverable version of [`Camera2D::zoom_at`].
+    pub fn try_zoom_at<V: Into<Vec2>>(&mut self, screen_point: V, factor: f32) -> ApiResult<()> {
+        if !(factor.is_finite() && factor > 0.0) {
+            return Err(ApiError::InvalidArgument);
+        }
+        self.zoom_at_impl(screen_point.into(), factor);
+        Ok(())
+    }
+
+    fn zoom_at_impl(&mut self, screen_point: Vec2, factor: f32) {
+        let anchor = self.screen_to_world(screen_point);
+        self.pixels_per_meter *= factor;
+        let moved = self.screen_to_world(screen_point);
+        self.center.x += anchor.x - moved.x;
+        self.center.y += anchor.y - moved.y;
+    }
+
+    /// World-space rectangle visible through the viewport.
+    pub fn viewport_aabb(&self) -> Aabb {
+        let half = Vec2::new(
+            self.viewport_size.x * 0.5 / self.pixels_per_meter,
+            self.viewport_size.y * 0.5 / self.pixels_per_meter,
+        );
+        Aabb::from_center_half_extents(self.center, half)
+    }
+}
@@ -0,0 +1,273 @@
+//! Kinematic character controller built on Box2D's mover functions.
+//!
+//! `CharacterMover` wraps `World::collide_mover`, [`solve_planes`], `World::cast_mover`, and
+//! [`clip_vector`] into the move-and-slide loop from the upstream character sample:
+//! - collect the planes touching the capsule,
+//! - solve for the translation that best reaches the target without penetrating them,
+//! - sweep the capsule along that translation and advance by the free fraction,
+//! - repeat until the step is shorter than the tolerance.
+//!
+//! The mover is not a body; it only reads the world, so other bodies do not feel it.
+//!
+//! ```no_run
+//! use boxdd::character::{CharacterDef, CharacterMover};
+//! use boxdd::{World, WorldDef, shapes};
+//! let world = World::new(WorldDef::default()).unwrap();
+//! let capsule = shapes::capsule([0.0, -0.5], [0.0, 0.5], 0.3);
+//! let mut mover = CharacterMover::new(CharacterDef::new(capsule).position([0.0, 2.0]));
+//! let dt = 1.0 / 60.0;
+//! let velocity = boxdd::Vec2::new(3.0, -10.0);
+//! let result = mover.move_and_slide(&world, [velocity.x * dt, velocity.y * dt]);
+//! let velocity = result.clip(velocity);
+//! # let _ = velocity;
+//! ```
+
+use crate::error::{ApiError, ApiResult};
+use crate::query::{CollisionPlane, MoverPlaneResult, QueryFilter, clip_vector, solve_planes};
+use crate::shapes::Capsule;
+use crate::types::{ShapeId, Vec2};
+use crate::world::World;
+
+/// Configuration for a [`CharacterMover`].
+#[derive(Copy, Clone, Debug)]
+pub struct CharacterDef {
+    /// Capsule in the mover's local frame.
+    pub capsule: Capsule,
+    /// Initial world position of the capsule frame.
+    pub position: Vec2,
+    /// Filter for the overlap pass that gathers contact planes.
+    pub collide_filter: QueryFilter,
+    /// Filter for the sweep; leave other movers out of it for soft mover-vs-mover collision.
+    pub cast_filter: QueryFilter,
+    /// Upper bound on solve-and-sweep passes per move.
+    pub max_iterations: u32,
+    /// A pass that moves less than this (meters) ends the move.
+    pub tolerance: f32,
+}
+
+impl CharacterDef {
+    /// Create a definition for `capsule` at the origin.
+    pub fn new(capsule: Capsule) -> Self {
+        Self {
+            capsule,
+            position: Vec2::ZERO,
+            collide_filter: QueryFilter::default(),
+            cast_filter: QueryFilter::default(),
+            max_iterations: 5,
+            tolerance: 0.01,
+        }
+    }
+
+    /// Initial world position.
+    pub fn position<V: Into<Vec2>>(mut self, position: V) -> Self {
+        self.position = position.into();
+        self
+    }
+
+    /// Use `filter` for both the plane and sweep passes.
+    pub fn filter(mut self, filter: QueryFilter) -> Self {
+        self.collide_filter = filter;
+        self.cast_filter = filter;
+        self
+    }
+
+    /// Filter for the plane-gathering pass.
+    pub fn collide_filter(mut self, filter: QueryFilter) -> Self {
+        self.collide_filter = filter;
+        self
+    }
+
+    /// Filter for the sweep pass.
+    pub fn cast_filter(mut self, filter: QueryFilter) -> Self {
+        self.cast_filter = filter;
+        self
+    }
+
+    /// Upper bound on solve-and-sweep passes per move.
+    pub fn max_iterations(mut self, n: u32) -> Self {
+        self.max_iterations = n;
+        self
+    }
+
+    /// Minimum pass length that keeps the loop going.
+    pub fn tolerance(mut self, tolerance: f32) -> Self {
+        self.tolerance = tolerance;
+        self
+    }
+
+    /// Validate the definition.
+    pub fn validate(&self) -> ApiResult<()> {
+        let c = &self.capsule;
+        let valid = c.center1.is_valid()
+            && c.center2.is_valid()
+            && c.radius.is_finite()
+            && c.radius > 0.0
+            && self.position.is_valid()
+            && self.max_iterations > 0
+            && self.tolerance.is_finite()
+            && self.tolerance >= 0.0;
+        if !valid {
+            return Err(ApiError::InvalidArgument);
+        }
+        Ok(())
+    }
+}
+
+/// Outcome of [`CharacterMover::move_and_slide`].
+#[derive(Clone, Debug)]
+pub struct MoveResult {
+    /// Capsule frame position after the move.
+    pub position: Vec2,
+    /// Distance actually traveled this move.
+    pub translation: Vec2,
+    /// Planes touching the capsule on the last pass, with the shapes that produced them.
+    pub contacts: Vec<MoverPlaneResult>,
+    /// Solver planes from the last pass, ready for [`clip_vector`].
+    pub planes: Vec<CollisionPlane>,
+    /// Solve-and-sweep passes run.
+    pub iterations: u32,
+}
+
+impl MoveResult {
+    /// Remove the components of `velocity` that push into the touched planes.
+    pub fn clip<V: Into<Vec2>>(&self, velocity: V) -> Vec2 {
+        clip_vector(velocity, &self.planes)
+    }
+
+    /// Whether any touched plane faces up within `max_slope_cos` (e.g. `0.7` for ~45 degrees).
+    pub fn on_ground(&self, up: Vec2, max_slope_cos: f32) -> bool {
+        self.planes
+            .iter()
+            .any(|p| p.plane.normal.dot(up) >= max_slope_cos)
+    }
+
+    /// Shapes touched on the last pass.
+    pub fn touched_shapes(&self) -> impl Iterator<Item = ShapeId> + '_ {
+        self.contacts.iter().map(|c| c.shape_id)
+    }
+}
+
+/// Capsule character moved with move-and-slide against the world.
+#[derive(Clone, Debug)]
+pub struct CharacterMover {
+    def: CharacterDef,
+    position: Vec2,
+    contacts: Vec<MoverPlaneResult>,
+}
+
+impl CharacterMover {
+    /// Create a mover. Panics if `def` is invalid.
+    pub fn new(def: CharacterDef) -> Self {
+        assert!(def.validate().is_ok(), "invalid CharacterDef: {def:?}");
+        Self::new_unchecked(def)
+    }
+
+    /// Recoverable version of [`Self::new`].
+    pub fn try_new(def: CharacterDef) -> ApiResult<Self> {
+        def.validate()?;
+        Ok(Self::new_unchecked(def))
+    }
+
+    fn new_unchecked(def: CharacterDef) -> Self {
+        Self {
+            position: def.position,
+            def,
+            contacts: Vec::new(),
+        }
+    }
+
+    pub fn def(&self) -> &CharacterDef {
+        &self.def
+    }
+
+    pub fn position(&self) -> Vec2 {
+        self.position
+    }
+
+    /// Teleport the mover without collision.
+    pub fn set_position<V: Into<Vec2>>(&mut self, position: V) {
+        self.position = position.into();
+    }
+
+    /// The capsule in world space at the current position.
+    pub fn world_capsule(&self) -> Capsule {
+        Capsule {
+            center1: self.position.plus(self.def.capsule.center1),
+            center2: self.position.plus(self.def.capsule.center2),
+            radius: self.def.capsule.radius,
+        }
+    }
+
+    /// Move by `translation`, sliding along whatever the capsule touches.
+    ///
+    /// Panics if `translation` is not finite or if called inside a Box2D callback.
+    pub fn move_and_slide<V: Into<Vec2>>(&mut self, world: &World, translation: V) -> MoveResult {
+        crate::core::callback_state::assert_not_in_callback();
+        let translation = translation.into();
+        assert!(
+            translation.is_valid(),
+            "translation must be finite, got {translation:?}"
+        );
+        self.move_and_slide_impl(world, translation)
+    }
+
+    /// Recoverable version of [`Self::move_and_slide`].
+    pub fn try_move_and_slide<V: Into<Vec2>>(
+        &mut self,
+        world: &World,
+        translation: V,
+    ) -> ApiResult<MoveResult> {
+        crate::core::callback_state::check_not_in_callback()?;
+        let translation = translation.into();
+        if !translation.is_valid() {
+            return Err(ApiError::InvalidArgument);
+        }
+        Ok(self.move_and_slide_impl(world, translation))
+    }
+
+    fn move_and_slide_impl(&mut self, world: &World, translation: Vec2) -> MoveResult {
+        let start = self.position;
+        let target = start.plus(translation);
+        let tolerance_sq = self.def.tolerance * self.def.tolerance;
+        let mut planes = Vec::new();
+        let mut iterations = 0;
+        for _ in 0..self.def.max_iterations {
+            iterations += 1;
+            let capsule = self.world_capsule();
+            world.collide_mover_into(
+                capsule.center1,
+                capsule.center2,
+                capsule.radius,
+                self.def.collide_filter,
+                &mut self.contacts,
+            );
+            self.contacts.retain(|c| c.hit);
+            planes.clear();
+            planes.extend(
+                self.contacts
+                    .iter()
+                    .filter_map(|c| c.into_rigid_collision_plane()),
+            );
+            let solved = solve_planes(target.minus(self.position), &mut planes);
+            let fraction = world.cast_mover(
+                capsule.center1,
+                capsule.center2,
+                capsule.radius,
+                solved.translation,
+                self.def.cast_filter,
+            );
+            let delta = solved.translation.scaled(fraction);
+            self.position = self.position.plus(delta);
+            if delta.length_squared() < tolerance_sq {
+                break;
+            }
+        }
+        MoveResult {
+            position: self.position,
+            translation: self.position.minus(start),
+            contacts: self.contacts.clone(),
+            planes,
+            iterations,
+        }
+    }
+}
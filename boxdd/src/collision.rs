@@ -9,7 +9,7 @@ use crate::{
     core::math::{Rot, Transform},
     error::{ApiError, ApiResult},
     query::Aabb,
-    shapes::{Capsule, ChainSegment, Circle, Polygon, Segment},
+    shapes::{Capsule, ChainSegment, Circle, Geometry, Polygon, Segment},
     types::{Manifold, Vec2},
 };
 use boxdd_sys::ffi;
@@ -1500,6 +1500,167 @@ pub fn try_collide_chain_segment_and_polygon(
     }))
 }
 
+/// Validate any [`Geometry`] variant.
+fn check_geometry_valid(geometry: Geometry) -> ApiResult<()> {
+    match geometry {
+        Geometry::Circle(g) => g.validate(),
+        Geometry::Segment(g) => g.validate(),
+        Geometry::Capsule(g) => g.validate(),
+        Geometry::Polygon(g) => g.validate(),
+    }
+}
+
+impl ShapeProxy {
+    /// Proxy covering `geometry` in its local space, as Box2D builds for a shape.
+    pub fn from_geometry(geometry: Geometry) -> Self {
+        assert_collision_input_valid("geometry", check_geometry_valid(geometry).is_ok());
+        Self::from_geometry_unchecked(geometry)
+    }
+
+    pub fn try_from_geometry(geometry: Geometry) -> ApiResult<Self> {
+        check_geometry_valid(geometry)?;
+        Ok(Self::from_geometry_unchecked(geometry))
+    }
+
+    fn from_geometry_unchecked(geometry: Geometry) -> Self {
+        let make = |points: &[ffi::b2Vec2], radius: f32| unsafe {
+            ffi::b2MakeProxy(points.as_ptr(), points.len() as i32, radius)
+        };
+        let raw = match geometry {
+            Geometry::Circle(g) => {
+                let c = g.into_raw();
+                make(&[c.center], c.radius)
+            }
+            Geometry::Segment(g) => {
+                let s = g.into_raw();
+                make(&[s.point1, s.point2], 0.0)
+            }
+            Geometry::Capsule(g) => {
+                let c = g.into_raw();
+                make(&[c.center1, c.center2], c.radius)
+            }
+            Geometry::Polygon(g) => {
+                let p = g.into_raw();
+                make(&p.vertices[..p.count as usize], p.radius)
+            }
+        };
+        Self { raw }
+    }
+}
+
+/// Flip a manifold computed with the shapes swapped back to `A`/`B` order.
+fn flip_manifold(mut manifold: Manifold) -> Manifold {
+    manifold.normal = Vec2::new(-manifold.normal.x, -manifold.normal.y);
+    for point in &mut manifold.contact_points {
+        core::mem::swap(&mut point.anchor_a, &mut point.anchor_b);
+    }
+    manifold
+}
+
+/// Contact manifold between any two solid geometries, dispatching to the `collide_*` pair
+/// functions and flipping the result when Box2D only implements the reverse order.
+///
+/// The normal points from `a` to `b`. Segment against segment never collides in Box2D and
+/// returns an empty manifold.
+///
+/// ```no_run
+/// use boxdd::{Transform, collision, shapes};
+/// let ball = shapes::circle([0.0, 0.0], 0.5);
+/// let floor = shapes::box_polygon(5.0, 0.5);
+/// let m = collision::collide(
+///     ball.into(),
+///     Transform::from_pos_angle([0.0, 0.9], 0.0),
+///     floor.into(),
+///     Transform::IDENTITY,
+/// );
+/// assert!(m.point_count > 0);
+/// ```
+pub fn collide(
+    a: Geometry,
+    transform_a: Transform,
+    b: Geometry,
+    transform_b: Transform,
+) -> Manifold {
+    assert_collision_input_valid("geometry a", check_geometry_valid(a).is_ok());
+    assert_collision_input_valid(
+        "transform_a",
+        check_collision_transform_valid(transform_a).is_ok(),
+    );
+    assert_collision_input_valid("geometry b", check_geometry_valid(b).is_ok());
+    assert_collision_input_valid(
+        "transform_b",
+        check_collision_transform_valid(transform_b).is_ok(),
+    );
+    collide_impl(a, transform_a, b, transform_b)
+}
+
+/// [`collide`] with recoverable validation.
+pub fn try_collide(
+    a: Geometry,
+    transform_a: Transform,
+    b: Geometry,
+    transform_b: Transform,
+) -> ApiResult<Manifold> {
+    check_geometry_valid(a)?;
+    check_collision_transform_valid(transform_a)?;
+    check_geometry_valid(b)?;
+    check_collision_transform_valid(transform_b)?;
+    Ok(collide_impl(a, transform_a, b, transform_b))
+}
+
+fn collide_impl(a: Geometry, xa: Transform, b: Geometry, xb: Transform) -> Manifold {
+    use Geometry as G;
+    match (a, b) {
+        (G::Circle(a), G::Circle(b)) => collide_circles(a, xa, b, xb),
+        (G::Capsule(a), G::Circle(b)) => collide_capsule_and_circle(a, xa, b, xb),
+        (G::Segment(a), G::Circle(b)) => collide_segment_and_circle(a, xa, b, xb),
+        (G::Polygon(a), G::Circle(b)) => collide_polygon_and_circle(a, xa, b, xb),
+        (G::Capsule(a), G::Capsule(b)) => collide_capsules(a, xa, b, xb),
+        (G::Segment(a), G::Capsule(b)) => collide_segment_and_capsule(a, xa, b, xb),
+        (G::Polygon(a), G::Capsule(b)) => collide_polygon_and_capsule(a, xa, b, xb),
+        (G::Polygon(a), G::Polygon(b)) => collide_polygons(a, xa, b, xb),
+        (G::Segment(a), G::Polygon(b)) => collide_segment_and_polygon(a, xa, b, xb),
+        (G::Segment(_), G::Segment(_)) => Manifold::default(),
+        (a, b) => flip_manifold(collide_impl(b, xb, a, xa)),
+    }
+}
+
+/// Closest points between any two solid geometries, including their radii.
+///
+/// A `distance` of zero means the shapes overlap.
+pub fn geometry_distance(
+    a: Geometry,
+    transform_a: Transform,
+    b: Geometry,
+    transform_b: Transform,
+) -> DistanceOutput {
+    let input = DistanceInput::new(
+        ShapeProxy::from_geometry(a),
+        ShapeProxy::from_geometry(b),
+        transform_a,
+        transform_b,
+    )
+    .with_radii(true);
+    shape_distance(input, &mut SimplexCache::new())
+}
+
+/// [`geometry_distance`] with recoverable validation.
+pub fn try_geometry_distance(
+    a: Geometry,
+    transform_a: Transform,
+    b: Geometry,
+    transform_b: Transform,
+) -> ApiResult<DistanceOutput> {
+    let input = DistanceInput::new(
+        ShapeProxy::try_from_geometry(a)?,
+        ShapeProxy::try_from_geometry(b)?,
+        transform_a,
+        transform_b,
+    )
+    .with_radii(true);
+    try_shape_distance(input, &mut SimplexCache::new())
+}
+
 impl Aabb {
     /// Check whether this AABB is valid for Box2D queries.
     #[inline]
@@ -0,0 +1,56 @@
+//! Packed RGB colors shared by shape materials and debug draw.
+
+use boxdd_sys::ffi;
+
+/// Packed Box2D debug-draw RGB color (`0xRRGGBB`).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(transparent)]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct HexColor(u32);
+
+impl HexColor {
+    pub const BLACK: Self = Self::from_rgb_u32(0x000000);
+    pub const WHITE: Self = Self::from_rgb_u32(0xFFFFFF);
+    pub const RED: Self = Self::from_rgb_u32(0xFF0000);
+    pub const GREEN: Self = Self::from_rgb_u32(0x00FF00);
+    pub const BLUE: Self = Self::from_rgb_u32(0x0000FF);
+    pub const BOX2D_RED: Self = Self::from_rgb_u32(0xDC3132);
+    pub const BOX2D_BLUE: Self = Self::from_rgb_u32(0x30AEBF);
+    pub const BOX2D_GREEN: Self = Self::from_rgb_u32(0x8CC924);
+    pub const BOX2D_YELLOW: Self = Self::from_rgb_u32(0xFFEE8C);
+
+    #[inline]
+    pub const fn from_rgb(red: u8, green: u8, blue: u8) -> Self {
+        Self(((red as u32) << 16) | ((green as u32) << 8) | blue as u32)
+    }
+
+    #[inline]
+    pub const fn from_rgb_u32(rgb: u32) -> Self {
+        Self(rgb & 0x00ff_ffff)
+    }
+
+    #[inline]
+    pub const fn from_raw(raw: ffi::b2HexColor) -> Self {
+        Self::from_rgb_u32(raw)
+    }
+
+    #[inline]
+    pub const fn rgb_u32(self) -> u32 {
+        self.0
+    }
+
+    #[inline]
+    pub const fn into_raw(self) -> ffi::b2HexColor {
+        self.0
+    }
+
+    #[inline]
+    pub const fn with_alpha(self, alpha: u8) -> u32 {
+        ((alpha as u32) << 24) | self.0
+    }
+}
+
+const _: () = {
+    assert!(core::mem::size_of::<HexColor>() == core::mem::size_of::<ffi::b2HexColor>());
+    assert!(core::mem::align_of::<HexColor>() == core::mem::align_of::<ffi::b2HexColor>());
+};
@@ -0,0 +1,824 @@
+//! Ready-made compound objects from the upstream Box2D samples.
+//!
+//! The C samples reuse a small canon of multi-body contraptions (a plank bridge, a ring-shaped
+//! donut, the two-wheeled doohickey, a car, and a humanoid ragdoll). These constructors build the
+//! same objects with the same proportions, parameterized by position and scale, so scenes, tests,
+//! and benchmarks can share them without copying example code.
+//!
+//! Each constructor panics on invalid parameters; the `try_*` variants return `ApiError` instead.
+//!
+//! ```no_run
+//! use boxdd::{compound, BodyBuilder, World, WorldDef};
+//! let mut world = World::new(WorldDef::default()).unwrap();
+//! let ground = world.create_body_id(BodyBuilder::new().build());
+//! let bridge = compound::bridge(&mut world, ground, &compound::BridgeDef::default());
+//! let car = compound::car(&mut world, &compound::CarDef::new([0.0, 4.0], 1.0));
+//! let _ = (bridge.planks.len(), car.chassis);
+//! ```
+use crate::body::{BodyBuilder, BodyType};
+use crate::error::{ApiError, ApiResult};
+use crate::filter::Filter;
+use crate::joints::{
+    JointBaseBuilder, PrismaticJointDef, RevoluteJointDef, WeldJointDef, WheelJointDef,
+};
+use crate::shapes::{self, ShapeDef, SurfaceMaterial};
+use crate::types::{BodyId, JointId, Vec2};
+use crate::world::World;
+
+fn check_scale(scale: f32) -> ApiResult<()> {
+    if crate::is_valid_float(scale) && scale > 0.0 {
+        Ok(())
+    } else {
+        Err(ApiError::InvalidArgument)
+    }
+}
+
+#[track_caller]
+fn assert_scale(scale: f32) {
+    assert!(
+        crate::is_valid_float(scale) && scale > 0.0,
+        "scale must be finite and > 0.0, got {scale}"
+    );
+}
+
+#[track_caller]
+fn assert_position(position: Vec2) {
+    assert!(
+        position.is_valid(),
+        "position must be a valid Box2D vector, got {position:?}"
+    );
+}
+
+fn check_position(position: Vec2) -> ApiResult<()> {
+    if position.is_valid() {
+        Ok(())
+    } else {
+        Err(ApiError::InvalidArgument)
+    }
+}
+
+/// Parameters for [`bridge`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct BridgeDef {
+    /// World point where the first plank is pinned to the ground.
+    pub origin: Vec2,
+    /// Number of planks; the bridge spans `2 * plank_count * plank_half_extents.x`.
+    pub plank_count: usize,
+    /// Half width and half height of each plank.
+    pub plank_half_extents: Vec2,
+    /// Plank density (kg/m²).
+    pub density: f32,
+    /// Joint friction, applied as a zero-speed motor torque limit. Zero disables it.
+    pub friction_torque: f32,
+}
+
+impl Default for BridgeDef {
+    fn default() -> Self {
+        Self {
+            origin: Vec2::new(-20.0, 2.0),
+            plank_count: 40,
+            plank_half_extents: Vec2::new(0.5, 0.125),
+            density: 20.0,
+            friction_torque: 200.0,
+        }
+    }
+}
+
+impl BridgeDef {
+    /// Default planks starting at `origin`; see [`BridgeDef::default`] for the other values.
+    pub fn new<V: Into<Vec2>>(origin: V, plank_count: usize) -> Self {
+        Self {
+            origin: origin.into(),
+            plank_count,
+            ..Self::default()
+        }
+    }
+    /// Set [`Self::plank_half_extents`].
+    pub fn plank_half_extents(mut self, half_width: f32, half_height: f32) -> Self {
+        self.plank_half_extents = Vec2::new(half_width, half_height);
+        self
+    }
+    /// Set [`Self::density`].
+    pub fn density(mut self, density: f32) -> Self {
+        self.density = density;
+        self
+    }
+    /// Set [`Self::friction_torque`].
+    pub fn friction_torque(mut self, torque: f32) -> Self {
+        self.friction_torque = torque;
+        self
+    }
+
+    /// `InvalidArgument` unless the origin is valid, there is at least one plank, the extents
+    /// are positive, and density and friction are finite and non-negative.
+    pub fn validate(&self) -> ApiResult<()> {
+        check_position(self.origin)?;
+        let h = self.plank_half_extents;
+        if self.plank_count == 0
+            || !(crate::is_valid_float(h.x) && h.x > 0.0)
+            || !(crate::is_valid_float(h.y) && h.y > 0.0)
+            || !(crate::is_valid_float(self.density) && self.density >= 0.0)
+            || !(crate::is_valid_float(self.friction_torque) && self.friction_torque >= 0.0)
+        {
+            return Err(ApiError::InvalidArgument);
+        }
+        Ok(())
+    }
+}
+
+/// Bodies and joints created by [`bridge`].
+#[derive(Clone, Debug)]
+pub struct Bridge {
+    /// Planks from left to right.
+    pub planks: Vec<BodyId>,
+    /// Revolute joints from left to right: ground-plank, plank-plank, ..., plank-ground.
+    pub joints: Vec<JointId>,
+}
+
+/// A chain of dynamic planks hinged to each other and pinned to `ground` at both ends.
+///
+/// Panics if `def` fails [`BridgeDef::validate`] or `ground` is invalid.
+pub fn bridge(world: &mut World, ground: BodyId, def: &BridgeDef) -> Bridge {
+    crate::core::callback_state::assert_not_in_callback();
+    crate::core::debug_checks::assert_body_valid(ground);
+    assert!(
+        def.validate().is_ok(),
+        "invalid BridgeDef: counts and sizes must be positive and values finite, got {def:?}"
+    );
+    bridge_impl(world, ground, def)
+}
+
+/// Recoverable version of [`bridge`].
+pub fn try_bridge(world: &mut World, ground: BodyId, def: &BridgeDef) -> ApiResult<Bridge> {
+    crate::core::callback_state::check_not_in_callback()?;
+    crate::core::debug_checks::check_body_valid(ground)?;
+    def.validate()?;
+    Ok(bridge_impl(world, ground, def))
+}
+
+fn bridge_impl(world: &mut World, ground: BodyId, def: &BridgeDef) -> Bridge {
+    let h = def.plank_half_extents;
+    let plank = shapes::box_polygon(h.x, h.y);
+    let sdef = ShapeDef::builder()
+        .density(def.density)
+        .material(SurfaceMaterial::default().with_friction(0.6))
+        .build();
+    let anchor = |i: usize| Vec2::new(def.origin.x + 2.0 * h.x * i as f32, def.origin.y);
+
+    let mut planks = Vec::with_capacity(def.plank_count);
+    for i in 0..def.plank_count {
+        let center = Vec2::new(anchor(i).x + h.x, def.origin.y);
+        let body = world.create_body_id(
+            BodyBuilder::new()
+                .body_type(BodyType::Dynamic)
+                .position(center)
+                .build(),
+        );
+        let _ = world.create_polygon_shape_for(body, &sdef, &plank);
+        planks.push(body);
+    }
+
+    let mut joints = Vec::with_capacity(def.plank_count + 1);
+    for i in 0..=def.plank_count {
+        let a = if i == 0 { ground } else { planks[i - 1] };
+        let b = if i == def.plank_count {
+            ground
+        } else {
+            planks[i]
+        };
+        let p = anchor(i);
+        let rdef = RevoluteJointDef::new(world.joint_base_from_world_points(a, b, p, p))
+            .enable_motor(def.friction_torque > 0.0)
+            .max_motor_torque(def.friction_torque);
+        joints.push(world.create_revolute_joint_id(&rdef));
+    }
+    Bridge { planks, joints }
+}
+
+/// Bodies and joints created by [`donut`].
+#[derive(Clone, Debug)]
+pub struct Donut {
+    /// Capsule segments around the ring, counter-clockwise from +x.
+    pub bodies: Vec<BodyId>,
+    /// Weld joints; joint `i` connects segment `i - 1` (wrapping) to segment `i`.
+    pub joints: Vec<JointId>,
+}
+
+/// Number of capsule segments in a [`donut`], as in the upstream sample.
+pub const DONUT_SIDES: usize = 7;
+
+/// A soft ring of capsules welded end to end, centered at `position`.
+///
+/// Segments share the negative collision group `group_index` (use any non-zero value; the sign
+/// is forced negative) so the ring does not collide with itself. Panics on a non-positive
+/// `scale` or invalid `position`.
+pub fn donut<V: Into<Vec2>>(world: &mut World, position: V, scale: f32, group_index: i32) -> Donut {
+    crate::core::callback_state::assert_not_in_callback();
+    let position = position.into();
+    assert_position(position);
+    assert_scale(scale);
+    donut_impl(world, position, scale, group_index)
+}
+
+/// Recoverable version of [`donut`].
+pub fn try_donut<V: Into<Vec2>>(
+    world: &mut World,
+    position: V,
+    scale: f32,
+    group_index: i32,
+) -> ApiResult<Donut> {
+    crate::core::callback_state::check_not_in_callback()?;
+    let position = position.into();
+    check_position(position)?;
+    check_scale(scale)?;
+    Ok(donut_impl(world, position, scale, group_index))
+}
+
+fn donut_impl(world: &mut World, position: Vec2, scale: f32, group_index: i32) -> Donut {
+    let radius = scale;
+    let delta = core::f32::consts::TAU / DONUT_SIDES as f32;
+    let length = core::f32::consts::TAU * radius / DONUT_SIDES as f32;
+    let capsule = shapes::capsule([0.0, -0.5 * length], [0.0, 0.5 * length], 0.25 * scale);
+    let group = -group_index.saturating_abs().max(1);
+    let sdef = ShapeDef::builder()
+        .material(SurfaceMaterial::default().with_friction(0.3))
+        .filter(Filter::new().group(group))
+        .build();
+
+    let mut bodies = Vec::with_capacity(DONUT_SIDES);
+    for i in 0..DONUT_SIDES {
+        let angle = i as f32 * delta;
+        let body = world.create_body_id(
+            BodyBuilder::new()
+                .body_type(BodyType::Dynamic)
+                .position([
+                    position.x + radius * angle.cos(),
+                    position.y + radius * angle.sin(),
+                ])
+                .angle(angle)
+                .build(),
+        );
+        let _ = world.create_capsule_shape_for(body, &sdef, &capsule);
+        bodies.push(body);
+    }
+
+    let mut joints = Vec::with_capacity(DONUT_SIDES);
+    for i in 0..DONUT_SIDES {
+        let a = bodies[(i + DONUT_SIDES - 1) % DONUT_SIDES];
+        let b = bodies[i];
+        let base = JointBaseBuilder::new()
+            .bodies_by_id(a, b)
+            .local_frames([0.0, 0.5 * length], delta, [0.0, -0.5 * length], 0.0)
+            .build();
+        let wdef = WeldJointDef::new(base)
+            .angular_hertz(5.0)
+            .angular_damping_ratio(0.0);
+        joints.push(world.create_weld_joint_id(&wdef));
+    }
+    Donut { bodies, joints }
+}
+
+/// Bodies and joints created by [`doohickey`].
+#[derive(Clone, Debug)]
+pub struct Doohickey {
+    /// Left and right wheels.
+    pub wheels: [BodyId; 2],
+    /// Left and right bars; `bars[i]` carries `wheels[i]`.
+    pub bars: [BodyId; 2],
+    /// Motorized revolute joints between each wheel and its bar.
+    pub axles: [JointId; 2],
+    /// Sprung prismatic joint between the two bars.
+    pub slider: JointId,
+}
+
+/// Two motorized wheels on telescoping bars, resting on the ground below `position`.
+///
+/// Panics on a non-positive `scale` or invalid `position`.
+pub fn doohickey<V: Into<Vec2>>(world: &mut World, position: V, scale: f32) -> Doohickey {
+    crate::core::callback_state::assert_not_in_callback();
+    let position = position.into();
+    assert_position(position);
+    assert_scale(scale);
+    doohickey_impl(world, position, scale)
+}
+
+/// Recoverable version of [`doohickey`].
+pub fn try_doohickey<V: Into<Vec2>>(
+    world: &mut World,
+    position: V,
+    scale: f32,
+) -> ApiResult<Doohickey> {
+    crate::core::callback_state::check_not_in_callback()?;
+    let position = position.into();
+    check_position(position)?;
+    check_scale(scale)?;
+    Ok(doohickey_impl(world, position, scale))
+}
+
+fn doohickey_impl(world: &mut World, position: Vec2, scale: f32) -> Doohickey {
+    let at = |x: f32, y: f32| Vec2::new(position.x + scale * x, position.y + scale * y);
+    let sdef = ShapeDef::builder()
+        .material(SurfaceMaterial::default().with_rolling_resistance(0.1))
+        .filter(Filter::new().group(-1))
+        .build();
+    let circle = shapes::circle([0.0, 0.0], scale);
+    let bar = shapes::capsule([-3.5 * scale, 0.0], [3.5 * scale, 0.0], 0.15 * scale);
+    let mut dynamic_at = |p: Vec2| {
+        world.create_body_id(
+            BodyBuilder::new()
+                .body_type(BodyType::Dynamic)
+                .position(p)
+                .build(),
+        )
+    };
+    let wheel_pos = [at(-5.0, 3.0), at(5.0, 3.0)];
+    let wheels = [dynamic_at(wheel_pos[0]), dynamic_at(wheel_pos[1])];
+    let bars = [dynamic_at(at(-1.5, 3.0)), dynamic_at(at(1.5, 3.0))];
+    for &w in &wheels {
+        let _ = world.create_circle_shape_for(w, &sdef, &circle);
+    }
+    for &b in &bars {
+        let _ = world.create_capsule_shape_for(b, &sdef, &bar);
+    }
+
+    let axle = |world: &mut World, i: usize| {
+        let p = wheel_pos[i];
+        let rdef =
+            RevoluteJointDef::new(world.joint_base_from_world_points(wheels[i], bars[i], p, p))
+                .enable_motor(true)
+                .max_motor_torque(2.0 * scale);
+        world.create_revolute_joint_id(&rdef)
+    };
+    let axles = [axle(world, 0), axle(world, 1)];
+
+    let base = world.joint_base_from_world_with_axis(
+        bars[0],
+        bars[1],
+        at(0.5, 3.0),
+        at(-0.5, 3.0),
+        [1.0, 0.0],
+    );
+    let pdef = PrismaticJointDef::new(base)
+        .enable_limit(true)
+        .lower_translation(-2.0 * scale)
+        .upper_translation(2.0 * scale)
+        .enable_motor(true)
+        .max_motor_force(2.0 * scale)
+        .enable_spring(true)
+        .hertz(1.0)
+        .damping_ratio(0.5);
+    let slider = world.create_prismatic_joint_id(&pdef);
+
+    Doohickey {
+        wheels,
+        bars,
+        axles,
+        slider,
+    }
+}
+
+/// Parameters for [`car`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct CarDef {
+    /// World position of the chassis origin.
+    pub position: Vec2,
+    /// Size multiplier; `1.0` matches the upstream sample (about 2.5 m long).
+    pub scale: f32,
+    /// Suspension spring stiffness of both wheel joints. Zero makes the suspension limp.
+    pub hertz: f32,
+    /// Suspension damping ratio of both wheel joints.
+    pub damping_ratio: f32,
+    /// Motor torque cap of both wheel joints (N·m).
+    pub torque: f32,
+}
+
+impl Default for CarDef {
+    fn default() -> Self {
+        Self {
+            position: Vec2::new(0.0, 0.0),
+            scale: 1.0,
+            hertz: 5.0,
+            damping_ratio: 0.7,
+            torque: 2.5,
+        }
+    }
+}
+
+impl CarDef {
+    /// Default tuning at `position` and `scale`; see [`CarDef::default`] for the other values.
+    pub fn new<V: Into<Vec2>>(position: V, scale: f32) -> Self {
+        Self {
+            position: position.into(),
+            scale,
+            ..Self::default()
+        }
+    }
+    /// Set [`Self::hertz`] and [`Self::damping_ratio`].
+    pub fn suspension(mut self, hertz: f32, damping_ratio: f32) -> Self {
+        self.hertz = hertz;
+        self.damping_ratio = damping_ratio;
+        self
+    }
+    /// Set [`Self::torque`].
+    pub fn torque(mut self, torque: f32) -> Self {
+        self.torque = torque;
+        self
+    }
+
+    /// `InvalidArgument` unless the position is valid, the scale is positive, and the tuning
+    /// values are finite and non-negative.
+    pub fn validate(&self) -> ApiResult<()> {
+        check_position(self.position)?;
+        check_scale(self.scale)?;
+        if ![self.hertz, self.damping_ratio, self.torque]
+            .iter()
+            .all(|&v| crate::is_valid_float(v) && v >= 0.0)
+        {
+            return Err(ApiError::InvalidArgument);
+        }
+        Ok(())
+    }
+}
+
+/// Bodies and joints created by [`car`].
+#[derive(Clone, Debug)]
+pub struct Car {
+    /// The car body; its origin is [`CarDef::position`].
+    pub chassis: BodyId,
+    /// Wheel behind the chassis origin (toward -x).
+    pub rear_wheel: BodyId,
+    /// Wheel ahead of the chassis origin (toward +x).
+    pub front_wheel: BodyId,
+    /// Wheel joint for the rear wheel; drive the car with its motor speed.
+    pub rear_axle: JointId,
+    /// Wheel joint for the front wheel.
+    pub front_axle: JointId,
+}
+
+/// A car with sprung, motorized wheel joints, with its chassis origin at `def.position`.
+///
+/// Motors start at zero speed, which acts as a brake; set the axle motor speed to drive. Panics
+/// if `def` fails [`CarDef::validate`].
+pub fn car(world: &mut World, def: &CarDef) -> Car {
+    crate::core::callback_state::assert_not_in_callback();
+    assert!(
+        def.validate().is_ok(),
+        "invalid CarDef: scale must be > 0.0 and other values finite and >= 0.0, got {def:?}"
+    );
+    car_impl(world, def)
+}
+
+/// Recoverable version of [`car`].
+pub fn try_car(world: &mut World, def: &CarDef) -> ApiResult<Car> {
+    crate::core::callback_state::check_not_in_callback()?;
+    def.validate()?;
+    Ok(car_impl(world, def))
+}
+
+fn car_impl(world: &mut World, def: &CarDef) -> Car {
+    let (position, scale) = (def.position, def.scale);
+    let at = |x: f32, y: f32| Vec2::new(position.x + scale * x, position.y + scale * y);
+    let outline = [
+        [-1.5, -0.5],
+        [1.5, -0.5],
+        [1.5, 0.0],
+        [0.0, 0.9],
+        [-1.15, 0.9],
+        [-1.5, 0.2],
+    ]
+    .map(|[x, y]| [0.85 * scale * x, 0.85 * scale * y]);
+    let hull = shapes::polygon_from_points(outline, 0.15 * scale)
+        .expect("car chassis outline is a valid hull");
+    let chassis_def = ShapeDef::builder()
+        .density(1.0 / scale)
+        .material(SurfaceMaterial::default().with_friction(0.2))
+        .build();
+    let wheel_def = ShapeDef::builder()
+        .density(2.0 / scale)
+        .material(
+            SurfaceMaterial::default()
+                .with_friction(1.5)
+                .with_rolling_resistance(0.1),
+        )
+        .build();
+    let wheel = shapes::circle([0.0, 0.0], 0.4 * scale);
+
+    let dynamic_at = |world: &mut World, p: Vec2| {
+        world.create_body_id(
+            BodyBuilder::new()
+                .body_type(BodyType::Dynamic)
+                .position(p)
+                .build(),
+        )
+    };
+    let chassis = dynamic_at(world, position);
+    let _ = world.create_polygon_shape_for(chassis, &chassis_def, &hull);
+    let rear_pos = at(-1.0, -0.35);
+    let front_pos = at(1.0, -0.4);
+    let rear_wheel = dynamic_at(world, rear_pos);
+    let _ = world.create_circle_shape_for(rear_wheel, &wheel_def, &wheel);
+    let front_wheel = dynamic_at(world, front_pos);
+    let _ = world.create_circle_shape_for(front_wheel, &wheel_def, &wheel);
+
+    let axle = |world: &mut World, wheel: BodyId, p: Vec2| {
+        let base = world.joint_base_from_world_with_axis(chassis, wheel, p, p, [0.0, 1.0]);
+        let wdef = WheelJointDef::new(base)
+            .enable_spring(true)
+            .hertz(def.hertz)
+            .damping_ratio(def.damping_ratio)
+            .enable_limit(true)
+            .lower_translation(-0.25 * scale)
+            .upper_translation(0.25 * scale)
+            .enable_motor(true)
+            .max_motor_torque(def.torque)
+            .motor_speed(0.0);
+        world.create_wheel_joint_id(&wdef)
+    };
+    let rear_axle = axle(world, rear_wheel, rear_pos);
+    let front_axle = axle(world, front_wheel, front_pos);
+
+    Car {
+        chassis,
+        rear_wheel,
+        front_wheel,
+        rear_axle,
+        front_axle,
+    }
+}
+
+/// Parameters for [`ragdoll`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RagdollDef {
+    /// World point between the feet; the ragdoll stands `1.55 * scale` tall above it.
+    pub position: Vec2,
+    /// Size multiplier; `1.0` is roughly a 1.5 m tall human.
+    pub scale: f32,
+    /// Bone density (kg/m²).
+    pub density: f32,
+    /// Collision group shared by every bone so the ragdoll does not collide with itself. Use
+    /// any non-zero value; the sign is forced negative.
+    pub group_index: i32,
+    /// Joint friction at the hip, applied as a zero-speed motor torque limit. Other joints use a
+    /// fraction of it (down to a tenth at the elbows). Zero disables it.
+    pub friction_torque: f32,
+    /// Stiffness of the spring pulling each joint toward its rest angle. Zero disables it.
+    pub joint_hertz: f32,
+    /// Damping ratio of the joint springs.
+    pub joint_damping_ratio: f32,
+}
+
+impl Default for RagdollDef {
+    fn default() -> Self {
+        Self {
+            position: Vec2::new(0.0, 0.0),
+            scale: 1.0,
+            density: 1.0,
+            group_index: 1,
+            friction_torque: 0.03,
+            joint_hertz: 5.0,
+            joint_damping_ratio: 0.5,
+        }
+    }
+}
+
+impl RagdollDef {
+    /// Default tuning at `position` and `scale`; see [`RagdollDef::default`] for the other
+    /// values.
+    pub fn new<V: Into<Vec2>>(position: V, scale: f32) -> Self {
+        Self {
+            position: position.into(),
+            scale,
+            ..Self::default()
+        }
+    }
+    /// Set [`Self::density`].
+    pub fn density(mut self, density: f32) -> Self {
+        self.density = density;
+        self
+    }
+    /// Set [`Self::group_index`].
+    pub fn group_index(mut self, group_index: i32) -> Self {
+        self.group_index = group_index;
+        self
+    }
+    /// Set [`Self::friction_torque`].
+    pub fn friction_torque(mut self, torque: f32) -> Self {
+        self.friction_torque = torque;
+        self
+    }
+    /// Set [`Self::joint_hertz`] and [`Self::joint_damping_ratio`].
+    pub fn joint_spring(mut self, hertz: f32, damping_ratio: f32) -> Self {
+        self.joint_hertz = hertz;
+        self.joint_damping_ratio = damping_ratio;
+        self
+    }
+
+    /// `InvalidArgument` unless the position is valid, the scale is positive, and the other
+    /// values are finite and non-negative.
+    pub fn validate(&self) -> ApiResult<()> {
+        check_position(self.position)?;
+        check_scale(self.scale)?;
+        if ![
+            self.density,
+            self.friction_torque,
+            self.joint_hertz,
+            self.joint_damping_ratio,
+        ]
+        .iter()
+        .all(|&v| crate::is_valid_float(v) && v >= 0.0)
+        {
+            return Err(ApiError::InvalidArgument);
+        }
+        Ok(())
+    }
+}
+
+/// Bodies and joints created by [`ragdoll`]. Each joint connects a bone to the bone it hangs
+/// from, starting at the hip.
+#[derive(Clone, Debug)]
+pub struct Ragdoll {
+    pub hip: BodyId,
+    pub torso: BodyId,
+    pub head: BodyId,
+    pub upper_left_leg: BodyId,
+    pub lower_left_leg: BodyId,
+    pub upper_right_leg: BodyId,
+    pub lower_right_leg: BodyId,
+    pub upper_left_arm: BodyId,
+    pub lower_left_arm: BodyId,
+    pub upper_right_arm: BodyId,
+    pub lower_right_arm: BodyId,
+    pub waist: JointId,
+    pub neck: JointId,
+    pub left_hip: JointId,
+    pub left_knee: JointId,
+    pub right_hip: JointId,
+    pub right_knee: JointId,
+    pub left_shoulder: JointId,
+    pub left_elbow: JointId,
+    pub right_shoulder: JointId,
+    pub right_elbow: JointId,
+}
+
+impl Ragdoll {
+    /// Every bone, hip first, in field order.
+    pub fn bodies(&self) -> [BodyId; 11] {
+        [
+            self.hip,
+            self.torso,
+            self.head,
+            self.upper_left_leg,
+            self.lower_left_leg,
+            self.upper_right_leg,
+            self.lower_right_leg,
+            self.upper_left_arm,
+            self.lower_left_arm,
+            self.upper_right_arm,
+            self.lower_right_arm,
+        ]
+    }
+
+    /// Every joint, in field order.
+    pub fn joints(&self) -> [JointId; 10] {
+        [
+            self.waist,
+            self.neck,
+            self.left_hip,
+            self.left_knee,
+            self.right_hip,
+            self.right_knee,
+            self.left_shoulder,
+            self.left_elbow,
+            self.right_shoulder,
+            self.right_elbow,
+        ]
+    }
+}
+
+/// A humanoid ragdoll of capsules and limited revolute joints, standing on `def.position`.
+///
+/// Proportions, joint limits, and per-joint friction follow the upstream `human` sample. The
+/// ragdoll is built facing +x with the arms at its sides; left and right limbs overlap.
+/// Panics if `def` fails [`RagdollDef::validate`].
+pub fn ragdoll(world: &mut World, def: &RagdollDef) -> Ragdoll {
+    crate::core::callback_state::assert_not_in_callback();
+    assert!(
+        def.validate().is_ok(),
+        "invalid RagdollDef: scale must be > 0.0 and other values finite and >= 0.0, got {def:?}"
+    );
+    ragdoll_impl(world, def)
+}
+
+/// Recoverable version of [`ragdoll`].
+pub fn try_ragdoll(world: &mut World, def: &RagdollDef) -> ApiResult<Ragdoll> {
+    crate::core::callback_state::check_not_in_callback()?;
+    def.validate()?;
+    Ok(ragdoll_impl(world, def))
+}
+
+fn ragdoll_impl(world: &mut World, def: &RagdollDef) -> Ragdoll {
+    use core::f32::consts::PI;
+
+    let s = def.scale;
+    let at = |y: f32| Vec2::new(def.position.x, def.position.y + s * y);
+    let sdef = ShapeDef::builder()
+        .density(def.density)
+        .material(SurfaceMaterial::default().with_friction(0.2))
+        .filter(Filter::new().group(-def.group_index.saturating_abs().max(1)))
+        .build();
+    // Center height, capsule half-length below/above the center, and radius.
+    let bone = |world: &mut World, y: f32, below: f32, above: f32, radius: f32| {
+        let body = world.create_body_id(
+            BodyBuilder::new()
+                .body_type(BodyType::Dynamic)
+                .position(at(y))
+                .build(),
+        );
+        let capsule = shapes::capsule([0.0, -s * below], [0.0, s * above], s * radius);
+        let _ = world.create_capsule_shape_for(body, &sdef, &capsule);
+        body
+    };
+    let hip = bone(world, 0.95, 0.02, 0.02, 0.095);
+    let torso = bone(world, 1.2, 0.135, 0.135, 0.09);
+    let head = bone(world, 1.475, 0.038, 0.039, 0.075);
+    let upper_left_leg = bone(world, 0.775, 0.125, 0.125, 0.06);
+    let lower_left_leg = bone(world, 0.475, 0.155, 0.125, 0.045);
+    let upper_right_leg = bone(world, 0.775, 0.125, 0.125, 0.06);
+    let lower_right_leg = bone(world, 0.475, 0.155, 0.125, 0.045);
+    let upper_left_arm = bone(world, 1.225, 0.125, 0.125, 0.035);
+    let lower_left_arm = bone(world, 0.975, 0.125, 0.125, 0.03);
+    let upper_right_arm = bone(world, 1.225, 0.125, 0.125, 0.035);
+    let lower_right_arm = bone(world, 0.975, 0.125, 0.125, 0.03);
+
+    // Pivot height, limits as fractions of pi, and the share of `friction_torque`.
+    let joint = |world: &mut World,
+                 parent: BodyId,
+                 child: BodyId,
+                 y: f32,
+                 lower: f32,
+                 upper: f32,
+                 friction: f32| {
+        let p = at(y);
+        let torque = friction * def.friction_torque;
+        let rdef = RevoluteJointDef::new(world.joint_base_from_world_points(parent, child, p, p))
+            .enable_limit(true)
+            .lower_angle(lower * PI)
+            .upper_angle(upper * PI)
+            .enable_motor(torque > 0.0)
+            .max_motor_torque(torque)
+            .enable_spring(def.joint_hertz > 0.0)
+            .hertz(def.joint_hertz)
+            .damping_ratio(def.joint_damping_ratio);
+        world.create_revolute_joint_id(&rdef)
+    };
+    let waist = joint(world, hip, torso, 1.0, -0.25, 0.0, 0.5);
+    let neck = joint(world, torso, head, 1.4, -0.3, 0.1, 0.25);
+    let left_hip = joint(world, hip, upper_left_leg, 0.9, -0.05, 0.4, 1.0);
+    let left_knee = joint(
+        world,
+        upper_left_leg,
+        lower_left_leg,
+        0.625,
+        -0.5,
+        -0.02,
+        0.5,
+    );
+    let right_hip = joint(world, hip, upper_right_leg, 0.9, -0.05, 0.4, 1.0);
+    let right_knee = joint(
+        world,
+        upper_right_leg,
+        lower_right_leg,
+        0.625,
+        -0.5,
+        -0.02,
+        0.5,
+    );
+    let left_shoulder = joint(world, torso, upper_left_arm, 1.35, -0.1, 0.8, 0.5);
+    let left_elbow = joint(world, upper_left_arm, lower_left_arm, 1.1, -0.2, 0.3, 0.1);
+    let right_shoulder = joint(world, torso, upper_right_arm, 1.35, -0.1, 0.8, 0.5);
+    let right_elbow = joint(world, upper_right_arm, lower_right_arm, 1.1, -0.2, 0.3, 0.1);
+
+    Ragdoll {
+        hip,
+        torso,
+        head,
+        upper_left_leg,
+        lower_left_leg,
+        upper_right_leg,
+        lower_right_leg,
+        upper_left_arm,
+        lower_left_arm,
+        upper_right_arm,
+        lower_right_arm,
+        waist,
+        neck,
+        left_hip,
+        left_knee,
+        right_hip,
+        right_knee,
+        left_shoulder,
+        left_elbow,
+        right_shoulder,
+        right_elbow,
+    }
+}
@@ -1,7 +1,12 @@
 use crate::error::ApiResult;
+use crate::query::{Aabb, QueryFilter};
 use crate::types::{ContactData, ContactId};
+use crate::world::World;
 use boxdd_sys::ffi;
 
+/// Half extent of the box used to visit every shape in a world.
+const WORLD_EXTENT: f32 = 1.0e18;
+
 #[inline]
 fn contact_is_valid_impl(id: ContactId) -> bool {
     unsafe { ffi::b2Contact_IsValid(id.into_raw()) }
@@ -56,6 +61,60 @@ impl ContactId {
     }
 }
 
+impl World {
+    /// Every touching contact in the world, each reported once.
+    ///
+    /// Box2D has no world-wide contact list, so this visits every shape and gathers its contacts;
+    /// prefer [`Shape::contact_data`](crate::Shape::contact_data) or
+    /// [`Body::contact_data`](crate::Body::contact_data) when only a few objects matter.
+    ///
+    /// ```no_run
+    /// use boxdd::{World, WorldDef};
+    /// let mut world = World::new(WorldDef::default()).unwrap();
+    /// world.step(1.0 / 60.0, 4);
+    /// for contact in world.contact_data() {
+    ///     for point in contact.manifold.points() {
+    ///         println!("{:?} separation {}", point.point, point.separation);
+    ///     }
+    /// }
+    /// ```
+    pub fn contact_data(&self) -> Vec<ContactData> {
+        let mut out = Vec::new();
+        self.contact_data_into(&mut out);
+        out
+    }
+
+    /// Reusable-buffer version of [`World::contact_data`].
+    pub fn contact_data_into(&self, out: &mut Vec<ContactData>) {
+        crate::core::callback_state::assert_not_in_callback();
+        self.contact_data_into_impl(out);
+    }
+
+    pub fn try_contact_data(&self) -> ApiResult<Vec<ContactData>> {
+        let mut out = Vec::new();
+        self.try_contact_data_into(&mut out)?;
+        Ok(out)
+    }
+
+    pub fn try_contact_data_into(&self, out: &mut Vec<ContactData>) -> ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        self.contact_data_into_impl(out);
+        Ok(())
+    }
+
+    fn contact_data_into_impl(&self, out: &mut Vec<ContactData>) {
+        out.clear();
+        let everything = Aabb::new([-WORLD_EXTENT, -WORLD_EXTENT], [WORLD_EXTENT, WORLD_EXTENT]);
+        let filter = QueryFilter::default().category(u64::MAX).mask(u64::MAX);
+        let mut scratch = Vec::new();
+        for shape in self.overlap_aabb(everything, filter) {
+            crate::shapes::shape_contact_data_into_impl(shape, &mut scratch);
+            // Both shapes report the contact; keep it on shape A's side only.
+            out.extend(scratch.iter().filter(|c| c.shape_id_a == shape));
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::ApiError;
@@ -0,0 +1,66 @@
+//! Per-body log of forces and impulses applied through the wrapper, behind the `force-tracking`
+//! feature, for `World::applied_forces`.
+use crate::core::world_core::Applied;
+use crate::types::{BodyId, Vec2};
+use crate::world::AppliedForces;
+use std::collections::HashMap;
+
+#[derive(Default)]
+pub(crate) struct AppliedForceLog {
+    /// Accumulating until the next step.
+    pending: HashMap<BodyId, AppliedForces>,
+    /// What the last completed step consumed.
+    last_step: HashMap<BodyId, AppliedForces>,
+}
+
+#[inline]
+fn cross(a: Vec2, b: Vec2) -> f32 {
+    a.x * b.y - a.y * b.x
+}
+
+impl AppliedForceLog {
+    pub(crate) fn record(&mut self, body: BodyId, applied: Applied) {
+        // Off-center forces and impulses also turn the body; fold their moment in like the solver.
+        let moment = |v: Vec2, point: Option<Vec2>| match point {
+            Some(p) => {
+                let c = crate::body::body_world_center_of_mass_impl(body);
+                cross(Vec2::new(p.x - c.x, p.y - c.y), v)
+            }
+            None => 0.0,
+        };
+        let entry = self.pending.entry(body).or_default();
+        entry.calls += 1;
+        match applied {
+            Applied::Force(f, point) => {
+                entry.force = Vec2::new(entry.force.x + f.x, entry.force.y + f.y);
+                entry.torque += moment(f, point);
+            }
+            Applied::Torque(t) => entry.torque += t,
+            Applied::LinearImpulse(i, point) => {
+                entry.linear_impulse =
+                    Vec2::new(entry.linear_impulse.x + i.x, entry.linear_impulse.y + i.y);
+                entry.angular_impulse += moment(i, point);
+            }
+            Applied::AngularImpulse(i) => entry.angular_impulse += i,
+        }
+    }
+
+    pub(crate) fn finish_step(&mut self) {
+        self.last_step = core::mem::take(&mut self.pending);
+    }
+
+    pub(crate) fn last_step(&self, body: BodyId) -> AppliedForces {
+        self.last_step.get(&body).copied().unwrap_or_default()
+    }
+
+    pub(crate) fn last_step_all(&self) -> Vec<(BodyId, AppliedForces)> {
+        let mut all: Vec<_> = self.last_step.iter().map(|(&b, &a)| (b, a)).collect();
+        all.sort_by_key(|&(b, _)| b);
+        all
+    }
+
+    pub(crate) fn remove_body(&mut self, body: BodyId) {
+        self.pending.remove(&body);
+        self.last_step.remove(&body);
+    }
+}
@@ -0,0 +1,123 @@
+//! Field checks shared by the definition validators and the `build_checked()` methods that
+//! call them.
+
+use crate::error::ValidationError;
+use crate::types::Vec2;
+
+pub(crate) type CheckResult = Result<(), ValidationError>;
+
+/// Reports failures against one definition type.
+#[derive(Copy, Clone)]
+pub(crate) struct DefChecks(pub(crate) &'static str);
+
+impl DefChecks {
+    fn fail(self, field: &'static str, message: String) -> CheckResult {
+        Err(ValidationError {
+            def: self.0,
+            field,
+            message,
+        })
+    }
+
+    pub(crate) fn require(
+        self,
+        field: &'static str,
+        ok: bool,
+        message: impl FnOnce() -> String,
+    ) -> CheckResult {
+        if ok {
+            Ok(())
+        } else {
+            self.fail(field, message())
+        }
+    }
+
+    /// The definition still carries the cookie written by Box2D's `b2Default*Def()`.
+    pub(crate) fn initialized(self, ok: bool) -> CheckResult {
+        self.require("internal_value", ok, || {
+            "must come from the default definition, not a zeroed or hand-built value".to_owned()
+        })
+    }
+
+    pub(crate) fn finite(self, field: &'static str, v: f32) -> CheckResult {
+        if crate::is_valid_float(v) {
+            Ok(())
+        } else {
+            self.fail(field, format!("must be finite, got {v}"))
+        }
+    }
+
+    pub(crate) fn non_negative(self, field: &'static str, v: f32) -> CheckResult {
+        if crate::is_valid_float(v) && v >= 0.0 {
+            Ok(())
+        } else {
+            self.fail(field, format!("must be finite and >= 0, got {v}"))
+        }
+    }
+
+    pub(crate) fn positive(self, field: &'static str, v: f32) -> CheckResult {
+        if crate::is_valid_float(v) && v > 0.0 {
+            Ok(())
+        } else {
+            self.fail(field, format!("must be finite and > 0, got {v}"))
+        }
+    }
+
+    pub(crate) fn vec2(self, field: &'static str, v: Vec2) -> CheckResult {
+        if v.is_valid() {
+            Ok(())
+        } else {
+            self.fail(field, format!("must be a finite vector, got {v:?}"))
+        }
+    }
+
+    pub(crate) fn transform(self, field: &'static str, t: crate::Transform) -> CheckResult {
+        if t.is_valid() {
+            Ok(())
+        } else {
+            self.fail(field, format!("must be a valid transform, got {t:?}"))
+        }
+    }
+
+    pub(crate) fn rot(self, field: &'static str, r: crate::Rot) -> CheckResult {
+        if r.is_valid() {
+            Ok(())
+        } else {
+            self.fail(field, format!("must be a normalized rotation, got {r:?}"))
+        }
+    }
+
+    pub(crate) fn at_least_zero(self, field: &'static str, v: i32) -> CheckResult {
+        if v >= 0 {
+            Ok(())
+        } else {
+            self.fail(field, format!("must be >= 0, got {v}"))
+        }
+    }
+
+    /// `lower <= upper`, reported against `lower`.
+    pub(crate) fn ordered(
+        self,
+        lower_field: &'static str,
+        lower: f32,
+        upper_field: &'static str,
+        upper: f32,
+    ) -> CheckResult {
+        if lower <= upper {
+            Ok(())
+        } else {
+            self.fail(
+                lower_field,
+                format!("({lower}) must be <= {upper_field} ({upper})"),
+            )
+        }
+    }
+
+    pub(crate) fn within(self, field: &'static str, v: f32, min: f32, max: f32) -> CheckResult {
+        if v >= min && v <= max {
+            Ok(())
+        } else {
+            self.fail(field, format!("must be in [{min}, {max}], got {v}"))
+        }
+    }
+}
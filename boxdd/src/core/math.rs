@@ -191,6 +191,24 @@ impl Rot {
             y: -s * v.x + c * v.y,
         }
     }
+    /// Advance this rotation by angular velocity `omega` (rad/s) over `dt` seconds.
+    ///
+    /// Same first-order update Box2D's solver applies each substep (`b2IntegrateRotation`): the
+    /// rotation is pushed along its tangent and renormalized, so the result matches the
+    /// simulation rather than `from_radians(angle + omega * dt)`. Returns zero if the update
+    /// degenerates.
+    #[inline]
+    pub fn integrate(self, omega: f32, dt: f32) -> Self {
+        let delta = omega * dt;
+        let c = self.c - delta * self.s;
+        let s = self.s + delta * self.c;
+        let mag = (c * c + s * s).sqrt();
+        let inv_mag = if mag > 0.0 { 1.0 / mag } else { 0.0 };
+        Self {
+            c: c * inv_mag,
+            s: s * inv_mag,
+        }
+    }
 }
 
 // serde support for Rot as angle (radians)
@@ -516,6 +534,44 @@ impl Transform {
         let dy = v.y - self.p.y;
         self.q.inv_rotate_vec(Vec2 { x: dx, y: dy })
     }
+    /// Extrapolate this transform by one solver substep of `dt` seconds.
+    ///
+    /// Moves the origin by `linear_velocity * dt` and integrates the rotation with
+    /// [`Rot::integrate`]. Box2D integrates the center of mass, so this matches the simulation for
+    /// bodies whose local center is at the origin; use [`Transform::step_about`] otherwise. A world
+    /// step of `dt` with `n` substeps corresponds to `n` calls with `dt / n`.
+    #[inline]
+    pub fn step(self, linear_velocity: Vec2, angular_velocity: f32, dt: f32) -> Self {
+        Self {
+            p: Vec2 {
+                x: self.p.x + dt * linear_velocity.x,
+                y: self.p.y + dt * linear_velocity.y,
+            },
+            q: self.q.integrate(angular_velocity, dt),
+        }
+    }
+    /// [`Transform::step`] for a body whose center of mass sits at `local_center`.
+    ///
+    /// `linear_velocity` is the center-of-mass velocity, as returned by the body velocity getters.
+    #[inline]
+    pub fn step_about(
+        self,
+        local_center: Vec2,
+        linear_velocity: Vec2,
+        angular_velocity: f32,
+        dt: f32,
+    ) -> Self {
+        let center = self.transform_point(local_center);
+        let q = self.q.integrate(angular_velocity, dt);
+        let offset = q.rotate_vec(local_center);
+        Self {
+            p: Vec2 {
+                x: center.x + dt * linear_velocity.x - offset.x,
+                y: center.y + dt * linear_velocity.y - offset.y,
+            },
+            q,
+        }
+    }
 }
 
 #[cfg(feature = "bytemuck")]
@@ -0,0 +1,49 @@
+//! Point-versus-world collision shared by `verlet` and `particles`.
+//!
+//! Points are resolved one-way against non-sensor world shapes: a ray cast along the step motion
+//! stops tunnelling through thin shapes, then a closest-point push-out keeps the point `radius`
+//! away from anything it overlaps. Bodies are never moved here.
+use crate::query::{Aabb, QueryFilter};
+use crate::types::{ShapeId, Vec2};
+use crate::world::World;
+
+/// Resolve a point moving from `from` to `to`. Returns the resolved position and the last shape
+/// touched with its contact point, if any. `shapes` is a reusable scratch buffer.
+pub(crate) fn collide_point(
+    world: &World,
+    from: Vec2,
+    to: Vec2,
+    radius: f32,
+    filter: QueryFilter,
+    shapes: &mut Vec<ShapeId>,
+) -> (Vec2, Option<(ShapeId, Vec2)>) {
+    let mut p = to;
+    let mut touched = None;
+
+    let translation = p.minus(from);
+    if translation.length_squared() > 0.0 {
+        let hit = world.cast_ray_closest(from, translation, filter);
+        if hit.hit && !crate::shapes::shape_is_sensor_impl(hit.shape_id) {
+            p = hit.point.plus(hit.normal.scaled(radius));
+            touched = Some((hit.shape_id, hit.point));
+        }
+    }
+
+    if radius > 0.0 {
+        let bounds = Aabb::from_center_half_extents(p, [radius, radius]);
+        world.overlap_aabb_into(bounds, filter, shapes);
+        for &sid in shapes.iter() {
+            if crate::shapes::shape_is_sensor_impl(sid) {
+                continue;
+            }
+            let closest = crate::shapes::shape_closest_point_impl(sid, p);
+            let offset = p.minus(closest);
+            let dist = offset.length();
+            if dist > 0.0 && dist < radius {
+                p = closest.plus(offset.scaled(radius / dist));
+                touched = Some((sid, closest));
+            }
+        }
+    }
+    (p, touched)
+}
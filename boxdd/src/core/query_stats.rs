@@ -0,0 +1,152 @@
+//! Per-world query instrumentation behind `World::enable_query_stats`.
+//!
+//! The state lives in `WorldCore::query_stats`; while a world is not instrumented the cost per
+//! query is one relaxed atomic load.
+use crate::core::world_core::WorldCore;
+use crate::dynamic_tree::TreeStats;
+use crate::world::{QueryBudget, QueryKind, QueryStats, SlowQuery};
+use boxdd_sys::ffi;
+use std::sync::atomic::Ordering;
+
+#[derive(Default)]
+pub(crate) struct QueryInstrumentation {
+    budget: QueryBudget,
+    stats: QueryStats,
+}
+
+fn with_state<R>(core: &WorldCore, f: impl FnOnce(&mut Option<QueryInstrumentation>) -> R) -> R {
+    let mut state = core.query_stats.lock().expect("query_stats mutex poisoned");
+    let r = f(&mut state);
+    core.query_stats_enabled
+        .store(state.is_some(), Ordering::Relaxed);
+    r
+}
+
+pub(crate) fn enable(core: &WorldCore, budget: QueryBudget) {
+    with_state(core, |state| {
+        state
+            .get_or_insert_with(QueryInstrumentation::default)
+            .budget = budget;
+    });
+}
+
+pub(crate) fn disable(core: &WorldCore) {
+    with_state(core, |state| *state = None);
+}
+
+pub(crate) fn is_enabled(core: &WorldCore) -> bool {
+    core.query_stats_enabled.load(Ordering::Relaxed)
+}
+
+pub(crate) fn snapshot(core: &WorldCore, reset: bool) -> Option<QueryStats> {
+    with_state(core, |state| {
+        let entry = state.as_mut()?;
+        Some(if reset {
+            core::mem::take(&mut entry.stats)
+        } else {
+            entry.stats.clone()
+        })
+    })
+}
+
+/// Run a query that does not report tree stats.
+#[inline]
+pub(crate) fn measure<R>(core: &WorldCore, kind: QueryKind, f: impl FnOnce() -> R) -> R {
+    measure_with(core, kind, f, |_| None)
+}
+
+/// Run a query returning Box2D tree stats.
+#[inline]
+pub(crate) fn measure_tree(
+    core: &WorldCore,
+    kind: QueryKind,
+    f: impl FnOnce() -> ffi::b2TreeStats,
+) -> ffi::b2TreeStats {
+    measure_with(core, kind, f, |raw| {
+        Some(TreeStats {
+            node_visits: raw.nodeVisits,
+            leaf_visits: raw.leafVisits,
+        })
+    })
+}
+
+#[inline]
+fn measure_with<R>(
+    core: &WorldCore,
+    kind: QueryKind,
+    f: impl FnOnce() -> R,
+    tree_stats: impl FnOnce(&R) -> Option<TreeStats>,
+) -> R {
+    if !core.query_stats_enabled.load(Ordering::Relaxed) {
+        return f();
+    }
+    let clock = Clock::start();
+    let r = f();
+    let elapsed_ms = clock.elapsed_ms();
+    record(core, kind, tree_stats(&r), elapsed_ms);
+    r
+}
+
+fn record(core: &WorldCore, kind: QueryKind, tree_stats: Option<TreeStats>, elapsed_ms: f32) {
+    let mut state = core.query_stats.lock().expect("query_stats mutex poisoned");
+    let Some(entry) = state.as_mut() else {
+        return;
+    };
+    let stats = &mut entry.stats;
+    match kind {
+        QueryKind::Ray => stats.rays += 1,
+        QueryKind::Overlap => stats.overlaps += 1,
+        QueryKind::Cast => stats.casts += 1,
+    }
+    if let Some(t) = tree_stats {
+        stats.node_visits += t.node_visits.max(0) as u64;
+        stats.leaf_visits += t.leaf_visits.max(0) as u64;
+    }
+    stats.elapsed_ms += elapsed_ms;
+
+    let budget = entry.budget;
+    let over_nodes =
+        matches!((budget.max_node_visits, tree_stats), (Some(max), Some(t)) if t.node_visits > max);
+    let over_time = budget.max_time_ms.is_some_and(|max| elapsed_ms > max);
+    if over_nodes || over_time {
+        if stats.slow_queries.len() < budget.max_recorded {
+            stats.slow_queries.push(SlowQuery {
+                kind,
+                tree_stats,
+                elapsed_ms,
+            });
+        } else {
+            stats.dropped_slow_queries += 1;
+        }
+    }
+}
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+struct Clock(std::time::Instant);
+
+#[cfg(not(all(target_arch = "wasm32", target_os = "unknown")))]
+impl Clock {
+    #[inline]
+    fn start() -> Self {
+        Self(std::time::Instant::now())
+    }
+    #[inline]
+    fn elapsed_ms(&self) -> f32 {
+        self.0.elapsed().as_secs_f32() * 1000.0
+    }
+}
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+struct Clock;
+
+#[cfg(all(target_arch = "wasm32", target_os = "unknown"))]
+impl Clock {
+    #[inline]
+    fn start() -> Self {
+        Self
+    }
+    #[inline]
+    fn elapsed_ms(&self) -> f32 {
+        0.0
+    }
+}
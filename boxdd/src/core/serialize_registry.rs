@@ -191,6 +191,18 @@ impl Registries {
                 .copied()
                 .filter(|&bid| unsafe { ffi::b2Body_IsValid(bid.into_raw()) }),
         );
+        out.sort_unstable();
+    }
+
+    pub(crate) fn chain_ids_into(&self, out: &mut Vec<ChainId>) {
+        out.clear();
+        out.extend(
+            self.chains
+                .iter()
+                .map(|(id, _)| *id)
+                .filter(|&cid| unsafe { ffi::b2Chain_IsValid(cid.into_raw()) }),
+        );
+        out.sort_unstable();
     }
 
     pub(crate) fn chain_records(&self) -> Vec<ChainCreateRecord> {
@@ -210,6 +222,23 @@ impl Registries {
         }));
     }
 
+    /// Drop entries whose ids are no longer valid; returns how many were removed.
+    pub(crate) fn compact(&mut self) -> usize {
+        let before = self.bodies.len() + self.chains.len() + self.shape_flags.len();
+        self.bodies
+            .retain(|bid| unsafe { ffi::b2Body_IsValid(bid.into_raw()) });
+        self.chains
+            .retain(|(cid, _)| unsafe { ffi::b2Chain_IsValid(cid.into_raw()) });
+        self.shape_flags
+            .retain(|(sid, _)| unsafe { ffi::b2Shape_IsValid(sid.into_raw()) });
+        before - (self.bodies.len() + self.chains.len() + self.shape_flags.len())
+    }
+
+    /// `(bodies, chains, shape_flags)` entry counts.
+    pub(crate) fn lens(&self) -> (usize, usize, usize) {
+        (self.bodies.len(), self.chains.len(), self.shape_flags.len())
+    }
+
     pub(crate) fn shape_flags(&self, sid: ShapeId) -> Option<ShapeFlagsRecord> {
         self.shape_flags
             .iter()
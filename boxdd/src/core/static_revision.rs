@@ -0,0 +1,65 @@
+//! Revision of a world's static collision geometry, behind `StaticQueryCache`.
+//!
+//! The shared mutators for shapes, chains, and bodies note every change that can alter what a
+//! static-only query sees (creating, destroying, moving, enabling, or refiltering shapes on
+//! static bodies, chains, and body type changes) on the world's core, which bumps its counter.
+//! Caches compare it against the value they were filled at.
+//!
+//! Shape changes also drop the cached `World::body_bounding_radius` of the shape's body, so that
+//! cache moves per body rather than per world, and mark the world's gravity scale rules for a
+//! re-sweep.
+use crate::body::BodyType;
+use crate::core::world_core::WorldCore;
+use crate::types::{BodyId, ShapeId};
+use std::sync::atomic::Ordering;
+
+impl WorldCore {
+    #[inline]
+    pub(crate) fn static_revision(&self) -> u64 {
+        self.static_revision.load(Ordering::Acquire)
+    }
+
+    #[inline]
+    pub(crate) fn bump_static_revision(&self) {
+        self.static_revision.fetch_add(1, Ordering::AcqRel);
+    }
+
+    /// Bump if `body` is a live static body. Call before destroying it.
+    #[inline]
+    pub(crate) fn note_body_change(&self, body: BodyId) {
+        if crate::body::body_is_valid_impl(body)
+            && crate::body::body_type_impl(body) == BodyType::Static
+        {
+            self.bump_static_revision();
+        }
+    }
+
+    /// Note a change to the shapes of `body`: drop its cached bounding radius, mark gravity scale
+    /// rules for a re-sweep, and bump the static revision if it is a live static body. Call before
+    /// destroying a shape.
+    #[inline]
+    pub(crate) fn note_body_shapes_change(&self, body: BodyId) {
+        self.forget_bounding_radius(body);
+        self.mark_gravity_rules_dirty();
+        self.note_body_change(body);
+    }
+
+    /// [`Self::note_body_shapes_change`] for the body of `shape`, if it is live.
+    #[inline]
+    pub(crate) fn note_shape_change(&self, shape: ShapeId) {
+        if crate::shapes::shape_is_valid_impl(shape) {
+            self.note_body_shapes_change(crate::shapes::shape_body_id_impl(shape));
+        }
+    }
+
+    #[inline]
+    pub(crate) fn forget_bounding_radius(&self, body: BodyId) {
+        let mut cache = self
+            .bounding_radii
+            .lock()
+            .expect("bounding_radii mutex poisoned");
+        if !cache.is_empty() {
+            cache.remove(&body);
+        }
+    }
+}
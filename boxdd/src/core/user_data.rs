@@ -12,6 +12,35 @@ pub(crate) struct IdKey {
     pub(crate) generation: u16,
 }
 
+impl IdKey {
+    #[inline]
+    fn raw_body(self) -> ffi::b2BodyId {
+        ffi::b2BodyId {
+            index1: self.index1,
+            world0: self.world0,
+            generation: self.generation,
+        }
+    }
+
+    #[inline]
+    fn raw_shape(self) -> ffi::b2ShapeId {
+        ffi::b2ShapeId {
+            index1: self.index1,
+            world0: self.world0,
+            generation: self.generation,
+        }
+    }
+
+    #[inline]
+    fn raw_joint(self) -> ffi::b2JointId {
+        ffi::b2JointId {
+            index1: self.index1,
+            world0: self.world0,
+            generation: self.generation,
+        }
+    }
+}
+
 impl From<ffi::b2BodyId> for IdKey {
     #[inline]
     fn from(id: ffi::b2BodyId) -> Self {
@@ -155,3 +184,30 @@ pub(crate) struct UserDataStore {
     pub(crate) shapes: HashMap<IdKey, ErasedUserData>,
     pub(crate) joints: HashMap<IdKey, ErasedUserData>,
 }
+
+impl UserDataStore {
+    /// Remove entries for ids Box2D no longer considers valid and return them, so the caller can
+    /// drop the values after releasing the lock.
+    pub(crate) fn take_stale(&mut self) -> Vec<ErasedUserData> {
+        let mut stale = Vec::new();
+        take_where(&mut self.bodies, &mut stale, |k| unsafe {
+            !ffi::b2Body_IsValid(k.raw_body())
+        });
+        take_where(&mut self.shapes, &mut stale, |k| unsafe {
+            !ffi::b2Shape_IsValid(k.raw_shape())
+        });
+        take_where(&mut self.joints, &mut stale, |k| unsafe {
+            !ffi::b2Joint_IsValid(k.raw_joint())
+        });
+        stale
+    }
+}
+
+fn take_where(
+    map: &mut HashMap<IdKey, ErasedUserData>,
+    out: &mut Vec<ErasedUserData>,
+    stale: impl Fn(IdKey) -> bool,
+) {
+    let keys: Vec<IdKey> = map.keys().copied().filter(|&k| stale(k)).collect();
+    out.extend(keys.into_iter().filter_map(|k| map.remove(&k)));
+}
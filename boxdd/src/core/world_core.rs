@@ -1,7 +1,8 @@
 use boxdd_sys::ffi;
 use std::any::Any;
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::AtomicBool;
-use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
 use std::sync::{Arc, Mutex, Weak};
 
 use crate::types::{BodyId, ChainId, JointId, ShapeId};
@@ -27,14 +28,18 @@ pub(crate) type MaterialMixCb = dyn Fn(crate::world::MaterialMixInput, crate::wo
     + Sync
     + 'static;
 
+pub(crate) type DestroyHook = Box<dyn FnMut(crate::world::DestroyedEntity) + Send + 'static>;
+
 pub(crate) struct CustomFilterCtx {
     pub(crate) core: Weak<WorldCore>,
-    pub(crate) cb: Box<CustomFilterCb>,
+    /// Shared so `World::debug_explain_filter` can call it without holding `custom_filter`.
+    pub(crate) cb: Arc<CustomFilterCb>,
 }
 
 pub(crate) struct PreSolveCtx {
     pub(crate) core: Weak<WorldCore>,
-    pub(crate) cb: Box<PreSolveCb>,
+    /// `None` when the trampoline is only installed for `World::disable_contact`.
+    pub(crate) cb: Option<Box<PreSolveCb>>,
 }
 
 pub(crate) struct MaterialMixCtx {
@@ -60,6 +65,126 @@ pub(crate) struct WorldCore {
     pub(crate) owned_shapes: AtomicUsize,
     pub(crate) owned_joints: AtomicUsize,
     pub(crate) owned_chains: AtomicUsize,
+    /// Revision of the world's static collision geometry; see `core::static_revision`.
+    pub(crate) static_revision: AtomicU64,
+    pub(crate) active_region: Mutex<Option<ActiveRegionState>>,
+    pub(crate) gravity_rules: Mutex<GravityScaleRules>,
+    pub(crate) counter_tracking: Mutex<CounterTracking>,
+    /// `None` until `World::enable_sleep_tracking(true)`.
+    pub(crate) sleep_tracking: Mutex<Option<SleepTracking>>,
+    /// `None` until the first completed step.
+    pub(crate) last_step: Mutex<Option<crate::world::StepInfo>>,
+    pub(crate) creation_defaults: Mutex<CreationDefaults>,
+    pub(crate) markers: Mutex<Vec<crate::world_extras::Marker>>,
+    /// Joints built by `MouseJointBuilder`; dead ids are dropped by `compact_registries`.
+    pub(crate) mouse_joints: Mutex<HashSet<JointId>>,
+    /// Non-zero `World::set_shape_tags` bits, pruned when the wrapper destroys a shape.
+    pub(crate) shape_tags: Mutex<HashMap<ShapeId, u32>>,
+    pub(crate) destroy_hooks: Mutex<Vec<DestroyHook>>,
+    /// `World::disable_contact` decisions keyed by the ordered shape pair.
+    pub(crate) contact_overrides: Mutex<HashMap<(ShapeId, ShapeId), ContactOverride>>,
+    /// `World::set_one_way_platform` shapes and their unit `up` direction.
+    pub(crate) one_way_platforms: Mutex<HashMap<ShapeId, crate::types::Vec2>>,
+    /// `World::body_bounding_radius` results, dropped when the body's shapes change or the body
+    /// is destroyed.
+    pub(crate) bounding_radii: Mutex<HashMap<BodyId, BoundingRadius>>,
+    /// `None` until `World::enable_query_stats`; `query_stats_enabled` mirrors `is_some()`.
+    pub(crate) query_stats: Mutex<Option<crate::core::query_stats::QueryInstrumentation>>,
+    pub(crate) query_stats_enabled: AtomicBool,
+    #[cfg(feature = "force-tracking")]
+    pub(crate) applied_forces: Mutex<crate::core::applied_forces::AppliedForceLog>,
+    /// Box2D holds a pointer to this context for the world's lifetime.
+    pub(crate) task_system: Option<Box<crate::world::TaskSystemCtx>>,
+}
+
+/// Per-category gravity scale rules applied by `World::step`.
+#[derive(Default)]
+pub(crate) struct GravityScaleRules {
+    /// `(category_bits, scale)` in the order they were set; later rules win.
+    pub(crate) rules: Vec<(u64, f32)>,
+    /// Bodies whose gravity scale a rule overrides: `body -> (own scale, rule scale)`.
+    pub(crate) applied: HashMap<BodyId, (f32, f32)>,
+    /// Every live body created through the world. Sweeps walk this instead of the broadphase,
+    /// which has no proxies for disabled bodies.
+    pub(crate) bodies: HashSet<BodyId>,
+    /// Set when rules change or a body, shape, or filter changes; forces a sweep on the next
+    /// step.
+    pub(crate) dirty: bool,
+}
+
+/// One pair disabled by `World::disable_contact`.
+pub(crate) struct ContactOverride {
+    pub(crate) duration: crate::world::ContactDisable,
+    /// Set by the pre-solve trampoline when the pair touched during the current step.
+    pub(crate) seen: bool,
+}
+
+/// Cached `World::body_bounding_radius`, valid while the shape revision and count match.
+pub(crate) struct BoundingRadius {
+    pub(crate) radius: f32,
+    pub(crate) shape_count: i32,
+}
+
+/// Counter samples behind `World::counters_delta` and `World::counters_high_water`.
+#[derive(Default)]
+pub(crate) struct CounterTracking {
+    /// Counters at the last `counters_delta` call.
+    pub(crate) last_sample: crate::world::Counters,
+    /// Field-wise maximum over every step since creation or the last reset.
+    pub(crate) high_water: crate::world::Counters,
+    /// Set by `World::enable_counters_high_water`; steps only sample counters while on.
+    pub(crate) high_water_enabled: bool,
+}
+
+/// Awake-body sets behind `World::sleep_state_changes`.
+#[derive(Default)]
+pub(crate) struct SleepTracking {
+    /// Bodies that were awake at the end of the last step.
+    pub(crate) awake: HashSet<BodyId>,
+    /// Scratch set swapped with `awake` each step.
+    pub(crate) next: HashSet<BodyId>,
+    /// Transitions found by the last step.
+    pub(crate) changes: Vec<(BodyId, crate::events::SleepState)>,
+}
+
+/// Definitions used by `World::create_body_simple` and `World::create_shape_simple`.
+#[derive(Default)]
+pub(crate) struct CreationDefaults {
+    pub(crate) body: crate::body::BodyDef,
+    pub(crate) shape: crate::shapes::ShapeDef,
+    /// `WorldBuilder::default_enable_contact_events` / `default_enable_hit_events`.
+    pub(crate) events: crate::world::ShapeEventDefaults,
+}
+
+impl CreationDefaults {
+    /// Show the world's event defaults on `shape` where it leaves them unset. They stay unset, so
+    /// creation still resolves them the same way.
+    pub(crate) fn seed_shape_events(&mut self) {
+        let mut raw = self.shape.0;
+        self.events.apply(&self.shape, &mut raw);
+        self.shape.0 = raw;
+    }
+}
+
+/// One force-like call made through the API.
+#[cfg_attr(not(feature = "force-tracking"), allow(dead_code))]
+#[derive(Copy, Clone, Debug)]
+pub(crate) enum Applied {
+    /// Force (N), at a world point or the center of mass.
+    Force(crate::types::Vec2, Option<crate::types::Vec2>),
+    Torque(f32),
+    /// Linear impulse (N·s), at a world point or the center of mass.
+    LinearImpulse(crate::types::Vec2, Option<crate::types::Vec2>),
+    AngularImpulse(f32),
+}
+
+/// Bodies tracked by `World::set_active_region`, kept sorted for deterministic enable order.
+#[derive(Default)]
+pub(crate) struct ActiveRegionState {
+    /// Enabled bodies seen inside the outer region on the last update.
+    pub(crate) inside: Vec<BodyId>,
+    /// Bodies disabled by the active region.
+    pub(crate) disabled: Vec<BodyId>,
 }
 
 // SAFETY: `WorldCore` contains only thread-safe primitives (atomics, mutexes) and is used as a
@@ -78,7 +203,10 @@ pub(crate) enum DeferredDestroy {
 }
 
 impl WorldCore {
-    pub(crate) fn new(id: ffi::b2WorldId) -> Arc<Self> {
+    pub(crate) fn new(
+        id: ffi::b2WorldId,
+        task_system: Option<Box<crate::world::TaskSystemCtx>>,
+    ) -> Arc<Self> {
         Arc::new(Self {
             id,
             custom_filter: Mutex::new(None),
@@ -97,6 +225,25 @@ impl WorldCore {
             owned_shapes: AtomicUsize::new(0),
             owned_joints: AtomicUsize::new(0),
             owned_chains: AtomicUsize::new(0),
+            static_revision: AtomicU64::new(0),
+            active_region: Mutex::new(None),
+            gravity_rules: Mutex::new(GravityScaleRules::default()),
+            counter_tracking: Mutex::new(CounterTracking::default()),
+            sleep_tracking: Mutex::new(None),
+            last_step: Mutex::new(None),
+            creation_defaults: Mutex::new(CreationDefaults::default()),
+            markers: Mutex::new(Vec::new()),
+            mouse_joints: Mutex::new(HashSet::new()),
+            shape_tags: Mutex::new(HashMap::new()),
+            destroy_hooks: Mutex::new(Vec::new()),
+            contact_overrides: Mutex::new(HashMap::new()),
+            one_way_platforms: Mutex::new(HashMap::new()),
+            bounding_radii: Mutex::new(HashMap::new()),
+            query_stats: Mutex::new(None),
+            query_stats_enabled: AtomicBool::new(false),
+            #[cfg(feature = "force-tracking")]
+            applied_forces: Mutex::new(Default::default()),
+            task_system,
         })
     }
 
@@ -109,6 +256,47 @@ impl WorldCore {
         )
     }
 
+    /// Whether `World::disable_contact` covers the pair; marks it as touching this step.
+    pub(crate) fn note_contact_override(&self, a: ShapeId, b: ShapeId) -> bool {
+        let key = if a <= b { (a, b) } else { (b, a) };
+        let mut overrides = self
+            .contact_overrides
+            .lock()
+            .expect("contact_overrides mutex poisoned");
+        match overrides.get_mut(&key) {
+            Some(entry) => {
+                entry.seen = true;
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// `Some(false)` when a one-way platform in the pair rejects a contact with this normal
+    /// (pointing from `a` to `b`), `None` when neither shape is a platform.
+    pub(crate) fn one_way_decision(
+        &self,
+        a: ShapeId,
+        b: ShapeId,
+        normal: crate::types::Vec2,
+    ) -> Option<bool> {
+        /// Minimum cosine between the normal and `up`, about 18 degrees as in the upstream sample.
+        const MIN_UP_DOT: f32 = 0.95;
+        let platforms = self
+            .one_way_platforms
+            .lock()
+            .expect("one_way_platforms mutex poisoned");
+        if platforms.is_empty() {
+            return None;
+        }
+        let from_a = platforms.get(&a).map(|up| normal.dot(*up));
+        let from_b = platforms.get(&b).map(|up| -normal.dot(*up));
+        match (from_a, from_b) {
+            (None, None) => None,
+            (x, y) => Some(x.into_iter().chain(y).all(|d| d > MIN_UP_DOT)),
+        }
+    }
+
     pub(crate) fn defer_destroy(&self, d: DeferredDestroy) {
         self.deferred_destroys
             .lock()
@@ -146,48 +334,25 @@ impl WorldCore {
             match item {
                 DeferredDestroy::Body(id) => {
                     if unsafe { ffi::b2Body_IsValid(id.into_raw()) } {
-                        #[cfg(feature = "serialize")]
-                        {
-                            let mut r = self.registries.lock().expect("registries mutex poisoned");
-                            r.remove_shape_flags_for_body(id);
-                            r.remove_chains_for_body(id);
-                            r.remove_body(id);
-                        }
-                        unsafe { ffi::b2DestroyBody(id.into_raw()) };
+                        self.destroy_body_now(id);
+                    } else {
+                        let _ = self.clear_body_user_data(id);
                     }
-                    let old = self
-                        .user_data
-                        .lock()
-                        .expect("user_data mutex poisoned")
-                        .bodies
-                        .remove(&crate::core::user_data::IdKey::from(id));
-                    drop(old);
                 }
                 DeferredDestroy::Shape {
                     id,
                     update_body_mass,
                 } => {
                     if unsafe { ffi::b2Shape_IsValid(id.into_raw()) } {
-                        unsafe { ffi::b2DestroyShape(id.into_raw(), update_body_mass) };
-                        #[cfg(feature = "serialize")]
-                        {
-                            self.registries
-                                .lock()
-                                .expect("registries mutex poisoned")
-                                .remove_shape_flags(id);
-                        }
+                        self.destroy_shape_now(id, update_body_mass);
+                    } else {
+                        let _ = self.clear_shape_user_data(id);
                     }
-                    let old = self
-                        .user_data
-                        .lock()
-                        .expect("user_data mutex poisoned")
-                        .shapes
-                        .remove(&crate::core::user_data::IdKey::from(id));
-                    drop(old);
                 }
                 DeferredDestroy::Joint { id, wake_bodies } => {
                     if unsafe { ffi::b2Joint_IsValid(id.into_raw()) } {
                         unsafe { ffi::b2DestroyJoint(id.into_raw(), wake_bodies) };
+                        self.notify_destroyed(&[crate::world::DestroyedEntity::Joint(id)]);
                     }
                     let old = self
                         .user_data
@@ -199,7 +364,11 @@ impl WorldCore {
                 }
                 DeferredDestroy::Chain(id) => {
                     if unsafe { ffi::b2Chain_IsValid(id.into_raw()) } {
+                        let destroyed = self.destroyed_with_chain(id);
+                        self.forget_chain_shapes(id);
+                        self.bump_static_revision();
                         unsafe { ffi::b2DestroyChain(id.into_raw()) };
+                        self.notify_destroyed(&destroyed);
                         #[cfg(feature = "serialize")]
                         {
                             self.registries
@@ -569,14 +738,376 @@ impl WorldCore {
             .remove_shape_flags(sid);
     }
 
-    #[cfg(feature = "serialize")]
-    pub(crate) fn cleanup_before_destroy_body(&self, id: BodyId) {
-        crate::core::callback_state::assert_not_in_callback();
-        let mut r = self.registries.lock().expect("registries mutex poisoned");
-        r.remove_shape_flags_for_body(id);
-        r.remove_chains_for_body(id);
-        r.remove_body(id);
+    /// Log a force or impulse applied to `body` through the API; a no-op without the
+    /// `force-tracking` feature.
+    #[inline]
+    pub(crate) fn record_applied(&self, body: BodyId, applied: Applied) {
+        #[cfg(feature = "force-tracking")]
+        self.applied_forces
+            .lock()
+            .expect("applied_forces mutex poisoned")
+            .record(body, applied);
+        #[cfg(not(feature = "force-tracking"))]
+        let _ = (body, applied);
+    }
+
+    /// Everything Box2D destroys along with body `id`: its joints, its shapes (chain segments
+    /// included), then the body itself. Empty when no destroy hook is registered. Must run while
+    /// the body is still valid.
+    pub(crate) fn destroyed_with_body(&self, id: BodyId) -> Vec<crate::world::DestroyedEntity> {
+        use crate::world::DestroyedEntity;
+        if !self.has_destroy_hooks() {
+            return Vec::new();
+        }
+        let mut out: Vec<DestroyedEntity> = crate::body::body_joints_impl(id)
+            .into_iter()
+            .map(DestroyedEntity::Joint)
+            .collect();
+        out.extend(
+            crate::body::body_shapes_impl(id)
+                .into_iter()
+                .map(DestroyedEntity::Shape),
+        );
+        out.push(DestroyedEntity::Body(id));
+        out
+    }
+
+    /// Segment shapes of chain `id` followed by the chain itself. Empty when no destroy hook is
+    /// registered. Must run while the chain is still valid.
+    pub(crate) fn destroyed_with_chain(&self, id: ChainId) -> Vec<crate::world::DestroyedEntity> {
+        use crate::world::DestroyedEntity;
+        if !self.has_destroy_hooks() {
+            return Vec::new();
+        }
+        let mut out: Vec<DestroyedEntity> = crate::shapes::chain::chain_segments_impl(id)
+            .into_iter()
+            .map(DestroyedEntity::Shape)
+            .collect();
+        out.push(DestroyedEntity::Chain(id));
+        out
+    }
+
+    fn has_destroy_hooks(&self) -> bool {
+        !self
+            .destroy_hooks
+            .lock()
+            .expect("destroy_hooks mutex poisoned")
+            .is_empty()
+    }
+
+    /// Run every destroy hook for each entity in order. Hooks run without the hook lock held.
+    pub(crate) fn notify_destroyed(&self, entities: &[crate::world::DestroyedEntity]) {
+        if entities.is_empty() {
+            return;
+        }
+        let mut hooks = {
+            let mut guard = self
+                .destroy_hooks
+                .lock()
+                .expect("destroy_hooks mutex poisoned");
+            if guard.is_empty() {
+                return;
+            }
+            core::mem::take(&mut *guard)
+        };
+        for &entity in entities {
+            for hook in hooks.iter_mut() {
+                hook(entity);
+            }
+        }
+        let mut guard = self
+            .destroy_hooks
+            .lock()
+            .expect("destroy_hooks mutex poisoned");
+        hooks.append(&mut guard);
+        *guard = hooks;
     }
+
+    /// Destroy a valid shape right away, dropping everything this crate tracks for it.
+    pub(crate) fn destroy_shape_now(&self, id: ShapeId, update_body_mass: bool) {
+        self.note_shape_change(id);
+        unsafe { ffi::b2DestroyShape(id.into_raw(), update_body_mass) };
+        let _ = self.clear_shape_user_data(id);
+        self.forget_shapes(&[id]);
+        self.notify_destroyed(&[crate::world::DestroyedEntity::Shape(id)]);
+        #[cfg(feature = "serialize")]
+        self.remove_shape_flags(id);
+    }
+
+    /// Drop the tags, one-way platform, and contact override entries of `shapes`, which are
+    /// being destroyed.
+    pub(crate) fn forget_shapes(&self, shapes: &[ShapeId]) {
+        {
+            let mut tags = self.shape_tags.lock().expect("shape_tags mutex poisoned");
+            if !tags.is_empty() {
+                for shape in shapes {
+                    tags.remove(shape);
+                }
+            }
+        }
+        {
+            let mut platforms = self
+                .one_way_platforms
+                .lock()
+                .expect("one_way_platforms mutex poisoned");
+            if !platforms.is_empty() {
+                for shape in shapes {
+                    platforms.remove(shape);
+                }
+            }
+        }
+        let mut overrides = self
+            .contact_overrides
+            .lock()
+            .expect("contact_overrides mutex poisoned");
+        if !overrides.is_empty() {
+            overrides.retain(|(a, b), _| !shapes.contains(a) && !shapes.contains(b));
+        }
+    }
+
+    fn has_shape_side_entries(&self) -> bool {
+        !self
+            .shape_tags
+            .lock()
+            .expect("shape_tags mutex poisoned")
+            .is_empty()
+            || !self
+                .one_way_platforms
+                .lock()
+                .expect("one_way_platforms mutex poisoned")
+                .is_empty()
+            || !self
+                .contact_overrides
+                .lock()
+                .expect("contact_overrides mutex poisoned")
+                .is_empty()
+    }
+
+    /// [`Self::forget_shapes`] for the segments of `id`, also dropping the cached bounding radius of
+    /// its body; call before destroying the chain.
+    pub(crate) fn forget_chain_shapes(&self, id: ChainId) {
+        let segments = crate::shapes::chain::chain_segments_impl(id);
+        if let Some(&first) = segments.first() {
+            self.forget_bounding_radius(crate::shapes::shape_body_id_impl(first));
+        }
+        if self.has_shape_side_entries() {
+            self.forget_shapes(&segments);
+        }
+    }
+
+    /// Record a body created through the world; the single body creation path calls this.
+    pub(crate) fn note_body_created(&self, id: BodyId) {
+        let mut rules = self
+            .gravity_rules
+            .lock()
+            .expect("gravity_rules mutex poisoned");
+        rules.bodies.insert(id);
+        rules.dirty = true;
+    }
+
+    /// Force a gravity scale rule sweep on the next step.
+    pub(crate) fn mark_gravity_rules_dirty(&self) {
+        self.gravity_rules
+            .lock()
+            .expect("gravity_rules mutex poisoned")
+            .dirty = true;
+    }
+
+    /// Destroy a valid body right away, dropping everything this crate tracks for it and for the
+    /// shapes and joints Box2D destroys with it. Every body destroy path ends here.
+    pub(crate) fn destroy_body_now(&self, id: BodyId) {
+        let destroyed = self.destroyed_with_body(id);
+        {
+            let mut rules = self
+                .gravity_rules
+                .lock()
+                .expect("gravity_rules mutex poisoned");
+            rules.bodies.remove(&id);
+            rules.applied.remove(&id);
+            rules.dirty = true;
+        }
+        #[cfg(feature = "serialize")]
+        {
+            let mut r = self.registries.lock().expect("registries mutex poisoned");
+            r.remove_shape_flags_for_body(id);
+            r.remove_chains_for_body(id);
+            r.remove_body(id);
+        }
+        self.clear_attached_user_data(id);
+        if self.has_shape_side_entries() {
+            self.forget_shapes(&crate::body::body_shapes_impl(id));
+        }
+        self.forget_bounding_radius(id);
+        self.note_body_change(id);
+        unsafe { ffi::b2DestroyBody(id.into_raw()) };
+        let _ = self.clear_body_user_data(id);
+        self.notify_destroyed(&destroyed);
+    }
+
+    /// Drop user data attached to the shapes and joints of `id`, which Box2D destroys along with
+    /// the body, and the body's applied-force log. Must run while the body is still valid.
+    pub(crate) fn clear_attached_user_data(&self, id: BodyId) {
+        #[cfg(feature = "force-tracking")]
+        self.applied_forces
+            .lock()
+            .expect("applied_forces mutex poisoned")
+            .remove_body(id);
+        let mut store = self.user_data.lock().expect("user_data mutex poisoned");
+        let mut old = Vec::new();
+        if !store.shapes.is_empty() {
+            for sid in crate::body::body_shapes_impl(id) {
+                old.extend(
+                    store
+                        .shapes
+                        .remove(&crate::core::user_data::IdKey::from(sid)),
+                );
+            }
+        }
+        if !store.joints.is_empty() {
+            for jid in crate::body::body_joints_impl(id) {
+                old.extend(
+                    store
+                        .joints
+                        .remove(&crate::core::user_data::IdKey::from(jid)),
+                );
+            }
+        }
+        drop(store);
+        drop(old);
+    }
+
+    /// Remove registry entries whose ids are no longer valid; returns how many were removed.
+    pub(crate) fn compact_registries(&self) -> usize {
+        let stale = self
+            .user_data
+            .lock()
+            .expect("user_data mutex poisoned")
+            .take_stale();
+        let mut removed = stale.len();
+        drop(stale);
+        #[cfg(feature = "serialize")]
+        {
+            removed += self
+                .registries
+                .lock()
+                .expect("registries mutex poisoned")
+                .compact();
+        }
+        {
+            let mut rules = self
+                .gravity_rules
+                .lock()
+                .expect("gravity_rules mutex poisoned");
+            let before = rules.applied.len();
+            rules
+                .applied
+                .retain(|&body, _| crate::body::body_is_valid_impl(body));
+            removed += before - rules.applied.len();
+        }
+        if let Some(state) = self
+            .active_region
+            .lock()
+            .expect("active_region mutex poisoned")
+            .as_mut()
+        {
+            let before = state.inside.len() + state.disabled.len();
+            state
+                .inside
+                .retain(|&body| crate::body::body_is_valid_impl(body));
+            state
+                .disabled
+                .retain(|&body| crate::body::body_is_valid_impl(body));
+            removed += before - (state.inside.len() + state.disabled.len());
+        }
+        removed += retain_live(&self.shape_tags, "shape_tags", |&shape| {
+            crate::shapes::shape_is_valid_impl(shape)
+        });
+        removed += retain_live(&self.contact_overrides, "contact_overrides", |&(a, b)| {
+            crate::shapes::shape_is_valid_impl(a) && crate::shapes::shape_is_valid_impl(b)
+        });
+        removed += retain_live(&self.one_way_platforms, "one_way_platforms", |&shape| {
+            crate::shapes::shape_is_valid_impl(shape)
+        });
+        removed += retain_live(&self.bounding_radii, "bounding_radii", |&body| {
+            crate::body::body_is_valid_impl(body)
+        });
+        {
+            let mut joints = self
+                .mouse_joints
+                .lock()
+                .expect("mouse_joints mutex poisoned");
+            let before = joints.len();
+            joints.retain(|&joint| crate::joints::joint_is_valid_impl(joint));
+            removed += before - joints.len();
+        }
+        removed
+    }
+
+    pub(crate) fn registry_sizes(&self) -> crate::world::RegistrySizes {
+        let mut sizes = crate::world::RegistrySizes::default();
+        {
+            let store = self.user_data.lock().expect("user_data mutex poisoned");
+            sizes.body_user_data = store.bodies.len();
+            sizes.shape_user_data = store.shapes.len();
+            sizes.joint_user_data = store.joints.len();
+        }
+        #[cfg(feature = "serialize")]
+        {
+            (sizes.bodies, sizes.chains, sizes.shape_flags) = self
+                .registries
+                .lock()
+                .expect("registries mutex poisoned")
+                .lens();
+        }
+        sizes.gravity_overrides = self
+            .gravity_rules
+            .lock()
+            .expect("gravity_rules mutex poisoned")
+            .applied
+            .len();
+        sizes.active_region_bodies = self
+            .active_region
+            .lock()
+            .expect("active_region mutex poisoned")
+            .as_ref()
+            .map_or(0, |s| s.inside.len() + s.disabled.len());
+        sizes.shape_tags = self
+            .shape_tags
+            .lock()
+            .expect("shape_tags mutex poisoned")
+            .len();
+        sizes.contact_overrides = self
+            .contact_overrides
+            .lock()
+            .expect("contact_overrides mutex poisoned")
+            .len();
+        sizes.one_way_platforms = self
+            .one_way_platforms
+            .lock()
+            .expect("one_way_platforms mutex poisoned")
+            .len();
+        sizes.bounding_radii = self
+            .bounding_radii
+            .lock()
+            .expect("bounding_radii mutex poisoned")
+            .len();
+        sizes.mouse_joints = self
+            .mouse_joints
+            .lock()
+            .expect("mouse_joints mutex poisoned")
+            .len();
+        sizes
+    }
+}
+
+/// Drop the entries of an id-keyed side table whose ids are no longer live, returning how many.
+fn retain_live<K, V>(table: &Mutex<HashMap<K, V>>, name: &str, live: impl Fn(&K) -> bool) -> usize {
+    let mut table = table
+        .lock()
+        .unwrap_or_else(|_| panic!("{name} mutex poisoned"));
+    let before = table.len();
+    table.retain(|k, _| live(k));
+    before - table.len()
 }
 
 pub(crate) struct BorrowedEventBuffersGuard {
@@ -609,5 +1140,6 @@ impl Drop for WorldCore {
         let _guard = crate::core::box2d_lock::lock();
         // SAFETY: `WorldCore` owns the Box2D world id; only the last Arc drops it.
         unsafe { ffi::b2DestroyWorld(self.id) };
+        crate::limits::world_destroyed();
     }
 }
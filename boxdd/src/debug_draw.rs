@@ -4,6 +4,10 @@
 //! step with `DebugDrawOptions` to render. Colors use the crate-owned [`HexColor`] type, which
 //! stores Box2D's packed `0xRRGGBB` convention.
 //!
+//! Renderers that only draw lines and triangles can implement [`DebugDrawBackend`] instead and
+//! call `World::debug_draw_backend`, or fill a [`VertexCollector`] with ready-made vertex buffers.
+//! [`svg`] renders the same output to an SVG document for headless runs.
+//!
 //! Example
 //! ```no_run
 //! use boxdd::{DebugDraw, DebugDrawOptions, HexColor, Vec2, World, WorldDef};
@@ -23,67 +27,23 @@
 //! }
 //! ```
 use crate::Transform;
-use crate::types::Vec2;
+use crate::filter::CollisionCategory;
+use crate::query::{Aabb, QueryFilter};
+use crate::shapes::SurfaceMaterial;
+use crate::types::{ShapeId, Vec2};
 use crate::world::World;
 use boxdd_sys::ffi;
 use smallvec::SmallVec;
 use std::any::Any;
 use std::ffi::CStr;
 
-type DebugDrawPanic = Box<dyn Any + Send + 'static>;
-
-/// Packed Box2D debug-draw RGB color (`0xRRGGBB`).
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[repr(transparent)]
-#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
-pub struct HexColor(u32);
-
-impl HexColor {
-    pub const BLACK: Self = Self::from_rgb_u32(0x000000);
-    pub const WHITE: Self = Self::from_rgb_u32(0xFFFFFF);
-    pub const RED: Self = Self::from_rgb_u32(0xFF0000);
-    pub const GREEN: Self = Self::from_rgb_u32(0x00FF00);
-    pub const BLUE: Self = Self::from_rgb_u32(0x0000FF);
-    pub const BOX2D_RED: Self = Self::from_rgb_u32(0xDC3132);
-    pub const BOX2D_BLUE: Self = Self::from_rgb_u32(0x30AEBF);
-    pub const BOX2D_GREEN: Self = Self::from_rgb_u32(0x8CC924);
-    pub const BOX2D_YELLOW: Self = Self::from_rgb_u32(0xFFEE8C);
-
-    #[inline]
-    pub const fn from_rgb(red: u8, green: u8, blue: u8) -> Self {
-        Self(((red as u32) << 16) | ((green as u32) << 8) | blue as u32)
-    }
-
-    #[inline]
-    pub const fn from_rgb_u32(rgb: u32) -> Self {
-        Self(rgb & 0x00ff_ffff)
-    }
-
-    #[inline]
-    pub const fn from_raw(raw: ffi::b2HexColor) -> Self {
-        Self::from_rgb_u32(raw)
-    }
-
-    #[inline]
-    pub const fn rgb_u32(self) -> u32 {
-        self.0
-    }
-
-    #[inline]
-    pub const fn into_raw(self) -> ffi::b2HexColor {
-        self.0
-    }
+mod mesh;
+pub mod svg;
 
-    #[inline]
-    pub const fn with_alpha(self, alpha: u8) -> u32 {
-        ((alpha as u32) << 24) | self.0
-    }
-}
+pub use crate::color::HexColor;
+pub use mesh::{DebugDrawBackend, DebugLabel, DebugPoint, DebugVertex, VertexCollector};
 
-const _: () = {
-    assert!(core::mem::size_of::<HexColor>() == core::mem::size_of::<ffi::b2HexColor>());
-    assert!(core::mem::align_of::<HexColor>() == core::mem::align_of::<ffi::b2HexColor>());
-};
+type DebugDrawPanic = Box<dyn Any + Send + 'static>;
 
 #[derive(Clone, Debug)]
 pub enum DebugDrawCmd {
@@ -197,6 +157,8 @@ pub struct DebugDrawOptions {
     pub draw_contact_forces: bool,
     pub draw_friction_forces: bool,
     pub draw_islands: bool,
+    /// Per-category / per-material shape colors applied for the duration of the draw call.
+    pub color_overrides: Option<CategoryColors>,
 }
 
 impl Default for DebugDrawOptions {
@@ -224,10 +186,165 @@ impl Default for DebugDrawOptions {
             draw_contact_forces: false,
             draw_friction_forces: false,
             draw_islands: false,
+            color_overrides: None,
         }
     }
 }
 
+impl DebugDrawOptions {
+    /// Restrict drawing to a world-space rectangle.
+    pub fn drawing_bounds(mut self, aabb: crate::query::Aabb) -> Self {
+        self.drawing_bounds = aabb.into_raw();
+        self
+    }
+
+    /// Cull drawing to what `camera` can see.
+    pub fn culled_to(self, camera: &crate::camera::Camera2D) -> Self {
+        self.drawing_bounds(camera.viewport_aabb())
+    }
+
+    /// Color shapes by collision category or user material id instead of Box2D's body-state palette.
+    pub fn color_overrides(mut self, colors: CategoryColors) -> Self {
+        self.color_overrides = Some(colors);
+        self
+    }
+}
+
+/// Shape colors keyed by collision category bit or [`SurfaceMaterial`] user material id.
+///
+/// Used through [`DebugDrawOptions::color_overrides`]. Material rules win over category rules;
+/// a shape in several colored categories takes the color of its lowest colored bit. Box2D treats
+/// a packed color of `0` as "no custom color", so [`HexColor::BLACK`] clears a rule. The table is
+/// fixed-size so `DebugDrawOptions` stays `Copy`; at most [`Self::MAX_MATERIALS`] material ids
+/// can be colored.
+///
+/// ```no_run
+/// use boxdd::{CategoryColors, CollisionCategory, DebugDrawOptions, HexColor};
+/// const TRIGGERS: CollisionCategory = CollisionCategory::bit(1);
+/// const TERRAIN: CollisionCategory = CollisionCategory::bit(2);
+/// let opts = DebugDrawOptions::default().color_overrides(
+///     CategoryColors::new()
+///         .category(TRIGGERS, HexColor::BOX2D_YELLOW)
+///         .category(TERRAIN, HexColor::from_rgb(0x60, 0x60, 0x60))
+///         .material(7, HexColor::RED),
+/// );
+/// ```
+///
+/// [`SurfaceMaterial`]: crate::SurfaceMaterial
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct CategoryColors {
+    categories: [u32; 64],
+    materials: [(u64, u32); CategoryColors::MAX_MATERIALS],
+    material_count: usize,
+}
+
+impl Default for CategoryColors {
+    fn default() -> Self {
+        Self {
+            categories: [0; 64],
+            materials: [(0, 0); Self::MAX_MATERIALS],
+            material_count: 0,
+        }
+    }
+}
+
+impl CategoryColors {
+    /// Maximum number of distinct user material ids that can carry a color.
+    pub const MAX_MATERIALS: usize = 8;
+
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Color shapes in any of the category bits set in `category`.
+    pub fn category(mut self, category: CollisionCategory, color: HexColor) -> Self {
+        for bit in category.iter_bits() {
+            self.categories[bit as usize] = color.rgb_u32();
+        }
+        self
+    }
+
+    /// Color shapes whose surface material carries `user_material_id`.
+    ///
+    /// Panics if more than [`Self::MAX_MATERIALS`] distinct ids are colored.
+    pub fn material(mut self, user_material_id: u64, color: HexColor) -> Self {
+        let used = &mut self.materials[..self.material_count];
+        if let Some(slot) = used.iter_mut().find(|(id, _)| *id == user_material_id) {
+            slot.1 = color.rgb_u32();
+            return self;
+        }
+        assert!(
+            self.material_count < Self::MAX_MATERIALS,
+            "CategoryColors holds at most {} material colors",
+            Self::MAX_MATERIALS
+        );
+        self.materials[self.material_count] = (user_material_id, color.rgb_u32());
+        self.material_count += 1;
+        self
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.categories.iter().all(|&c| c == 0)
+            && self.materials[..self.material_count]
+                .iter()
+                .all(|&(_, c)| c == 0)
+    }
+
+    /// Resolve the override color for a shape with `categories` and `user_material_id`.
+    pub fn color_for(
+        &self,
+        categories: CollisionCategory,
+        user_material_id: u64,
+    ) -> Option<HexColor> {
+        let by_material = self.materials[..self.material_count]
+            .iter()
+            .find(|&&(id, c)| id == user_material_id && c != 0)
+            .map(|&(_, c)| c);
+        by_material
+            .or_else(|| {
+                categories
+                    .iter_bits()
+                    .map(|bit| self.categories[bit as usize])
+                    .find(|&c| c != 0)
+            })
+            .map(HexColor::from_rgb_u32)
+    }
+}
+
+/// Temporarily writes `customColor` on every shape inside the drawing bounds that matches
+/// `colors`, returning the original materials for [`restore_color_overrides`].
+fn apply_color_overrides(
+    world: &World,
+    drawing_bounds: ffi::b2AABB,
+    colors: &CategoryColors,
+) -> Vec<(ShapeId, SurfaceMaterial)> {
+    let mut saved = Vec::new();
+    if colors.is_empty() {
+        return saved;
+    }
+    let filter = QueryFilter::default()
+        .category(CollisionCategory::ALL.bits())
+        .mask(CollisionCategory::ALL.bits());
+    for shape in world.overlap_aabb(Aabb::from_raw(drawing_bounds), filter) {
+        let material = crate::shapes::shape_surface_material_impl(shape);
+        let categories = crate::shapes::shape_filter_impl(shape).categories();
+        if let Some(color) = colors.color_for(categories, material.user_material_id()) {
+            crate::shapes::shape_set_surface_material_impl(
+                shape,
+                &material.with_custom_color(color),
+            );
+            saved.push((shape, material));
+        }
+    }
+    saved
+}
+
+fn restore_color_overrides(saved: Vec<(ShapeId, SurfaceMaterial)>) {
+    for (shape, material) in saved {
+        crate::shapes::shape_set_surface_material_impl(shape, &material);
+    }
+}
+
 struct DebugDrawCtx<'a, T: ?Sized> {
     drawer: &'a mut T,
     panicked: &'a mut bool,
@@ -628,8 +745,12 @@ impl World {
         dd.DrawPointFcn = Some(draw_point_cb);
         dd.DrawStringFcn = Some(draw_string_cb);
         apply_debug_draw_options(&mut dd, opts, &mut ctx as *mut _ as *mut _);
-
+        let saved = opts
+            .color_overrides
+            .map(|colors| apply_color_overrides(self, opts.drawing_bounds, &colors))
+            .unwrap_or_default();
         unsafe { ffi::b2World_Draw(self.raw(), &mut dd) };
+        restore_color_overrides(saved);
         finish_debug_draw(self, &mut panic);
     }
 
@@ -777,7 +898,12 @@ impl World {
         dd.DrawPointFcn = Some(draw_point_cb);
         dd.DrawStringFcn = Some(draw_string_cb);
         apply_debug_draw_options(&mut dd, opts, &mut ctx as *mut _ as *mut _);
+        let saved = opts
+            .color_overrides
+            .map(|colors| apply_color_overrides(self, opts.drawing_bounds, &colors))
+            .unwrap_or_default();
         unsafe { ffi::b2World_Draw(self.raw(), &mut dd) };
+        restore_color_overrides(saved);
         finish_debug_draw(self, &mut panic);
     }
 
@@ -0,0 +1,305 @@
+//! Tessellated debug draw: shapes arrive as colored lines and triangles.
+//!
+//! Implement [`DebugDrawBackend`] (two required methods) and pass it to
+//! [`World::debug_draw_backend`], or use [`VertexCollector`] to get vertex buffers ready for a
+//! line-list and a triangle-list pipeline.
+
+use super::{DebugDraw, DebugDrawOptions, HexColor};
+use crate::Transform;
+use crate::types::Vec2;
+use crate::world::World;
+
+/// Length of the axes drawn for `draw_transform`, matching the upstream samples.
+pub(super) const AXIS_LENGTH: f32 = 0.2;
+/// Arc steps per rounded polygon corner.
+const CORNER_STEPS: usize = 4;
+
+/// Renderer-facing debug draw sink working on lines and triangles only.
+///
+/// Solid shapes are filled with [`fill_alpha`](Self::fill_alpha) and outlined with opaque lines,
+/// like the upstream samples. Colors are RGBA bytes.
+pub trait DebugDrawBackend {
+    /// Draw a line from `a` to `b`.
+    fn line(&mut self, a: Vec2, b: Vec2, color: [u8; 4]);
+
+    /// Draw a filled triangle (counter-clockwise).
+    fn triangle(&mut self, a: Vec2, b: Vec2, c: Vec2, color: [u8; 4]);
+
+    /// Draw a point `size` pixels wide. Ignored by default.
+    fn point(&mut self, _p: Vec2, _size: f32, _color: [u8; 4]) {}
+
+    /// Draw a text label. Ignored by default.
+    fn text(&mut self, _p: Vec2, _text: &str, _color: [u8; 4]) {}
+
+    /// Segments used for full circles; capsules use half as many per end.
+    fn circle_segments(&self) -> usize {
+        16
+    }
+
+    /// Alpha of filled shapes.
+    fn fill_alpha(&self) -> u8 {
+        128
+    }
+}
+
+/// Vertex with a position and an RGBA color.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct DebugVertex {
+    pub position: Vec2,
+    pub color: [u8; 4],
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Zeroable for DebugVertex {}
+#[cfg(feature = "bytemuck")]
+unsafe impl bytemuck::Pod for DebugVertex {}
+
+/// A point from `draw_point`; `size` is in pixels.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DebugPoint {
+    pub position: Vec2,
+    pub size: f32,
+    pub color: [u8; 4],
+}
+
+/// A text label from `draw_string` (body names, joint info).
+#[derive(Clone, Debug, PartialEq)]
+pub struct DebugLabel {
+    pub position: Vec2,
+    pub text: String,
+    pub color: [u8; 4],
+}
+
+/// [`DebugDrawBackend`] that records everything into vertex buffers.
+///
+/// `lines` holds line-list vertex pairs and `triangles` holds triangle-list triples, both in world
+/// space. Clear and refill it each frame to reuse the allocations.
+///
+/// ```no_run
+/// use boxdd::{DebugDrawOptions, VertexCollector, World, WorldDef};
+/// let mut world = World::new(WorldDef::default()).unwrap();
+/// let mut mesh = VertexCollector::new();
+/// mesh.clear();
+/// world.debug_draw_backend(&mut mesh, DebugDrawOptions::default());
+/// // upload `mesh.triangles` and `mesh.lines` to the GPU
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct VertexCollector {
+    pub lines: Vec<DebugVertex>,
+    pub triangles: Vec<DebugVertex>,
+    pub points: Vec<DebugPoint>,
+    pub labels: Vec<DebugLabel>,
+}
+
+impl VertexCollector {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Empty every buffer, keeping capacity.
+    pub fn clear(&mut self) {
+        self.lines.clear();
+        self.triangles.clear();
+        self.points.clear();
+        self.labels.clear();
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+            && self.triangles.is_empty()
+            && self.points.is_empty()
+            && self.labels.is_empty()
+    }
+}
+
+impl DebugDrawBackend for VertexCollector {
+    fn line(&mut self, a: Vec2, b: Vec2, color: [u8; 4]) {
+        self.lines.push(DebugVertex { position: a, color });
+        self.lines.push(DebugVertex { position: b, color });
+    }
+
+    fn triangle(&mut self, a: Vec2, b: Vec2, c: Vec2, color: [u8; 4]) {
+        for position in [a, b, c] {
+            self.triangles.push(DebugVertex { position, color });
+        }
+    }
+
+    fn point(&mut self, p: Vec2, size: f32, color: [u8; 4]) {
+        self.points.push(DebugPoint {
+            position: p,
+            size,
+            color,
+        });
+    }
+
+    fn text(&mut self, p: Vec2, text: &str, color: [u8; 4]) {
+        self.labels.push(DebugLabel {
+            position: p,
+            text: text.to_owned(),
+            color,
+        });
+    }
+}
+
+/// Adapts a [`DebugDrawBackend`] to the shape-level [`DebugDraw`] callbacks.
+struct Tessellator<'a, B: ?Sized> {
+    backend: &'a mut B,
+    scratch: Vec<Vec2>,
+}
+
+#[inline]
+fn rgba(color: HexColor, alpha: u8) -> [u8; 4] {
+    let rgb = color.rgb_u32();
+    [(rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8, alpha]
+}
+
+#[inline]
+fn polar(center: Vec2, radius: f32, angle: f32) -> Vec2 {
+    let (s, c) = angle.sin_cos();
+    Vec2::new(center.x + radius * c, center.y + radius * s)
+}
+
+impl<B: DebugDrawBackend + ?Sized> Tessellator<'_, B> {
+    /// Outline and fill the convex loop in `self.scratch`.
+    fn fill_scratch(&mut self, color: HexColor) {
+        let fill = rgba(color, self.backend.fill_alpha());
+        let line = rgba(color, 255);
+        let pts = &self.scratch;
+        for i in 1..pts.len().saturating_sub(1) {
+            self.backend.triangle(pts[0], pts[i], pts[i + 1], fill);
+        }
+        for (i, &p) in pts.iter().enumerate() {
+            self.backend.line(p, pts[(i + 1) % pts.len()], line);
+        }
+    }
+
+    fn push_arc(&mut self, center: Vec2, radius: f32, from: f32, sweep: f32, steps: usize) {
+        for i in 0..=steps {
+            let t = i as f32 / steps as f32;
+            self.scratch.push(polar(center, radius, from + sweep * t));
+        }
+    }
+}
+
+impl<B: DebugDrawBackend + ?Sized> DebugDraw for Tessellator<'_, B> {
+    fn draw_polygon(&mut self, vertices: &[Vec2], color: HexColor) {
+        let line = rgba(color, 255);
+        for (i, &p) in vertices.iter().enumerate() {
+            self.backend
+                .line(p, vertices[(i + 1) % vertices.len()], line);
+        }
+    }
+
+    fn draw_solid_polygon(
+        &mut self,
+        transform: Transform,
+        vertices: &[Vec2],
+        radius: f32,
+        color: HexColor,
+    ) {
+        self.scratch.clear();
+        let n = vertices.len();
+        if radius > 0.0 && n >= 2 {
+            for i in 0..n {
+                let prev = transform.transform_point(vertices[(i + n - 1) % n]);
+                let v = transform.transform_point(vertices[i]);
+                let next = transform.transform_point(vertices[(i + 1) % n]);
+                // Outward normals of the edges meeting at `v` (counter-clockwise winding).
+                let a0 = (-(v.x - prev.x)).atan2(v.y - prev.y);
+                let mut sweep = (-(next.x - v.x)).atan2(next.y - v.y) - a0;
+                if sweep < 0.0 {
+                    sweep += core::f32::consts::TAU;
+                }
+                self.push_arc(v, radius, a0, sweep, CORNER_STEPS);
+            }
+        } else {
+            self.scratch
+                .extend(vertices.iter().map(|&v| transform.transform_point(v)));
+        }
+        self.fill_scratch(color);
+    }
+
+    fn draw_circle(&mut self, center: Vec2, radius: f32, color: HexColor) {
+        let line = rgba(color, 255);
+        let segments = self.backend.circle_segments().max(3);
+        let step = core::f32::consts::TAU / segments as f32;
+        for i in 0..segments {
+            let a = polar(center, radius, step * i as f32);
+            let b = polar(center, radius, step * (i + 1) as f32);
+            self.backend.line(a, b, line);
+        }
+    }
+
+    fn draw_solid_circle(&mut self, transform: Transform, radius: f32, color: HexColor) {
+        let center = transform.position();
+        let segments = self.backend.circle_segments().max(3);
+        self.scratch.clear();
+        let step = core::f32::consts::TAU / segments as f32;
+        for i in 0..segments {
+            self.scratch.push(polar(center, radius, step * i as f32));
+        }
+        self.fill_scratch(color);
+        let rim = transform.transform_point(Vec2::new(radius, 0.0));
+        self.backend.line(center, rim, rgba(color, 255));
+    }
+
+    fn draw_solid_capsule(&mut self, p1: Vec2, p2: Vec2, radius: f32, color: HexColor) {
+        let half = (self.backend.circle_segments() / 2).max(2);
+        let axis = (p2.y - p1.y).atan2(p2.x - p1.x);
+        let quarter = core::f32::consts::FRAC_PI_2;
+        let pi = core::f32::consts::PI;
+        self.scratch.clear();
+        self.push_arc(p2, radius, axis - quarter, pi, half);
+        self.push_arc(p1, radius, axis + quarter, pi, half);
+        self.fill_scratch(color);
+    }
+
+    fn draw_segment(&mut self, p1: Vec2, p2: Vec2, color: HexColor) {
+        self.backend.line(p1, p2, rgba(color, 255));
+    }
+
+    fn draw_transform(&mut self, transform: Transform) {
+        let origin = transform.position();
+        let x = transform.transform_point(Vec2::new(AXIS_LENGTH, 0.0));
+        let y = transform.transform_point(Vec2::new(0.0, AXIS_LENGTH));
+        self.backend.line(origin, x, rgba(HexColor::RED, 255));
+        self.backend.line(origin, y, rgba(HexColor::GREEN, 255));
+    }
+
+    fn draw_point(&mut self, p: Vec2, size: f32, color: HexColor) {
+        self.backend.point(p, size, rgba(color, 255));
+    }
+
+    fn draw_string(&mut self, p: Vec2, s: &str, color: HexColor) {
+        self.backend.text(p, s, rgba(color, 255));
+    }
+}
+
+impl World {
+    /// Debug draw through a [`DebugDrawBackend`], tessellating shapes into lines and triangles.
+    ///
+    /// The same locking rules as [`World::debug_draw`] apply to the backend's methods.
+    pub fn debug_draw_backend(
+        &mut self,
+        backend: &mut (impl DebugDrawBackend + ?Sized),
+        opts: DebugDrawOptions,
+    ) {
+        let mut tessellator = Tessellator {
+            backend,
+            scratch: Vec::new(),
+        };
+        self.debug_draw(&mut tessellator, opts);
+    }
+
+    /// Recoverable version of [`World::debug_draw_backend`].
+    pub fn try_debug_draw_backend(
+        &mut self,
+        backend: &mut (impl DebugDrawBackend + ?Sized),
+        opts: DebugDrawOptions,
+    ) -> crate::error::ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        self.debug_draw_backend(backend, opts);
+        Ok(())
+    }
+}
@@ -0,0 +1,348 @@
+//! SVG export of debug draw output.
+//!
+//! Renders the commands from [`World::debug_draw_collect`] into a standalone SVG document, so a
+//! world can be looked at without a window: attach it to a bug report, write it from a failing
+//! test in CI, or embed it in docs. What gets drawn (shapes, joints, AABBs, contacts, ...) follows
+//! the [`DebugDrawOptions`] toggles as usual.
+//!
+//! ```no_run
+//! use boxdd::debug_draw::svg::SvgExport;
+//! use boxdd::{DebugDrawOptions, World, WorldDef};
+//! let mut world = World::new(WorldDef::default()).unwrap();
+//! let opts = DebugDrawOptions {
+//!     draw_bounds: true,
+//!     draw_contacts: true,
+//!     ..Default::default()
+//! };
+//! std::fs::write("world.svg", world.debug_draw_svg(opts)).unwrap();
+//!
+//! let cmds = world.debug_draw_collect(opts);
+//! let mut file = std::fs::File::create("zoomed.svg").unwrap();
+//! SvgExport::new().pixels_per_meter(200.0).write(&cmds, &mut file).unwrap();
+//! ```
+
+use core::fmt::Write as _;
+use std::io;
+
+use super::{DebugDrawCmd, DebugDrawOptions, HexColor};
+use crate::Transform;
+use crate::types::Vec2;
+use crate::world::World;
+
+/// Layout and styling for an SVG export.
+///
+/// The view box is fitted to the drawn geometry plus [`padding`](Self::padding). World `+y` points
+/// up in the image.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SvgExport {
+    /// Image pixels per world meter.
+    pub pixels_per_meter: f32,
+    /// Margin around the drawn geometry, in meters.
+    pub padding: f32,
+    /// Background fill; `None` leaves the image transparent.
+    pub background: Option<HexColor>,
+    /// Opacity of filled shapes, in `[0, 1]`.
+    pub fill_opacity: f32,
+}
+
+impl Default for SvgExport {
+    fn default() -> Self {
+        Self {
+            pixels_per_meter: 50.0,
+            padding: 0.5,
+            background: Some(HexColor::from_rgb_u32(0x1e1e1e)),
+            fill_opacity: 0.5,
+        }
+    }
+}
+
+impl SvgExport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn pixels_per_meter(mut self, pixels_per_meter: f32) -> Self {
+        self.pixels_per_meter = pixels_per_meter;
+        self
+    }
+
+    pub fn padding(mut self, padding: f32) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    pub fn background(mut self, background: Option<HexColor>) -> Self {
+        self.background = background;
+        self
+    }
+
+    pub fn fill_opacity(mut self, fill_opacity: f32) -> Self {
+        self.fill_opacity = fill_opacity;
+        self
+    }
+
+    /// Render `cmds` into an SVG document.
+    pub fn render(&self, cmds: &[DebugDrawCmd]) -> String {
+        let ppm = if self.pixels_per_meter.is_finite() && self.pixels_per_meter > 0.0 {
+            self.pixels_per_meter
+        } else {
+            Self::default().pixels_per_meter
+        };
+        let pad = self.padding.max(0.0);
+        let (lower, upper) = bounds(cmds).unwrap_or((Vec2::ZERO, Vec2::new(1.0, 1.0)));
+        let (x, y) = (lower.x - pad, -(upper.y + pad));
+        let (w, h) = (upper.x - lower.x + 2.0 * pad, upper.y - lower.y + 2.0 * pad);
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{}" height="{}" viewBox="{x} {y} {w} {h}">"#,
+            (w * ppm).ceil(),
+            (h * ppm).ceil(),
+        );
+        if let Some(bg) = self.background {
+            let _ = writeln!(
+                out,
+                r#"<rect x="{x}" y="{y}" width="{w}" height="{h}" fill="{}"/>"#,
+                hex(bg)
+            );
+        }
+        // One pixel wide outlines at any scale.
+        let _ = writeln!(
+            out,
+            r#"<g fill="none" stroke-width="{}" stroke-linejoin="round" stroke-linecap="round">"#,
+            1.0 / ppm
+        );
+        let mut style = Style {
+            out,
+            ppm,
+            fill_opacity: self.fill_opacity.clamp(0.0, 1.0),
+        };
+        for cmd in cmds {
+            style.cmd(cmd);
+        }
+        let mut out = style.out;
+        out.push_str("</g>\n</svg>\n");
+        out
+    }
+
+    /// Render `cmds` into `writer`.
+    pub fn write(&self, cmds: &[DebugDrawCmd], writer: &mut impl io::Write) -> io::Result<()> {
+        writer.write_all(self.render(cmds).as_bytes())
+    }
+}
+
+/// Flip to SVG's downward y axis.
+fn pt(p: Vec2) -> (f32, f32) {
+    (p.x, -p.y)
+}
+
+fn hex(color: HexColor) -> String {
+    format!("#{:06x}", color.rgb_u32())
+}
+
+fn points(out: &mut String, vertices: impl IntoIterator<Item = Vec2>) {
+    for (i, v) in vertices.into_iter().enumerate() {
+        let (x, y) = pt(v);
+        let sep = if i == 0 { "" } else { " " };
+        let _ = write!(out, "{sep}{x},{y}");
+    }
+}
+
+struct Style {
+    out: String,
+    ppm: f32,
+    fill_opacity: f32,
+}
+
+impl Style {
+    fn line(&mut self, a: Vec2, b: Vec2, color: HexColor) {
+        let ((x1, y1), (x2, y2)) = (pt(a), pt(b));
+        let _ = writeln!(
+            self.out,
+            r#"<line x1="{x1}" y1="{y1}" x2="{x2}" y2="{y2}" stroke="{}"/>"#,
+            hex(color)
+        );
+    }
+
+    fn cmd(&mut self, cmd: &DebugDrawCmd) {
+        let op = self.fill_opacity;
+        match cmd {
+            DebugDrawCmd::Polygon { vertices, color } => {
+                self.out.push_str(r#"<polygon points=""#);
+                points(&mut self.out, vertices.iter().copied());
+                let _ = writeln!(self.out, r#"" stroke="{}"/>"#, hex(*color));
+            }
+            DebugDrawCmd::SolidPolygon {
+                transform,
+                vertices,
+                radius,
+                color,
+            } => {
+                // A round-joined stroke of width 2r is exactly the rounded polygon's skin.
+                let c = hex(*color);
+                self.out.push_str(r#"<polygon points=""#);
+                points(
+                    &mut self.out,
+                    vertices.iter().map(|&v| transform.transform_point(v)),
+                );
+                if *radius > 0.0 {
+                    let _ = writeln!(
+                        self.out,
+                        r#"" fill="{c}" fill-opacity="{op}" stroke="{c}" stroke-opacity="{op}" stroke-width="{}"/>"#,
+                        2.0 * radius
+                    );
+                } else {
+                    let _ = writeln!(
+                        self.out,
+                        r#"" fill="{c}" fill-opacity="{op}" stroke="{c}"/>"#
+                    );
+                }
+            }
+            DebugDrawCmd::Circle {
+                center,
+                radius,
+                color,
+            } => {
+                let (cx, cy) = pt(*center);
+                let _ = writeln!(
+                    self.out,
+                    r#"<circle cx="{cx}" cy="{cy}" r="{radius}" stroke="{}"/>"#,
+                    hex(*color)
+                );
+            }
+            DebugDrawCmd::SolidCircle {
+                transform,
+                radius,
+                color,
+            } => {
+                let c = hex(*color);
+                let (cx, cy) = pt(transform.position());
+                let _ = writeln!(
+                    self.out,
+                    r#"<circle cx="{cx}" cy="{cy}" r="{radius}" fill="{c}" fill-opacity="{op}" stroke="{c}"/>"#
+                );
+                let rim = transform.transform_point(Vec2::new(*radius, 0.0));
+                self.line(transform.position(), rim, *color);
+            }
+            DebugDrawCmd::SolidCapsule {
+                p1,
+                p2,
+                radius,
+                color,
+            } => {
+                let ((x1, y1), (x2, y2)) = (pt(*p1), pt(*p2));
+                let _ = writeln!(
+                    self.out,
+                    r#"<line x1="{x1}" y1="{y1}" x2="{x2}" y2="{y2}" stroke="{}" stroke-opacity="{op}" stroke-width="{}"/>"#,
+                    hex(*color),
+                    2.0 * radius
+                );
+            }
+            DebugDrawCmd::Segment { p1, p2, color } => self.line(*p1, *p2, *color),
+            DebugDrawCmd::Transform(transform) => self.transform(*transform),
+            DebugDrawCmd::Point { p, size, color } => {
+                let (cx, cy) = pt(*p);
+                let _ = writeln!(
+                    self.out,
+                    r#"<circle cx="{cx}" cy="{cy}" r="{}" fill="{}" stroke="none"/>"#,
+                    0.5 * size / self.ppm,
+                    hex(*color)
+                );
+            }
+            DebugDrawCmd::String { p, s, color } => {
+                let (x, y) = pt(*p);
+                let _ = write!(
+                    self.out,
+                    r#"<text x="{x}" y="{y}" font-size="{}" font-family="monospace" fill="{}" stroke="none">"#,
+                    12.0 / self.ppm,
+                    hex(*color)
+                );
+                escape_into(&mut self.out, s);
+                self.out.push_str("</text>\n");
+            }
+        }
+    }
+
+    fn transform(&mut self, transform: Transform) {
+        let origin = transform.position();
+        let axis = super::mesh::AXIS_LENGTH;
+        let x = transform.transform_point(Vec2::new(axis, 0.0));
+        let y = transform.transform_point(Vec2::new(0.0, axis));
+        self.line(origin, x, HexColor::RED);
+        self.line(origin, y, HexColor::GREEN);
+    }
+}
+
+fn escape_into(out: &mut String, s: &str) {
+    for ch in s.chars() {
+        match ch {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' => out.push_str("&quot;"),
+            _ => out.push(ch),
+        }
+    }
+}
+
+/// World-space box around everything `cmds` draws. Text and points count by their anchor only.
+fn bounds(cmds: &[DebugDrawCmd]) -> Option<(Vec2, Vec2)> {
+    let mut lower = Vec2::new(f32::INFINITY, f32::INFINITY);
+    let mut upper = Vec2::new(f32::NEG_INFINITY, f32::NEG_INFINITY);
+    let mut add = |p: Vec2, r: f32| {
+        if p.is_valid() {
+            lower = Vec2::new(lower.x.min(p.x - r), lower.y.min(p.y - r));
+            upper = Vec2::new(upper.x.max(p.x + r), upper.y.max(p.y + r));
+        }
+    };
+    for cmd in cmds {
+        match cmd {
+            DebugDrawCmd::Polygon { vertices, .. } => {
+                vertices.iter().for_each(|&v| add(v, 0.0));
+            }
+            DebugDrawCmd::SolidPolygon {
+                transform,
+                vertices,
+                radius,
+                ..
+            } => {
+                for &v in vertices {
+                    add(transform.transform_point(v), *radius);
+                }
+            }
+            DebugDrawCmd::Circle { center, radius, .. } => add(*center, *radius),
+            DebugDrawCmd::SolidCircle {
+                transform, radius, ..
+            } => add(transform.position(), *radius),
+            DebugDrawCmd::SolidCapsule { p1, p2, radius, .. } => {
+                add(*p1, *radius);
+                add(*p2, *radius);
+            }
+            DebugDrawCmd::Segment { p1, p2, .. } => {
+                add(*p1, 0.0);
+                add(*p2, 0.0);
+            }
+            DebugDrawCmd::Transform(transform) => {
+                add(transform.position(), super::mesh::AXIS_LENGTH)
+            }
+            DebugDrawCmd::Point { p, .. } | DebugDrawCmd::String { p, .. } => add(*p, 0.0),
+        }
+    }
+    (lower.x <= upper.x && lower.y <= upper.y).then_some((lower, upper))
+}
+
+impl World {
+    /// Render the world's debug draw output into an SVG document with the default
+    /// [`SvgExport`] style.
+    pub fn debug_draw_svg(&mut self, opts: DebugDrawOptions) -> String {
+        SvgExport::default().render(&self.debug_draw_collect(opts))
+    }
+
+    /// Recoverable version of [`World::debug_draw_svg`].
+    pub fn try_debug_draw_svg(
+        &mut self,
+        opts: DebugDrawOptions,
+    ) -> crate::error::ApiResult<String> {
+        Ok(SvgExport::default().render(&self.try_debug_draw_collect(opts)?))
+    }
+}
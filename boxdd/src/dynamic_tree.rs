@@ -2,8 +2,13 @@
 //!
 //! The dynamic tree can organize spatial data that is not part of a Box2D world.
 //! Proxies store an AABB, category bits, and an opaque `u64` user data value.
+//! [`TypedDynamicTree`] keeps a typed value per proxy instead, for game entities indexed
+//! alongside the physics world.
 
-use std::{collections::BTreeSet, panic::AssertUnwindSafe};
+use std::{
+    collections::{BTreeSet, HashMap},
+    panic::AssertUnwindSafe,
+};
 
 use boxdd_sys::ffi;
 
@@ -531,6 +536,185 @@ impl Drop for DynamicTree {
     }
 }
 
+/// A [`DynamicTree`] that owns one `T` per proxy, for a spatial index of game entities that is
+/// not part of a Box2D world.
+///
+/// Queries and ray casts hand the value to the callback instead of the raw `u64` user data.
+/// Moving a proxy removes and reinserts it; call [`rebuild`](Self::rebuild) after large batches
+/// of moves to restore tree quality.
+///
+/// ```no_run
+/// use boxdd::{Aabb, TypedDynamicTree, Vec2};
+/// let mut pickups = TypedDynamicTree::new();
+/// let coin = pickups.insert(Aabb::from_center_half_extents([2.0, 1.0], [0.25, 0.25]), 1, "coin");
+/// pickups.move_proxy(coin, Aabb::from_center_half_extents([2.5, 1.0], [0.25, 0.25]));
+/// let near_player = Aabb::from_center_half_extents([2.0, 1.0], [1.0, 1.0]);
+/// pickups.query(near_player, u64::MAX, &mut |_, name| {
+///     println!("picked up {name}");
+///     true
+/// });
+/// ```
+pub struct TypedDynamicTree<T> {
+    tree: DynamicTree,
+    values: HashMap<TreeProxyId, T>,
+}
+
+impl<T> Default for TypedDynamicTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> TypedDynamicTree<T> {
+    /// Create an empty tree.
+    pub fn new() -> Self {
+        Self {
+            tree: DynamicTree::new(),
+            values: HashMap::new(),
+        }
+    }
+
+    /// Insert `value` with bounds `aabb` and return its proxy id.
+    pub fn insert(&mut self, aabb: Aabb, category_bits: u64, value: T) -> TreeProxyId {
+        let proxy = self.tree.create_proxy(aabb, category_bits, 0);
+        self.values.insert(proxy, value);
+        proxy
+    }
+
+    /// Insert with recoverable validation.
+    pub fn try_insert(
+        &mut self,
+        aabb: Aabb,
+        category_bits: u64,
+        value: T,
+    ) -> ApiResult<TreeProxyId> {
+        let proxy = self.tree.try_create_proxy(aabb, category_bits, 0)?;
+        self.values.insert(proxy, value);
+        Ok(proxy)
+    }
+
+    /// Remove a proxy and return its value, or `None` if it does not belong to this tree.
+    pub fn remove(&mut self, proxy: TreeProxyId) -> Option<T> {
+        let value = self.values.remove(&proxy)?;
+        self.tree.destroy_proxy(proxy);
+        Some(value)
+    }
+
+    /// Move a proxy to new bounds.
+    pub fn move_proxy(&mut self, proxy: TreeProxyId, aabb: Aabb) {
+        self.tree.move_proxy(proxy, aabb);
+    }
+
+    /// Move a proxy with recoverable validation.
+    pub fn try_move_proxy(&mut self, proxy: TreeProxyId, aabb: Aabb) -> ApiResult<()> {
+        self.tree.try_move_proxy(proxy, aabb)
+    }
+
+    /// Value stored for `proxy`.
+    pub fn get(&self, proxy: TreeProxyId) -> Option<&T> {
+        self.values.get(&proxy)
+    }
+
+    pub fn get_mut(&mut self, proxy: TreeProxyId) -> Option<&mut T> {
+        self.values.get_mut(&proxy)
+    }
+
+    /// Bounds of `proxy`, or `None` if it does not belong to this tree.
+    pub fn aabb(&self, proxy: TreeProxyId) -> Option<Aabb> {
+        self.tree.try_aabb(proxy).ok()
+    }
+
+    /// Number of stored values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Every proxy and its value, in no particular order.
+    pub fn iter(&self) -> impl Iterator<Item = (TreeProxyId, &T)> + '_ {
+        self.values.iter().map(|(&proxy, value)| (proxy, value))
+    }
+
+    /// Visit values whose bounds overlap `aabb` and whose category matches `mask_bits`.
+    /// Return `false` from `visit` to stop early.
+    pub fn query<F>(&self, aabb: Aabb, mask_bits: u64, visit: &mut F) -> TreeStats
+    where
+        F: FnMut(TreeProxyId, &T) -> bool,
+    {
+        let values = &self.values;
+        self.tree.query(aabb, mask_bits, &mut |proxy, _| {
+            visit(proxy, &values[&proxy])
+        })
+    }
+
+    /// Query with recoverable validation.
+    pub fn try_query<F>(&self, aabb: Aabb, mask_bits: u64, visit: &mut F) -> ApiResult<TreeStats>
+    where
+        F: FnMut(TreeProxyId, &T) -> bool,
+    {
+        let values = &self.values;
+        self.tree.try_query(aabb, mask_bits, &mut |proxy, _| {
+            visit(proxy, &values[&proxy])
+        })
+    }
+
+    /// Ray cast against the stored bounds. The callback returns the new maximum fraction, as
+    /// for [`DynamicTree::ray_cast`].
+    pub fn ray_cast<F>(
+        &self,
+        input: TreeRayCastInput,
+        mask_bits: u64,
+        callback: &mut F,
+    ) -> TreeStats
+    where
+        F: FnMut(TreeRayCastInput, TreeProxyId, &T) -> f32,
+    {
+        let values = &self.values;
+        self.tree
+            .ray_cast(input, mask_bits, &mut |input, proxy, _| {
+                callback(input, proxy, &values[&proxy])
+            })
+    }
+
+    /// Ray cast with recoverable validation.
+    pub fn try_ray_cast<F>(
+        &self,
+        input: TreeRayCastInput,
+        mask_bits: u64,
+        callback: &mut F,
+    ) -> ApiResult<TreeStats>
+    where
+        F: FnMut(TreeRayCastInput, TreeProxyId, &T) -> f32,
+    {
+        let values = &self.values;
+        self.tree
+            .try_ray_cast(input, mask_bits, &mut |input, proxy, _| {
+                callback(input, proxy, &values[&proxy])
+            })
+    }
+
+    /// Rebuild the tree for query performance; see [`DynamicTree::rebuild`].
+    pub fn rebuild(&mut self, full_build: bool) -> i32 {
+        self.tree.rebuild(full_build)
+    }
+
+    /// The underlying tree, for statistics and shape casts.
+    pub fn tree(&self) -> &DynamicTree {
+        &self.tree
+    }
+}
+
+impl<T> core::fmt::Debug for TypedDynamicTree<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TypedDynamicTree")
+            .field("len", &self.values.len())
+            .finish()
+    }
+}
+
 struct QueryCtx<'a, F> {
     callback: &'a mut F,
     stopped_early: bool,
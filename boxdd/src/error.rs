@@ -43,6 +43,9 @@ pub enum ApiError {
     #[error("invalid argument for this API")]
     InvalidArgument,
 
+    #[error("too many points for a polygon (at most MAX_POLYGON_VERTICES)")]
+    TooManyPolygonVertices,
+
     #[error("string contains an interior NUL byte")]
     NulByteInString,
 
@@ -51,4 +54,83 @@ pub enum ApiError {
 
     #[error("no free callback slot is available for material mixing callbacks")]
     CallbackSlotsExhausted,
+
+    #[error("the Box2D world is no longer valid")]
+    InvalidWorld,
+
+    /// A user callback panicked during a step; see [`StepError::CallbackPanicked`].
+    #[error("a Box2D callback panicked during the step")]
+    CallbackPanicked,
+}
+
+/// Descriptive error returned by `build_checked()` on definition builders.
+///
+/// Names the definition and field that failed so bad configuration (NaN gravity, negative
+/// density, inverted joint limits) is reported before it reaches Box2D. Converts into
+/// [`ApiError::InvalidArgument`] for use with `?` in `try_*` code.
+#[derive(Debug, thiserror::Error, Clone, PartialEq, Eq)]
+#[error("invalid {def}: {field} {message}")]
+pub struct ValidationError {
+    /// Definition type, e.g. `"BodyDef"`.
+    pub def: &'static str,
+    /// Offending field, e.g. `"linear_damping"`.
+    pub field: &'static str,
+    /// What the field must satisfy and the value it had.
+    pub message: String,
+}
+
+impl From<ValidationError> for ApiError {
+    fn from(_: ValidationError) -> Self {
+        ApiError::InvalidArgument
+    }
+}
+
+/// Error returned by [`World::try_step`](crate::World::try_step) and the stepping helpers built
+/// on it.
+///
+/// Converts into the matching [`ApiError`] for use with `?` in `try_*` code; the panic message
+/// of [`StepError::CallbackPanicked`] is lost in that conversion.
+#[non_exhaustive]
+#[derive(Debug, thiserror::Error, Clone, PartialEq)]
+pub enum StepError {
+    #[error("boxdd API called from a Box2D callback; Box2D world is locked")]
+    InCallback,
+
+    #[error("time_step must be finite and >= 0.0, got {0}")]
+    InvalidTimeStep(f32),
+
+    #[error("sub_steps must be > 0, got {0}")]
+    InvalidSubSteps(i32),
+
+    /// [`World::try_step_scaled`](crate::World::try_step_scaled) inputs out of range, or a
+    /// non-finite `real_dt * time_scale`.
+    #[error(
+        "invalid scaled step: real_dt {real_dt}, time_scale {time_scale}, max_substep_dt {max_substep_dt}"
+    )]
+    InvalidScaledStep {
+        real_dt: f32,
+        time_scale: f32,
+        max_substep_dt: f32,
+    },
+
+    #[error("the Box2D world is no longer valid")]
+    InvalidWorld,
+
+    /// A user callback (custom filter, pre-solve, material mixing) panicked during the step.
+    /// The step itself completed; the callback's result for that pair fell back to the default.
+    #[error("a Box2D callback panicked during the step: {0}")]
+    CallbackPanicked(String),
+}
+
+impl From<StepError> for ApiError {
+    fn from(e: StepError) -> Self {
+        match e {
+            StepError::InCallback => ApiError::InCallback,
+            StepError::InvalidWorld => ApiError::InvalidWorld,
+            StepError::CallbackPanicked(_) => ApiError::CallbackPanicked,
+            StepError::InvalidTimeStep(_)
+            | StepError::InvalidSubSteps(_)
+            | StepError::InvalidScaledStep { .. } => ApiError::InvalidArgument,
+        }
+    }
 }
@@ -10,6 +10,13 @@ pub struct BodyMoveEvent {
     pub fell_asleep: bool,
 }
 
+/// Sleep state a body changed to, from [`World::sleep_state_changes`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum SleepState {
+    Awake,
+    Asleep,
+}
+
 /// Zero-copy view wrapper for a body move event.
 /// Borrowed data is valid only within the closure passed to
 /// `with_body_events_view`.
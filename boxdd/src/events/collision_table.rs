@@ -0,0 +1,172 @@
+use std::collections::BTreeMap;
+
+use crate::types::{BodyId, ShapeId};
+use crate::world::World;
+
+use super::ContactEvents;
+
+/// Body-level touch bookkeeping maintained from contact begin/end events.
+///
+/// Box2D reports touching per shape pair, so two bodies can touch through several shape pairs at
+/// once; `CollisionTable` counts them and only reports the bodies as separated when the last pair
+/// ends. Shapes need `enable_contact_events` for their contacts to be tracked.
+///
+/// Feed it once per step with [`CollisionTable::update`] (or [`CollisionTable::apply`] if you
+/// already took a [`ContactEvents`] snapshot). Box2D emits end events when a touching shape or
+/// body is destroyed, and `update` additionally drops pairs whose shapes are no longer valid, so
+/// destroyed objects never linger.
+///
+/// ```no_run
+/// use boxdd::{BodyBuilder, CollisionTable, World, WorldDef};
+/// let mut world = World::new(WorldDef::default()).unwrap();
+/// let ground = world.create_body_id(BodyBuilder::new().build());
+/// let player = world.create_body_id(BodyBuilder::new().build());
+/// let mut touching = CollisionTable::new();
+/// world.step(1.0 / 60.0, 4);
+/// touching.update(&world);
+/// let grounded = touching.is_touching(player, ground);
+/// # let _ = grounded;
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CollisionTable {
+    /// Touching shape pairs (ordered) and the bodies they belonged to when they began touching.
+    shape_pairs: BTreeMap<(ShapeId, ShapeId), (BodyId, BodyId)>,
+    /// Number of touching shape pairs per body pair (ordered).
+    body_pairs: BTreeMap<(BodyId, BodyId), u32>,
+}
+
+#[inline]
+fn ordered<T: Ord>(a: T, b: T) -> (T, T) {
+    if a <= b { (a, b) } else { (b, a) }
+}
+
+impl CollisionTable {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Read this step's contact events from `world`, apply them, and drop pairs whose shapes have
+    /// been destroyed.
+    pub fn update(&mut self, world: &World) {
+        self.apply(&world.contact_events());
+        self.retain_valid();
+    }
+
+    /// Apply a contact event snapshot. Begin events for shapes that are already gone are ignored.
+    pub fn apply(&mut self, events: &ContactEvents) {
+        for e in &events.end {
+            self.end_shape_pair(e.shape_a, e.shape_b);
+        }
+        for e in &events.begin {
+            if !crate::shapes::shape_is_valid_impl(e.shape_a)
+                || !crate::shapes::shape_is_valid_impl(e.shape_b)
+            {
+                continue;
+            }
+            let key = ordered(e.shape_a, e.shape_b);
+            if self.shape_pairs.contains_key(&key) {
+                continue;
+            }
+            let bodies = ordered(
+                crate::shapes::shape_body_id_impl(key.0),
+                crate::shapes::shape_body_id_impl(key.1),
+            );
+            self.shape_pairs.insert(key, bodies);
+            *self.body_pairs.entry(bodies).or_insert(0) += 1;
+        }
+    }
+
+    fn end_shape_pair(&mut self, a: ShapeId, b: ShapeId) {
+        let Some(bodies) = self.shape_pairs.remove(&ordered(a, b)) else {
+            return;
+        };
+        if let Some(count) = self.body_pairs.get_mut(&bodies) {
+            *count -= 1;
+            if *count == 0 {
+                self.body_pairs.remove(&bodies);
+            }
+        }
+    }
+
+    /// Drop pairs involving shapes that are no longer valid.
+    pub fn retain_valid(&mut self) {
+        let stale: Vec<(ShapeId, ShapeId)> = self
+            .shape_pairs
+            .keys()
+            .copied()
+            .filter(|&(a, b)| {
+                !crate::shapes::shape_is_valid_impl(a) || !crate::shapes::shape_is_valid_impl(b)
+            })
+            .collect();
+        for (a, b) in stale {
+            self.end_shape_pair(a, b);
+        }
+    }
+
+    /// Forget every pair involving `body`, e.g. right after destroying it without reading events.
+    pub fn remove_body(&mut self, body: BodyId) {
+        let stale: Vec<(ShapeId, ShapeId)> = self
+            .shape_pairs
+            .iter()
+            .filter(|(_, (a, b))| *a == body || *b == body)
+            .map(|(&key, _)| key)
+            .collect();
+        for (a, b) in stale {
+            self.end_shape_pair(a, b);
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.shape_pairs.clear();
+        self.body_pairs.clear();
+    }
+
+    /// Whether any shape of `a` touches any shape of `b`.
+    pub fn is_touching(&self, a: BodyId, b: BodyId) -> bool {
+        self.body_pairs.contains_key(&ordered(a, b))
+    }
+
+    /// Whether the two shapes touch.
+    pub fn is_shape_touching(&self, a: ShapeId, b: ShapeId) -> bool {
+        self.shape_pairs.contains_key(&ordered(a, b))
+    }
+
+    /// Bodies touching `body`, sorted.
+    pub fn touching_bodies(&self, body: BodyId) -> Vec<BodyId> {
+        let mut out: Vec<BodyId> = self
+            .body_pairs
+            .keys()
+            .filter_map(|&(a, b)| {
+                if a == body {
+                    Some(b)
+                } else if b == body {
+                    Some(a)
+                } else {
+                    None
+                }
+            })
+            .collect();
+        out.sort_unstable();
+        out
+    }
+
+    /// Touching body pairs with the number of touching shape pairs between them, each pair
+    /// ordered and the whole list sorted.
+    pub fn pairs(&self) -> impl Iterator<Item = (BodyId, BodyId, u32)> + '_ {
+        self.body_pairs.iter().map(|(&(a, b), &n)| (a, b, n))
+    }
+
+    /// Touching shape pairs, each pair ordered and the whole list sorted.
+    pub fn shape_pairs(&self) -> impl Iterator<Item = (ShapeId, ShapeId)> + '_ {
+        self.shape_pairs.keys().copied()
+    }
+
+    /// Number of touching body pairs.
+    pub fn len(&self) -> usize {
+        self.body_pairs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.body_pairs.is_empty()
+    }
+}
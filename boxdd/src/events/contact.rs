@@ -49,6 +49,34 @@ impl<'a> ContactHit<'a> {
     pub fn approach_speed(&self) -> f32 {
         self.0.approachSpeed
     }
+    /// The contact may have been destroyed by the time the event is read.
+    pub fn contact_id(&self) -> ContactId {
+        ContactId::from_raw(self.0.contactId)
+    }
+    /// See [`ContactHitEvent::impulse_estimate`].
+    pub fn impulse_estimate(&self, mass: f32) -> f32 {
+        hit_impulse_estimate(mass, self.0.approachSpeed)
+    }
+    /// Copy the event out of the borrowed buffer.
+    pub fn to_event(self) -> ContactHitEvent {
+        contact_hit_event_from_raw(self.0)
+    }
+}
+
+#[inline]
+fn hit_impulse_estimate(mass: f32, approach_speed: f32) -> f32 {
+    mass * approach_speed
+}
+
+fn contact_hit_event_from_raw(e: &ffi::b2ContactHitEvent) -> ContactHitEvent {
+    ContactHitEvent {
+        shape_a: ShapeId::from_raw(e.shapeIdA),
+        shape_b: ShapeId::from_raw(e.shapeIdB),
+        contact_id: ContactId::from_raw(e.contactId),
+        point: Vec2::from_raw(e.point),
+        normal: Vec2::from_raw(e.normal),
+        approach_speed: e.approachSpeed,
+    }
 }
 
 pub struct BeginIter<'a>(core::slice::Iter<'a, ffi::b2ContactBeginTouchEvent>);
@@ -98,14 +126,32 @@ pub struct ContactEndTouchEvent {
 }
 
 #[derive(Clone, Debug)]
+#[non_exhaustive]
 pub struct ContactHitEvent {
     pub shape_a: ShapeId,
     pub shape_b: ShapeId,
+    /// The contact may have been destroyed by the time the event is read.
+    pub contact_id: ContactId,
+    /// Mid-point between the two surfaces at the start of the step (possibly a speculative point
+    /// where they were not yet touching).
     pub point: Vec2,
+    /// Unit normal pointing from shape A to shape B.
     pub normal: Vec2,
+    /// Closing speed along the normal in m/s, always above the world's hit event threshold.
     pub approach_speed: f32,
 }
 
+impl ContactHitEvent {
+    /// Rough impulse magnitude (N·s) of the hit: `mass * approach_speed`, the momentum needed to
+    /// stop `mass` along the normal. Good enough to scale sounds, particles, or damage.
+    ///
+    /// For a hit between two dynamic bodies pass the reduced mass
+    /// `mass_a * mass_b / (mass_a + mass_b)`; against static geometry pass the moving body's mass.
+    pub fn impulse_estimate(&self, mass: f32) -> f32 {
+        hit_impulse_estimate(mass, self.approach_speed)
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct ContactEvents {
     pub begin: Vec<ContactBeginTouchEvent>,
@@ -140,13 +186,7 @@ fn contact_events_into_impl(world: ffi::b2WorldId, out: &mut ContactEvents) {
         shape_a: ShapeId::from_raw(e.shapeIdA),
         shape_b: ShapeId::from_raw(e.shapeIdB),
     });
-    super::map_snapshot_into(&mut out.hit, hit, |e| ContactHitEvent {
-        shape_a: ShapeId::from_raw(e.shapeIdA),
-        shape_b: ShapeId::from_raw(e.shapeIdB),
-        point: Vec2::from_raw(e.point),
-        normal: Vec2::from_raw(e.normal),
-        approach_speed: e.approachSpeed,
-    });
+    super::map_snapshot_into(&mut out.hit, hit, contact_hit_event_from_raw);
 }
 
 fn contact_events_snapshot_impl(world: ffi::b2WorldId) -> ContactEvents {
@@ -0,0 +1,140 @@
+use std::collections::BTreeSet;
+
+use crate::types::ShapeId;
+use crate::world::World;
+
+use super::ContactEvents;
+
+/// Touching shape pairs with per-step enter/stay/exit sets, maintained from contact events.
+///
+/// Each [`ContactTracker::update`] (or [`ContactTracker::apply`]) starts a new step: pairs that
+/// began touching land in [`pairs_entered`](Self::pairs_entered), pairs that stopped in
+/// [`pairs_exited`](Self::pairs_exited), and pairs that were already touching and still are in
+/// [`pairs_staying`](Self::pairs_staying). Pairs are ordered shape id tuples and each list is
+/// sorted. Shapes need `enable_contact_events` for their contacts to be tracked.
+///
+/// Begin events for shapes that are already gone are ignored, and `update` reports pairs whose
+/// shapes were destroyed without an end event as exited, so stale ids never linger. Use
+/// [`crate::CollisionTable`] when only body-level touching matters.
+///
+/// ```no_run
+/// use boxdd::{ContactTracker, World, WorldDef};
+/// let mut world = World::new(WorldDef::default()).unwrap();
+/// let mut contacts = ContactTracker::new();
+/// world.step(1.0 / 60.0, 4);
+/// contacts.update(&world);
+/// for &(a, b) in contacts.pairs_entered() {
+///     println!("{a:?} started touching {b:?}");
+/// }
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct ContactTracker {
+    touching: BTreeSet<(ShapeId, ShapeId)>,
+    entered: Vec<(ShapeId, ShapeId)>,
+    exited: Vec<(ShapeId, ShapeId)>,
+}
+
+#[inline]
+fn ordered(a: ShapeId, b: ShapeId) -> (ShapeId, ShapeId) {
+    if a <= b { (a, b) } else { (b, a) }
+}
+
+impl ContactTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start a new step: read this step's contact events from `world`, apply them, and report
+    /// pairs whose shapes have been destroyed as exited.
+    pub fn update(&mut self, world: &World) {
+        self.apply(&world.contact_events());
+        self.retain_valid();
+    }
+
+    /// Start a new step from a contact event snapshot.
+    pub fn apply(&mut self, events: &ContactEvents) {
+        self.entered.clear();
+        self.exited.clear();
+        for e in &events.end {
+            let key = ordered(e.shape_a, e.shape_b);
+            if self.touching.remove(&key) {
+                self.exited.push(key);
+            }
+        }
+        for e in &events.begin {
+            if !crate::shapes::shape_is_valid_impl(e.shape_a)
+                || !crate::shapes::shape_is_valid_impl(e.shape_b)
+            {
+                continue;
+            }
+            let key = ordered(e.shape_a, e.shape_b);
+            if self.touching.insert(key) {
+                self.entered.push(key);
+            }
+        }
+        self.entered.sort_unstable();
+        self.exited.sort_unstable();
+    }
+
+    /// Move pairs involving shapes that are no longer valid to this step's exited set.
+    pub fn retain_valid(&mut self) {
+        let before = self.exited.len();
+        self.touching.retain(|&(a, b)| {
+            let valid =
+                crate::shapes::shape_is_valid_impl(a) && crate::shapes::shape_is_valid_impl(b);
+            if !valid {
+                self.exited.push((a, b));
+            }
+            valid
+        });
+        if self.exited.len() != before {
+            self.entered.retain(|key| self.touching.contains(key));
+            self.exited.sort_unstable();
+            self.exited.dedup();
+        }
+    }
+
+    /// Forget every pair and this step's sets.
+    pub fn clear(&mut self) {
+        self.touching.clear();
+        self.entered.clear();
+        self.exited.clear();
+    }
+
+    /// Pairs that began touching this step.
+    pub fn pairs_entered(&self) -> &[(ShapeId, ShapeId)] {
+        &self.entered
+    }
+
+    /// Pairs that stopped touching this step, including pairs whose shapes were destroyed.
+    pub fn pairs_exited(&self) -> &[(ShapeId, ShapeId)] {
+        &self.exited
+    }
+
+    /// Pairs that were touching before this step and still are.
+    pub fn pairs_staying(&self) -> impl Iterator<Item = (ShapeId, ShapeId)> + '_ {
+        self.touching
+            .iter()
+            .copied()
+            .filter(|key| self.entered.binary_search(key).is_err())
+    }
+
+    /// All pairs touching after this step (entered and staying).
+    pub fn pairs_touching(&self) -> impl Iterator<Item = (ShapeId, ShapeId)> + '_ {
+        self.touching.iter().copied()
+    }
+
+    /// Whether the two shapes touch.
+    pub fn is_touching(&self, a: ShapeId, b: ShapeId) -> bool {
+        self.touching.contains(&ordered(a, b))
+    }
+
+    /// Number of touching pairs.
+    pub fn len(&self) -> usize {
+        self.touching.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.touching.is_empty()
+    }
+}
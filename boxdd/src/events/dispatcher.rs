@@ -0,0 +1,231 @@
+//! Typed event handlers drained after each step.
+//!
+//! [`EventDispatcher`] owns one list of handlers per event kind. [`World::step_with_events`]
+//! steps the world, copies the event kinds that have handlers into reusable buffers, and calls
+//! the handlers with `&mut World`, so they can destroy bodies or spawn new ones without the
+//! zero-copy views' restrictions.
+
+use super::{
+    BodyMoveEvent, ContactBeginTouchEvent, ContactEndTouchEvent, ContactEvents, ContactHitEvent,
+    JointEvent, SensorBeginTouchEvent, SensorEndTouchEvent, SensorEvents,
+};
+use crate::error::ApiResult;
+use crate::world::World;
+
+type Handler<E> = Box<dyn FnMut(&mut World, &E)>;
+
+/// Typed handlers for the per-step event buffers.
+///
+/// Handlers run in this order: body moves, contact begin, contact hit, contact end, sensor
+/// enter, sensor exit, joint events; within a kind, in registration order. Event kinds without
+/// handlers are not read.
+///
+/// ```no_run
+/// use boxdd::{EventDispatcher, World, WorldDef};
+/// let mut world = World::new(WorldDef::default()).unwrap();
+/// let mut events = EventDispatcher::new()
+///     .on_contact_begin(|_world, e| println!("{:?} touched {:?}", e.shape_a, e.shape_b))
+///     .on_sensor_enter(|world, e| {
+///         if let Some(body) = e.visitor_body {
+///             world.destroy_body_id(body);
+///         }
+///     });
+/// world.step_with_events(1.0 / 60.0, 4, &mut events);
+/// ```
+#[derive(Default)]
+pub struct EventDispatcher {
+    body_move: Vec<Handler<BodyMoveEvent>>,
+    contact_begin: Vec<Handler<ContactBeginTouchEvent>>,
+    contact_hit: Vec<Handler<ContactHitEvent>>,
+    contact_end: Vec<Handler<ContactEndTouchEvent>>,
+    sensor_enter: Vec<Handler<SensorBeginTouchEvent>>,
+    sensor_exit: Vec<Handler<SensorEndTouchEvent>>,
+    joint: Vec<Handler<JointEvent>>,
+    bodies: Vec<BodyMoveEvent>,
+    contacts: ContactEvents,
+    sensors: SensorEvents,
+    joints: Vec<JointEvent>,
+}
+
+impl core::fmt::Debug for EventDispatcher {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EventDispatcher")
+            .field("body_move", &self.body_move.len())
+            .field("contact_begin", &self.contact_begin.len())
+            .field("contact_hit", &self.contact_hit.len())
+            .field("contact_end", &self.contact_end.len())
+            .field("sensor_enter", &self.sensor_enter.len())
+            .field("sensor_exit", &self.sensor_exit.len())
+            .field("joint", &self.joint.len())
+            .finish()
+    }
+}
+
+impl EventDispatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Handle body move events (bodies that moved this step, including falling asleep).
+    pub fn on_body_move(mut self, f: impl FnMut(&mut World, &BodyMoveEvent) + 'static) -> Self {
+        self.body_move.push(Box::new(f));
+        self
+    }
+
+    /// Handle contacts that started touching. Requires contact events on the shapes.
+    pub fn on_contact_begin(
+        mut self,
+        f: impl FnMut(&mut World, &ContactBeginTouchEvent) + 'static,
+    ) -> Self {
+        self.contact_begin.push(Box::new(f));
+        self
+    }
+
+    /// Handle contacts that stopped touching.
+    pub fn on_contact_end(
+        mut self,
+        f: impl FnMut(&mut World, &ContactEndTouchEvent) + 'static,
+    ) -> Self {
+        self.contact_end.push(Box::new(f));
+        self
+    }
+
+    /// Handle impacts above the world's hit event threshold. Requires hit events on the shapes.
+    pub fn on_contact_hit(mut self, f: impl FnMut(&mut World, &ContactHitEvent) + 'static) -> Self {
+        self.contact_hit.push(Box::new(f));
+        self
+    }
+
+    /// Handle shapes entering a sensor.
+    pub fn on_sensor_enter(
+        mut self,
+        f: impl FnMut(&mut World, &SensorBeginTouchEvent) + 'static,
+    ) -> Self {
+        self.sensor_enter.push(Box::new(f));
+        self
+    }
+
+    /// Handle shapes leaving a sensor, including when either shape is destroyed.
+    pub fn on_sensor_exit(
+        mut self,
+        f: impl FnMut(&mut World, &SensorEndTouchEvent) + 'static,
+    ) -> Self {
+        self.sensor_exit.push(Box::new(f));
+        self
+    }
+
+    /// Handle joints whose force or torque exceeded their threshold, the usual place to break
+    /// them with `World::destroy_joint_id`.
+    #[doc(alias = "on_joint_break")]
+    pub fn on_joint_event(mut self, f: impl FnMut(&mut World, &JointEvent) + 'static) -> Self {
+        self.joint.push(Box::new(f));
+        self
+    }
+
+    /// Whether no handlers are registered.
+    pub fn is_empty(&self) -> bool {
+        self.body_move.is_empty()
+            && self.contact_begin.is_empty()
+            && self.contact_hit.is_empty()
+            && self.contact_end.is_empty()
+            && self.sensor_enter.is_empty()
+            && self.sensor_exit.is_empty()
+            && self.joint.is_empty()
+    }
+
+    /// Remove all handlers, keeping the event buffers' capacity.
+    pub fn clear(&mut self) {
+        self.body_move.clear();
+        self.contact_begin.clear();
+        self.contact_hit.clear();
+        self.contact_end.clear();
+        self.sensor_enter.clear();
+        self.sensor_exit.clear();
+        self.joint.clear();
+    }
+
+    /// Read the events of the last step and run the handlers. Returns the number of events
+    /// delivered (each event counted once, however many handlers saw it).
+    ///
+    /// Panics if called inside a Box2D callback.
+    pub fn dispatch(&mut self, world: &mut World) -> usize {
+        crate::core::callback_state::assert_not_in_callback();
+        self.dispatch_impl(world)
+    }
+
+    /// Recoverable version of [`Self::dispatch`].
+    pub fn try_dispatch(&mut self, world: &mut World) -> ApiResult<usize> {
+        crate::core::callback_state::check_not_in_callback()?;
+        Ok(self.dispatch_impl(world))
+    }
+
+    fn dispatch_impl(&mut self, world: &mut World) -> usize {
+        // Copy everything first: handlers may destroy entities or step, invalidating the buffers.
+        let want_contacts = !(self.contact_begin.is_empty()
+            && self.contact_hit.is_empty()
+            && self.contact_end.is_empty());
+        let want_sensors = !(self.sensor_enter.is_empty() && self.sensor_exit.is_empty());
+        if !self.body_move.is_empty() {
+            world.body_events_into(&mut self.bodies);
+        }
+        if want_contacts {
+            world.contact_events_into(&mut self.contacts);
+        }
+        if want_sensors {
+            world.sensor_events_into(&mut self.sensors);
+        }
+        if !self.joint.is_empty() {
+            world.joint_events_into(&mut self.joints);
+        }
+        let mut delivered = 0;
+        delivered += run(world, &mut self.body_move, &self.bodies);
+        delivered += run(world, &mut self.contact_begin, &self.contacts.begin);
+        delivered += run(world, &mut self.contact_hit, &self.contacts.hit);
+        delivered += run(world, &mut self.contact_end, &self.contacts.end);
+        delivered += run(world, &mut self.sensor_enter, &self.sensors.begin);
+        delivered += run(world, &mut self.sensor_exit, &self.sensors.end);
+        delivered += run(world, &mut self.joint, &self.joints);
+        delivered
+    }
+}
+
+fn run<E>(world: &mut World, handlers: &mut [Handler<E>], events: &[E]) -> usize {
+    if handlers.is_empty() {
+        return 0;
+    }
+    for event in events {
+        for handler in handlers.iter_mut() {
+            handler(world, event);
+        }
+    }
+    events.len()
+}
+
+impl World {
+    /// Step the world, then deliver the step's events to `dispatcher`'s handlers.
+    ///
+    /// Returns the number of events delivered. Equivalent to [`World::step`] followed by
+    /// [`EventDispatcher::dispatch`].
+    pub fn step_with_events(
+        &mut self,
+        time_step: f32,
+        sub_steps: i32,
+        dispatcher: &mut EventDispatcher,
+    ) -> usize {
+        self.step(time_step, sub_steps);
+        dispatcher.dispatch(self)
+    }
+
+    /// Recoverable version of [`World::step_with_events`], reporting errors like
+    /// [`World::try_step`]. Events are not delivered when the step fails, including when a
+    /// callback panicked; they stay readable until the next step.
+    pub fn try_step_with_events(
+        &mut self,
+        time_step: f32,
+        sub_steps: i32,
+        dispatcher: &mut EventDispatcher,
+    ) -> Result<usize, crate::error::StepError> {
+        self.try_step(time_step, sub_steps)?;
+        Ok(dispatcher.dispatch_impl(self))
+    }
+}
@@ -9,6 +9,8 @@
 //! - Owned snapshot getters are available on both [`crate::World`] and `WorldHandle`.
 //! - Borrowed zero-copy views and raw event-buffer access intentionally stay on [`crate::World`]:
 //!   they are tied to completed-step world buffers and the world's deferred-destroy flush semantics.
+//! - [`EventDispatcher`] registers typed handlers that [`crate::World::step_with_events`] calls
+//!   after each step.
 
 #[inline]
 fn map_snapshot_into<TRaw, T>(out: &mut Vec<T>, slice: &[TRaw], map: impl FnMut(&TRaw) -> T) {
@@ -20,14 +22,22 @@ fn map_snapshot_into<TRaw, T>(out: &mut Vec<T>, slice: &[TRaw], map: impl FnMut(
 }
 
 mod body;
+mod collision_table;
 mod contact;
+mod contact_tracker;
+mod dispatcher;
 mod joint;
 mod sensor;
+mod summary;
 
-pub use body::BodyMoveEvent;
+pub use body::{BodyMoveEvent, SleepState};
+pub use collision_table::CollisionTable;
 pub use contact::{ContactBeginTouchEvent, ContactEndTouchEvent, ContactEvents, ContactHitEvent};
+pub use contact_tracker::ContactTracker;
+pub use dispatcher::EventDispatcher;
 pub use joint::JointEvent;
 pub use sensor::{SensorBeginTouchEvent, SensorEndTouchEvent, SensorEvents};
+pub use summary::EventCounts;
 
 #[cfg(test)]
 mod tests {
@@ -66,6 +76,7 @@ mod tests {
             ApiError::InCallback
         );
         assert_eq!(world.try_joint_events().unwrap_err(), ApiError::InCallback);
+        assert_eq!(world.try_event_summary().unwrap_err(), ApiError::InCallback);
         assert_eq!(
             world.try_joint_events_into(&mut joint_events).unwrap_err(),
             ApiError::InCallback
@@ -96,6 +107,10 @@ mod tests {
             ApiError::InCallback
         );
         assert_eq!(handle.try_joint_events().unwrap_err(), ApiError::InCallback);
+        assert_eq!(
+            handle.try_event_summary().unwrap_err(),
+            ApiError::InCallback
+        );
         assert_eq!(
             handle.try_joint_events_into(&mut joint_events).unwrap_err(),
             ApiError::InCallback
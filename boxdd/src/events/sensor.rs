@@ -1,7 +1,17 @@
-use crate::types::ShapeId;
+use crate::types::{BodyId, ShapeId};
 use crate::world::{World, WorldHandle};
 use boxdd_sys::ffi;
 
+/// Body owning `shape`, or `None` if the shape has since been destroyed.
+#[inline]
+fn shape_body_or_none(shape: ffi::b2ShapeId) -> Option<BodyId> {
+    if unsafe { ffi::b2Shape_IsValid(shape) } {
+        Some(BodyId::from_raw(unsafe { ffi::b2Shape_GetBody(shape) }))
+    } else {
+        None
+    }
+}
+
 /// Zero-copy view wrappers for sensor events.
 /// Data is borrowed and valid only for the duration of the closure passed
 /// to `with_sensor_events_view`.
@@ -14,6 +24,14 @@ impl<'a> SensorBeginTouch<'a> {
     pub fn visitor_shape(&self) -> ShapeId {
         ShapeId::from_raw(self.0.visitorShapeId)
     }
+    /// Body owning the sensor shape, or `None` if the shape is no longer valid.
+    pub fn sensor_body_id(&self) -> Option<BodyId> {
+        shape_body_or_none(self.0.sensorShapeId)
+    }
+    /// Body owning the visitor shape, or `None` if the shape is no longer valid.
+    pub fn visitor_body_id(&self) -> Option<BodyId> {
+        shape_body_or_none(self.0.visitorShapeId)
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -25,9 +43,25 @@ impl<'a> SensorEndTouch<'a> {
     pub fn visitor_shape(&self) -> ShapeId {
         ShapeId::from_raw(self.0.visitorShapeId)
     }
+    /// Body owning the sensor shape, or `None` if the shape is no longer valid.
+    pub fn sensor_body_id(&self) -> Option<BodyId> {
+        shape_body_or_none(self.0.sensorShapeId)
+    }
+    /// Body owning the visitor shape, or `None` if the shape is no longer valid.
+    pub fn visitor_body_id(&self) -> Option<BodyId> {
+        shape_body_or_none(self.0.visitorShapeId)
+    }
 }
 
 pub struct SensorBeginIter<'a>(core::slice::Iter<'a, ffi::b2SensorBeginTouchEvent>);
+impl<'a> SensorBeginIter<'a> {
+    /// Iterate `(sensor_body, visitor_body)` pairs, skipping events whose shapes are no longer
+    /// valid (e.g. destroyed after the step).
+    pub fn bodies(self) -> impl Iterator<Item = (BodyId, BodyId)> + 'a {
+        self.filter_map(|e| Some((e.sensor_body_id()?, e.visitor_body_id()?)))
+    }
+}
+
 impl<'a> Iterator for SensorBeginIter<'a> {
     type Item = SensorBeginTouch<'a>;
     fn next(&mut self) -> Option<Self::Item> {
@@ -39,6 +73,14 @@ impl<'a> Iterator for SensorBeginIter<'a> {
 }
 
 pub struct SensorEndIter<'a>(core::slice::Iter<'a, ffi::b2SensorEndTouchEvent>);
+impl<'a> SensorEndIter<'a> {
+    /// Iterate `(sensor_body, visitor_body)` pairs, skipping events whose shapes are no longer
+    /// valid (e.g. destroyed after the step).
+    pub fn bodies(self) -> impl Iterator<Item = (BodyId, BodyId)> + 'a {
+        self.filter_map(|e| Some((e.sensor_body_id()?, e.visitor_body_id()?)))
+    }
+}
+
 impl<'a> Iterator for SensorEndIter<'a> {
     type Item = SensorEndTouch<'a>;
     fn next(&mut self) -> Option<Self::Item> {
@@ -53,12 +95,40 @@ impl<'a> Iterator for SensorEndIter<'a> {
 pub struct SensorBeginTouchEvent {
     pub sensor_shape: ShapeId,
     pub visitor_shape: ShapeId,
+    /// Body owning `sensor_shape`, resolved when the event was read.
+    pub sensor_body: Option<BodyId>,
+    /// Body owning `visitor_shape`, resolved when the event was read.
+    pub visitor_body: Option<BodyId>,
 }
 
+impl SensorBeginTouchEvent {
+    pub fn sensor_body_id(&self) -> Option<BodyId> {
+        self.sensor_body
+    }
+    pub fn visitor_body_id(&self) -> Option<BodyId> {
+        self.visitor_body
+    }
+}
+
+/// End events are also reported when the sensor or visitor is destroyed, so the body ids are
+/// `None` when the shape was already gone when the event was read.
 #[derive(Clone, Debug)]
 pub struct SensorEndTouchEvent {
     pub sensor_shape: ShapeId,
     pub visitor_shape: ShapeId,
+    /// Body owning `sensor_shape`, resolved when the event was read.
+    pub sensor_body: Option<BodyId>,
+    /// Body owning `visitor_shape`, resolved when the event was read.
+    pub visitor_body: Option<BodyId>,
+}
+
+impl SensorEndTouchEvent {
+    pub fn sensor_body_id(&self) -> Option<BodyId> {
+        self.sensor_body
+    }
+    pub fn visitor_body_id(&self) -> Option<BodyId> {
+        self.visitor_body
+    }
 }
 
 #[derive(Clone, Debug, Default)]
@@ -67,6 +137,22 @@ pub struct SensorEvents {
     pub end: Vec<SensorEndTouchEvent>,
 }
 
+impl SensorEvents {
+    /// `(sensor_body, visitor_body)` pairs of begin events whose shapes were valid when read.
+    pub fn begin_bodies(&self) -> impl Iterator<Item = (BodyId, BodyId)> + '_ {
+        self.begin
+            .iter()
+            .filter_map(|e| Some((e.sensor_body?, e.visitor_body?)))
+    }
+
+    /// `(sensor_body, visitor_body)` pairs of end events whose shapes were valid when read.
+    pub fn end_bodies(&self) -> impl Iterator<Item = (BodyId, BodyId)> + '_ {
+        self.end
+            .iter()
+            .filter_map(|e| Some((e.sensor_body?, e.visitor_body?)))
+    }
+}
+
 fn sensor_events_into_impl(world: ffi::b2WorldId, out: &mut SensorEvents) {
     let raw = unsafe { ffi::b2World_GetSensorEvents(world) };
     let begin = if raw.beginCount > 0 && !raw.beginEvents.is_null() {
@@ -83,10 +169,14 @@ fn sensor_events_into_impl(world: ffi::b2WorldId, out: &mut SensorEvents) {
     super::map_snapshot_into(&mut out.begin, begin, |e| SensorBeginTouchEvent {
         sensor_shape: ShapeId::from_raw(e.sensorShapeId),
         visitor_shape: ShapeId::from_raw(e.visitorShapeId),
+        sensor_body: shape_body_or_none(e.sensorShapeId),
+        visitor_body: shape_body_or_none(e.visitorShapeId),
     });
     super::map_snapshot_into(&mut out.end, end, |e| SensorEndTouchEvent {
         sensor_shape: ShapeId::from_raw(e.sensorShapeId),
         visitor_shape: ShapeId::from_raw(e.visitorShapeId),
+        sensor_body: shape_body_or_none(e.sensorShapeId),
+        visitor_body: shape_body_or_none(e.visitorShapeId),
     });
 }
 
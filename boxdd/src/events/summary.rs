@@ -0,0 +1,95 @@
+use crate::world::{World, WorldHandle};
+use boxdd_sys::ffi;
+
+/// Number of events of each kind produced by the last step.
+///
+/// Reads only the counts from Box2D's event buffers, so it is cheap enough to call every frame
+/// for HUDs and profiling overlays; use the snapshot or view APIs when you need the payloads.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct EventCounts {
+    pub contacts_begun: usize,
+    pub contacts_ended: usize,
+    pub hits: usize,
+    pub sensors_begun: usize,
+    pub sensors_ended: usize,
+    pub moves: usize,
+    pub joint_events: usize,
+}
+
+impl EventCounts {
+    /// Sum of all counts.
+    pub const fn total(&self) -> usize {
+        self.contacts_begun
+            + self.contacts_ended
+            + self.hits
+            + self.sensors_begun
+            + self.sensors_ended
+            + self.moves
+            + self.joint_events
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.total() == 0
+    }
+}
+
+#[inline]
+fn count(n: core::ffi::c_int) -> usize {
+    n.max(0) as usize
+}
+
+fn event_summary_impl(world: ffi::b2WorldId) -> EventCounts {
+    let contacts = unsafe { ffi::b2World_GetContactEvents(world) };
+    let sensors = unsafe { ffi::b2World_GetSensorEvents(world) };
+    let bodies = unsafe { ffi::b2World_GetBodyEvents(world) };
+    let joints = unsafe { ffi::b2World_GetJointEvents(world) };
+    EventCounts {
+        contacts_begun: count(contacts.beginCount),
+        contacts_ended: count(contacts.endCount),
+        hits: count(contacts.hitCount),
+        sensors_begun: count(sensors.beginCount),
+        sensors_ended: count(sensors.endCount),
+        moves: count(bodies.moveCount),
+        joint_events: count(joints.count),
+    }
+}
+
+fn event_summary_checked_impl(world: ffi::b2WorldId) -> EventCounts {
+    crate::core::callback_state::assert_not_in_callback();
+    event_summary_impl(world)
+}
+
+fn try_event_summary_impl(world: ffi::b2WorldId) -> crate::error::ApiResult<EventCounts> {
+    crate::core::callback_state::check_not_in_callback()?;
+    Ok(event_summary_impl(world))
+}
+
+impl World {
+    /// Event counts from the last step without copying any event payloads.
+    ///
+    /// ```rust
+    /// use boxdd::prelude::*;
+    /// let mut world = World::new(WorldDef::default()).unwrap();
+    /// world.step(1.0 / 60.0, 4);
+    /// let counts = world.event_summary();
+    /// println!("contacts +{} -{}", counts.contacts_begun, counts.contacts_ended);
+    /// ```
+    pub fn event_summary(&self) -> EventCounts {
+        event_summary_checked_impl(self.raw())
+    }
+
+    pub fn try_event_summary(&self) -> crate::error::ApiResult<EventCounts> {
+        try_event_summary_impl(self.raw())
+    }
+}
+
+impl WorldHandle {
+    pub fn event_summary(&self) -> EventCounts {
+        event_summary_checked_impl(self.raw())
+    }
+
+    pub fn try_event_summary(&self) -> crate::error::ApiResult<EventCounts> {
+        try_event_summary_impl(self.raw())
+    }
+}
@@ -1,13 +1,168 @@
+use crate::error::{ApiError, ApiResult};
+use crate::types::JointId;
 use boxdd_sys::ffi;
+use core::fmt;
+use core::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Not};
 
+/// A set of collision category bits.
+///
+/// Define game categories as constants and combine them with `|`, `&`, `^`, and `!`:
+///
+/// ```
+/// use boxdd::{CollisionCategory as C, Filter};
+/// const PLAYER: C = C::bit(1);
+/// const DEBRIS: C = C::bit(2);
+///
+/// let filter = Filter::new().category(PLAYER).mask(C::ALL ^ DEBRIS).group(-1);
+/// assert!(filter.mask_categories().contains(PLAYER));
+/// assert!(!filter.mask_categories().intersects(DEBRIS));
+/// assert_eq!(format!("{:?}", PLAYER | DEBRIS), "CollisionCategory(1 | 2)");
+/// ```
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
-#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", serde(transparent))]
+#[repr(transparent)]
+#[derive(Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct CollisionCategory(pub u64);
+
+impl CollisionCategory {
+    pub const NONE: Self = Self(0);
+    pub const ALL: Self = Self(u64::MAX);
+    /// Box2D's default category for new shapes.
+    pub const DEFAULT: Self = Self(1);
+
+    /// Category with only bit `index` set. Panics (at compile time in `const` items) if
+    /// `index >= 64`.
+    #[inline]
+    pub const fn bit(index: u32) -> Self {
+        assert!(index < 64, "collision category bit index must be < 64");
+        Self(1 << index)
+    }
+
+    #[inline]
+    pub const fn from_bits(bits: u64) -> Self {
+        Self(bits)
+    }
+
+    #[inline]
+    pub const fn bits(self) -> u64 {
+        self.0
+    }
+
+    #[inline]
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Whether every bit of `other` is set in `self`.
+    #[inline]
+    pub const fn contains(self, other: Self) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    /// Whether `self` and `other` share any bit.
+    #[inline]
+    pub const fn intersects(self, other: Self) -> bool {
+        self.0 & other.0 != 0
+    }
+
+    #[inline]
+    pub const fn union(self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    #[inline]
+    pub const fn difference(self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    /// Indices of the set bits, lowest first.
+    pub fn iter_bits(self) -> impl Iterator<Item = u32> {
+        (0..64).filter(move |&i| self.0 & (1 << i) != 0)
+    }
+}
+
+impl fmt::Debug for CollisionCategory {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("CollisionCategory(")?;
+        match *self {
+            Self::NONE => f.write_str("NONE")?,
+            Self::ALL => f.write_str("ALL")?,
+            _ => {
+                for (n, bit) in self.iter_bits().enumerate() {
+                    if n > 0 {
+                        f.write_str(" | ")?;
+                    }
+                    write!(f, "{bit}")?;
+                }
+            }
+        }
+        f.write_str(")")
+    }
+}
+
+impl From<u64> for CollisionCategory {
+    #[inline]
+    fn from(bits: u64) -> Self {
+        Self(bits)
+    }
+}
+
+impl From<CollisionCategory> for u64 {
+    #[inline]
+    fn from(c: CollisionCategory) -> Self {
+        c.0
+    }
+}
+
+macro_rules! impl_category_op {
+    ($op:ident, $fn:ident, $op_assign:ident, $fn_assign:ident, $tok:tt) => {
+        impl $op for CollisionCategory {
+            type Output = Self;
+            #[inline]
+            fn $fn(self, rhs: Self) -> Self {
+                Self(self.0 $tok rhs.0)
+            }
+        }
+
+        impl $op_assign for CollisionCategory {
+            #[inline]
+            fn $fn_assign(&mut self, rhs: Self) {
+                self.0 = self.0 $tok rhs.0;
+            }
+        }
+    };
+}
+
+impl_category_op!(BitOr, bitor, BitOrAssign, bitor_assign, |);
+impl_category_op!(BitAnd, bitand, BitAndAssign, bitand_assign, &);
+impl_category_op!(BitXor, bitxor, BitXorAssign, bitxor_assign, ^);
+
+impl Not for CollisionCategory {
+    type Output = Self;
+    #[inline]
+    fn not(self) -> Self {
+        Self(!self.0)
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, PartialEq, Eq)]
 pub struct Filter {
     pub category_bits: u64,
     pub mask_bits: u64,
     pub group_index: i32,
 }
 
+impl fmt::Debug for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Filter")
+            .field("category", &self.categories())
+            .field("mask", &self.mask_categories())
+            .field("group_index", &self.group_index)
+            .finish()
+    }
+}
+
 impl Default for Filter {
     fn default() -> Self {
         Self::from_raw(unsafe { ffi::b2DefaultFilter() })
@@ -15,6 +170,12 @@ impl Default for Filter {
 }
 
 impl Filter {
+    /// Box2D's default filter: category [`CollisionCategory::DEFAULT`], collides with everything.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
     #[inline]
     /// Construct from the raw Box2D filter value.
     pub const fn from_raw(raw: ffi::b2Filter) -> Self {
@@ -34,4 +195,139 @@ impl Filter {
             groupIndex: self.group_index,
         }
     }
+
+    /// Categories this shape belongs to.
+    #[inline]
+    pub const fn category(mut self, category: CollisionCategory) -> Self {
+        self.category_bits = category.0;
+        self
+    }
+
+    /// Categories this shape collides with.
+    #[inline]
+    pub const fn mask(mut self, mask: CollisionCategory) -> Self {
+        self.mask_bits = mask.0;
+        self
+    }
+
+    /// Collision group: shapes sharing the same positive group always collide, and shapes sharing
+    /// the same negative group never do, regardless of category and mask.
+    #[inline]
+    pub const fn group(mut self, group_index: i32) -> Self {
+        self.group_index = group_index;
+        self
+    }
+
+    #[inline]
+    pub const fn categories(&self) -> CollisionCategory {
+        CollisionCategory(self.category_bits)
+    }
+
+    #[inline]
+    pub const fn mask_categories(&self) -> CollisionCategory {
+        CollisionCategory(self.mask_bits)
+    }
+
+    /// Default filter in collision group `group`, whose members never collide with each other
+    /// (e.g. the limbs of one ragdoll). Other shapes still use category/mask.
+    ///
+    /// Panics if `group` is zero, which means "no group" in Box2D.
+    pub fn same_group_never_collide(group: i32) -> Self {
+        assert!(
+            group != 0 && group != i32::MIN,
+            "collision group must be non-zero, got {group}"
+        );
+        Self::new().group(-group.abs())
+    }
+
+    pub fn try_same_group_never_collide(group: i32) -> ApiResult<Self> {
+        check_group(group)?;
+        Ok(Self::new().group(-group.abs()))
+    }
+
+    /// Default filter in collision group `group`, whose members always collide with each other
+    /// even if their category/mask would reject the pair.
+    ///
+    /// Panics if `group` is zero, which means "no group" in Box2D.
+    pub fn always_collide(group: i32) -> Self {
+        assert!(
+            group != 0 && group != i32::MIN,
+            "collision group must be non-zero, got {group}"
+        );
+        Self::new().group(group.abs())
+    }
+
+    pub fn try_always_collide(group: i32) -> ApiResult<Self> {
+        check_group(group)?;
+        Ok(Self::new().group(group.abs()))
+    }
+
+    /// Whether two shapes with these filters would collide under Box2D's default filtering.
+    pub const fn should_collide(&self, other: &Filter) -> bool {
+        self.decide(other).is_none()
+    }
+
+    /// Filter-only part of [`FilterDecision`]; `None` means the filters accept the pair.
+    pub(crate) const fn decide(&self, other: &Filter) -> Option<FilterDecision> {
+        if self.group_index == other.group_index && self.group_index != 0 {
+            return if self.group_index > 0 {
+                None
+            } else {
+                Some(FilterDecision::NegativeGroup {
+                    group_index: self.group_index,
+                })
+            };
+        }
+        let a_accepts_b = self.mask_bits & other.category_bits != 0;
+        let b_accepts_a = other.mask_bits & self.category_bits != 0;
+        if a_accepts_b && b_accepts_a {
+            None
+        } else {
+            Some(FilterDecision::CategoryMask {
+                a_accepts_b,
+                b_accepts_a,
+            })
+        }
+    }
+}
+
+fn check_group(group: i32) -> ApiResult<()> {
+    if group != 0 && group != i32::MIN {
+        Ok(())
+    } else {
+        Err(ApiError::InvalidArgument)
+    }
+}
+
+/// Why two shapes do or don't collide, as reported by `World::debug_explain_filter`.
+///
+/// Checks run in Box2D's order and the first one that rejects the pair is reported.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum FilterDecision {
+    /// The shapes pass every filter and collide when their bounds overlap.
+    Collide,
+    /// Both shapes share this negative group index.
+    NegativeGroup { group_index: i32 },
+    /// At least one shape's mask excludes the other's category.
+    CategoryMask {
+        a_accepts_b: bool,
+        b_accepts_a: bool,
+    },
+    /// At least one shape is a sensor; sensors report overlaps but never create contacts.
+    Sensor,
+    /// Both shapes are on the same body.
+    SameBody,
+    /// Neither body is dynamic.
+    NoDynamicBody,
+    /// The bodies are connected by this joint, which has `collide_connected` disabled.
+    JointedBodies { joint: JointId },
+    /// The world's custom filter callback rejected the pair.
+    CustomFilter,
+}
+
+impl FilterDecision {
+    #[inline]
+    pub const fn collides(self) -> bool {
+        matches!(self, Self::Collide)
+    }
 }
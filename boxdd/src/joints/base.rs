@@ -13,6 +13,14 @@ mod runtime_handle;
 mod scoped;
 mod user_data;
 
+pub(crate) use user_data::{
+    joint_clear_user_data_checked_impl, joint_set_user_data_checked_impl,
+    joint_take_user_data_checked_impl, joint_with_user_data_checked_impl,
+    joint_with_user_data_mut_checked_impl, try_joint_clear_user_data_checked_impl,
+    try_joint_set_user_data_checked_impl, try_joint_take_user_data_checked_impl,
+    try_joint_with_user_data_checked_impl, try_joint_with_user_data_mut_checked_impl,
+};
+
 /// A scoped joint handle tied to a mutable borrow of the world.
 pub struct Joint<'w> {
     pub(crate) id: JointId,
@@ -106,6 +114,15 @@ impl ConstraintTuning {
             damping_ratio,
         }
     }
+
+    /// Both values finite and non-negative, as Box2D requires.
+    #[inline]
+    pub fn is_valid(self) -> bool {
+        self.hertz.is_finite()
+            && self.hertz >= 0.0
+            && self.damping_ratio.is_finite()
+            && self.damping_ratio >= 0.0
+    }
 }
 
 #[inline]
@@ -211,6 +228,24 @@ pub(crate) fn check_joint_local_frame_valid(frame: crate::Transform) -> ApiResul
     }
 }
 
+#[track_caller]
+pub(crate) fn assert_constraint_tuning_valid(tuning: ConstraintTuning) {
+    assert!(
+        tuning.is_valid(),
+        "constraint tuning must be finite and >= 0, got {:?}",
+        tuning
+    );
+}
+
+#[inline]
+pub(crate) fn check_constraint_tuning_valid(tuning: ConstraintTuning) -> ApiResult<()> {
+    if tuning.is_valid() {
+        Ok(())
+    } else {
+        Err(ApiError::InvalidArgument)
+    }
+}
+
 #[inline]
 pub(crate) fn joint_set_local_frame_a_impl(id: JointId, frame: crate::Transform) {
     unsafe { ffi::b2Joint_SetLocalFrameA(raw_joint_id(id), frame.into_raw()) }
@@ -313,6 +313,8 @@ impl OwnedJoint {
             } else {
                 unsafe { ffi::b2DestroyJoint(raw_joint_id(self.id), wake_bodies) };
                 let _ = self.core.clear_joint_user_data(self.id);
+                self.core
+                    .notify_destroyed(&[crate::world::DestroyedEntity::Joint(self.id)]);
             }
         }
         self.destroy_on_drop = false;
@@ -338,6 +340,8 @@ impl Drop for OwnedJoint {
             } else {
                 unsafe { ffi::b2DestroyJoint(raw_joint_id(self.id), self.wake_bodies_on_drop) };
                 let _ = self.core.clear_joint_user_data(self.id);
+                self.core
+                    .notify_destroyed(&[crate::world::DestroyedEntity::Joint(self.id)]);
             }
         }
     }
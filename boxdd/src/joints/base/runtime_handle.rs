@@ -127,11 +127,13 @@ pub(crate) trait JointRuntimeHandle {
 
     fn set_constraint_tuning(&mut self, tuning: ConstraintTuning) {
         self.assert_valid();
+        assert_constraint_tuning_valid(tuning);
         joint_set_constraint_tuning_impl(self.joint_id(), tuning);
     }
 
     fn try_set_constraint_tuning(&mut self, tuning: ConstraintTuning) -> ApiResult<()> {
         self.check_valid()?;
+        check_constraint_tuning_valid(tuning)?;
         joint_set_constraint_tuning_impl(self.joint_id(), tuning);
         Ok(())
     }
@@ -295,6 +295,8 @@ impl<'w> Joint<'w> {
         if unsafe { ffi::b2Joint_IsValid(raw_joint_id(self.id)) } {
             unsafe { ffi::b2DestroyJoint(raw_joint_id(self.id), wake_bodies) };
             let _ = self.core.clear_joint_user_data(self.id);
+            self.core
+                .notify_destroyed(&[crate::world::DestroyedEntity::Joint(self.id)]);
         }
     }
 
@@ -303,6 +305,8 @@ impl<'w> Joint<'w> {
         if unsafe { ffi::b2Joint_IsValid(raw_joint_id(self.id)) } {
             unsafe { ffi::b2DestroyJoint(raw_joint_id(self.id), wake_bodies) };
             let _ = self.core.clear_joint_user_data(self.id);
+            self.core
+                .notify_destroyed(&[crate::world::DestroyedEntity::Joint(self.id)]);
         }
         Ok(())
     }
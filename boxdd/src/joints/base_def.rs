@@ -1,5 +1,6 @@
 use crate::body::Body;
-use crate::error::ApiResult;
+use crate::core::def_checks::{CheckResult, DefChecks};
+use crate::error::{ApiResult, ValidationError};
 use crate::types::BodyId;
 use boxdd_sys::ffi;
 
@@ -110,6 +111,13 @@ impl JointBase {
     pub fn validate(&self) -> ApiResult<()> {
         super::check_joint_base_valid(self)
     }
+
+    /// Constraint tuning checks that `build_checked()` adds on top of the creation-time checks.
+    pub(crate) fn check_tuning_fields(&self, c: DefChecks) -> CheckResult {
+        c.non_negative("constraint_hertz", self.0.constraintHertz)?;
+        c.non_negative("constraint_damping_ratio", self.0.constraintDampingRatio)?;
+        c.non_negative("draw_scale", self.draw_scale())
+    }
 }
 
 #[derive(Clone, Debug)]
@@ -218,6 +226,14 @@ impl JointBaseBuilder {
     pub fn build(self) -> JointBase {
         self.base
     }
+    /// Build the base, reporting the first out-of-range field. Body ids are not checked here
+    /// because that needs the world; joint creation still validates them.
+    pub fn build_checked(self) -> Result<JointBase, ValidationError> {
+        let c = DefChecks("JointBase");
+        super::validate_joint_base_fields(&self.base.0, c)?;
+        self.base.check_tuning_fields(c)?;
+        Ok(self.base)
+    }
     /// Set local frames using world anchors and a shared world axis (X-axis of joint frame).
     /// This computes localFrameA/B.rotation so that their X-axis aligns with the given world axis,
     /// and localFrameA/B.position to the given world anchor points.
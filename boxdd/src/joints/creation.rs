@@ -7,5 +7,8 @@ mod world_api;
 pub(crate) use validation::{
     check_distance_joint_def_valid, check_filter_joint_def_valid, check_joint_base_valid,
     check_motor_joint_def_valid, check_prismatic_joint_def_valid, check_revolute_joint_def_valid,
-    check_weld_joint_def_valid, check_wheel_joint_def_valid,
+    check_weld_joint_def_valid, check_wheel_joint_def_valid, validate_distance_joint_def_fields,
+    validate_filter_joint_def_fields, validate_joint_base_fields, validate_motor_joint_def_fields,
+    validate_prismatic_joint_def_fields, validate_revolute_joint_def_fields,
+    validate_weld_joint_def_fields, validate_wheel_joint_def_fields,
 };
@@ -1,4 +1,5 @@
 use super::*;
+use crate::core::def_checks::{CheckResult, DefChecks};
 
 #[inline]
 fn assert_joint_def_bodies_valid(base: &ffi::b2JointDef) {
@@ -47,17 +48,6 @@ fn assert_joint_def_local_frames_valid(base: &ffi::b2JointDef) {
     );
 }
 
-#[inline]
-fn check_joint_def_local_frames_valid(base: &ffi::b2JointDef) -> ApiResult<()> {
-    if crate::Transform::from_raw(base.localFrameA).is_valid()
-        && crate::Transform::from_raw(base.localFrameB).is_valid()
-    {
-        Ok(())
-    } else {
-        Err(crate::error::ApiError::InvalidArgument)
-    }
-}
-
 #[inline]
 fn assert_joint_def_event_thresholds_valid(base: &ffi::b2JointDef) {
     assert!(
@@ -72,19 +62,6 @@ fn assert_joint_def_event_thresholds_valid(base: &ffi::b2JointDef) {
     );
 }
 
-#[inline]
-fn check_joint_def_event_thresholds_valid(base: &ffi::b2JointDef) -> ApiResult<()> {
-    if crate::is_valid_float(base.forceThreshold)
-        && base.forceThreshold >= 0.0
-        && crate::is_valid_float(base.torqueThreshold)
-        && base.torqueThreshold >= 0.0
-    {
-        Ok(())
-    } else {
-        Err(crate::error::ApiError::InvalidArgument)
-    }
-}
-
 #[inline]
 pub(super) fn assert_joint_def_targets_world(world: &World, base: &ffi::b2JointDef) {
     let target_world = world.raw().index1 - 1;
@@ -119,17 +96,30 @@ fn assert_joint_base_raw_valid(base: &ffi::b2JointDef) {
     assert_joint_def_event_thresholds_valid(base);
 }
 
+/// World-independent base checks (local frames and event thresholds), reported against the
+/// definition `c` names. Body ids need the world and are checked separately at creation.
+pub(crate) fn validate_joint_base_fields(base: &ffi::b2JointDef, c: DefChecks) -> CheckResult {
+    c.transform(
+        "local_frame_a",
+        crate::Transform::from_raw(base.localFrameA),
+    )?;
+    c.transform(
+        "local_frame_b",
+        crate::Transform::from_raw(base.localFrameB),
+    )?;
+    c.non_negative("force_threshold", base.forceThreshold)?;
+    c.non_negative("torque_threshold", base.torqueThreshold)
+}
+
 #[inline]
-fn check_joint_base_raw_valid(base: &ffi::b2JointDef) -> ApiResult<()> {
+fn check_joint_def_body_ids_valid(base: &ffi::b2JointDef) -> ApiResult<()> {
     check_joint_def_bodies_valid(base)?;
-    check_joint_def_body_pair_valid(base)?;
-    check_joint_def_local_frames_valid(base)?;
-    check_joint_def_event_thresholds_valid(base)?;
-    Ok(())
+    check_joint_def_body_pair_valid(base)
 }
 
 pub(crate) fn check_joint_base_valid(base: &JointBase) -> ApiResult<()> {
-    check_joint_base_raw_valid(&base.0)
+    check_joint_def_body_ids_valid(&base.0)?;
+    validate_joint_base_fields(&base.0, DefChecks("JointBase")).map_err(Into::into)
 }
 
 #[inline]
@@ -184,17 +174,24 @@ pub(super) fn assert_distance_joint_def_raw_valid(def: &ffi::b2DistanceJointDef)
     );
 }
 
+/// Field-level form of the world-independent `DistanceJointDef` checks, shared by creation and
+/// [`DistanceJointDef::build_checked`].
+pub(crate) fn validate_distance_joint_def_fields(def: &ffi::b2DistanceJointDef) -> CheckResult {
+    let c = DefChecks("DistanceJointDef");
+    validate_joint_base_fields(&def.base, c)?;
+    c.initialized(distance_joint_def_cookie_is_valid(def))?;
+    c.positive("length", def.length)?;
+    c.ordered(
+        "lower_spring_force",
+        def.lowerSpringForce,
+        "upper_spring_force",
+        def.upperSpringForce,
+    )
+}
+
 pub(super) fn check_distance_joint_def_raw_valid(def: &ffi::b2DistanceJointDef) -> ApiResult<()> {
-    check_joint_base_raw_valid(&def.base)?;
-    if distance_joint_def_cookie_is_valid(def)
-        && crate::is_valid_float(def.length)
-        && def.length > 0.0
-        && def.lowerSpringForce <= def.upperSpringForce
-    {
-        Ok(())
-    } else {
-        Err(crate::error::ApiError::InvalidArgument)
-    }
+    check_joint_def_body_ids_valid(&def.base)?;
+    validate_distance_joint_def_fields(def).map_err(Into::into)
 }
 
 pub(crate) fn check_distance_joint_def_valid(def: &DistanceJointDef) -> ApiResult<()> {
@@ -209,13 +206,17 @@ pub(super) fn assert_motor_joint_def_raw_valid(def: &ffi::b2MotorJointDef) {
     );
 }
 
+/// Field-level form of the world-independent `MotorJointDef` checks, shared by creation and
+/// [`MotorJointDef::build_checked`].
+pub(crate) fn validate_motor_joint_def_fields(def: &ffi::b2MotorJointDef) -> CheckResult {
+    let c = DefChecks("MotorJointDef");
+    validate_joint_base_fields(&def.base, c)?;
+    c.initialized(motor_joint_def_cookie_is_valid(def))
+}
+
 pub(super) fn check_motor_joint_def_raw_valid(def: &ffi::b2MotorJointDef) -> ApiResult<()> {
-    check_joint_base_raw_valid(&def.base)?;
-    if motor_joint_def_cookie_is_valid(def) {
-        Ok(())
-    } else {
-        Err(crate::error::ApiError::InvalidArgument)
-    }
+    check_joint_def_body_ids_valid(&def.base)?;
+    validate_motor_joint_def_fields(def).map_err(Into::into)
 }
 
 pub(crate) fn check_motor_joint_def_valid(def: &MotorJointDef) -> ApiResult<()> {
@@ -230,13 +231,17 @@ pub(super) fn assert_filter_joint_def_raw_valid(def: &ffi::b2FilterJointDef) {
     );
 }
 
+/// Field-level form of the world-independent `FilterJointDef` checks, shared by creation and
+/// [`FilterJointDef::build_checked`].
+pub(crate) fn validate_filter_joint_def_fields(def: &ffi::b2FilterJointDef) -> CheckResult {
+    let c = DefChecks("FilterJointDef");
+    validate_joint_base_fields(&def.base, c)?;
+    c.initialized(filter_joint_def_cookie_is_valid(def))
+}
+
 pub(super) fn check_filter_joint_def_raw_valid(def: &ffi::b2FilterJointDef) -> ApiResult<()> {
-    check_joint_base_raw_valid(&def.base)?;
-    if filter_joint_def_cookie_is_valid(def) {
-        Ok(())
-    } else {
-        Err(crate::error::ApiError::InvalidArgument)
-    }
+    check_joint_def_body_ids_valid(&def.base)?;
+    validate_filter_joint_def_fields(def).map_err(Into::into)
 }
 
 pub(crate) fn check_filter_joint_def_valid(def: &FilterJointDef) -> ApiResult<()> {
@@ -255,13 +260,23 @@ pub(super) fn assert_prismatic_joint_def_raw_valid(def: &ffi::b2PrismaticJointDe
     );
 }
 
+/// Field-level form of the world-independent `PrismaticJointDef` checks, shared by creation and
+/// [`PrismaticJointDef::build_checked`].
+pub(crate) fn validate_prismatic_joint_def_fields(def: &ffi::b2PrismaticJointDef) -> CheckResult {
+    let c = DefChecks("PrismaticJointDef");
+    validate_joint_base_fields(&def.base, c)?;
+    c.initialized(prismatic_joint_def_cookie_is_valid(def))?;
+    c.ordered(
+        "lower_translation",
+        def.lowerTranslation,
+        "upper_translation",
+        def.upperTranslation,
+    )
+}
+
 pub(super) fn check_prismatic_joint_def_raw_valid(def: &ffi::b2PrismaticJointDef) -> ApiResult<()> {
-    check_joint_base_raw_valid(&def.base)?;
-    if prismatic_joint_def_cookie_is_valid(def) && def.lowerTranslation <= def.upperTranslation {
-        Ok(())
-    } else {
-        Err(crate::error::ApiError::InvalidArgument)
-    }
+    check_joint_def_body_ids_valid(&def.base)?;
+    validate_prismatic_joint_def_fields(def).map_err(Into::into)
 }
 
 pub(crate) fn check_prismatic_joint_def_valid(def: &PrismaticJointDef) -> ApiResult<()> {
@@ -288,17 +303,21 @@ pub(super) fn assert_revolute_joint_def_raw_valid(def: &ffi::b2RevoluteJointDef)
     );
 }
 
+/// Field-level form of the world-independent `RevoluteJointDef` checks, shared by creation and
+/// [`RevoluteJointDef::build_checked`].
+pub(crate) fn validate_revolute_joint_def_fields(def: &ffi::b2RevoluteJointDef) -> CheckResult {
+    let c = DefChecks("RevoluteJointDef");
+    validate_joint_base_fields(&def.base, c)?;
+    c.initialized(revolute_joint_def_cookie_is_valid(def))?;
+    let max_angle = 0.99 * ffi::B2_PI as f32;
+    c.within("lower_angle", def.lowerAngle, -max_angle, max_angle)?;
+    c.within("upper_angle", def.upperAngle, -max_angle, max_angle)?;
+    c.ordered("lower_angle", def.lowerAngle, "upper_angle", def.upperAngle)
+}
+
 pub(super) fn check_revolute_joint_def_raw_valid(def: &ffi::b2RevoluteJointDef) -> ApiResult<()> {
-    check_joint_base_raw_valid(&def.base)?;
-    if revolute_joint_def_cookie_is_valid(def)
-        && def.lowerAngle <= def.upperAngle
-        && def.lowerAngle >= -0.99 * ffi::B2_PI as f32
-        && def.upperAngle <= 0.99 * ffi::B2_PI as f32
-    {
-        Ok(())
-    } else {
-        Err(crate::error::ApiError::InvalidArgument)
-    }
+    check_joint_def_body_ids_valid(&def.base)?;
+    validate_revolute_joint_def_fields(def).map_err(Into::into)
 }
 
 pub(crate) fn check_revolute_joint_def_valid(def: &RevoluteJointDef) -> ApiResult<()> {
@@ -313,13 +332,17 @@ pub(super) fn assert_weld_joint_def_raw_valid(def: &ffi::b2WeldJointDef) {
     );
 }
 
+/// Field-level form of the world-independent `WeldJointDef` checks, shared by creation and
+/// [`WeldJointDef::build_checked`].
+pub(crate) fn validate_weld_joint_def_fields(def: &ffi::b2WeldJointDef) -> CheckResult {
+    let c = DefChecks("WeldJointDef");
+    validate_joint_base_fields(&def.base, c)?;
+    c.initialized(weld_joint_def_cookie_is_valid(def))
+}
+
 pub(super) fn check_weld_joint_def_raw_valid(def: &ffi::b2WeldJointDef) -> ApiResult<()> {
-    check_joint_base_raw_valid(&def.base)?;
-    if weld_joint_def_cookie_is_valid(def) {
-        Ok(())
-    } else {
-        Err(crate::error::ApiError::InvalidArgument)
-    }
+    check_joint_def_body_ids_valid(&def.base)?;
+    validate_weld_joint_def_fields(def).map_err(Into::into)
 }
 
 pub(crate) fn check_weld_joint_def_valid(def: &WeldJointDef) -> ApiResult<()> {
@@ -338,13 +361,23 @@ pub(super) fn assert_wheel_joint_def_raw_valid(def: &ffi::b2WheelJointDef) {
     );
 }
 
+/// Field-level form of the world-independent `WheelJointDef` checks, shared by creation and
+/// [`WheelJointDef::build_checked`].
+pub(crate) fn validate_wheel_joint_def_fields(def: &ffi::b2WheelJointDef) -> CheckResult {
+    let c = DefChecks("WheelJointDef");
+    validate_joint_base_fields(&def.base, c)?;
+    c.initialized(wheel_joint_def_cookie_is_valid(def))?;
+    c.ordered(
+        "lower_translation",
+        def.lowerTranslation,
+        "upper_translation",
+        def.upperTranslation,
+    )
+}
+
 pub(super) fn check_wheel_joint_def_raw_valid(def: &ffi::b2WheelJointDef) -> ApiResult<()> {
-    check_joint_base_raw_valid(&def.base)?;
-    if wheel_joint_def_cookie_is_valid(def) && def.lowerTranslation <= def.upperTranslation {
-        Ok(())
-    } else {
-        Err(crate::error::ApiError::InvalidArgument)
-    }
+    check_joint_def_body_ids_valid(&def.base)?;
+    validate_wheel_joint_def_fields(def).map_err(Into::into)
 }
 
 pub(crate) fn check_wheel_joint_def_valid(def: &WheelJointDef) -> ApiResult<()> {
@@ -517,6 +517,8 @@ impl World {
         if unsafe { ffi::b2Joint_IsValid(raw_joint_id(id)) } {
             unsafe { ffi::b2DestroyJoint(raw_joint_id(id), wake_bodies) };
             let _ = self.core_arc().clear_joint_user_data(id);
+            self.core_arc()
+                .notify_destroyed(&[crate::world::DestroyedEntity::Joint(id)]);
         }
     }
 
@@ -524,6 +526,8 @@ impl World {
         check_joint_valid(id)?;
         unsafe { ffi::b2DestroyJoint(raw_joint_id(id), wake_bodies) };
         let _ = self.core_arc().clear_joint_user_data(id);
+        self.core_arc()
+            .notify_destroyed(&[crate::world::DestroyedEntity::Joint(id)]);
         Ok(())
     }
 }
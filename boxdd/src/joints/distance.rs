@@ -102,6 +102,25 @@ impl DistanceJointDef {
         super::check_distance_joint_def_valid(self)
     }
 
+    /// Run the same checks as joint creation, except body ids, plus range checks on the tuning
+    /// fields, reporting the first out-of-range field.
+    pub fn build_checked(self) -> Result<Self, crate::error::ValidationError> {
+        let c = crate::core::def_checks::DefChecks("DistanceJointDef");
+        super::validate_distance_joint_def_fields(&self.0)?;
+        self.base().check_tuning_fields(c)?;
+        c.non_negative("hertz", self.0.hertz)?;
+        c.non_negative("damping_ratio", self.0.dampingRatio)?;
+        c.ordered(
+            "min_length",
+            self.0.minLength,
+            "max_length",
+            self.0.maxLength,
+        )?;
+        c.non_negative("max_motor_force", self.0.maxMotorForce)?;
+        c.finite("motor_speed", self.0.motorSpeed)?;
+        Ok(self)
+    }
+
     /// Target distance between anchors (meters).
     pub fn length(mut self, v: f32) -> Self {
         self.0.length = v;
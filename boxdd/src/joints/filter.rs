@@ -37,6 +37,15 @@ impl FilterJointDef {
     pub fn validate(&self) -> ApiResult<()> {
         super::check_filter_joint_def_valid(self)
     }
+
+    /// Run the same checks as joint creation, except body ids, plus range checks on the tuning
+    /// fields, reporting the first out-of-range field.
+    pub fn build_checked(self) -> Result<Self, crate::error::ValidationError> {
+        let c = crate::core::def_checks::DefChecks("FilterJointDef");
+        super::validate_filter_joint_def_fields(&self.0)?;
+        self.base().check_tuning_fields(c)?;
+        Ok(self)
+    }
 }
 
 /// Builder for a filter joint that disables collision between two bodies while keeping them in the same island.
@@ -0,0 +1,186 @@
+//! Mirroring joint definitions for symmetric rigs.
+
+use crate::error::{ApiError, ApiResult};
+use crate::types::{BodyId, Vec2};
+use boxdd_sys::ffi;
+
+use super::{
+    DistanceJointDef, FilterJointDef, MotorJointDef, PrismaticJointDef, RevoluteJointDef,
+    WeldJointDef, WheelJointDef,
+};
+
+mod sealed {
+    pub trait Sealed {
+        fn raw_base_mut(&mut self) -> &mut boxdd_sys::ffi::b2JointDef;
+    }
+}
+
+/// Joint definitions that can be reflected across a world axis. See [`mirror`].
+pub trait MirrorJoint: sealed::Sealed + Sized {
+    /// Mirrored copy of this definition across the line through the origin along `axis`.
+    ///
+    /// Panics if `axis` is zero or not finite.
+    fn mirrored(&self, axis: Vec2) -> Self;
+}
+
+/// Reflection across a line through the origin with unit direction `(c, s)`.
+#[derive(Copy, Clone)]
+struct Reflection {
+    c: f32,
+    s: f32,
+}
+
+impl Reflection {
+    fn new(axis: Vec2) -> Self {
+        match Self::try_new(axis) {
+            Ok(r) => r,
+            Err(_) => panic!("mirror axis must be finite and non-zero, got {axis:?}"),
+        }
+    }
+
+    fn try_new(axis: Vec2) -> ApiResult<Self> {
+        let len = axis.length();
+        if !(len.is_finite() && len > 0.0) {
+            return Err(ApiError::InvalidArgument);
+        }
+        Ok(Self {
+            c: axis.x / len,
+            s: axis.y / len,
+        })
+    }
+
+    fn point(self, p: ffi::b2Vec2) -> ffi::b2Vec2 {
+        let d = 2.0 * (p.x * self.c + p.y * self.s);
+        ffi::b2Vec2 {
+            x: d * self.c - p.x,
+            y: d * self.s - p.y,
+        }
+    }
+
+    /// Frame angle `phi` becomes `2 * alpha - phi`, so the frame x-axis is reflected.
+    fn rot(self, q: ffi::b2Rot) -> ffi::b2Rot {
+        let c2 = self.c * self.c - self.s * self.s;
+        let s2 = 2.0 * self.c * self.s;
+        ffi::b2Rot {
+            c: c2 * q.c + s2 * q.s,
+            s: s2 * q.c - c2 * q.s,
+        }
+    }
+
+    fn frame(self, t: ffi::b2Transform) -> ffi::b2Transform {
+        ffi::b2Transform {
+            p: self.point(t.p),
+            q: self.rot(t.q),
+        }
+    }
+
+    fn base(self, base: &mut ffi::b2JointDef) {
+        base.localFrameA = self.frame(base.localFrameA);
+        base.localFrameB = self.frame(base.localFrameB);
+    }
+}
+
+/// Vectors expressed in a mirrored joint frame only flip their y component.
+fn flip_frame_vector(v: ffi::b2Vec2) -> ffi::b2Vec2 {
+    ffi::b2Vec2 { x: v.x, y: -v.y }
+}
+
+macro_rules! impl_mirror_joint {
+    ($ty:ty, |$raw:ident, $r:ident| $body:block) => {
+        impl sealed::Sealed for $ty {
+            fn raw_base_mut(&mut self) -> &mut ffi::b2JointDef {
+                &mut self.0.base
+            }
+        }
+
+        impl MirrorJoint for $ty {
+            fn mirrored(&self, axis: Vec2) -> Self {
+                let $r = Reflection::new(axis);
+                let mut $raw = self.0;
+                $r.base(&mut $raw.base);
+                $body
+                Self($raw)
+            }
+        }
+    };
+}
+
+impl_mirror_joint!(RevoluteJointDef, |raw, _r| {
+    let (lower, upper) = (raw.lowerAngle, raw.upperAngle);
+    raw.lowerAngle = -upper;
+    raw.upperAngle = -lower;
+    raw.targetAngle = -raw.targetAngle;
+    raw.motorSpeed = -raw.motorSpeed;
+});
+
+impl_mirror_joint!(PrismaticJointDef, |raw, _r| {});
+
+impl_mirror_joint!(WheelJointDef, |raw, _r| {
+    // The wheel motor spins the wheel, so its direction flips with the mirror.
+    raw.motorSpeed = -raw.motorSpeed;
+});
+
+impl_mirror_joint!(WeldJointDef, |raw, _r| {});
+
+impl_mirror_joint!(DistanceJointDef, |raw, _r| {});
+
+impl_mirror_joint!(MotorJointDef, |raw, _r| {
+    raw.linearVelocity = flip_frame_vector(raw.linearVelocity);
+    raw.angularVelocity = -raw.angularVelocity;
+});
+
+impl_mirror_joint!(FilterJointDef, |raw, _r| {});
+
+/// Mirror a joint definition across the line through the origin along `axis`, e.g. `[0.0, 1.0]`
+/// to turn a left leg into a right leg.
+///
+/// The bodies are expected to be mirrored the same way: positions reflected, angles negated, and
+/// shape vertices reflected in body space. Local frames are reflected, angular quantities (angle
+/// limits, target angles, rotational motor speeds) change sign and swap ends, and translations
+/// along joint axes are kept. Body ids are copied unchanged; use [`mirror_onto`] to attach the
+/// copy to the mirrored bodies.
+///
+/// ```
+/// use boxdd::joints::{JointBase, RevoluteJointDef, mirror};
+/// let left = RevoluteJointDef::new(JointBase::default()).limit_deg(-10.0, 45.0);
+/// let right = mirror(&left, [0.0, 1.0]);
+/// assert!((right.maximum_angle() - 10.0_f32.to_radians()).abs() < 1e-6);
+/// ```
+///
+/// Panics if `axis` is zero or not finite.
+pub fn mirror<D: MirrorJoint, V: Into<Vec2>>(def: &D, axis: V) -> D {
+    def.mirrored(axis.into())
+}
+
+/// Recoverable version of [`mirror`].
+pub fn try_mirror<D: MirrorJoint, V: Into<Vec2>>(def: &D, axis: V) -> ApiResult<D> {
+    let axis = axis.into();
+    Reflection::try_new(axis)?;
+    Ok(def.mirrored(axis))
+}
+
+/// [`mirror`] and attach the copy to `body_a` / `body_b` (usually the mirrored bodies).
+pub fn mirror_onto<D: MirrorJoint, V: Into<Vec2>>(
+    def: &D,
+    axis: V,
+    body_a: BodyId,
+    body_b: BodyId,
+) -> D {
+    let mut out = mirror(def, axis);
+    let base = sealed::Sealed::raw_base_mut(&mut out);
+    base.bodyIdA = body_a.into_raw();
+    base.bodyIdB = body_b.into_raw();
+    out
+}
+
+/// Recoverable version of [`mirror_onto`].
+pub fn try_mirror_onto<D: MirrorJoint, V: Into<Vec2>>(
+    def: &D,
+    axis: V,
+    body_a: BodyId,
+    body_b: BodyId,
+) -> ApiResult<D> {
+    let axis = axis.into();
+    Reflection::try_new(axis)?;
+    Ok(mirror_onto(def, axis, body_a, body_b))
+}
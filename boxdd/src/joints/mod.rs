@@ -8,7 +8,7 @@
 //! - ID style: `World::create_*_joint_id(&def) -> b2JointId` returning the raw id for storage.
 //!
 //! The `World` convenience builders (`revolute`, `prismatic`, `wheel`, `distance`, `weld`,
-//! `motor_joint`, `filter_joint`) help compose joints in world space and build local frames
+//! `motor_joint`, `mouse_joint`, `filter_joint`) help compose joints in world space and build local frames
 //! from world anchors/axes.
 
 mod base;
@@ -16,7 +16,9 @@ mod base_def;
 mod creation;
 mod distance;
 mod filter;
+mod mirror;
 mod motor;
+mod mouse;
 mod prismatic;
 mod revolute;
 mod runtime;
@@ -26,6 +28,9 @@ mod runtime_typed_prismatic;
 mod runtime_typed_revolute;
 mod runtime_typed_weld;
 mod runtime_typed_wheel;
+#[cfg(feature = "serde")]
+mod serde_defs;
+mod typed;
 mod weld;
 mod wheel;
 
@@ -33,9 +38,12 @@ pub use base::{ConstraintTuning, Joint, JointType, OwnedJoint};
 pub use base_def::{JointBase, JointBaseBuilder};
 pub use distance::{DistanceJointBuilder, DistanceJointDef};
 pub use filter::{FilterJointBuilder, FilterJointDef};
+pub use mirror::{MirrorJoint, mirror, mirror_onto, try_mirror, try_mirror_onto};
 pub use motor::{MotorJointBuilder, MotorJointDef};
+pub use mouse::MouseJointBuilder;
 pub use prismatic::{PrismaticJointBuilder, PrismaticJointDef};
 pub use revolute::{RevoluteJointBuilder, RevoluteJointDef};
+pub use typed::{DistanceJoint, MotorJoint, PrismaticJoint, RevoluteJoint, WeldJoint, WheelJoint};
 pub use weld::{WeldJointBuilder, WeldJointDef};
 pub use wheel::{WheelJointBuilder, WheelJointDef};
 
@@ -45,10 +53,22 @@ use crate::world::{World, WorldHandle};
 use boxdd_sys::ffi;
 use runtime::*;
 
+pub(crate) use base::{
+    joint_body_a_id_impl, joint_body_b_id_impl, joint_clear_user_data_checked_impl,
+    joint_collide_connected_impl, joint_local_frame_a_impl, joint_local_frame_b_impl,
+    joint_set_local_frame_a_impl, joint_set_local_frame_b_impl, joint_set_user_data_checked_impl,
+    joint_take_user_data_checked_impl, joint_with_user_data_checked_impl,
+    joint_with_user_data_mut_checked_impl, try_joint_clear_user_data_checked_impl,
+    try_joint_set_user_data_checked_impl, try_joint_take_user_data_checked_impl,
+    try_joint_with_user_data_checked_impl, try_joint_with_user_data_mut_checked_impl,
+};
 pub(crate) use creation::{
     check_distance_joint_def_valid, check_filter_joint_def_valid, check_joint_base_valid,
     check_motor_joint_def_valid, check_prismatic_joint_def_valid, check_revolute_joint_def_valid,
-    check_weld_joint_def_valid, check_wheel_joint_def_valid,
+    check_weld_joint_def_valid, check_wheel_joint_def_valid, validate_distance_joint_def_fields,
+    validate_filter_joint_def_fields, validate_joint_base_fields, validate_motor_joint_def_fields,
+    validate_prismatic_joint_def_fields, validate_revolute_joint_def_fields,
+    validate_weld_joint_def_fields, validate_wheel_joint_def_fields,
 };
 
 #[inline]
@@ -89,6 +89,25 @@ impl MotorJointDef {
         super::check_motor_joint_def_valid(self)
     }
 
+    /// Run the same checks as joint creation, except body ids, plus range checks on the tuning
+    /// fields, reporting the first out-of-range field.
+    pub fn build_checked(self) -> Result<Self, crate::error::ValidationError> {
+        let c = crate::core::def_checks::DefChecks("MotorJointDef");
+        super::validate_motor_joint_def_fields(&self.0)?;
+        self.base().check_tuning_fields(c)?;
+        c.vec2("linear_velocity", self.target_linear_velocity())?;
+        c.non_negative("max_velocity_force", self.0.maxVelocityForce)?;
+        c.finite("angular_velocity", self.0.angularVelocity)?;
+        c.non_negative("max_velocity_torque", self.0.maxVelocityTorque)?;
+        c.non_negative("linear_hertz", self.0.linearHertz)?;
+        c.non_negative("linear_damping_ratio", self.0.linearDampingRatio)?;
+        c.non_negative("max_spring_force", self.0.maxSpringForce)?;
+        c.non_negative("angular_hertz", self.0.angularHertz)?;
+        c.non_negative("angular_damping_ratio", self.0.angularDampingRatio)?;
+        c.non_negative("max_spring_torque", self.0.maxSpringTorque)?;
+        Ok(self)
+    }
+
     /// Target linear velocity of body B relative to A (m/s).
     pub fn linear_velocity<V: Into<crate::types::Vec2>>(mut self, v: V) -> Self {
         self.0.linearVelocity = v.into().into_raw();
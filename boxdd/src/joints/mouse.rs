@@ -0,0 +1,245 @@
+use crate::core::world_core::WorldCore;
+use crate::types::{BodyId, JointId, Vec2};
+use crate::world::World;
+
+use super::runtime::{assert_joint_kind, check_joint_kind};
+use super::{Joint, JointBase, JointType, MotorJointDef, OwnedJoint, base};
+use crate::error::{ApiError, ApiResult};
+
+/// Default spring stiffness of a mouse joint (Hz).
+const DEFAULT_HERTZ: f32 = 5.0;
+/// Default damping ratio of a mouse joint.
+const DEFAULT_DAMPING_RATIO: f32 = 0.7;
+/// Default force limit of a mouse joint, per kilogram of the dragged body (N/kg).
+const DEFAULT_FORCE_PER_KG: f32 = 1000.0;
+
+// Mouse joint convenience builder
+/// Fluent builder for a mouse joint: a spring that pulls a grab point on a body toward a
+/// world-space target, for dragging bodies in editors and testbeds.
+///
+/// Box2D v3 has no dedicated mouse joint; this builds a [motor joint](MotorJointDef) whose linear
+/// spring connects a static ground body to the grab point, the way the upstream samples do.
+/// Move the target afterwards with [`World::mouse_set_target`]. The body keeps rotating freely
+/// around the grab point.
+///
+/// The ground body is the caller's, typically a static body shared by every mouse joint.
+///
+/// ```no_run
+/// use boxdd::{BodyBuilder, BodyType, World, WorldDef};
+/// let mut world = World::new(WorldDef::default()).unwrap();
+/// let ground = world.create_body_id(BodyBuilder::new().build());
+/// let body = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+/// let drag = world.mouse_joint(ground, body).target([0.5, 0.0]).hertz(6.0).build().id();
+/// world.mouse_set_target(drag, [3.0, 2.0]);
+/// world.step(1.0 / 60.0, 4);
+/// world.destroy_joint_id(drag, true);
+/// ```
+pub struct MouseJointBuilder<'w> {
+    pub(crate) world: &'w mut World,
+    pub(crate) body: BodyId,
+    pub(crate) ground: BodyId,
+    pub(crate) target: Option<Vec2>,
+    pub(crate) hertz: f32,
+    pub(crate) damping_ratio: f32,
+    pub(crate) max_force: Option<f32>,
+    pub(crate) collide_connected: bool,
+}
+
+impl<'w> MouseJointBuilder<'w> {
+    /// World-space grab point, which is also the initial target. Defaults to the body origin.
+    pub fn target<V: Into<Vec2>>(mut self, target: V) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+    /// Spring stiffness (Hz). Defaults to 5.
+    pub fn hertz(mut self, hertz: f32) -> Self {
+        self.hertz = hertz;
+        self
+    }
+    /// Spring damping ratio. Defaults to 0.7.
+    pub fn damping_ratio(mut self, damping_ratio: f32) -> Self {
+        self.damping_ratio = damping_ratio;
+        self
+    }
+    /// Maximum spring force (N). Defaults to 1000 times the body mass.
+    pub fn max_force(mut self, max_force: f32) -> Self {
+        self.max_force = Some(max_force);
+        self
+    }
+    /// Allow the body to collide with the ground body. Defaults to `true`.
+    pub fn collide_connected(mut self, flag: bool) -> Self {
+        self.collide_connected = flag;
+        self
+    }
+
+    #[must_use]
+    pub fn build(self) -> Joint<'w> {
+        crate::core::debug_checks::assert_body_valid(self.ground);
+        crate::core::debug_checks::assert_body_valid(self.body);
+        let def = self.def();
+        let core = self.world.core_arc();
+        let joint = self.world.create_motor_joint(&def);
+        register_mouse_joint(&core, joint.id());
+        joint
+    }
+
+    pub fn try_build(self) -> ApiResult<Joint<'w>> {
+        let def = self.try_def()?;
+        let core = self.world.core_arc();
+        let joint = self.world.try_create_motor_joint(&def)?;
+        register_mouse_joint(&core, joint.id());
+        Ok(joint)
+    }
+
+    #[must_use]
+    pub fn build_owned(self) -> OwnedJoint {
+        crate::core::debug_checks::assert_body_valid(self.ground);
+        crate::core::debug_checks::assert_body_valid(self.body);
+        let def = self.def();
+        let joint = self.world.create_motor_joint_owned(&def);
+        register_mouse_joint(self.world.core(), joint.id());
+        joint
+    }
+
+    pub fn try_build_owned(self) -> ApiResult<OwnedJoint> {
+        let def = self.try_def()?;
+        let joint = self.world.try_create_motor_joint_owned(&def)?;
+        register_mouse_joint(self.world.core(), joint.id());
+        Ok(joint)
+    }
+
+    fn try_def(&self) -> ApiResult<MotorJointDef> {
+        crate::core::debug_checks::check_body_valid(self.ground)?;
+        crate::core::debug_checks::check_body_valid(self.body)?;
+        if self.target.is_some_and(|t| !t.is_valid()) {
+            return Err(ApiError::InvalidArgument);
+        }
+        Ok(self.def())
+    }
+
+    fn def(&self) -> MotorJointDef {
+        let ground = self.ground;
+        let body_xf = crate::body::body_transform_impl(self.body);
+        let target = self.target.unwrap_or(body_xf.position());
+        let ground_xf = crate::body::body_transform_impl(ground);
+        let max_force = self.max_force.unwrap_or_else(|| {
+            DEFAULT_FORCE_PER_KG * crate::body::body_mass_impl(self.body).max(1.0)
+        });
+        let base = JointBase::builder()
+            .bodies_by_id(ground, self.body)
+            .local_frames(
+                ground_xf.inv_transform_point(target),
+                0.0,
+                body_xf.inv_transform_point(target),
+                0.0,
+            )
+            .collide_connected(self.collide_connected)
+            .build();
+        MotorJointDef::new(base)
+            .linear_hertz(self.hertz)
+            .linear_damping_ratio(self.damping_ratio)
+            .max_spring_force(max_force)
+    }
+}
+
+fn register_mouse_joint(core: &WorldCore, id: JointId) {
+    core.mouse_joints
+        .lock()
+        .expect("mouse_joints mutex poisoned")
+        .insert(id);
+}
+
+fn is_mouse_joint(core: &WorldCore, id: JointId) -> bool {
+    core.mouse_joints
+        .lock()
+        .expect("mouse_joints mutex poisoned")
+        .contains(&id)
+}
+
+#[track_caller]
+fn assert_mouse_joint(core: &WorldCore, id: JointId) {
+    assert_joint_kind(id, JointType::Motor);
+    assert!(
+        is_mouse_joint(core, id),
+        "joint {id:?} was not built with World::mouse_joint"
+    );
+}
+
+fn check_mouse_joint(core: &WorldCore, id: JointId) -> ApiResult<()> {
+    check_joint_kind(id, JointType::Motor)?;
+    if !is_mouse_joint(core, id) {
+        return Err(ApiError::InvalidJointType);
+    }
+    Ok(())
+}
+
+fn mouse_target_impl(id: JointId) -> Vec2 {
+    let ground_xf = crate::body::body_transform_impl(base::joint_body_a_id_impl(id));
+    ground_xf.transform_point(base::joint_local_frame_a_impl(id).position())
+}
+
+fn mouse_set_target_impl(id: JointId, target: Vec2) {
+    let ground_xf = crate::body::body_transform_impl(base::joint_body_a_id_impl(id));
+    let frame = base::joint_local_frame_a_impl(id);
+    let frame = crate::Transform::from_pos_angle(
+        ground_xf.inv_transform_point(target),
+        frame.rotation().angle(),
+    );
+    base::joint_set_local_frame_a_impl(id, frame);
+    base::joint_wake_bodies_impl(id);
+}
+
+impl World {
+    /// Start building a mouse joint that drags `body`, anchored to `ground` (a static or
+    /// kinematic body); see [`MouseJointBuilder`].
+    pub fn mouse_joint<'w>(&'w mut self, ground: BodyId, body: BodyId) -> MouseJointBuilder<'w> {
+        MouseJointBuilder {
+            world: self,
+            body,
+            ground,
+            target: None,
+            hertz: DEFAULT_HERTZ,
+            damping_ratio: DEFAULT_DAMPING_RATIO,
+            max_force: None,
+            collide_connected: true,
+        }
+    }
+
+    /// World-space point the mouse joint `id` is pulling toward. Panics like
+    /// [`World::mouse_set_target`].
+    pub fn mouse_target(&self, id: JointId) -> Vec2 {
+        assert_mouse_joint(self.core(), id);
+        mouse_target_impl(id)
+    }
+
+    pub fn try_mouse_target(&self, id: JointId) -> ApiResult<Vec2> {
+        check_mouse_joint(self.core(), id)?;
+        Ok(mouse_target_impl(id))
+    }
+
+    /// Move the target of a mouse joint built with [`World::mouse_joint`] and wake the body.
+    ///
+    /// Panics if `id` is not a live joint built by [`MouseJointBuilder`]; other motor joints are
+    /// rejected because their body A frame is not a grab target.
+    pub fn mouse_set_target<V: Into<Vec2>>(&mut self, id: JointId, target: V) {
+        crate::core::callback_state::assert_not_in_callback();
+        let target = target.into();
+        assert_mouse_joint(self.core(), id);
+        assert!(
+            target.is_valid(),
+            "target must be a valid Vec2, got {target:?}"
+        );
+        mouse_set_target_impl(id, target);
+    }
+
+    pub fn try_mouse_set_target<V: Into<Vec2>>(&mut self, id: JointId, target: V) -> ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        let target = target.into();
+        check_mouse_joint(self.core(), id)?;
+        if !target.is_valid() {
+            return Err(ApiError::InvalidArgument);
+        }
+        mouse_set_target_impl(id, target);
+        Ok(())
+    }
+}
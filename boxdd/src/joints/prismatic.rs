@@ -87,6 +87,20 @@ impl PrismaticJointDef {
         super::check_prismatic_joint_def_valid(self)
     }
 
+    /// Run the same checks as joint creation, except body ids, plus range checks on the tuning
+    /// fields, reporting the first out-of-range field.
+    pub fn build_checked(self) -> Result<Self, crate::error::ValidationError> {
+        let c = crate::core::def_checks::DefChecks("PrismaticJointDef");
+        super::validate_prismatic_joint_def_fields(&self.0)?;
+        self.base().check_tuning_fields(c)?;
+        c.non_negative("hertz", self.0.hertz)?;
+        c.non_negative("damping_ratio", self.0.dampingRatio)?;
+        c.finite("target_translation", self.0.targetTranslation)?;
+        c.non_negative("max_motor_force", self.0.maxMotorForce)?;
+        c.finite("motor_speed", self.0.motorSpeed)?;
+        Ok(self)
+    }
+
     /// Enable/disable spring along the prismatic axis.
     pub fn enable_spring(mut self, flag: bool) -> Self {
         self.0.enableSpring = flag;
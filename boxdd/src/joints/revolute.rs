@@ -92,6 +92,20 @@ impl RevoluteJointDef {
         super::check_revolute_joint_def_valid(self)
     }
 
+    /// Run the same checks as joint creation, except body ids, plus range checks on the tuning
+    /// fields, reporting the first out-of-range field.
+    pub fn build_checked(self) -> Result<Self, crate::error::ValidationError> {
+        let c = crate::core::def_checks::DefChecks("RevoluteJointDef");
+        super::validate_revolute_joint_def_fields(&self.0)?;
+        self.base().check_tuning_fields(c)?;
+        c.finite("target_angle", self.0.targetAngle)?;
+        c.non_negative("hertz", self.0.hertz)?;
+        c.non_negative("damping_ratio", self.0.dampingRatio)?;
+        c.non_negative("max_motor_torque", self.0.maxMotorTorque)?;
+        c.finite("motor_speed", self.0.motorSpeed)?;
+        Ok(self)
+    }
+
     pub fn target_angle(mut self, v: f32) -> Self {
         self.0.targetAngle = v;
         self
@@ -210,6 +224,35 @@ impl<'w> RevoluteJointBuilder<'w> {
         self
     }
 
+    /// Limit angles in radians with spring-cushioned stops instead of hard ones.
+    ///
+    /// Box2D solves the limit with the joint's constraint softness, so this enables the limit and
+    /// sets the base `constraint_hertz` / `constraint_damping_ratio` to `hertz` / `damping_ratio`.
+    /// A heavily loaded pendulum then eases into its stops rather than jittering against them.
+    /// The hinge point shares that softness, so keep `hertz` well above the swing frequency
+    /// (typical 15–60 Hz, damping 0.5–5); the free swing between the limits is unaffected.
+    ///
+    /// ```no_run
+    /// use boxdd::{BodyBuilder, BodyType, World, WorldDef, shapes};
+    /// let mut world = World::new(WorldDef::default()).unwrap();
+    /// let anchor = world.create_body_id(BodyBuilder::new().position([0.0, 5.0]).build());
+    /// let bob = world.create_body_id(
+    ///     BodyBuilder::new().body_type(BodyType::Dynamic).position([2.0, 5.0]).build(),
+    /// );
+    /// world.create_shape_simple(bob, shapes::box_polygon(0.5, 0.5));
+    /// let _hinge = world
+    ///     .revolute(anchor, bob)
+    ///     .anchor_world([0.0, 5.0])
+    ///     .soft_limits(-1.0, 1.0, 30.0, 2.0)
+    ///     .build();
+    /// ```
+    pub fn soft_limits(mut self, lower: f32, upper: f32, hertz: f32, damping_ratio: f32) -> Self {
+        self = self.limit(lower, upper);
+        self.def.0.base.constraintHertz = hertz;
+        self.def.0.base.constraintDampingRatio = damping_ratio;
+        self
+    }
+
     /// Convenience: enable limit and motor together.
     /// - lower/upper: radians; -pi..pi typical
     /// - max_torque: N·m; speed: rad/s
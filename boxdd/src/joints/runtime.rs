@@ -69,8 +69,13 @@ impl World {
         try_joint_read_checked_impl(id, base::joint_constraint_tuning_impl)
     }
 
+    /// Change the stiffness (Hz) and damping ratio of a joint's soft constraint at runtime.
+    ///
+    /// Raising the stiffness of a machine that starts to stretch under load keeps it together
+    /// without rebuilding its joints; values set here persist until changed again.
     pub fn set_joint_constraint_tuning(&mut self, id: JointId, tuning: ConstraintTuning) {
         assert_joint_valid(id);
+        base::assert_constraint_tuning_valid(tuning);
         base::joint_set_constraint_tuning_impl(id, tuning)
     }
 
@@ -80,6 +85,7 @@ impl World {
         tuning: ConstraintTuning,
     ) -> ApiResult<()> {
         check_joint_valid(id)?;
+        base::check_constraint_tuning_valid(tuning)?;
         base::joint_set_constraint_tuning_impl(id, tuning);
         Ok(())
     }
@@ -457,6 +457,80 @@ impl<'w> MotorJointRuntimeHandle for Joint<'w> {
     }
 }
 
+/// Set the motor velocities that carry body B's joint frame to `target` over `dt`, relative to
+/// body A's motion at its joint frame.
+fn motor_follow_impl(id: JointId, target: crate::Transform, dt: f32) {
+    let body_a = base::joint_body_a_id_impl(id);
+    let body_b = base::joint_body_b_id_impl(id);
+    let xf_a = crate::body::body_transform_impl(body_a);
+    let xf_b = crate::body::body_transform_impl(body_b);
+    let frame_a = base::joint_local_frame_a_impl(id);
+    let frame_b = base::joint_local_frame_b_impl(id);
+    let anchor_a = xf_a.transform_point(frame_a.position());
+    let current = xf_b.transform_point(frame_b.position());
+    let current_angle = xf_b.rotation().angle() + frame_b.rotation().angle();
+
+    let inv_dt = 1.0 / dt;
+    let carried = crate::body::body_world_point_velocity_impl(body_a, anchor_a);
+    let linear = target
+        .position()
+        .minus(current)
+        .scaled(inv_dt)
+        .minus(carried);
+    let mut delta_angle = target.rotation().angle() - current_angle;
+    delta_angle = (delta_angle + core::f32::consts::PI).rem_euclid(core::f32::consts::TAU)
+        - core::f32::consts::PI;
+    let angular = delta_angle * inv_dt - crate::body::body_angular_velocity_impl(body_a);
+
+    motor_set_linear_velocity_impl(id, linear);
+    motor_set_angular_velocity_impl(id, angular);
+    base::joint_wake_bodies_impl(id);
+}
+
+impl World {
+    /// Drive a motor joint so body B's joint frame reaches the world pose `target` in `dt`
+    /// seconds, for animated-but-physical objects (moving platforms, grabbed props, ragdoll
+    /// pose matching).
+    ///
+    /// Call once per step with the step's `dt`. The required relative linear and angular
+    /// velocities are computed from the current pose and body A's motion and written with
+    /// [`World::motor_set_linear_velocity`] and [`World::motor_set_angular_velocity`]; the joint
+    /// clamps the effort by its `max_velocity_force` and `max_velocity_torque`, so a blocked body
+    /// lags behind instead of pushing through. When body B's local frame is the identity,
+    /// `target` is simply the desired body transform.
+    ///
+    /// Panics if `id` is not a valid motor joint, `target` is not a valid transform, or `dt` is
+    /// not finite and positive.
+    pub fn motor_follow(&mut self, id: JointId, target: crate::Transform, dt: f32) {
+        crate::core::callback_state::assert_not_in_callback();
+        assert_joint_kind(id, JointType::Motor);
+        assert!(
+            target.is_valid(),
+            "target must be a valid transform, got {target:?}"
+        );
+        assert!(
+            dt.is_finite() && dt > 0.0,
+            "dt must be finite and > 0.0, got {dt}"
+        );
+        motor_follow_impl(id, target, dt);
+    }
+
+    pub fn try_motor_follow(
+        &mut self,
+        id: JointId,
+        target: crate::Transform,
+        dt: f32,
+    ) -> ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        check_joint_kind(id, JointType::Motor)?;
+        if !(target.is_valid() && dt.is_finite() && dt > 0.0) {
+            return Err(crate::error::ApiError::InvalidArgument);
+        }
+        motor_follow_impl(id, target, dt);
+        Ok(())
+    }
+}
+
 impl World {
     pub fn motor_linear_velocity(&self, id: JointId) -> Vec2 {
         joint_kind_get_checked_impl(id, JointType::Motor, motor_linear_velocity_impl)
@@ -45,6 +45,12 @@ fn revolute_angle_impl(id: JointId) -> f32 {
     joint_scalar_read_impl(id, ffi::b2RevoluteJoint_GetAngle)
 }
 
+/// Angular velocity of body B relative to body A, the rate of change of the joint angle.
+fn revolute_speed_impl(id: JointId) -> f32 {
+    crate::body::body_angular_velocity_impl(base::joint_body_b_id_impl(id))
+        - crate::body::body_angular_velocity_impl(base::joint_body_a_id_impl(id))
+}
+
 #[inline]
 fn revolute_limit_enabled_impl(id: JointId) -> bool {
     joint_scalar_read_impl(id, ffi::b2RevoluteJoint_IsLimitEnabled)
@@ -217,6 +223,15 @@ impl World {
         try_joint_kind_get_checked_impl(id, JointType::Revolute, revolute_angle_impl)
     }
 
+    /// Joint angular speed: body B's angular velocity relative to body A (rad/s).
+    pub fn revolute_speed(&self, id: JointId) -> f32 {
+        joint_kind_get_checked_impl(id, JointType::Revolute, revolute_speed_impl)
+    }
+
+    pub fn try_revolute_speed(&self, id: JointId) -> ApiResult<f32> {
+        try_joint_kind_get_checked_impl(id, JointType::Revolute, revolute_speed_impl)
+    }
+
     pub fn revolute_limit_enabled(&self, id: JointId) -> bool {
         joint_kind_get_checked_impl(id, JointType::Revolute, revolute_limit_enabled_impl)
     }
@@ -394,6 +409,14 @@ impl WorldHandle {
         try_joint_kind_get_checked_impl(id, JointType::Revolute, revolute_angle_impl)
     }
 
+    pub fn revolute_speed(&self, id: JointId) -> f32 {
+        joint_kind_get_checked_impl(id, JointType::Revolute, revolute_speed_impl)
+    }
+
+    pub fn try_revolute_speed(&self, id: JointId) -> ApiResult<f32> {
+        try_joint_kind_get_checked_impl(id, JointType::Revolute, revolute_speed_impl)
+    }
+
     pub fn revolute_limit_enabled(&self, id: JointId) -> bool {
         joint_kind_get_checked_impl(id, JointType::Revolute, revolute_limit_enabled_impl)
     }
@@ -506,6 +529,12 @@ impl OwnedJoint {
     pub fn try_revolute_angle(&self) -> ApiResult<f32> {
         RevoluteJointRuntimeHandle::try_revolute_angle(self)
     }
+    pub fn revolute_speed(&self) -> f32 {
+        RevoluteJointRuntimeHandle::revolute_speed(self)
+    }
+    pub fn try_revolute_speed(&self) -> ApiResult<f32> {
+        RevoluteJointRuntimeHandle::try_revolute_speed(self)
+    }
     pub fn revolute_limit_enabled(&self) -> bool {
         RevoluteJointRuntimeHandle::revolute_limit_enabled(self)
     }
@@ -635,6 +664,12 @@ impl<'w> Joint<'w> {
     pub fn try_revolute_angle(&self) -> ApiResult<f32> {
         RevoluteJointRuntimeHandle::try_revolute_angle(self)
     }
+    pub fn revolute_speed(&self) -> f32 {
+        RevoluteJointRuntimeHandle::revolute_speed(self)
+    }
+    pub fn try_revolute_speed(&self) -> ApiResult<f32> {
+        RevoluteJointRuntimeHandle::try_revolute_speed(self)
+    }
     pub fn revolute_limit_enabled(&self) -> bool {
         RevoluteJointRuntimeHandle::revolute_limit_enabled(self)
     }
@@ -864,6 +899,22 @@ trait RevoluteJointRuntimeHandle {
         )
     }
 
+    fn revolute_speed(&self) -> f32 {
+        joint_kind_get_checked_impl(
+            self.revolute_joint_id(),
+            JointType::Revolute,
+            revolute_speed_impl,
+        )
+    }
+
+    fn try_revolute_speed(&self) -> ApiResult<f32> {
+        try_joint_kind_get_checked_impl(
+            self.revolute_joint_id(),
+            JointType::Revolute,
+            revolute_speed_impl,
+        )
+    }
+
     fn revolute_limit_enabled(&self) -> bool {
         joint_kind_get_checked_impl(
             self.revolute_joint_id(),
@@ -65,6 +65,36 @@ fn wheel_enable_motor_impl(id: JointId, value: bool) {
     joint_scalar_write_impl(id, value, ffi::b2WheelJoint_EnableMotor)
 }
 
+/// Anchor separation along the suspension axis and its rate of change. The axis is body A's
+/// joint frame x axis, so it turns with body A.
+fn wheel_axis_state_impl(id: JointId) -> (f32, f32) {
+    let body_a = base::joint_body_a_id_impl(id);
+    let body_b = base::joint_body_b_id_impl(id);
+    let xf_a = crate::body::body_transform_impl(body_a);
+    let xf_b = crate::body::body_transform_impl(body_b);
+    let frame_a = base::joint_local_frame_a_impl(id);
+    let anchor_a = xf_a.transform_point(frame_a.position());
+    let anchor_b = xf_b.transform_point(base::joint_local_frame_b_impl(id).position());
+    let axis = xf_a
+        .rotation()
+        .rotate_vec(frame_a.rotation().rotate_vec(Vec2::new(1.0, 0.0)));
+    let d = anchor_b.minus(anchor_a);
+    let w_a = crate::body::body_angular_velocity_impl(body_a);
+    let relative = crate::body::body_world_point_velocity_impl(body_b, anchor_b).minus(
+        crate::body::body_world_point_velocity_impl(body_a, anchor_a),
+    );
+    let axis_rate = Vec2::new(-w_a * axis.y, w_a * axis.x);
+    (d.dot(axis), relative.dot(axis) + d.dot(axis_rate))
+}
+
+fn wheel_translation_impl(id: JointId) -> f32 {
+    wheel_axis_state_impl(id).0
+}
+
+fn wheel_speed_impl(id: JointId) -> f32 {
+    wheel_axis_state_impl(id).1
+}
+
 #[inline]
 fn wheel_motor_speed_impl(id: JointId) -> f32 {
     joint_scalar_read_impl(id, ffi::b2WheelJoint_GetMotorSpeed)
@@ -333,6 +363,30 @@ trait WheelJointRuntimeHandle {
         )
     }
 
+    fn wheel_translation(&self) -> f32 {
+        joint_kind_get_checked_impl(
+            self.wheel_joint_id(),
+            JointType::Wheel,
+            wheel_translation_impl,
+        )
+    }
+
+    fn try_wheel_translation(&self) -> ApiResult<f32> {
+        try_joint_kind_get_checked_impl(
+            self.wheel_joint_id(),
+            JointType::Wheel,
+            wheel_translation_impl,
+        )
+    }
+
+    fn wheel_speed(&self) -> f32 {
+        joint_kind_get_checked_impl(self.wheel_joint_id(), JointType::Wheel, wheel_speed_impl)
+    }
+
+    fn try_wheel_speed(&self) -> ApiResult<f32> {
+        try_joint_kind_get_checked_impl(self.wheel_joint_id(), JointType::Wheel, wheel_speed_impl)
+    }
+
     fn wheel_set_motor_speed(&mut self, speed: f32) {
         joint_kind_set_checked_impl(
             self.wheel_joint_id(),
@@ -555,6 +609,24 @@ impl World {
         try_joint_kind_get_checked_impl(id, JointType::Wheel, wheel_motor_speed_impl)
     }
 
+    /// Suspension travel: anchor B's offset from anchor A along the wheel axis (m).
+    pub fn wheel_translation(&self, id: JointId) -> f32 {
+        joint_kind_get_checked_impl(id, JointType::Wheel, wheel_translation_impl)
+    }
+
+    pub fn try_wheel_translation(&self, id: JointId) -> ApiResult<f32> {
+        try_joint_kind_get_checked_impl(id, JointType::Wheel, wheel_translation_impl)
+    }
+
+    /// Suspension speed: rate of change of [`World::wheel_translation`] (m/s).
+    pub fn wheel_speed(&self, id: JointId) -> f32 {
+        joint_kind_get_checked_impl(id, JointType::Wheel, wheel_speed_impl)
+    }
+
+    pub fn try_wheel_speed(&self, id: JointId) -> ApiResult<f32> {
+        try_joint_kind_get_checked_impl(id, JointType::Wheel, wheel_speed_impl)
+    }
+
     pub fn wheel_set_motor_speed(&mut self, id: JointId, speed: f32) {
         joint_kind_set_checked_impl(id, JointType::Wheel, speed, wheel_set_motor_speed_impl)
     }
@@ -663,6 +735,22 @@ impl WorldHandle {
         try_joint_kind_get_checked_impl(id, JointType::Wheel, wheel_motor_speed_impl)
     }
 
+    pub fn wheel_translation(&self, id: JointId) -> f32 {
+        joint_kind_get_checked_impl(id, JointType::Wheel, wheel_translation_impl)
+    }
+
+    pub fn try_wheel_translation(&self, id: JointId) -> ApiResult<f32> {
+        try_joint_kind_get_checked_impl(id, JointType::Wheel, wheel_translation_impl)
+    }
+
+    pub fn wheel_speed(&self, id: JointId) -> f32 {
+        joint_kind_get_checked_impl(id, JointType::Wheel, wheel_speed_impl)
+    }
+
+    pub fn try_wheel_speed(&self, id: JointId) -> ApiResult<f32> {
+        try_joint_kind_get_checked_impl(id, JointType::Wheel, wheel_speed_impl)
+    }
+
     pub fn wheel_motor_torque(&self, id: JointId) -> f32 {
         joint_kind_get_checked_impl(id, JointType::Wheel, wheel_motor_torque_impl)
     }
@@ -765,6 +853,18 @@ impl OwnedJoint {
     pub fn try_wheel_motor_speed(&self) -> ApiResult<f32> {
         WheelJointRuntimeHandle::try_wheel_motor_speed(self)
     }
+    pub fn wheel_translation(&self) -> f32 {
+        WheelJointRuntimeHandle::wheel_translation(self)
+    }
+    pub fn try_wheel_translation(&self) -> ApiResult<f32> {
+        WheelJointRuntimeHandle::try_wheel_translation(self)
+    }
+    pub fn wheel_speed(&self) -> f32 {
+        WheelJointRuntimeHandle::wheel_speed(self)
+    }
+    pub fn try_wheel_speed(&self) -> ApiResult<f32> {
+        WheelJointRuntimeHandle::try_wheel_speed(self)
+    }
     pub fn wheel_set_motor_speed(&mut self, speed: f32) {
         WheelJointRuntimeHandle::wheel_set_motor_speed(self, speed)
     }
@@ -876,6 +976,18 @@ impl<'w> Joint<'w> {
     pub fn try_wheel_motor_speed(&self) -> ApiResult<f32> {
         WheelJointRuntimeHandle::try_wheel_motor_speed(self)
     }
+    pub fn wheel_translation(&self) -> f32 {
+        WheelJointRuntimeHandle::wheel_translation(self)
+    }
+    pub fn try_wheel_translation(&self) -> ApiResult<f32> {
+        WheelJointRuntimeHandle::try_wheel_translation(self)
+    }
+    pub fn wheel_speed(&self) -> f32 {
+        WheelJointRuntimeHandle::wheel_speed(self)
+    }
+    pub fn try_wheel_speed(&self) -> ApiResult<f32> {
+        WheelJointRuntimeHandle::try_wheel_speed(self)
+    }
     pub fn wheel_set_motor_speed(&mut self, speed: f32) {
         WheelJointRuntimeHandle::wheel_set_motor_speed(self, speed)
     }
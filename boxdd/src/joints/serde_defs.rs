@@ -0,0 +1,236 @@
+//! serde support for joint definitions via plain config structs.
+//!
+//! Body ids are stored as-is, so a deserialized definition only makes sense in a world where
+//! those ids refer to the intended bodies (e.g. after re-creating bodies in the same order, or
+//! after patching `body_a`/`body_b` through [`JointBaseBuilder::bodies_by_id`]). The user data
+//! pointer is never serialized.
+
+use crate::Transform;
+use crate::types::{BodyId, Vec2};
+use boxdd_sys::ffi;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use super::{
+    DistanceJointDef, FilterJointDef, JointBase, JointBaseBuilder, MotorJointDef,
+    PrismaticJointDef, RevoluteJointDef, WeldJointDef, WheelJointDef,
+};
+
+impl Serialize for JointBase {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        #[derive(Serialize)]
+        struct Repr {
+            body_a: BodyId,
+            body_b: BodyId,
+            local_frame_a: Transform,
+            local_frame_b: Transform,
+            force_threshold: f32,
+            torque_threshold: f32,
+            constraint_hertz: f32,
+            constraint_damping_ratio: f32,
+            draw_scale: f32,
+            collide_connected: bool,
+        }
+        Repr {
+            body_a: self.body_a_id(),
+            body_b: self.body_b_id(),
+            local_frame_a: self.local_frame_a(),
+            local_frame_b: self.local_frame_b(),
+            force_threshold: self.0.forceThreshold,
+            torque_threshold: self.0.torqueThreshold,
+            constraint_hertz: self.0.constraintHertz,
+            constraint_damping_ratio: self.0.constraintDampingRatio,
+            draw_scale: self.0.drawScale,
+            collide_connected: self.0.collideConnected,
+        }
+        .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for JointBase {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        struct Repr {
+            body_a: BodyId,
+            body_b: BodyId,
+            local_frame_a: Transform,
+            local_frame_b: Transform,
+            force_threshold: f32,
+            torque_threshold: f32,
+            constraint_hertz: f32,
+            constraint_damping_ratio: f32,
+            draw_scale: f32,
+            collide_connected: bool,
+        }
+        let r = Repr::deserialize(deserializer)?;
+        Ok(JointBaseBuilder::new()
+            .bodies_by_id(r.body_a, r.body_b)
+            .local_frames_raw(r.local_frame_a.into_raw(), r.local_frame_b.into_raw())
+            .force_threshold(r.force_threshold)
+            .torque_threshold(r.torque_threshold)
+            .constraint_hertz(r.constraint_hertz)
+            .constraint_damping_ratio(r.constraint_damping_ratio)
+            .draw_scale(r.draw_scale)
+            .collide_connected(r.collide_connected)
+            .build())
+    }
+}
+
+/// Conversion between a raw `b2*JointDef` field and its serialized form.
+trait ReprField: Copy {
+    type Raw;
+    fn from_raw(raw: Self::Raw) -> Self;
+    fn into_raw(self) -> Self::Raw;
+}
+
+impl ReprField for f32 {
+    type Raw = f32;
+    fn from_raw(raw: f32) -> Self {
+        raw
+    }
+    fn into_raw(self) -> f32 {
+        self
+    }
+}
+
+impl ReprField for bool {
+    type Raw = bool;
+    fn from_raw(raw: bool) -> Self {
+        raw
+    }
+    fn into_raw(self) -> bool {
+        self
+    }
+}
+
+impl ReprField for Vec2 {
+    type Raw = ffi::b2Vec2;
+    fn from_raw(raw: ffi::b2Vec2) -> Self {
+        Vec2::from_raw(raw)
+    }
+    fn into_raw(self) -> ffi::b2Vec2 {
+        Vec2::into_raw(self)
+    }
+}
+
+// Each def serializes as `{ base, <fields...> }`; deserializing starts from Box2D's defaults via
+// `Def::new(base)` so internal validation markers stay intact.
+macro_rules! impl_joint_def_serde {
+    ($def:ident { $($name:ident: $ty:ty = $raw:ident),* $(,)? }) => {
+        impl Serialize for $def {
+            fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+            where
+                S: Serializer,
+            {
+                #[derive(Serialize)]
+                struct Repr {
+                    base: JointBase,
+                    $($name: $ty,)*
+                }
+                Repr {
+                    base: self.base(),
+                    $($name: <$ty as ReprField>::from_raw(self.0.$raw),)*
+                }
+                .serialize(serializer)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $def {
+            fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+            where
+                D: Deserializer<'de>,
+            {
+                #[derive(Deserialize)]
+                struct Repr {
+                    base: JointBase,
+                    $($name: $ty,)*
+                }
+                let r = Repr::deserialize(deserializer)?;
+                #[allow(unused_mut)]
+                let mut def = $def::new(r.base);
+                $(def.0.$raw = ReprField::into_raw(r.$name);)*
+                Ok(def)
+            }
+        }
+    };
+}
+
+impl_joint_def_serde!(DistanceJointDef {
+    length: f32 = length,
+    enable_spring: bool = enableSpring,
+    lower_spring_force: f32 = lowerSpringForce,
+    upper_spring_force: f32 = upperSpringForce,
+    hertz: f32 = hertz,
+    damping_ratio: f32 = dampingRatio,
+    enable_limit: bool = enableLimit,
+    min_length: f32 = minLength,
+    max_length: f32 = maxLength,
+    enable_motor: bool = enableMotor,
+    max_motor_force: f32 = maxMotorForce,
+    motor_speed: f32 = motorSpeed,
+});
+
+impl_joint_def_serde!(FilterJointDef {});
+
+impl_joint_def_serde!(MotorJointDef {
+    linear_velocity: Vec2 = linearVelocity,
+    max_velocity_force: f32 = maxVelocityForce,
+    angular_velocity: f32 = angularVelocity,
+    max_velocity_torque: f32 = maxVelocityTorque,
+    linear_hertz: f32 = linearHertz,
+    linear_damping_ratio: f32 = linearDampingRatio,
+    max_spring_force: f32 = maxSpringForce,
+    angular_hertz: f32 = angularHertz,
+    angular_damping_ratio: f32 = angularDampingRatio,
+    max_spring_torque: f32 = maxSpringTorque,
+});
+
+impl_joint_def_serde!(PrismaticJointDef {
+    enable_spring: bool = enableSpring,
+    hertz: f32 = hertz,
+    damping_ratio: f32 = dampingRatio,
+    target_translation: f32 = targetTranslation,
+    enable_limit: bool = enableLimit,
+    lower_translation: f32 = lowerTranslation,
+    upper_translation: f32 = upperTranslation,
+    enable_motor: bool = enableMotor,
+    max_motor_force: f32 = maxMotorForce,
+    motor_speed: f32 = motorSpeed,
+});
+
+impl_joint_def_serde!(RevoluteJointDef {
+    target_angle: f32 = targetAngle,
+    enable_spring: bool = enableSpring,
+    hertz: f32 = hertz,
+    damping_ratio: f32 = dampingRatio,
+    enable_limit: bool = enableLimit,
+    lower_angle: f32 = lowerAngle,
+    upper_angle: f32 = upperAngle,
+    enable_motor: bool = enableMotor,
+    max_motor_torque: f32 = maxMotorTorque,
+    motor_speed: f32 = motorSpeed,
+});
+
+impl_joint_def_serde!(WeldJointDef {
+    linear_hertz: f32 = linearHertz,
+    angular_hertz: f32 = angularHertz,
+    linear_damping_ratio: f32 = linearDampingRatio,
+    angular_damping_ratio: f32 = angularDampingRatio,
+});
+
+impl_joint_def_serde!(WheelJointDef {
+    enable_spring: bool = enableSpring,
+    hertz: f32 = hertz,
+    damping_ratio: f32 = dampingRatio,
+    enable_limit: bool = enableLimit,
+    lower_translation: f32 = lowerTranslation,
+    upper_translation: f32 = upperTranslation,
+    enable_motor: bool = enableMotor,
+    max_motor_torque: f32 = maxMotorTorque,
+    motor_speed: f32 = motorSpeed,
+});
@@ -0,0 +1,1140 @@
+//! Typed views of a scoped [`Joint`] for one joint kind.
+//!
+//! `Joint::as_revolute()` and friends check the joint type once and return a wrapper whose
+//! methods drop the kind prefix (`motor_speed()` instead of `revolute_motor_speed()`), so a
+//! prismatic-only setter cannot be called on a revolute joint by accident. Every wrapper also
+//! forwards the methods shared by all kinds (`id`, the body ids, `constraint_force`,
+//! `constraint_torque`, typed user data); [`into_joint`](RevoluteJoint::into_joint) gives back
+//! the untyped handle for everything else.
+//!
+//! ```no_run
+//! use boxdd::{BodyBuilder, BodyType, World, WorldDef};
+//! let mut world = World::new(WorldDef::default()).unwrap();
+//! let ground = world.create_body_id(BodyBuilder::new().build());
+//! let body = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+//! let joint = world.revolute(ground, body).build();
+//! let mut hinge = joint.into_revolute().unwrap();
+//! hinge.enable_motor(true);
+//! hinge.set_motor_speed(2.0);
+//! let _reaction = (hinge.angle(), hinge.constraint_torque());
+//! ```
+
+use super::{Joint, JointType};
+use crate::error::ApiResult;
+use crate::types::{BodyId, JointId, Vec2};
+
+/// Typed-wrapper boilerplate: the struct, the kind-agnostic forwards, and the `Joint`
+/// conversions.
+macro_rules! typed_joint {
+    ($(#[$doc:meta])* $name:ident, $kind:ident, $as_fn:ident, $into_fn:ident) => {
+        $(#[$doc])*
+        #[derive(Debug)]
+        pub struct $name<'w>(Joint<'w>);
+
+        impl<'w> $name<'w> {
+            /// Back to the untyped handle.
+            pub fn into_joint(self) -> Joint<'w> {
+                self.0
+            }
+
+            /// Id of the wrapped joint.
+            pub fn id(&self) -> JointId {
+                self.0.id()
+            }
+            /// First body of the joint.
+            pub fn body_a_id(&self) -> BodyId {
+                self.0.body_a_id()
+            }
+            /// Recoverable version of [`Self::body_a_id`].
+            pub fn try_body_a_id(&self) -> ApiResult<BodyId> {
+                self.0.try_body_a_id()
+            }
+            /// Second body of the joint.
+            pub fn body_b_id(&self) -> BodyId {
+                self.0.body_b_id()
+            }
+            /// Recoverable version of [`Self::body_b_id`].
+            pub fn try_body_b_id(&self) -> ApiResult<BodyId> {
+                self.0.try_body_b_id()
+            }
+            /// Wake both bodies of the joint.
+            pub fn wake_bodies(&mut self) {
+                self.0.wake_bodies()
+            }
+            /// Recoverable version of [`Self::wake_bodies`].
+            pub fn try_wake_bodies(&mut self) -> ApiResult<()> {
+                self.0.try_wake_bodies()
+            }
+            /// Constraint force applied in the last step (newtons).
+            pub fn constraint_force(&self) -> Vec2 {
+                self.0.constraint_force()
+            }
+            /// Recoverable version of [`Self::constraint_force`].
+            pub fn try_constraint_force(&self) -> ApiResult<Vec2> {
+                self.0.try_constraint_force()
+            }
+            /// Constraint torque applied in the last step (newton-meters).
+            pub fn constraint_torque(&self) -> f32 {
+                self.0.constraint_torque()
+            }
+            /// Recoverable version of [`Self::constraint_torque`].
+            pub fn try_constraint_torque(&self) -> ApiResult<f32> {
+                self.0.try_constraint_torque()
+            }
+            /// Attach typed user data; see [`Joint::set_user_data`].
+            pub fn set_user_data<T: 'static>(&mut self, value: T) {
+                self.0.set_user_data(value)
+            }
+            /// Recoverable version of [`Self::set_user_data`].
+            pub fn try_set_user_data<T: 'static>(&mut self, value: T) -> ApiResult<()> {
+                self.0.try_set_user_data(value)
+            }
+            /// Drop any typed user data; returns whether there was some.
+            pub fn clear_user_data(&mut self) -> bool {
+                self.0.clear_user_data()
+            }
+            /// Recoverable version of [`Self::clear_user_data`].
+            pub fn try_clear_user_data(&mut self) -> ApiResult<bool> {
+                self.0.try_clear_user_data()
+            }
+            /// Borrow typed user data of type `T`, if present.
+            pub fn with_user_data<T: 'static, R>(&self, f: impl FnOnce(&T) -> R) -> Option<R> {
+                self.0.with_user_data(f)
+            }
+            /// Recoverable version of [`Self::with_user_data`].
+            pub fn try_with_user_data<T: 'static, R>(
+                &self,
+                f: impl FnOnce(&T) -> R,
+            ) -> ApiResult<Option<R>> {
+                self.0.try_with_user_data(f)
+            }
+            /// Mutably borrow typed user data of type `T`, if present.
+            pub fn with_user_data_mut<T: 'static, R>(
+                &mut self,
+                f: impl FnOnce(&mut T) -> R,
+            ) -> Option<R> {
+                self.0.with_user_data_mut(f)
+            }
+            /// Recoverable version of [`Self::with_user_data_mut`].
+            pub fn try_with_user_data_mut<T: 'static, R>(
+                &mut self,
+                f: impl FnOnce(&mut T) -> R,
+            ) -> ApiResult<Option<R>> {
+                self.0.try_with_user_data_mut(f)
+            }
+            /// Remove and return typed user data of type `T`, if present.
+            pub fn take_user_data<T: 'static>(&mut self) -> Option<T> {
+                self.0.take_user_data()
+            }
+            /// Recoverable version of [`Self::take_user_data`].
+            pub fn try_take_user_data<T: 'static>(&mut self) -> ApiResult<Option<T>> {
+                self.0.try_take_user_data()
+            }
+        }
+
+        impl<'w> Joint<'w> {
+            #[doc = concat!("Typed view if this is a [`JointType::", stringify!($kind), "`] joint.")]
+            pub fn $as_fn(&mut self) -> Option<$name<'_>> {
+                self.is_kind(JointType::$kind)
+                    .then(|| $name(Joint::new(self.core.clone(), self.id)))
+            }
+
+            #[doc = concat!("Consuming version of [`Joint::", stringify!($as_fn), "`]; gives the joint back on a type mismatch.")]
+            pub fn $into_fn(self) -> Result<$name<'w>, Joint<'w>> {
+                if self.is_kind(JointType::$kind) {
+                    Ok($name(self))
+                } else {
+                    Err(self)
+                }
+            }
+        }
+    };
+}
+
+impl Joint<'_> {
+    fn is_kind(&self, kind: JointType) -> bool {
+        self.try_joint_type().is_ok_and(|t| t == kind)
+    }
+}
+
+typed_joint!(
+    /// Revolute joint: bodies share an anchor and rotate about it, with optional spring, angle limits, and motor.
+    RevoluteJoint, Revolute, as_revolute, into_revolute
+);
+
+impl RevoluteJoint<'_> {
+    /// Whether the spring is enabled.
+    pub fn spring_enabled(&self) -> bool {
+        self.0.revolute_spring_enabled()
+    }
+    /// Recoverable version of [`Self::spring_enabled`].
+    pub fn try_spring_enabled(&self) -> ApiResult<bool> {
+        self.0.try_revolute_spring_enabled()
+    }
+    /// Enable or disable the spring.
+    pub fn enable_spring(&mut self, enable: bool) {
+        self.0.revolute_enable_spring(enable)
+    }
+    /// Recoverable version of [`Self::enable_spring`].
+    pub fn try_enable_spring(&mut self, enable: bool) -> ApiResult<()> {
+        self.0.try_revolute_enable_spring(enable)
+    }
+    /// Spring stiffness in hertz.
+    pub fn spring_hertz(&self) -> f32 {
+        self.0.revolute_spring_hertz()
+    }
+    /// Recoverable version of [`Self::spring_hertz`].
+    pub fn try_spring_hertz(&self) -> ApiResult<f32> {
+        self.0.try_revolute_spring_hertz()
+    }
+    /// Set the spring stiffness in hertz.
+    pub fn set_spring_hertz(&mut self, hertz: f32) {
+        self.0.revolute_set_spring_hertz(hertz)
+    }
+    /// Recoverable version of [`Self::set_spring_hertz`].
+    pub fn try_set_spring_hertz(&mut self, hertz: f32) -> ApiResult<()> {
+        self.0.try_revolute_set_spring_hertz(hertz)
+    }
+    /// Spring damping ratio (non-dimensional).
+    pub fn spring_damping_ratio(&self) -> f32 {
+        self.0.revolute_spring_damping_ratio()
+    }
+    /// Recoverable version of [`Self::spring_damping_ratio`].
+    pub fn try_spring_damping_ratio(&self) -> ApiResult<f32> {
+        self.0.try_revolute_spring_damping_ratio()
+    }
+    /// Set the spring damping ratio (non-dimensional).
+    pub fn set_spring_damping_ratio(&mut self, damping_ratio: f32) {
+        self.0.revolute_set_spring_damping_ratio(damping_ratio)
+    }
+    /// Recoverable version of [`Self::set_spring_damping_ratio`].
+    pub fn try_set_spring_damping_ratio(&mut self, damping_ratio: f32) -> ApiResult<()> {
+        self.0.try_revolute_set_spring_damping_ratio(damping_ratio)
+    }
+    /// Angle (radians) the spring pulls toward.
+    pub fn target_angle(&self) -> f32 {
+        self.0.revolute_target_angle()
+    }
+    /// Recoverable version of [`Self::target_angle`].
+    pub fn try_target_angle(&self) -> ApiResult<f32> {
+        self.0.try_revolute_target_angle()
+    }
+    /// Set the angle (radians) the spring pulls toward.
+    pub fn set_target_angle(&mut self, angle: f32) {
+        self.0.revolute_set_target_angle(angle)
+    }
+    /// Recoverable version of [`Self::set_target_angle`].
+    pub fn try_set_target_angle(&mut self, angle: f32) -> ApiResult<()> {
+        self.0.try_revolute_set_target_angle(angle)
+    }
+    /// Current angle of body B relative to body A (radians).
+    pub fn angle(&self) -> f32 {
+        self.0.revolute_angle()
+    }
+    /// Recoverable version of [`Self::angle`].
+    pub fn try_angle(&self) -> ApiResult<f32> {
+        self.0.try_revolute_angle()
+    }
+    /// Current angular speed (radians per second).
+    pub fn speed(&self) -> f32 {
+        self.0.revolute_speed()
+    }
+    /// Recoverable version of [`Self::speed`].
+    pub fn try_speed(&self) -> ApiResult<f32> {
+        self.0.try_revolute_speed()
+    }
+    /// Whether the angle limits are enabled.
+    pub fn limit_enabled(&self) -> bool {
+        self.0.revolute_limit_enabled()
+    }
+    /// Recoverable version of [`Self::limit_enabled`].
+    pub fn try_limit_enabled(&self) -> ApiResult<bool> {
+        self.0.try_revolute_limit_enabled()
+    }
+    /// Enable or disable the angle limits.
+    pub fn enable_limit(&mut self, enable: bool) {
+        self.0.revolute_enable_limit(enable)
+    }
+    /// Recoverable version of [`Self::enable_limit`].
+    pub fn try_enable_limit(&mut self, enable: bool) -> ApiResult<()> {
+        self.0.try_revolute_enable_limit(enable)
+    }
+    /// Lower angle (radians) limit.
+    pub fn lower_limit(&self) -> f32 {
+        self.0.revolute_lower_limit()
+    }
+    /// Recoverable version of [`Self::lower_limit`].
+    pub fn try_lower_limit(&self) -> ApiResult<f32> {
+        self.0.try_revolute_lower_limit()
+    }
+    /// Upper angle (radians) limit.
+    pub fn upper_limit(&self) -> f32 {
+        self.0.revolute_upper_limit()
+    }
+    /// Recoverable version of [`Self::upper_limit`].
+    pub fn try_upper_limit(&self) -> ApiResult<f32> {
+        self.0.try_revolute_upper_limit()
+    }
+    /// Set the lower and upper angle (radians) limits.
+    pub fn set_limits(&mut self, lower: f32, upper: f32) {
+        self.0.revolute_set_limits(lower, upper)
+    }
+    /// Recoverable version of [`Self::set_limits`].
+    pub fn try_set_limits(&mut self, lower: f32, upper: f32) -> ApiResult<()> {
+        self.0.try_revolute_set_limits(lower, upper)
+    }
+    /// Whether the motor is enabled.
+    pub fn motor_enabled(&self) -> bool {
+        self.0.revolute_motor_enabled()
+    }
+    /// Recoverable version of [`Self::motor_enabled`].
+    pub fn try_motor_enabled(&self) -> ApiResult<bool> {
+        self.0.try_revolute_motor_enabled()
+    }
+    /// Enable or disable the motor.
+    pub fn enable_motor(&mut self, enable: bool) {
+        self.0.revolute_enable_motor(enable)
+    }
+    /// Recoverable version of [`Self::enable_motor`].
+    pub fn try_enable_motor(&mut self, enable: bool) -> ApiResult<()> {
+        self.0.try_revolute_enable_motor(enable)
+    }
+    /// Motor target speed (radians per second).
+    pub fn motor_speed(&self) -> f32 {
+        self.0.revolute_motor_speed()
+    }
+    /// Recoverable version of [`Self::motor_speed`].
+    pub fn try_motor_speed(&self) -> ApiResult<f32> {
+        self.0.try_revolute_motor_speed()
+    }
+    /// Set the motor target speed (radians per second).
+    pub fn set_motor_speed(&mut self, speed: f32) {
+        self.0.revolute_set_motor_speed(speed)
+    }
+    /// Recoverable version of [`Self::set_motor_speed`].
+    pub fn try_set_motor_speed(&mut self, speed: f32) -> ApiResult<()> {
+        self.0.try_revolute_set_motor_speed(speed)
+    }
+    /// Motor torque applied in the last step (newton-meters).
+    pub fn motor_torque(&self) -> f32 {
+        self.0.revolute_motor_torque()
+    }
+    /// Recoverable version of [`Self::motor_torque`].
+    pub fn try_motor_torque(&self) -> ApiResult<f32> {
+        self.0.try_revolute_motor_torque()
+    }
+    /// Maximum motor torque (newton-meters).
+    pub fn max_motor_torque(&self) -> f32 {
+        self.0.revolute_max_motor_torque()
+    }
+    /// Recoverable version of [`Self::max_motor_torque`].
+    pub fn try_max_motor_torque(&self) -> ApiResult<f32> {
+        self.0.try_revolute_max_motor_torque()
+    }
+    /// Set the maximum motor torque (newton-meters).
+    pub fn set_max_motor_torque(&mut self, torque: f32) {
+        self.0.revolute_set_max_motor_torque(torque)
+    }
+    /// Recoverable version of [`Self::set_max_motor_torque`].
+    pub fn try_set_max_motor_torque(&mut self, torque: f32) -> ApiResult<()> {
+        self.0.try_revolute_set_max_motor_torque(torque)
+    }
+}
+
+typed_joint!(
+    /// Prismatic joint: body B slides along an axis fixed in body A, with optional spring, translation limits, and motor.
+    PrismaticJoint, Prismatic, as_prismatic, into_prismatic
+);
+
+impl PrismaticJoint<'_> {
+    /// Whether the spring is enabled.
+    pub fn spring_enabled(&self) -> bool {
+        self.0.prismatic_spring_enabled()
+    }
+    /// Recoverable version of [`Self::spring_enabled`].
+    pub fn try_spring_enabled(&self) -> ApiResult<bool> {
+        self.0.try_prismatic_spring_enabled()
+    }
+    /// Enable or disable the spring.
+    pub fn enable_spring(&mut self, enable: bool) {
+        self.0.prismatic_enable_spring(enable)
+    }
+    /// Recoverable version of [`Self::enable_spring`].
+    pub fn try_enable_spring(&mut self, enable: bool) -> ApiResult<()> {
+        self.0.try_prismatic_enable_spring(enable)
+    }
+    /// Spring stiffness in hertz.
+    pub fn spring_hertz(&self) -> f32 {
+        self.0.prismatic_spring_hertz()
+    }
+    /// Recoverable version of [`Self::spring_hertz`].
+    pub fn try_spring_hertz(&self) -> ApiResult<f32> {
+        self.0.try_prismatic_spring_hertz()
+    }
+    /// Set the spring stiffness in hertz.
+    pub fn set_spring_hertz(&mut self, hertz: f32) {
+        self.0.prismatic_set_spring_hertz(hertz)
+    }
+    /// Recoverable version of [`Self::set_spring_hertz`].
+    pub fn try_set_spring_hertz(&mut self, hertz: f32) -> ApiResult<()> {
+        self.0.try_prismatic_set_spring_hertz(hertz)
+    }
+    /// Spring damping ratio (non-dimensional).
+    pub fn spring_damping_ratio(&self) -> f32 {
+        self.0.prismatic_spring_damping_ratio()
+    }
+    /// Recoverable version of [`Self::spring_damping_ratio`].
+    pub fn try_spring_damping_ratio(&self) -> ApiResult<f32> {
+        self.0.try_prismatic_spring_damping_ratio()
+    }
+    /// Set the spring damping ratio (non-dimensional).
+    pub fn set_spring_damping_ratio(&mut self, damping_ratio: f32) {
+        self.0.prismatic_set_spring_damping_ratio(damping_ratio)
+    }
+    /// Recoverable version of [`Self::set_spring_damping_ratio`].
+    pub fn try_set_spring_damping_ratio(&mut self, damping_ratio: f32) -> ApiResult<()> {
+        self.0.try_prismatic_set_spring_damping_ratio(damping_ratio)
+    }
+    /// Translation (meters) the spring pulls toward.
+    pub fn target_translation(&self) -> f32 {
+        self.0.prismatic_target_translation()
+    }
+    /// Recoverable version of [`Self::target_translation`].
+    pub fn try_target_translation(&self) -> ApiResult<f32> {
+        self.0.try_prismatic_target_translation()
+    }
+    /// Set the translation (meters) the spring pulls toward.
+    pub fn set_target_translation(&mut self, translation: f32) {
+        self.0.prismatic_set_target_translation(translation)
+    }
+    /// Recoverable version of [`Self::set_target_translation`].
+    pub fn try_set_target_translation(&mut self, translation: f32) -> ApiResult<()> {
+        self.0.try_prismatic_set_target_translation(translation)
+    }
+    /// Whether the translation limits are enabled.
+    pub fn limit_enabled(&self) -> bool {
+        self.0.prismatic_limit_enabled()
+    }
+    /// Recoverable version of [`Self::limit_enabled`].
+    pub fn try_limit_enabled(&self) -> ApiResult<bool> {
+        self.0.try_prismatic_limit_enabled()
+    }
+    /// Enable or disable the translation limits.
+    pub fn enable_limit(&mut self, enable: bool) {
+        self.0.prismatic_enable_limit(enable)
+    }
+    /// Recoverable version of [`Self::enable_limit`].
+    pub fn try_enable_limit(&mut self, enable: bool) -> ApiResult<()> {
+        self.0.try_prismatic_enable_limit(enable)
+    }
+    /// Lower translation (meters) limit.
+    pub fn lower_limit(&self) -> f32 {
+        self.0.prismatic_lower_limit()
+    }
+    /// Recoverable version of [`Self::lower_limit`].
+    pub fn try_lower_limit(&self) -> ApiResult<f32> {
+        self.0.try_prismatic_lower_limit()
+    }
+    /// Upper translation (meters) limit.
+    pub fn upper_limit(&self) -> f32 {
+        self.0.prismatic_upper_limit()
+    }
+    /// Recoverable version of [`Self::upper_limit`].
+    pub fn try_upper_limit(&self) -> ApiResult<f32> {
+        self.0.try_prismatic_upper_limit()
+    }
+    /// Set the lower and upper translation (meters) limits.
+    pub fn set_limits(&mut self, lower: f32, upper: f32) {
+        self.0.prismatic_set_limits(lower, upper)
+    }
+    /// Recoverable version of [`Self::set_limits`].
+    pub fn try_set_limits(&mut self, lower: f32, upper: f32) -> ApiResult<()> {
+        self.0.try_prismatic_set_limits(lower, upper)
+    }
+    /// Whether the motor is enabled.
+    pub fn motor_enabled(&self) -> bool {
+        self.0.prismatic_motor_enabled()
+    }
+    /// Recoverable version of [`Self::motor_enabled`].
+    pub fn try_motor_enabled(&self) -> ApiResult<bool> {
+        self.0.try_prismatic_motor_enabled()
+    }
+    /// Enable or disable the motor.
+    pub fn enable_motor(&mut self, enable: bool) {
+        self.0.prismatic_enable_motor(enable)
+    }
+    /// Recoverable version of [`Self::enable_motor`].
+    pub fn try_enable_motor(&mut self, enable: bool) -> ApiResult<()> {
+        self.0.try_prismatic_enable_motor(enable)
+    }
+    /// Motor target speed (meters per second).
+    pub fn motor_speed(&self) -> f32 {
+        self.0.prismatic_motor_speed()
+    }
+    /// Recoverable version of [`Self::motor_speed`].
+    pub fn try_motor_speed(&self) -> ApiResult<f32> {
+        self.0.try_prismatic_motor_speed()
+    }
+    /// Set the motor target speed (meters per second).
+    pub fn set_motor_speed(&mut self, speed: f32) {
+        self.0.prismatic_set_motor_speed(speed)
+    }
+    /// Recoverable version of [`Self::set_motor_speed`].
+    pub fn try_set_motor_speed(&mut self, speed: f32) -> ApiResult<()> {
+        self.0.try_prismatic_set_motor_speed(speed)
+    }
+    /// Maximum motor force (newtons).
+    pub fn max_motor_force(&self) -> f32 {
+        self.0.prismatic_max_motor_force()
+    }
+    /// Recoverable version of [`Self::max_motor_force`].
+    pub fn try_max_motor_force(&self) -> ApiResult<f32> {
+        self.0.try_prismatic_max_motor_force()
+    }
+    /// Set the maximum motor force (newtons).
+    pub fn set_max_motor_force(&mut self, force: f32) {
+        self.0.prismatic_set_max_motor_force(force)
+    }
+    /// Recoverable version of [`Self::set_max_motor_force`].
+    pub fn try_set_max_motor_force(&mut self, force: f32) -> ApiResult<()> {
+        self.0.try_prismatic_set_max_motor_force(force)
+    }
+    /// Motor force applied in the last step (newtons).
+    pub fn motor_force(&self) -> f32 {
+        self.0.prismatic_motor_force()
+    }
+    /// Recoverable version of [`Self::motor_force`].
+    pub fn try_motor_force(&self) -> ApiResult<f32> {
+        self.0.try_prismatic_motor_force()
+    }
+    /// Current translation along the axis (meters).
+    pub fn translation(&self) -> f32 {
+        self.0.prismatic_translation()
+    }
+    /// Recoverable version of [`Self::translation`].
+    pub fn try_translation(&self) -> ApiResult<f32> {
+        self.0.try_prismatic_translation()
+    }
+    /// Current translation speed (meters per second).
+    pub fn speed(&self) -> f32 {
+        self.0.prismatic_speed()
+    }
+    /// Recoverable version of [`Self::speed`].
+    pub fn try_speed(&self) -> ApiResult<f32> {
+        self.0.try_prismatic_speed()
+    }
+}
+
+typed_joint!(
+    /// Wheel joint: a suspension axis fixed in body A plus free rotation, with optional spring, suspension limits, and motor.
+    WheelJoint, Wheel, as_wheel, into_wheel
+);
+
+impl WheelJoint<'_> {
+    /// Whether the suspension spring is enabled.
+    pub fn spring_enabled(&self) -> bool {
+        self.0.wheel_spring_enabled()
+    }
+    /// Recoverable version of [`Self::spring_enabled`].
+    pub fn try_spring_enabled(&self) -> ApiResult<bool> {
+        self.0.try_wheel_spring_enabled()
+    }
+    /// Enable or disable the suspension spring.
+    pub fn enable_spring(&mut self, enable: bool) {
+        self.0.wheel_enable_spring(enable)
+    }
+    /// Recoverable version of [`Self::enable_spring`].
+    pub fn try_enable_spring(&mut self, enable: bool) -> ApiResult<()> {
+        self.0.try_wheel_enable_spring(enable)
+    }
+    /// Suspension spring stiffness in hertz.
+    pub fn spring_hertz(&self) -> f32 {
+        self.0.wheel_spring_hertz()
+    }
+    /// Recoverable version of [`Self::spring_hertz`].
+    pub fn try_spring_hertz(&self) -> ApiResult<f32> {
+        self.0.try_wheel_spring_hertz()
+    }
+    /// Set the suspension spring stiffness in hertz.
+    pub fn set_spring_hertz(&mut self, hertz: f32) {
+        self.0.wheel_set_spring_hertz(hertz)
+    }
+    /// Recoverable version of [`Self::set_spring_hertz`].
+    pub fn try_set_spring_hertz(&mut self, hertz: f32) -> ApiResult<()> {
+        self.0.try_wheel_set_spring_hertz(hertz)
+    }
+    /// Suspension spring damping ratio (non-dimensional).
+    pub fn spring_damping_ratio(&self) -> f32 {
+        self.0.wheel_spring_damping_ratio()
+    }
+    /// Recoverable version of [`Self::spring_damping_ratio`].
+    pub fn try_spring_damping_ratio(&self) -> ApiResult<f32> {
+        self.0.try_wheel_spring_damping_ratio()
+    }
+    /// Set the suspension spring damping ratio (non-dimensional).
+    pub fn set_spring_damping_ratio(&mut self, damping_ratio: f32) {
+        self.0.wheel_set_spring_damping_ratio(damping_ratio)
+    }
+    /// Recoverable version of [`Self::set_spring_damping_ratio`].
+    pub fn try_set_spring_damping_ratio(&mut self, damping_ratio: f32) -> ApiResult<()> {
+        self.0.try_wheel_set_spring_damping_ratio(damping_ratio)
+    }
+    /// Whether the translation limits are enabled.
+    pub fn limit_enabled(&self) -> bool {
+        self.0.wheel_limit_enabled()
+    }
+    /// Recoverable version of [`Self::limit_enabled`].
+    pub fn try_limit_enabled(&self) -> ApiResult<bool> {
+        self.0.try_wheel_limit_enabled()
+    }
+    /// Enable or disable the translation limits.
+    pub fn enable_limit(&mut self, enable: bool) {
+        self.0.wheel_enable_limit(enable)
+    }
+    /// Recoverable version of [`Self::enable_limit`].
+    pub fn try_enable_limit(&mut self, enable: bool) -> ApiResult<()> {
+        self.0.try_wheel_enable_limit(enable)
+    }
+    /// Lower translation (meters) limit.
+    pub fn lower_limit(&self) -> f32 {
+        self.0.wheel_lower_limit()
+    }
+    /// Recoverable version of [`Self::lower_limit`].
+    pub fn try_lower_limit(&self) -> ApiResult<f32> {
+        self.0.try_wheel_lower_limit()
+    }
+    /// Upper translation (meters) limit.
+    pub fn upper_limit(&self) -> f32 {
+        self.0.wheel_upper_limit()
+    }
+    /// Recoverable version of [`Self::upper_limit`].
+    pub fn try_upper_limit(&self) -> ApiResult<f32> {
+        self.0.try_wheel_upper_limit()
+    }
+    /// Set the lower and upper translation (meters) limits.
+    pub fn set_limits(&mut self, lower: f32, upper: f32) {
+        self.0.wheel_set_limits(lower, upper)
+    }
+    /// Recoverable version of [`Self::set_limits`].
+    pub fn try_set_limits(&mut self, lower: f32, upper: f32) -> ApiResult<()> {
+        self.0.try_wheel_set_limits(lower, upper)
+    }
+    /// Whether the motor is enabled.
+    pub fn motor_enabled(&self) -> bool {
+        self.0.wheel_motor_enabled()
+    }
+    /// Recoverable version of [`Self::motor_enabled`].
+    pub fn try_motor_enabled(&self) -> ApiResult<bool> {
+        self.0.try_wheel_motor_enabled()
+    }
+    /// Enable or disable the motor.
+    pub fn enable_motor(&mut self, enable: bool) {
+        self.0.wheel_enable_motor(enable)
+    }
+    /// Recoverable version of [`Self::enable_motor`].
+    pub fn try_enable_motor(&mut self, enable: bool) -> ApiResult<()> {
+        self.0.try_wheel_enable_motor(enable)
+    }
+    /// Motor target speed (radians per second).
+    pub fn motor_speed(&self) -> f32 {
+        self.0.wheel_motor_speed()
+    }
+    /// Recoverable version of [`Self::motor_speed`].
+    pub fn try_motor_speed(&self) -> ApiResult<f32> {
+        self.0.try_wheel_motor_speed()
+    }
+    /// Current translation along the axis (meters).
+    pub fn translation(&self) -> f32 {
+        self.0.wheel_translation()
+    }
+    /// Recoverable version of [`Self::translation`].
+    pub fn try_translation(&self) -> ApiResult<f32> {
+        self.0.try_wheel_translation()
+    }
+    /// Current translation speed (meters per second).
+    pub fn speed(&self) -> f32 {
+        self.0.wheel_speed()
+    }
+    /// Recoverable version of [`Self::speed`].
+    pub fn try_speed(&self) -> ApiResult<f32> {
+        self.0.try_wheel_speed()
+    }
+    /// Set the motor target speed (radians per second).
+    pub fn set_motor_speed(&mut self, speed: f32) {
+        self.0.wheel_set_motor_speed(speed)
+    }
+    /// Recoverable version of [`Self::set_motor_speed`].
+    pub fn try_set_motor_speed(&mut self, speed: f32) -> ApiResult<()> {
+        self.0.try_wheel_set_motor_speed(speed)
+    }
+    /// Motor torque applied in the last step (newton-meters).
+    pub fn motor_torque(&self) -> f32 {
+        self.0.wheel_motor_torque()
+    }
+    /// Recoverable version of [`Self::motor_torque`].
+    pub fn try_motor_torque(&self) -> ApiResult<f32> {
+        self.0.try_wheel_motor_torque()
+    }
+    /// Maximum motor torque (newton-meters).
+    pub fn max_motor_torque(&self) -> f32 {
+        self.0.wheel_max_motor_torque()
+    }
+    /// Recoverable version of [`Self::max_motor_torque`].
+    pub fn try_max_motor_torque(&self) -> ApiResult<f32> {
+        self.0.try_wheel_max_motor_torque()
+    }
+    /// Set the maximum motor torque (newton-meters).
+    pub fn set_max_motor_torque(&mut self, torque: f32) {
+        self.0.wheel_set_max_motor_torque(torque)
+    }
+    /// Recoverable version of [`Self::set_max_motor_torque`].
+    pub fn try_set_max_motor_torque(&mut self, torque: f32) -> ApiResult<()> {
+        self.0.try_wheel_set_max_motor_torque(torque)
+    }
+}
+
+typed_joint!(
+    /// Distance joint: keeps two anchors at a rest length, with optional spring, length range, and motor.
+    DistanceJoint, Distance, as_distance, into_distance
+);
+
+impl DistanceJoint<'_> {
+    /// Rest length of the spring (meters).
+    pub fn length(&self) -> f32 {
+        self.0.distance_length()
+    }
+    /// Recoverable version of [`Self::length`].
+    pub fn try_length(&self) -> ApiResult<f32> {
+        self.0.try_distance_length()
+    }
+    /// Set the rest length (meters).
+    pub fn set_length(&mut self, length: f32) {
+        self.0.distance_set_length(length)
+    }
+    /// Recoverable version of [`Self::set_length`].
+    pub fn try_set_length(&mut self, length: f32) -> ApiResult<()> {
+        self.0.try_distance_set_length(length)
+    }
+    /// Whether the spring is enabled.
+    pub fn spring_enabled(&self) -> bool {
+        self.0.distance_spring_enabled()
+    }
+    /// Recoverable version of [`Self::spring_enabled`].
+    pub fn try_spring_enabled(&self) -> ApiResult<bool> {
+        self.0.try_distance_spring_enabled()
+    }
+    /// Enable or disable the spring.
+    pub fn enable_spring(&mut self, enable: bool) {
+        self.0.distance_enable_spring(enable)
+    }
+    /// Recoverable version of [`Self::enable_spring`].
+    pub fn try_enable_spring(&mut self, enable: bool) -> ApiResult<()> {
+        self.0.try_distance_enable_spring(enable)
+    }
+    /// Lower bound of the spring force (newtons); negative values push.
+    pub fn lower_spring_force(&self) -> f32 {
+        self.0.distance_lower_spring_force()
+    }
+    /// Recoverable version of [`Self::lower_spring_force`].
+    pub fn try_lower_spring_force(&self) -> ApiResult<f32> {
+        self.0.try_distance_lower_spring_force()
+    }
+    /// Upper bound of the spring force (newtons).
+    pub fn upper_spring_force(&self) -> f32 {
+        self.0.distance_upper_spring_force()
+    }
+    /// Recoverable version of [`Self::upper_spring_force`].
+    pub fn try_upper_spring_force(&self) -> ApiResult<f32> {
+        self.0.try_distance_upper_spring_force()
+    }
+    /// Set the lower and upper spring force bounds (newtons).
+    pub fn set_spring_force_range(&mut self, lower_force: f32, upper_force: f32) {
+        self.0
+            .distance_set_spring_force_range(lower_force, upper_force)
+    }
+    /// Recoverable version of [`Self::set_spring_force_range`].
+    pub fn try_set_spring_force_range(
+        &mut self,
+        lower_force: f32,
+        upper_force: f32,
+    ) -> ApiResult<()> {
+        self.0
+            .try_distance_set_spring_force_range(lower_force, upper_force)
+    }
+    /// Spring stiffness in hertz.
+    pub fn spring_hertz(&self) -> f32 {
+        self.0.distance_spring_hertz()
+    }
+    /// Recoverable version of [`Self::spring_hertz`].
+    pub fn try_spring_hertz(&self) -> ApiResult<f32> {
+        self.0.try_distance_spring_hertz()
+    }
+    /// Set the spring stiffness in hertz.
+    pub fn set_spring_hertz(&mut self, hertz: f32) {
+        self.0.distance_set_spring_hertz(hertz)
+    }
+    /// Recoverable version of [`Self::set_spring_hertz`].
+    pub fn try_set_spring_hertz(&mut self, hertz: f32) -> ApiResult<()> {
+        self.0.try_distance_set_spring_hertz(hertz)
+    }
+    /// Spring damping ratio (non-dimensional).
+    pub fn spring_damping_ratio(&self) -> f32 {
+        self.0.distance_spring_damping_ratio()
+    }
+    /// Recoverable version of [`Self::spring_damping_ratio`].
+    pub fn try_spring_damping_ratio(&self) -> ApiResult<f32> {
+        self.0.try_distance_spring_damping_ratio()
+    }
+    /// Set the spring damping ratio (non-dimensional).
+    pub fn set_spring_damping_ratio(&mut self, damping_ratio: f32) {
+        self.0.distance_set_spring_damping_ratio(damping_ratio)
+    }
+    /// Recoverable version of [`Self::set_spring_damping_ratio`].
+    pub fn try_set_spring_damping_ratio(&mut self, damping_ratio: f32) -> ApiResult<()> {
+        self.0.try_distance_set_spring_damping_ratio(damping_ratio)
+    }
+    /// Whether the length range is enforced.
+    pub fn limit_enabled(&self) -> bool {
+        self.0.distance_limit_enabled()
+    }
+    /// Recoverable version of [`Self::limit_enabled`].
+    pub fn try_limit_enabled(&self) -> ApiResult<bool> {
+        self.0.try_distance_limit_enabled()
+    }
+    /// Enable or disable the length range.
+    pub fn enable_limit(&mut self, enable: bool) {
+        self.0.distance_enable_limit(enable)
+    }
+    /// Recoverable version of [`Self::enable_limit`].
+    pub fn try_enable_limit(&mut self, enable: bool) -> ApiResult<()> {
+        self.0.try_distance_enable_limit(enable)
+    }
+    /// Minimum length allowed by the length range (meters).
+    pub fn min_length(&self) -> f32 {
+        self.0.distance_min_length()
+    }
+    /// Recoverable version of [`Self::min_length`].
+    pub fn try_min_length(&self) -> ApiResult<f32> {
+        self.0.try_distance_min_length()
+    }
+    /// Maximum length allowed by the length range (meters).
+    pub fn max_length(&self) -> f32 {
+        self.0.distance_max_length()
+    }
+    /// Recoverable version of [`Self::max_length`].
+    pub fn try_max_length(&self) -> ApiResult<f32> {
+        self.0.try_distance_max_length()
+    }
+    /// Current distance between the anchors (meters).
+    pub fn current_length(&self) -> f32 {
+        self.0.distance_current_length()
+    }
+    /// Recoverable version of [`Self::current_length`].
+    pub fn try_current_length(&self) -> ApiResult<f32> {
+        self.0.try_distance_current_length()
+    }
+    /// Set the minimum and maximum length (meters).
+    pub fn set_length_range(&mut self, min_length: f32, max_length: f32) {
+        self.0.distance_set_length_range(min_length, max_length)
+    }
+    /// Recoverable version of [`Self::set_length_range`].
+    pub fn try_set_length_range(&mut self, min_length: f32, max_length: f32) -> ApiResult<()> {
+        self.0.try_distance_set_length_range(min_length, max_length)
+    }
+    /// Whether the motor is enabled.
+    pub fn motor_enabled(&self) -> bool {
+        self.0.distance_motor_enabled()
+    }
+    /// Recoverable version of [`Self::motor_enabled`].
+    pub fn try_motor_enabled(&self) -> ApiResult<bool> {
+        self.0.try_distance_motor_enabled()
+    }
+    /// Enable or disable the motor.
+    pub fn enable_motor(&mut self, enable: bool) {
+        self.0.distance_enable_motor(enable)
+    }
+    /// Recoverable version of [`Self::enable_motor`].
+    pub fn try_enable_motor(&mut self, enable: bool) -> ApiResult<()> {
+        self.0.try_distance_enable_motor(enable)
+    }
+    /// Motor target speed (meters per second).
+    pub fn motor_speed(&self) -> f32 {
+        self.0.distance_motor_speed()
+    }
+    /// Recoverable version of [`Self::motor_speed`].
+    pub fn try_motor_speed(&self) -> ApiResult<f32> {
+        self.0.try_distance_motor_speed()
+    }
+    /// Set the motor target speed (meters per second).
+    pub fn set_motor_speed(&mut self, speed: f32) {
+        self.0.distance_set_motor_speed(speed)
+    }
+    /// Recoverable version of [`Self::set_motor_speed`].
+    pub fn try_set_motor_speed(&mut self, speed: f32) -> ApiResult<()> {
+        self.0.try_distance_set_motor_speed(speed)
+    }
+    /// Maximum motor force (newtons).
+    pub fn max_motor_force(&self) -> f32 {
+        self.0.distance_max_motor_force()
+    }
+    /// Recoverable version of [`Self::max_motor_force`].
+    pub fn try_max_motor_force(&self) -> ApiResult<f32> {
+        self.0.try_distance_max_motor_force()
+    }
+    /// Set the maximum motor force (newtons).
+    pub fn set_max_motor_force(&mut self, force: f32) {
+        self.0.distance_set_max_motor_force(force)
+    }
+    /// Recoverable version of [`Self::set_max_motor_force`].
+    pub fn try_set_max_motor_force(&mut self, force: f32) -> ApiResult<()> {
+        self.0.try_distance_set_max_motor_force(force)
+    }
+    /// Motor force applied in the last step (newtons).
+    pub fn motor_force(&self) -> f32 {
+        self.0.distance_motor_force()
+    }
+    /// Recoverable version of [`Self::motor_force`].
+    pub fn try_motor_force(&self) -> ApiResult<f32> {
+        self.0.try_distance_motor_force()
+    }
+}
+
+typed_joint!(
+    /// Weld joint: glues two bodies together, optionally softened by linear and angular springs.
+    WeldJoint, Weld, as_weld, into_weld
+);
+
+impl WeldJoint<'_> {
+    /// Linear spring stiffness in hertz; zero is rigid.
+    pub fn linear_hertz(&self) -> f32 {
+        self.0.weld_linear_hertz()
+    }
+    /// Recoverable version of [`Self::linear_hertz`].
+    pub fn try_linear_hertz(&self) -> ApiResult<f32> {
+        self.0.try_weld_linear_hertz()
+    }
+    /// Set the linear spring stiffness in hertz.
+    pub fn set_linear_hertz(&mut self, hertz: f32) {
+        self.0.weld_set_linear_hertz(hertz)
+    }
+    /// Recoverable version of [`Self::set_linear_hertz`].
+    pub fn try_set_linear_hertz(&mut self, hertz: f32) -> ApiResult<()> {
+        self.0.try_weld_set_linear_hertz(hertz)
+    }
+    /// Linear spring damping ratio (non-dimensional).
+    pub fn linear_damping_ratio(&self) -> f32 {
+        self.0.weld_linear_damping_ratio()
+    }
+    /// Recoverable version of [`Self::linear_damping_ratio`].
+    pub fn try_linear_damping_ratio(&self) -> ApiResult<f32> {
+        self.0.try_weld_linear_damping_ratio()
+    }
+    /// Set the linear spring damping ratio (non-dimensional).
+    pub fn set_linear_damping_ratio(&mut self, damping_ratio: f32) {
+        self.0.weld_set_linear_damping_ratio(damping_ratio)
+    }
+    /// Recoverable version of [`Self::set_linear_damping_ratio`].
+    pub fn try_set_linear_damping_ratio(&mut self, damping_ratio: f32) -> ApiResult<()> {
+        self.0.try_weld_set_linear_damping_ratio(damping_ratio)
+    }
+    /// Angular spring stiffness in hertz; zero is rigid.
+    pub fn angular_hertz(&self) -> f32 {
+        self.0.weld_angular_hertz()
+    }
+    /// Recoverable version of [`Self::angular_hertz`].
+    pub fn try_angular_hertz(&self) -> ApiResult<f32> {
+        self.0.try_weld_angular_hertz()
+    }
+    /// Set the angular spring stiffness in hertz.
+    pub fn set_angular_hertz(&mut self, hertz: f32) {
+        self.0.weld_set_angular_hertz(hertz)
+    }
+    /// Recoverable version of [`Self::set_angular_hertz`].
+    pub fn try_set_angular_hertz(&mut self, hertz: f32) -> ApiResult<()> {
+        self.0.try_weld_set_angular_hertz(hertz)
+    }
+    /// Angular spring damping ratio (non-dimensional).
+    pub fn angular_damping_ratio(&self) -> f32 {
+        self.0.weld_angular_damping_ratio()
+    }
+    /// Recoverable version of [`Self::angular_damping_ratio`].
+    pub fn try_angular_damping_ratio(&self) -> ApiResult<f32> {
+        self.0.try_weld_angular_damping_ratio()
+    }
+    /// Set the angular spring damping ratio (non-dimensional).
+    pub fn set_angular_damping_ratio(&mut self, damping_ratio: f32) {
+        self.0.weld_set_angular_damping_ratio(damping_ratio)
+    }
+    /// Recoverable version of [`Self::set_angular_damping_ratio`].
+    pub fn try_set_angular_damping_ratio(&mut self, damping_ratio: f32) -> ApiResult<()> {
+        self.0.try_weld_set_angular_damping_ratio(damping_ratio)
+    }
+}
+
+typed_joint!(
+    /// Motor joint: drives body B relative to body A with velocity targets and position springs.
+    MotorJoint, Motor, as_motor, into_motor
+);
+
+impl MotorJoint<'_> {
+    /// Target linear velocity of body B relative to body A (meters per second).
+    pub fn linear_velocity(&self) -> Vec2 {
+        self.0.motor_linear_velocity()
+    }
+    /// Recoverable version of [`Self::linear_velocity`].
+    pub fn try_linear_velocity(&self) -> ApiResult<Vec2> {
+        self.0.try_motor_linear_velocity()
+    }
+    /// Set the target linear velocity (meters per second).
+    pub fn set_linear_velocity<V: Into<Vec2>>(&mut self, v: V) {
+        self.0.motor_set_linear_velocity(v)
+    }
+    /// Recoverable version of [`Self::set_linear_velocity`].
+    pub fn try_set_linear_velocity<V: Into<Vec2>>(&mut self, v: V) -> ApiResult<()> {
+        self.0.try_motor_set_linear_velocity(v)
+    }
+    /// Target angular velocity of body B relative to body A (radians per second).
+    pub fn angular_velocity(&self) -> f32 {
+        self.0.motor_angular_velocity()
+    }
+    /// Recoverable version of [`Self::angular_velocity`].
+    pub fn try_angular_velocity(&self) -> ApiResult<f32> {
+        self.0.try_motor_angular_velocity()
+    }
+    /// Set the target angular velocity (radians per second).
+    pub fn set_angular_velocity(&mut self, w: f32) {
+        self.0.motor_set_angular_velocity(w)
+    }
+    /// Recoverable version of [`Self::set_angular_velocity`].
+    pub fn try_set_angular_velocity(&mut self, w: f32) -> ApiResult<()> {
+        self.0.try_motor_set_angular_velocity(w)
+    }
+    /// Maximum force used to reach the target velocity (newtons).
+    pub fn max_velocity_force(&self) -> f32 {
+        self.0.motor_max_velocity_force()
+    }
+    /// Recoverable version of [`Self::max_velocity_force`].
+    pub fn try_max_velocity_force(&self) -> ApiResult<f32> {
+        self.0.try_motor_max_velocity_force()
+    }
+    /// Set the maximum force used to reach the target velocity (newtons).
+    pub fn set_max_velocity_force(&mut self, f: f32) {
+        self.0.motor_set_max_velocity_force(f)
+    }
+    /// Recoverable version of [`Self::set_max_velocity_force`].
+    pub fn try_set_max_velocity_force(&mut self, f: f32) -> ApiResult<()> {
+        self.0.try_motor_set_max_velocity_force(f)
+    }
+    /// Maximum torque used to reach the target angular velocity (newton-meters).
+    pub fn max_velocity_torque(&self) -> f32 {
+        self.0.motor_max_velocity_torque()
+    }
+    /// Recoverable version of [`Self::max_velocity_torque`].
+    pub fn try_max_velocity_torque(&self) -> ApiResult<f32> {
+        self.0.try_motor_max_velocity_torque()
+    }
+    /// Set the maximum torque used to reach the target angular velocity (newton-meters).
+    pub fn set_max_velocity_torque(&mut self, t: f32) {
+        self.0.motor_set_max_velocity_torque(t)
+    }
+    /// Recoverable version of [`Self::set_max_velocity_torque`].
+    pub fn try_set_max_velocity_torque(&mut self, t: f32) -> ApiResult<()> {
+        self.0.try_motor_set_max_velocity_torque(t)
+    }
+    /// Linear position spring stiffness in hertz.
+    pub fn linear_hertz(&self) -> f32 {
+        self.0.motor_linear_hertz()
+    }
+    /// Recoverable version of [`Self::linear_hertz`].
+    pub fn try_linear_hertz(&self) -> ApiResult<f32> {
+        self.0.try_motor_linear_hertz()
+    }
+    /// Set the linear spring stiffness in hertz.
+    pub fn set_linear_hertz(&mut self, hertz: f32) {
+        self.0.motor_set_linear_hertz(hertz)
+    }
+    /// Recoverable version of [`Self::set_linear_hertz`].
+    pub fn try_set_linear_hertz(&mut self, hertz: f32) -> ApiResult<()> {
+        self.0.try_motor_set_linear_hertz(hertz)
+    }
+    /// Linear spring damping ratio (non-dimensional).
+    pub fn linear_damping_ratio(&self) -> f32 {
+        self.0.motor_linear_damping_ratio()
+    }
+    /// Recoverable version of [`Self::linear_damping_ratio`].
+    pub fn try_linear_damping_ratio(&self) -> ApiResult<f32> {
+        self.0.try_motor_linear_damping_ratio()
+    }
+    /// Set the linear spring damping ratio (non-dimensional).
+    pub fn set_linear_damping_ratio(&mut self, damping: f32) {
+        self.0.motor_set_linear_damping_ratio(damping)
+    }
+    /// Recoverable version of [`Self::set_linear_damping_ratio`].
+    pub fn try_set_linear_damping_ratio(&mut self, damping: f32) -> ApiResult<()> {
+        self.0.try_motor_set_linear_damping_ratio(damping)
+    }
+    /// Angular position spring stiffness in hertz.
+    pub fn angular_hertz(&self) -> f32 {
+        self.0.motor_angular_hertz()
+    }
+    /// Recoverable version of [`Self::angular_hertz`].
+    pub fn try_angular_hertz(&self) -> ApiResult<f32> {
+        self.0.try_motor_angular_hertz()
+    }
+    /// Set the angular spring stiffness in hertz.
+    pub fn set_angular_hertz(&mut self, hertz: f32) {
+        self.0.motor_set_angular_hertz(hertz)
+    }
+    /// Recoverable version of [`Self::set_angular_hertz`].
+    pub fn try_set_angular_hertz(&mut self, hertz: f32) -> ApiResult<()> {
+        self.0.try_motor_set_angular_hertz(hertz)
+    }
+    /// Angular spring damping ratio (non-dimensional).
+    pub fn angular_damping_ratio(&self) -> f32 {
+        self.0.motor_angular_damping_ratio()
+    }
+    /// Recoverable version of [`Self::angular_damping_ratio`].
+    pub fn try_angular_damping_ratio(&self) -> ApiResult<f32> {
+        self.0.try_motor_angular_damping_ratio()
+    }
+    /// Set the angular spring damping ratio (non-dimensional).
+    pub fn set_angular_damping_ratio(&mut self, damping: f32) {
+        self.0.motor_set_angular_damping_ratio(damping)
+    }
+    /// Recoverable version of [`Self::set_angular_damping_ratio`].
+    pub fn try_set_angular_damping_ratio(&mut self, damping: f32) -> ApiResult<()> {
+        self.0.try_motor_set_angular_damping_ratio(damping)
+    }
+    /// Maximum force of the linear position spring (newtons).
+    pub fn max_spring_force(&self) -> f32 {
+        self.0.motor_max_spring_force()
+    }
+    /// Recoverable version of [`Self::max_spring_force`].
+    pub fn try_max_spring_force(&self) -> ApiResult<f32> {
+        self.0.try_motor_max_spring_force()
+    }
+    /// Set the maximum force of the linear position spring (newtons).
+    pub fn set_max_spring_force(&mut self, f: f32) {
+        self.0.motor_set_max_spring_force(f)
+    }
+    /// Recoverable version of [`Self::set_max_spring_force`].
+    pub fn try_set_max_spring_force(&mut self, f: f32) -> ApiResult<()> {
+        self.0.try_motor_set_max_spring_force(f)
+    }
+    /// Maximum torque of the angular position spring (newton-meters).
+    pub fn max_spring_torque(&self) -> f32 {
+        self.0.motor_max_spring_torque()
+    }
+    /// Recoverable version of [`Self::max_spring_torque`].
+    pub fn try_max_spring_torque(&self) -> ApiResult<f32> {
+        self.0.try_motor_max_spring_torque()
+    }
+    /// Set the maximum torque of the angular position spring (newton-meters).
+    pub fn set_max_spring_torque(&mut self, t: f32) {
+        self.0.motor_set_max_spring_torque(t)
+    }
+    /// Recoverable version of [`Self::set_max_spring_torque`].
+    pub fn try_set_max_spring_torque(&mut self, t: f32) -> ApiResult<()> {
+        self.0.try_motor_set_max_spring_torque(t)
+    }
+}
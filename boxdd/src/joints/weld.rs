@@ -58,6 +58,19 @@ impl WeldJointDef {
         super::check_weld_joint_def_valid(self)
     }
 
+    /// Run the same checks as joint creation, except body ids, plus range checks on the tuning
+    /// fields, reporting the first out-of-range field.
+    pub fn build_checked(self) -> Result<Self, crate::error::ValidationError> {
+        let c = crate::core::def_checks::DefChecks("WeldJointDef");
+        super::validate_weld_joint_def_fields(&self.0)?;
+        self.base().check_tuning_fields(c)?;
+        c.non_negative("linear_hertz", self.0.linearHertz)?;
+        c.non_negative("angular_hertz", self.0.angularHertz)?;
+        c.non_negative("linear_damping_ratio", self.0.linearDampingRatio)?;
+        c.non_negative("angular_damping_ratio", self.0.angularDampingRatio)?;
+        Ok(self)
+    }
+
     /// Linear stiffness (Hz) for weld constraint.
     pub fn linear_hertz(mut self, v: f32) -> Self {
         self.0.linearHertz = v;
@@ -87,6 +87,19 @@ impl WheelJointDef {
         super::check_wheel_joint_def_valid(self)
     }
 
+    /// Run the same checks as joint creation, except body ids, plus range checks on the tuning
+    /// fields, reporting the first out-of-range field.
+    pub fn build_checked(self) -> Result<Self, crate::error::ValidationError> {
+        let c = crate::core::def_checks::DefChecks("WheelJointDef");
+        super::validate_wheel_joint_def_fields(&self.0)?;
+        self.base().check_tuning_fields(c)?;
+        c.non_negative("hertz", self.0.hertz)?;
+        c.non_negative("damping_ratio", self.0.dampingRatio)?;
+        c.non_negative("max_motor_torque", self.0.maxMotorTorque)?;
+        c.finite("motor_speed", self.0.motorSpeed)?;
+        Ok(self)
+    }
+
     /// Enable/disable suspension spring.
     pub fn enable_spring(mut self, flag: bool) -> Self {
         self.0.enableSpring = flag;
@@ -9,6 +9,7 @@
 //! - Character mover helpers: cast movers, collect collision planes, solve planes, and clip velocity without raw FFI.
 //! - Standalone collision geometry helpers: shape proxies, segment/GJK distance, manifolds, shape cast, TOI, recoverable `try_*` validation paths, AABB validation/ray cast, and deterministic global math helpers.
 //! - Core math types (`Vec2`, `Rot`, `Transform`) use explicit `from_raw(...)` / `into_raw()` naming for Box2D interop instead of implicit raw conversions.
+//! - `build_info()` reports crate/Box2D versions, forwarded native features, and how Box2D was linked for bug triage.
 //! - Global Box2D foundation helpers expose allocated-byte inspection, timing ticks/millisecond helpers, thread yielding, and deterministic hashing without dropping to `boxdd_sys::ffi`.
 //! - Shape geometry uses crate-owned values (`Circle`, `Segment`, `ChainSegment`, `Capsule`, `Polygon`) across helpers, shape editing, and creation, including square/rounded/offset/hull-based polygon builders plus standalone and construction-time `try_*` geometry helpers without raw FFI.
 //! - Chain runtime material helpers use visible live-segment indexing on open chains instead of Box2D's ghost-placeholder storage layout.
@@ -28,6 +29,7 @@
 //!   - ID-style: raw ids (`BodyId`/`ShapeId`/`JointId`/`ChainId`) for maximum flexibility.
 //! - Safe handle methods validate ids and panic on invalid ids (prevents UB if an id becomes stale).
 //!   For recoverable failures (invalid ids / wrong typed-joint family / calling during Box2D callbacks), use `try_*` APIs returning `ApiResult<T>`.
+//! - Deterministic ordering: id types implement `Ord` (slot index, world, generation); `World::body_ids()` / `sorted_body_ids()` and `active_region_disabled_bodies()` return ids in that order, and event arrays keep Box2D's step order, which is reproducible for identical inputs.
 //! - Threading: `World` and owned handles are `!Send`/`!Sync`. Run physics on one thread; in async runtimes prefer
 //!   `spawn_local`/`LocalSet`, or create the world inside a dedicated physics thread and communicate via channels.
 //!
@@ -81,6 +83,10 @@
 //! Modules
 //! - `world`, `body`, `contact`, `shapes`, `joints`, `query`, `collision`, `events`, `debug_draw`, `prelude`.
 //!   Import `boxdd::prelude::*` for the most common types.
+//! - `camera`: `Camera2D` world/screen conversion, zoom-at-cursor, and viewport culling bounds.
+//! - `character`: `CharacterMover`, a capsule move-and-slide controller over the mover queries.
+//! - `compound`: the upstream samples' bridge, donut, doohickey, car, and ragdoll as parameterized constructors.
+//! - `verlet`: rope/cloth point masses with distance constraints, stepped after the world.
 //!
 //! Queries (AABB + Ray Cast)
 //! ```no_run
@@ -168,18 +174,26 @@
 //! ```
 //!
 //! Feature Flags
+//! - `debug-draw` (default): the `debug_draw` module and `World::debug_draw*`. Headless servers can
+//!   build with `default-features = false`; `HexColor` stays available for shape materials.
 //! - `serialize`: scene snapshot helpers (save/apply world config; build/restore minimal full-scene snapshot).
 //! - `pkg-config`: allow linking against a system `box2d` via pkg-config.
 //! - `mint`: lightweight math interop types (`mint::Vector2`, `mint::Point2`, `mint::RowMatrix2` /
 //!   `mint::ColumnMatrix2` for `Rot`, and row/column-major 2D affine matrices for `Transform`).
 //! - `cgmath` / `nalgebra` / `glam`: conversions with their 2D math types.
 //! - `bytemuck`: `Pod`/`Zeroable` for core math types (`Vec2`, `Rot`, `Transform`, `Aabb`) for zero-copy interop.
+//! - `polygon-simplify`: reduce polygon-from-points inputs above `MAX_POLYGON_VERTICES` instead of
+//!   rejecting them.
+//! - `particles`: position-based particle fluid (`particles::ParticleSystem`) stepped after `World::step`.
+//! - `parallel`: step on a rayon thread pool (`RayonTaskSystem`) whenever `worker_count(n)` asks
+//!   for more than one worker.
 //!
 //! Threading and async
-//! - `WorldDef::builder().worker_count(n)` preserves Box2D's worker-count setting, but actual
-//!   multithreaded stepping still requires explicit raw task callbacks through
-//!   `unsafe WorldBuilder::task_system_raw(...)` / `WorldDef::set_task_system_raw(...)`. It does
-//!   not make `World`, `WorldHandle`, or owned handles `Send`/`Sync`.
+//! - `WorldDef::builder().worker_count(n)` preserves Box2D's worker-count setting. Multithreaded
+//!   stepping needs a task system: the `parallel` feature's rayon pool, your own `TaskSystem`
+//!   through `WorldBuilder::task_system(...)`, or raw callbacks through
+//!   `unsafe WorldBuilder::task_system_raw(...)`. None of these make `World`, `WorldHandle`, or
+//!   owned handles `Send`/`Sync`.
 //! - Keep the world on one thread/task. In async runtimes prefer `spawn_local` / `LocalSet`; in
 //!   multi-threaded engines prefer a dedicated physics thread plus channels.
 //! - `set_custom_filter*`, `set_pre_solve*`, `set_friction_callback`, and `set_restitution_callback`
@@ -194,6 +208,8 @@
 //!   values instead of relying on assert-enabled native builds.
 //! - `WorldDef`, `BodyDef`, `ShapeDef`, `SurfaceMaterial`, `JointBase`, and concrete
 //!   `*JointDef` values expose `validate()` for preflight checks before crossing the FFI boundary.
+//! - Definition builders and joint defs also offer `build_checked()`, which returns a
+//!   `ValidationError` naming the offending field instead of a bare `ApiError`.
 //! - Crate-owned geometry values (`Circle`, `Segment`, `Capsule`, `ChainSegment`, `Polygon`) also
 //!   expose `is_valid()` / `validate()` for preflight geometry checks, and the world-free helper
 //!   methods (`mass_data`, `aabb`, `contains_point`, `ray_cast`, `transformed`) follow the same
@@ -225,14 +241,26 @@
 //! ```
 
 pub mod body;
+pub mod build_info;
+pub mod camera;
+pub mod character;
 pub mod collision;
+mod color;
+pub mod compound;
 pub mod contact;
+#[cfg(feature = "debug-draw")]
+#[cfg_attr(docsrs, doc(cfg(feature = "debug-draw")))]
 pub mod debug_draw;
 pub mod dynamic_tree;
 pub mod error;
 pub mod events;
 pub mod filter;
 pub mod joints;
+pub mod limits;
+pub mod net;
+#[cfg(feature = "particles")]
+#[cfg_attr(docsrs, doc(cfg(feature = "particles")))]
+pub mod particles;
 pub mod prelude;
 pub mod query;
 #[cfg(feature = "serialize")]
@@ -244,38 +272,49 @@ pub mod types;
 #[cfg(feature = "unchecked")]
 #[cfg_attr(docsrs, doc(cfg(feature = "unchecked")))]
 pub mod unchecked;
+pub mod verlet;
 pub mod world;
 pub mod world_extras;
 pub mod core {
+    #[cfg(feature = "force-tracking")]
+    pub(crate) mod applied_forces;
     pub(crate) mod box2d_lock;
     pub(crate) mod callback_state;
     pub(crate) mod debug_checks;
+    pub(crate) mod def_checks;
     pub(crate) mod ffi_vec;
     pub(crate) mod material_mix_registry;
     pub mod math;
+    pub(crate) mod point_collision;
+    pub(crate) mod query_stats;
     #[cfg(feature = "serialize")]
     pub(crate) mod serialize_registry;
+    pub(crate) mod static_revision;
     pub(crate) mod user_data;
     pub(crate) mod world_core;
 }
 
 pub use body::OwnedBody;
-pub use body::{Body, BodyBuilder, BodyDef, BodyType};
+pub use body::{Body, BodyBuilder, BodyDef, BodyFinalState, BodyType};
+pub use build_info::{BuildInfo, NativeBuild, build_info};
+pub use camera::Camera2D;
 pub use collision::{
     CastOutput, DistanceInput, DistanceOutput, MAX_SHAPE_PROXY_POINTS, SegmentDistanceResult,
     ShapeCastInput, ShapeCastPairInput, ShapeProxy, SimplexCache, Sweep, ToiInput, ToiOutput,
-    ToiState, collide_capsule_and_circle, collide_capsules, collide_chain_segment_and_capsule,
-    collide_chain_segment_and_circle, collide_chain_segment_and_polygon, collide_circles,
-    collide_polygon_and_capsule, collide_polygon_and_circle, collide_polygons,
-    collide_segment_and_capsule, collide_segment_and_circle, collide_segment_and_polygon,
-    segment_distance, shape_cast, shape_distance, time_of_impact, try_collide_capsule_and_circle,
+    ToiState, collide, collide_capsule_and_circle, collide_capsules,
+    collide_chain_segment_and_capsule, collide_chain_segment_and_circle,
+    collide_chain_segment_and_polygon, collide_circles, collide_polygon_and_capsule,
+    collide_polygon_and_circle, collide_polygons, collide_segment_and_capsule,
+    collide_segment_and_circle, collide_segment_and_polygon, geometry_distance, segment_distance,
+    shape_cast, shape_distance, time_of_impact, try_collide, try_collide_capsule_and_circle,
     try_collide_capsules, try_collide_chain_segment_and_capsule,
     try_collide_chain_segment_and_circle, try_collide_chain_segment_and_polygon,
     try_collide_circles, try_collide_polygon_and_capsule, try_collide_polygon_and_circle,
     try_collide_polygons, try_collide_segment_and_capsule, try_collide_segment_and_circle,
-    try_collide_segment_and_polygon, try_segment_distance, try_shape_cast, try_shape_distance,
-    try_time_of_impact,
+    try_collide_segment_and_polygon, try_geometry_distance, try_segment_distance, try_shape_cast,
+    try_shape_distance, try_time_of_impact,
 };
+pub use color::HexColor;
 #[cfg(feature = "glam")]
 #[cfg_attr(docsrs, doc(cfg(feature = "glam")))]
 pub use core::math::RotFromGlamError;
@@ -296,35 +335,55 @@ pub use core::math::{
     is_valid_float, length_units_per_meter, milliseconds_and_reset, milliseconds_since,
     rotation_between_unit_vectors, set_length_units_per_meter, ticks, version, yield_now,
 };
-pub use debug_draw::{DebugDraw, DebugDrawCmd, DebugDrawOptions, HexColor};
-pub use dynamic_tree::{DynamicTree, TreeProxyId, TreeRayCastInput, TreeShapeCastInput, TreeStats};
-pub use error::{ApiError, ApiResult};
+#[cfg(feature = "debug-draw")]
+#[cfg_attr(docsrs, doc(cfg(feature = "debug-draw")))]
+pub use debug_draw::{
+    CategoryColors, DebugDraw, DebugDrawBackend, DebugDrawCmd, DebugDrawOptions, DebugVertex,
+    VertexCollector,
+};
+pub use dynamic_tree::{
+    DynamicTree, TreeProxyId, TreeRayCastInput, TreeShapeCastInput, TreeStats, TypedDynamicTree,
+};
+pub use error::{ApiError, ApiResult, StepError, ValidationError};
 pub use events::{
-    BodyMoveEvent, ContactBeginTouchEvent, ContactEndTouchEvent, ContactEvents, ContactHitEvent,
-    JointEvent, SensorBeginTouchEvent, SensorEndTouchEvent, SensorEvents,
+    BodyMoveEvent, CollisionTable, ContactBeginTouchEvent, ContactEndTouchEvent, ContactEvents,
+    ContactHitEvent, ContactTracker, EventCounts, EventDispatcher, JointEvent,
+    SensorBeginTouchEvent, SensorEndTouchEvent, SensorEvents, SleepState,
 };
-pub use filter::Filter;
+pub use filter::{CollisionCategory, Filter, FilterDecision};
 pub use joints::{
-    ConstraintTuning, DistanceJointBuilder, DistanceJointDef, FilterJointBuilder, FilterJointDef,
-    Joint, JointBase, JointBaseBuilder, JointType, MotorJointBuilder, MotorJointDef,
-    PrismaticJointBuilder, PrismaticJointDef, RevoluteJointBuilder, RevoluteJointDef,
-    WeldJointBuilder, WeldJointDef, WheelJointBuilder, WheelJointDef,
+    ConstraintTuning, DistanceJoint, DistanceJointBuilder, DistanceJointDef, FilterJointBuilder,
+    FilterJointDef, Joint, JointBase, JointBaseBuilder, JointType, MotorJoint, MotorJointBuilder,
+    MotorJointDef, MouseJointBuilder, PrismaticJoint, PrismaticJointBuilder, PrismaticJointDef,
+    RevoluteJoint, RevoluteJointBuilder, RevoluteJointDef, WeldJoint, WeldJointBuilder,
+    WeldJointDef, WheelJoint, WheelJointBuilder, WheelJointDef,
 };
+pub use limits::WorldCapacity;
 pub use query::{
-    Aabb, CollisionPlane, MoverPlaneResult, Plane, PlaneSolverResult, QueryFilter, RayResult,
-    clip_vector, solve_planes, try_clip_vector, try_solve_planes,
+    Aabb, CollisionPlane, MoverPlaneResult, PickResult, Plane, PlaneSolverResult, QueryFilter,
+    QuerySnapshot, RayHit, RayResult, SnapshotShape, StaticQueryCache, clip_vector, solve_planes,
+    try_clip_vector, try_solve_planes,
 };
 pub use shapes::chain::{Chain, ChainDef, ChainDefBuilder, ChainDefMaterialLayout, OwnedChain};
 pub use shapes::{
-    Capsule, ChainSegment, Circle, MAX_POLYGON_VERTICES, OwnedShape, Polygon, Segment, Shape,
-    ShapeDef, ShapeDefBuilder, ShapeType, SurfaceMaterial,
+    Capsule, ChainSegment, Circle, Geometry, Hull, MAX_POLYGON_VERTICES, OwnedShape, Polygon,
+    Segment, Shape, ShapeDef, ShapeDefBuilder, ShapeType, SurfaceMaterial,
 };
 pub use types::{
     BodyId, ChainId, ContactData, ContactId, JointId, Manifold, ManifoldPoint, MassData,
     MotionLocks, ShapeId, Vec2,
 };
+#[cfg(feature = "force-tracking")]
+pub use world::AppliedForces;
+#[cfg(feature = "parallel")]
+pub use world::RayonTaskSystem;
 pub use world::{
-    CallbackWorld, MaterialMixInput, OutstandingOwnedHandles, OwnedHandleCounts, Profile, World,
-    WorldBuilder, WorldDef, WorldHandle,
+    CallbackWorld, CapsuleResizeError, ContactDisable, DestroyedEntity, FixedTimestep,
+    MaterialMixInput, OutstandingOwnedHandles, OwnedHandleCounts, PendingTask, Profile,
+    QueryBudget, QueryKind, QueryStats, RegistrySizes, SlowQuery, StaticTreeHint, StepInfo,
+    TaskRange, TaskSystem, World, WorldBuilder, WorldDef, WorldHandle, WorldProfile,
+};
+pub use world_extras::{
+    ChainRider, Door, DoorState, Elevator, ExplosionDef, Ladder, Marker, OneWayPlatform,
+    ShapeMorph, SoftTarget, Spinner, Tracks, TracksDef, Zone,
 };
-pub use world_extras::ExplosionDef;
@@ -0,0 +1,94 @@
+//! Hard limits of the bundled Box2D build.
+//!
+//! Box2D keeps worlds in a fixed-size table and polygons in fixed-size arrays; everything else
+//! (bodies, shapes, joints, chains) lives in growable pools addressed by 32-bit indices. Use
+//! [`worlds_remaining`] and [`World::capacity_remaining`] to throttle procedural spawning before
+//! `World::new` fails or an id index would overflow.
+//!
+//! ```no_run
+//! use boxdd::{World, WorldDef, limits};
+//! if limits::worlds_remaining() > 0 {
+//!     let world = World::new(WorldDef::default()).unwrap();
+//!     let capacity = world.capacity_remaining();
+//!     assert!(capacity.bodies > 0);
+//! }
+//! ```
+
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use crate::world::World;
+
+/// Maximum number of simultaneously live worlds (upstream `B2_MAX_WORLDS`).
+pub const MAX_WORLDS: usize = 128;
+
+pub use crate::shapes::MAX_POLYGON_VERTICES;
+
+/// Minimum number of points in a chain, ghost points included.
+pub const MIN_CHAIN_POINTS: usize = 4;
+
+/// Upper bound on Box2D worker threads (upstream `B2_MAX_WORKERS`).
+pub const MAX_WORKERS: usize = 64;
+
+/// Maximum number of live bodies, shapes, or joints in one world: ids carry a 32-bit index.
+/// Memory is normally exhausted long before this.
+pub const MAX_IDS_PER_WORLD: usize = i32::MAX as usize;
+
+/// Most sub-steps [`World::step_scaled`] uses for one call. Past this, sub-steps get longer than
+/// the requested `max_substep_dt` instead of the solver doing unbounded work in one frame, and
+/// the step's [`StepInfo::clamped`](crate::StepInfo::clamped) is set.
+pub const MAX_SCALED_SUB_STEPS: i32 = 64;
+
+static LIVE_WORLDS: AtomicUsize = AtomicUsize::new(0);
+
+/// Number of worlds currently alive in this process.
+pub fn live_worlds() -> usize {
+    LIVE_WORLDS.load(Ordering::Relaxed)
+}
+
+/// How many more worlds can be created before `World::new` fails.
+pub fn worlds_remaining() -> usize {
+    MAX_WORLDS.saturating_sub(live_worlds())
+}
+
+pub(crate) fn world_created() {
+    LIVE_WORLDS.fetch_add(1, Ordering::Relaxed);
+}
+
+pub(crate) fn world_destroyed() {
+    let prev = LIVE_WORLDS.fetch_sub(1, Ordering::Relaxed);
+    debug_assert!(prev > 0, "live world counter underflow");
+}
+
+/// Remaining headroom returned by [`World::capacity_remaining`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct WorldCapacity {
+    /// Worlds that can still be created in this process.
+    pub worlds: usize,
+    pub bodies: usize,
+    pub shapes: usize,
+    pub joints: usize,
+}
+
+impl World {
+    /// Remaining headroom against [`MAX_WORLDS`] and [`MAX_IDS_PER_WORLD`].
+    pub fn capacity_remaining(&self) -> WorldCapacity {
+        crate::core::callback_state::assert_not_in_callback();
+        self.capacity_remaining_impl()
+    }
+
+    pub fn try_capacity_remaining(&self) -> crate::error::ApiResult<WorldCapacity> {
+        crate::core::callback_state::check_not_in_callback()?;
+        Ok(self.capacity_remaining_impl())
+    }
+
+    fn capacity_remaining_impl(&self) -> WorldCapacity {
+        let counters = self.counters();
+        let left = |used: i32| MAX_IDS_PER_WORLD.saturating_sub(used.max(0) as usize);
+        WorldCapacity {
+            worlds: worlds_remaining(),
+            bodies: left(counters.body_count),
+            shapes: left(counters.shape_count),
+            joints: left(counters.joint_count),
+        }
+    }
+}
@@ -0,0 +1,187 @@
+//! Compact body state for network replication.
+//!
+//! A [`QuantizedTransform`] packs a pose into five bytes: position as two `i16` grid cells of
+//! `precision` meters and the angle as one `u8` step of `TAU / 256`. Dequantizing is exact, so a
+//! server that applies [`dequantize_transform`] to its own output sees the same bits as every
+//! client. Positions outside the `i16` grid can be sent relative to a previously acknowledged pose
+//! with [`quantize_delta`] / [`dequantize_delta`].
+//!
+//! On the receiving side, [`World::apply_network_state`] moves a body toward the corrected pose,
+//! hiding small errors over a few frames instead of snapping.
+//!
+//! ```no_run
+//! use boxdd::net::{dequantize_transform, quantize_transform};
+//! use boxdd::{BodyBuilder, World, WorldDef};
+//! let mut world = World::new(WorldDef::default()).unwrap();
+//! let body = world.create_body_id(BodyBuilder::new().build());
+//! let precision = 0.01;
+//! let sent = quantize_transform(world.body_transform(body), precision).unwrap();
+//! // ... on the client:
+//! world.apply_network_state(body, sent, precision, 0.8);
+//! let _exact = dequantize_transform(sent, precision);
+//! ```
+use core::f32::consts::{PI, TAU};
+
+use crate::Transform;
+use crate::error::{ApiError, ApiResult};
+use crate::types::{BodyId, Vec2};
+use crate::world::World;
+
+/// Angle steps per full turn in a [`QuantizedTransform`].
+pub const ANGLE_STEPS: u32 = 256;
+
+/// Pose packed for the wire; see the [module docs](self).
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct QuantizedTransform {
+    /// Position x in grid cells.
+    pub x: i16,
+    /// Position y in grid cells.
+    pub y: i16,
+    /// Angle in steps of `TAU / 256`, counter-clockwise from +x.
+    pub angle: u8,
+}
+
+/// Quantize `radians` to the nearest of [`ANGLE_STEPS`] steps, wrapping around a full turn.
+pub fn quantize_angle(radians: f32) -> u8 {
+    let steps = (radians / TAU * ANGLE_STEPS as f32).round();
+    steps.rem_euclid(ANGLE_STEPS as f32) as u8
+}
+
+/// Angle in `(-PI, PI]` for a quantized step.
+pub fn dequantize_angle(step: u8) -> f32 {
+    let radians = step as f32 * (TAU / ANGLE_STEPS as f32);
+    if radians > PI { radians - TAU } else { radians }
+}
+
+/// Quantize `t` on a grid of `precision` meters around the origin.
+///
+/// Returns `None` when the position is not finite or falls outside the `i16` grid
+/// (`±32767 * precision` meters).
+///
+/// # Panics
+/// If `precision` is not finite and positive.
+pub fn quantize_transform(t: Transform, precision: f32) -> Option<QuantizedTransform> {
+    quantize_delta(Transform::IDENTITY, t, precision)
+}
+
+/// Exact inverse of [`quantize_transform`] up to the grid resolution.
+pub fn dequantize_transform(q: QuantizedTransform, precision: f32) -> Transform {
+    dequantize_delta(Transform::IDENTITY, q, precision)
+}
+
+/// Quantize `t` relative to `base`, typically the last pose the receiver acknowledged.
+///
+/// `base` should itself be a dequantized pose so both ends hold the same bits. Returns `None` when
+/// `t` is too far from `base` for the `i16` grid; send a full state instead.
+///
+/// # Panics
+/// If `precision` is not finite and positive.
+pub fn quantize_delta(base: Transform, t: Transform, precision: f32) -> Option<QuantizedTransform> {
+    assert_precision_valid(precision);
+    let cell = |v: f32, origin: f32| {
+        let cells = ((v - origin) / precision).round();
+        (cells.is_finite() && cells.abs() <= i16::MAX as f32).then_some(cells as i16)
+    };
+    let base_p = base.position();
+    let p = t.position();
+    let angle = t.rotation().angle() - base.rotation().angle();
+    Some(QuantizedTransform {
+        x: cell(p.x, base_p.x)?,
+        y: cell(p.y, base_p.y)?,
+        angle: quantize_angle(angle),
+    })
+}
+
+/// Exact inverse of [`quantize_delta`] against the same `base`.
+pub fn dequantize_delta(base: Transform, q: QuantizedTransform, precision: f32) -> Transform {
+    let p = base.position();
+    Transform::from_pos_angle(
+        Vec2::new(p.x + q.x as f32 * precision, p.y + q.y as f32 * precision),
+        base.rotation().angle() + dequantize_angle(q.angle),
+    )
+}
+
+fn assert_precision_valid(precision: f32) {
+    assert!(
+        precision.is_finite() && precision > 0.0,
+        "precision must be finite and > 0, got {precision}"
+    );
+}
+
+fn assert_smoothing_valid(smoothing: f32) {
+    assert!(
+        (0.0..=1.0).contains(&smoothing),
+        "smoothing must be in [0, 1], got {smoothing}"
+    );
+}
+
+/// Pose between the body's current transform and `target`, keeping `smoothing` of the error.
+fn smoothed_transform_impl(body: BodyId, target: Transform, smoothing: f32) -> Transform {
+    let current = crate::body::body_transform_impl(body);
+    let (p, q) = (current.position(), target.position());
+    let mut error = current.rotation().angle() - target.rotation().angle();
+    if error > PI {
+        error -= TAU;
+    } else if error < -PI {
+        error += TAU;
+    }
+    Transform::from_pos_angle(
+        Vec2::new(q.x + (p.x - q.x) * smoothing, q.y + (p.y - q.y) * smoothing),
+        target.rotation().angle() + error * smoothing,
+    )
+}
+
+impl World {
+    /// Dequantize `state` and move `body` toward it; see [`World::apply_network_transform`].
+    pub fn apply_network_state(
+        &mut self,
+        body: BodyId,
+        state: QuantizedTransform,
+        precision: f32,
+        smoothing: f32,
+    ) {
+        assert_precision_valid(precision);
+        self.apply_network_transform(body, dequantize_transform(state, precision), smoothing);
+    }
+
+    pub fn try_apply_network_state(
+        &mut self,
+        body: BodyId,
+        state: QuantizedTransform,
+        precision: f32,
+        smoothing: f32,
+    ) -> ApiResult<()> {
+        if !(precision.is_finite() && precision > 0.0) {
+            return Err(ApiError::InvalidArgument);
+        }
+        self.try_apply_network_transform(body, dequantize_transform(state, precision), smoothing)
+    }
+
+    /// Correct `body` toward an authoritative pose, keeping `smoothing` of the error.
+    ///
+    /// `0` snaps to `target`; `0.8` removes a fifth of the error per call. Call it every frame
+    /// with the latest received pose so the remaining error decays smoothly; velocities are left
+    /// to the caller.
+    pub fn apply_network_transform(&mut self, body: BodyId, target: Transform, smoothing: f32) {
+        crate::core::debug_checks::assert_body_valid(body);
+        assert!(target.is_valid(), "target must be a valid Transform");
+        assert_smoothing_valid(smoothing);
+        let xf = smoothed_transform_impl(body, target, smoothing);
+        self.set_body_position_and_rotation(body, xf.position(), xf.rotation().angle());
+    }
+
+    pub fn try_apply_network_transform(
+        &mut self,
+        body: BodyId,
+        target: Transform,
+        smoothing: f32,
+    ) -> ApiResult<()> {
+        crate::core::debug_checks::check_body_valid(body)?;
+        if !target.is_valid() || !(0.0..=1.0).contains(&smoothing) {
+            return Err(ApiError::InvalidArgument);
+        }
+        let xf = smoothed_transform_impl(body, target, smoothing);
+        self.try_set_body_position_and_rotation(body, xf.position(), xf.rotation().angle())
+    }
+}
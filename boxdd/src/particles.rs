@@ -0,0 +1,516 @@
+//! Lightweight particle fluid (position-based fluids) that interacts with world shapes.
+//!
+//! `ParticleSystem` is a standalone subsystem: particles are not Box2D bodies. Step it right
+//! after `World::step` with the same time step. Each step it
+//! - integrates world gravity,
+//! - enforces an incompressibility constraint between neighbours found through a spatial hash,
+//! - collides particles against non-sensor world shapes using ray casts and closest-point
+//!   queries,
+//! - and pushes dynamic bodies back with impulses proportional to the particle corrections,
+//!   which show up in `force-tracking` logs like any other impulse.
+//!
+//! This covers "water in a box" style effects; it is not a replacement for a dedicated fluid
+//! solver.
+//!
+//! ```no_run
+//! use boxdd::particles::{ParticleSystem, ParticleSystemDef};
+//! use boxdd::{Aabb, World, WorldDef};
+//! let mut world = World::new(WorldDef::builder().gravity([0.0, -10.0]).build()).unwrap();
+//! let mut water = ParticleSystem::new(ParticleSystemDef::default());
+//! water.spawn_grid(Aabb::new([-1.0, 1.0], [1.0, 2.0]), [0.0, 0.0]);
+//! world.step(1.0 / 60.0, 4);
+//! water.step(&mut world, 1.0 / 60.0);
+//! for p in water.positions() {
+//!     let _ = (p.x, p.y);
+//! }
+//! ```
+
+use crate::error::{ApiError, ApiResult};
+use crate::query::{Aabb, QueryFilter};
+use crate::types::{BodyId, ShapeId, Vec2};
+use crate::world::World;
+
+/// Configuration for a [`ParticleSystem`].
+#[derive(Copy, Clone, Debug)]
+pub struct ParticleSystemDef {
+    /// Collision radius of each particle in meters. Particles are spawned `2 * radius` apart.
+    pub radius: f32,
+    /// Mass of each particle in kilograms, used for impulses applied to bodies.
+    pub mass: f32,
+    /// Neighbour search / kernel radius in meters. Must be larger than `2 * radius`.
+    pub smoothing_radius: f32,
+    /// Number of density constraint iterations per step.
+    pub iterations: u32,
+    /// XSPH viscosity factor in `[0, 1]`.
+    pub viscosity: f32,
+    /// Constraint relaxation added to the denominator of the density solve.
+    pub relaxation: f32,
+    /// Filter used for world collision queries.
+    pub filter: QueryFilter,
+    /// Whether particles push dynamic bodies back with impulses.
+    pub push_bodies: bool,
+}
+
+impl Default for ParticleSystemDef {
+    fn default() -> Self {
+        Self {
+            radius: 0.05,
+            mass: 0.01,
+            smoothing_radius: 0.2,
+            iterations: 3,
+            viscosity: 0.05,
+            relaxation: 10.0,
+            filter: QueryFilter::default(),
+            push_bodies: true,
+        }
+    }
+}
+
+impl ParticleSystemDef {
+    /// Create a default particle system definition.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collision radius of each particle.
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Mass of each particle.
+    pub fn mass(mut self, mass: f32) -> Self {
+        self.mass = mass;
+        self
+    }
+
+    /// Neighbour search / kernel radius.
+    pub fn smoothing_radius(mut self, h: f32) -> Self {
+        self.smoothing_radius = h;
+        self
+    }
+
+    /// Density constraint iterations per step.
+    pub fn iterations(mut self, n: u32) -> Self {
+        self.iterations = n;
+        self
+    }
+
+    /// XSPH viscosity factor.
+    pub fn viscosity(mut self, v: f32) -> Self {
+        self.viscosity = v;
+        self
+    }
+
+    /// Constraint relaxation of the density solve; larger values soften the fluid.
+    pub fn relaxation(mut self, relaxation: f32) -> Self {
+        self.relaxation = relaxation;
+        self
+    }
+
+    /// Filter used for world collision queries.
+    pub fn filter(mut self, filter: QueryFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Whether particles push dynamic bodies back.
+    pub fn push_bodies(mut self, flag: bool) -> Self {
+        self.push_bodies = flag;
+        self
+    }
+
+    /// Validate the definition.
+    pub fn validate(&self) -> ApiResult<()> {
+        let positive = |v: f32| v.is_finite() && v > 0.0;
+        let valid = positive(self.radius)
+            && positive(self.mass)
+            && positive(self.relaxation)
+            && self.smoothing_radius.is_finite()
+            && self.smoothing_radius > 2.0 * self.radius
+            && (0.0..=1.0).contains(&self.viscosity);
+        if !valid {
+            return Err(ApiError::InvalidArgument);
+        }
+        Ok(())
+    }
+}
+
+/// A set of fluid particles stepped alongside a [`World`].
+#[derive(Clone, Debug)]
+pub struct ParticleSystem {
+    def: ParticleSystemDef,
+    rest_density: f32,
+    positions: Vec<Vec2>,
+    velocities: Vec<Vec2>,
+    // Scratch buffers reused across steps.
+    predicted: Vec<Vec2>,
+    lambdas: Vec<f32>,
+    deltas: Vec<Vec2>,
+    cells: Vec<(i64, u32)>,
+    neighbor_offsets: Vec<u32>,
+    neighbors: Vec<u32>,
+    shapes: Vec<ShapeId>,
+}
+
+impl ParticleSystem {
+    /// Create an empty particle system.
+    ///
+    /// Panics if `def` is invalid; see [`ParticleSystemDef::validate`].
+    pub fn new(def: ParticleSystemDef) -> Self {
+        assert!(def.validate().is_ok(), "invalid ParticleSystemDef: {def:?}");
+        Self::new_unchecked(def)
+    }
+
+    /// Recoverable version of [`Self::new`].
+    pub fn try_new(def: ParticleSystemDef) -> ApiResult<Self> {
+        def.validate()?;
+        Ok(Self::new_unchecked(def))
+    }
+
+    fn new_unchecked(def: ParticleSystemDef) -> Self {
+        Self {
+            rest_density: rest_density(&def),
+            def,
+            positions: Vec::new(),
+            velocities: Vec::new(),
+            predicted: Vec::new(),
+            lambdas: Vec::new(),
+            deltas: Vec::new(),
+            cells: Vec::new(),
+            neighbor_offsets: Vec::new(),
+            neighbors: Vec::new(),
+            shapes: Vec::new(),
+        }
+    }
+
+    /// The definition this system was created with.
+    pub fn def(&self) -> &ParticleSystemDef {
+        &self.def
+    }
+
+    /// Number of particles.
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Whether the system has no particles.
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Particle positions in world space.
+    pub fn positions(&self) -> &[Vec2] {
+        &self.positions
+    }
+
+    /// Particle velocities in meters per second.
+    pub fn velocities(&self) -> &[Vec2] {
+        &self.velocities
+    }
+
+    /// Add one particle and return its index.
+    pub fn spawn<P: Into<Vec2>, V: Into<Vec2>>(&mut self, position: P, velocity: V) -> usize {
+        self.positions.push(position.into());
+        self.velocities.push(velocity.into());
+        self.positions.len() - 1
+    }
+
+    /// Fill `region` with particles on a square lattice spaced `2 * radius` apart.
+    ///
+    /// Returns the number of particles added.
+    pub fn spawn_grid<V: Into<Vec2>>(&mut self, region: Aabb, velocity: V) -> usize {
+        let velocity = velocity.into();
+        let spacing = 2.0 * self.def.radius;
+        let start = self.positions.len();
+        let mut y = region.lower.y + self.def.radius;
+        while y <= region.upper.y - self.def.radius {
+            let mut x = region.lower.x + self.def.radius;
+            while x <= region.upper.x - self.def.radius {
+                self.spawn(Vec2::new(x, y), velocity);
+                x += spacing;
+            }
+            y += spacing;
+        }
+        self.positions.len() - start
+    }
+
+    /// Remove all particles.
+    pub fn clear(&mut self) {
+        self.positions.clear();
+        self.velocities.clear();
+    }
+
+    /// Remove particles for which `keep` returns `false`.
+    pub fn retain(&mut self, mut keep: impl FnMut(Vec2, Vec2) -> bool) {
+        let mut i = 0;
+        while i < self.positions.len() {
+            if keep(self.positions[i], self.velocities[i]) {
+                i += 1;
+            } else {
+                self.positions.swap_remove(i);
+                self.velocities.swap_remove(i);
+            }
+        }
+    }
+
+    /// Advance the particles by `dt`, colliding with and pushing on `world`.
+    ///
+    /// Call this right after `World::step` with the same `dt`.
+    pub fn step(&mut self, world: &mut World, dt: f32) {
+        crate::core::callback_state::assert_not_in_callback();
+        assert!(
+            dt.is_finite() && dt >= 0.0,
+            "dt must be finite and >= 0.0, got {dt}"
+        );
+        self.step_impl(world, dt);
+    }
+
+    /// Recoverable version of [`Self::step`].
+    pub fn try_step(&mut self, world: &mut World, dt: f32) -> ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        if !(dt.is_finite() && dt >= 0.0) {
+            return Err(ApiError::InvalidArgument);
+        }
+        self.step_impl(world, dt);
+        Ok(())
+    }
+
+    fn step_impl(&mut self, world: &mut World, dt: f32) {
+        let n = self.positions.len();
+        if n == 0 || dt == 0.0 {
+            return;
+        }
+        let gravity = world.gravity();
+
+        // Predict.
+        self.predicted.clear();
+        for (x, v) in self.positions.iter().zip(self.velocities.iter_mut()) {
+            *v = v.plus(gravity.scaled(dt));
+            self.predicted.push(x.plus(v.scaled(dt)));
+        }
+
+        self.find_neighbors();
+
+        // Density constraint iterations.
+        for _ in 0..self.def.iterations {
+            self.solve_density();
+        }
+
+        // World collision, accumulating body impulses from the corrections.
+        let inv_dt = 1.0 / dt;
+        for i in 0..n {
+            if let Some((body, point, correction)) = self.collide(world, i)
+                && self.def.push_bodies
+                && crate::body::body_type_impl(body) == crate::body::BodyType::Dynamic
+            {
+                let impulse = correction.scaled(-self.def.mass * inv_dt);
+                crate::body::body_apply_linear_impulse_impl(body, impulse, point, true);
+                world.core().record_applied(
+                    body,
+                    crate::core::world_core::Applied::LinearImpulse(impulse, Some(point)),
+                );
+            }
+        }
+
+        // Velocity update and XSPH viscosity.
+        for i in 0..n {
+            self.velocities[i] = self.predicted[i].minus(self.positions[i]).scaled(inv_dt);
+        }
+        if self.def.viscosity > 0.0 {
+            self.apply_viscosity();
+        }
+        core::mem::swap(&mut self.positions, &mut self.predicted);
+    }
+
+    fn cell_key(&self, p: Vec2) -> i64 {
+        let h = self.def.smoothing_radius;
+        cell_key((p.x / h).floor() as i32, (p.y / h).floor() as i32)
+    }
+
+    fn find_neighbors(&mut self) {
+        let h = self.def.smoothing_radius;
+        let h2 = h * h;
+        self.cells.clear();
+        for (i, &p) in self.predicted.iter().enumerate() {
+            self.cells.push((self.cell_key(p), i as u32));
+        }
+        self.cells.sort_unstable();
+
+        self.neighbor_offsets.clear();
+        self.neighbors.clear();
+        for &pi in &self.predicted {
+            self.neighbor_offsets.push(self.neighbors.len() as u32);
+            let cx = (pi.x / h).floor() as i32;
+            let cy = (pi.y / h).floor() as i32;
+            for dy in -1..=1 {
+                for dx in -1..=1 {
+                    let key = cell_key(cx + dx, cy + dy);
+                    let start = self.cells.partition_point(|&(k, _)| k < key);
+                    for &(k, j) in &self.cells[start..] {
+                        if k != key {
+                            break;
+                        }
+                        if self.predicted[j as usize].minus(pi).length_squared() < h2 {
+                            self.neighbors.push(j);
+                        }
+                    }
+                }
+            }
+        }
+        self.neighbor_offsets.push(self.neighbors.len() as u32);
+    }
+
+    fn neighbors_of(&self, i: usize) -> &[u32] {
+        let a = self.neighbor_offsets[i] as usize;
+        let b = self.neighbor_offsets[i + 1] as usize;
+        &self.neighbors[a..b]
+    }
+
+    fn solve_density(&mut self) {
+        let n = self.predicted.len();
+        let h = self.def.smoothing_radius;
+        let inv_rho0 = 1.0 / self.rest_density;
+
+        self.lambdas.clear();
+        for i in 0..n {
+            let pi = self.predicted[i];
+            let mut density = 0.0;
+            let mut grad_i = Vec2::ZERO;
+            let mut sum_grad2 = 0.0;
+            for &j in self.neighbors_of(i) {
+                let r = pi.minus(self.predicted[j as usize]);
+                density += poly6(r.length_squared(), h);
+                if j as usize != i {
+                    let g = spiky_gradient(r, h).scaled(inv_rho0);
+                    grad_i = grad_i.plus(g);
+                    sum_grad2 += g.length_squared();
+                }
+            }
+            let constraint = density * inv_rho0 - 1.0;
+            sum_grad2 += grad_i.length_squared();
+            // Only resist compression; free surfaces should not pull particles together.
+            let lambda = if constraint > 0.0 {
+                -constraint / (sum_grad2 + self.def.relaxation)
+            } else {
+                0.0
+            };
+            self.lambdas.push(lambda);
+        }
+
+        // Artificial pressure (tensile instability correction).
+        let dq = 0.2 * h;
+        let w_dq = poly6(dq * dq, h);
+        self.deltas.clear();
+        for i in 0..n {
+            let pi = self.predicted[i];
+            let mut delta = Vec2::ZERO;
+            for &j in self.neighbors_of(i) {
+                let j = j as usize;
+                if j == i {
+                    continue;
+                }
+                let r = pi.minus(self.predicted[j]);
+                let ratio = poly6(r.length_squared(), h) / w_dq;
+                let s_corr = -0.01 * ratio * ratio * ratio * ratio;
+                let scale = self.lambdas[i] + self.lambdas[j] + s_corr;
+                delta = delta.plus(spiky_gradient(r, h).scaled(scale));
+            }
+            self.deltas.push(delta.scaled(inv_rho0));
+        }
+        for (p, d) in self.predicted.iter_mut().zip(&self.deltas) {
+            *p = p.plus(*d);
+        }
+    }
+
+    fn apply_viscosity(&mut self) {
+        let n = self.predicted.len();
+        let h = self.def.smoothing_radius;
+        let w0 = poly6(0.0, h);
+        self.deltas.clear();
+        for i in 0..n {
+            let vi = self.velocities[i];
+            let mut acc = Vec2::ZERO;
+            for &j in self.neighbors_of(i) {
+                let j = j as usize;
+                if j == i {
+                    continue;
+                }
+                let w = poly6(
+                    self.predicted[i].minus(self.predicted[j]).length_squared(),
+                    h,
+                ) / w0;
+                acc = acc.plus(self.velocities[j].minus(vi).scaled(w));
+            }
+            self.deltas.push(acc.scaled(self.def.viscosity));
+        }
+        for (v, d) in self.velocities.iter_mut().zip(&self.deltas) {
+            *v = v.plus(*d);
+        }
+    }
+
+    /// Resolve particle `i` against world shapes. Returns the last touched body, the contact
+    /// point, and the total position correction applied to the particle.
+    fn collide(&mut self, world: &World, i: usize) -> Option<(BodyId, Vec2, Vec2)> {
+        let predicted = self.predicted[i];
+        let (p, touched) = crate::core::point_collision::collide_point(
+            world,
+            self.positions[i],
+            predicted,
+            self.def.radius,
+            self.def.filter,
+            &mut self.shapes,
+        );
+        self.predicted[i] = p;
+        touched.map(|(shape, point)| {
+            (
+                crate::shapes::shape_body_id_impl(shape),
+                point,
+                p.minus(predicted),
+            )
+        })
+    }
+}
+
+#[inline]
+fn cell_key(cx: i32, cy: i32) -> i64 {
+    ((cx as i64) << 32) | (cy as u32 as i64)
+}
+
+/// 2D poly6 kernel, taking the squared distance.
+#[inline]
+fn poly6(r2: f32, h: f32) -> f32 {
+    let h2 = h * h;
+    if r2 >= h2 {
+        return 0.0;
+    }
+    let d = h2 - r2;
+    4.0 / (core::f32::consts::PI * h2 * h2 * h2 * h2) * d * d * d
+}
+
+/// Gradient of the 2D spiky kernel.
+#[inline]
+fn spiky_gradient(r: Vec2, h: f32) -> Vec2 {
+    let len = r.length();
+    if len <= 0.0 || len >= h {
+        return Vec2::ZERO;
+    }
+    let d = h - len;
+    let coeff = -30.0 / (core::f32::consts::PI * h.powi(5)) * d * d;
+    r.scaled(coeff / len)
+}
+
+/// Kernel density of a particle in a square lattice at spawn spacing.
+fn rest_density(def: &ParticleSystemDef) -> f32 {
+    let spacing = 2.0 * def.radius;
+    let h = def.smoothing_radius;
+    let reach = (h / spacing).ceil() as i32;
+    let mut density = 0.0;
+    for y in -reach..=reach {
+        for x in -reach..=reach {
+            let r = Vec2::new(x as f32 * spacing, y as f32 * spacing);
+            density += poly6(r.length_squared(), h);
+        }
+    }
+    density
+}
@@ -1,12 +1,16 @@
 pub use crate::{
-    ApiError, ApiResult, Body, BodyBuilder, BodyDef, BodyType, CallbackWorld, Filter,
-    MaterialMixInput, OutstandingOwnedHandles, OwnedBody, OwnedHandleCounts, ShapeCastInput, World,
-    WorldBuilder, WorldDef, WorldHandle,
-    debug_draw::{DebugDraw, DebugDrawCmd, DebugDrawOptions, HexColor, RawDebugDraw},
-    dynamic_tree::{DynamicTree, TreeProxyId, TreeRayCastInput, TreeShapeCastInput, TreeStats},
+    ApiError, ApiResult, Body, BodyBuilder, BodyDef, BodyType, CallbackWorld, CollisionCategory,
+    ContactDisable, DestroyedEntity, Filter, FilterDecision, FixedTimestep, HexColor,
+    MaterialMixInput, OutstandingOwnedHandles, OwnedBody, OwnedHandleCounts, ShapeCastInput,
+    StepError, StepInfo, ValidationError, World, WorldBuilder, WorldDef, WorldHandle, WorldProfile,
+    camera::Camera2D,
+    dynamic_tree::{
+        DynamicTree, TreeProxyId, TreeRayCastInput, TreeShapeCastInput, TreeStats, TypedDynamicTree,
+    },
     events::{
-        BodyMoveEvent, ContactBeginTouchEvent, ContactEndTouchEvent, ContactEvents,
-        ContactHitEvent, JointEvent, SensorBeginTouchEvent, SensorEndTouchEvent, SensorEvents,
+        BodyMoveEvent, CollisionTable, ContactBeginTouchEvent, ContactEndTouchEvent, ContactEvents,
+        ContactHitEvent, ContactTracker, EventCounts, EventDispatcher, JointEvent,
+        SensorBeginTouchEvent, SensorEndTouchEvent, SensorEvents, SleepState,
     },
     joints::{
         ConstraintTuning, DistanceJointDef, FilterJointDef, Joint, JointBase, JointBaseBuilder,
@@ -14,8 +18,9 @@ pub use crate::{
         WheelJointDef,
     },
     query::{
-        Aabb, CollisionPlane, MoverPlaneResult, Plane, PlaneSolverResult, QueryFilter, RayResult,
-        clip_vector, solve_planes, try_clip_vector, try_solve_planes,
+        Aabb, CollisionPlane, MoverPlaneResult, PickResult, Plane, PlaneSolverResult, QueryFilter,
+        QuerySnapshot, RayHit, RayResult, SnapshotShape, StaticQueryCache, clip_vector,
+        solve_planes, try_clip_vector, try_solve_planes,
     },
     shapes::{
         self, Capsule, ChainSegment, Circle, MAX_POLYGON_VERTICES, OwnedShape, Polygon, Segment,
@@ -27,10 +32,19 @@ pub use crate::{
         MotionLocks, ShapeId, Vec2,
     },
     world::{Counters, Profile},
-    world_extras::ExplosionDef,
+    world_extras::{
+        ChainRider, Door, DoorState, Elevator, ExplosionDef, Ladder, Marker, OneWayPlatform,
+        ShapeMorph, SoftTarget, Spinner, Tracks, TracksDef, Zone,
+    },
     {Rot, Transform},
 };
 
+#[cfg(feature = "debug-draw")]
+pub use crate::debug_draw::{
+    CategoryColors, DebugDraw, DebugDrawBackend, DebugDrawCmd, DebugDrawOptions, RawDebugDraw,
+    VertexCollector,
+};
+
 #[cfg(feature = "unchecked")]
 pub use crate::unchecked::*;
 
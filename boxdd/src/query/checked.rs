@@ -1,6 +1,6 @@
+use crate::core::world_core::WorldCore;
 use crate::error::ApiResult;
 use crate::types::{ShapeId, Vec2};
-use boxdd_sys::ffi;
 
 use super::raw::*;
 use super::types::*;
@@ -10,3 +10,37 @@ pub(crate) fn try_checked_query_result_impl<R>(f: impl FnOnce() -> ApiResult<R>)
     crate::core::callback_state::check_not_in_callback()?;
     f()
 }
+
+/// Closest non-sensor hit among `body`'s shapes, using `cast` for each shape.
+pub(crate) fn closest_body_hit_impl(
+    body: crate::types::BodyId,
+    mut cast: impl FnMut(ShapeId) -> crate::collision::CastOutput,
+) -> RayResult {
+    let mut best = RayResult {
+        shape_id: ShapeId {
+            index1: 0,
+            world0: 0,
+            generation: 0,
+        },
+        point: Vec2::ZERO,
+        normal: Vec2::ZERO,
+        fraction: 1.0,
+        hit: false,
+    };
+    for shape in crate::body::body_shapes_impl(body) {
+        if crate::shapes::shape_is_sensor_impl(shape) {
+            continue;
+        }
+        let out = cast(shape);
+        if out.hit && (!best.hit || out.fraction < best.fraction) {
+            best = RayResult {
+                shape_id: shape,
+                point: out.point,
+                normal: out.normal,
+                fraction: out.fraction,
+                hit: true,
+            };
+        }
+    }
+    best
+}
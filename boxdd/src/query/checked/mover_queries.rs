@@ -1,7 +1,7 @@
 use super::*;
 
 pub(crate) fn cast_mover_checked_impl<V1: Into<Vec2>, V2: Into<Vec2>, VT: Into<Vec2>>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     c1: V1,
     c2: V2,
     radius: f32,
@@ -16,12 +16,12 @@ pub(crate) fn cast_mover_checked_impl<V1: Into<Vec2>, V2: Into<Vec2>, VT: Into<V
         assert_query_vec2_valid("c2", c2);
         assert_query_vec2_valid("translation", translation);
         assert_query_mover_radius_valid(radius);
-        cast_mover_impl(raw_world_id, c1, c2, radius, translation, filter)
+        cast_mover_impl(world, c1, c2, radius, translation, filter)
     })
 }
 
 pub(crate) fn try_cast_mover_impl<V1: Into<Vec2>, V2: Into<Vec2>, VT: Into<Vec2>>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     c1: V1,
     c2: V2,
     radius: f32,
@@ -36,19 +36,12 @@ pub(crate) fn try_cast_mover_impl<V1: Into<Vec2>, V2: Into<Vec2>, VT: Into<Vec2>
         check_query_vec2_valid(c2)?;
         check_query_vec2_valid(translation)?;
         check_query_mover_radius_valid(radius)?;
-        Ok(cast_mover_impl(
-            raw_world_id,
-            c1,
-            c2,
-            radius,
-            translation,
-            filter,
-        ))
+        Ok(cast_mover_impl(world, c1, c2, radius, translation, filter))
     })
 }
 
 pub(crate) fn collide_mover_checked_impl<V1: Into<Vec2>, V2: Into<Vec2>>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     c1: V1,
     c2: V2,
     radius: f32,
@@ -60,12 +53,12 @@ pub(crate) fn collide_mover_checked_impl<V1: Into<Vec2>, V2: Into<Vec2>>(
         assert_query_vec2_valid("c1", c1);
         assert_query_vec2_valid("c2", c2);
         assert_query_mover_radius_valid(radius);
-        collide_mover_impl(raw_world_id, c1, c2, radius, filter)
+        collide_mover_impl(world, c1, c2, radius, filter)
     })
 }
 
 pub(crate) fn collide_mover_into_checked_impl<V1: Into<Vec2>, V2: Into<Vec2>>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     c1: V1,
     c2: V2,
     radius: f32,
@@ -78,12 +71,12 @@ pub(crate) fn collide_mover_into_checked_impl<V1: Into<Vec2>, V2: Into<Vec2>>(
         assert_query_vec2_valid("c1", c1);
         assert_query_vec2_valid("c2", c2);
         assert_query_mover_radius_valid(radius);
-        collide_mover_into_impl(raw_world_id, c1, c2, radius, filter, out);
+        collide_mover_into_impl(world, c1, c2, radius, filter, out);
     });
 }
 
 pub(crate) fn try_collide_mover_impl<V1: Into<Vec2>, V2: Into<Vec2>>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     c1: V1,
     c2: V2,
     radius: f32,
@@ -95,12 +88,12 @@ pub(crate) fn try_collide_mover_impl<V1: Into<Vec2>, V2: Into<Vec2>>(
         check_query_vec2_valid(c1)?;
         check_query_vec2_valid(c2)?;
         check_query_mover_radius_valid(radius)?;
-        Ok(collide_mover_impl(raw_world_id, c1, c2, radius, filter))
+        Ok(collide_mover_impl(world, c1, c2, radius, filter))
     })
 }
 
 pub(crate) fn try_collide_mover_into_impl<V1: Into<Vec2>, V2: Into<Vec2>>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     c1: V1,
     c2: V2,
     radius: f32,
@@ -113,7 +106,7 @@ pub(crate) fn try_collide_mover_into_impl<V1: Into<Vec2>, V2: Into<Vec2>>(
         check_query_vec2_valid(c1)?;
         check_query_vec2_valid(c2)?;
         check_query_mover_radius_valid(radius)?;
-        collide_mover_into_impl(raw_world_id, c1, c2, radius, filter, out);
+        collide_mover_into_impl(world, c1, c2, radius, filter, out);
         Ok(())
     })
 }
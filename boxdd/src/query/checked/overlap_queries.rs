@@ -1,18 +1,18 @@
 use super::*;
 
 pub(crate) fn overlap_aabb_checked_impl(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     aabb: Aabb,
     filter: QueryFilter,
 ) -> Vec<ShapeId> {
     checked_query_impl(|| {
         assert_query_aabb_valid(aabb);
-        overlap_aabb_impl(raw_world_id, aabb, filter)
+        overlap_aabb_impl(world, aabb, filter)
     })
 }
 
 pub(crate) fn visit_overlap_aabb_checked_impl<F>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     aabb: Aabb,
     filter: QueryFilter,
     visit: &mut F,
@@ -22,35 +22,35 @@ where
 {
     checked_query_impl(|| {
         assert_query_aabb_valid(aabb);
-        visit_overlap_aabb_impl(raw_world_id, aabb, filter, visit)
+        visit_overlap_aabb_impl(world, aabb, filter, visit)
     })
 }
 
 pub(crate) fn overlap_aabb_into_checked_impl(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     aabb: Aabb,
     filter: QueryFilter,
     out: &mut Vec<ShapeId>,
 ) {
     checked_query_impl(|| {
         assert_query_aabb_valid(aabb);
-        overlap_aabb_into_impl(raw_world_id, aabb, filter, out);
+        overlap_aabb_into_impl(world, aabb, filter, out);
     });
 }
 
 pub(crate) fn try_overlap_aabb_impl(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     aabb: Aabb,
     filter: QueryFilter,
 ) -> ApiResult<Vec<ShapeId>> {
     try_checked_query_result_impl(|| {
         check_query_aabb_valid(aabb)?;
-        Ok(overlap_aabb_impl(raw_world_id, aabb, filter))
+        Ok(overlap_aabb_impl(world, aabb, filter))
     })
 }
 
 pub(crate) fn try_visit_overlap_aabb_impl<F>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     aabb: Aabb,
     filter: QueryFilter,
     visit: &mut F,
@@ -60,25 +60,25 @@ where
 {
     try_checked_query_result_impl(|| {
         check_query_aabb_valid(aabb)?;
-        Ok(visit_overlap_aabb_impl(raw_world_id, aabb, filter, visit))
+        Ok(visit_overlap_aabb_impl(world, aabb, filter, visit))
     })
 }
 
 pub(crate) fn try_overlap_aabb_into_impl(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     aabb: Aabb,
     filter: QueryFilter,
     out: &mut Vec<ShapeId>,
 ) -> ApiResult<()> {
     try_checked_query_result_impl(|| {
         check_query_aabb_valid(aabb)?;
-        overlap_aabb_into_impl(raw_world_id, aabb, filter, out);
+        overlap_aabb_into_impl(world, aabb, filter, out);
         Ok(())
     })
 }
 
 pub(crate) fn overlap_polygon_points_checked_impl<I, P>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     points: I,
     radius: f32,
     filter: QueryFilter,
@@ -90,12 +90,12 @@ where
     checked_query_impl(|| {
         assert_query_non_negative_finite_scalar("radius", radius);
         let points = collect_asserted_proxy_points(points);
-        overlap_polygon_points_impl(raw_world_id, &points, radius, filter)
+        overlap_polygon_points_impl(world, &points, radius, filter)
     })
 }
 
 pub(crate) fn visit_overlap_polygon_points_checked_impl<I, P, F>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     points: I,
     radius: f32,
     filter: QueryFilter,
@@ -109,12 +109,12 @@ where
     checked_query_impl(|| {
         assert_query_non_negative_finite_scalar("radius", radius);
         let points = collect_asserted_proxy_points(points);
-        visit_overlap_polygon_points_impl(raw_world_id, &points, radius, filter, visit)
+        visit_overlap_polygon_points_impl(world, &points, radius, filter, visit)
     })
 }
 
 pub(crate) fn overlap_polygon_points_into_checked_impl<I, P>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     points: I,
     radius: f32,
     filter: QueryFilter,
@@ -126,12 +126,12 @@ pub(crate) fn overlap_polygon_points_into_checked_impl<I, P>(
     checked_query_impl(|| {
         assert_query_non_negative_finite_scalar("radius", radius);
         let points = collect_asserted_proxy_points(points);
-        overlap_polygon_points_into_impl(raw_world_id, &points, radius, filter, out)
+        overlap_polygon_points_into_impl(world, &points, radius, filter, out)
     });
 }
 
 pub(crate) fn try_overlap_polygon_points_impl<I, P>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     points: I,
     radius: f32,
     filter: QueryFilter,
@@ -143,17 +143,12 @@ where
     try_checked_query_result_impl(|| {
         check_query_non_negative_finite_scalar(radius)?;
         let points = try_collect_proxy_points(points)?;
-        Ok(overlap_polygon_points_impl(
-            raw_world_id,
-            &points,
-            radius,
-            filter,
-        ))
+        Ok(overlap_polygon_points_impl(world, &points, radius, filter))
     })
 }
 
 pub(crate) fn try_visit_overlap_polygon_points_impl<I, P, F>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     points: I,
     radius: f32,
     filter: QueryFilter,
@@ -168,17 +163,13 @@ where
         check_query_non_negative_finite_scalar(radius)?;
         let points = try_collect_proxy_points(points)?;
         Ok(visit_overlap_polygon_points_impl(
-            raw_world_id,
-            &points,
-            radius,
-            filter,
-            visit,
+            world, &points, radius, filter, visit,
         ))
     })
 }
 
 pub(crate) fn try_overlap_polygon_points_into_impl<I, P>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     points: I,
     radius: f32,
     filter: QueryFilter,
@@ -191,13 +182,13 @@ where
     try_checked_query_result_impl(|| {
         check_query_non_negative_finite_scalar(radius)?;
         let points = try_collect_proxy_points(points)?;
-        overlap_polygon_points_into_impl(raw_world_id, &points, radius, filter, out);
+        overlap_polygon_points_into_impl(world, &points, radius, filter, out);
         Ok(())
     })
 }
 
 pub(crate) fn overlap_polygon_points_with_offset_checked_impl<I, P, V, A>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     points: I,
     radius: f32,
     position: V,
@@ -218,7 +209,7 @@ where
         assert_query_angle_valid(angle_radians);
         let points = collect_asserted_proxy_points(points);
         overlap_polygon_points_with_offset_impl(
-            raw_world_id,
+            world,
             &points,
             radius,
             position,
@@ -229,7 +220,7 @@ where
 }
 
 pub(crate) fn visit_overlap_polygon_points_with_offset_checked_impl<I, P, V, A, F>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     points: I,
     radius: f32,
     position: V,
@@ -252,7 +243,7 @@ where
         assert_query_angle_valid(angle_radians);
         let points = collect_asserted_proxy_points(points);
         visit_overlap_polygon_points_with_offset_impl(
-            raw_world_id,
+            world,
             &points,
             radius,
             position,
@@ -264,7 +255,7 @@ where
 }
 
 pub(crate) fn overlap_polygon_points_with_offset_into_checked_impl<I, P, V, A>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     points: I,
     radius: f32,
     position: V,
@@ -285,7 +276,7 @@ pub(crate) fn overlap_polygon_points_with_offset_into_checked_impl<I, P, V, A>(
         assert_query_angle_valid(angle_radians);
         let points = collect_asserted_proxy_points(points);
         overlap_polygon_points_with_offset_into_impl(
-            raw_world_id,
+            world,
             &points,
             radius,
             position,
@@ -297,7 +288,7 @@ pub(crate) fn overlap_polygon_points_with_offset_into_checked_impl<I, P, V, A>(
 }
 
 pub(crate) fn try_overlap_polygon_points_with_offset_impl<I, P, V, A>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     points: I,
     radius: f32,
     position: V,
@@ -318,7 +309,7 @@ where
         check_query_angle_valid(angle_radians)?;
         let points = try_collect_proxy_points(points)?;
         Ok(overlap_polygon_points_with_offset_impl(
-            raw_world_id,
+            world,
             &points,
             radius,
             position,
@@ -329,7 +320,7 @@ where
 }
 
 pub(crate) fn try_visit_overlap_polygon_points_with_offset_impl<I, P, V, A, F>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     points: I,
     radius: f32,
     position: V,
@@ -352,7 +343,7 @@ where
         check_query_angle_valid(angle_radians)?;
         let points = try_collect_proxy_points(points)?;
         Ok(visit_overlap_polygon_points_with_offset_impl(
-            raw_world_id,
+            world,
             &points,
             radius,
             position,
@@ -364,7 +355,7 @@ where
 }
 
 pub(crate) fn try_overlap_polygon_points_with_offset_into_impl<I, P, V, A>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     points: I,
     radius: f32,
     position: V,
@@ -386,7 +377,7 @@ where
         check_query_angle_valid(angle_radians)?;
         let points = try_collect_proxy_points(points)?;
         overlap_polygon_points_with_offset_into_impl(
-            raw_world_id,
+            world,
             &points,
             radius,
             position,
@@ -397,3 +388,261 @@ where
         Ok(())
     })
 }
+
+fn pick_impl(world: &WorldCore, point: Vec2, filter: QueryFilter) -> Option<PickResult> {
+    // Tiny box around the point; the broadphase only returns candidates, `TestPoint` decides.
+    let aabb = Aabb::from_center_half_extents(point, [f32::EPSILON, f32::EPSILON]);
+    let mut picked = None;
+    visit_overlap_aabb_impl(world, aabb, filter, &mut |shape| {
+        if crate::shapes::shape_is_sensor_impl(shape)
+            || !crate::shapes::shape_test_point_impl(shape, point)
+        {
+            return true;
+        }
+        picked = Some(PickResult {
+            body: crate::shapes::shape_body_id_impl(shape),
+            shape,
+            world_point: point,
+        });
+        false
+    });
+    picked
+}
+
+pub(crate) fn pick_checked_impl(
+    world: &WorldCore,
+    camera: &crate::camera::Camera2D,
+    screen_pos: Vec2,
+    filter: QueryFilter,
+) -> Option<PickResult> {
+    checked_query_impl(|| {
+        let point = camera.screen_to_world(screen_pos);
+        assert_query_vec2_valid("picked point", point);
+        pick_impl(world, point, filter)
+    })
+}
+
+pub(crate) fn try_pick_impl(
+    world: &WorldCore,
+    camera: &crate::camera::Camera2D,
+    screen_pos: Vec2,
+    filter: QueryFilter,
+) -> ApiResult<Option<PickResult>> {
+    try_checked_query_result_impl(|| {
+        let point = camera.screen_to_world(screen_pos);
+        check_query_vec2_valid(point)?;
+        Ok(pick_impl(world, point, filter))
+    })
+}
+
+pub(crate) fn overlap_polygon_checked_impl(
+    world: &WorldCore,
+    polygon: &crate::shapes::Polygon,
+    transform: crate::Transform,
+    filter: QueryFilter,
+) -> Vec<ShapeId> {
+    checked_query_impl(|| {
+        assert!(
+            polygon.is_valid(),
+            "polygon must contain valid Box2D geometry"
+        );
+        assert!(
+            transform.is_valid(),
+            "transform must be a valid Box2D transform, got {transform:?}"
+        );
+        overlap_shape_proxy_impl(world, &make_polygon_proxy(polygon, transform), filter)
+    })
+}
+
+pub(crate) fn try_overlap_polygon_impl(
+    world: &WorldCore,
+    polygon: &crate::shapes::Polygon,
+    transform: crate::Transform,
+    filter: QueryFilter,
+) -> ApiResult<Vec<ShapeId>> {
+    try_checked_query_result_impl(|| {
+        if !(polygon.is_valid() && transform.is_valid()) {
+            return Err(crate::error::ApiError::InvalidArgument);
+        }
+        Ok(overlap_shape_proxy_impl(
+            world,
+            &make_polygon_proxy(polygon, transform),
+            filter,
+        ))
+    })
+}
+
+fn geometry_is_valid(geometry: &crate::shapes::Geometry) -> bool {
+    use crate::shapes::Geometry;
+    match *geometry {
+        Geometry::Circle(g) => g.is_valid(),
+        Geometry::Segment(g) => g.is_valid(),
+        Geometry::Capsule(g) => g.is_valid(),
+        Geometry::Polygon(g) => g.is_valid(),
+    }
+}
+
+pub(crate) fn overlap_geometry_checked_impl(
+    world: &WorldCore,
+    geometry: &crate::shapes::Geometry,
+    transform: crate::Transform,
+    filter: QueryFilter,
+) -> Vec<ShapeId> {
+    checked_query_impl(|| {
+        assert!(
+            geometry_is_valid(geometry),
+            "geometry must be valid Box2D geometry, got {geometry:?}"
+        );
+        assert!(
+            transform.is_valid(),
+            "transform must be a valid Box2D transform, got {transform:?}"
+        );
+        overlap_shape_proxy_impl(world, &make_geometry_proxy(geometry, transform), filter)
+    })
+}
+
+pub(crate) fn try_overlap_geometry_impl(
+    world: &WorldCore,
+    geometry: &crate::shapes::Geometry,
+    transform: crate::Transform,
+    filter: QueryFilter,
+) -> ApiResult<Vec<ShapeId>> {
+    try_checked_query_result_impl(|| {
+        if !(geometry_is_valid(geometry) && transform.is_valid()) {
+            return Err(crate::error::ApiError::InvalidArgument);
+        }
+        Ok(overlap_shape_proxy_impl(
+            world,
+            &make_geometry_proxy(geometry, transform),
+            filter,
+        ))
+    })
+}
+
+pub(crate) fn visit_overlap_geometry_checked_impl<F>(
+    world: &WorldCore,
+    geometry: &crate::shapes::Geometry,
+    transform: crate::Transform,
+    filter: QueryFilter,
+    visit: &mut F,
+) -> bool
+where
+    F: FnMut(ShapeId) -> bool,
+{
+    checked_query_impl(|| {
+        assert!(
+            geometry_is_valid(geometry),
+            "geometry must be valid Box2D geometry, got {geometry:?}"
+        );
+        assert!(
+            transform.is_valid(),
+            "transform must be a valid Box2D transform, got {transform:?}"
+        );
+        visit_overlap_shape_proxy_impl(
+            world,
+            &make_geometry_proxy(geometry, transform),
+            filter,
+            visit,
+        )
+    })
+}
+
+pub(crate) fn try_visit_overlap_geometry_impl<F>(
+    world: &WorldCore,
+    geometry: &crate::shapes::Geometry,
+    transform: crate::Transform,
+    filter: QueryFilter,
+    visit: &mut F,
+) -> ApiResult<bool>
+where
+    F: FnMut(ShapeId) -> bool,
+{
+    try_checked_query_result_impl(|| {
+        if !(geometry_is_valid(geometry) && transform.is_valid()) {
+            return Err(crate::error::ApiError::InvalidArgument);
+        }
+        Ok(visit_overlap_shape_proxy_impl(
+            world,
+            &make_geometry_proxy(geometry, transform),
+            filter,
+            visit,
+        ))
+    })
+}
+
+pub(crate) fn overlap_round_checked_impl(
+    world: &WorldCore,
+    points: &[Vec2],
+    radius: f32,
+    filter: QueryFilter,
+) -> Vec<ShapeId> {
+    checked_query_impl(|| {
+        for &p in points {
+            assert_query_vec2_valid("center", p);
+        }
+        assert_query_non_negative_finite_scalar("radius", radius);
+        overlap_shape_proxy_impl(world, &make_round_proxy(points, radius), filter)
+    })
+}
+
+pub(crate) fn try_overlap_round_impl(
+    world: &WorldCore,
+    points: &[Vec2],
+    radius: f32,
+    filter: QueryFilter,
+) -> ApiResult<Vec<ShapeId>> {
+    try_checked_query_result_impl(|| {
+        for &p in points {
+            check_query_vec2_valid(p)?;
+        }
+        check_query_non_negative_finite_scalar(radius)?;
+        Ok(overlap_shape_proxy_impl(
+            world,
+            &make_round_proxy(points, radius),
+            filter,
+        ))
+    })
+}
+
+pub(crate) fn visit_overlap_round_checked_impl<F>(
+    world: &WorldCore,
+    points: &[Vec2],
+    radius: f32,
+    filter: QueryFilter,
+    visit: &mut F,
+) -> bool
+where
+    F: FnMut(ShapeId) -> bool,
+{
+    checked_query_impl(|| {
+        for &p in points {
+            assert_query_vec2_valid("center", p);
+        }
+        assert_query_non_negative_finite_scalar("radius", radius);
+        visit_overlap_shape_proxy_impl(world, &make_round_proxy(points, radius), filter, visit)
+    })
+}
+
+pub(crate) fn try_visit_overlap_round_impl<F>(
+    world: &WorldCore,
+    points: &[Vec2],
+    radius: f32,
+    filter: QueryFilter,
+    visit: &mut F,
+) -> ApiResult<bool>
+where
+    F: FnMut(ShapeId) -> bool,
+{
+    try_checked_query_result_impl(|| {
+        for &p in points {
+            check_query_vec2_valid(p)?;
+        }
+        check_query_non_negative_finite_scalar(radius)?;
+        Ok(visit_overlap_shape_proxy_impl(
+            world,
+            &make_round_proxy(points, radius),
+            filter,
+            visit,
+        ))
+    })
+}
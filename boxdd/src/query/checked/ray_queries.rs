@@ -1,7 +1,7 @@
 use super::*;
 
 pub(crate) fn cast_ray_closest_checked_impl<VO: Into<Vec2>, VT: Into<Vec2>>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     origin: VO,
     translation: VT,
     filter: QueryFilter,
@@ -11,12 +11,12 @@ pub(crate) fn cast_ray_closest_checked_impl<VO: Into<Vec2>, VT: Into<Vec2>>(
         let translation = translation.into();
         assert_query_vec2_valid("origin", origin);
         assert_query_vec2_valid("translation", translation);
-        cast_ray_closest_impl(raw_world_id, origin, translation, filter)
+        cast_ray_closest_impl(world, origin, translation, filter)
     })
 }
 
 pub(crate) fn try_cast_ray_closest_impl<VO: Into<Vec2>, VT: Into<Vec2>>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     origin: VO,
     translation: VT,
     filter: QueryFilter,
@@ -26,17 +26,12 @@ pub(crate) fn try_cast_ray_closest_impl<VO: Into<Vec2>, VT: Into<Vec2>>(
         let translation = translation.into();
         check_query_vec2_valid(origin)?;
         check_query_vec2_valid(translation)?;
-        Ok(cast_ray_closest_impl(
-            raw_world_id,
-            origin,
-            translation,
-            filter,
-        ))
+        Ok(cast_ray_closest_impl(world, origin, translation, filter))
     })
 }
 
 pub(crate) fn cast_ray_all_checked_impl<VO: Into<Vec2>, VT: Into<Vec2>>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     origin: VO,
     translation: VT,
     filter: QueryFilter,
@@ -46,12 +41,12 @@ pub(crate) fn cast_ray_all_checked_impl<VO: Into<Vec2>, VT: Into<Vec2>>(
         let translation = translation.into();
         assert_query_vec2_valid("origin", origin);
         assert_query_vec2_valid("translation", translation);
-        cast_ray_all_impl(raw_world_id, origin, translation, filter)
+        cast_ray_all_impl(world, origin, translation, filter)
     })
 }
 
 pub(crate) fn cast_ray_all_into_checked_impl<VO: Into<Vec2>, VT: Into<Vec2>>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     origin: VO,
     translation: VT,
     filter: QueryFilter,
@@ -62,12 +57,12 @@ pub(crate) fn cast_ray_all_into_checked_impl<VO: Into<Vec2>, VT: Into<Vec2>>(
         let translation = translation.into();
         assert_query_vec2_valid("origin", origin);
         assert_query_vec2_valid("translation", translation);
-        cast_ray_all_into_impl(raw_world_id, origin, translation, filter, out);
+        cast_ray_all_into_impl(world, origin, translation, filter, out);
     });
 }
 
 pub(crate) fn try_cast_ray_all_impl<VO: Into<Vec2>, VT: Into<Vec2>>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     origin: VO,
     translation: VT,
     filter: QueryFilter,
@@ -77,12 +72,12 @@ pub(crate) fn try_cast_ray_all_impl<VO: Into<Vec2>, VT: Into<Vec2>>(
         let translation = translation.into();
         check_query_vec2_valid(origin)?;
         check_query_vec2_valid(translation)?;
-        Ok(cast_ray_all_impl(raw_world_id, origin, translation, filter))
+        Ok(cast_ray_all_impl(world, origin, translation, filter))
     })
 }
 
 pub(crate) fn try_cast_ray_all_into_impl<VO: Into<Vec2>, VT: Into<Vec2>>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     origin: VO,
     translation: VT,
     filter: QueryFilter,
@@ -93,7 +88,161 @@ pub(crate) fn try_cast_ray_all_into_impl<VO: Into<Vec2>, VT: Into<Vec2>>(
         let translation = translation.into();
         check_query_vec2_valid(origin)?;
         check_query_vec2_valid(translation)?;
-        cast_ray_all_into_impl(raw_world_id, origin, translation, filter, out);
+        cast_ray_all_into_impl(world, origin, translation, filter, out);
         Ok(())
     })
 }
+
+pub(crate) fn cast_ray_hits_into_checked_impl<VO: Into<Vec2>, VT: Into<Vec2>>(
+    world: &WorldCore,
+    origin: VO,
+    translation: VT,
+    filter: QueryFilter,
+    max_hits: usize,
+    out: &mut Vec<RayHit>,
+) {
+    checked_query_impl(|| {
+        let origin = origin.into();
+        let translation = translation.into();
+        assert_query_vec2_valid("origin", origin);
+        assert_query_vec2_valid("translation", translation);
+        cast_ray_hits_into_impl(world, origin, translation, filter, max_hits, out);
+    });
+}
+
+pub(crate) fn try_cast_ray_hits_into_impl<VO: Into<Vec2>, VT: Into<Vec2>>(
+    world: &WorldCore,
+    origin: VO,
+    translation: VT,
+    filter: QueryFilter,
+    max_hits: usize,
+    out: &mut Vec<RayHit>,
+) -> ApiResult<()> {
+    try_checked_query_result_impl(|| {
+        let origin = origin.into();
+        let translation = translation.into();
+        check_query_vec2_valid(origin)?;
+        check_query_vec2_valid(translation)?;
+        cast_ray_hits_into_impl(world, origin, translation, filter, max_hits, out);
+        Ok(())
+    })
+}
+
+pub(crate) fn cast_ray_with_checked_impl<VO: Into<Vec2>, VT: Into<Vec2>, F>(
+    world: &WorldCore,
+    origin: VO,
+    translation: VT,
+    filter: QueryFilter,
+    visit: &mut F,
+) where
+    F: FnMut(RayHit) -> f32,
+{
+    checked_query_impl(|| {
+        let origin = origin.into();
+        let translation = translation.into();
+        assert_query_vec2_valid("origin", origin);
+        assert_query_vec2_valid("translation", translation);
+        cast_ray_with_impl(world, origin, translation, filter, visit);
+    });
+}
+
+pub(crate) fn try_cast_ray_with_impl<VO: Into<Vec2>, VT: Into<Vec2>, F>(
+    world: &WorldCore,
+    origin: VO,
+    translation: VT,
+    filter: QueryFilter,
+    visit: &mut F,
+) -> ApiResult<()>
+where
+    F: FnMut(RayHit) -> f32,
+{
+    try_checked_query_result_impl(|| {
+        let origin = origin.into();
+        let translation = translation.into();
+        check_query_vec2_valid(origin)?;
+        check_query_vec2_valid(translation)?;
+        cast_ray_with_impl(world, origin, translation, filter, visit);
+        Ok(())
+    })
+}
+
+fn occlusion_from_hits(hits: &[RayResult], mut transmission: impl FnMut(&RayResult) -> f32) -> f32 {
+    hits.iter()
+        .filter(|hit| !crate::shapes::shape_is_sensor_impl(hit.shape_id))
+        .map(|hit| transmission(hit).clamp(0.0, 1.0))
+        .product()
+}
+
+pub(crate) fn occlusion_checked_impl<VF: Into<Vec2>, VT: Into<Vec2>>(
+    world: &WorldCore,
+    from: VF,
+    to: VT,
+    filter: QueryFilter,
+    transmission: impl FnMut(&RayResult) -> f32,
+) -> f32 {
+    checked_query_impl(|| {
+        let from = from.into();
+        let to = to.into();
+        assert_query_vec2_valid("from", from);
+        assert_query_vec2_valid("to", to);
+        let hits = cast_ray_all_impl(world, from, to.minus(from), filter);
+        occlusion_from_hits(&hits, transmission)
+    })
+}
+
+pub(crate) fn try_occlusion_impl<VF: Into<Vec2>, VT: Into<Vec2>>(
+    world: &WorldCore,
+    from: VF,
+    to: VT,
+    filter: QueryFilter,
+    transmission: impl FnMut(&RayResult) -> f32,
+) -> ApiResult<f32> {
+    try_checked_query_result_impl(|| {
+        let from = from.into();
+        let to = to.into();
+        check_query_vec2_valid(from)?;
+        check_query_vec2_valid(to)?;
+        let hits = cast_ray_all_impl(world, from, to.minus(from), filter);
+        Ok(occlusion_from_hits(&hits, transmission))
+    })
+}
+
+fn cast_ray_against_body_impl(
+    body: crate::types::BodyId,
+    origin: Vec2,
+    translation: Vec2,
+) -> RayResult {
+    closest_body_hit_impl(body, |shape| {
+        crate::shapes::shape_ray_cast_impl(shape, origin, translation)
+    })
+}
+
+pub(crate) fn cast_ray_against_body_checked_impl<VO: Into<Vec2>, VT: Into<Vec2>>(
+    body: crate::types::BodyId,
+    origin: VO,
+    translation: VT,
+) -> RayResult {
+    checked_query_impl(|| {
+        crate::core::debug_checks::assert_body_valid(body);
+        let origin = origin.into();
+        let translation = translation.into();
+        assert_query_vec2_valid("origin", origin);
+        assert_query_vec2_valid("translation", translation);
+        cast_ray_against_body_impl(body, origin, translation)
+    })
+}
+
+pub(crate) fn try_cast_ray_against_body_impl<VO: Into<Vec2>, VT: Into<Vec2>>(
+    body: crate::types::BodyId,
+    origin: VO,
+    translation: VT,
+) -> ApiResult<RayResult> {
+    try_checked_query_result_impl(|| {
+        crate::core::debug_checks::check_body_valid(body)?;
+        let origin = origin.into();
+        let translation = translation.into();
+        check_query_vec2_valid(origin)?;
+        check_query_vec2_valid(translation)?;
+        Ok(cast_ray_against_body_impl(body, origin, translation))
+    })
+}
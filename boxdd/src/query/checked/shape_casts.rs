@@ -1,7 +1,7 @@
 use super::*;
 
 pub(crate) fn cast_shape_points_checked_impl<I, P, VT>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     points: I,
     radius: f32,
     translation: VT,
@@ -17,12 +17,12 @@ where
         assert_query_non_negative_finite_scalar("radius", radius);
         assert_query_vec2_valid("translation", translation);
         let points = collect_asserted_proxy_points(points);
-        cast_shape_points_impl(raw_world_id, &points, radius, translation, filter)
+        cast_shape_points_impl(world, &points, radius, translation, filter)
     })
 }
 
 pub(crate) fn cast_shape_points_into_checked_impl<I, P, VT>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     points: I,
     radius: f32,
     translation: VT,
@@ -38,12 +38,12 @@ pub(crate) fn cast_shape_points_into_checked_impl<I, P, VT>(
         assert_query_non_negative_finite_scalar("radius", radius);
         assert_query_vec2_valid("translation", translation);
         let points = collect_asserted_proxy_points(points);
-        cast_shape_points_into_impl(raw_world_id, &points, radius, translation, filter, out)
+        cast_shape_points_into_impl(world, &points, radius, translation, filter, out)
     });
 }
 
 pub(crate) fn try_cast_shape_points_impl<I, P, VT>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     points: I,
     radius: f32,
     translation: VT,
@@ -60,7 +60,7 @@ where
         check_query_vec2_valid(translation)?;
         let points = try_collect_proxy_points(points)?;
         Ok(cast_shape_points_impl(
-            raw_world_id,
+            world,
             &points,
             radius,
             translation,
@@ -70,7 +70,7 @@ where
 }
 
 pub(crate) fn try_cast_shape_points_into_impl<I, P, VT>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     points: I,
     radius: f32,
     translation: VT,
@@ -87,13 +87,13 @@ where
         check_query_non_negative_finite_scalar(radius)?;
         check_query_vec2_valid(translation)?;
         let points = try_collect_proxy_points(points)?;
-        cast_shape_points_into_impl(raw_world_id, &points, radius, translation, filter, out);
+        cast_shape_points_into_impl(world, &points, radius, translation, filter, out);
         Ok(())
     })
 }
 
 pub(crate) fn cast_shape_points_with_offset_checked_impl<I, P, V, A, VT>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     points: I,
     radius: f32,
     position: V,
@@ -118,7 +118,7 @@ where
         assert_query_vec2_valid("translation", translation);
         let points = collect_asserted_proxy_points(points);
         cast_shape_points_with_offset_impl(
-            raw_world_id,
+            world,
             &points,
             radius,
             position,
@@ -131,7 +131,7 @@ where
 
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn cast_shape_points_with_offset_into_checked_impl<I, P, V, A, VT>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     points: I,
     radius: f32,
     position: V,
@@ -156,7 +156,7 @@ pub(crate) fn cast_shape_points_with_offset_into_checked_impl<I, P, V, A, VT>(
         assert_query_vec2_valid("translation", translation);
         let points = collect_asserted_proxy_points(points);
         cast_shape_points_with_offset_into_impl(
-            raw_world_id,
+            world,
             &points,
             radius,
             position,
@@ -169,7 +169,7 @@ pub(crate) fn cast_shape_points_with_offset_into_checked_impl<I, P, V, A, VT>(
 }
 
 pub(crate) fn try_cast_shape_points_with_offset_impl<I, P, V, A, VT>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     points: I,
     radius: f32,
     position: V,
@@ -194,7 +194,7 @@ where
         check_query_vec2_valid(translation)?;
         let points = try_collect_proxy_points(points)?;
         Ok(cast_shape_points_with_offset_impl(
-            raw_world_id,
+            world,
             &points,
             radius,
             position,
@@ -207,7 +207,7 @@ where
 
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn try_cast_shape_points_with_offset_into_impl<I, P, V, A, VT>(
-    raw_world_id: ffi::b2WorldId,
+    world: &WorldCore,
     points: I,
     radius: f32,
     position: V,
@@ -233,7 +233,7 @@ where
         check_query_vec2_valid(translation)?;
         let points = try_collect_proxy_points(points)?;
         cast_shape_points_with_offset_into_impl(
-            raw_world_id,
+            world,
             &points,
             radius,
             position,
@@ -245,3 +245,141 @@ where
         Ok(())
     })
 }
+
+pub(crate) fn cast_shape_hits_into_checked_impl<VT: Into<Vec2>>(
+    world: &WorldCore,
+    proxy: &crate::collision::ShapeProxy,
+    translation: VT,
+    filter: QueryFilter,
+    closest: bool,
+    out: &mut Vec<RayHit>,
+) {
+    checked_query_impl(|| {
+        let translation = translation.into();
+        assert!(
+            proxy.validate().is_ok(),
+            "proxy must contain valid Box2D geometry, got {proxy:?}"
+        );
+        assert_query_vec2_valid("translation", translation);
+        cast_shape_hits_into_impl(world, proxy, translation, filter, closest, out);
+    });
+}
+
+pub(crate) fn try_cast_shape_hits_into_impl<VT: Into<Vec2>>(
+    world: &WorldCore,
+    proxy: &crate::collision::ShapeProxy,
+    translation: VT,
+    filter: QueryFilter,
+    closest: bool,
+    out: &mut Vec<RayHit>,
+) -> ApiResult<()> {
+    try_checked_query_result_impl(|| {
+        let translation = translation.into();
+        proxy.validate()?;
+        check_query_vec2_valid(translation)?;
+        cast_shape_hits_into_impl(world, proxy, translation, filter, closest, out);
+        Ok(())
+    })
+}
+
+pub(crate) fn cast_shape_with_checked_impl<VT: Into<Vec2>, F>(
+    world: &WorldCore,
+    proxy: &crate::collision::ShapeProxy,
+    translation: VT,
+    filter: QueryFilter,
+    visit: &mut F,
+) where
+    F: FnMut(RayHit) -> f32,
+{
+    checked_query_impl(|| {
+        let translation = translation.into();
+        assert!(
+            proxy.validate().is_ok(),
+            "proxy must contain valid Box2D geometry, got {proxy:?}"
+        );
+        assert_query_vec2_valid("translation", translation);
+        cast_shape_with_impl(world, proxy, translation, filter, visit);
+    });
+}
+
+pub(crate) fn try_cast_shape_with_impl<VT: Into<Vec2>, F>(
+    world: &WorldCore,
+    proxy: &crate::collision::ShapeProxy,
+    translation: VT,
+    filter: QueryFilter,
+    visit: &mut F,
+) -> ApiResult<()>
+where
+    F: FnMut(RayHit) -> f32,
+{
+    try_checked_query_result_impl(|| {
+        let translation = translation.into();
+        proxy.validate()?;
+        check_query_vec2_valid(translation)?;
+        cast_shape_with_impl(world, proxy, translation, filter, visit);
+        Ok(())
+    })
+}
+
+fn cast_shape_against_body_impl(
+    body: crate::types::BodyId,
+    proxy: crate::collision::ShapeProxy,
+    transform: crate::Transform,
+    translation: Vec2,
+) -> RayResult {
+    let body_transform = crate::body::body_transform_impl(body);
+    closest_body_hit_impl(body, |shape| {
+        crate::collision::shape_cast(crate::collision::ShapeCastPairInput::new(
+            crate::shapes::shape_local_proxy_impl(shape),
+            proxy,
+            body_transform,
+            transform,
+            translation,
+        ))
+    })
+}
+
+pub(crate) fn cast_shape_against_body_checked_impl<VT: Into<Vec2>>(
+    body: crate::types::BodyId,
+    proxy: &crate::collision::ShapeProxy,
+    transform: crate::Transform,
+    translation: VT,
+) -> RayResult {
+    checked_query_impl(|| {
+        crate::core::debug_checks::assert_body_valid(body);
+        let translation = translation.into();
+        assert!(
+            proxy.validate().is_ok(),
+            "proxy must contain valid Box2D geometry, got {proxy:?}"
+        );
+        assert!(
+            transform.is_valid(),
+            "transform must be a valid Box2D transform, got {transform:?}"
+        );
+        assert_query_vec2_valid("translation", translation);
+        cast_shape_against_body_impl(body, *proxy, transform, translation)
+    })
+}
+
+pub(crate) fn try_cast_shape_against_body_impl<VT: Into<Vec2>>(
+    body: crate::types::BodyId,
+    proxy: &crate::collision::ShapeProxy,
+    transform: crate::Transform,
+    translation: VT,
+) -> ApiResult<RayResult> {
+    try_checked_query_result_impl(|| {
+        crate::core::debug_checks::check_body_valid(body)?;
+        let translation = translation.into();
+        proxy.validate()?;
+        if !transform.is_valid() {
+            return Err(crate::error::ApiError::InvalidArgument);
+        }
+        check_query_vec2_valid(translation)?;
+        Ok(cast_shape_against_body_impl(
+            body,
+            *proxy,
+            transform,
+            translation,
+        ))
+    })
+}
@@ -12,10 +12,14 @@
 
 mod checked;
 mod raw;
+mod snapshot;
+mod static_cache;
 mod types;
 mod world_api;
 
+pub use snapshot::{QuerySnapshot, SnapshotShape};
+pub use static_cache::StaticQueryCache;
 pub use types::{
-    Aabb, CollisionPlane, MoverPlaneResult, Plane, PlaneSolverResult, QueryFilter, RayResult,
-    clip_vector, solve_planes, try_clip_vector, try_solve_planes,
+    Aabb, CollisionPlane, MoverPlaneResult, PickResult, Plane, PlaneSolverResult, QueryFilter,
+    RayHit, RayResult, clip_vector, solve_planes, try_clip_vector, try_solve_planes,
 };
@@ -5,6 +5,9 @@ use smallvec::SmallVec;
 use std::any::Any;
 
 use super::types::*;
+use crate::core::query_stats::{measure, measure_tree};
+use crate::core::world_core::WorldCore;
+use crate::world::QueryKind;
 
 const MAX_PROXY_POINTS: usize = ffi::B2_MAX_POLYGON_VERTICES as usize;
 type ProxyPoints = SmallVec<[ffi::b2Vec2; MAX_PROXY_POINTS]>;
@@ -161,6 +164,43 @@ where
     ctx.visit(ShapeId::from_raw(shape_id))
 }
 
+struct VisitRayHitCtx<'a, F> {
+    visit: &'a mut F,
+    panic: Option<PanicPayload>,
+}
+
+impl<F> VisitRayHitCtx<'_, F> {
+    fn finish(self) {
+        if let Some(p) = self.panic {
+            std::panic::resume_unwind(p);
+        }
+    }
+}
+
+unsafe extern "C" fn visit_ray_hit_cb<F>(
+    shape_id: ffi::b2ShapeId,
+    point: ffi::b2Vec2,
+    normal: ffi::b2Vec2,
+    fraction: f32,
+    ctx: *mut core::ffi::c_void,
+) -> f32
+where
+    F: FnMut(RayHit) -> f32,
+{
+    let ctx = unsafe { &mut *(ctx as *mut VisitRayHitCtx<'_, F>) };
+    if ctx.panic.is_some() {
+        return 0.0;
+    }
+    let hit = ray_hit(shape_id, point, normal, fraction);
+    match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| (ctx.visit)(hit))) {
+        Ok(control) => control,
+        Err(p) => {
+            ctx.panic = Some(p);
+            0.0
+        }
+    }
+}
+
 #[allow(clippy::unnecessary_cast)]
 unsafe extern "C" fn collect_ray_result_cb(
     shape_id: ffi::b2ShapeId,
@@ -183,6 +223,54 @@ unsafe extern "C" fn collect_ray_result_cb(
     }
 }
 
+unsafe extern "C" fn collect_ray_hit_cb(
+    shape_id: ffi::b2ShapeId,
+    point: ffi::b2Vec2,
+    normal: ffi::b2Vec2,
+    fraction: f32,
+    ctx: *mut core::ffi::c_void,
+) -> f32 {
+    let ctx = unsafe { &mut *(ctx as *mut CollectCtx<'_, RayHit>) };
+    if ctx.push(ray_hit(shape_id, point, normal, fraction)) {
+        1.0f32
+    } else {
+        0.0
+    }
+}
+
+/// Keeps only the latest hit and clips the cast to it, so the survivor is the closest.
+unsafe extern "C" fn closest_ray_hit_cb(
+    shape_id: ffi::b2ShapeId,
+    point: ffi::b2Vec2,
+    normal: ffi::b2Vec2,
+    fraction: f32,
+    ctx: *mut core::ffi::c_void,
+) -> f32 {
+    let ctx = unsafe { &mut *(ctx as *mut CollectCtx<'_, RayHit>) };
+    ctx.out.clear();
+    if ctx.push(ray_hit(shape_id, point, normal, fraction)) {
+        fraction
+    } else {
+        0.0
+    }
+}
+
+fn ray_hit(
+    shape_id: ffi::b2ShapeId,
+    point: ffi::b2Vec2,
+    normal: ffi::b2Vec2,
+    fraction: f32,
+) -> RayHit {
+    RayHit {
+        shape_id: ShapeId::from_raw(shape_id),
+        body_id: crate::types::BodyId::from_raw(unsafe { ffi::b2Shape_GetBody(shape_id) }),
+        point: Vec2::from_raw(point),
+        normal: Vec2::from_raw(normal),
+        fraction,
+        user_material_id: unsafe { ffi::b2Shape_GetUserMaterial(shape_id) },
+    }
+}
+
 unsafe extern "C" fn collect_mover_plane_result_cb(
     shape_id: ffi::b2ShapeId,
     plane: *const ffi::b2PlaneResult,
@@ -207,7 +295,7 @@ pub(super) fn make_capsule<V1: Into<Vec2>, V2: Into<Vec2>>(
 }
 
 pub(super) fn visit_overlap_aabb_impl<F>(
-    world: ffi::b2WorldId,
+    world: &WorldCore,
     aabb: Aabb,
     filter: QueryFilter,
     visit: &mut F,
@@ -216,20 +304,20 @@ where
     F: FnMut(ShapeId) -> bool,
 {
     let mut ctx = VisitShapeIdCtx::new(visit);
-    unsafe {
-        let _ = ffi::b2World_OverlapAABB(
-            world,
+    let _ = measure_tree(world, QueryKind::Overlap, || unsafe {
+        ffi::b2World_OverlapAABB(
+            world.id,
             aabb.into_raw(),
             filter.0,
             Some(visit_shape_id_cb::<F>),
             &mut ctx as *mut _ as *mut _,
-        );
-    }
+        )
+    });
     ctx.finish()
 }
 
 pub(super) fn overlap_aabb_into_impl(
-    world: ffi::b2WorldId,
+    world: &WorldCore,
     aabb: Aabb,
     filter: QueryFilter,
     out: &mut Vec<ShapeId>,
@@ -243,7 +331,7 @@ pub(super) fn overlap_aabb_into_impl(
 }
 
 pub(super) fn overlap_aabb_impl(
-    world: ffi::b2WorldId,
+    world: &WorldCore,
     aabb: Aabb,
     filter: QueryFilter,
 ) -> Vec<ShapeId> {
@@ -253,7 +341,7 @@ pub(super) fn overlap_aabb_impl(
 }
 
 pub(super) fn visit_overlap_shape_proxy_impl<F>(
-    world: ffi::b2WorldId,
+    world: &WorldCore,
     proxy: &ffi::b2ShapeProxy,
     filter: QueryFilter,
     visit: &mut F,
@@ -262,32 +350,84 @@ where
     F: FnMut(ShapeId) -> bool,
 {
     let mut ctx = VisitShapeIdCtx::new(visit);
-    unsafe {
-        let _ = ffi::b2World_OverlapShape(
-            world,
+    let _ = measure_tree(world, QueryKind::Overlap, || unsafe {
+        ffi::b2World_OverlapShape(
+            world.id,
             proxy,
             filter.0,
             Some(visit_shape_id_cb::<F>),
             &mut ctx as *mut _ as *mut _,
-        );
-    }
+        )
+    });
     ctx.finish()
 }
 
+pub(super) fn overlap_shape_proxy_impl(
+    world: &WorldCore,
+    proxy: &ffi::b2ShapeProxy,
+    filter: QueryFilter,
+) -> Vec<ShapeId> {
+    let mut out = Vec::new();
+    let mut collect = |shape_id| {
+        out.push(shape_id);
+        true
+    };
+    let _ = visit_overlap_shape_proxy_impl(world, proxy, filter, &mut collect);
+    out
+}
+
+pub(super) fn make_polygon_proxy(
+    polygon: &crate::shapes::Polygon,
+    transform: crate::Transform,
+) -> ffi::b2ShapeProxy {
+    let raw = polygon.into_raw();
+    unsafe {
+        ffi::b2MakeOffsetProxy(
+            raw.vertices.as_ptr(),
+            raw.count,
+            raw.radius,
+            transform.position().into_raw(),
+            transform.rotation().into_raw(),
+        )
+    }
+}
+
+pub(super) fn make_round_proxy(points: &[Vec2], radius: f32) -> ffi::b2ShapeProxy {
+    let raw: ProxyPoints = points.iter().map(|p| p.into_raw()).collect();
+    unsafe { ffi::b2MakeProxy(raw.as_ptr(), raw.len() as i32, radius) }
+}
+
+/// World-space proxy for `geometry` placed at `transform`.
+pub(super) fn make_geometry_proxy(
+    geometry: &crate::shapes::Geometry,
+    transform: crate::Transform,
+) -> ffi::b2ShapeProxy {
+    use crate::shapes::Geometry;
+    let at = |p: Vec2| transform.transform_point(p);
+    match geometry {
+        Geometry::Circle(c) => make_round_proxy(&[at(c.center)], c.radius),
+        Geometry::Capsule(c) => make_round_proxy(&[at(c.center1), at(c.center2)], c.radius),
+        Geometry::Segment(s) => make_round_proxy(&[at(s.point1), at(s.point2)], 0.0),
+        Geometry::Polygon(p) => make_polygon_proxy(p, transform),
+    }
+}
+
 pub(super) fn cast_ray_closest_impl<VO: Into<Vec2>, VT: Into<Vec2>>(
-    world: ffi::b2WorldId,
+    world: &WorldCore,
     origin: VO,
     translation: VT,
     filter: QueryFilter,
 ) -> RayResult {
     let o: ffi::b2Vec2 = origin.into().into_raw();
     let t: ffi::b2Vec2 = translation.into().into_raw();
-    let raw = unsafe { ffi::b2World_CastRayClosest(world, o, t, filter.0) };
+    let raw = measure(world, QueryKind::Ray, || unsafe {
+        ffi::b2World_CastRayClosest(world.id, o, t, filter.0)
+    });
     RayResult::from_raw(raw)
 }
 
 pub(super) fn cast_ray_all_impl<VO: Into<Vec2>, VT: Into<Vec2>>(
-    world: ffi::b2WorldId,
+    world: &WorldCore,
     origin: VO,
     translation: VT,
     filter: QueryFilter,
@@ -298,7 +438,7 @@ pub(super) fn cast_ray_all_impl<VO: Into<Vec2>, VT: Into<Vec2>>(
 }
 
 pub(super) fn cast_ray_all_into_impl<VO: Into<Vec2>, VT: Into<Vec2>>(
-    world: ffi::b2WorldId,
+    world: &WorldCore,
     origin: VO,
     translation: VT,
     filter: QueryFilter,
@@ -308,21 +448,141 @@ pub(super) fn cast_ray_all_into_impl<VO: Into<Vec2>, VT: Into<Vec2>>(
     let mut ctx = CollectCtx::from_cleared(out);
     let o: ffi::b2Vec2 = origin.into().into_raw();
     let t: ffi::b2Vec2 = translation.into().into_raw();
-    unsafe {
-        let _ = ffi::b2World_CastRay(
-            world,
+    let _ = measure_tree(world, QueryKind::Ray, || unsafe {
+        ffi::b2World_CastRay(
+            world.id,
             o,
             t,
             filter.0,
             Some(collect_ray_result_cb),
             &mut ctx as *mut _ as *mut _,
-        );
+        )
+    });
+    ctx.resume_unwind_if_needed();
+    // Box2D reports hits in broad-phase order.
+    out.sort_by(|a, b| a.fraction.total_cmp(&b.fraction));
+}
+
+pub(super) fn cast_ray_hits_into_impl(
+    world: &WorldCore,
+    origin: Vec2,
+    translation: Vec2,
+    filter: QueryFilter,
+    max_hits: usize,
+    out: &mut Vec<RayHit>,
+) {
+    out.clear();
+    if max_hits == 0 {
+        return;
     }
+    // Box2D reports hits in broad-phase order, so keep the nearest `max_hits` seen so far and,
+    // once full, clip the ray to the farthest of them so farther shapes are never visited.
+    let mut visit = |hit: RayHit| -> f32 {
+        if out.len() < max_hits {
+            out.push(hit);
+            if out.len() < max_hits {
+                return 1.0;
+            }
+        } else {
+            let farthest = farthest_hit(out);
+            if hit.fraction < out[farthest].fraction {
+                out[farthest] = hit;
+            }
+        }
+        out[farthest_hit(out)].fraction
+    };
+    cast_ray_with_impl(world, origin, translation, filter, &mut visit);
+    out.sort_by(|a, b| a.fraction.total_cmp(&b.fraction));
+}
+
+fn farthest_hit(hits: &[RayHit]) -> usize {
+    hits.iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| a.fraction.total_cmp(&b.fraction))
+        .map_or(0, |(i, _)| i)
+}
+
+pub(super) fn cast_ray_with_impl<F>(
+    world: &WorldCore,
+    origin: Vec2,
+    translation: Vec2,
+    filter: QueryFilter,
+    visit: &mut F,
+) where
+    F: FnMut(RayHit) -> f32,
+{
+    let mut ctx = VisitRayHitCtx { visit, panic: None };
+    let _ = measure_tree(world, QueryKind::Ray, || unsafe {
+        ffi::b2World_CastRay(
+            world.id,
+            origin.into_raw(),
+            translation.into_raw(),
+            filter.0,
+            Some(visit_ray_hit_cb::<F>),
+            &mut ctx as *mut _ as *mut _,
+        )
+    });
+    ctx.finish();
+}
+
+pub(super) fn cast_shape_with_impl<F>(
+    world: &WorldCore,
+    proxy: &crate::collision::ShapeProxy,
+    translation: Vec2,
+    filter: QueryFilter,
+    visit: &mut F,
+) where
+    F: FnMut(RayHit) -> f32,
+{
+    let raw = proxy.into_raw();
+    let mut ctx = VisitRayHitCtx { visit, panic: None };
+    let _ = measure_tree(world, QueryKind::Cast, || unsafe {
+        ffi::b2World_CastShape(
+            world.id,
+            &raw,
+            translation.into_raw(),
+            filter.0,
+            Some(visit_ray_hit_cb::<F>),
+            &mut ctx as *mut _ as *mut _,
+        )
+    });
+    ctx.finish();
+}
+
+/// Sweep `proxy` along `translation`. With `closest` only the nearest hit is kept; otherwise
+/// every hit is kept, sorted by fraction.
+pub(super) fn cast_shape_hits_into_impl(
+    world: &WorldCore,
+    proxy: &crate::collision::ShapeProxy,
+    translation: Vec2,
+    filter: QueryFilter,
+    closest: bool,
+    out: &mut Vec<RayHit>,
+) {
+    out.clear();
+    let raw = proxy.into_raw();
+    let callback = if closest {
+        closest_ray_hit_cb
+    } else {
+        collect_ray_hit_cb
+    };
+    let mut ctx = CollectCtx::from_cleared(out);
+    let _ = measure_tree(world, QueryKind::Cast, || unsafe {
+        ffi::b2World_CastShape(
+            world.id,
+            &raw,
+            translation.into_raw(),
+            filter.0,
+            Some(callback),
+            &mut ctx as *mut _ as *mut _,
+        )
+    });
     ctx.resume_unwind_if_needed();
+    out.sort_by(|a, b| a.fraction.total_cmp(&b.fraction));
 }
 
 pub(super) fn overlap_polygon_points_into_impl(
-    world: ffi::b2WorldId,
+    world: &WorldCore,
     points: &ProxyPoints,
     radius: f32,
     filter: QueryFilter,
@@ -337,7 +597,7 @@ pub(super) fn overlap_polygon_points_into_impl(
 }
 
 pub(super) fn visit_overlap_polygon_points_impl<F>(
-    world: ffi::b2WorldId,
+    world: &WorldCore,
     points: &ProxyPoints,
     radius: f32,
     filter: QueryFilter,
@@ -353,7 +613,7 @@ where
 }
 
 pub(super) fn overlap_polygon_points_impl(
-    world: ffi::b2WorldId,
+    world: &WorldCore,
     points: &ProxyPoints,
     radius: f32,
     filter: QueryFilter,
@@ -364,7 +624,7 @@ pub(super) fn overlap_polygon_points_impl(
 }
 
 pub(super) fn cast_shape_points_into_impl(
-    world: ffi::b2WorldId,
+    world: &WorldCore,
     points: &ProxyPoints,
     radius: f32,
     translation: Vec2,
@@ -377,21 +637,21 @@ pub(super) fn cast_shape_points_into_impl(
     };
     let mut ctx = CollectCtx::from_cleared(out);
     let t = translation.into_raw();
-    unsafe {
-        let _ = ffi::b2World_CastShape(
-            world,
+    let _ = measure_tree(world, QueryKind::Cast, || unsafe {
+        ffi::b2World_CastShape(
+            world.id,
             &proxy,
             t,
             filter.0,
             Some(collect_ray_result_cb),
             &mut ctx as *mut _ as *mut _,
-        );
-    }
+        )
+    });
     ctx.resume_unwind_if_needed();
 }
 
 pub(super) fn cast_shape_points_impl(
-    world: ffi::b2WorldId,
+    world: &WorldCore,
     points: &ProxyPoints,
     radius: f32,
     translation: Vec2,
@@ -403,7 +663,7 @@ pub(super) fn cast_shape_points_impl(
 }
 
 pub(super) fn cast_mover_impl(
-    world: ffi::b2WorldId,
+    world: &WorldCore,
     c1: Vec2,
     c2: Vec2,
     radius: f32,
@@ -412,11 +672,13 @@ pub(super) fn cast_mover_impl(
 ) -> f32 {
     let cap = make_capsule(c1, c2, radius);
     let t = translation.into_raw();
-    unsafe { ffi::b2World_CastMover(world, &cap, t, filter.0) }
+    measure(world, QueryKind::Cast, || unsafe {
+        ffi::b2World_CastMover(world.id, &cap, t, filter.0)
+    })
 }
 
 pub(super) fn collide_mover_into_impl(
-    world: ffi::b2WorldId,
+    world: &WorldCore,
     c1: Vec2,
     c2: Vec2,
     radius: f32,
@@ -426,20 +688,20 @@ pub(super) fn collide_mover_into_impl(
     out.clear();
     let cap = make_capsule(c1, c2, radius);
     let mut ctx = CollectCtx::from_cleared(out);
-    unsafe {
+    measure(world, QueryKind::Overlap, || unsafe {
         ffi::b2World_CollideMover(
-            world,
+            world.id,
             &cap,
             filter.0,
             Some(collect_mover_plane_result_cb),
             &mut ctx as *mut _ as *mut _,
-        );
-    }
+        )
+    });
     ctx.resume_unwind_if_needed();
 }
 
 pub(super) fn collide_mover_impl(
-    world: ffi::b2WorldId,
+    world: &WorldCore,
     c1: Vec2,
     c2: Vec2,
     radius: f32,
@@ -451,7 +713,7 @@ pub(super) fn collide_mover_impl(
 }
 
 pub(super) fn overlap_polygon_points_with_offset_into_impl(
-    world: ffi::b2WorldId,
+    world: &WorldCore,
     points: &ProxyPoints,
     radius: f32,
     position: Vec2,
@@ -476,7 +738,7 @@ pub(super) fn overlap_polygon_points_with_offset_into_impl(
 }
 
 pub(super) fn visit_overlap_polygon_points_with_offset_impl<F>(
-    world: ffi::b2WorldId,
+    world: &WorldCore,
     points: &ProxyPoints,
     radius: f32,
     position: Vec2,
@@ -494,7 +756,7 @@ where
 }
 
 pub(super) fn overlap_polygon_points_with_offset_impl(
-    world: ffi::b2WorldId,
+    world: &WorldCore,
     points: &ProxyPoints,
     radius: f32,
     position: Vec2,
@@ -516,7 +778,7 @@ pub(super) fn overlap_polygon_points_with_offset_impl(
 
 #[allow(clippy::too_many_arguments)]
 pub(super) fn cast_shape_points_with_offset_into_impl(
-    world: ffi::b2WorldId,
+    world: &WorldCore,
     points: &ProxyPoints,
     radius: f32,
     position: Vec2,
@@ -531,21 +793,21 @@ pub(super) fn cast_shape_points_with_offset_into_impl(
     };
     let mut ctx = CollectCtx::from_cleared(out);
     let t = translation.into_raw();
-    unsafe {
-        let _ = ffi::b2World_CastShape(
-            world,
+    let _ = measure_tree(world, QueryKind::Cast, || unsafe {
+        ffi::b2World_CastShape(
+            world.id,
             &proxy,
             t,
             filter.0,
             Some(collect_ray_result_cb),
             &mut ctx as *mut _ as *mut _,
-        );
-    }
+        )
+    });
     ctx.resume_unwind_if_needed();
 }
 
 pub(super) fn cast_shape_points_with_offset_impl(
-    world: ffi::b2WorldId,
+    world: &WorldCore,
     points: &ProxyPoints,
     radius: f32,
     position: Vec2,
@@ -0,0 +1,275 @@
+//! Immutable copy of the broad-phase for queries off the simulation thread.
+//!
+//! [`World::query_snapshot`](crate::World::query_snapshot) copies every shape's AABB, filter,
+//! body transform, and geometry into a [`QuerySnapshot`] with its own bounding-volume tree. The
+//! snapshot is `Send + Sync` and never touches the world again, so AI or pathfinding jobs can
+//! run ray and overlap queries against last frame's state on any thread while the world steps.
+
+use super::types::{
+    Aabb, QueryFilter, RayHit, assert_query_aabb_valid, assert_query_vec2_valid,
+    check_query_aabb_valid, check_query_vec2_valid,
+};
+use crate::Transform;
+use crate::dynamic_tree::{DynamicTree, TreeRayCastInput};
+use crate::error::ApiResult;
+use crate::filter::Filter;
+use crate::shapes::{Geometry, ShapeType};
+use crate::types::{BodyId, ShapeId, Vec2};
+
+/// One shape as it was when the snapshot was taken.
+#[derive(Copy, Clone, Debug)]
+pub struct SnapshotShape {
+    pub shape_id: ShapeId,
+    pub body_id: BodyId,
+    pub shape_type: ShapeType,
+    /// World-space bounds, as stored in the broad-phase (not fattened).
+    pub aabb: Aabb,
+    pub filter: Filter,
+    pub is_sensor: bool,
+    pub user_material_id: u64,
+    /// Body transform at capture time.
+    pub transform: Transform,
+    /// Local geometry; chain segments are stored as their one-sided [`Segment`](crate::Segment).
+    pub geometry: Geometry,
+}
+
+impl SnapshotShape {
+    fn accepts(&self, filter: QueryFilter) -> bool {
+        (self.filter.category_bits & filter.mask_bits()) != 0
+            && (self.filter.mask_bits & filter.category_bits()) != 0
+    }
+
+    /// Cast a world-space ray against this shape's geometry.
+    fn ray_cast(&self, origin: Vec2, translation: Vec2) -> Option<(Vec2, Vec2, f32)> {
+        let rot = self.transform.rotation();
+        let local_origin = self.transform.inv_transform_point(origin);
+        let local_translation = rot.inv_rotate_vec(translation);
+        let out = match self.geometry {
+            Geometry::Circle(g) => g.ray_cast(local_origin, local_translation),
+            Geometry::Segment(g) => g.ray_cast(
+                local_origin,
+                local_translation,
+                self.shape_type == ShapeType::ChainSegment,
+            ),
+            Geometry::Capsule(g) => g.ray_cast(local_origin, local_translation),
+            Geometry::Polygon(g) => g.ray_cast(local_origin, local_translation),
+        };
+        out.hit.then(|| {
+            (
+                self.transform.transform_point(out.point),
+                rot.rotate_vec(out.normal),
+                out.fraction,
+            )
+        })
+    }
+}
+
+/// Read-only broad-phase copy returned by [`World::query_snapshot`](crate::World::query_snapshot).
+///
+/// Queries follow the world's rules: a shape matches a [`QueryFilter`] when its category is in
+/// the filter's mask and the filter's category is in its mask. Ray casts skip sensors and hit
+/// chain segments from their solid side only. Shapes created, moved, or destroyed after the
+/// capture are not seen; take a new snapshot each frame.
+///
+/// ```no_run
+/// use boxdd::{QueryFilter, Vec2, World, WorldDef};
+/// let mut world = World::new(WorldDef::default()).unwrap();
+/// let snapshot = world.query_snapshot();
+/// std::thread::scope(|s| {
+///     s.spawn(|| {
+///         let _hit = snapshot.cast_ray_closest(
+///             Vec2::new(0.0, 10.0),
+///             Vec2::new(0.0, -20.0),
+///             QueryFilter::default(),
+///         );
+///     });
+///     world.step(1.0 / 60.0, 4);
+/// });
+/// ```
+pub struct QuerySnapshot {
+    tree: DynamicTree,
+    shapes: Vec<SnapshotShape>,
+}
+
+// SAFETY: the tree is built once in `capture` and only read afterwards. Box2D's tree queries and
+// ray casts keep their traversal stacks on the caller's stack, so concurrent reads are sound, and
+// the tree holds no pointers into any world.
+unsafe impl Send for QuerySnapshot {}
+unsafe impl Sync for QuerySnapshot {}
+
+impl core::fmt::Debug for QuerySnapshot {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("QuerySnapshot")
+            .field("shapes", &self.shapes.len())
+            .finish()
+    }
+}
+
+impl QuerySnapshot {
+    pub(crate) fn capture(shapes: Vec<SnapshotShape>) -> Self {
+        let mut tree = DynamicTree::new();
+        for (index, shape) in shapes.iter().enumerate() {
+            tree.create_proxy(shape.aabb, shape.filter.category_bits, index as u64);
+        }
+        tree.rebuild(true);
+        Self { tree, shapes }
+    }
+
+    /// Number of captured shapes.
+    pub fn len(&self) -> usize {
+        self.shapes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.shapes.is_empty()
+    }
+
+    /// Every captured shape, in capture order.
+    pub fn shapes(&self) -> &[SnapshotShape] {
+        &self.shapes
+    }
+
+    /// Captured state of `shape`, if it was in the world at capture time.
+    pub fn shape(&self, shape: ShapeId) -> Option<&SnapshotShape> {
+        self.shapes.iter().find(|s| s.shape_id == shape)
+    }
+
+    /// Shapes whose bounds overlap `aabb`.
+    pub fn overlap_aabb(&self, aabb: Aabb, filter: QueryFilter) -> Vec<ShapeId> {
+        let mut out = Vec::new();
+        self.overlap_aabb_into(aabb, filter, &mut out);
+        out
+    }
+
+    /// Reusable-buffer version of [`Self::overlap_aabb`].
+    pub fn overlap_aabb_into(&self, aabb: Aabb, filter: QueryFilter, out: &mut Vec<ShapeId>) {
+        assert_query_aabb_valid(aabb);
+        self.overlap_aabb_impl(aabb, filter, out);
+    }
+
+    pub fn try_overlap_aabb(&self, aabb: Aabb, filter: QueryFilter) -> ApiResult<Vec<ShapeId>> {
+        let mut out = Vec::new();
+        self.try_overlap_aabb_into(aabb, filter, &mut out)?;
+        Ok(out)
+    }
+
+    pub fn try_overlap_aabb_into(
+        &self,
+        aabb: Aabb,
+        filter: QueryFilter,
+        out: &mut Vec<ShapeId>,
+    ) -> ApiResult<()> {
+        check_query_aabb_valid(aabb)?;
+        self.overlap_aabb_impl(aabb, filter, out);
+        Ok(())
+    }
+
+    /// Closest non-sensor hit along `origin + t * translation`, `t` in `[0, 1]`.
+    pub fn cast_ray_closest<VO: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        origin: VO,
+        translation: VT,
+        filter: QueryFilter,
+    ) -> Option<RayHit> {
+        let (origin, translation) = (origin.into(), translation.into());
+        assert_query_vec2_valid("origin", origin);
+        assert_query_vec2_valid("translation", translation);
+        self.cast_ray_impl(origin, translation, filter, true)
+            .into_iter()
+            .next()
+    }
+
+    pub fn try_cast_ray_closest<VO: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        origin: VO,
+        translation: VT,
+        filter: QueryFilter,
+    ) -> ApiResult<Option<RayHit>> {
+        let (origin, translation) = (origin.into(), translation.into());
+        check_query_vec2_valid(origin)?;
+        check_query_vec2_valid(translation)?;
+        Ok(self
+            .cast_ray_impl(origin, translation, filter, true)
+            .into_iter()
+            .next())
+    }
+
+    /// Every non-sensor hit along the ray, sorted by fraction.
+    pub fn cast_ray_all<VO: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        origin: VO,
+        translation: VT,
+        filter: QueryFilter,
+    ) -> Vec<RayHit> {
+        let (origin, translation) = (origin.into(), translation.into());
+        assert_query_vec2_valid("origin", origin);
+        assert_query_vec2_valid("translation", translation);
+        self.cast_ray_impl(origin, translation, filter, false)
+    }
+
+    pub fn try_cast_ray_all<VO: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        origin: VO,
+        translation: VT,
+        filter: QueryFilter,
+    ) -> ApiResult<Vec<RayHit>> {
+        let (origin, translation) = (origin.into(), translation.into());
+        check_query_vec2_valid(origin)?;
+        check_query_vec2_valid(translation)?;
+        Ok(self.cast_ray_impl(origin, translation, filter, false))
+    }
+
+    fn overlap_aabb_impl(&self, aabb: Aabb, filter: QueryFilter, out: &mut Vec<ShapeId>) {
+        out.clear();
+        self.tree.query(aabb, filter.mask_bits(), &mut |_, index| {
+            let shape = &self.shapes[index as usize];
+            if shape.accepts(filter) {
+                out.push(shape.shape_id);
+            }
+            true
+        });
+    }
+
+    /// All hits sorted by fraction, or only the closest one when `closest` is set.
+    fn cast_ray_impl(
+        &self,
+        origin: Vec2,
+        translation: Vec2,
+        filter: QueryFilter,
+        closest: bool,
+    ) -> Vec<RayHit> {
+        let mut hits = Vec::new();
+        let input = TreeRayCastInput::new(origin, translation);
+        self.tree
+            .ray_cast(input, filter.mask_bits(), &mut |input, _, index| {
+                let shape = &self.shapes[index as usize];
+                if shape.is_sensor || !shape.accepts(filter) {
+                    return input.max_fraction;
+                }
+                let Some((point, normal, fraction)) = shape.ray_cast(origin, translation) else {
+                    return input.max_fraction;
+                };
+                if fraction > input.max_fraction {
+                    return input.max_fraction;
+                }
+                let hit = RayHit {
+                    shape_id: shape.shape_id,
+                    body_id: shape.body_id,
+                    point,
+                    normal,
+                    fraction,
+                    user_material_id: shape.user_material_id,
+                };
+                if closest {
+                    hits.clear();
+                    hits.push(hit);
+                    fraction
+                } else {
+                    hits.push(hit);
+                    input.max_fraction
+                }
+            });
+        hits.sort_by(|a, b| a.fraction.total_cmp(&b.fraction));
+        hits
+    }
+}
@@ -0,0 +1,254 @@
+//! Memoized results for queries that only see static geometry.
+//!
+//! AI and terrain code often repeats the same probes every frame: line-of-sight rays between fixed
+//! points, ground checks under patrol waypoints, overlap tests around spawn areas. When the filter
+//! only matches categories used by static bodies, the answer cannot change until static geometry
+//! does, so [`StaticQueryCache`] keeps it until then.
+
+use std::collections::HashMap;
+
+use super::types::{Aabb, QueryFilter, RayResult};
+use crate::error::ApiResult;
+use crate::types::{ShapeId, Vec2};
+use crate::world::World;
+
+/// Default [`StaticQueryCache::max_entries`].
+const DEFAULT_MAX_ENTRIES: usize = 1024;
+
+#[derive(Copy, Clone, PartialEq, Eq, Hash)]
+enum QueryKey {
+    RayClosest {
+        origin: [u32; 2],
+        translation: [u32; 2],
+        filter: [u64; 2],
+    },
+    OverlapAabb {
+        lower: [u32; 2],
+        upper: [u32; 2],
+        filter: [u64; 2],
+    },
+}
+
+fn bits(v: Vec2) -> [u32; 2] {
+    [v.x.to_bits(), v.y.to_bits()]
+}
+
+fn filter_bits(filter: QueryFilter) -> [u64; 2] {
+    [filter.category_bits(), filter.mask_bits()]
+}
+
+enum Cached {
+    Ray(RayResult),
+    Shapes(Vec<ShapeId>),
+}
+
+/// Opt-in cache for repeated static-only ray and overlap queries.
+///
+/// Construct it with the collision categories your static bodies use. A query is cached only when
+/// its filter's mask stays inside those categories; any other query runs against the world every
+/// time. Keys are exact: the same origin, translation or box, and filter bits.
+///
+/// The cache empties itself when static geometry changes through the safe API (static shapes
+/// created, destroyed, moved, refiltered or reshaped, chains, static bodies moved, enabled or
+/// disabled, body type changes), when used with a different world, and when it reaches
+/// [`max_entries`](Self::max_entries). Kinematic and dynamic bodies are not tracked: keep their
+/// categories out of [`static_categories`](Self::static_categories). Call
+/// [`clear`](Self::clear) after editing static geometry through raw FFI.
+///
+/// ```no_run
+/// use boxdd::{QueryFilter, StaticQueryCache, World, WorldDef};
+/// const TERRAIN: u64 = 0x1;
+/// let mut world = World::new(WorldDef::default()).unwrap();
+/// let mut cache = StaticQueryCache::new(TERRAIN);
+/// let filter = QueryFilter::default().mask(TERRAIN);
+/// for _frame in 0..60 {
+///     let ground = cache.cast_ray_closest(&world, [4.0, 10.0], [0.0, -20.0], filter);
+///     if ground.hit { /* snap the waypoint to ground.point */ }
+///     world.step(1.0 / 60.0, 4);
+/// }
+/// ```
+pub struct StaticQueryCache {
+    static_categories: u64,
+    max_entries: usize,
+    world: Option<(u16, u16)>,
+    revision: u64,
+    entries: HashMap<QueryKey, Cached>,
+    hits: u64,
+    misses: u64,
+}
+
+impl core::fmt::Debug for StaticQueryCache {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("StaticQueryCache")
+            .field("static_categories", &self.static_categories)
+            .field("entries", &self.entries.len())
+            .field("hits", &self.hits)
+            .field("misses", &self.misses)
+            .finish()
+    }
+}
+
+impl StaticQueryCache {
+    /// Cache queries whose mask only covers `static_categories`.
+    pub fn new(static_categories: u64) -> Self {
+        Self {
+            static_categories,
+            max_entries: DEFAULT_MAX_ENTRIES,
+            world: None,
+            revision: 0,
+            entries: HashMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+
+    /// Entry limit; reaching it empties the cache. Defaults to 1024.
+    pub fn max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = max_entries.max(1);
+        self
+    }
+
+    pub fn static_categories(&self) -> u64 {
+        self.static_categories
+    }
+
+    /// Number of cached results.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Queries answered from the cache so far.
+    pub fn hits(&self) -> u64 {
+        self.hits
+    }
+
+    /// Cacheable queries that had to run against the world.
+    pub fn misses(&self) -> u64 {
+        self.misses
+    }
+
+    /// Drop every cached result.
+    pub fn clear(&mut self) {
+        self.entries.clear();
+    }
+
+    /// Cached [`World::cast_ray_closest`].
+    pub fn cast_ray_closest<VO: Into<Vec2>, VT: Into<Vec2>>(
+        &mut self,
+        world: &World,
+        origin: VO,
+        translation: VT,
+        filter: QueryFilter,
+    ) -> RayResult {
+        let (origin, translation) = (origin.into(), translation.into());
+        let key = QueryKey::RayClosest {
+            origin: bits(origin),
+            translation: bits(translation),
+            filter: filter_bits(filter),
+        };
+        if let Some(Cached::Ray(hit)) = self.lookup(world, filter, key) {
+            return *hit;
+        }
+        let hit = world.cast_ray_closest(origin, translation, filter);
+        self.store(filter, key, Cached::Ray(hit));
+        hit
+    }
+
+    pub fn try_cast_ray_closest<VO: Into<Vec2>, VT: Into<Vec2>>(
+        &mut self,
+        world: &World,
+        origin: VO,
+        translation: VT,
+        filter: QueryFilter,
+    ) -> ApiResult<RayResult> {
+        let (origin, translation) = (origin.into(), translation.into());
+        let key = QueryKey::RayClosest {
+            origin: bits(origin),
+            translation: bits(translation),
+            filter: filter_bits(filter),
+        };
+        if let Some(Cached::Ray(hit)) = self.lookup(world, filter, key) {
+            return Ok(*hit);
+        }
+        let hit = world.try_cast_ray_closest(origin, translation, filter)?;
+        self.store(filter, key, Cached::Ray(hit));
+        Ok(hit)
+    }
+
+    /// Cached [`World::overlap_aabb`].
+    pub fn overlap_aabb(&mut self, world: &World, aabb: Aabb, filter: QueryFilter) -> Vec<ShapeId> {
+        let key = QueryKey::OverlapAabb {
+            lower: bits(aabb.lower),
+            upper: bits(aabb.upper),
+            filter: filter_bits(filter),
+        };
+        if let Some(Cached::Shapes(shapes)) = self.lookup(world, filter, key) {
+            return shapes.clone();
+        }
+        let shapes = world.overlap_aabb(aabb, filter);
+        self.store(filter, key, Cached::Shapes(shapes.clone()));
+        shapes
+    }
+
+    pub fn try_overlap_aabb(
+        &mut self,
+        world: &World,
+        aabb: Aabb,
+        filter: QueryFilter,
+    ) -> ApiResult<Vec<ShapeId>> {
+        let key = QueryKey::OverlapAabb {
+            lower: bits(aabb.lower),
+            upper: bits(aabb.upper),
+            filter: filter_bits(filter),
+        };
+        if let Some(Cached::Shapes(shapes)) = self.lookup(world, filter, key) {
+            return Ok(shapes.clone());
+        }
+        let shapes = world.try_overlap_aabb(aabb, filter)?;
+        self.store(filter, key, Cached::Shapes(shapes.clone()));
+        Ok(shapes)
+    }
+
+    fn is_cacheable(&self, filter: QueryFilter) -> bool {
+        filter.mask_bits() & !self.static_categories == 0
+    }
+
+    /// Flush stale results, then look `key` up if the query is cacheable.
+    fn lookup(&mut self, world: &World, filter: QueryFilter, key: QueryKey) -> Option<&Cached> {
+        let raw = world.world_id_raw();
+        let world_key = (raw.index1, raw.generation);
+        let revision = world.core().static_revision();
+        if self.world != Some(world_key) || self.revision != revision {
+            self.entries.clear();
+            self.world = Some(world_key);
+            self.revision = revision;
+        }
+        if !self.is_cacheable(filter) {
+            return None;
+        }
+        match self.entries.get(&key) {
+            Some(cached) => {
+                self.hits += 1;
+                Some(cached)
+            }
+            None => {
+                self.misses += 1;
+                None
+            }
+        }
+    }
+
+    fn store(&mut self, filter: QueryFilter, key: QueryKey, value: Cached) {
+        if !self.is_cacheable(filter) {
+            return;
+        }
+        if self.entries.len() >= self.max_entries {
+            self.entries.clear();
+        }
+        self.entries.insert(key, value);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::error::ApiResult;
-use crate::types::{ShapeId, Vec2};
+use crate::shapes::SurfaceMaterial;
+use crate::types::{BodyId, ShapeId, Vec2};
 use boxdd_sys::ffi;
 
 pub(super) fn minimum_mover_radius() -> f32 {
@@ -139,6 +140,24 @@ impl Aabb {
             upper: Vec2::new(c.x + h.x, c.y + h.y),
         }
     }
+
+    /// Whether this AABB and `other` overlap (touching counts).
+    #[inline]
+    pub fn overlaps(self, other: Aabb) -> bool {
+        self.lower.x <= other.upper.x
+            && other.lower.x <= self.upper.x
+            && self.lower.y <= other.upper.y
+            && other.lower.y <= self.upper.y
+    }
+
+    /// Grow this AABB by `margin` on every side.
+    #[inline]
+    pub fn expanded(self, margin: f32) -> Self {
+        Self {
+            lower: Vec2::new(self.lower.x - margin, self.lower.y - margin),
+            upper: Vec2::new(self.upper.x + margin, self.upper.y + margin),
+        }
+    }
 }
 
 #[cfg(feature = "mint")]
@@ -324,6 +343,15 @@ impl QueryFilter {
     }
 }
 
+/// Shape under a point, as returned by `World::pick`.
+#[derive(Copy, Clone, Debug)]
+pub struct PickResult {
+    pub body: BodyId,
+    pub shape: ShapeId,
+    /// The picked point in world space.
+    pub world_point: Vec2,
+}
+
 /// Result of a closest ray cast
 #[doc(alias = "ray_result")]
 #[derive(Copy, Clone, Debug)]
@@ -335,6 +363,81 @@ pub struct RayResult {
     pub hit: bool,
 }
 
+/// One hit of [`World::cast_ray_hits`](crate::World::cast_ray_hits) or a shape cast such as
+/// [`World::cast_shape_all`](crate::World::cast_shape_all), with the owning body and material id
+/// resolved at cast time.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct RayHit {
+    pub shape_id: ShapeId,
+    pub body_id: BodyId,
+    pub point: Vec2,
+    pub normal: Vec2,
+    /// Position of the hit along the ray, in `[0, 1]` of the translation.
+    pub fraction: f32,
+    /// The hit shape's [`SurfaceMaterial::user_material_id`].
+    pub user_material_id: u64,
+}
+
+impl RayHit {
+    /// Distance from `origin` to the hit point.
+    ///
+    /// Pass the origin used for the cast; for shape casts this is the proxy origin.
+    #[inline]
+    pub fn distance<V: Into<Vec2>>(&self, origin: V) -> f32 {
+        self.point.minus(origin.into()).length()
+    }
+
+    /// Body owning the hit shape, as [`Self::body_id`].
+    ///
+    /// Panics when the body has since been destroyed.
+    #[inline]
+    pub fn body(&self) -> BodyId {
+        crate::core::debug_checks::assert_body_valid(self.body_id);
+        self.body_id
+    }
+
+    /// Recoverable version of [`Self::body`].
+    #[inline]
+    pub fn try_body(&self) -> ApiResult<BodyId> {
+        crate::core::debug_checks::check_body_valid(self.body_id)?;
+        Ok(self.body_id)
+    }
+
+    /// Whether the hit shape is a sensor.
+    ///
+    /// Panics when the shape has since been destroyed.
+    #[inline]
+    pub fn is_sensor(&self) -> bool {
+        crate::core::debug_checks::assert_shape_valid(self.shape_id);
+        crate::shapes::shape_is_sensor_impl(self.shape_id)
+    }
+
+    /// Recoverable version of [`Self::is_sensor`].
+    #[inline]
+    pub fn try_is_sensor(&self) -> ApiResult<bool> {
+        crate::core::debug_checks::check_shape_valid(self.shape_id)?;
+        Ok(crate::shapes::shape_is_sensor_impl(self.shape_id))
+    }
+
+    /// Surface material of the hit shape. Its
+    /// [`user_material_id`](SurfaceMaterial::user_material_id) is also kept in
+    /// [`Self::user_material_id`], which stays readable after the shape is destroyed.
+    ///
+    /// Panics when the shape has since been destroyed.
+    #[inline]
+    pub fn material(&self) -> SurfaceMaterial {
+        crate::core::debug_checks::assert_shape_valid(self.shape_id);
+        crate::shapes::shape_surface_material_impl(self.shape_id)
+    }
+
+    /// Recoverable version of [`Self::material`].
+    #[inline]
+    pub fn try_material(&self) -> ApiResult<SurfaceMaterial> {
+        crate::core::debug_checks::check_shape_valid(self.shape_id)?;
+        Ok(crate::shapes::shape_surface_material_impl(self.shape_id))
+    }
+}
+
 impl RayResult {
     #[inline]
     pub fn from_raw(raw: ffi::b2RayResult) -> Self {
@@ -346,6 +449,62 @@ impl RayResult {
             hit: raw.hit,
         }
     }
+
+    /// Distance from `origin` to the hit point.
+    ///
+    /// Pass the origin used for the cast; for shape casts this is the proxy origin.
+    #[inline]
+    pub fn distance<V: Into<Vec2>>(&self, origin: V) -> f32 {
+        self.point.minus(origin.into()).length()
+    }
+
+    /// Body owning the hit shape.
+    ///
+    /// Panics when there was no hit or the shape has since been destroyed.
+    #[inline]
+    pub fn body(&self) -> BodyId {
+        crate::core::debug_checks::assert_shape_valid(self.shape_id);
+        crate::shapes::shape_body_id_impl(self.shape_id)
+    }
+
+    /// Recoverable version of [`Self::body`].
+    #[inline]
+    pub fn try_body(&self) -> ApiResult<BodyId> {
+        crate::core::debug_checks::check_shape_valid(self.shape_id)?;
+        Ok(crate::shapes::shape_body_id_impl(self.shape_id))
+    }
+
+    /// Whether the hit shape is a sensor.
+    ///
+    /// Panics when there was no hit or the shape has since been destroyed.
+    #[inline]
+    pub fn is_sensor(&self) -> bool {
+        crate::core::debug_checks::assert_shape_valid(self.shape_id);
+        crate::shapes::shape_is_sensor_impl(self.shape_id)
+    }
+
+    /// Recoverable version of [`Self::is_sensor`].
+    #[inline]
+    pub fn try_is_sensor(&self) -> ApiResult<bool> {
+        crate::core::debug_checks::check_shape_valid(self.shape_id)?;
+        Ok(crate::shapes::shape_is_sensor_impl(self.shape_id))
+    }
+
+    /// Surface material of the hit shape.
+    ///
+    /// Panics when there was no hit or the shape has since been destroyed.
+    #[inline]
+    pub fn material(&self) -> SurfaceMaterial {
+        crate::core::debug_checks::assert_shape_valid(self.shape_id);
+        crate::shapes::shape_surface_material_impl(self.shape_id)
+    }
+
+    /// Recoverable version of [`Self::material`].
+    #[inline]
+    pub fn try_material(&self) -> ApiResult<SurfaceMaterial> {
+        crate::core::debug_checks::check_shape_valid(self.shape_id)?;
+        Ok(crate::shapes::shape_surface_material_impl(self.shape_id))
+    }
 }
 
 /// A collision plane used by Box2D's character mover helpers.
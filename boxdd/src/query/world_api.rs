@@ -1,5 +1,9 @@
+use crate::Transform;
+use crate::camera::Camera2D;
+use crate::collision::ShapeProxy;
 use crate::error::ApiResult;
-use crate::types::{ShapeId, Vec2};
+use crate::shapes::{Geometry, Polygon};
+use crate::types::{BodyId, ShapeId, Vec2};
 use crate::world::{World, WorldHandle};
 
 use super::checked::*;
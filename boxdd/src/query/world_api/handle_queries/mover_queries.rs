@@ -9,7 +9,7 @@ impl WorldHandle {
         translation: VT,
         filter: QueryFilter,
     ) -> f32 {
-        cast_mover_checked_impl(self.raw(), c1, c2, radius, translation, filter)
+        cast_mover_checked_impl(self.core(), c1, c2, radius, translation, filter)
     }
 
     pub fn try_cast_mover<V1: Into<Vec2>, V2: Into<Vec2>, VT: Into<Vec2>>(
@@ -20,7 +20,7 @@ impl WorldHandle {
         translation: VT,
         filter: QueryFilter,
     ) -> ApiResult<f32> {
-        try_cast_mover_impl(self.raw(), c1, c2, radius, translation, filter)
+        try_cast_mover_impl(self.core(), c1, c2, radius, translation, filter)
     }
 
     pub fn collide_mover<V1: Into<Vec2>, V2: Into<Vec2>>(
@@ -30,7 +30,7 @@ impl WorldHandle {
         radius: f32,
         filter: QueryFilter,
     ) -> Vec<MoverPlaneResult> {
-        collide_mover_checked_impl(self.raw(), c1, c2, radius, filter)
+        collide_mover_checked_impl(self.core(), c1, c2, radius, filter)
     }
 
     pub fn collide_mover_into<V1: Into<Vec2>, V2: Into<Vec2>>(
@@ -41,7 +41,7 @@ impl WorldHandle {
         filter: QueryFilter,
         out: &mut Vec<MoverPlaneResult>,
     ) {
-        collide_mover_into_checked_impl(self.raw(), c1, c2, radius, filter, out);
+        collide_mover_into_checked_impl(self.core(), c1, c2, radius, filter, out);
     }
 
     pub fn try_collide_mover<V1: Into<Vec2>, V2: Into<Vec2>>(
@@ -51,7 +51,7 @@ impl WorldHandle {
         radius: f32,
         filter: QueryFilter,
     ) -> ApiResult<Vec<MoverPlaneResult>> {
-        try_collide_mover_impl(self.raw(), c1, c2, radius, filter)
+        try_collide_mover_impl(self.core(), c1, c2, radius, filter)
     }
 
     pub fn try_collide_mover_into<V1: Into<Vec2>, V2: Into<Vec2>>(
@@ -62,6 +62,6 @@ impl WorldHandle {
         filter: QueryFilter,
         out: &mut Vec<MoverPlaneResult>,
     ) -> ApiResult<()> {
-        try_collide_mover_into_impl(self.raw(), c1, c2, radius, filter, out)
+        try_collide_mover_into_impl(self.core(), c1, c2, radius, filter, out)
     }
 }
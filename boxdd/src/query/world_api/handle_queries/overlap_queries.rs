@@ -2,22 +2,22 @@ use super::*;
 
 impl WorldHandle {
     pub fn overlap_aabb(&self, aabb: Aabb, filter: QueryFilter) -> Vec<ShapeId> {
-        overlap_aabb_checked_impl(self.raw(), aabb, filter)
+        overlap_aabb_checked_impl(self.core(), aabb, filter)
     }
 
     pub fn overlap_aabb_into(&self, aabb: Aabb, filter: QueryFilter, out: &mut Vec<ShapeId>) {
-        overlap_aabb_into_checked_impl(self.raw(), aabb, filter, out);
+        overlap_aabb_into_checked_impl(self.core(), aabb, filter, out);
     }
 
     pub fn visit_overlap_aabb<F>(&self, aabb: Aabb, filter: QueryFilter, mut visit: F) -> bool
     where
         F: FnMut(ShapeId) -> bool,
     {
-        visit_overlap_aabb_checked_impl(self.raw(), aabb, filter, &mut visit)
+        visit_overlap_aabb_checked_impl(self.core(), aabb, filter, &mut visit)
     }
 
     pub fn try_overlap_aabb(&self, aabb: Aabb, filter: QueryFilter) -> ApiResult<Vec<ShapeId>> {
-        try_overlap_aabb_impl(self.raw(), aabb, filter)
+        try_overlap_aabb_impl(self.core(), aabb, filter)
     }
 
     pub fn try_overlap_aabb_into(
@@ -26,7 +26,7 @@ impl WorldHandle {
         filter: QueryFilter,
         out: &mut Vec<ShapeId>,
     ) -> ApiResult<()> {
-        try_overlap_aabb_into_impl(self.raw(), aabb, filter, out)
+        try_overlap_aabb_into_impl(self.core(), aabb, filter, out)
     }
 
     pub fn try_visit_overlap_aabb<F>(
@@ -38,7 +38,7 @@ impl WorldHandle {
     where
         F: FnMut(ShapeId) -> bool,
     {
-        try_visit_overlap_aabb_impl(self.raw(), aabb, filter, &mut visit)
+        try_visit_overlap_aabb_impl(self.core(), aabb, filter, &mut visit)
     }
 
     pub fn overlap_polygon_points<I, P>(
@@ -51,7 +51,7 @@ impl WorldHandle {
         I: IntoIterator<Item = P>,
         P: Into<Vec2>,
     {
-        overlap_polygon_points_checked_impl(self.raw(), points, radius, filter)
+        overlap_polygon_points_checked_impl(self.core(), points, radius, filter)
     }
 
     pub fn overlap_polygon_points_into<I, P>(
@@ -64,7 +64,7 @@ impl WorldHandle {
         I: IntoIterator<Item = P>,
         P: Into<Vec2>,
     {
-        overlap_polygon_points_into_checked_impl(self.raw(), points, radius, filter, out);
+        overlap_polygon_points_into_checked_impl(self.core(), points, radius, filter, out);
     }
 
     pub fn visit_overlap_polygon_points<I, P, F>(
@@ -79,7 +79,7 @@ impl WorldHandle {
         P: Into<Vec2>,
         F: FnMut(ShapeId) -> bool,
     {
-        visit_overlap_polygon_points_checked_impl(self.raw(), points, radius, filter, &mut visit)
+        visit_overlap_polygon_points_checked_impl(self.core(), points, radius, filter, &mut visit)
     }
 
     pub fn try_overlap_polygon_points<I, P>(
@@ -92,7 +92,7 @@ impl WorldHandle {
         I: IntoIterator<Item = P>,
         P: Into<Vec2>,
     {
-        try_overlap_polygon_points_impl(self.raw(), points, radius, filter)
+        try_overlap_polygon_points_impl(self.core(), points, radius, filter)
     }
 
     pub fn try_overlap_polygon_points_into<I, P>(
@@ -106,7 +106,7 @@ impl WorldHandle {
         I: IntoIterator<Item = P>,
         P: Into<Vec2>,
     {
-        try_overlap_polygon_points_into_impl(self.raw(), points, radius, filter, out)
+        try_overlap_polygon_points_into_impl(self.core(), points, radius, filter, out)
     }
 
     pub fn try_visit_overlap_polygon_points<I, P, F>(
@@ -121,7 +121,7 @@ impl WorldHandle {
         P: Into<Vec2>,
         F: FnMut(ShapeId) -> bool,
     {
-        try_visit_overlap_polygon_points_impl(self.raw(), points, radius, filter, &mut visit)
+        try_visit_overlap_polygon_points_impl(self.core(), points, radius, filter, &mut visit)
     }
 
     pub fn overlap_polygon_points_with_offset<I, P, V, A>(
@@ -139,7 +139,7 @@ impl WorldHandle {
         A: Into<f32>,
     {
         overlap_polygon_points_with_offset_checked_impl(
-            self.raw(),
+            self.core(),
             points,
             radius,
             position,
@@ -163,7 +163,7 @@ impl WorldHandle {
         A: Into<f32>,
     {
         overlap_polygon_points_with_offset_into_checked_impl(
-            self.raw(),
+            self.core(),
             points,
             radius,
             position,
@@ -190,7 +190,7 @@ impl WorldHandle {
         F: FnMut(ShapeId) -> bool,
     {
         visit_overlap_polygon_points_with_offset_checked_impl(
-            self.raw(),
+            self.core(),
             points,
             radius,
             position,
@@ -215,7 +215,7 @@ impl WorldHandle {
         A: Into<f32>,
     {
         try_overlap_polygon_points_with_offset_impl(
-            self.raw(),
+            self.core(),
             points,
             radius,
             position,
@@ -240,7 +240,7 @@ impl WorldHandle {
         A: Into<f32>,
     {
         try_overlap_polygon_points_with_offset_into_impl(
-            self.raw(),
+            self.core(),
             points,
             radius,
             position,
@@ -267,7 +267,7 @@ impl WorldHandle {
         F: FnMut(ShapeId) -> bool,
     {
         try_visit_overlap_polygon_points_with_offset_impl(
-            self.raw(),
+            self.core(),
             points,
             radius,
             position,
@@ -276,4 +276,236 @@ impl WorldHandle {
             &mut visit,
         )
     }
+
+    pub fn pick<V: Into<Vec2>>(
+        &self,
+        camera: &Camera2D,
+        screen_pos: V,
+        filter: QueryFilter,
+    ) -> Option<PickResult> {
+        pick_checked_impl(self.core(), camera, screen_pos.into(), filter)
+    }
+
+    pub fn try_pick<V: Into<Vec2>>(
+        &self,
+        camera: &Camera2D,
+        screen_pos: V,
+        filter: QueryFilter,
+    ) -> ApiResult<Option<PickResult>> {
+        try_pick_impl(self.core(), camera, screen_pos.into(), filter)
+    }
+
+    pub fn overlap_polygon(
+        &self,
+        polygon: &Polygon,
+        transform: Transform,
+        filter: QueryFilter,
+    ) -> Vec<ShapeId> {
+        overlap_polygon_checked_impl(self.core(), polygon, transform, filter)
+    }
+
+    pub fn try_overlap_polygon(
+        &self,
+        polygon: &Polygon,
+        transform: Transform,
+        filter: QueryFilter,
+    ) -> ApiResult<Vec<ShapeId>> {
+        try_overlap_polygon_impl(self.core(), polygon, transform, filter)
+    }
+
+    pub fn overlap_circle<V: Into<Vec2>>(
+        &self,
+        center: V,
+        radius: f32,
+        filter: QueryFilter,
+    ) -> Vec<ShapeId> {
+        overlap_round_checked_impl(self.core(), &[center.into()], radius, filter)
+    }
+
+    pub fn try_overlap_circle<V: Into<Vec2>>(
+        &self,
+        center: V,
+        radius: f32,
+        filter: QueryFilter,
+    ) -> ApiResult<Vec<ShapeId>> {
+        try_overlap_round_impl(self.core(), &[center.into()], radius, filter)
+    }
+
+    pub fn overlap_capsule<V1: Into<Vec2>, V2: Into<Vec2>>(
+        &self,
+        center1: V1,
+        center2: V2,
+        radius: f32,
+        filter: QueryFilter,
+    ) -> Vec<ShapeId> {
+        overlap_round_checked_impl(
+            self.core(),
+            &[center1.into(), center2.into()],
+            radius,
+            filter,
+        )
+    }
+
+    pub fn try_overlap_capsule<V1: Into<Vec2>, V2: Into<Vec2>>(
+        &self,
+        center1: V1,
+        center2: V2,
+        radius: f32,
+        filter: QueryFilter,
+    ) -> ApiResult<Vec<ShapeId>> {
+        try_overlap_round_impl(
+            self.core(),
+            &[center1.into(), center2.into()],
+            radius,
+            filter,
+        )
+    }
+
+    pub fn overlap_geometry<G: Into<Geometry>>(
+        &self,
+        geometry: G,
+        transform: Transform,
+        filter: QueryFilter,
+    ) -> Vec<ShapeId> {
+        overlap_geometry_checked_impl(self.core(), &geometry.into(), transform, filter)
+    }
+
+    pub fn try_overlap_geometry<G: Into<Geometry>>(
+        &self,
+        geometry: G,
+        transform: Transform,
+        filter: QueryFilter,
+    ) -> ApiResult<Vec<ShapeId>> {
+        try_overlap_geometry_impl(self.core(), &geometry.into(), transform, filter)
+    }
+
+    pub fn visit_overlap_circle<V: Into<Vec2>, F>(
+        &self,
+        center: V,
+        radius: f32,
+        filter: QueryFilter,
+        mut visit: F,
+    ) -> bool
+    where
+        F: FnMut(ShapeId) -> bool,
+    {
+        visit_overlap_round_checked_impl(self.core(), &[center.into()], radius, filter, &mut visit)
+    }
+
+    pub fn try_visit_overlap_circle<V: Into<Vec2>, F>(
+        &self,
+        center: V,
+        radius: f32,
+        filter: QueryFilter,
+        mut visit: F,
+    ) -> ApiResult<bool>
+    where
+        F: FnMut(ShapeId) -> bool,
+    {
+        try_visit_overlap_round_impl(self.core(), &[center.into()], radius, filter, &mut visit)
+    }
+
+    pub fn visit_overlap_capsule<V1: Into<Vec2>, V2: Into<Vec2>, F>(
+        &self,
+        center1: V1,
+        center2: V2,
+        radius: f32,
+        filter: QueryFilter,
+        mut visit: F,
+    ) -> bool
+    where
+        F: FnMut(ShapeId) -> bool,
+    {
+        visit_overlap_round_checked_impl(
+            self.core(),
+            &[center1.into(), center2.into()],
+            radius,
+            filter,
+            &mut visit,
+        )
+    }
+
+    pub fn try_visit_overlap_capsule<V1: Into<Vec2>, V2: Into<Vec2>, F>(
+        &self,
+        center1: V1,
+        center2: V2,
+        radius: f32,
+        filter: QueryFilter,
+        mut visit: F,
+    ) -> ApiResult<bool>
+    where
+        F: FnMut(ShapeId) -> bool,
+    {
+        try_visit_overlap_round_impl(
+            self.core(),
+            &[center1.into(), center2.into()],
+            radius,
+            filter,
+            &mut visit,
+        )
+    }
+
+    pub fn visit_overlap_polygon<F>(
+        &self,
+        polygon: &Polygon,
+        transform: Transform,
+        filter: QueryFilter,
+        visit: F,
+    ) -> bool
+    where
+        F: FnMut(ShapeId) -> bool,
+    {
+        self.visit_overlap_geometry(*polygon, transform, filter, visit)
+    }
+
+    pub fn try_visit_overlap_polygon<F>(
+        &self,
+        polygon: &Polygon,
+        transform: Transform,
+        filter: QueryFilter,
+        visit: F,
+    ) -> ApiResult<bool>
+    where
+        F: FnMut(ShapeId) -> bool,
+    {
+        self.try_visit_overlap_geometry(*polygon, transform, filter, visit)
+    }
+
+    pub fn visit_overlap_geometry<G: Into<Geometry>, F>(
+        &self,
+        geometry: G,
+        transform: Transform,
+        filter: QueryFilter,
+        mut visit: F,
+    ) -> bool
+    where
+        F: FnMut(ShapeId) -> bool,
+    {
+        visit_overlap_geometry_checked_impl(
+            self.core(),
+            &geometry.into(),
+            transform,
+            filter,
+            &mut visit,
+        )
+    }
+
+    pub fn try_visit_overlap_geometry<G: Into<Geometry>, F>(
+        &self,
+        geometry: G,
+        transform: Transform,
+        filter: QueryFilter,
+        mut visit: F,
+    ) -> ApiResult<bool>
+    where
+        F: FnMut(ShapeId) -> bool,
+    {
+        try_visit_overlap_geometry_impl(
+            self.core(),
+            &geometry.into(),
+            transform,
+            filter,
+            &mut visit,
+        )
+    }
 }
@@ -7,7 +7,7 @@ impl WorldHandle {
         translation: VT,
         filter: QueryFilter,
     ) -> RayResult {
-        cast_ray_closest_checked_impl(self.raw(), origin, translation, filter)
+        cast_ray_closest_checked_impl(self.core(), origin, translation, filter)
     }
 
     pub fn try_cast_ray_closest<VO: Into<Vec2>, VT: Into<Vec2>>(
@@ -16,7 +16,7 @@ impl WorldHandle {
         translation: VT,
         filter: QueryFilter,
     ) -> ApiResult<RayResult> {
-        try_cast_ray_closest_impl(self.raw(), origin, translation, filter)
+        try_cast_ray_closest_impl(self.core(), origin, translation, filter)
     }
 
     pub fn cast_ray_all<VO: Into<Vec2>, VT: Into<Vec2>>(
@@ -25,7 +25,7 @@ impl WorldHandle {
         translation: VT,
         filter: QueryFilter,
     ) -> Vec<RayResult> {
-        cast_ray_all_checked_impl(self.raw(), origin, translation, filter)
+        cast_ray_all_checked_impl(self.core(), origin, translation, filter)
     }
 
     pub fn cast_ray_all_into<VO: Into<Vec2>, VT: Into<Vec2>>(
@@ -35,7 +35,7 @@ impl WorldHandle {
         filter: QueryFilter,
         out: &mut Vec<RayResult>,
     ) {
-        cast_ray_all_into_checked_impl(self.raw(), origin, translation, filter, out);
+        cast_ray_all_into_checked_impl(self.core(), origin, translation, filter, out);
     }
 
     pub fn try_cast_ray_all<VO: Into<Vec2>, VT: Into<Vec2>>(
@@ -44,7 +44,7 @@ impl WorldHandle {
         translation: VT,
         filter: QueryFilter,
     ) -> ApiResult<Vec<RayResult>> {
-        try_cast_ray_all_impl(self.raw(), origin, translation, filter)
+        try_cast_ray_all_impl(self.core(), origin, translation, filter)
     }
 
     pub fn try_cast_ray_all_into<VO: Into<Vec2>, VT: Into<Vec2>>(
@@ -54,6 +54,133 @@ impl WorldHandle {
         filter: QueryFilter,
         out: &mut Vec<RayResult>,
     ) -> ApiResult<()> {
-        try_cast_ray_all_into_impl(self.raw(), origin, translation, filter, out)
+        try_cast_ray_all_into_impl(self.core(), origin, translation, filter, out)
+    }
+
+    pub fn cast_ray_hits<VO: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        origin: VO,
+        translation: VT,
+        filter: QueryFilter,
+        max_hits: usize,
+    ) -> Vec<RayHit> {
+        let mut out = Vec::new();
+        self.cast_ray_hits_into(origin, translation, filter, max_hits, &mut out);
+        out
+    }
+
+    pub fn cast_ray_hits_into<VO: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        origin: VO,
+        translation: VT,
+        filter: QueryFilter,
+        max_hits: usize,
+        out: &mut Vec<RayHit>,
+    ) {
+        cast_ray_hits_into_checked_impl(self.core(), origin, translation, filter, max_hits, out);
+    }
+
+    pub fn try_cast_ray_hits<VO: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        origin: VO,
+        translation: VT,
+        filter: QueryFilter,
+        max_hits: usize,
+    ) -> ApiResult<Vec<RayHit>> {
+        let mut out = Vec::new();
+        self.try_cast_ray_hits_into(origin, translation, filter, max_hits, &mut out)?;
+        Ok(out)
+    }
+
+    pub fn try_cast_ray_hits_into<VO: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        origin: VO,
+        translation: VT,
+        filter: QueryFilter,
+        max_hits: usize,
+        out: &mut Vec<RayHit>,
+    ) -> ApiResult<()> {
+        try_cast_ray_hits_into_impl(self.core(), origin, translation, filter, max_hits, out)
+    }
+
+    pub fn cast_ray_with<VO: Into<Vec2>, VT: Into<Vec2>, F>(
+        &self,
+        origin: VO,
+        translation: VT,
+        filter: QueryFilter,
+        mut visit: F,
+    ) where
+        F: FnMut(RayHit) -> f32,
+    {
+        cast_ray_with_checked_impl(self.core(), origin, translation, filter, &mut visit);
+    }
+
+    pub fn try_cast_ray_with<VO: Into<Vec2>, VT: Into<Vec2>, F>(
+        &self,
+        origin: VO,
+        translation: VT,
+        filter: QueryFilter,
+        mut visit: F,
+    ) -> ApiResult<()>
+    where
+        F: FnMut(RayHit) -> f32,
+    {
+        try_cast_ray_with_impl(self.core(), origin, translation, filter, &mut visit)
+    }
+
+    pub fn occlusion<VF: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        from: VF,
+        to: VT,
+        filter: QueryFilter,
+    ) -> f32 {
+        occlusion_checked_impl(self.core(), from, to, filter, |_| 0.5)
+    }
+
+    pub fn try_occlusion<VF: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        from: VF,
+        to: VT,
+        filter: QueryFilter,
+    ) -> ApiResult<f32> {
+        try_occlusion_impl(self.core(), from, to, filter, |_| 0.5)
+    }
+
+    pub fn occlusion_with<VF: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        from: VF,
+        to: VT,
+        filter: QueryFilter,
+        transmission: impl FnMut(&RayResult) -> f32,
+    ) -> f32 {
+        occlusion_checked_impl(self.core(), from, to, filter, transmission)
+    }
+
+    pub fn try_occlusion_with<VF: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        from: VF,
+        to: VT,
+        filter: QueryFilter,
+        transmission: impl FnMut(&RayResult) -> f32,
+    ) -> ApiResult<f32> {
+        try_occlusion_impl(self.core(), from, to, filter, transmission)
+    }
+
+    pub fn cast_ray_against_body<VO: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        body: BodyId,
+        origin: VO,
+        translation: VT,
+    ) -> RayResult {
+        cast_ray_against_body_checked_impl(body, origin, translation)
+    }
+
+    pub fn try_cast_ray_against_body<VO: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        body: BodyId,
+        origin: VO,
+        translation: VT,
+    ) -> ApiResult<RayResult> {
+        try_cast_ray_against_body_impl(body, origin, translation)
     }
 }
@@ -13,7 +13,7 @@ impl WorldHandle {
         P: Into<Vec2>,
         VT: Into<Vec2>,
     {
-        cast_shape_points_checked_impl(self.raw(), points, radius, translation, filter)
+        cast_shape_points_checked_impl(self.core(), points, radius, translation, filter)
     }
 
     pub fn cast_shape_points_into<I, P, VT>(
@@ -28,7 +28,7 @@ impl WorldHandle {
         P: Into<Vec2>,
         VT: Into<Vec2>,
     {
-        cast_shape_points_into_checked_impl(self.raw(), points, radius, translation, filter, out);
+        cast_shape_points_into_checked_impl(self.core(), points, radius, translation, filter, out);
     }
 
     pub fn try_cast_shape_points<I, P, VT>(
@@ -43,7 +43,7 @@ impl WorldHandle {
         P: Into<Vec2>,
         VT: Into<Vec2>,
     {
-        try_cast_shape_points_impl(self.raw(), points, radius, translation, filter)
+        try_cast_shape_points_impl(self.core(), points, radius, translation, filter)
     }
 
     pub fn try_cast_shape_points_into<I, P, VT>(
@@ -59,7 +59,7 @@ impl WorldHandle {
         P: Into<Vec2>,
         VT: Into<Vec2>,
     {
-        try_cast_shape_points_into_impl(self.raw(), points, radius, translation, filter, out)
+        try_cast_shape_points_into_impl(self.core(), points, radius, translation, filter, out)
     }
 
     pub fn cast_shape_points_with_offset<I, P, V, A, VT>(
@@ -79,7 +79,7 @@ impl WorldHandle {
         VT: Into<Vec2>,
     {
         cast_shape_points_with_offset_checked_impl(
-            self.raw(),
+            self.core(),
             points,
             radius,
             position,
@@ -107,7 +107,7 @@ impl WorldHandle {
         VT: Into<Vec2>,
     {
         cast_shape_points_with_offset_into_checked_impl(
-            self.raw(),
+            self.core(),
             points,
             radius,
             position,
@@ -135,7 +135,7 @@ impl WorldHandle {
         VT: Into<Vec2>,
     {
         try_cast_shape_points_with_offset_impl(
-            self.raw(),
+            self.core(),
             points,
             radius,
             position,
@@ -164,7 +164,7 @@ impl WorldHandle {
         VT: Into<Vec2>,
     {
         try_cast_shape_points_with_offset_into_impl(
-            self.raw(),
+            self.core(),
             points,
             radius,
             position,
@@ -174,4 +174,113 @@ impl WorldHandle {
             out,
         )
     }
+
+    pub fn cast_shape_against_body<VT: Into<Vec2>>(
+        &self,
+        body: BodyId,
+        proxy: &ShapeProxy,
+        transform: Transform,
+        translation: VT,
+    ) -> RayResult {
+        cast_shape_against_body_checked_impl(body, proxy, transform, translation)
+    }
+
+    pub fn try_cast_shape_against_body<VT: Into<Vec2>>(
+        &self,
+        body: BodyId,
+        proxy: &ShapeProxy,
+        transform: Transform,
+        translation: VT,
+    ) -> ApiResult<RayResult> {
+        try_cast_shape_against_body_impl(body, proxy, transform, translation)
+    }
+
+    pub fn cast_shape_closest<VT: Into<Vec2>>(
+        &self,
+        proxy: &ShapeProxy,
+        translation: VT,
+        filter: QueryFilter,
+    ) -> Option<RayHit> {
+        let mut out = Vec::new();
+        cast_shape_hits_into_checked_impl(self.core(), proxy, translation, filter, true, &mut out);
+        out.pop()
+    }
+
+    pub fn try_cast_shape_closest<VT: Into<Vec2>>(
+        &self,
+        proxy: &ShapeProxy,
+        translation: VT,
+        filter: QueryFilter,
+    ) -> ApiResult<Option<RayHit>> {
+        let mut out = Vec::new();
+        try_cast_shape_hits_into_impl(self.core(), proxy, translation, filter, true, &mut out)?;
+        Ok(out.pop())
+    }
+
+    pub fn cast_shape_all<VT: Into<Vec2>>(
+        &self,
+        proxy: &ShapeProxy,
+        translation: VT,
+        filter: QueryFilter,
+    ) -> Vec<RayHit> {
+        let mut out = Vec::new();
+        self.cast_shape_all_into(proxy, translation, filter, &mut out);
+        out
+    }
+
+    pub fn cast_shape_all_into<VT: Into<Vec2>>(
+        &self,
+        proxy: &ShapeProxy,
+        translation: VT,
+        filter: QueryFilter,
+        out: &mut Vec<RayHit>,
+    ) {
+        cast_shape_hits_into_checked_impl(self.core(), proxy, translation, filter, false, out);
+    }
+
+    pub fn try_cast_shape_all<VT: Into<Vec2>>(
+        &self,
+        proxy: &ShapeProxy,
+        translation: VT,
+        filter: QueryFilter,
+    ) -> ApiResult<Vec<RayHit>> {
+        let mut out = Vec::new();
+        self.try_cast_shape_all_into(proxy, translation, filter, &mut out)?;
+        Ok(out)
+    }
+
+    pub fn try_cast_shape_all_into<VT: Into<Vec2>>(
+        &self,
+        proxy: &ShapeProxy,
+        translation: VT,
+        filter: QueryFilter,
+        out: &mut Vec<RayHit>,
+    ) -> ApiResult<()> {
+        try_cast_shape_hits_into_impl(self.core(), proxy, translation, filter, false, out)
+    }
+
+    pub fn cast_shape_with<VT: Into<Vec2>, F>(
+        &self,
+        proxy: &ShapeProxy,
+        translation: VT,
+        filter: QueryFilter,
+        mut visit: F,
+    ) where
+        F: FnMut(RayHit) -> f32,
+    {
+        cast_shape_with_checked_impl(self.core(), proxy, translation, filter, &mut visit);
+    }
+
+    pub fn try_cast_shape_with<VT: Into<Vec2>, F>(
+        &self,
+        proxy: &ShapeProxy,
+        translation: VT,
+        filter: QueryFilter,
+        mut visit: F,
+    ) -> ApiResult<()>
+    where
+        F: FnMut(RayHit) -> f32,
+    {
+        try_cast_shape_with_impl(self.core(), proxy, translation, filter, &mut visit)
+    }
 }
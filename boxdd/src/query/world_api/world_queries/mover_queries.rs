@@ -10,7 +10,7 @@ impl World {
         translation: VT,
         filter: QueryFilter,
     ) -> f32 {
-        cast_mover_checked_impl(self.raw(), c1, c2, radius, translation, filter)
+        cast_mover_checked_impl(self.core(), c1, c2, radius, translation, filter)
     }
 
     pub fn try_cast_mover<V1: Into<Vec2>, V2: Into<Vec2>, VT: Into<Vec2>>(
@@ -21,7 +21,7 @@ impl World {
         translation: VT,
         filter: QueryFilter,
     ) -> ApiResult<f32> {
-        try_cast_mover_impl(self.raw(), c1, c2, radius, translation, filter)
+        try_cast_mover_impl(self.core(), c1, c2, radius, translation, filter)
     }
 
     /// Collect collision planes for a capsule mover at its current position.
@@ -32,7 +32,7 @@ impl World {
         radius: f32,
         filter: QueryFilter,
     ) -> Vec<MoverPlaneResult> {
-        collide_mover_checked_impl(self.raw(), c1, c2, radius, filter)
+        collide_mover_checked_impl(self.core(), c1, c2, radius, filter)
     }
 
     /// Collect collision planes for a capsule mover and reuse `out`.
@@ -44,7 +44,7 @@ impl World {
         filter: QueryFilter,
         out: &mut Vec<MoverPlaneResult>,
     ) {
-        collide_mover_into_checked_impl(self.raw(), c1, c2, radius, filter, out);
+        collide_mover_into_checked_impl(self.core(), c1, c2, radius, filter, out);
     }
 
     pub fn try_collide_mover<V1: Into<Vec2>, V2: Into<Vec2>>(
@@ -54,7 +54,7 @@ impl World {
         radius: f32,
         filter: QueryFilter,
     ) -> ApiResult<Vec<MoverPlaneResult>> {
-        try_collide_mover_impl(self.raw(), c1, c2, radius, filter)
+        try_collide_mover_impl(self.core(), c1, c2, radius, filter)
     }
 
     pub fn try_collide_mover_into<V1: Into<Vec2>, V2: Into<Vec2>>(
@@ -65,6 +65,6 @@ impl World {
         filter: QueryFilter,
         out: &mut Vec<MoverPlaneResult>,
     ) -> ApiResult<()> {
-        try_collide_mover_into_impl(self.raw(), c1, c2, radius, filter, out)
+        try_collide_mover_into_impl(self.core(), c1, c2, radius, filter, out)
     }
 }
@@ -14,14 +14,14 @@ impl World {
     /// assert!(!hits.is_empty());
     /// ```
     pub fn overlap_aabb(&self, aabb: Aabb, filter: QueryFilter) -> Vec<ShapeId> {
-        overlap_aabb_checked_impl(self.raw(), aabb, filter)
+        overlap_aabb_checked_impl(self.core(), aabb, filter)
     }
 
     /// Overlap test for all shapes in an AABB and write matching shape ids into `out`.
     ///
     /// `out` is cleared before new hits are appended so its allocation can be reused across frames.
     pub fn overlap_aabb_into(&self, aabb: Aabb, filter: QueryFilter, out: &mut Vec<ShapeId>) {
-        overlap_aabb_into_checked_impl(self.raw(), aabb, filter, out);
+        overlap_aabb_into_checked_impl(self.core(), aabb, filter, out);
     }
 
     /// Visit matching shape ids in an AABB without allocating a result container.
@@ -32,11 +32,11 @@ impl World {
     where
         F: FnMut(ShapeId) -> bool,
     {
-        visit_overlap_aabb_checked_impl(self.raw(), aabb, filter, &mut visit)
+        visit_overlap_aabb_checked_impl(self.core(), aabb, filter, &mut visit)
     }
 
     pub fn try_overlap_aabb(&self, aabb: Aabb, filter: QueryFilter) -> ApiResult<Vec<ShapeId>> {
-        try_overlap_aabb_impl(self.raw(), aabb, filter)
+        try_overlap_aabb_impl(self.core(), aabb, filter)
     }
 
     pub fn try_overlap_aabb_into(
@@ -45,7 +45,7 @@ impl World {
         filter: QueryFilter,
         out: &mut Vec<ShapeId>,
     ) -> ApiResult<()> {
-        try_overlap_aabb_into_impl(self.raw(), aabb, filter, out)
+        try_overlap_aabb_into_impl(self.core(), aabb, filter, out)
     }
 
     pub fn try_visit_overlap_aabb<F>(
@@ -57,7 +57,7 @@ impl World {
     where
         F: FnMut(ShapeId) -> bool,
     {
-        try_visit_overlap_aabb_impl(self.raw(), aabb, filter, &mut visit)
+        try_visit_overlap_aabb_impl(self.core(), aabb, filter, &mut visit)
     }
 
     /// Overlap polygon points (creates a temporary shape proxy from given points + radius) and collect all shape ids.
@@ -80,7 +80,7 @@ impl World {
         I: IntoIterator<Item = P>,
         P: Into<Vec2>,
     {
-        overlap_polygon_points_checked_impl(self.raw(), points, radius, filter)
+        overlap_polygon_points_checked_impl(self.core(), points, radius, filter)
     }
 
     /// Overlap a temporary polygon proxy and write matching shape ids into `out`.
@@ -94,7 +94,7 @@ impl World {
         I: IntoIterator<Item = P>,
         P: Into<Vec2>,
     {
-        overlap_polygon_points_into_checked_impl(self.raw(), points, radius, filter, out);
+        overlap_polygon_points_into_checked_impl(self.core(), points, radius, filter, out);
     }
 
     /// Visit matching shape ids for a temporary polygon proxy without allocating a result container.
@@ -113,7 +113,7 @@ impl World {
         P: Into<Vec2>,
         F: FnMut(ShapeId) -> bool,
     {
-        visit_overlap_polygon_points_checked_impl(self.raw(), points, radius, filter, &mut visit)
+        visit_overlap_polygon_points_checked_impl(self.core(), points, radius, filter, &mut visit)
     }
 
     pub fn try_overlap_polygon_points<I, P>(
@@ -126,7 +126,7 @@ impl World {
         I: IntoIterator<Item = P>,
         P: Into<Vec2>,
     {
-        try_overlap_polygon_points_impl(self.raw(), points, radius, filter)
+        try_overlap_polygon_points_impl(self.core(), points, radius, filter)
     }
 
     pub fn try_overlap_polygon_points_into<I, P>(
@@ -140,7 +140,7 @@ impl World {
         I: IntoIterator<Item = P>,
         P: Into<Vec2>,
     {
-        try_overlap_polygon_points_into_impl(self.raw(), points, radius, filter, out)
+        try_overlap_polygon_points_into_impl(self.core(), points, radius, filter, out)
     }
 
     pub fn try_visit_overlap_polygon_points<I, P, F>(
@@ -155,7 +155,7 @@ impl World {
         P: Into<Vec2>,
         F: FnMut(ShapeId) -> bool,
     {
-        try_visit_overlap_polygon_points_impl(self.raw(), points, radius, filter, &mut visit)
+        try_visit_overlap_polygon_points_impl(self.core(), points, radius, filter, &mut visit)
     }
 
     /// Overlap polygon points with an offset transform.
@@ -183,7 +183,7 @@ impl World {
         A: Into<f32>,
     {
         overlap_polygon_points_with_offset_checked_impl(
-            self.raw(),
+            self.core(),
             points,
             radius,
             position,
@@ -208,7 +208,7 @@ impl World {
         A: Into<f32>,
     {
         overlap_polygon_points_with_offset_into_checked_impl(
-            self.raw(),
+            self.core(),
             points,
             radius,
             position,
@@ -239,7 +239,7 @@ impl World {
         F: FnMut(ShapeId) -> bool,
     {
         visit_overlap_polygon_points_with_offset_checked_impl(
-            self.raw(),
+            self.core(),
             points,
             radius,
             position,
@@ -264,7 +264,7 @@ impl World {
         A: Into<f32>,
     {
         try_overlap_polygon_points_with_offset_impl(
-            self.raw(),
+            self.core(),
             points,
             radius,
             position,
@@ -289,7 +289,7 @@ impl World {
         A: Into<f32>,
     {
         try_overlap_polygon_points_with_offset_into_impl(
-            self.raw(),
+            self.core(),
             points,
             radius,
             position,
@@ -316,7 +316,7 @@ impl World {
         F: FnMut(ShapeId) -> bool,
     {
         try_visit_overlap_polygon_points_with_offset_impl(
-            self.raw(),
+            self.core(),
             points,
             radius,
             position,
@@ -325,4 +325,284 @@ impl World {
             &mut visit,
         )
     }
+
+    /// Shape under a screen position, for editor selection and mouse dragging.
+    ///
+    /// `screen_pos` is converted to world space with `camera`, then the first non-sensor shape
+    /// containing that point is returned. When shapes overlap, which one wins is unspecified.
+    ///
+    /// Example
+    /// ```no_run
+    /// use boxdd::{Camera2D, QueryFilter, World, WorldDef};
+    /// let world = World::new(WorldDef::default()).unwrap();
+    /// let camera = Camera2D::new([0.0, 0.0], 30.0, [800.0, 600.0]);
+    /// if let Some(hit) = world.pick(&camera, [400.0, 300.0], QueryFilter::default()) {
+    ///     let _ = (hit.body, hit.shape, hit.world_point);
+    /// }
+    /// ```
+    pub fn pick<V: Into<Vec2>>(
+        &self,
+        camera: &Camera2D,
+        screen_pos: V,
+        filter: QueryFilter,
+    ) -> Option<PickResult> {
+        pick_checked_impl(self.core(), camera, screen_pos.into(), filter)
+    }
+
+    pub fn try_pick<V: Into<Vec2>>(
+        &self,
+        camera: &Camera2D,
+        screen_pos: V,
+        filter: QueryFilter,
+    ) -> ApiResult<Option<PickResult>> {
+        try_pick_impl(self.core(), camera, screen_pos.into(), filter)
+    }
+
+    /// Shapes overlapping `polygon` placed at `transform`, e.g. a spawn-clearance check.
+    ///
+    /// Example
+    /// ```no_run
+    /// use boxdd::{QueryFilter, Transform, World, WorldDef, shapes};
+    /// let world = World::new(WorldDef::default()).unwrap();
+    /// let crate_box = shapes::box_polygon(0.5, 0.5);
+    /// let spawn = Transform::from_pos_angle([3.0, 2.0], 0.0);
+    /// let clear = world.overlap_polygon(&crate_box, spawn, QueryFilter::default()).is_empty();
+    /// let _ = clear;
+    /// ```
+    pub fn overlap_polygon(
+        &self,
+        polygon: &Polygon,
+        transform: Transform,
+        filter: QueryFilter,
+    ) -> Vec<ShapeId> {
+        overlap_polygon_checked_impl(self.core(), polygon, transform, filter)
+    }
+
+    pub fn try_overlap_polygon(
+        &self,
+        polygon: &Polygon,
+        transform: Transform,
+        filter: QueryFilter,
+    ) -> ApiResult<Vec<ShapeId>> {
+        try_overlap_polygon_impl(self.core(), polygon, transform, filter)
+    }
+
+    /// Shapes overlapping a circle.
+    pub fn overlap_circle<V: Into<Vec2>>(
+        &self,
+        center: V,
+        radius: f32,
+        filter: QueryFilter,
+    ) -> Vec<ShapeId> {
+        overlap_round_checked_impl(self.core(), &[center.into()], radius, filter)
+    }
+
+    pub fn try_overlap_circle<V: Into<Vec2>>(
+        &self,
+        center: V,
+        radius: f32,
+        filter: QueryFilter,
+    ) -> ApiResult<Vec<ShapeId>> {
+        try_overlap_round_impl(self.core(), &[center.into()], radius, filter)
+    }
+
+    /// Shapes overlapping a capsule between `center1` and `center2`.
+    pub fn overlap_capsule<V1: Into<Vec2>, V2: Into<Vec2>>(
+        &self,
+        center1: V1,
+        center2: V2,
+        radius: f32,
+        filter: QueryFilter,
+    ) -> Vec<ShapeId> {
+        overlap_round_checked_impl(
+            self.core(),
+            &[center1.into(), center2.into()],
+            radius,
+            filter,
+        )
+    }
+
+    pub fn try_overlap_capsule<V1: Into<Vec2>, V2: Into<Vec2>>(
+        &self,
+        center1: V1,
+        center2: V2,
+        radius: f32,
+        filter: QueryFilter,
+    ) -> ApiResult<Vec<ShapeId>> {
+        try_overlap_round_impl(
+            self.core(),
+            &[center1.into(), center2.into()],
+            radius,
+            filter,
+        )
+    }
+
+    /// Shapes overlapping any [`Geometry`] from [`shapes`](crate::shapes) placed at `transform`.
+    ///
+    /// Circles, capsules, and rounded polygons keep their radius; segments are tested as zero-width lines.
+    ///
+    /// Example
+    /// ```no_run
+    /// use boxdd::{QueryFilter, Transform, World, WorldDef, shapes};
+    /// let world = World::new(WorldDef::default()).unwrap();
+    /// let blast = shapes::circle([0.0, 0.0], 3.0);
+    /// let at = Transform::from_pos_angle([5.0, 1.0], 0.0);
+    /// for shape in world.overlap_geometry(blast, at, QueryFilter::default()) {
+    ///     let _ = shape;
+    /// }
+    /// ```
+    pub fn overlap_geometry<G: Into<Geometry>>(
+        &self,
+        geometry: G,
+        transform: Transform,
+        filter: QueryFilter,
+    ) -> Vec<ShapeId> {
+        overlap_geometry_checked_impl(self.core(), &geometry.into(), transform, filter)
+    }
+
+    pub fn try_overlap_geometry<G: Into<Geometry>>(
+        &self,
+        geometry: G,
+        transform: Transform,
+        filter: QueryFilter,
+    ) -> ApiResult<Vec<ShapeId>> {
+        try_overlap_geometry_impl(self.core(), &geometry.into(), transform, filter)
+    }
+
+    /// Visit shapes overlapping a circle without allocating; return `false` to stop early.
+    ///
+    /// Returns `true` if all hits were visited. The same visitor contract applies to the other
+    /// `visit_overlap_*` methods.
+    pub fn visit_overlap_circle<V: Into<Vec2>, F>(
+        &self,
+        center: V,
+        radius: f32,
+        filter: QueryFilter,
+        mut visit: F,
+    ) -> bool
+    where
+        F: FnMut(ShapeId) -> bool,
+    {
+        visit_overlap_round_checked_impl(self.core(), &[center.into()], radius, filter, &mut visit)
+    }
+
+    pub fn try_visit_overlap_circle<V: Into<Vec2>, F>(
+        &self,
+        center: V,
+        radius: f32,
+        filter: QueryFilter,
+        mut visit: F,
+    ) -> ApiResult<bool>
+    where
+        F: FnMut(ShapeId) -> bool,
+    {
+        try_visit_overlap_round_impl(self.core(), &[center.into()], radius, filter, &mut visit)
+    }
+
+    /// Visit shapes overlapping a capsule between `center1` and `center2`.
+    pub fn visit_overlap_capsule<V1: Into<Vec2>, V2: Into<Vec2>, F>(
+        &self,
+        center1: V1,
+        center2: V2,
+        radius: f32,
+        filter: QueryFilter,
+        mut visit: F,
+    ) -> bool
+    where
+        F: FnMut(ShapeId) -> bool,
+    {
+        visit_overlap_round_checked_impl(
+            self.core(),
+            &[center1.into(), center2.into()],
+            radius,
+            filter,
+            &mut visit,
+        )
+    }
+
+    pub fn try_visit_overlap_capsule<V1: Into<Vec2>, V2: Into<Vec2>, F>(
+        &self,
+        center1: V1,
+        center2: V2,
+        radius: f32,
+        filter: QueryFilter,
+        mut visit: F,
+    ) -> ApiResult<bool>
+    where
+        F: FnMut(ShapeId) -> bool,
+    {
+        try_visit_overlap_round_impl(
+            self.core(),
+            &[center1.into(), center2.into()],
+            radius,
+            filter,
+            &mut visit,
+        )
+    }
+
+    /// Visit shapes overlapping `polygon` placed at `transform`.
+    pub fn visit_overlap_polygon<F>(
+        &self,
+        polygon: &Polygon,
+        transform: Transform,
+        filter: QueryFilter,
+        visit: F,
+    ) -> bool
+    where
+        F: FnMut(ShapeId) -> bool,
+    {
+        self.visit_overlap_geometry(*polygon, transform, filter, visit)
+    }
+
+    pub fn try_visit_overlap_polygon<F>(
+        &self,
+        polygon: &Polygon,
+        transform: Transform,
+        filter: QueryFilter,
+        visit: F,
+    ) -> ApiResult<bool>
+    where
+        F: FnMut(ShapeId) -> bool,
+    {
+        self.try_visit_overlap_geometry(*polygon, transform, filter, visit)
+    }
+
+    /// Visit shapes overlapping `geometry` placed at `transform`.
+    pub fn visit_overlap_geometry<G: Into<Geometry>, F>(
+        &self,
+        geometry: G,
+        transform: Transform,
+        filter: QueryFilter,
+        mut visit: F,
+    ) -> bool
+    where
+        F: FnMut(ShapeId) -> bool,
+    {
+        visit_overlap_geometry_checked_impl(
+            self.core(),
+            &geometry.into(),
+            transform,
+            filter,
+            &mut visit,
+        )
+    }
+
+    pub fn try_visit_overlap_geometry<G: Into<Geometry>, F>(
+        &self,
+        geometry: G,
+        transform: Transform,
+        filter: QueryFilter,
+        mut visit: F,
+    ) -> ApiResult<bool>
+    where
+        F: FnMut(ShapeId) -> bool,
+    {
+        try_visit_overlap_geometry_impl(
+            self.core(),
+            &geometry.into(),
+            transform,
+            filter,
+            &mut visit,
+        )
+    }
 }
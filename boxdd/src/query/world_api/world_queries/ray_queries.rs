@@ -16,7 +16,7 @@ impl World {
         translation: VT,
         filter: QueryFilter,
     ) -> RayResult {
-        cast_ray_closest_checked_impl(self.raw(), origin, translation, filter)
+        cast_ray_closest_checked_impl(self.core(), origin, translation, filter)
     }
 
     pub fn try_cast_ray_closest<VO: Into<Vec2>, VT: Into<Vec2>>(
@@ -25,10 +25,10 @@ impl World {
         translation: VT,
         filter: QueryFilter,
     ) -> ApiResult<RayResult> {
-        try_cast_ray_closest_impl(self.raw(), origin, translation, filter)
+        try_cast_ray_closest_impl(self.core(), origin, translation, filter)
     }
 
-    /// Cast a ray and collect all hits along the path.
+    /// Cast a ray and collect all hits along the path, nearest first.
     ///
     /// Example
     /// ```no_run
@@ -43,7 +43,7 @@ impl World {
         translation: VT,
         filter: QueryFilter,
     ) -> Vec<RayResult> {
-        cast_ray_all_checked_impl(self.raw(), origin, translation, filter)
+        cast_ray_all_checked_impl(self.core(), origin, translation, filter)
     }
 
     /// Cast a ray and append all hits into `out`, reusing the caller-owned allocation.
@@ -54,7 +54,7 @@ impl World {
         filter: QueryFilter,
         out: &mut Vec<RayResult>,
     ) {
-        cast_ray_all_into_checked_impl(self.raw(), origin, translation, filter, out);
+        cast_ray_all_into_checked_impl(self.core(), origin, translation, filter, out);
     }
 
     pub fn try_cast_ray_all<VO: Into<Vec2>, VT: Into<Vec2>>(
@@ -63,7 +63,7 @@ impl World {
         translation: VT,
         filter: QueryFilter,
     ) -> ApiResult<Vec<RayResult>> {
-        try_cast_ray_all_impl(self.raw(), origin, translation, filter)
+        try_cast_ray_all_impl(self.core(), origin, translation, filter)
     }
 
     pub fn try_cast_ray_all_into<VO: Into<Vec2>, VT: Into<Vec2>>(
@@ -73,6 +73,194 @@ impl World {
         filter: QueryFilter,
         out: &mut Vec<RayResult>,
     ) -> ApiResult<()> {
-        try_cast_ray_all_into_impl(self.raw(), origin, translation, filter, out)
+        try_cast_ray_all_into_impl(self.core(), origin, translation, filter, out)
+    }
+
+    /// Cast a ray and return up to `max_hits` hits, nearest first, each with its body and user
+    /// material id. Pass `usize::MAX` for every hit.
+    ///
+    /// Suited to piercing bullets and line-of-sight checks that need to know what they passed
+    /// through. Box2D does not report shapes that contain `origin`.
+    ///
+    /// ```no_run
+    /// use boxdd::{QueryFilter, World, WorldDef};
+    /// let world = World::new(WorldDef::default()).unwrap();
+    /// // A bullet that punches through at most three targets.
+    /// for hit in world.cast_ray_hits([0.0, 1.0], [20.0, 0.0], QueryFilter::default(), 3) {
+    ///     println!("{:?} at {:?} (material {})", hit.body_id, hit.point, hit.user_material_id);
+    /// }
+    /// ```
+    pub fn cast_ray_hits<VO: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        origin: VO,
+        translation: VT,
+        filter: QueryFilter,
+        max_hits: usize,
+    ) -> Vec<RayHit> {
+        let mut out = Vec::new();
+        self.cast_ray_hits_into(origin, translation, filter, max_hits, &mut out);
+        out
+    }
+
+    /// Reusable-buffer version of [`World::cast_ray_hits`]; `out` is cleared first.
+    pub fn cast_ray_hits_into<VO: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        origin: VO,
+        translation: VT,
+        filter: QueryFilter,
+        max_hits: usize,
+        out: &mut Vec<RayHit>,
+    ) {
+        cast_ray_hits_into_checked_impl(self.core(), origin, translation, filter, max_hits, out);
+    }
+
+    pub fn try_cast_ray_hits<VO: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        origin: VO,
+        translation: VT,
+        filter: QueryFilter,
+        max_hits: usize,
+    ) -> ApiResult<Vec<RayHit>> {
+        let mut out = Vec::new();
+        self.try_cast_ray_hits_into(origin, translation, filter, max_hits, &mut out)?;
+        Ok(out)
+    }
+
+    pub fn try_cast_ray_hits_into<VO: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        origin: VO,
+        translation: VT,
+        filter: QueryFilter,
+        max_hits: usize,
+        out: &mut Vec<RayHit>,
+    ) -> ApiResult<()> {
+        try_cast_ray_hits_into_impl(self.core(), origin, translation, filter, max_hits, out)
+    }
+
+    /// Stream every hit along the ray to `visit` without allocating.
+    ///
+    /// The return value steers the cast like Box2D's cast callback: `-1.0` ignores the hit, `0.0`
+    /// stops, `hit.fraction` clips the ray to this hit (later hits are closer), and `1.0`
+    /// continues unclipped. Hits arrive in broad-phase order, not sorted.
+    ///
+    /// ```no_run
+    /// use boxdd::{QueryFilter, World, WorldDef};
+    /// let world = World::new(WorldDef::default()).unwrap();
+    /// let mut nearest = None;
+    /// world.cast_ray_with([0.0, 1.0], [20.0, 0.0], QueryFilter::default(), |hit| {
+    ///     nearest = Some(hit.body_id);
+    ///     hit.fraction
+    /// });
+    /// let _ = nearest;
+    /// ```
+    pub fn cast_ray_with<VO: Into<Vec2>, VT: Into<Vec2>, F>(
+        &self,
+        origin: VO,
+        translation: VT,
+        filter: QueryFilter,
+        mut visit: F,
+    ) where
+        F: FnMut(RayHit) -> f32,
+    {
+        cast_ray_with_checked_impl(self.core(), origin, translation, filter, &mut visit);
+    }
+
+    pub fn try_cast_ray_with<VO: Into<Vec2>, VT: Into<Vec2>, F>(
+        &self,
+        origin: VO,
+        translation: VT,
+        filter: QueryFilter,
+        mut visit: F,
+    ) -> ApiResult<()>
+    where
+        F: FnMut(RayHit) -> f32,
+    {
+        try_cast_ray_with_impl(self.core(), origin, translation, filter, &mut visit)
+    }
+
+    /// Sound occlusion factor in `[0, 1]` along the segment `from -> to` (`1` = unobstructed).
+    ///
+    /// Every non-sensor shape crossed by the segment halves the factor. Shapes containing `from`
+    /// are not reported by Box2D ray casts and therefore do not occlude. Use
+    /// [`Self::occlusion_with`] to derive per-shape transmission from materials.
+    ///
+    /// Example
+    /// ```no_run
+    /// use boxdd::{World, WorldDef, QueryFilter};
+    /// let world = World::new(WorldDef::default()).unwrap();
+    /// let gain = world.occlusion([0.0, 0.0], [10.0, 0.0], QueryFilter::default());
+    /// assert!((0.0..=1.0).contains(&gain));
+    /// ```
+    pub fn occlusion<VF: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        from: VF,
+        to: VT,
+        filter: QueryFilter,
+    ) -> f32 {
+        occlusion_checked_impl(self.core(), from, to, filter, |_| 0.5)
+    }
+
+    pub fn try_occlusion<VF: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        from: VF,
+        to: VT,
+        filter: QueryFilter,
+    ) -> ApiResult<f32> {
+        try_occlusion_impl(self.core(), from, to, filter, |_| 0.5)
+    }
+
+    /// Like [`Self::occlusion`], but `transmission` returns the fraction in `[0, 1]` that each
+    /// crossed shape lets through (e.g. keyed on `hit.material().user_material_id()`).
+    /// The result is the product over all crossed non-sensor shapes.
+    pub fn occlusion_with<VF: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        from: VF,
+        to: VT,
+        filter: QueryFilter,
+        transmission: impl FnMut(&RayResult) -> f32,
+    ) -> f32 {
+        occlusion_checked_impl(self.core(), from, to, filter, transmission)
+    }
+
+    pub fn try_occlusion_with<VF: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        from: VF,
+        to: VT,
+        filter: QueryFilter,
+        transmission: impl FnMut(&RayResult) -> f32,
+    ) -> ApiResult<f32> {
+        try_occlusion_impl(self.core(), from, to, filter, transmission)
+    }
+
+    /// Cast a ray against `body`'s shapes only and return the closest hit.
+    ///
+    /// Sensors are skipped and no query filter applies, so other bodies can never be hit. Panics
+    /// if `body` is invalid.
+    ///
+    /// Example
+    /// ```no_run
+    /// use boxdd::{World, WorldDef, BodyBuilder, ShapeDef, shapes, Vec2};
+    /// let mut world = World::new(WorldDef::default()).unwrap();
+    /// let enemy = world.create_body_id(BodyBuilder::new().position([3.0, 0.0]).build());
+    /// let _ = world.create_polygon_shape_for(enemy, &ShapeDef::default(), &shapes::box_polygon(0.5, 0.5));
+    /// let hit = world.cast_ray_against_body(enemy, Vec2::ZERO, Vec2::new(10.0, 0.0));
+    /// assert!(hit.hit);
+    /// ```
+    pub fn cast_ray_against_body<VO: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        body: BodyId,
+        origin: VO,
+        translation: VT,
+    ) -> RayResult {
+        cast_ray_against_body_checked_impl(body, origin, translation)
+    }
+
+    pub fn try_cast_ray_against_body<VO: Into<Vec2>, VT: Into<Vec2>>(
+        &self,
+        body: BodyId,
+        origin: VO,
+        translation: VT,
+    ) -> ApiResult<RayResult> {
+        try_cast_ray_against_body_impl(body, origin, translation)
     }
 }
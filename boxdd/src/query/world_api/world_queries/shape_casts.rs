@@ -23,7 +23,7 @@ impl World {
         P: Into<Vec2>,
         VT: Into<Vec2>,
     {
-        cast_shape_points_checked_impl(self.raw(), points, radius, translation, filter)
+        cast_shape_points_checked_impl(self.core(), points, radius, translation, filter)
     }
 
     /// Cast a temporary polygon proxy and write all hits into `out`.
@@ -39,7 +39,7 @@ impl World {
         P: Into<Vec2>,
         VT: Into<Vec2>,
     {
-        cast_shape_points_into_checked_impl(self.raw(), points, radius, translation, filter, out);
+        cast_shape_points_into_checked_impl(self.core(), points, radius, translation, filter, out);
     }
 
     pub fn try_cast_shape_points<I, P, VT>(
@@ -54,7 +54,7 @@ impl World {
         P: Into<Vec2>,
         VT: Into<Vec2>,
     {
-        try_cast_shape_points_impl(self.raw(), points, radius, translation, filter)
+        try_cast_shape_points_impl(self.core(), points, radius, translation, filter)
     }
 
     pub fn try_cast_shape_points_into<I, P, VT>(
@@ -70,7 +70,7 @@ impl World {
         P: Into<Vec2>,
         VT: Into<Vec2>,
     {
-        try_cast_shape_points_into_impl(self.raw(), points, radius, translation, filter, out)
+        try_cast_shape_points_into_impl(self.core(), points, radius, translation, filter, out)
     }
 
     /// Cast polygon points with an offset transform (position + angle).
@@ -100,7 +100,7 @@ impl World {
         VT: Into<Vec2>,
     {
         cast_shape_points_with_offset_checked_impl(
-            self.raw(),
+            self.core(),
             points,
             radius,
             position,
@@ -129,7 +129,7 @@ impl World {
         VT: Into<Vec2>,
     {
         cast_shape_points_with_offset_into_checked_impl(
-            self.raw(),
+            self.core(),
             points,
             radius,
             position,
@@ -157,7 +157,7 @@ impl World {
         VT: Into<Vec2>,
     {
         try_cast_shape_points_with_offset_impl(
-            self.raw(),
+            self.core(),
             points,
             radius,
             position,
@@ -186,7 +186,7 @@ impl World {
         VT: Into<Vec2>,
     {
         try_cast_shape_points_with_offset_into_impl(
-            self.raw(),
+            self.core(),
             points,
             radius,
             position,
@@ -196,4 +196,147 @@ impl World {
             out,
         )
     }
+
+    /// Cast `proxy`, placed at `transform`, along `translation` against `body`'s shapes only and
+    /// return the closest hit in world space.
+    ///
+    /// Sensors are skipped and no query filter applies. Panics if `body` is invalid.
+    ///
+    /// Example
+    /// ```no_run
+    /// use boxdd::{World, WorldDef, BodyBuilder, ShapeDef, ShapeProxy, Transform, shapes, Vec2};
+    /// let mut world = World::new(WorldDef::default()).unwrap();
+    /// let enemy = world.create_body_id(BodyBuilder::new().position([3.0, 0.0]).build());
+    /// let _ = world.create_polygon_shape_for(enemy, &ShapeDef::default(), &shapes::box_polygon(0.5, 0.5));
+    /// let sword = ShapeProxy::new([[0.0, 0.0], [1.0, 0.0]], 0.1).unwrap();
+    /// let hit = world.cast_shape_against_body(enemy, &sword, Transform::IDENTITY, Vec2::new(5.0, 0.0));
+    /// assert!(hit.hit);
+    /// ```
+    pub fn cast_shape_against_body<VT: Into<Vec2>>(
+        &self,
+        body: BodyId,
+        proxy: &ShapeProxy,
+        transform: Transform,
+        translation: VT,
+    ) -> RayResult {
+        cast_shape_against_body_checked_impl(body, proxy, transform, translation)
+    }
+
+    pub fn try_cast_shape_against_body<VT: Into<Vec2>>(
+        &self,
+        body: BodyId,
+        proxy: &ShapeProxy,
+        transform: Transform,
+        translation: VT,
+    ) -> ApiResult<RayResult> {
+        try_cast_shape_against_body_impl(body, proxy, transform, translation)
+    }
+
+    /// Sweep `proxy` along `translation` and return the closest hit, with its body and material.
+    ///
+    /// The proxy's points are in world space; build one from a box, capsule, circle, or point
+    /// cloud with [`ShapeProxy::new`]. Box2D does not report shapes that already overlap the
+    /// proxy at the start of the sweep.
+    ///
+    /// ```no_run
+    /// use boxdd::{QueryFilter, ShapeProxy, World, WorldDef};
+    /// let world = World::new(WorldDef::default()).unwrap();
+    /// // A 1x1 box at (0, 5) dropped 10 m.
+    /// let falling = ShapeProxy::new([[-0.5, 4.5], [0.5, 4.5], [0.5, 5.5], [-0.5, 5.5]], 0.0).unwrap();
+    /// if let Some(hit) = world.cast_shape_closest(&falling, [0.0, -10.0], QueryFilter::default()) {
+    ///     println!("lands on {:?} after {:.2} m", hit.body_id, 10.0 * hit.fraction);
+    /// }
+    /// ```
+    pub fn cast_shape_closest<VT: Into<Vec2>>(
+        &self,
+        proxy: &ShapeProxy,
+        translation: VT,
+        filter: QueryFilter,
+    ) -> Option<RayHit> {
+        let mut out = Vec::new();
+        cast_shape_hits_into_checked_impl(self.core(), proxy, translation, filter, true, &mut out);
+        out.pop()
+    }
+
+    pub fn try_cast_shape_closest<VT: Into<Vec2>>(
+        &self,
+        proxy: &ShapeProxy,
+        translation: VT,
+        filter: QueryFilter,
+    ) -> ApiResult<Option<RayHit>> {
+        let mut out = Vec::new();
+        try_cast_shape_hits_into_impl(self.core(), proxy, translation, filter, true, &mut out)?;
+        Ok(out.pop())
+    }
+
+    /// Every hit of `proxy` swept along `translation`, sorted by fraction.
+    pub fn cast_shape_all<VT: Into<Vec2>>(
+        &self,
+        proxy: &ShapeProxy,
+        translation: VT,
+        filter: QueryFilter,
+    ) -> Vec<RayHit> {
+        let mut out = Vec::new();
+        self.cast_shape_all_into(proxy, translation, filter, &mut out);
+        out
+    }
+
+    /// Reusable-buffer version of [`World::cast_shape_all`]; `out` is cleared first.
+    pub fn cast_shape_all_into<VT: Into<Vec2>>(
+        &self,
+        proxy: &ShapeProxy,
+        translation: VT,
+        filter: QueryFilter,
+        out: &mut Vec<RayHit>,
+    ) {
+        cast_shape_hits_into_checked_impl(self.core(), proxy, translation, filter, false, out);
+    }
+
+    pub fn try_cast_shape_all<VT: Into<Vec2>>(
+        &self,
+        proxy: &ShapeProxy,
+        translation: VT,
+        filter: QueryFilter,
+    ) -> ApiResult<Vec<RayHit>> {
+        let mut out = Vec::new();
+        self.try_cast_shape_all_into(proxy, translation, filter, &mut out)?;
+        Ok(out)
+    }
+
+    pub fn try_cast_shape_all_into<VT: Into<Vec2>>(
+        &self,
+        proxy: &ShapeProxy,
+        translation: VT,
+        filter: QueryFilter,
+        out: &mut Vec<RayHit>,
+    ) -> ApiResult<()> {
+        try_cast_shape_hits_into_impl(self.core(), proxy, translation, filter, false, out)
+    }
+
+    /// Stream every hit of `proxy` swept along `translation` to `visit`; the return value steers
+    /// the cast as in [`World::cast_ray_with`].
+    pub fn cast_shape_with<VT: Into<Vec2>, F>(
+        &self,
+        proxy: &ShapeProxy,
+        translation: VT,
+        filter: QueryFilter,
+        mut visit: F,
+    ) where
+        F: FnMut(RayHit) -> f32,
+    {
+        cast_shape_with_checked_impl(self.core(), proxy, translation, filter, &mut visit);
+    }
+
+    pub fn try_cast_shape_with<VT: Into<Vec2>, F>(
+        &self,
+        proxy: &ShapeProxy,
+        translation: VT,
+        filter: QueryFilter,
+        mut visit: F,
+    ) -> ApiResult<()>
+    where
+        F: FnMut(RayHit) -> f32,
+    {
+        try_cast_shape_with_impl(self.core(), proxy, translation, filter, &mut visit)
+    }
 }
@@ -1,14 +1,63 @@
 //! Serializable snapshots for configs and selected runtime state.
 //!
 //! This module is only compiled when the `serialize` feature is enabled.
+//!
+//! Scene records carry an `extensions` map for gameplay metadata (entity kind, health, ...).
+//! Fill it through [`SceneSnapshot::take_with`] or by editing records directly, and read it
+//! back through [`SceneSnapshot::rebuild_with`].
+//!
+//! Snapshots serialize with any serde format; [`SceneSnapshot::to_bytes`] and
+//! [`SceneSnapshot::from_bytes`] add a compact, versioned binary encoding for save games.
 
 use crate::{
     body::BodyType,
     joints::JointType,
     shapes::ShapeType,
-    types::{BodyId, JointId, Vec2},
+    types::{BodyId, ChainId, JointId, ShapeId, Vec2},
     world::World,
 };
+use std::collections::HashMap;
+
+/// User extension data stored alongside snapshot records, keyed by name.
+pub type Extensions = HashMap<String, serde_json::Value>;
+
+/// Record visited by [`SceneSnapshot::take_with`] while the snapshot is being taken.
+#[derive(Debug)]
+pub enum SnapshotEntry<'a> {
+    /// The scene itself; called once after all bodies are recorded.
+    Scene { extensions: &'a mut Extensions },
+    /// A body record; called after its shapes.
+    Body {
+        id: BodyId,
+        extensions: &'a mut Extensions,
+    },
+    /// A shape record.
+    Shape {
+        id: ShapeId,
+        body: BodyId,
+        extensions: &'a mut Extensions,
+    },
+}
+
+/// Record visited by [`SceneSnapshot::rebuild_with`] after the matching object was created.
+#[derive(Debug)]
+pub enum RebuildEntry<'a> {
+    /// The scene itself; called once after all objects are created.
+    Scene { extensions: &'a Extensions },
+    /// A body created from `bodies[index]`; called after its shapes.
+    Body {
+        index: usize,
+        id: BodyId,
+        record: &'a BodyRecord,
+    },
+    /// A shape created from `bodies[body_index].shapes[shape_index]`.
+    Shape {
+        body_index: usize,
+        shape_index: usize,
+        id: ShapeId,
+        record: &'a ShapeInstance,
+    },
+}
 // no Hash/Eq on FFI ids; use simple field comparisons and linear scans
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -102,20 +151,132 @@ impl BodySnapshot {
 // =============== Full Scene Snapshot (experimental, minimal joints) ===============
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+#[non_exhaustive]
 pub struct SceneSnapshot {
     pub world: WorldConfigSnapshot,
     pub bodies: Vec<BodyRecord>,
     pub joints: Vec<JointRecord>,
     #[serde(default)]
     pub chains: Vec<ChainRecord>,
+    /// Touching contacts, captured only with [`SnapshotOptions::include_contacts`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub contacts: Vec<ContactRecord>,
+    /// Sensor overlaps, captured only with [`SnapshotOptions::include_sensor_overlaps`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub sensor_overlaps: Vec<SensorOverlapRecord>,
+    /// Named logical positions (spawn points, triggers, ...) stored with [`World::set_marker`].
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub markers: Vec<crate::world_extras::Marker>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub extensions: Extensions,
+}
+
+/// Leading bytes of a binary scene snapshot.
+pub const SNAPSHOT_MAGIC: [u8; 4] = *b"BXDS";
+/// Binary snapshot format version written by [`SceneSnapshot::to_bytes`].
+pub const SNAPSHOT_FORMAT_VERSION: u16 = 1;
+const SNAPSHOT_HEADER_LEN: usize = SNAPSHOT_MAGIC.len() + 2;
+
+/// Why [`SceneSnapshot::from_bytes`] rejected its input.
+#[derive(Debug, thiserror::Error)]
+pub enum SnapshotFormatError {
+    #[error("not a boxdd scene snapshot (missing header)")]
+    BadMagic,
+    #[error("unsupported scene snapshot format version {0} (expected {SNAPSHOT_FORMAT_VERSION})")]
+    UnsupportedVersion(u16),
+    #[error("failed to encode scene snapshot: {0}")]
+    Encode(#[from] rmp_serde::encode::Error),
+    #[error("failed to decode scene snapshot: {0}")]
+    Decode(#[from] rmp_serde::decode::Error),
+}
+
+/// Options for [`SceneSnapshot::take_with_options`].
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct SnapshotOptions {
+    /// Capture touching contact pairs with their manifolds and accumulated impulses.
+    pub include_contacts: bool,
+    /// Capture the shapes each sensor currently overlaps.
+    pub include_sensor_overlaps: bool,
+}
+
+impl SnapshotOptions {
+    /// Capture only what [`SceneSnapshot::take`] does.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Capture everything the snapshot can record.
+    pub fn full() -> Self {
+        Self {
+            include_contacts: true,
+            include_sensor_overlaps: true,
+        }
+    }
+
+    /// Set [`Self::include_contacts`].
+    pub fn include_contacts(mut self, flag: bool) -> Self {
+        self.include_contacts = flag;
+        self
+    }
+
+    /// Set [`Self::include_sensor_overlaps`].
+    pub fn include_sensor_overlaps(mut self, flag: bool) -> Self {
+        self.include_sensor_overlaps = flag;
+        self
+    }
+}
+
+/// Index of a shape inside a scene snapshot: `bodies[body].shapes[shape]`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash, serde::Serialize, serde::Deserialize)]
+pub struct ShapeRef {
+    pub body: u32,
+    pub shape: u32,
+}
+
+/// A touching contact captured by [`SceneSnapshot::take_with_options`].
+///
+/// Box2D v3 does not expose a way to seed contact impulses, so rebuilding recreates contacts
+/// from scratch on the first step. The recorded manifold (points, separations, and
+/// `normal_impulse` / `tangent_impulse` warm-start values) is kept so callers can inspect the
+/// resting state or compare it against the rebuilt world.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ContactRecord {
+    pub shape_a: ShapeRef,
+    pub shape_b: ShapeRef,
+    pub manifold: crate::types::Manifold,
+}
+
+/// Shapes overlapping a sensor when the snapshot was taken.
+///
+/// Box2D recomputes sensor overlaps at the end of each step and cannot be seeded, so the first
+/// step after a rebuild reports these overlaps again as begin events. The record lets callers
+/// filter those out or verify the rebuilt world reached the same state.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct SensorOverlapRecord {
+    pub sensor: ShapeRef,
+    pub visitors: Vec<ShapeRef>,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub struct BodyRecord {
+    /// Creation definition, including the awake, sleep, bullet, and enabled flags.
     pub def: crate::body::BodyDef,
     #[serde(default)]
     pub name: Option<String>,
+    /// Sleep velocity threshold; `None` keeps the Box2D default.
+    #[serde(default)]
+    pub sleep_threshold: Option<f32>,
+    /// Motion locks; `None` leaves the body unlocked.
+    #[serde(default)]
+    pub motion_locks: Option<crate::types::MotionLocks>,
+    /// Mass data applied after the shapes are attached, so overrides from
+    /// [`World::set_body_mass_data`] survive the rebuild. Recorded for dynamic bodies only.
+    #[serde(default)]
+    pub mass_data: Option<crate::types::MassData>,
     pub shapes: Vec<ShapeInstance>,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub extensions: Extensions,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -124,6 +285,8 @@ pub struct ShapeInstance {
     #[serde(default)]
     pub sensor: bool,
     pub geom: ShapeGeom,
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub extensions: Extensions,
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
@@ -227,22 +390,108 @@ pub enum JointParams {
 }
 
 impl SceneSnapshot {
+    /// An empty scene with the given world settings, for building snapshots by hand. Fill it in
+    /// with the setters below or through the public fields.
+    pub fn new(world: WorldConfigSnapshot) -> Self {
+        Self {
+            world,
+            bodies: Vec::new(),
+            joints: Vec::new(),
+            chains: Vec::new(),
+            contacts: Vec::new(),
+            sensor_overlaps: Vec::new(),
+            markers: Vec::new(),
+            extensions: Extensions::new(),
+        }
+    }
+
+    /// Set [`Self::bodies`].
+    pub fn bodies(mut self, bodies: Vec<BodyRecord>) -> Self {
+        self.bodies = bodies;
+        self
+    }
+
+    /// Set [`Self::joints`].
+    pub fn joints(mut self, joints: Vec<JointRecord>) -> Self {
+        self.joints = joints;
+        self
+    }
+
+    /// Set [`Self::chains`].
+    pub fn chains(mut self, chains: Vec<ChainRecord>) -> Self {
+        self.chains = chains;
+        self
+    }
+
+    /// Set [`Self::markers`].
+    pub fn markers(mut self, markers: Vec<crate::world_extras::Marker>) -> Self {
+        self.markers = markers;
+        self
+    }
+
+    /// Set [`Self::extensions`].
+    pub fn extensions(mut self, extensions: Extensions) -> Self {
+        self.extensions = extensions;
+        self
+    }
+
     pub fn take(world: &World) -> Self {
+        Self::take_with(world, |_| {})
+    }
+
+    /// Take a snapshot, letting `on_entry` attach extension data to each record.
+    pub fn take_with(world: &World, on_entry: impl FnMut(SnapshotEntry<'_>)) -> Self {
+        Self::take_with_options(world, SnapshotOptions::default(), on_entry)
+    }
+
+    /// Take a snapshot with explicit [`SnapshotOptions`].
+    pub fn take_with_options(
+        world: &World,
+        options: SnapshotOptions,
+        mut on_entry: impl FnMut(SnapshotEntry<'_>),
+    ) -> Self {
         crate::core::callback_state::assert_not_in_callback();
         let cfg = WorldConfigSnapshot::take(world);
         // Build body list from registry (only tracks bodies created via this wrapper)
         let body_ids = world.body_ids();
         let mut bodies = Vec::new();
+        let mut shape_refs: HashMap<ShapeId, ShapeRef> = HashMap::new();
+        let mut shape_ids = Vec::new();
 
-        for &bid in &body_ids {
+        for (body_index, &bid) in body_ids.iter().enumerate() {
             crate::core::debug_checks::assert_body_valid(bid);
             // BodyDef from runtime
             let def = body_def_from_runtime(world, bid);
             // Optional name
             let name = world.body_name(bid);
             // Shapes
-            let shapes = shapes_from_body(world, bid);
-            bodies.push(BodyRecord { def, name, shapes });
+            shape_ids.clear();
+            let shapes = shapes_from_body(world, bid, &mut shape_ids, &mut on_entry);
+            for (shape_index, &sid) in shape_ids.iter().enumerate() {
+                shape_refs.insert(
+                    sid,
+                    ShapeRef {
+                        body: body_index as u32,
+                        shape: shape_index as u32,
+                    },
+                );
+            }
+            let mut extensions = Extensions::new();
+            on_entry(SnapshotEntry::Body {
+                id: bid,
+                extensions: &mut extensions,
+            });
+            let locks = crate::body::body_motion_locks_impl(bid);
+            bodies.push(BodyRecord {
+                def,
+                name,
+                sleep_threshold: Some(crate::body::body_sleep_threshold_impl(bid)),
+                motion_locks: (locks != crate::types::MotionLocks::default()).then_some(locks),
+                mass_data: (crate::body::body_type_impl(bid) == BodyType::Dynamic)
+                    .then(|| crate::body::body_mass_data_impl(bid)),
+                shapes,
+                extensions,
+            });
         }
 
         // Gather joints by walking per body and deduping (without Hash/Eq)
@@ -304,15 +553,84 @@ impl SceneSnapshot {
             }
         }
 
+        let contacts = if options.include_contacts {
+            contacts_from_shapes(&shape_refs)
+        } else {
+            Vec::new()
+        };
+
+        let sensor_overlaps = if options.include_sensor_overlaps {
+            sensor_overlaps_from_shapes(&shape_refs)
+        } else {
+            Vec::new()
+        };
+
+        let mut extensions = Extensions::new();
+        on_entry(SnapshotEntry::Scene {
+            extensions: &mut extensions,
+        });
+
         Self {
             world: cfg,
             bodies,
             joints,
             chains,
+            contacts,
+            sensor_overlaps,
+            markers: world.markers(),
+            extensions,
+        }
+    }
+
+    /// Encode the snapshot in the compact binary format.
+    ///
+    /// The output is [`SNAPSHOT_MAGIC`], the little-endian [`SNAPSHOT_FORMAT_VERSION`], then the
+    /// snapshot as MessagePack with named fields, so records stay readable when fields are added.
+    /// It is several times smaller and faster to produce than JSON for large scenes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.try_to_bytes()
+            .expect("scene snapshot encoding into memory failed")
+    }
+
+    /// Recoverable version of [`Self::to_bytes`].
+    pub fn try_to_bytes(&self) -> Result<Vec<u8>, SnapshotFormatError> {
+        let mut out = Vec::with_capacity(SNAPSHOT_HEADER_LEN + 64 * self.bodies.len());
+        out.extend_from_slice(&SNAPSHOT_MAGIC);
+        out.extend_from_slice(&SNAPSHOT_FORMAT_VERSION.to_le_bytes());
+        rmp_serde::encode::write_named(&mut out, self)?;
+        Ok(out)
+    }
+
+    /// Decode a snapshot written by [`Self::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SnapshotFormatError> {
+        let Some((header, payload)) = bytes.split_at_checked(SNAPSHOT_HEADER_LEN) else {
+            return Err(SnapshotFormatError::BadMagic);
+        };
+        if header[..SNAPSHOT_MAGIC.len()] != SNAPSHOT_MAGIC {
+            return Err(SnapshotFormatError::BadMagic);
         }
+        let version = u16::from_le_bytes([header[4], header[5]]);
+        if version != SNAPSHOT_FORMAT_VERSION {
+            return Err(SnapshotFormatError::UnsupportedVersion(version));
+        }
+        Ok(rmp_serde::from_slice(payload)?)
+    }
+
+    /// Rebuild the scene into a new world.
+    ///
+    /// Body state (velocities, sleep, bullet and enabled flags, motion locks, and mass data) is
+    /// restored. Contact impulses and sensor overlaps are recomputed by the first step because
+    /// Box2D has no way to seed them; see [`ContactRecord`] and [`SensorOverlapRecord`].
+    ///
+    /// The returned [`RebuildMap`] maps snapshot indices to the ids created in the new world.
+    pub fn rebuild(&self) -> (World, RebuildMap) {
+        self.rebuild_with(|_| {})
     }
 
-    pub fn rebuild(&self) -> World {
+    /// Rebuild the scene, reporting each created object and its record to `on_entry`.
+    ///
+    /// Use this to re-link gameplay entities through the stored extension data.
+    pub fn rebuild_with(&self, mut on_entry: impl FnMut(RebuildEntry<'_>)) -> (World, RebuildMap) {
         // Build world with gravity from config then apply runtime knobs
         let mut world = World::new(
             crate::world::WorldDef::builder()
@@ -321,46 +639,71 @@ impl SceneSnapshot {
         )
         .expect("create world");
         self.world.apply(&mut world);
+        for marker in &self.markers {
+            world.set_marker(marker.clone());
+        }
 
         // Create bodies and shapes
         let mut map: Vec<crate::types::BodyId> = Vec::with_capacity(self.bodies.len());
-        for br in &self.bodies {
+        for (index, br) in self.bodies.iter().enumerate() {
             let id = world.create_body_id(br.def.clone());
             if let Some(name) = &br.name {
                 world.set_body_name(id, name);
             }
-            for sh in &br.shapes {
+            if let Some(threshold) = br.sleep_threshold {
+                world.set_body_sleep_threshold(id, threshold);
+            }
+            if let Some(locks) = br.motion_locks {
+                world.set_body_motion_locks(id, locks);
+            }
+            for (shape_index, sh) in br.shapes.iter().enumerate() {
                 let def = &sh.def;
-                match &sh.geom {
+                let sid = match &sh.geom {
                     ShapeGeom::Circle { center, radius } => {
                         let c = crate::shapes::Circle::new(*center, *radius);
-                        let _ = world.create_circle_shape_for(id, def, &c);
+                        Some(world.create_circle_shape_for(id, def, &c))
                     }
                     ShapeGeom::Segment { p1, p2 } => {
                         let s = crate::shapes::Segment::new(*p1, *p2);
-                        let _ = world.create_segment_shape_for(id, def, &s);
+                        Some(world.create_segment_shape_for(id, def, &s))
                     }
                     ShapeGeom::Capsule { c1, c2, radius } => {
                         let cap = crate::shapes::Capsule::new(*c1, *c2, *radius);
-                        let _ = world.create_capsule_shape_for(id, def, &cap);
+                        Some(world.create_capsule_shape_for(id, def, &cap))
                     }
                     ShapeGeom::Polygon { vertices, radius } => {
                         // Build polygon via helper from points
-                        if let Some(poly) =
-                            crate::shapes::helpers::polygon_from_points(vertices.clone(), *radius)
-                        {
-                            let _ = world.create_polygon_shape_for(id, def, &poly);
-                        }
+                        crate::shapes::helpers::polygon_from_points(vertices.clone(), *radius)
+                            .map(|poly| world.create_polygon_shape_for(id, def, &poly))
                     }
+                };
+                if let Some(sid) = sid {
+                    on_entry(RebuildEntry::Shape {
+                        body_index: index,
+                        shape_index,
+                        id: sid,
+                        record: sh,
+                    });
                 }
             }
+            // After the shapes, which would otherwise recompute the mass.
+            if let Some(mass_data) = br.mass_data {
+                world.set_body_mass_data(id, mass_data);
+            }
+            on_entry(RebuildEntry::Body {
+                index,
+                id,
+                record: br,
+            });
             map.push(id);
         }
 
         // Create chains (captured via ID-style chain creation records).
+        let mut chain_map = Vec::with_capacity(self.chains.len());
         for cr in &self.chains {
             let body = map.get(cr.body as usize).copied();
             let Some(body) = body else {
+                chain_map.push(None);
                 continue;
             };
             let mut b = crate::shapes::chain::ChainDef::builder()
@@ -378,21 +721,23 @@ impl SceneSnapshot {
                 }
             }
             let def = b.build();
-            let _ = world.create_chain_for_id(body, &def);
+            chain_map.push(Some(world.create_chain_for_id(body, &def)));
         }
 
         // Create joints (base frames only; type-specific parameters defaulted)
+        let mut joint_map = Vec::with_capacity(self.joints.len());
         for jr in &self.joints {
             let a = map.get(jr.body_a as usize).copied();
             let b = map.get(jr.body_b as usize).copied();
             let (Some(aid), Some(bid)) = (a, b) else {
+                joint_map.push(None);
                 continue;
             };
             let base = crate::joints::JointBaseBuilder::new()
                 .bodies_by_id(aid, bid)
                 .local_frames_raw(jr.local_a.into_raw(), jr.local_b.into_raw())
                 .build();
-            match jr.kind {
+            let id = match jr.kind {
                 JointKind::Distance => {
                     let def = crate::joints::DistanceJointDef::new(base);
                     let id = world.create_distance_joint_id(&def);
@@ -419,10 +764,11 @@ impl SceneSnapshot {
                         world.distance_set_motor_speed(id, *motor_speed);
                         world.distance_set_max_motor_force(id, *max_motor_force);
                     }
+                    id
                 }
                 JointKind::Filter => {
                     let def = crate::joints::FilterJointDef::new(base);
-                    let _ = world.create_filter_joint_id(&def);
+                    world.create_filter_joint_id(&def)
                 }
                 JointKind::Motor => {
                     let def = crate::joints::MotorJointDef::new(base);
@@ -451,6 +797,7 @@ impl SceneSnapshot {
                         world.motor_set_max_spring_force(id, *max_spring_force);
                         world.motor_set_max_spring_torque(id, *max_spring_torque);
                     }
+                    id
                 }
                 JointKind::Prismatic => {
                     let def = crate::joints::PrismaticJointDef::new(base);
@@ -478,6 +825,7 @@ impl SceneSnapshot {
                         world.prismatic_set_motor_speed(id, *motor_speed);
                         world.prismatic_set_max_motor_force(id, *max_motor_force);
                     }
+                    id
                 }
                 JointKind::Revolute => {
                     let def = crate::joints::RevoluteJointDef::new(base);
@@ -505,6 +853,7 @@ impl SceneSnapshot {
                         world.revolute_set_motor_speed(id, *motor_speed);
                         world.revolute_set_max_motor_torque(id, *max_motor_torque);
                     }
+                    id
                 }
                 JointKind::Weld => {
                     let def = crate::joints::WeldJointDef::new(base);
@@ -521,6 +870,7 @@ impl SceneSnapshot {
                         world.weld_set_angular_hertz(id, *angular_hertz);
                         world.weld_set_angular_damping_ratio(id, *angular_damping_ratio);
                     }
+                    id
                 }
                 JointKind::Wheel => {
                     let def = crate::joints::WheelJointDef::new(base);
@@ -546,11 +896,64 @@ impl SceneSnapshot {
                         world.wheel_set_motor_speed(id, *motor_speed);
                         world.wheel_set_max_motor_torque(id, *max_motor_torque);
                     }
+                    id
                 }
+            };
+            joint_map.push(Some(id));
+        }
+
+        // Joint creation wakes the attached bodies; put sleeping bodies back to sleep.
+        for (br, &id) in self.bodies.iter().zip(&map) {
+            if br.def.is_enabled() && !br.def.is_awake() && world.body_is_awake(id) {
+                world.set_body_awake(id, false);
             }
         }
 
-        world
+        on_entry(RebuildEntry::Scene {
+            extensions: &self.extensions,
+        });
+        let rebuild_map = RebuildMap {
+            bodies: map,
+            joints: joint_map,
+            chains: chain_map,
+        };
+        (world, rebuild_map)
+    }
+}
+
+/// Snapshot index to runtime id table returned by [`SceneSnapshot::rebuild`].
+///
+/// Entries are `None` for records that could not be recreated (e.g. a joint referencing a
+/// missing body index).
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RebuildMap {
+    /// `bodies[i]` is the body created from `SceneSnapshot::bodies[i]`.
+    pub bodies: Vec<BodyId>,
+    /// `joints[i]` is the joint created from `SceneSnapshot::joints[i]`.
+    pub joints: Vec<Option<JointId>>,
+    /// `chains[i]` is the chain created from `SceneSnapshot::chains[i]`.
+    pub chains: Vec<Option<ChainId>>,
+}
+
+impl RebuildMap {
+    /// Body created for snapshot body `index`.
+    pub fn body(&self, index: usize) -> Option<BodyId> {
+        self.bodies.get(index).copied()
+    }
+
+    /// Joint created for snapshot joint `index`.
+    pub fn joint(&self, index: usize) -> Option<JointId> {
+        self.joints.get(index).copied().flatten()
+    }
+
+    /// Chain created for snapshot chain `index`.
+    pub fn chain(&self, index: usize) -> Option<ChainId> {
+        self.chains.get(index).copied().flatten()
+    }
+
+    /// Snapshot index of a rebuilt body.
+    pub fn body_index(&self, id: BodyId) -> Option<usize> {
+        self.bodies.iter().position(|&b| b == id)
     }
 }
 
@@ -566,10 +969,19 @@ fn body_def_from_runtime(world: &World, id: BodyId) -> crate::body::BodyDef {
         .linear_damping(crate::body::body_linear_damping_impl(id))
         .angular_damping(crate::body::body_angular_damping_impl(id))
         .gravity_scale(crate::body::body_gravity_scale_impl(id))
+        .enable_sleep(crate::body::body_is_sleep_enabled_impl(id))
+        .awake(crate::body::body_is_awake_impl(id))
+        .bullet(crate::body::body_is_bullet_impl(id))
+        .enabled(crate::body::body_is_enabled_impl(id))
         .build()
 }
 
-fn shapes_from_body(world: &World, body: BodyId) -> Vec<ShapeInstance> {
+fn shapes_from_body(
+    world: &World,
+    body: BodyId,
+    ids: &mut Vec<ShapeId>,
+    on_entry: &mut impl FnMut(SnapshotEntry<'_>),
+) -> Vec<ShapeInstance> {
     crate::core::debug_checks::assert_body_valid(body);
     let mut out = Vec::new();
     for sid in world.body_shapes(body) {
@@ -641,11 +1053,70 @@ fn shapes_from_body(world: &World, body: BodyId) -> Vec<ShapeInstance> {
                 continue;
             }
         };
+        let mut extensions = Extensions::new();
+        on_entry(SnapshotEntry::Shape {
+            id: sid,
+            body,
+            extensions: &mut extensions,
+        });
         out.push(ShapeInstance {
             def: sdef,
             sensor: is_sensor,
             geom,
+            extensions,
         });
+        ids.push(sid);
+    }
+    out
+}
+
+fn contacts_from_shapes(shape_refs: &HashMap<ShapeId, ShapeRef>) -> Vec<ContactRecord> {
+    // Sort by snapshot index so the captured contact list is deterministic.
+    let mut shapes: Vec<(ShapeId, ShapeRef)> = shape_refs.iter().map(|(&k, &v)| (k, v)).collect();
+    shapes.sort_by_key(|&(_, r)| (r.body, r.shape));
+    let mut seen = std::collections::HashSet::new();
+    let mut out = Vec::new();
+    for (sid, _) in shapes {
+        for cd in crate::shapes::shape_contact_data_impl(sid) {
+            if cd.manifold.point_count <= 0 || !seen.insert(cd.contact_id) {
+                continue;
+            }
+            let (Some(&shape_a), Some(&shape_b)) = (
+                shape_refs.get(&cd.shape_id_a),
+                shape_refs.get(&cd.shape_id_b),
+            ) else {
+                continue;
+            };
+            out.push(ContactRecord {
+                shape_a,
+                shape_b,
+                manifold: cd.manifold,
+            });
+        }
+    }
+    out
+}
+
+fn sensor_overlaps_from_shapes(
+    shape_refs: &HashMap<ShapeId, ShapeRef>,
+) -> Vec<SensorOverlapRecord> {
+    let mut sensors: Vec<(ShapeId, ShapeRef)> = shape_refs
+        .iter()
+        .filter(|&(&sid, _)| crate::shapes::shape_is_sensor_impl(sid))
+        .map(|(&k, &v)| (k, v))
+        .collect();
+    sensors.sort_by_key(|&(_, r)| (r.body, r.shape));
+    let mut out = Vec::new();
+    for (sid, sensor) in sensors {
+        let mut visitors: Vec<ShapeRef> = crate::shapes::shape_sensor_overlaps_impl(sid)
+            .into_iter()
+            .filter_map(|v| shape_refs.get(&v).copied())
+            .collect();
+        if visitors.is_empty() {
+            continue;
+        }
+        visitors.sort_by_key(|r| (r.body, r.shape));
+        out.push(SensorOverlapRecord { sensor, visitors });
     }
     out
 }
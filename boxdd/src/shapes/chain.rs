@@ -2,6 +2,7 @@ use std::marker::PhantomData;
 
 use crate::body::{Body, OwnedBody};
 use crate::error::{ApiError, ApiResult};
+use crate::query::Aabb;
 use crate::shapes::SurfaceMaterial;
 use crate::types::{BodyId, ChainId, ShapeId, Vec2};
 use crate::world::World;
@@ -72,7 +73,7 @@ fn chain_segments_into_impl(id: ChainId, out: &mut Vec<ShapeId>) {
     }
 }
 
-fn chain_segments_impl(id: ChainId) -> Vec<ShapeId> {
+pub(crate) fn chain_segments_impl(id: ChainId) -> Vec<ShapeId> {
     let id = raw_chain_id(id);
     let count = unsafe { ffi::b2Chain_GetSegmentCount(id) }.max(0) as usize;
     unsafe {
@@ -92,7 +93,7 @@ fn try_chain_segment_count_impl(id: ChainId) -> ApiResult<i32> {
     Ok(chain_segment_count_impl(id))
 }
 
-fn chain_segments_checked_impl(id: ChainId) -> Vec<ShapeId> {
+pub(crate) fn chain_segments_checked_impl(id: ChainId) -> Vec<ShapeId> {
     crate::core::debug_checks::assert_chain_valid(id);
     chain_segments_impl(id)
 }
@@ -102,7 +103,7 @@ fn chain_segments_into_checked_impl(id: ChainId, out: &mut Vec<ShapeId>) {
     chain_segments_into_impl(id, out);
 }
 
-fn try_chain_segments_impl(id: ChainId) -> ApiResult<Vec<ShapeId>> {
+pub(crate) fn try_chain_segments_impl(id: ChainId) -> ApiResult<Vec<ShapeId>> {
     crate::core::debug_checks::check_chain_valid(id)?;
     Ok(chain_segments_impl(id))
 }
@@ -220,23 +221,27 @@ fn check_chain_surface_material_index_in_range(id: ChainId, index: i32) -> ApiRe
     }
 }
 
-fn chain_surface_material_count_checked_impl(id: ChainId) -> i32 {
+pub(crate) fn chain_surface_material_count_checked_impl(id: ChainId) -> i32 {
     crate::core::debug_checks::assert_chain_valid(id);
     chain_surface_material_count_impl(id)
 }
 
-fn try_chain_surface_material_count_impl(id: ChainId) -> ApiResult<i32> {
+pub(crate) fn try_chain_surface_material_count_impl(id: ChainId) -> ApiResult<i32> {
     crate::core::debug_checks::check_chain_valid(id)?;
     Ok(chain_surface_material_count_impl(id))
 }
 
-fn chain_set_surface_material_checked_impl(id: ChainId, index: i32, material: &SurfaceMaterial) {
+pub(crate) fn chain_set_surface_material_checked_impl(
+    id: ChainId,
+    index: i32,
+    material: &SurfaceMaterial,
+) {
     crate::core::debug_checks::assert_chain_valid(id);
     assert_chain_surface_material_index_in_range(id, index);
     chain_set_surface_material_impl(id, index, material)
 }
 
-fn try_chain_set_surface_material_impl(
+pub(crate) fn try_chain_set_surface_material_impl(
     id: ChainId,
     index: i32,
     material: &SurfaceMaterial,
@@ -247,25 +252,90 @@ fn try_chain_set_surface_material_impl(
     Ok(())
 }
 
-fn chain_surface_material_checked_impl(id: ChainId, index: i32) -> SurfaceMaterial {
+pub(crate) fn chain_surface_material_checked_impl(id: ChainId, index: i32) -> SurfaceMaterial {
     crate::core::debug_checks::assert_chain_valid(id);
     assert_chain_surface_material_index_in_range(id, index);
     chain_surface_material_impl(id, index)
 }
 
-fn try_chain_surface_material_impl(id: ChainId, index: i32) -> ApiResult<SurfaceMaterial> {
+pub(crate) fn try_chain_surface_material_impl(
+    id: ChainId,
+    index: i32,
+) -> ApiResult<SurfaceMaterial> {
     crate::core::debug_checks::check_chain_valid(id)?;
     check_chain_surface_material_index_in_range(id, index)?;
     Ok(chain_surface_material_impl(id, index))
 }
 
+fn chain_update_surface_materials_impl(
+    id: ChainId,
+    f: impl Fn(SurfaceMaterial) -> SurfaceMaterial,
+) {
+    for index in 0..chain_surface_material_count_impl(id) {
+        let material = f(chain_surface_material_impl(id, index));
+        chain_set_surface_material_impl(id, index, &material);
+    }
+}
+
+pub(crate) fn chain_set_friction_checked_impl(id: ChainId, friction: f32) {
+    crate::core::debug_checks::assert_chain_valid(id);
+    crate::shapes::assert_non_negative_finite_shape_scalar("friction", friction);
+    chain_update_surface_materials_impl(id, |m| m.with_friction(friction));
+}
+
+pub(crate) fn try_chain_set_friction_impl(id: ChainId, friction: f32) -> ApiResult<()> {
+    crate::core::debug_checks::check_chain_valid(id)?;
+    crate::shapes::check_non_negative_finite_shape_scalar(friction)?;
+    chain_update_surface_materials_impl(id, |m| m.with_friction(friction));
+    Ok(())
+}
+
+pub(crate) fn chain_set_restitution_checked_impl(id: ChainId, restitution: f32) {
+    crate::core::debug_checks::assert_chain_valid(id);
+    crate::shapes::assert_non_negative_finite_shape_scalar("restitution", restitution);
+    chain_update_surface_materials_impl(id, |m| m.with_restitution(restitution));
+}
+
+pub(crate) fn try_chain_set_restitution_impl(id: ChainId, restitution: f32) -> ApiResult<()> {
+    crate::core::debug_checks::check_chain_valid(id)?;
+    crate::shapes::check_non_negative_finite_shape_scalar(restitution)?;
+    chain_update_surface_materials_impl(id, |m| m.with_restitution(restitution));
+    Ok(())
+}
+
+fn chain_aabb_impl(id: ChainId) -> Aabb {
+    let mut segments = chain_segments_impl(id).into_iter();
+    let Some(first) = segments.next() else {
+        return Aabb::new(Vec2::ZERO, Vec2::ZERO);
+    };
+    segments.fold(crate::shapes::shape_aabb_impl(first), |acc, segment| {
+        let b = crate::shapes::shape_aabb_impl(segment);
+        Aabb {
+            lower: Vec2::new(acc.lower.x.min(b.lower.x), acc.lower.y.min(b.lower.y)),
+            upper: Vec2::new(acc.upper.x.max(b.upper.x), acc.upper.y.max(b.upper.y)),
+        }
+    })
+}
+
+pub(crate) fn chain_aabb_checked_impl(id: ChainId) -> Aabb {
+    crate::core::debug_checks::assert_chain_valid(id);
+    chain_aabb_impl(id)
+}
+
+pub(crate) fn try_chain_aabb_impl(id: ChainId) -> ApiResult<Aabb> {
+    crate::core::debug_checks::check_chain_valid(id)?;
+    Ok(chain_aabb_impl(id))
+}
+
 #[inline]
 fn destroy_chain_now_impl(world_core: &crate::core::world_core::WorldCore, id: ChainId) {
+    let destroyed = world_core.destroyed_with_chain(id);
+    world_core.forget_chain_shapes(id);
+    world_core.bump_static_revision();
     unsafe { ffi::b2DestroyChain(raw_chain_id(id)) }
     #[cfg(feature = "serialize")]
     world_core.remove_chain(id);
-    #[cfg(not(feature = "serialize"))]
-    let _ = world_core;
+    world_core.notify_destroyed(&destroyed);
 }
 
 fn destroy_owned_chain_if_needed_impl(
@@ -371,6 +441,30 @@ trait ChainRuntimeHandle {
     fn try_handle_surface_material(&self, index: i32) -> ApiResult<SurfaceMaterial> {
         try_chain_surface_material_impl(self.chain_id(), index)
     }
+
+    fn handle_set_friction(&mut self, friction: f32) {
+        chain_set_friction_checked_impl(self.chain_id(), friction)
+    }
+
+    fn try_handle_set_friction(&mut self, friction: f32) -> ApiResult<()> {
+        try_chain_set_friction_impl(self.chain_id(), friction)
+    }
+
+    fn handle_set_restitution(&mut self, restitution: f32) {
+        chain_set_restitution_checked_impl(self.chain_id(), restitution)
+    }
+
+    fn try_handle_set_restitution(&mut self, restitution: f32) -> ApiResult<()> {
+        try_chain_set_restitution_impl(self.chain_id(), restitution)
+    }
+
+    fn handle_aabb(&self) -> Aabb {
+        chain_aabb_checked_impl(self.chain_id())
+    }
+
+    fn try_handle_aabb(&self) -> ApiResult<Aabb> {
+        try_chain_aabb_impl(self.chain_id())
+    }
 }
 
 impl ChainRuntimeHandle for OwnedChain {
@@ -477,6 +571,33 @@ impl OwnedChain {
         ChainRuntimeHandle::try_handle_surface_material(self, index)
     }
 
+    /// Set the friction of every material slot, keeping the other material fields.
+    pub fn set_friction(&mut self, friction: f32) {
+        ChainRuntimeHandle::handle_set_friction(self, friction)
+    }
+
+    pub fn try_set_friction(&mut self, friction: f32) -> ApiResult<()> {
+        ChainRuntimeHandle::try_handle_set_friction(self, friction)
+    }
+
+    /// Set the restitution of every material slot, keeping the other material fields.
+    pub fn set_restitution(&mut self, restitution: f32) {
+        ChainRuntimeHandle::handle_set_restitution(self, restitution)
+    }
+
+    pub fn try_set_restitution(&mut self, restitution: f32) -> ApiResult<()> {
+        ChainRuntimeHandle::try_handle_set_restitution(self, restitution)
+    }
+
+    /// Union of the segments' world AABBs.
+    pub fn aabb(&self) -> Aabb {
+        ChainRuntimeHandle::handle_aabb(self)
+    }
+
+    pub fn try_aabb(&self) -> ApiResult<Aabb> {
+        ChainRuntimeHandle::try_handle_aabb(self)
+    }
+
     pub fn into_id(mut self) -> ChainId {
         self.destroy_on_drop = false;
         self.id
@@ -591,6 +712,33 @@ impl<'w> Chain<'w> {
         ChainRuntimeHandle::try_handle_surface_material(self, index)
     }
 
+    /// Set the friction of every material slot, keeping the other material fields.
+    pub fn set_friction(&mut self, friction: f32) {
+        ChainRuntimeHandle::handle_set_friction(self, friction)
+    }
+
+    pub fn try_set_friction(&mut self, friction: f32) -> ApiResult<()> {
+        ChainRuntimeHandle::try_handle_set_friction(self, friction)
+    }
+
+    /// Set the restitution of every material slot, keeping the other material fields.
+    pub fn set_restitution(&mut self, restitution: f32) {
+        ChainRuntimeHandle::handle_set_restitution(self, restitution)
+    }
+
+    pub fn try_set_restitution(&mut self, restitution: f32) -> ApiResult<()> {
+        ChainRuntimeHandle::try_handle_set_restitution(self, restitution)
+    }
+
+    /// Union of the segments' world AABBs.
+    pub fn aabb(&self) -> Aabb {
+        ChainRuntimeHandle::handle_aabb(self)
+    }
+
+    pub fn try_aabb(&self) -> ApiResult<Aabb> {
+        ChainRuntimeHandle::try_handle_aabb(self)
+    }
+
     /// Destroy this chain immediately.
     pub fn destroy(self) {
         destroy_scoped_chain_checked_impl(&self.core, self.id);
@@ -791,7 +939,7 @@ impl From<ChainDef> for ChainDefBuilder {
 pub(crate) fn assert_chain_def_valid(def: &ChainDef) {
     let count = def.def.count;
     assert!(
-        count >= 4,
+        count >= crate::limits::MIN_CHAIN_POINTS as i32,
         "invalid ChainDef: expected at least 4 points (including ghosts), got {count}"
     );
     assert!(
@@ -811,7 +959,7 @@ pub(crate) fn assert_chain_def_valid(def: &ChainDef) {
 
 pub(crate) fn check_chain_def_valid(def: &ChainDef) -> ApiResult<()> {
     let count = def.def.count;
-    if count < 4 {
+    if count < crate::limits::MIN_CHAIN_POINTS as i32 {
         return Err(ApiError::InvalidChainDef);
     }
     if def.def.points.is_null() {
@@ -834,6 +982,7 @@ pub(crate) fn create_chain_for_body_impl(
 ) -> ChainId {
     crate::core::debug_checks::assert_body_valid(body);
     assert_chain_def_valid(def);
+    core.note_body_shapes_change(body);
     let id = ChainId::from_raw(unsafe { ffi::b2CreateChain(body.into_raw(), &def.def) });
     #[cfg(feature = "serialize")]
     {
@@ -852,6 +1001,7 @@ pub(crate) fn try_create_chain_for_body_impl(
 ) -> ApiResult<ChainId> {
     crate::core::debug_checks::check_body_valid(body)?;
     check_chain_def_valid(def)?;
+    core.note_body_shapes_change(body);
     let id = ChainId::from_raw(unsafe { ffi::b2CreateChain(body.into_raw(), &def.def) });
     #[cfg(feature = "serialize")]
     {
@@ -1,4 +1,4 @@
-use crate::debug_draw::HexColor;
+use crate::color::HexColor;
 use crate::error::ApiResult;
 use crate::filter::Filter;
 use boxdd_sys::ffi;
@@ -112,11 +112,28 @@ impl PartialEq for SurfaceMaterial {
 #[doc(alias = "shape_def")]
 #[doc(alias = "shapedef")]
 #[derive(Clone, Debug)]
-pub struct ShapeDef(pub(crate) ffi::b2ShapeDef);
+pub struct ShapeDef(
+    pub(crate) ffi::b2ShapeDef,
+    /// Target mass in kg; when set, density is derived from the geometry's area at creation.
+    pub(crate) Option<f32>,
+    /// Event flags set explicitly; the others take the world's defaults at creation.
+    pub(crate) EventFlagsSet,
+);
+
+/// Which event flags of a [`ShapeDef`] were set explicitly, through the builder or a raw value.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub(crate) struct EventFlagsSet {
+    pub(crate) contact_events: bool,
+    pub(crate) hit_events: bool,
+}
 
 impl Default for ShapeDef {
     fn default() -> Self {
-        Self(unsafe { ffi::b2DefaultShapeDef() })
+        Self(
+            unsafe { ffi::b2DefaultShapeDef() },
+            None,
+            EventFlagsSet::default(),
+        )
     }
 }
 
@@ -131,7 +148,14 @@ impl ShapeDef {
     /// Construct from the raw Box2D shape definition value.
     #[inline]
     pub fn from_raw(raw: ffi::b2ShapeDef) -> Self {
-        Self(raw)
+        Self(
+            raw,
+            None,
+            EventFlagsSet {
+                contact_events: true,
+                hit_events: true,
+            },
+        )
     }
 
     /// Surface material parameters used by the shape.
@@ -141,11 +165,19 @@ impl ShapeDef {
     }
 
     /// Density in kg/m².
+    ///
+    /// Ignored at creation when [`target_mass`](Self::target_mass) is set.
     #[inline]
     pub const fn density(&self) -> f32 {
         self.0.density
     }
 
+    /// Mass in kg requested through [`ShapeDefBuilder::mass`], if any.
+    #[inline]
+    pub const fn target_mass(&self) -> Option<f32> {
+        self.1
+    }
+
     /// Collision filter used by the shape.
     #[inline]
     pub const fn filter(&self) -> Filter {
@@ -171,12 +203,18 @@ impl ShapeDef {
     }
 
     /// Whether contact begin/end events are enabled for the shape.
+    ///
+    /// Unless set through [`ShapeDefBuilder::enable_contact_events`] or [`Self::from_raw`], the
+    /// world's `WorldBuilder::default_enable_contact_events` replaces this at creation.
     #[inline]
     pub const fn contact_events_enabled(&self) -> bool {
         self.0.enableContactEvents
     }
 
     /// Whether hit events are enabled for the shape.
+    ///
+    /// Unless set through [`ShapeDefBuilder::enable_hit_events`] or [`Self::from_raw`], the
+    /// world's `WorldBuilder::default_enable_hit_events` replaces this at creation.
     #[inline]
     pub const fn hit_events_enabled(&self) -> bool {
         self.0.enableHitEvents
@@ -201,6 +239,9 @@ impl ShapeDef {
     }
 
     /// Convert into the raw Box2D shape definition value.
+    ///
+    /// A [`target_mass`](Self::target_mass) is not representable in the raw value and is dropped;
+    /// the raw density is used as-is.
     #[inline]
     pub fn into_raw(self) -> ffi::b2ShapeDef {
         self.0
@@ -225,9 +266,20 @@ impl ShapeDefBuilder {
         self.def.0.material = mat.0;
         self
     }
-    /// Density in kg/m². Affects mass.
+    /// Density in kg/m². Affects mass. Replaces any earlier [`mass`](Self::mass).
     pub fn density(mut self, v: f32) -> Self {
         self.def.0.density = v;
+        self.def.1 = None;
+        self
+    }
+    /// Target mass in kg, e.g. "this crate weighs 10 kg". Replaces any earlier
+    /// [`density`](Self::density).
+    ///
+    /// Density is computed when the shape is created, as `mass / area` of the geometry it is
+    /// created with, so one definition can be reused for shapes of different sizes that should all
+    /// weigh the same. Segments have no area and stay massless.
+    pub fn mass(mut self, kg: f32) -> Self {
+        self.def.1 = Some(kg);
         self
     }
     /// Collision filter (category/mask/group).
@@ -258,14 +310,18 @@ impl ShapeDefBuilder {
         self.def.0.enableSensorEvents = flag;
         self
     }
-    /// Emit contact begin/end events.
+    /// Emit contact begin/end events. Overrides the world's
+    /// `WorldBuilder::default_enable_contact_events`.
     pub fn enable_contact_events(mut self, flag: bool) -> Self {
         self.def.0.enableContactEvents = flag;
+        self.def.2.contact_events = true;
         self
     }
-    /// Emit impact hit events when above threshold.
+    /// Emit impact hit events when above threshold. Overrides the world's
+    /// `WorldBuilder::default_enable_hit_events`.
     pub fn enable_hit_events(mut self, flag: bool) -> Self {
         self.def.0.enableHitEvents = flag;
+        self.def.2.hit_events = true;
         self
     }
     /// Emit pre-solve events (advanced).
@@ -290,6 +346,13 @@ impl ShapeDefBuilder {
     pub fn build(self) -> ShapeDef {
         self.def
     }
+
+    /// Build the definition, running the same checks as shape creation and reporting the first
+    /// failing field. `build()` skips these checks.
+    pub fn build_checked(self) -> Result<ShapeDef, crate::error::ValidationError> {
+        super::validate_shape_def(&self.def)?;
+        Ok(self.def)
+    }
 }
 
 impl From<ShapeDef> for ShapeDefBuilder {
@@ -379,6 +442,7 @@ impl serde::Serialize for ShapeDef {
             enable_pre_solve_events: bool,
             invoke_contact_creation: bool,
             update_body_mass: bool,
+            mass: Option<f32>,
         }
         let r = Repr {
             material: SurfaceMaterial::from_raw(self.0.material),
@@ -392,6 +456,7 @@ impl serde::Serialize for ShapeDef {
             enable_pre_solve_events: self.0.enablePreSolveEvents,
             invoke_contact_creation: self.0.invokeContactCreation,
             update_body_mass: self.0.updateBodyMass,
+            mass: self.1,
         };
         r.serialize(serializer)
     }
@@ -427,6 +492,8 @@ impl<'de> serde::Deserialize<'de> for ShapeDef {
             invoke_contact_creation: bool,
             #[serde(default)]
             update_body_mass: bool,
+            #[serde(default)]
+            mass: Option<f32>,
         }
         let r = Repr::deserialize(deserializer)?;
         let mut b = ShapeDef::builder();
@@ -446,6 +513,9 @@ impl<'de> serde::Deserialize<'de> for ShapeDef {
             .enable_pre_solve_events(r.enable_pre_solve_events)
             .invoke_contact_creation(r.invoke_contact_creation)
             .update_body_mass(r.update_body_mass);
+        if let Some(mass) = r.mass {
+            b = b.mass(mass);
+        }
         Ok(b.build())
     }
 }
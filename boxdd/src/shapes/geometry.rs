@@ -12,6 +12,7 @@ use smallvec::SmallVec;
 mod capsule;
 mod chain_segment;
 mod circle;
+mod hull;
 mod polygon;
 mod segment;
 
@@ -68,43 +69,84 @@ fn raw_ray_input_is_valid(input: &ffi::b2RayCastInput) -> bool {
     unsafe { ffi::b2IsValidRay(input) }
 }
 
+/// Collect hull input points, rejecting invalid points and more than `MAX_POLYGON_VERTICES`
+/// points (or reducing them with the `polygon-simplify` feature).
 #[inline]
 fn collect_polygon_points<I, P>(
     points: I,
-) -> Option<SmallVec<[ffi::b2Vec2; MAX_POLYGON_INPUT_POINTS]>>
+) -> ApiResult<SmallVec<[ffi::b2Vec2; MAX_POLYGON_INPUT_POINTS]>>
 where
     I: IntoIterator<Item = P>,
     P: Into<Vec2>,
 {
     let mut pts: SmallVec<[ffi::b2Vec2; MAX_POLYGON_INPUT_POINTS]> =
         SmallVec::with_capacity(MAX_POLYGON_INPUT_POINTS);
-    for point in points {
-        if pts.len() == MAX_POLYGON_INPUT_POINTS {
-            return None;
-        }
+    let mut points = points.into_iter();
+    for point in points.by_ref() {
         let point = point.into();
         if !point.is_valid() {
-            return None;
+            return Err(ApiError::InvalidArgument);
         }
         pts.push(point.into_raw());
+        if pts.len() > MAX_POLYGON_VERTICES {
+            return reduce_polygon_points(pts, points);
+        }
     }
 
-    if pts.is_empty() || pts.len() > MAX_POLYGON_VERTICES {
-        return None;
+    if pts.is_empty() {
+        return Err(ApiError::InvalidArgument);
     }
 
-    Some(pts)
+    Ok(pts)
 }
 
+#[cfg(not(feature = "polygon-simplify"))]
 #[inline]
-fn compute_hull_from_points<I, P>(points: I) -> Option<ffi::b2Hull>
+fn reduce_polygon_points<I, P>(
+    _head: SmallVec<[ffi::b2Vec2; MAX_POLYGON_INPUT_POINTS]>,
+    _rest: I,
+) -> ApiResult<SmallVec<[ffi::b2Vec2; MAX_POLYGON_INPUT_POINTS]>>
+where
+    I: Iterator<Item = P>,
+    P: Into<Vec2>,
+{
+    Err(ApiError::TooManyPolygonVertices)
+}
+
+#[cfg(feature = "polygon-simplify")]
+fn reduce_polygon_points<I, P>(
+    head: SmallVec<[ffi::b2Vec2; MAX_POLYGON_INPUT_POINTS]>,
+    rest: I,
+) -> ApiResult<SmallVec<[ffi::b2Vec2; MAX_POLYGON_INPUT_POINTS]>>
+where
+    I: Iterator<Item = P>,
+    P: Into<Vec2>,
+{
+    let mut all: Vec<Vec2> = head.into_iter().map(Vec2::from_raw).collect();
+    for point in rest {
+        let point = point.into();
+        if !point.is_valid() {
+            return Err(ApiError::InvalidArgument);
+        }
+        all.push(point);
+    }
+    let reduced = super::simplify::reduce_to_convex(&all, MAX_POLYGON_VERTICES);
+    Ok(reduced.into_iter().map(Vec2::into_raw).collect())
+}
+
+#[inline]
+fn compute_hull_from_points<I, P>(points: I) -> ApiResult<ffi::b2Hull>
 where
     I: IntoIterator<Item = P>,
     P: Into<Vec2>,
 {
     let pts = collect_polygon_points(points)?;
     let hull = unsafe { ffi::b2ComputeHull(pts.as_ptr(), pts.len() as i32) };
-    (hull.count > 0).then_some(hull)
+    if hull.count > 0 {
+        Ok(hull)
+    } else {
+        Err(ApiError::InvalidArgument)
+    }
 }
 
 #[inline]
@@ -124,7 +166,7 @@ fn geometry_density_is_valid(value: f32) -> bool {
 
 #[inline]
 fn minimum_shape_segment_length_squared() -> f32 {
-    let linear_slop = 0.005 * crate::length_units_per_meter();
+    let linear_slop = crate::tuning::linear_slop();
     linear_slop * linear_slop
 }
 
@@ -295,7 +337,7 @@ where
     I: IntoIterator<Item = P>,
     P: Into<Vec2>,
 {
-    compute_hull_from_points(points).ok_or(ApiError::InvalidArgument)
+    compute_hull_from_points(points)
 }
 
 /// Circle geometry in local shape space.
@@ -349,6 +391,50 @@ pub struct Polygon {
     raw: ffi::b2Polygon,
 }
 
+/// Convex hull of a point set, as computed by [`compute_hull`].
+///
+/// Box2D welds close points and drops collinear ones, so [`points`](Self::points) may hold fewer
+/// points than the input, in counter-clockwise order. Turn it into shape geometry with
+/// [`to_polygon`](Self::to_polygon).
+#[derive(Copy, Clone)]
+pub struct Hull {
+    raw: ffi::b2Hull,
+}
+
+/// Any solid geometry a shape can be created from, for APIs that take a mix of shape kinds such
+/// as [`World::create_body_with_shapes`](crate::World::create_body_with_shapes).
+#[derive(Copy, Clone, Debug)]
+pub enum Geometry {
+    Circle(Circle),
+    Segment(Segment),
+    Capsule(Capsule),
+    Polygon(Polygon),
+}
+
+impl From<Circle> for Geometry {
+    fn from(v: Circle) -> Self {
+        Self::Circle(v)
+    }
+}
+
+impl From<Segment> for Geometry {
+    fn from(v: Segment) -> Self {
+        Self::Segment(v)
+    }
+}
+
+impl From<Capsule> for Geometry {
+    fn from(v: Capsule) -> Self {
+        Self::Capsule(v)
+    }
+}
+
+impl From<Polygon> for Geometry {
+    fn from(v: Polygon) -> Self {
+        Self::Polygon(v)
+    }
+}
+
 /// Circle helper.
 #[inline]
 pub fn circle<C: Into<Vec2>>(center: C, radius: f32) -> Circle {
@@ -435,6 +521,46 @@ pub fn try_offset_box_polygon(
     Polygon::try_offset_box_polygon(half_width, half_height, transform)
 }
 
+/// Box polygon centered at `center` in body space and rotated by `angle` radians.
+///
+/// Shorthand for [`offset_box_polygon`] when building compound bodies, e.g. a bumper box on a car
+/// chassis.
+#[inline]
+pub fn offset_box<C: Into<Vec2>>(
+    half_width: f32,
+    half_height: f32,
+    center: C,
+    angle: f32,
+) -> Polygon {
+    offset_box_polygon(
+        half_width,
+        half_height,
+        Transform::from_pos_angle(center, angle),
+    )
+}
+
+/// Recoverable version of [`offset_box`].
+#[inline]
+pub fn try_offset_box<C: Into<Vec2>>(
+    half_width: f32,
+    half_height: f32,
+    center: C,
+    angle: f32,
+) -> ApiResult<Polygon> {
+    try_offset_box_polygon(
+        half_width,
+        half_height,
+        Transform::from_pos_angle(center, angle),
+    )
+}
+
+/// Circle centered at `center` in body space; the same as [`circle`], named to pair with
+/// [`offset_box`].
+#[inline]
+pub fn offset_circle<C: Into<Vec2>>(center: C, radius: f32) -> Circle {
+    Circle::new(center, radius)
+}
+
 /// Offset rounded box polygon helper using the crate's `Transform` vocabulary.
 #[inline]
 pub fn offset_rounded_box_polygon(
@@ -458,6 +584,10 @@ pub fn try_offset_rounded_box_polygon(
 }
 
 /// Build a polygon from arbitrary points by computing a convex hull.
+///
+/// At most [`MAX_POLYGON_VERTICES`] input points are accepted; returns `None` for more points,
+/// invalid points, or degenerate hulls. Use [`try_polygon_from_points`] to tell these apart, or
+/// enable the `polygon-simplify` feature to reduce larger inputs automatically.
 #[inline]
 pub fn polygon_from_points<I, P>(points: I, radius: f32) -> Option<Polygon>
 where
@@ -468,6 +598,10 @@ where
 }
 
 /// Recoverably build a polygon from arbitrary points by computing a convex hull.
+///
+/// Returns `ApiError::TooManyPolygonVertices` for more than [`MAX_POLYGON_VERTICES`] points
+/// (unless the `polygon-simplify` feature reduces them), and `ApiError::InvalidArgument` for
+/// invalid points or degenerate hulls.
 #[inline]
 pub fn try_polygon_from_points<I, P>(points: I, radius: f32) -> ApiResult<Polygon>
 where
@@ -505,6 +639,49 @@ where
     Polygon::try_offset_from_points(points, radius, transform)
 }
 
+/// Compute the convex hull of up to [`MAX_POLYGON_VERTICES`] points.
+///
+/// Returns `None` for more points (unless the `polygon-simplify` feature reduces them), invalid
+/// points, or degenerate hulls such as collinear or coincident points.
+///
+/// ```no_run
+/// use boxdd::shapes;
+/// let hull = shapes::compute_hull([[0.0, 0.0], [2.0, 0.0], [1.0, 0.5], [2.0, 2.0], [0.0, 2.0]])
+///     .expect("non-degenerate points");
+/// assert_eq!(hull.points().len(), 4);
+/// let rounded = hull.to_polygon(0.05);
+/// assert!(shapes::validate_polygon(&rounded).is_ok());
+/// ```
+#[inline]
+pub fn compute_hull<I, P>(points: I) -> Option<Hull>
+where
+    I: IntoIterator<Item = P>,
+    P: Into<Vec2>,
+{
+    try_compute_hull(points).ok()
+}
+
+/// Recoverably compute a convex hull; see [`compute_hull`].
+#[inline]
+pub fn try_compute_hull<I, P>(points: I) -> ApiResult<Hull>
+where
+    I: IntoIterator<Item = P>,
+    P: Into<Vec2>,
+{
+    try_compute_hull_from_points(points).map(|raw| Hull { raw })
+}
+
+/// Check that `polygon` is usable as shape geometry: a valid convex hull with finite vertices,
+/// normals, centroid, and a non-negative radius.
+#[inline]
+pub fn validate_polygon(polygon: &Polygon) -> ApiResult<()> {
+    polygon.validate()?;
+    let hull = try_compute_hull_from_points(polygon.vertices().iter().copied())?;
+    geometry_is_valid_or_err(
+        hull.count as usize == polygon.count() && unsafe { ffi::b2ValidateHull(&hull) },
+    )
+}
+
 /// Check whether a point set can produce a valid Box2D convex hull.
 #[inline]
 pub fn polygon_hull_is_valid<I, P>(points: I) -> bool
@@ -0,0 +1,62 @@
+use super::*;
+
+impl Hull {
+    /// Hull points in counter-clockwise order.
+    #[inline]
+    pub fn points(&self) -> &[Vec2] {
+        let count = self.raw.count.clamp(0, MAX_POLYGON_VERTICES as i32) as usize;
+        unsafe { core::slice::from_raw_parts(self.raw.points.as_ptr().cast::<Vec2>(), count) }
+    }
+
+    /// Whether Box2D accepts this hull: convex, counter-clockwise, without collinear points.
+    #[inline]
+    pub fn is_valid(&self) -> bool {
+        unsafe { ffi::b2ValidateHull(&self.raw) }
+    }
+
+    /// Polygon with these vertices, rounded by `radius`.
+    #[inline]
+    pub fn to_polygon(&self, radius: f32) -> Polygon {
+        assert_non_negative_finite_polygon_scalar("radius", radius);
+        Polygon::from_raw(unsafe { ffi::b2MakePolygon(&self.raw, radius) })
+    }
+
+    #[inline]
+    pub fn try_to_polygon(&self, radius: f32) -> ApiResult<Polygon> {
+        check_non_negative_finite_polygon_scalar(radius)?;
+        Ok(Polygon::from_raw(unsafe {
+            ffi::b2MakePolygon(&self.raw, radius)
+        }))
+    }
+
+    /// Polygon with these vertices moved by `transform` into body space, rounded by `radius`.
+    #[inline]
+    pub fn to_offset_polygon(&self, radius: f32, transform: Transform) -> Polygon {
+        assert_non_negative_finite_polygon_scalar("radius", radius);
+        assert_transform_valid(transform);
+        self.offset_polygon_unchecked(radius, transform)
+    }
+
+    #[inline]
+    pub fn try_to_offset_polygon(&self, radius: f32, transform: Transform) -> ApiResult<Polygon> {
+        check_non_negative_finite_polygon_scalar(radius)?;
+        check_transform_valid(transform)?;
+        Ok(self.offset_polygon_unchecked(radius, transform))
+    }
+
+    fn offset_polygon_unchecked(&self, radius: f32, transform: Transform) -> Polygon {
+        let (p, q) = (
+            transform.position().into_raw(),
+            transform.rotation().into_raw(),
+        );
+        Polygon::from_raw(unsafe { ffi::b2MakeOffsetRoundedPolygon(&self.raw, p, q, radius) })
+    }
+}
+
+impl fmt::Debug for Hull {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Hull")
+            .field("points", &self.points())
+            .finish()
+    }
+}
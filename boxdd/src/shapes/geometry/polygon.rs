@@ -257,7 +257,7 @@ impl Polygon {
         I: IntoIterator<Item = P>,
         P: Into<Vec2>,
     {
-        compute_hull_from_points(points).is_some_and(|hull| unsafe { ffi::b2ValidateHull(&hull) })
+        compute_hull_from_points(points).is_ok_and(|hull| unsafe { ffi::b2ValidateHull(&hull) })
     }
 
     #[inline]
@@ -5,3 +5,4 @@ pub use super::geometry::{
     try_offset_rounded_box_polygon, try_polygon_from_points, try_rounded_box_polygon,
     try_square_polygon,
 };
+pub use super::simplify::{simplify, try_simplify, try_weld_close_points, weld_close_points};
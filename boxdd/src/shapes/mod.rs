@@ -13,6 +13,7 @@ pub mod helpers;
 mod owned;
 mod runtime;
 mod scoped;
+mod simplify;
 
 use crate::body::{Body, OwnedBody};
 use crate::collision::CastOutput;
@@ -29,15 +30,17 @@ pub(crate) use runtime::*;
 
 pub use definition::{ShapeDef, ShapeDefBuilder, SurfaceMaterial};
 pub use geometry::{
-    Capsule, ChainSegment, Circle, MAX_POLYGON_VERTICES, Polygon, Segment, box_polygon, capsule,
-    chain_segment, circle, offset_box_polygon, offset_polygon_from_points,
-    offset_rounded_box_polygon, polygon_from_points, polygon_hull_is_valid, rounded_box_polygon,
-    segment, square_polygon, try_box_polygon, try_offset_box_polygon,
-    try_offset_polygon_from_points, try_offset_rounded_box_polygon, try_polygon_from_points,
-    try_rounded_box_polygon, try_square_polygon,
+    Capsule, ChainSegment, Circle, Geometry, Hull, MAX_POLYGON_VERTICES, Polygon, Segment,
+    box_polygon, capsule, chain_segment, circle, compute_hull, offset_box, offset_box_polygon,
+    offset_circle, offset_polygon_from_points, offset_rounded_box_polygon, polygon_from_points,
+    polygon_hull_is_valid, rounded_box_polygon, segment, square_polygon, try_box_polygon,
+    try_compute_hull, try_offset_box, try_offset_box_polygon, try_offset_polygon_from_points,
+    try_offset_rounded_box_polygon, try_polygon_from_points, try_rounded_box_polygon,
+    try_square_polygon, validate_polygon,
 };
 pub use owned::OwnedShape;
 pub use scoped::Shape;
+pub use simplify::{simplify, try_simplify, try_weld_close_points, weld_close_points};
 
 /// Shape kinds reported by Box2D.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -523,10 +523,7 @@ impl OwnedShape {
                         update_body_mass,
                     });
             } else {
-                unsafe { ffi::b2DestroyShape(raw_shape_id(self.id), update_body_mass) };
-                let _ = self.core.clear_shape_user_data(self.id);
-                #[cfg(feature = "serialize")]
-                self.core.remove_shape_flags(self.id);
+                self.core.destroy_shape_now(self.id, update_body_mass);
             }
         }
         self.destroy_on_drop = false;
@@ -550,12 +547,8 @@ impl Drop for OwnedShape {
                         update_body_mass: self.update_body_mass_on_drop,
                     });
             } else {
-                unsafe {
-                    ffi::b2DestroyShape(raw_shape_id(self.id), self.update_body_mass_on_drop)
-                };
-                let _ = self.core.clear_shape_user_data(self.id);
-                #[cfg(feature = "serialize")]
-                self.core.remove_shape_flags(self.id);
+                self.core
+                    .destroy_shape_now(self.id, self.update_body_mass_on_drop);
             }
         }
     }
@@ -74,6 +74,36 @@ pub(crate) fn shape_polygon_impl(id: ShapeId) -> Polygon {
     Polygon::from_raw(unsafe { ffi::b2Shape_GetPolygon(raw_shape_id(id)) })
 }
 
+/// Collision proxy for the shape's geometry in its body's local frame.
+pub(crate) fn shape_local_proxy_impl(id: ShapeId) -> crate::collision::ShapeProxy {
+    let make = |points: &[ffi::b2Vec2], radius: f32| unsafe {
+        ffi::b2MakeProxy(points.as_ptr(), points.len() as i32, radius)
+    };
+    let raw = match shape_type_impl(id) {
+        ShapeType::Circle => {
+            let c = shape_circle_impl(id).into_raw();
+            make(&[c.center], c.radius)
+        }
+        ShapeType::Capsule => {
+            let c = shape_capsule_impl(id).into_raw();
+            make(&[c.center1, c.center2], c.radius)
+        }
+        ShapeType::Segment => {
+            let s = shape_segment_impl(id).into_raw();
+            make(&[s.point1, s.point2], 0.0)
+        }
+        ShapeType::ChainSegment => {
+            let s = shape_chain_segment_impl(id).segment.into_raw();
+            make(&[s.point1, s.point2], 0.0)
+        }
+        ShapeType::Polygon => {
+            let p = shape_polygon_impl(id).into_raw();
+            make(&p.vertices[..p.count as usize], p.radius)
+        }
+    };
+    crate::collision::ShapeProxy::from_raw(raw)
+}
+
 #[inline]
 pub(crate) fn shape_closest_point_impl<V: Into<Vec2>>(id: ShapeId, target: V) -> Vec2 {
     let target: ffi::b2Vec2 = target.into().into_raw();
@@ -126,26 +156,46 @@ pub(crate) fn shape_apply_wind_impl<V: Into<Vec2>>(
 }
 
 #[inline]
-pub(crate) fn shape_set_circle_impl(id: ShapeId, circle: &Circle) {
+pub(crate) fn shape_set_circle_impl(
+    core: &crate::core::world_core::WorldCore,
+    id: ShapeId,
+    circle: &Circle,
+) {
     let raw = circle.into_raw();
+    core.note_shape_change(id);
     unsafe { ffi::b2Shape_SetCircle(raw_shape_id(id), &raw) }
 }
 
 #[inline]
-pub(crate) fn shape_set_segment_impl(id: ShapeId, segment: &Segment) {
+pub(crate) fn shape_set_segment_impl(
+    core: &crate::core::world_core::WorldCore,
+    id: ShapeId,
+    segment: &Segment,
+) {
     let raw = segment.into_raw();
+    core.note_shape_change(id);
     unsafe { ffi::b2Shape_SetSegment(raw_shape_id(id), &raw) }
 }
 
 #[inline]
-pub(crate) fn shape_set_capsule_impl(id: ShapeId, capsule: &Capsule) {
+pub(crate) fn shape_set_capsule_impl(
+    core: &crate::core::world_core::WorldCore,
+    id: ShapeId,
+    capsule: &Capsule,
+) {
     let raw = capsule.into_raw();
+    core.note_shape_change(id);
     unsafe { ffi::b2Shape_SetCapsule(raw_shape_id(id), &raw) }
 }
 
 #[inline]
-pub(crate) fn shape_set_polygon_impl(id: ShapeId, polygon: &Polygon) {
+pub(crate) fn shape_set_polygon_impl(
+    core: &crate::core::world_core::WorldCore,
+    id: ShapeId,
+    polygon: &Polygon,
+) {
     let raw = polygon.into_raw();
+    core.note_shape_change(id);
     unsafe { ffi::b2Shape_SetPolygon(raw_shape_id(id), &raw) }
 }
 
@@ -155,7 +205,12 @@ pub(crate) fn shape_filter_impl(id: ShapeId) -> Filter {
 }
 
 #[inline]
-pub(crate) fn shape_set_filter_impl(id: ShapeId, filter: Filter) {
+pub(crate) fn shape_set_filter_impl(
+    core: &crate::core::world_core::WorldCore,
+    id: ShapeId,
+    filter: Filter,
+) {
+    core.note_shape_change(id);
     unsafe { ffi::b2Shape_SetFilter(raw_shape_id(id), filter.into_raw()) }
 }
 
@@ -199,6 +254,23 @@ pub(crate) fn shape_pre_solve_events_enabled_impl(id: ShapeId) -> bool {
     unsafe { ffi::b2Shape_ArePreSolveEventsEnabled(raw_shape_id(id)) }
 }
 
+/// Destroy every contact of `id`; Box2D creates them again on the next step.
+///
+/// Box2D copies per-shape flags such as pre-solve events into a contact when it is created, so
+/// toggling them later only affects new contacts. Re-applying a changed filter is the public way
+/// to drop a shape's contacts; changing only the group index keeps the broad-phase proxy and
+/// wakes the bodies that were touching.
+pub(crate) fn shape_reset_contacts_impl(id: ShapeId) {
+    let raw = raw_shape_id(id);
+    let filter = unsafe { ffi::b2Shape_GetFilter(raw) };
+    let mut nudged = filter;
+    nudged.groupIndex = filter.groupIndex.wrapping_add(1);
+    unsafe {
+        ffi::b2Shape_SetFilter(raw, nudged);
+        ffi::b2Shape_SetFilter(raw, filter);
+    }
+}
+
 #[inline]
 pub(crate) fn shape_enable_hit_events_impl(id: ShapeId, flag: bool) {
     unsafe { ffi::b2Shape_EnableHitEvents(raw_shape_id(id), flag) }
@@ -4,15 +4,69 @@ use super::*;
 pub(crate) fn record_shape_flags_on_create(
     core: &crate::core::world_core::WorldCore,
     id: ShapeId,
-    def: &ShapeDef,
+    def: &ffi::b2ShapeDef,
 ) {
+    core.note_shape_change(id);
     #[cfg(feature = "serialize")]
-    core.record_shape_flags(id, &def.0);
+    core.record_shape_flags(id, def);
     #[cfg(not(feature = "serialize"))]
-    let _ = (core, id, def);
+    let _ = def;
+}
+
+/// Geometry area in m², used to turn [`ShapeDefBuilder::mass`] into a density.
+pub(crate) trait ShapeArea {
+    fn area(&self) -> f32;
+}
+
+impl ShapeArea for Circle {
+    fn area(&self) -> f32 {
+        self.mass_data(1.0).mass
+    }
+}
+
+impl ShapeArea for Capsule {
+    fn area(&self) -> f32 {
+        self.mass_data(1.0).mass
+    }
+}
+
+impl ShapeArea for Polygon {
+    fn area(&self) -> f32 {
+        self.mass_data(1.0).mass
+    }
+}
+
+impl ShapeArea for Segment {
+    fn area(&self) -> f32 {
+        0.0
+    }
+}
+
+/// Raw definition to hand to Box2D, with density resolved from the target mass if one is set and
+/// the world's event defaults filled in where the definition leaves them unset.
+fn resolved_raw_shape_def<G: ShapeArea>(
+    core: &crate::core::world_core::WorldCore,
+    def: &ShapeDef,
+    geometry: &G,
+) -> ffi::b2ShapeDef {
+    let mut raw = def.0;
+    if !(def.2.contact_events && def.2.hit_events) {
+        core.creation_defaults
+            .lock()
+            .expect("creation_defaults mutex poisoned")
+            .events
+            .apply(def, &mut raw);
+    }
+    if let Some(mass) = def.target_mass() {
+        let area = geometry.area();
+        if area > 0.0 {
+            raw.density = mass / area;
+        }
+    }
+    raw
 }
 
-pub(crate) fn create_body_attached_shape_id_impl<G, R>(
+pub(crate) fn create_body_attached_shape_id_impl<G: ShapeArea, R>(
     core: &crate::core::world_core::WorldCore,
     body: BodyId,
     def: &ShapeDef,
@@ -24,13 +78,14 @@ pub(crate) fn create_body_attached_shape_id_impl<G, R>(
     crate::core::debug_checks::assert_body_valid(body);
     assert_shape_def_valid(def);
     assert_geometry_valid(geometry);
+    let raw_def = resolved_raw_shape_def(core, def, geometry);
     let raw = into_raw(geometry);
-    let id = ShapeId::from_raw(create_raw(body.into_raw(), &def.0, &raw));
-    record_shape_flags_on_create(core, id, def);
+    let id = ShapeId::from_raw(create_raw(body.into_raw(), &raw_def, &raw));
+    record_shape_flags_on_create(core, id, &raw_def);
     id
 }
 
-pub(crate) fn try_create_body_attached_shape_id_impl<G, R>(
+pub(crate) fn try_create_body_attached_shape_id_impl<G: ShapeArea, R>(
     core: &crate::core::world_core::WorldCore,
     body: BodyId,
     def: &ShapeDef,
@@ -42,9 +97,10 @@ pub(crate) fn try_create_body_attached_shape_id_impl<G, R>(
     crate::core::debug_checks::check_body_valid(body)?;
     check_shape_def_valid(def)?;
     check_geometry_valid(geometry)?;
+    let raw_def = resolved_raw_shape_def(core, def, geometry);
     let raw = into_raw(geometry);
-    let id = ShapeId::from_raw(create_raw(body.into_raw(), &def.0, &raw));
-    record_shape_flags_on_create(core, id, def);
+    let id = ShapeId::from_raw(create_raw(body.into_raw(), &raw_def, &raw));
+    record_shape_flags_on_create(core, id, &raw_def);
     Ok(id)
 }
 
@@ -416,3 +472,49 @@ pub(crate) fn try_create_circle_shape_for_body_impl(
         |body, def, raw| unsafe { ffi::b2CreateCircleShape(body, def, raw) },
     )
 }
+
+pub(crate) fn assert_geometry_valid(geometry: &Geometry) {
+    match geometry {
+        Geometry::Circle(g) => assert_circle_geometry_valid(g),
+        Geometry::Segment(g) => assert_segment_geometry_valid(g),
+        Geometry::Capsule(g) => assert_capsule_geometry_valid(g),
+        Geometry::Polygon(g) => assert_polygon_geometry_valid(g),
+    }
+}
+
+pub(crate) fn check_geometry_valid(geometry: &Geometry) -> ApiResult<()> {
+    match geometry {
+        Geometry::Circle(g) => check_circle_geometry_valid(g),
+        Geometry::Segment(g) => check_segment_geometry_valid(g),
+        Geometry::Capsule(g) => check_capsule_geometry_valid(g),
+        Geometry::Polygon(g) => check_polygon_geometry_valid(g),
+    }
+}
+
+pub(crate) fn create_geometry_shape_for_body_impl(
+    core: &crate::core::world_core::WorldCore,
+    body: BodyId,
+    def: &ShapeDef,
+    geometry: &Geometry,
+) -> ShapeId {
+    match geometry {
+        Geometry::Circle(g) => create_circle_shape_for_body_impl(core, body, def, g),
+        Geometry::Segment(g) => create_segment_shape_for_body_impl(core, body, def, g),
+        Geometry::Capsule(g) => create_capsule_shape_for_body_impl(core, body, def, g),
+        Geometry::Polygon(g) => create_polygon_shape_for_body_impl(core, body, def, g),
+    }
+}
+
+pub(crate) fn try_create_geometry_shape_for_body_impl(
+    core: &crate::core::world_core::WorldCore,
+    body: BodyId,
+    def: &ShapeDef,
+    geometry: &Geometry,
+) -> ApiResult<ShapeId> {
+    match geometry {
+        Geometry::Circle(g) => try_create_circle_shape_for_body_impl(core, body, def, g),
+        Geometry::Segment(g) => try_create_segment_shape_for_body_impl(core, body, def, g),
+        Geometry::Capsule(g) => try_create_capsule_shape_for_body_impl(core, body, def, g),
+        Geometry::Polygon(g) => try_create_polygon_shape_for_body_impl(core, body, def, g),
+    }
+}
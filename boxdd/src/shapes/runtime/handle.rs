@@ -384,52 +384,52 @@ pub(crate) trait ShapeRuntimeHandle {
     fn set_circle(&mut self, circle: &Circle) {
         self.assert_valid();
         assert_circle_geometry_valid(circle);
-        shape_set_circle_impl(self.shape_id(), circle)
+        shape_set_circle_impl(self.shape_world_core(), self.shape_id(), circle)
     }
 
     fn try_set_circle(&mut self, circle: &Circle) -> ApiResult<()> {
         self.check_valid()?;
         check_circle_geometry_valid(circle)?;
-        shape_set_circle_impl(self.shape_id(), circle);
+        shape_set_circle_impl(self.shape_world_core(), self.shape_id(), circle);
         Ok(())
     }
 
     fn set_segment(&mut self, segment: &Segment) {
         self.assert_valid();
         assert_segment_geometry_valid(segment);
-        shape_set_segment_impl(self.shape_id(), segment)
+        shape_set_segment_impl(self.shape_world_core(), self.shape_id(), segment)
     }
 
     fn try_set_segment(&mut self, segment: &Segment) -> ApiResult<()> {
         self.check_valid()?;
         check_segment_geometry_valid(segment)?;
-        shape_set_segment_impl(self.shape_id(), segment);
+        shape_set_segment_impl(self.shape_world_core(), self.shape_id(), segment);
         Ok(())
     }
 
     fn set_capsule(&mut self, capsule: &Capsule) {
         self.assert_valid();
         assert_capsule_geometry_valid(capsule);
-        shape_set_capsule_impl(self.shape_id(), capsule)
+        shape_set_capsule_impl(self.shape_world_core(), self.shape_id(), capsule)
     }
 
     fn try_set_capsule(&mut self, capsule: &Capsule) -> ApiResult<()> {
         self.check_valid()?;
         check_capsule_geometry_valid(capsule)?;
-        shape_set_capsule_impl(self.shape_id(), capsule);
+        shape_set_capsule_impl(self.shape_world_core(), self.shape_id(), capsule);
         Ok(())
     }
 
     fn set_polygon(&mut self, polygon: &Polygon) {
         self.assert_valid();
         assert_polygon_geometry_valid(polygon);
-        shape_set_polygon_impl(self.shape_id(), polygon)
+        shape_set_polygon_impl(self.shape_world_core(), self.shape_id(), polygon)
     }
 
     fn try_set_polygon(&mut self, polygon: &Polygon) -> ApiResult<()> {
         self.check_valid()?;
         check_polygon_geometry_valid(polygon)?;
-        shape_set_polygon_impl(self.shape_id(), polygon);
+        shape_set_polygon_impl(self.shape_world_core(), self.shape_id(), polygon);
         Ok(())
     }
 
@@ -445,12 +445,12 @@ pub(crate) trait ShapeRuntimeHandle {
 
     fn set_filter(&mut self, filter: Filter) {
         self.assert_valid();
-        shape_set_filter_impl(self.shape_id(), filter)
+        shape_set_filter_impl(self.shape_world_core(), self.shape_id(), filter)
     }
 
     fn try_set_filter(&mut self, filter: Filter) -> ApiResult<()> {
         self.check_valid()?;
-        shape_set_filter_impl(self.shape_id(), filter);
+        shape_set_filter_impl(self.shape_world_core(), self.shape_id(), filter);
         Ok(())
     }
 
@@ -52,16 +52,31 @@ pub(crate) fn assert_shape_def_valid(def: &ShapeDef) {
         "invalid ShapeDef: not initialized from b2DefaultShapeDef"
     );
     assert_non_negative_finite_shape_scalar("density", def.density());
+    if let Some(mass) = def.target_mass() {
+        assert_non_negative_finite_shape_scalar("mass", mass);
+    }
     assert_surface_material_valid(&def.material());
 }
 
+/// Field-level form of the shape definition checks, shared by creation and
+/// [`ShapeDefBuilder::build_checked`](crate::shapes::ShapeDefBuilder::build_checked).
+pub(crate) fn validate_shape_def(def: &ShapeDef) -> crate::core::def_checks::CheckResult {
+    let c = crate::core::def_checks::DefChecks("ShapeDef");
+    let material = def.material();
+    c.initialized(shape_def_cookie_is_valid(def))?;
+    c.non_negative("density", def.density())?;
+    if let Some(mass) = def.target_mass() {
+        c.non_negative("mass", mass)?;
+    }
+    c.non_negative("friction", material.friction())?;
+    c.non_negative("restitution", material.restitution())?;
+    c.non_negative("rolling_resistance", material.rolling_resistance())?;
+    c.finite("tangent_speed", material.tangent_speed())
+}
+
 #[inline]
 pub(crate) fn check_shape_def_valid(def: &ShapeDef) -> ApiResult<()> {
-    if !shape_def_cookie_is_valid(def) {
-        return Err(ApiError::InvalidArgument);
-    }
-    check_non_negative_finite_shape_scalar(def.density())?;
-    check_surface_material_valid(&def.material())
+    validate_shape_def(def).map_err(Into::into)
 }
 
 #[track_caller]
@@ -498,20 +498,14 @@ impl<'w> Shape<'w> {
     pub fn destroy(self, update_body_mass: bool) {
         crate::core::callback_state::assert_not_in_callback();
         if unsafe { ffi::b2Shape_IsValid(raw_shape_id(self.id)) } {
-            unsafe { ffi::b2DestroyShape(raw_shape_id(self.id), update_body_mass) };
-            let _ = self.core.clear_shape_user_data(self.id);
-            #[cfg(feature = "serialize")]
-            self.core.remove_shape_flags(self.id);
+            self.core.destroy_shape_now(self.id, update_body_mass);
         }
     }
 
     pub fn try_destroy(self, update_body_mass: bool) -> ApiResult<()> {
         self.check_valid()?;
         if unsafe { ffi::b2Shape_IsValid(raw_shape_id(self.id)) } {
-            unsafe { ffi::b2DestroyShape(raw_shape_id(self.id), update_body_mass) };
-            let _ = self.core.clear_shape_user_data(self.id);
-            #[cfg(feature = "serialize")]
-            self.core.remove_shape_flags(self.id);
+            self.core.destroy_shape_now(self.id, update_body_mass);
         }
         Ok(())
     }
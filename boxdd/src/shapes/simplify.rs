@@ -0,0 +1,216 @@
+//! Outline preprocessing: point welding, polyline simplification, and reduction to Box2D's
+//! polygon vertex limit.
+
+use crate::error::{ApiError, ApiResult};
+use crate::types::Vec2;
+
+/// Simplify a polyline with Ramer–Douglas–Peucker, dropping points closer than `tolerance`
+/// (meters) to the simplified line. Endpoints are always kept.
+///
+/// For closed outlines, repeat the first point at the end so the closing edge is considered, then
+/// drop it again before building a chain loop.
+///
+/// Panics if `tolerance` is negative or not finite, or any point is invalid.
+///
+/// ```
+/// use boxdd::{Vec2, shapes};
+/// let line = [[0.0, 0.0], [1.0, 0.01], [2.0, 0.0]];
+/// assert_eq!(shapes::simplify(line, 0.05), vec![Vec2::new(0.0, 0.0), Vec2::new(2.0, 0.0)]);
+/// ```
+pub fn simplify<I, P>(points: I, tolerance: f32) -> Vec<Vec2>
+where
+    I: IntoIterator<Item = P>,
+    P: Into<Vec2>,
+{
+    match try_simplify(points, tolerance) {
+        Ok(points) => points,
+        Err(_) => panic!("simplify requires valid points and a finite tolerance >= 0.0"),
+    }
+}
+
+/// Recoverable version of [`simplify`].
+pub fn try_simplify<I, P>(points: I, tolerance: f32) -> ApiResult<Vec<Vec2>>
+where
+    I: IntoIterator<Item = P>,
+    P: Into<Vec2>,
+{
+    check_tolerance(tolerance)?;
+    let points = collect_valid_points(points)?;
+    Ok(douglas_peucker(&points, tolerance))
+}
+
+/// Merge consecutive points closer than `epsilon` (meters), keeping the first of each run.
+///
+/// Useful before hulling or building chains from imported outlines, which often contain
+/// duplicated or near-duplicated vertices. Only neighbours are welded; for closed outlines also
+/// compare the last point against the first.
+///
+/// Panics if `epsilon` is negative or not finite, or any point is invalid.
+pub fn weld_close_points<I, P>(points: I, epsilon: f32) -> Vec<Vec2>
+where
+    I: IntoIterator<Item = P>,
+    P: Into<Vec2>,
+{
+    match try_weld_close_points(points, epsilon) {
+        Ok(points) => points,
+        Err(_) => panic!("weld_close_points requires valid points and a finite epsilon >= 0.0"),
+    }
+}
+
+/// Recoverable version of [`weld_close_points`].
+pub fn try_weld_close_points<I, P>(points: I, epsilon: f32) -> ApiResult<Vec<Vec2>>
+where
+    I: IntoIterator<Item = P>,
+    P: Into<Vec2>,
+{
+    check_tolerance(epsilon)?;
+    let epsilon2 = epsilon * epsilon;
+    let mut out: Vec<Vec2> = Vec::new();
+    for p in collect_valid_points(points)? {
+        match out.last() {
+            Some(last) if p.minus(*last).length_squared() <= epsilon2 => {}
+            _ => out.push(p),
+        }
+    }
+    Ok(out)
+}
+
+fn check_tolerance(value: f32) -> ApiResult<()> {
+    if value.is_finite() && value >= 0.0 {
+        Ok(())
+    } else {
+        Err(ApiError::InvalidArgument)
+    }
+}
+
+fn collect_valid_points<I, P>(points: I) -> ApiResult<Vec<Vec2>>
+where
+    I: IntoIterator<Item = P>,
+    P: Into<Vec2>,
+{
+    points
+        .into_iter()
+        .map(|p| {
+            let p = p.into();
+            if p.is_valid() {
+                Ok(p)
+            } else {
+                Err(ApiError::InvalidArgument)
+            }
+        })
+        .collect()
+}
+
+/// Ramer–Douglas–Peucker simplification of an open polyline. Endpoints are always kept.
+fn douglas_peucker(points: &[Vec2], tolerance: f32) -> Vec<Vec2> {
+    if points.len() < 3 {
+        return points.to_vec();
+    }
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+    let mut stack = vec![(0, points.len() - 1)];
+    while let Some((first, last)) = stack.pop() {
+        let mut max_dist = 0.0;
+        let mut index = first;
+        for i in first + 1..last {
+            let d = point_segment_distance(points[i], points[first], points[last]);
+            if d > max_dist {
+                max_dist = d;
+                index = i;
+            }
+        }
+        if max_dist > tolerance {
+            keep[index] = true;
+            stack.push((first, index));
+            stack.push((index, last));
+        }
+    }
+    points
+        .iter()
+        .zip(keep)
+        .filter_map(|(&p, k)| k.then_some(p))
+        .collect()
+}
+
+fn point_segment_distance(p: Vec2, a: Vec2, b: Vec2) -> f32 {
+    let ab = b.minus(a);
+    let len2 = ab.length_squared();
+    if len2 <= f32::EPSILON {
+        return p.minus(a).length();
+    }
+    let t = (p.minus(a).dot(ab) / len2).clamp(0.0, 1.0);
+    p.minus(a.plus(ab.scaled(t))).length()
+}
+
+#[cfg(feature = "polygon-simplify")]
+fn cross(o: Vec2, a: Vec2, b: Vec2) -> f32 {
+    (a.x - o.x) * (b.y - o.y) - (a.y - o.y) * (b.x - o.x)
+}
+
+#[cfg(feature = "polygon-simplify")]
+/// Counter-clockwise convex hull (Andrew's monotone chain) without collinear points.
+fn convex_hull(points: &[Vec2]) -> Vec<Vec2> {
+    let mut pts = points.to_vec();
+    pts.sort_by(|a, b| a.x.total_cmp(&b.x).then(a.y.total_cmp(&b.y)));
+    pts.dedup();
+    if pts.len() < 3 {
+        return pts;
+    }
+    let mut hull: Vec<Vec2> = Vec::with_capacity(pts.len() + 1);
+    for &p in &pts {
+        while hull.len() >= 2 && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0 {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    let lower_len = hull.len() + 1;
+    for &p in pts.iter().rev().skip(1) {
+        while hull.len() >= lower_len && cross(hull[hull.len() - 2], hull[hull.len() - 1], p) <= 0.0
+        {
+            hull.pop();
+        }
+        hull.push(p);
+    }
+    hull.pop();
+    hull
+}
+
+#[cfg(feature = "polygon-simplify")]
+/// Reduce `points` to at most `max_vertices` convex hull vertices, raising the Douglas–Peucker
+/// tolerance until the hull fits.
+pub(crate) fn reduce_to_convex(points: &[Vec2], max_vertices: usize) -> Vec<Vec2> {
+    let hull = convex_hull(points);
+    if hull.len() <= max_vertices {
+        return hull;
+    }
+    // Split the closed hull at the vertex farthest from hull[0] so both chains keep an anchor.
+    let far = (1..hull.len())
+        .max_by(|&a, &b| {
+            let da = hull[a].minus(hull[0]).length_squared();
+            let db = hull[b].minus(hull[0]).length_squared();
+            da.total_cmp(&db)
+        })
+        .unwrap_or(1);
+    let mut upper = hull[..=far].to_vec();
+    let mut lower = hull[far..].to_vec();
+    lower.push(hull[0]);
+    let extent = hull[far].minus(hull[0]).length().max(f32::EPSILON);
+
+    let mut tolerance = extent * 1.0e-3;
+    loop {
+        let a = douglas_peucker(&upper, tolerance);
+        let b = douglas_peucker(&lower, tolerance);
+        // Both chains share their endpoints.
+        if a.len() + b.len() - 2 <= max_vertices {
+            upper = a;
+            lower = b;
+            break;
+        }
+        tolerance *= 2.0;
+    }
+    upper.pop();
+    lower.pop();
+    upper.extend(lower);
+    upper
+}
@@ -8,20 +8,20 @@
 //! Key concepts (upstream names in parentheses):
 //!
 //! - Linear slop (`B2_LINEAR_SLOP`): small collision/constraint tolerance.
-//!   Not directly exposed; part of solver internals. Affects contact stability.
-//! - Speculative distance (`B2_SPECULATIVE_DISTANCE`): reduces jitter with
-//!   limited speculative collision. Runtime access is exposed through
-//!   `World::enable_speculative`.
+//!   Read it with [`linear_slop`]; part of solver internals. Affects contact stability.
+//! - Speculative distance (`B2_SPECULATIVE_DISTANCE`): contact points are created once shapes
+//!   are this close, so approaching bodies are stopped without first overlapping. Read it with
+//!   [`speculative_distance`]; toggle it with `World::enable_speculative`.
 //! - AABB margin (`B2_AABB_MARGIN`): fattening of dynamic-tree proxies to avoid
-//!   frequent broadphase updates. Not directly exposed.
+//!   frequent broadphase updates. Read it with [`aabb_margin`].
 //! - Max rotation per step (`B2_MAX_ROTATION`): large cap to prevent numerical
 //!   issues; increasing too much can break continuous collision.
 //! - Time to sleep (`B2_TIME_TO_SLEEP`): inactivity time before sleeping.
 //!   Use `World::enable_sleeping`/`World::is_sleeping_enabled` to toggle sleeping.
 //! - Graph color count (`B2_GRAPH_COLOR_COUNT`): internal constraint-coloring
 //!   size. Not exposed.
-//! - Max workers (`B2_MAX_WORKERS`): internal upper bound; configure desired
-//!   worker count via `WorldDef::builder().worker_count(n)`. Actual multithreaded
+//! - Max workers (`B2_MAX_WORKERS`, mirrored as `limits::MAX_WORKERS`): internal upper
+//!   bound; configure desired worker count via `WorldDef::builder().worker_count(n)`. Actual multithreaded
 //!   stepping still requires explicit raw task callbacks on `WorldDef` / `WorldBuilder`.
 //!
 //! Safe API controls related to tuning:
@@ -50,8 +50,39 @@
 //!   - This only affects Box2D's worker usage when a task system is also installed; `World`
 //!     itself stays pinned to one thread/task.
 //!
+//! Contact begin distance
+//! - Upstream fixes the three distances above per build, scaled by the global length unit, and
+//!   has no per-world or per-shape override. To make contacts begin later for very small
+//!   objects (visible hovering) or earlier for large ones, call
+//!   [`set_length_units_per_meter`](crate::set_length_units_per_meter) once before creating any
+//!   world, or scale the simulation so the smallest objects stay well above `linear_slop()`.
+//!   Disabling speculative collision removes the early contacts entirely at the cost of more
+//!   late collisions for fast bodies.
+//!
 //! Notes
 //! - Upstream constants in `src/constants.h` are implementation details and may
 //!   change across Box2D versions. The safe API focuses on stable, high-level
 //!   controls. If you need additional tuning hooks, open an issue and we can
 //!   consider exposing them in a versioned, documented way.
+
+/// Collision and constraint tolerance (`B2_LINEAR_SLOP`), in length units.
+#[inline]
+pub fn linear_slop() -> f32 {
+    0.005 * crate::length_units_per_meter()
+}
+
+/// Separation at which contact points begin (`B2_SPECULATIVE_DISTANCE`), in length units.
+///
+/// Shapes closer than this get speculative contact points that only push once the gap closes
+/// within the step.
+#[inline]
+pub fn speculative_distance() -> f32 {
+    4.0 * linear_slop()
+}
+
+/// Fattening applied to broad-phase proxies of movable shapes (`B2_AABB_MARGIN`), in length
+/// units. Movable shapes re-enter the broad-phase only when they leave their enlarged bounds.
+#[inline]
+pub fn aabb_margin() -> f32 {
+    0.1 * crate::length_units_per_meter()
+}
@@ -45,6 +45,39 @@ impl Vec2 {
     }
 }
 
+// Crate-internal arithmetic for pure-Rust helpers; the public type stays a plain value.
+impl Vec2 {
+    #[inline]
+    pub(crate) fn plus(self, o: Self) -> Self {
+        Self::new(self.x + o.x, self.y + o.y)
+    }
+
+    #[inline]
+    pub(crate) fn minus(self, o: Self) -> Self {
+        Self::new(self.x - o.x, self.y - o.y)
+    }
+
+    #[inline]
+    pub(crate) fn scaled(self, s: f32) -> Self {
+        Self::new(self.x * s, self.y * s)
+    }
+
+    #[inline]
+    pub(crate) fn dot(self, o: Self) -> f32 {
+        self.x * o.x + self.y * o.y
+    }
+
+    #[inline]
+    pub(crate) fn length_squared(self) -> f32 {
+        self.dot(self)
+    }
+
+    #[inline]
+    pub(crate) fn length(self) -> f32 {
+        self.length_squared().sqrt()
+    }
+}
+
 // Conversions from common 2D types to Vec2 for ergonomic APIs
 impl From<[f32; 2]> for Vec2 {
     #[inline]
@@ -165,9 +198,12 @@ impl From<Vec2> for glam::Vec2 {
 }
 
 /// Opaque Box2D body identifier.
+///
+/// Ids order by slot index, then world, then generation. Bulk getters such as
+/// `World::body_ids` return ids in this order.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct BodyId {
     pub index1: i32,
     pub world0: u16,
@@ -202,7 +238,7 @@ const _: () = {
 /// Opaque Box2D shape identifier.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ShapeId {
     pub index1: i32,
     pub world0: u16,
@@ -237,7 +273,7 @@ const _: () = {
 /// Opaque Box2D joint identifier.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct JointId {
     pub index1: i32,
     pub world0: u16,
@@ -272,7 +308,7 @@ const _: () = {
 /// Opaque Box2D chain identifier.
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[repr(C)]
-#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct ChainId {
     pub index1: i32,
     pub world0: u16,
@@ -77,11 +77,6 @@ unsafe fn body_type_unchecked_impl(id: BodyId) -> BodyType {
     BodyType::from_raw(unsafe { ffi::b2Body_GetType(raw_body_id(id)) })
 }
 
-#[inline]
-unsafe fn set_body_type_unchecked_impl(id: BodyId, body_type: BodyType) {
-    unsafe { ffi::b2Body_SetType(raw_body_id(id), body_type.into_raw()) }
-}
-
 #[inline]
 unsafe fn set_body_gravity_scale_unchecked_impl(id: BodyId, value: f32) {
     unsafe { ffi::b2Body_SetGravityScale(raw_body_id(id), value) }
@@ -222,7 +217,7 @@ impl WorldUncheckedExt for World {
     }
 
     unsafe fn set_body_type_unchecked(&mut self, body: BodyId, t: BodyType) {
-        unsafe { set_body_type_unchecked_impl(body, t) }
+        crate::body::body_set_type_impl(self.core(), body, t)
     }
 
     unsafe fn shape_body_unchecked(&self, shape: ShapeId) -> BodyId {
@@ -277,7 +272,7 @@ impl BodyUncheckedExt for Body<'_> {
     }
 
     unsafe fn set_body_type_unchecked(&mut self, t: BodyType) {
-        unsafe { set_body_type_unchecked_impl(self.id, t) }
+        crate::body::body_set_type_impl(&self.core, self.id, t)
     }
 
     unsafe fn set_gravity_scale_unchecked(&mut self, v: f32) {
@@ -319,7 +314,7 @@ impl BodyUncheckedExt for OwnedBody {
     }
 
     unsafe fn set_body_type_unchecked(&mut self, t: BodyType) {
-        unsafe { set_body_type_unchecked_impl(self.id(), t) }
+        crate::body::body_set_type_impl(&self.core, self.id(), t)
     }
 
     unsafe fn set_gravity_scale_unchecked(&mut self, v: f32) {
@@ -0,0 +1,445 @@
+//! Verlet ropes and cloth: point masses with distance constraints, decoupled from rigid joints.
+//!
+//! `VerletSystem` is meant for visual ropes/cloth that only need one-way interaction with the
+//! rigid world:
+//! - points collide with non-sensor world shapes (ray cast against the step motion plus a
+//!   closest-point push-out), but never push bodies,
+//! - points can be pinned to a fixed world position or to a local point on a body, and follow it,
+//! - it is stepped after `World::step` and needs only `&World`.
+//!
+//! ```no_run
+//! use boxdd::verlet::{VerletDef, VerletSystem};
+//! use boxdd::{World, WorldDef};
+//! let mut world = World::new(WorldDef::builder().gravity([0.0, -10.0]).build()).unwrap();
+//! let mut rope = VerletSystem::new(VerletDef::default());
+//! let points = rope.add_rope([0.0, 5.0], [4.0, 5.0], 16);
+//! rope.pin(points.start, [0.0, 5.0]);
+//! world.step(1.0 / 60.0, 4);
+//! rope.step(&world, 1.0 / 60.0);
+//! ```
+
+use core::ops::Range;
+
+use crate::error::{ApiError, ApiResult};
+use crate::query::QueryFilter;
+use crate::types::{BodyId, ShapeId, Vec2};
+use crate::world::World;
+
+/// Configuration for a [`VerletSystem`].
+#[derive(Copy, Clone, Debug)]
+pub struct VerletDef {
+    /// Collision radius of each point in meters.
+    pub radius: f32,
+    /// Constraint relaxation iterations per step.
+    pub iterations: u32,
+    /// Velocity damping in `[0, 1]`; `0` keeps all momentum.
+    pub damping: f32,
+    /// Multiplier for world gravity.
+    pub gravity_scale: f32,
+    /// Whether points collide with world shapes.
+    pub collide: bool,
+    /// Filter used for world collision queries.
+    pub filter: QueryFilter,
+}
+
+impl Default for VerletDef {
+    fn default() -> Self {
+        Self {
+            radius: 0.05,
+            iterations: 8,
+            damping: 0.01,
+            gravity_scale: 1.0,
+            collide: true,
+            filter: QueryFilter::default(),
+        }
+    }
+}
+
+impl VerletDef {
+    /// Create a default definition.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Collision radius of each point.
+    pub fn radius(mut self, radius: f32) -> Self {
+        self.radius = radius;
+        self
+    }
+
+    /// Constraint iterations per step.
+    pub fn iterations(mut self, n: u32) -> Self {
+        self.iterations = n;
+        self
+    }
+
+    /// Velocity damping.
+    pub fn damping(mut self, damping: f32) -> Self {
+        self.damping = damping;
+        self
+    }
+
+    /// Multiplier for world gravity.
+    pub fn gravity_scale(mut self, scale: f32) -> Self {
+        self.gravity_scale = scale;
+        self
+    }
+
+    /// Whether points collide with world shapes.
+    pub fn collide(mut self, flag: bool) -> Self {
+        self.collide = flag;
+        self
+    }
+
+    /// Filter used for world collision queries.
+    pub fn filter(mut self, filter: QueryFilter) -> Self {
+        self.filter = filter;
+        self
+    }
+
+    /// Validate the definition.
+    pub fn validate(&self) -> ApiResult<()> {
+        let valid = self.radius.is_finite()
+            && self.radius >= 0.0
+            && self.gravity_scale.is_finite()
+            && (0.0..=1.0).contains(&self.damping);
+        if !valid {
+            return Err(ApiError::InvalidArgument);
+        }
+        Ok(())
+    }
+}
+
+/// Where a pinned point is held.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum Pin {
+    /// Fixed world position.
+    World(Vec2),
+    /// Local point on a body; follows the body transform.
+    Body {
+        /// Body the point follows.
+        body: BodyId,
+        /// Point in the body's local frame.
+        local_point: Vec2,
+    },
+}
+
+/// Distance constraint between two points.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct DistanceConstraint {
+    /// First point index.
+    pub a: usize,
+    /// Second point index.
+    pub b: usize,
+    /// Rest length in meters.
+    pub length: f32,
+    /// Stiffness in `[0, 1]` applied per iteration.
+    pub stiffness: f32,
+}
+
+/// Point masses with distance constraints, stepped after the rigid world.
+#[derive(Clone, Debug)]
+pub struct VerletSystem {
+    def: VerletDef,
+    positions: Vec<Vec2>,
+    previous: Vec<Vec2>,
+    pins: Vec<Option<Pin>>,
+    constraints: Vec<DistanceConstraint>,
+    // Scratch buffers reused across steps.
+    step_start: Vec<Vec2>,
+    shapes: Vec<ShapeId>,
+}
+
+impl VerletSystem {
+    /// Create an empty system.
+    ///
+    /// Panics if `def` is invalid; see [`VerletDef::validate`].
+    pub fn new(def: VerletDef) -> Self {
+        assert!(def.validate().is_ok(), "invalid VerletDef: {def:?}");
+        Self::new_unchecked(def)
+    }
+
+    /// Recoverable version of [`Self::new`].
+    pub fn try_new(def: VerletDef) -> ApiResult<Self> {
+        def.validate()?;
+        Ok(Self::new_unchecked(def))
+    }
+
+    fn new_unchecked(def: VerletDef) -> Self {
+        Self {
+            def,
+            positions: Vec::new(),
+            previous: Vec::new(),
+            pins: Vec::new(),
+            constraints: Vec::new(),
+            step_start: Vec::new(),
+            shapes: Vec::new(),
+        }
+    }
+
+    /// The definition this system was created with.
+    pub fn def(&self) -> &VerletDef {
+        &self.def
+    }
+
+    /// Number of points.
+    pub fn len(&self) -> usize {
+        self.positions.len()
+    }
+
+    /// Whether the system has no points.
+    pub fn is_empty(&self) -> bool {
+        self.positions.is_empty()
+    }
+
+    /// Point positions in world space.
+    pub fn positions(&self) -> &[Vec2] {
+        &self.positions
+    }
+
+    /// Distance constraints.
+    pub fn constraints(&self) -> &[DistanceConstraint] {
+        &self.constraints
+    }
+
+    /// Pin state of point `index`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of range.
+    pub fn pin_of(&self, index: usize) -> Option<Pin> {
+        self.pins[index]
+    }
+
+    /// Add a point at rest and return its index.
+    pub fn add_point<V: Into<Vec2>>(&mut self, position: V) -> usize {
+        let p = position.into();
+        self.positions.push(p);
+        self.previous.push(p);
+        self.pins.push(None);
+        self.positions.len() - 1
+    }
+
+    /// Connect points `a` and `b` with a rigid constraint at their current distance.
+    ///
+    /// Panics if either index is out of range.
+    pub fn add_constraint(&mut self, a: usize, b: usize) -> usize {
+        let length = self.positions[a].minus(self.positions[b]).length();
+        self.add_constraint_with(a, b, length, 1.0)
+    }
+
+    /// Connect points `a` and `b` with an explicit rest length and stiffness.
+    ///
+    /// Panics if either index is out of range or the parameters are invalid.
+    pub fn add_constraint_with(
+        &mut self,
+        a: usize,
+        b: usize,
+        length: f32,
+        stiffness: f32,
+    ) -> usize {
+        assert!(
+            a < self.positions.len() && b < self.positions.len() && a != b,
+            "invalid constraint points ({a}, {b})"
+        );
+        assert!(
+            length.is_finite() && length >= 0.0,
+            "length must be finite and >= 0.0, got {length}"
+        );
+        assert!(
+            (0.0..=1.0).contains(&stiffness),
+            "stiffness must be in [0, 1], got {stiffness}"
+        );
+        self.constraints.push(DistanceConstraint {
+            a,
+            b,
+            length,
+            stiffness,
+        });
+        self.constraints.len() - 1
+    }
+
+    /// Add a rope of `segments` links from `start` to `end`. Returns the point index range.
+    pub fn add_rope<A: Into<Vec2>, B: Into<Vec2>>(
+        &mut self,
+        start: A,
+        end: B,
+        segments: usize,
+    ) -> Range<usize> {
+        let (start, end) = (start.into(), end.into());
+        let segments = segments.max(1);
+        let first = self.positions.len();
+        for i in 0..=segments {
+            let t = i as f32 / segments as f32;
+            self.add_point(start.plus(end.minus(start).scaled(t)));
+            if i > 0 {
+                let n = self.positions.len();
+                self.add_constraint(n - 2, n - 1);
+            }
+        }
+        first..self.positions.len()
+    }
+
+    /// Add a `columns x rows` cloth grid whose first row starts at `top_left` and extends along
+    /// +x and -y with the given spacing. Points are laid out row by row; returns the index range.
+    pub fn add_cloth<V: Into<Vec2>>(
+        &mut self,
+        top_left: V,
+        columns: usize,
+        rows: usize,
+        spacing: f32,
+    ) -> Range<usize> {
+        let origin = top_left.into();
+        let first = self.positions.len();
+        for r in 0..rows {
+            for c in 0..columns {
+                let i = self.add_point(Vec2::new(
+                    origin.x + c as f32 * spacing,
+                    origin.y - r as f32 * spacing,
+                ));
+                if c > 0 {
+                    self.add_constraint(i - 1, i);
+                }
+                if r > 0 {
+                    self.add_constraint(i - columns, i);
+                }
+            }
+        }
+        first..self.positions.len()
+    }
+
+    /// Pin point `index` to a fixed world position.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of range.
+    pub fn pin<V: Into<Vec2>>(&mut self, index: usize, position: V) {
+        self.pins[index] = Some(Pin::World(position.into()));
+    }
+
+    /// Pin point `index` to `local_point` on `body`; the point follows the body each step.
+    ///
+    /// The body must stay alive while pinned; [`Self::step`] panics and [`Self::try_step`]
+    /// returns `ApiError::InvalidBodyId` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of range.
+    pub fn pin_to_body<V: Into<Vec2>>(&mut self, index: usize, body: BodyId, local_point: V) {
+        self.pins[index] = Some(Pin::Body {
+            body,
+            local_point: local_point.into(),
+        });
+    }
+
+    /// Release a pinned point.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index` is out of range.
+    pub fn unpin(&mut self, index: usize) {
+        self.pins[index] = None;
+    }
+
+    /// Remove all points and constraints.
+    pub fn clear(&mut self) {
+        self.positions.clear();
+        self.previous.clear();
+        self.pins.clear();
+        self.constraints.clear();
+    }
+
+    /// Advance by `dt`, colliding with `world`. Call this right after `World::step`.
+    pub fn step(&mut self, world: &World, dt: f32) {
+        crate::core::callback_state::assert_not_in_callback();
+        assert!(
+            dt.is_finite() && dt >= 0.0,
+            "dt must be finite and >= 0.0, got {dt}"
+        );
+        for pin in self.pins.iter().flatten() {
+            if let Pin::Body { body, .. } = *pin {
+                crate::core::debug_checks::assert_body_valid(body);
+            }
+        }
+        self.step_impl(world, dt);
+    }
+
+    /// Recoverable version of [`Self::step`].
+    pub fn try_step(&mut self, world: &World, dt: f32) -> ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        if !(dt.is_finite() && dt >= 0.0) {
+            return Err(ApiError::InvalidArgument);
+        }
+        for pin in self.pins.iter().flatten() {
+            if let Pin::Body { body, .. } = *pin {
+                crate::core::debug_checks::check_body_valid(body)?;
+            }
+        }
+        self.step_impl(world, dt);
+        Ok(())
+    }
+
+    fn step_impl(&mut self, world: &World, dt: f32) {
+        if self.positions.is_empty() || dt == 0.0 {
+            return;
+        }
+        let accel = world.gravity().scaled(self.def.gravity_scale * dt * dt);
+        let keep = 1.0 - self.def.damping;
+        self.step_start.clear();
+        self.step_start.extend_from_slice(&self.positions);
+
+        for i in 0..self.positions.len() {
+            let p = self.positions[i];
+            let motion = p.minus(self.previous[i]).scaled(keep);
+            self.previous[i] = p;
+            self.positions[i] = p.plus(motion).plus(accel);
+        }
+        self.apply_pins();
+
+        for _ in 0..self.def.iterations {
+            for c in &self.constraints {
+                let (pa, pb) = (self.positions[c.a], self.positions[c.b]);
+                let delta = pb.minus(pa);
+                let dist = delta.length();
+                if dist <= f32::EPSILON {
+                    continue;
+                }
+                let wa = if self.pins[c.a].is_some() { 0.0 } else { 1.0 };
+                let wb = if self.pins[c.b].is_some() { 0.0 } else { 1.0 };
+                let w = wa + wb;
+                if w == 0.0 {
+                    continue;
+                }
+                let correction = delta.scaled(c.stiffness * (dist - c.length) / (dist * w));
+                self.positions[c.a] = pa.plus(correction.scaled(wa));
+                self.positions[c.b] = pb.minus(correction.scaled(wb));
+            }
+            if self.def.collide {
+                for i in 0..self.positions.len() {
+                    if self.pins[i].is_none() {
+                        (self.positions[i], _) = crate::core::point_collision::collide_point(
+                            world,
+                            self.step_start[i],
+                            self.positions[i],
+                            self.def.radius,
+                            self.def.filter,
+                            &mut self.shapes,
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    fn apply_pins(&mut self) {
+        for (i, pin) in self.pins.iter().enumerate() {
+            match *pin {
+                Some(Pin::World(p)) => self.positions[i] = p,
+                Some(Pin::Body { body, local_point }) => {
+                    self.positions[i] = crate::body::body_world_point_impl(body, local_point);
+                }
+                None => {}
+            }
+        }
+    }
+}
@@ -2,6 +2,7 @@ use crate::Transform;
 use crate::body::{Body, BodyDef, BodyType};
 use crate::collision::CastOutput;
 use crate::core::world_core::{CustomFilterCtx, MaterialMixCtx, PreSolveCtx, WorldCore};
+use crate::dynamic_tree::TreeStats;
 use crate::query::Aabb;
 use crate::shapes::{ShapeDef, SurfaceMaterial};
 use crate::types::{BodyId, ChainId, JointId, MassData, MotionLocks, ShapeId, Vec2};
@@ -14,20 +15,29 @@ mod body_api;
 mod borrow;
 mod creation;
 mod definition;
+mod fixed_timestep;
 mod handle;
 mod metrics;
 mod runtime;
 mod shape_api;
+mod task_system;
 
-pub use definition::{Error, WorldBuilder, WorldDef};
+pub use definition::{Error, WorldBuilder, WorldDef, WorldProfile};
 pub(crate) use definition::{
-    assert_non_negative_finite_world_scalar, assert_positive_finite_world_scalar,
-    assert_world_gravity_valid, check_non_negative_finite_world_scalar,
-    check_positive_finite_world_scalar, check_world_gravity_valid,
+    ShapeEventDefaults, assert_non_negative_finite_world_scalar,
+    assert_positive_finite_world_scalar, assert_world_gravity_valid,
+    check_non_negative_finite_world_scalar, check_positive_finite_world_scalar,
+    check_world_gravity_valid,
 };
+pub use fixed_timestep::FixedTimestep;
 pub use handle::{CallbackWorld, WorldHandle};
-pub use metrics::{Counters, OutstandingOwnedHandles, OwnedHandleCounts, Profile};
-pub use runtime::MaterialMixInput;
+#[cfg(feature = "force-tracking")]
+pub use metrics::AppliedForces;
+pub use metrics::{
+    Counters, OutstandingOwnedHandles, OwnedHandleCounts, Profile, QueryBudget, QueryKind,
+    QueryStats, RegistrySizes, SlowQuery, StaticTreeHint,
+};
+pub use runtime::{ContactDisable, DestroyedEntity, MaterialMixInput, StepInfo};
 pub(crate) use runtime::{
     try_world_awake_body_count_impl, try_world_counters_impl, try_world_gravity_impl,
     try_world_hit_event_threshold_impl, try_world_is_continuous_enabled_impl,
@@ -40,6 +50,11 @@ pub(crate) use runtime::{
     world_maximum_linear_speed_checked_impl, world_profile_checked_impl,
     world_restitution_threshold_checked_impl,
 };
+pub use shape_api::CapsuleResizeError;
+#[cfg(feature = "parallel")]
+pub use task_system::RayonTaskSystem;
+pub(crate) use task_system::TaskSystemCtx;
+pub use task_system::{MAX_WORKERS, PendingTask, TaskRange, TaskSystem};
 
 #[inline]
 fn raw_body_id(id: BodyId) -> ffi::b2BodyId {
@@ -81,13 +96,32 @@ impl World {
     pub fn new(def: WorldDef) -> Result<Self, Error> {
         def.validate()?;
         let _guard = crate::core::box2d_lock::lock();
-        let raw = def.into_raw();
+        let events = def.1;
+        let task_system = task_system::creation_task_system(&def);
+        let mut raw = def.into_raw();
+        let task_system = match task_system {
+            Some(slot) => Some(
+                task_system::TaskSystemCtx::install(slot, &mut raw)
+                    .ok_or(Error::TaskSystemInUse)?,
+            ),
+            None => None,
+        };
         // SAFETY: FFI call to create a world; returns an id handle
         let world_id = unsafe { ffi::b2CreateWorld(&raw) };
         let ok = unsafe { ffi::b2World_IsValid(world_id) };
         if ok {
+            crate::limits::world_created();
+            let core = WorldCore::new(world_id, task_system);
+            {
+                let mut defaults = core
+                    .creation_defaults
+                    .lock()
+                    .expect("creation_defaults mutex poisoned");
+                defaults.events = events;
+                defaults.seed_shape_events();
+            }
             Ok(Self {
-                core: WorldCore::new(world_id),
+                core,
                 _not_send_sync: core::marker::PhantomData,
             })
         } else {
@@ -108,6 +142,10 @@ impl World {
         Arc::clone(&self.core)
     }
 
+    pub(crate) fn core(&self) -> &WorldCore {
+        &self.core
+    }
+
     pub(crate) fn with_borrowed_event_buffers<T>(&self, f: impl FnOnce() -> T) -> T {
         crate::core::callback_state::assert_not_in_callback();
         let core = self.core_arc();
@@ -246,6 +284,9 @@ impl World {
     }
 
     /// Enumerate known body ids created via this wrapper. Invalid/destroyed ids are filtered out.
+    ///
+    /// Ids are sorted by [`BodyId`]'s ordering (slot index, then world, then generation), so the
+    /// result does not depend on creation or destruction history beyond which slots are live.
     #[cfg(feature = "serialize")]
     pub fn body_ids(&self) -> Vec<BodyId> {
         crate::core::callback_state::assert_not_in_callback();
@@ -292,6 +333,57 @@ impl World {
         Ok(())
     }
 
+    /// Body ids in deterministic order, for hashing, serialization, and replay.
+    ///
+    /// Same as [`Self::body_ids`], which is already sorted; use this name where the ordering
+    /// guarantee matters to the caller.
+    #[cfg(feature = "serialize")]
+    pub fn sorted_body_ids(&self) -> Vec<BodyId> {
+        self.body_ids()
+    }
+
+    #[cfg(feature = "serialize")]
+    pub fn try_sorted_body_ids(&self) -> crate::error::ApiResult<Vec<BodyId>> {
+        self.try_body_ids()
+    }
+
+    /// Enumerate live chain ids created via this wrapper, sorted like [`Self::body_ids`].
+    #[cfg(feature = "serialize")]
+    pub fn chain_ids(&self) -> Vec<ChainId> {
+        let mut out = Vec::new();
+        self.chain_ids_into(&mut out);
+        out
+    }
+
+    /// Enumerate live chain ids created via this wrapper into a caller-owned buffer.
+    #[cfg(feature = "serialize")]
+    pub fn chain_ids_into(&self, out: &mut Vec<ChainId>) {
+        crate::core::callback_state::assert_not_in_callback();
+        self.core
+            .registries
+            .lock()
+            .expect("registries mutex poisoned")
+            .chain_ids_into(out);
+    }
+
+    #[cfg(feature = "serialize")]
+    pub fn try_chain_ids(&self) -> crate::error::ApiResult<Vec<ChainId>> {
+        let mut out = Vec::new();
+        self.try_chain_ids_into(&mut out)?;
+        Ok(out)
+    }
+
+    #[cfg(feature = "serialize")]
+    pub fn try_chain_ids_into(&self, out: &mut Vec<ChainId>) -> crate::error::ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        self.core
+            .registries
+            .lock()
+            .expect("registries mutex poisoned")
+            .chain_ids_into(out);
+        Ok(())
+    }
+
     /// Return chain creation records captured at creation time using crate-owned value types.
     #[cfg(feature = "serialize")]
     pub fn chain_records(&self) -> Vec<ChainCreateRecord> {
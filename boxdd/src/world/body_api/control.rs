@@ -29,6 +29,33 @@ impl World {
         Ok(())
     }
 
+    /// Move a kinematic body along a scripted path by giving the pose it should reach at the end
+    /// of the next step of length `time_step`.
+    ///
+    /// Box2D computes the velocities for you: linear velocity `(target.p - p) / time_step` and
+    /// angular velocity from the relative rotation between the current and target orientation
+    /// divided by `time_step` (so it takes the short way round). The body then moves there during
+    /// the step, is woken if asleep and `wake` is set, and bodies resting on it are carried along
+    /// with friction, unlike teleporting with [`World::set_body_position_and_rotation`]. Call it
+    /// every step with the same `time_step` you pass to [`World::step`]; velocities stay set
+    /// afterward, so stop the body with a final target equal to its current pose or by zeroing
+    /// its velocity.
+    ///
+    /// ```no_run
+    /// use boxdd::{BodyBuilder, BodyType, Transform, World, WorldDef};
+    /// let mut world = World::new(WorldDef::default()).unwrap();
+    /// let platform =
+    ///     world.create_body_id(BodyBuilder::new().body_type(BodyType::Kinematic).build());
+    /// let dt = 1.0 / 60.0;
+    /// for i in 1..=120 {
+    ///     let x = 3.0 * (i as f32 * dt).sin();
+    ///     world.set_body_target_transform(platform, Transform::from_pos_angle([x, 0.0], 0.0), dt, true);
+    ///     world.step(dt, 4);
+    /// }
+    /// ```
+    ///
+    /// Panics if `time_step` is not finite and positive or `target` is not a valid transform.
+    #[doc(alias = "set_kinematic_target")]
     pub fn set_body_target_transform(
         &mut self,
         body: BodyId,
@@ -37,6 +64,14 @@ impl World {
         wake: bool,
     ) {
         crate::core::debug_checks::assert_body_valid(body);
+        assert!(
+            time_step.is_finite() && time_step > 0.0,
+            "time_step must be finite and > 0.0, got {time_step}"
+        );
+        assert!(
+            target.is_valid(),
+            "target must be a valid transform, got {target:?}"
+        );
         unsafe {
             ffi::b2Body_SetTargetTransform(raw_body_id(body), target.into_raw(), time_step, wake)
         };
@@ -50,6 +85,9 @@ impl World {
         wake: bool,
     ) -> crate::error::ApiResult<()> {
         crate::core::debug_checks::check_body_valid(body)?;
+        if !(time_step.is_finite() && time_step > 0.0 && target.is_valid()) {
+            return Err(crate::error::ApiError::InvalidArgument);
+        }
         unsafe {
             ffi::b2Body_SetTargetTransform(raw_body_id(body), target.into_raw(), time_step, wake)
         };
@@ -64,10 +102,7 @@ impl World {
         angle_radians: f32,
     ) {
         crate::core::debug_checks::assert_body_valid(body);
-        let (s, c) = angle_radians.sin_cos();
-        let rot = ffi::b2Rot { c, s };
-        let pos: ffi::b2Vec2 = p.into().into_raw();
-        unsafe { ffi::b2Body_SetTransform(raw_body_id(body), pos, rot) };
+        crate::body::body_set_position_and_rotation_impl(&self.core, body, p, angle_radians);
     }
 
     pub fn try_set_body_position_and_rotation<V: Into<Vec2>>(
@@ -77,10 +112,7 @@ impl World {
         angle_radians: f32,
     ) -> crate::error::ApiResult<()> {
         crate::core::debug_checks::check_body_valid(body)?;
-        let (s, c) = angle_radians.sin_cos();
-        let rot = ffi::b2Rot { c, s };
-        let pos: ffi::b2Vec2 = p.into().into_raw();
-        unsafe { ffi::b2Body_SetTransform(raw_body_id(body), pos, rot) };
+        crate::body::body_set_position_and_rotation_impl(&self.core, body, p, angle_radians);
         Ok(())
     }
 
@@ -256,6 +288,30 @@ impl World {
         Ok(())
     }
 
+    /// Apply a force (N) at a world point of a body.
+    pub fn body_apply_force<F: Into<Vec2>, P: Into<Vec2>>(
+        &mut self,
+        body: BodyId,
+        force: F,
+        point: P,
+        wake: bool,
+    ) {
+        crate::core::debug_checks::assert_body_valid(body);
+        crate::body::body_apply_force_impl(&self.core, body, force.into(), point.into(), wake);
+    }
+
+    pub fn try_body_apply_force<F: Into<Vec2>, P: Into<Vec2>>(
+        &mut self,
+        body: BodyId,
+        force: F,
+        point: P,
+        wake: bool,
+    ) -> crate::error::ApiResult<()> {
+        crate::core::debug_checks::check_body_valid(body)?;
+        crate::body::body_apply_force_impl(&self.core, body, force.into(), point.into(), wake);
+        Ok(())
+    }
+
     /// Apply a linear impulse to the center of mass of a body.
     pub fn body_apply_linear_impulse_to_center<V: Into<Vec2>>(
         &mut self,
@@ -264,8 +320,14 @@ impl World {
         wake: bool,
     ) {
         crate::core::debug_checks::assert_body_valid(body);
-        let i: ffi::b2Vec2 = impulse.into().into_raw();
-        unsafe { ffi::b2Body_ApplyLinearImpulseToCenter(raw_body_id(body), i, wake) };
+        let impulse = impulse.into();
+        unsafe {
+            ffi::b2Body_ApplyLinearImpulseToCenter(raw_body_id(body), impulse.into_raw(), wake)
+        };
+        self.core.record_applied(
+            body,
+            crate::core::world_core::Applied::LinearImpulse(impulse, None),
+        );
     }
 
     pub fn try_body_apply_linear_impulse_to_center<V: Into<Vec2>>(
@@ -275,8 +337,14 @@ impl World {
         wake: bool,
     ) -> crate::error::ApiResult<()> {
         crate::core::debug_checks::check_body_valid(body)?;
-        let i: ffi::b2Vec2 = impulse.into().into_raw();
-        unsafe { ffi::b2Body_ApplyLinearImpulseToCenter(raw_body_id(body), i, wake) };
+        let impulse = impulse.into();
+        unsafe {
+            ffi::b2Body_ApplyLinearImpulseToCenter(raw_body_id(body), impulse.into_raw(), wake)
+        };
+        self.core.record_applied(
+            body,
+            crate::core::world_core::Applied::LinearImpulse(impulse, None),
+        );
         Ok(())
     }
 
@@ -284,6 +352,10 @@ impl World {
     pub fn body_apply_angular_impulse(&mut self, body: BodyId, impulse: f32, wake: bool) {
         crate::core::debug_checks::assert_body_valid(body);
         unsafe { ffi::b2Body_ApplyAngularImpulse(raw_body_id(body), impulse, wake) };
+        self.core.record_applied(
+            body,
+            crate::core::world_core::Applied::AngularImpulse(impulse),
+        );
     }
 
     pub fn try_body_apply_angular_impulse(
@@ -294,6 +366,10 @@ impl World {
     ) -> crate::error::ApiResult<()> {
         crate::core::debug_checks::check_body_valid(body)?;
         unsafe { ffi::b2Body_ApplyAngularImpulse(raw_body_id(body), impulse, wake) };
+        self.core.record_applied(
+            body,
+            crate::core::world_core::Applied::AngularImpulse(impulse),
+        );
         Ok(())
     }
 
@@ -324,36 +400,36 @@ impl World {
     /// Set a body's type by id.
     pub fn set_body_type(&mut self, body: BodyId, t: BodyType) {
         crate::core::debug_checks::assert_body_valid(body);
-        unsafe { ffi::b2Body_SetType(raw_body_id(body), t.into_raw()) }
+        crate::body::body_set_type_impl(&self.core, body, t)
     }
 
     pub fn try_set_body_type(&mut self, body: BodyId, t: BodyType) -> crate::error::ApiResult<()> {
         crate::core::debug_checks::check_body_valid(body)?;
-        unsafe { ffi::b2Body_SetType(raw_body_id(body), t.into_raw()) }
+        crate::body::body_set_type_impl(&self.core, body, t);
         Ok(())
     }
 
     /// Enable a body by id.
     pub fn enable_body(&mut self, body: BodyId) {
         crate::core::debug_checks::assert_body_valid(body);
-        crate::body::body_enable_impl(body)
+        crate::body::body_enable_impl(&self.core, body)
     }
 
     pub fn try_enable_body(&mut self, body: BodyId) -> crate::error::ApiResult<()> {
         crate::core::debug_checks::check_body_valid(body)?;
-        crate::body::body_enable_impl(body);
+        crate::body::body_enable_impl(&self.core, body);
         Ok(())
     }
 
     /// Disable a body by id.
     pub fn disable_body(&mut self, body: BodyId) {
         crate::core::debug_checks::assert_body_valid(body);
-        crate::body::body_disable_impl(body)
+        crate::body::body_disable_impl(&self.core, body)
     }
 
     pub fn try_disable_body(&mut self, body: BodyId) -> crate::error::ApiResult<()> {
         crate::core::debug_checks::check_body_valid(body)?;
-        crate::body::body_disable_impl(body);
+        crate::body::body_disable_impl(&self.core, body);
         Ok(())
     }
 
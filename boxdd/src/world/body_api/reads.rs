@@ -274,4 +274,24 @@ impl World {
         crate::body::body_joints_into_impl(body, out);
         Ok(())
     }
+
+    /// Bodies reachable from `body` through joints within `max_depth` joint hops, in
+    /// breadth-first order starting with `body` itself.
+    ///
+    /// Useful for selecting a whole mechanism in an editor or damaging an articulated structure
+    /// as a unit. Static bodies are included when reached but not traversed through, so two
+    /// ragdolls pinned to the same ground stay separate. Pass `usize::MAX` for no depth limit.
+    pub fn connected_bodies(&self, body: BodyId, max_depth: usize) -> Vec<BodyId> {
+        crate::core::debug_checks::assert_body_valid(body);
+        crate::body::body_connected_bodies_impl(body, max_depth)
+    }
+
+    pub fn try_connected_bodies(
+        &self,
+        body: BodyId,
+        max_depth: usize,
+    ) -> crate::error::ApiResult<Vec<BodyId>> {
+        crate::core::debug_checks::check_body_valid(body)?;
+        Ok(crate::body::body_connected_bodies_impl(body, max_depth))
+    }
 }
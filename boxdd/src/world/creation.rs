@@ -1,5 +1,6 @@
 use super::*;
 
 mod body_lifecycle;
+mod defaults;
 mod joint_builders;
 mod shape_creation;
@@ -3,6 +3,7 @@ use super::*;
 fn create_body_id_impl(world: &mut World, def: BodyDef) -> BodyId {
     let raw = def.0;
     let id = BodyId::from_raw(unsafe { ffi::b2CreateBody(world.raw(), &raw) });
+    world.core.note_body_created(id);
     #[cfg(feature = "serialize")]
     {
         world.core.record_body(id);
@@ -10,6 +11,67 @@ fn create_body_id_impl(world: &mut World, def: BodyDef) -> BodyId {
     id
 }
 
+/// Create the body and its shapes, updating the body's mass once at the end instead of after
+/// every shape. Inputs must already be validated.
+fn create_body_with_shapes_impl(
+    world: &mut World,
+    def: BodyDef,
+    shapes: &[(ShapeDef, crate::shapes::Geometry)],
+) -> (BodyId, Vec<ShapeId>) {
+    let body = create_body_id_impl(world, def);
+    let mut ids = Vec::with_capacity(shapes.len());
+    let mut update_mass = false;
+    for (shape_def, geometry) in shapes {
+        update_mass |= shape_def.updates_body_mass();
+        let mut shape_def = shape_def.clone();
+        shape_def.0.updateBodyMass = false;
+        ids.push(crate::shapes::create_geometry_shape_for_body_impl(
+            world.core.as_ref(),
+            body,
+            &shape_def,
+            geometry,
+        ));
+    }
+    if update_mass {
+        unsafe { ffi::b2Body_ApplyMassFromShapes(raw_body_id(body)) };
+    }
+    (body, ids)
+}
+
+#[track_caller]
+fn assert_body_with_shapes_valid(def: &BodyDef, shapes: &[(ShapeDef, crate::shapes::Geometry)]) {
+    crate::core::callback_state::assert_not_in_callback();
+    crate::body::assert_body_def_valid(def);
+    for (shape_def, geometry) in shapes {
+        crate::shapes::assert_shape_def_valid(shape_def);
+        crate::shapes::assert_geometry_valid(geometry);
+    }
+}
+
+fn check_body_with_shapes_valid(
+    def: &BodyDef,
+    shapes: &[(ShapeDef, crate::shapes::Geometry)],
+) -> crate::error::ApiResult<()> {
+    crate::core::callback_state::check_not_in_callback()?;
+    crate::body::check_body_def_valid(def)?;
+    for (shape_def, geometry) in shapes {
+        crate::shapes::check_shape_def_valid(shape_def)?;
+        crate::shapes::check_geometry_valid(geometry)?;
+    }
+    Ok(())
+}
+
+fn destroy_body_take_impl(world: &mut World, id: BodyId) -> crate::body::BodyFinalState {
+    let state = crate::body::BodyFinalState {
+        body_type: crate::body::body_type_impl(id),
+        transform: crate::body::body_transform_impl(id),
+        linear_velocity: crate::body::body_linear_velocity_impl(id),
+        angular_velocity: crate::body::body_angular_velocity_impl(id),
+    };
+    world.core.destroy_body_now(id);
+    state
+}
+
 impl World {
     /// Create a body owned by this world.
     pub fn create_body<'w>(&'w mut self, def: BodyDef) -> Body<'w> {
@@ -57,23 +119,97 @@ impl World {
         Ok(create_body_id_impl(self, def))
     }
 
+    /// Create a body together with its shapes, returning the shape ids in input order.
+    ///
+    /// Every definition and geometry is validated before anything is created, and the body's mass
+    /// is computed once at the end rather than after each shape (skipped if no shape def asks
+    /// for it through `update_body_mass`).
+    ///
+    /// ```no_run
+    /// use boxdd::{BodyBuilder, BodyType, ShapeDef, World, WorldDef, shapes};
+    /// let mut world = World::new(WorldDef::default()).unwrap();
+    /// let def = ShapeDef::builder().density(1.0).build();
+    /// let (cart, shapes) = world.create_body_with_shapes(
+    ///     BodyBuilder::new().body_type(BodyType::Dynamic).build(),
+    ///     &[
+    ///         (def.clone(), shapes::box_polygon(1.0, 0.25).into()),
+    ///         (def.clone(), shapes::circle([-0.7, -0.3], 0.2).into()),
+    ///         (def, shapes::circle([0.7, -0.3], 0.2).into()),
+    ///     ],
+    /// );
+    /// # let _ = (cart, shapes);
+    /// ```
+    pub fn create_body_with_shapes(
+        &mut self,
+        def: BodyDef,
+        shapes: &[(ShapeDef, crate::shapes::Geometry)],
+    ) -> (BodyId, Vec<ShapeId>) {
+        assert_body_with_shapes_valid(&def, shapes);
+        create_body_with_shapes_impl(self, def, shapes)
+    }
+
+    pub fn try_create_body_with_shapes(
+        &mut self,
+        def: BodyDef,
+        shapes: &[(ShapeDef, crate::shapes::Geometry)],
+    ) -> crate::error::ApiResult<(BodyId, Vec<ShapeId>)> {
+        check_body_with_shapes_valid(&def, shapes)?;
+        Ok(create_body_with_shapes_impl(self, def, shapes))
+    }
+
+    /// RAII variant of [`World::create_body_with_shapes`]. Dropping the body destroys its shapes
+    /// too, so they are returned as plain ids.
+    pub fn create_body_with_shapes_owned(
+        &mut self,
+        def: BodyDef,
+        shapes: &[(ShapeDef, crate::shapes::Geometry)],
+    ) -> (crate::body::OwnedBody, Vec<ShapeId>) {
+        assert_body_with_shapes_valid(&def, shapes);
+        let (body, ids) = create_body_with_shapes_impl(self, def, shapes);
+        (crate::body::OwnedBody::new(self.core_arc(), body), ids)
+    }
+
+    pub fn try_create_body_with_shapes_owned(
+        &mut self,
+        def: BodyDef,
+        shapes: &[(ShapeDef, crate::shapes::Geometry)],
+    ) -> crate::error::ApiResult<(crate::body::OwnedBody, Vec<ShapeId>)> {
+        check_body_with_shapes_valid(&def, shapes)?;
+        let (body, ids) = create_body_with_shapes_impl(self, def, shapes);
+        Ok((crate::body::OwnedBody::new(self.core_arc(), body), ids))
+    }
+
     /// Destroy a body by id.
     pub fn destroy_body_id(&mut self, id: BodyId) {
         crate::core::callback_state::assert_not_in_callback();
         if unsafe { ffi::b2Body_IsValid(raw_body_id(id)) } {
-            #[cfg(feature = "serialize")]
-            self.core.cleanup_before_destroy_body(id);
-            unsafe { ffi::b2DestroyBody(raw_body_id(id)) };
-            let _ = self.core.clear_body_user_data(id);
+            self.core.destroy_body_now(id);
         }
     }
 
+    /// Destroy a body and return its last transform and velocities, read in the same call.
+    ///
+    /// Useful for save systems, kill-cams, and debris spawned where something died. Panics if
+    /// `id` is invalid.
+    pub fn destroy_body_take(&mut self, id: BodyId) -> crate::body::BodyFinalState {
+        crate::core::callback_state::assert_not_in_callback();
+        crate::core::debug_checks::assert_body_valid(id);
+        destroy_body_take_impl(self, id)
+    }
+
+    pub fn try_destroy_body_take(
+        &mut self,
+        id: BodyId,
+    ) -> crate::error::ApiResult<crate::body::BodyFinalState> {
+        crate::core::callback_state::check_not_in_callback()?;
+        crate::core::debug_checks::check_body_valid(id)?;
+        Ok(destroy_body_take_impl(self, id))
+    }
+
     pub fn try_destroy_body_id(&mut self, id: BodyId) -> crate::error::ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
         crate::core::debug_checks::check_body_valid(id)?;
-        #[cfg(feature = "serialize")]
-        self.core.cleanup_before_destroy_body(id);
-        unsafe { ffi::b2DestroyBody(raw_body_id(id)) };
-        let _ = self.core.clear_body_user_data(id);
+        self.core.destroy_body_now(id);
         Ok(())
     }
 }
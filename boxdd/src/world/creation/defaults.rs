@@ -0,0 +1,133 @@
+use super::*;
+
+impl World {
+    /// Body definition used by [`World::create_body_simple`]. Starts as Box2D's default.
+    pub fn default_body_def(&self) -> BodyDef {
+        self.core
+            .creation_defaults
+            .lock()
+            .expect("creation_defaults mutex poisoned")
+            .body
+            .clone()
+    }
+
+    /// Replace the body definition used by [`World::create_body_simple`].
+    pub fn set_default_body_def(&mut self, def: BodyDef) {
+        crate::body::assert_body_def_valid(&def);
+        self.core
+            .creation_defaults
+            .lock()
+            .expect("creation_defaults mutex poisoned")
+            .body = def;
+    }
+
+    pub fn try_set_default_body_def(&mut self, def: BodyDef) -> crate::error::ApiResult<()> {
+        crate::body::check_body_def_valid(&def)?;
+        self.core
+            .creation_defaults
+            .lock()
+            .expect("creation_defaults mutex poisoned")
+            .body = def;
+        Ok(())
+    }
+
+    /// Shape definition used by [`World::create_shape_simple`]. Starts as Box2D's default with
+    /// the event flags from `WorldBuilder::default_enable_contact_events` /
+    /// `default_enable_hit_events`.
+    pub fn default_shape_def(&self) -> ShapeDef {
+        self.core
+            .creation_defaults
+            .lock()
+            .expect("creation_defaults mutex poisoned")
+            .shape
+            .clone()
+    }
+
+    /// Replace the shape definition used by [`World::create_shape_simple`]. Event flags `def` does
+    /// not set explicitly keep the world's defaults.
+    ///
+    /// ```no_run
+    /// use boxdd::{BodyBuilder, BodyType, ShapeDef, SurfaceMaterial, World, WorldDef, shapes};
+    /// let mut world = World::new(WorldDef::default()).unwrap();
+    /// world.set_default_body_def(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+    /// world.set_default_shape_def(
+    ///     ShapeDef::builder()
+    ///         .density(1.0)
+    ///         .material(SurfaceMaterial::default().with_friction(0.8))
+    ///         .build(),
+    /// );
+    /// for i in 0..10 {
+    ///     let body = world.create_body_simple([0.0, i as f32]);
+    ///     world.create_shape_simple(body, shapes::box_polygon(0.5, 0.5));
+    /// }
+    /// ```
+    pub fn set_default_shape_def(&mut self, def: ShapeDef) {
+        crate::shapes::assert_shape_def_valid(&def);
+        let mut defaults = self
+            .core
+            .creation_defaults
+            .lock()
+            .expect("creation_defaults mutex poisoned");
+        defaults.shape = def;
+        defaults.seed_shape_events();
+    }
+
+    pub fn try_set_default_shape_def(&mut self, def: ShapeDef) -> crate::error::ApiResult<()> {
+        crate::shapes::check_shape_def_valid(&def)?;
+        let mut defaults = self
+            .core
+            .creation_defaults
+            .lock()
+            .expect("creation_defaults mutex poisoned");
+        defaults.shape = def;
+        defaults.seed_shape_events();
+        Ok(())
+    }
+
+    /// Create a body from the world's default body definition, placed at `position`.
+    pub fn create_body_simple<V: Into<Vec2>>(&mut self, position: V) -> BodyId {
+        let mut def = self.default_body_def();
+        def.0.position = position.into().into_raw();
+        self.create_body_id(def)
+    }
+
+    pub fn try_create_body_simple<V: Into<Vec2>>(
+        &mut self,
+        position: V,
+    ) -> crate::error::ApiResult<BodyId> {
+        let mut def = self.default_body_def();
+        def.0.position = position.into().into_raw();
+        self.try_create_body_id(def)
+    }
+
+    /// Attach a shape to `body` using the world's default shape definition.
+    pub fn create_shape_simple<G: Into<crate::shapes::Geometry>>(
+        &mut self,
+        body: BodyId,
+        geometry: G,
+    ) -> ShapeId {
+        crate::core::callback_state::assert_not_in_callback();
+        let def = self.default_shape_def();
+        crate::shapes::create_geometry_shape_for_body_impl(
+            self.core.as_ref(),
+            body,
+            &def,
+            &geometry.into(),
+        )
+    }
+
+    pub fn try_create_shape_simple<G: Into<crate::shapes::Geometry>>(
+        &mut self,
+        body: BodyId,
+        geometry: G,
+    ) -> crate::error::ApiResult<ShapeId> {
+        crate::core::callback_state::check_not_in_callback()?;
+        let def = self.default_shape_def();
+        crate::shapes::try_create_geometry_shape_for_body_impl(
+            self.core.as_ref(),
+            body,
+            &def,
+            &geometry.into(),
+        )
+    }
+}
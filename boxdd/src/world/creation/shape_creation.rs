@@ -247,8 +247,7 @@ impl World {
     pub fn destroy_shape_id(&mut self, shape: ShapeId, update_body_mass: bool) {
         crate::core::callback_state::assert_not_in_callback();
         if unsafe { ffi::b2Shape_IsValid(raw_shape_id(shape)) } {
-            unsafe { ffi::b2DestroyShape(raw_shape_id(shape), update_body_mass) };
-            let _ = self.core.clear_shape_user_data(shape);
+            self.core.destroy_shape_now(shape, update_body_mass);
         }
         #[cfg(feature = "serialize")]
         {
@@ -296,7 +295,11 @@ impl World {
     pub fn destroy_chain_id(&mut self, chain: ChainId) {
         crate::core::debug_checks::assert_chain_valid(chain);
         if unsafe { ffi::b2Chain_IsValid(raw_chain_id(chain)) } {
+            let destroyed = self.core.destroyed_with_chain(chain);
+            self.core.forget_chain_shapes(chain);
+            self.core.bump_static_revision();
             unsafe { ffi::b2DestroyChain(raw_chain_id(chain)) };
+            self.core.notify_destroyed(&destroyed);
         }
         #[cfg(feature = "serialize")]
         {
@@ -306,7 +309,11 @@ impl World {
 
     pub fn try_destroy_chain_id(&mut self, chain: ChainId) -> crate::error::ApiResult<()> {
         crate::core::debug_checks::check_chain_valid(chain)?;
+        let destroyed = self.core.destroyed_with_chain(chain);
+        self.core.forget_chain_shapes(chain);
+        self.core.bump_static_revision();
         unsafe { ffi::b2DestroyChain(raw_chain_id(chain)) };
+        self.core.notify_destroyed(&destroyed);
         #[cfg(feature = "serialize")]
         {
             self.core.remove_chain(chain);
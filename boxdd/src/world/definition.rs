@@ -1,3 +1,4 @@
+use super::task_system::{TaskSystem, TaskSystemSlot};
 use super::*;
 
 /// Error type for world creation and operations.
@@ -9,6 +10,10 @@ pub enum Error {
 
     #[error("failed to create Box2D world")]
     CreateFailed,
+
+    /// The definition's [`TaskSystem`] refused another world; see [`TaskSystem::attach_world`].
+    #[error("task system is already attached to another world")]
+    TaskSystemInUse,
 }
 
 #[inline]
@@ -68,41 +73,72 @@ pub(crate) fn check_positive_finite_world_scalar(value: f32) -> crate::error::Ap
     }
 }
 
-#[inline]
-fn check_world_worker_count_valid(worker_count: i32) -> crate::error::ApiResult<()> {
-    if worker_count >= 0 {
-        Ok(())
-    } else {
-        Err(crate::error::ApiError::InvalidArgument)
-    }
+/// Field-level form of the world definition checks, shared by creation and
+/// [`WorldBuilder::build_checked`].
+pub(crate) fn validate_world_def(def: &WorldDef) -> crate::core::def_checks::CheckResult {
+    let c = crate::core::def_checks::DefChecks("WorldDef");
+    c.initialized(world_def_cookie_is_valid(def))?;
+    c.vec2("gravity", def.gravity())?;
+    c.non_negative("restitution_threshold", def.restitution_threshold())?;
+    c.non_negative("hit_event_threshold", def.hit_event_threshold())?;
+    c.non_negative("contact_hertz", def.contact_hertz())?;
+    c.non_negative("contact_damping_ratio", def.contact_damping_ratio())?;
+    c.non_negative("contact_speed", def.contact_speed())?;
+    c.positive("maximum_linear_speed", def.maximum_linear_speed())?;
+    c.at_least_zero("worker_count", def.worker_count())
 }
 
 #[inline]
 pub(crate) fn check_world_def_valid(def: &WorldDef) -> crate::error::ApiResult<()> {
-    if !world_def_cookie_is_valid(def) {
-        return Err(crate::error::ApiError::InvalidArgument);
-    }
-    check_world_gravity_valid(def.gravity())?;
-    check_non_negative_finite_world_scalar(def.restitution_threshold())?;
-    check_non_negative_finite_world_scalar(def.hit_event_threshold())?;
-    check_non_negative_finite_world_scalar(def.contact_hertz())?;
-    check_non_negative_finite_world_scalar(def.contact_damping_ratio())?;
-    check_non_negative_finite_world_scalar(def.contact_speed())?;
-    check_positive_finite_world_scalar(def.maximum_linear_speed())?;
-    check_world_worker_count_valid(def.worker_count())
+    validate_world_def(def).map_err(Into::into)
 }
 
 /// World definition builder for constructing a simulation world.
 #[doc(alias = "world_def")]
 #[doc(alias = "worlddef")]
 #[derive(Clone, Debug)]
-pub struct WorldDef(pub(crate) ffi::b2WorldDef);
+pub struct WorldDef(
+    pub(crate) ffi::b2WorldDef,
+    /// Crate-side event flags for shape definitions that leave them unset.
+    pub(crate) ShapeEventDefaults,
+    /// Safe task system installed on the world at creation.
+    pub(crate) Option<TaskSystemSlot>,
+);
 
 impl Default for WorldDef {
     fn default() -> Self {
         // SAFETY: FFI call to obtain a plain value struct
         let def = unsafe { ffi::b2DefaultWorldDef() };
-        Self(def)
+        Self(def, ShapeEventDefaults::default(), None)
+    }
+}
+
+/// Event flags a world applies to shape definitions that do not set them explicitly.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) struct ShapeEventDefaults {
+    pub(crate) contact_events: bool,
+    pub(crate) hit_events: bool,
+}
+
+impl ShapeEventDefaults {
+    /// Write these flags into `raw` where `def` leaves them unset.
+    pub(crate) fn apply(self, def: &crate::shapes::ShapeDef, raw: &mut ffi::b2ShapeDef) {
+        if !def.2.contact_events {
+            raw.enableContactEvents = self.contact_events;
+        }
+        if !def.2.hit_events {
+            raw.enableHitEvents = self.hit_events;
+        }
+    }
+}
+
+impl Default for ShapeEventDefaults {
+    fn default() -> Self {
+        let def = unsafe { ffi::b2DefaultShapeDef() };
+        Self {
+            contact_events: def.enableContactEvents,
+            hit_events: def.enableHitEvents,
+        }
     }
 }
 
@@ -119,7 +155,7 @@ impl WorldDef {
     /// later used to create or step a world. This constructor does not validate callback
     /// pointers, task contexts, or other raw pointer fields.
     pub unsafe fn from_raw(raw: ffi::b2WorldDef) -> Self {
-        Self(raw)
+        Self(raw, ShapeEventDefaults::default(), None)
     }
 
     pub fn gravity(&self) -> crate::types::Vec2 {
@@ -166,11 +202,47 @@ impl WorldDef {
         self.0.workerCount
     }
 
+    /// Contact-event flag the created world gives shape definitions that do not set one.
+    pub fn default_contact_events_enabled(&self) -> bool {
+        self.1.contact_events
+    }
+
+    /// Hit-event flag the created world gives shape definitions that do not set one.
+    pub fn default_hit_events_enabled(&self) -> bool {
+        self.1.hit_events
+    }
+
     /// Returns whether raw task-system callbacks are installed on this definition.
     pub fn has_task_system_raw(&self) -> bool {
         self.0.enqueueTask.is_some() || self.0.finishTask.is_some()
     }
 
+    /// Returns whether a [`TaskSystem`] is installed on this definition.
+    pub fn has_task_system(&self) -> bool {
+        self.2.is_some()
+    }
+
+    /// Install a [`TaskSystem`] that runs the step's parallel stages on the caller's threads.
+    ///
+    /// Sets `worker_count` to the system's worker count (clamped to `1..=MAX_WORKERS`) and
+    /// replaces any raw task callbacks. The created world keeps the system alive.
+    pub fn set_task_system(&mut self, task_system: impl TaskSystem) {
+        self.set_task_system_arc(Arc::new(task_system));
+    }
+
+    /// [`Self::set_task_system`] for a task system shared between several worlds.
+    ///
+    /// A system may refuse to serve more than one world at a time through
+    /// [`TaskSystem::attach_world`]; [`RayonTaskSystem`](crate::RayonTaskSystem) does.
+    pub fn set_task_system_arc(&mut self, task_system: Arc<dyn TaskSystem>) {
+        let slot = TaskSystemSlot(task_system);
+        self.0.workerCount = slot.worker_count();
+        self.0.enqueueTask = None;
+        self.0.finishTask = None;
+        self.0.userTaskContext = core::ptr::null_mut();
+        self.2 = Some(slot);
+    }
+
     /// Install raw Box2D task-system callbacks on this definition.
     ///
     /// # Safety
@@ -187,10 +259,12 @@ impl WorldDef {
         self.0.enqueueTask = enqueue_task;
         self.0.finishTask = finish_task;
         self.0.userTaskContext = user_task_context;
+        self.2 = None;
     }
 
-    /// Remove any raw Box2D task-system callbacks from this definition.
+    /// Remove any task system from this definition, raw callbacks or [`TaskSystem`].
     pub fn clear_task_system_raw(&mut self) {
+        self.2 = None;
         self.0.workerCount = 0;
         self.0.enqueueTask = None;
         self.0.finishTask = None;
@@ -206,6 +280,74 @@ impl WorldDef {
     }
 }
 
+/// Starting-point world settings for common kinds of games, for [`WorldDef::from_profile`].
+///
+/// Values not listed keep Box2D's defaults. Every profile is a plain [`WorldDef`], so individual
+/// fields can still be changed through [`WorldBuilder::from`].
+///
+/// | Setting | Box2D default | `Platformer2D` | `TopDown` | `Simulation` |
+/// |---|---|---|---|---|
+/// | gravity (m/s²) | (0, -10) | (0, -20) | (0, 0) | (0, -9.81) |
+/// | contact hertz | 30 | 30 | 30 | 60 |
+/// | contact damping ratio | 10 | 10 | 10 | 10 |
+/// | contact speed (m/s) | 3 | 3 | 3 | 2 |
+/// | restitution threshold (m/s) | 1 | 1 | 0.5 | 0.5 |
+/// | sleep | on | on | on | off |
+/// | continuous | on | on | on | on |
+/// | [`substeps`](Self::substeps) | 4 | 4 | 4 | 8 |
+///
+/// - `Platformer2D`: stronger gravity makes jumps feel snappy rather than floaty; continuous
+///   collision keeps fast characters and projectiles from tunneling through thin platforms.
+/// - `TopDown`: no gravity, since "down" is into the screen; friction and damping on bodies stop
+///   things instead. A lower restitution threshold lets slow bumps still bounce.
+/// - `Simulation`: real-world gravity, stiffer contacts, and twice the substeps for accuracy over
+///   speed. Sleep is off so measured bodies never freeze mid-experiment.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum WorldProfile {
+    Platformer2D,
+    TopDown,
+    Simulation,
+}
+
+impl WorldProfile {
+    /// Suggested `sub_steps` argument for [`World::step`](crate::World::step) with this profile.
+    pub const fn substeps(self) -> i32 {
+        match self {
+            Self::Platformer2D | Self::TopDown => 4,
+            Self::Simulation => 8,
+        }
+    }
+}
+
+impl WorldDef {
+    /// World settings tuned for `profile`; see [`WorldProfile`] for the values and reasoning.
+    ///
+    /// ```no_run
+    /// use boxdd::{World, WorldBuilder, WorldDef, WorldProfile};
+    /// let profile = WorldProfile::Platformer2D;
+    /// let def = WorldBuilder::from(WorldDef::from_profile(profile))
+    ///     .enable_sleep(false)
+    ///     .build();
+    /// let mut world = World::new(def).unwrap();
+    /// world.step(1.0 / 60.0, profile.substeps());
+    /// ```
+    pub fn from_profile(profile: WorldProfile) -> Self {
+        let b = Self::builder();
+        let b = match profile {
+            WorldProfile::Platformer2D => b.gravity([0.0, -20.0]),
+            WorldProfile::TopDown => b.gravity([0.0, 0.0]).restitution_threshold(0.5),
+            WorldProfile::Simulation => b
+                .gravity([0.0, -9.81])
+                .contact_hertz(60.0)
+                .contact_speed(2.0)
+                .restitution_threshold(0.5)
+                .enable_sleep(false),
+        };
+        b.enable_continuous(true).build()
+    }
+}
+
 #[cfg(feature = "serde")]
 impl serde::Serialize for WorldDef {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
@@ -225,6 +367,8 @@ impl serde::Serialize for WorldDef {
             enable_continuous: bool,
             enable_contact_softening: bool,
             worker_count: i32,
+            default_enable_contact_events: bool,
+            default_enable_hit_events: bool,
         }
         let r = Repr {
             gravity: crate::types::Vec2::from_raw(self.0.gravity),
@@ -238,6 +382,8 @@ impl serde::Serialize for WorldDef {
             enable_continuous: self.0.enableContinuous,
             enable_contact_softening: self.0.enableContactSoftening,
             worker_count: self.0.workerCount,
+            default_enable_contact_events: self.1.contact_events,
+            default_enable_hit_events: self.1.hit_events,
         };
         r.serialize(serializer)
     }
@@ -273,6 +419,10 @@ impl<'de> serde::Deserialize<'de> for WorldDef {
             enable_contact_softening: Option<bool>,
             #[serde(default)]
             worker_count: Option<i32>,
+            #[serde(default)]
+            default_enable_contact_events: Option<bool>,
+            #[serde(default)]
+            default_enable_hit_events: Option<bool>,
         }
         let r = Repr::deserialize(deserializer)?;
         let mut b = WorldDef::default();
@@ -309,6 +459,12 @@ impl<'de> serde::Deserialize<'de> for WorldDef {
         if let Some(v) = r.worker_count {
             b.0.workerCount = v;
         }
+        if let Some(v) = r.default_enable_contact_events {
+            b.1.contact_events = v;
+        }
+        if let Some(v) = r.default_enable_hit_events {
+            b.1.hit_events = v;
+        }
         Ok(b)
     }
 }
@@ -394,14 +550,48 @@ impl WorldBuilder {
     /// Number of worker threads Box2D may use during stepping when a task system is installed.
     ///
     /// This does not make `World` or owned handles `Send` / `Sync`. Non-zero values only become
-    /// active when advanced users also supply raw task callbacks through
-    /// `unsafe WorldBuilder::task_system_raw(...)`, `WorldDef::set_task_system_raw(...)`, or an
-    /// explicit raw `WorldDef` conversion path.
+    /// active with a task system: the `parallel` feature's rayon pool (installed automatically for
+    /// `n > 1`), a safe [`TaskSystem`] through [`Self::task_system`], or raw callbacks through `unsafe WorldBuilder::task_system_raw(...)`,
+    /// `WorldDef::set_task_system_raw(...)`, or an explicit raw `WorldDef` conversion path.
     pub fn worker_count(mut self, n: i32) -> Self {
         self.def.0.workerCount = n;
         self
     }
 
+    /// Contact begin/end events for shapes whose definition does not set them.
+    ///
+    /// Applies to every shape the created world makes from `ShapeDef::default()`,
+    /// [`ShapeDef::builder`](crate::ShapeDef::builder) without
+    /// [`enable_contact_events`](crate::ShapeDefBuilder::enable_contact_events), and
+    /// [`World::default_shape_def`](crate::World::default_shape_def), including definitions passed
+    /// to [`World::set_default_shape_def`](crate::World::set_default_shape_def). Definitions that set
+    /// the flag, or come from [`ShapeDef::from_raw`](crate::ShapeDef::from_raw), are used as given.
+    pub fn default_enable_contact_events(mut self, flag: bool) -> Self {
+        self.def.1.contact_events = flag;
+        self
+    }
+
+    /// Hit events for shapes whose definition does not set them. Hit events are off in Box2D's
+    /// default shape definition, which is the usual reason a world reports none.
+    ///
+    /// Applies like [`Self::default_enable_contact_events`]: definitions that call
+    /// [`enable_hit_events`](crate::ShapeDefBuilder::enable_hit_events) or come from
+    /// [`ShapeDef::from_raw`](crate::ShapeDef::from_raw) are used as given.
+    ///
+    /// ```no_run
+    /// use boxdd::{BodyBuilder, BodyType, ShapeDef, World, WorldDef, shapes};
+    /// let mut world = World::new(WorldDef::builder().default_enable_hit_events(true).build()).unwrap();
+    /// assert!(world.default_shape_def().hit_events_enabled());
+    /// let body = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+    /// // Reports hit events even though `ShapeDef::default()` leaves them off.
+    /// let shape = world.create_polygon_shape_for(body, &ShapeDef::default(), &shapes::box_polygon(0.5, 0.5));
+    /// assert!(world.shape_hit_events_enabled(shape));
+    /// ```
+    pub fn default_enable_hit_events(mut self, flag: bool) -> Self {
+        self.def.1.hit_events = flag;
+        self
+    }
+
     /// Install raw Box2D task-system callbacks on the builder.
     ///
     /// # Safety
@@ -426,7 +616,19 @@ impl WorldBuilder {
         self
     }
 
-    /// Remove any raw Box2D task-system callbacks from the builder.
+    /// Run the step's parallel stages on `task_system`; see [`WorldDef::set_task_system`].
+    pub fn task_system(mut self, task_system: impl TaskSystem) -> Self {
+        self.def.set_task_system(task_system);
+        self
+    }
+
+    /// Share one [`TaskSystem`] between several worlds; see [`WorldDef::set_task_system`].
+    pub fn task_system_arc(mut self, task_system: Arc<dyn TaskSystem>) -> Self {
+        self.def.set_task_system_arc(task_system);
+        self
+    }
+
+    /// Remove any task system from the builder, raw callbacks or [`TaskSystem`].
     pub fn clear_task_system_raw(mut self) -> Self {
         self.def.clear_task_system_raw();
         self
@@ -436,4 +638,11 @@ impl WorldBuilder {
     pub fn build(self) -> WorldDef {
         self.def
     }
+
+    /// Build the definition, running the same checks as world creation and reporting the first
+    /// failing field. `build()` skips these checks.
+    pub fn build_checked(self) -> Result<WorldDef, crate::error::ValidationError> {
+        validate_world_def(&self.def)?;
+        Ok(self.def)
+    }
 }
@@ -0,0 +1,209 @@
+//! Stepping from wall-clock time.
+//!
+//! [`World::step_duration`] takes a [`Duration`] directly. [`FixedTimestep`] accumulates frame
+//! times in integer nanoseconds and runs as many fixed steps as have elapsed, so the simulated
+//! clock never drifts from the wall clock the way a running `f32` sum does over long sessions.
+
+use std::time::Duration;
+
+use crate::error::{ApiError, ApiResult, StepError};
+
+const NANOS_PER_SEC: u64 = 1_000_000_000;
+/// Default [`FixedTimestep::max_steps`]: enough to absorb ordinary frame-time jitter without a
+/// stall turning into a spiral of catch-up steps.
+const DEFAULT_MAX_STEPS: u32 = 8;
+
+impl crate::World {
+    /// Step the simulation by `time_step` using `sub_steps` sub-steps.
+    ///
+    /// Panics if `sub_steps <= 0` or if called inside a Box2D callback.
+    pub fn step_duration(&mut self, time_step: Duration, sub_steps: i32) {
+        self.step(time_step.as_secs_f32(), sub_steps);
+    }
+
+    /// Recoverable version of [`World::step_duration`].
+    pub fn try_step_duration(
+        &mut self,
+        time_step: Duration,
+        sub_steps: i32,
+    ) -> Result<(), StepError> {
+        self.try_step(time_step.as_secs_f32(), sub_steps)
+    }
+}
+
+/// Fixed-rate stepping driven by elapsed wall-clock time.
+///
+/// Feed each frame's elapsed time to [`advance`](Self::advance); it runs one world step per whole
+/// `step` that has accumulated and keeps the remainder for the next frame. All bookkeeping is in
+/// integer nanoseconds: after `n` steps [`simulated_time`](Self::simulated_time) is exactly
+/// `n * step`, and the leftover is exactly what was fed in minus that.
+///
+/// ```no_run
+/// use std::time::{Duration, Instant};
+/// use boxdd::{FixedTimestep, World, WorldDef};
+/// let mut world = World::new(WorldDef::default()).unwrap();
+/// let mut clock = FixedTimestep::from_hz(60, 4).max_steps(5);
+/// let mut last = Instant::now();
+/// loop {
+///     let now = Instant::now();
+///     clock.advance(&mut world, now - last);
+///     last = now;
+///     let _blend = clock.alpha(); // interpolate rendering between the last two steps
+///     # break;
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct FixedTimestep {
+    step_nanos: u64,
+    sub_steps: i32,
+    max_steps: u32,
+    accumulated: u64,
+    steps: u64,
+}
+
+impl FixedTimestep {
+    /// Step by `step` with `sub_steps` sub-steps. Panics if `step` is zero or `sub_steps <= 0`.
+    pub fn new(step: Duration, sub_steps: i32) -> Self {
+        assert!(!step.is_zero(), "step must be > 0");
+        assert!(sub_steps > 0, "sub_steps must be > 0, got {sub_steps}");
+        Self::new_unchecked(step, sub_steps)
+    }
+
+    /// Recoverable version of [`Self::new`].
+    pub fn try_new(step: Duration, sub_steps: i32) -> ApiResult<Self> {
+        if step.is_zero() || sub_steps <= 0 {
+            return Err(ApiError::InvalidArgument);
+        }
+        Ok(Self::new_unchecked(step, sub_steps))
+    }
+
+    /// Step `hz` times per second, rounding the step to the nearest nanosecond.
+    ///
+    /// Panics if `hz` is zero or `sub_steps <= 0`.
+    pub fn from_hz(hz: u32, sub_steps: i32) -> Self {
+        assert!(hz > 0, "hz must be > 0");
+        Self::new(
+            Duration::from_nanos((NANOS_PER_SEC + u64::from(hz) / 2) / u64::from(hz)),
+            sub_steps,
+        )
+    }
+
+    fn new_unchecked(step: Duration, sub_steps: i32) -> Self {
+        Self {
+            step_nanos: u64::try_from(step.as_nanos()).unwrap_or(u64::MAX),
+            sub_steps,
+            max_steps: DEFAULT_MAX_STEPS,
+            accumulated: 0,
+            steps: 0,
+        }
+    }
+
+    /// Cap the steps one [`advance`](Self::advance) may run. Time beyond the cap is dropped, so
+    /// a long stall (a breakpoint, a hitch) slows the simulation down instead of making every
+    /// following frame catch up. Defaults to 8; pass `u32::MAX` to never drop time. A cap of zero
+    /// is treated as one. The last step of an `advance` that dropped time reports
+    /// [`StepInfo::clamped`](crate::StepInfo).
+    pub fn max_steps(mut self, max_steps: u32) -> Self {
+        self.max_steps = max_steps.max(1);
+        self
+    }
+
+    /// Length of one step.
+    pub fn step(&self) -> Duration {
+        Duration::from_nanos(self.step_nanos)
+    }
+
+    pub fn sub_steps(&self) -> i32 {
+        self.sub_steps
+    }
+
+    /// Accumulated time not yet simulated; always shorter than one step after `advance`.
+    pub fn pending(&self) -> Duration {
+        Duration::from_nanos(self.accumulated)
+    }
+
+    /// Steps run since creation or the last [`reset`](Self::reset).
+    pub fn steps(&self) -> u64 {
+        self.steps
+    }
+
+    /// Simulated time: exactly `steps() * step()`.
+    pub fn simulated_time(&self) -> Duration {
+        Duration::from_nanos(self.steps.saturating_mul(self.step_nanos))
+    }
+
+    /// How far the pending time reaches into the next step, in `[0, 1)`; use it to interpolate
+    /// rendered transforms between the previous and current step.
+    pub fn alpha(&self) -> f32 {
+        (self.accumulated as f64 / self.step_nanos as f64) as f32
+    }
+
+    /// Forget pending time and the step count.
+    pub fn reset(&mut self) {
+        self.accumulated = 0;
+        self.steps = 0;
+    }
+
+    /// Add `elapsed` and step `world` once per whole step accumulated. Returns the steps run.
+    ///
+    /// Panics if called inside a Box2D callback, or resumes a callback panic like
+    /// [`World::step`](crate::World::step).
+    pub fn advance(&mut self, world: &mut crate::World, elapsed: Duration) -> u32 {
+        crate::core::callback_state::assert_not_in_callback();
+        let (due, dropped) = self.accumulate(elapsed);
+        let dt = self.step().as_secs_f32();
+        for _ in 0..due {
+            world.step(dt, self.sub_steps);
+        }
+        if dropped {
+            world.mark_last_step_clamped();
+        }
+        due
+    }
+
+    /// Recoverable version of [`Self::advance`]. Stops at the first failed step; steps that ran
+    /// before it stay counted.
+    pub fn try_advance(
+        &mut self,
+        world: &mut crate::World,
+        elapsed: Duration,
+    ) -> Result<u32, StepError> {
+        if crate::core::callback_state::in_callback() {
+            return Err(StepError::InCallback);
+        }
+        let (due, dropped) = self.accumulate(elapsed);
+        let dt = self.step().as_secs_f32();
+        for ran in 0..due {
+            if let Err(e) = world.try_step(dt, self.sub_steps) {
+                // Give back the steps that did not run; a callback panic still completed its step.
+                let completed = ran + u32::from(matches!(e, StepError::CallbackPanicked(_)));
+                let unrun = u64::from(due - completed);
+                self.steps -= unrun;
+                self.accumulated = self
+                    .accumulated
+                    .saturating_add(unrun.saturating_mul(self.step_nanos));
+                return Err(e);
+            }
+        }
+        if dropped {
+            world.mark_last_step_clamped();
+        }
+        Ok(due)
+    }
+
+    /// Add `elapsed` and take the due steps out of the accumulator. Also reports whether time
+    /// beyond `max_steps` was dropped.
+    fn accumulate(&mut self, elapsed: Duration) -> (u32, bool) {
+        let elapsed = u64::try_from(elapsed.as_nanos()).unwrap_or(u64::MAX);
+        self.accumulated = self.accumulated.saturating_add(elapsed);
+        let whole = self.accumulated / self.step_nanos;
+        let due = whole.min(u64::from(self.max_steps)) as u32;
+        self.accumulated = if whole > u64::from(self.max_steps) {
+            self.accumulated % self.step_nanos
+        } else {
+            self.accumulated - whole * self.step_nanos
+        };
+        self.steps += u64::from(due);
+        (due, whole > u64::from(self.max_steps))
+    }
+}
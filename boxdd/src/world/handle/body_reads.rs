@@ -271,6 +271,21 @@ impl WorldHandle {
         Ok(())
     }
 
+    /// See [`World::connected_bodies`](crate::World::connected_bodies).
+    pub fn connected_bodies(&self, body: BodyId, max_depth: usize) -> Vec<BodyId> {
+        crate::core::debug_checks::assert_body_valid(body);
+        crate::body::body_connected_bodies_impl(body, max_depth)
+    }
+
+    pub fn try_connected_bodies(
+        &self,
+        body: BodyId,
+        max_depth: usize,
+    ) -> crate::error::ApiResult<Vec<BodyId>> {
+        crate::core::debug_checks::check_body_valid(body)?;
+        Ok(crate::body::body_connected_bodies_impl(body, max_depth))
+    }
+
     pub fn body_type(&self, body: BodyId) -> BodyType {
         crate::core::debug_checks::assert_body_valid(body);
         crate::body::body_type_impl(body)
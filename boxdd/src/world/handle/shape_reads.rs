@@ -24,6 +24,16 @@ impl WorldHandle {
         Ok(crate::shapes::shape_body_id_impl(shape))
     }
 
+    pub fn chain_of_shape(&self, shape: ShapeId) -> Option<ChainId> {
+        crate::core::debug_checks::assert_shape_valid(shape);
+        crate::shapes::shape_parent_chain_id_impl(shape)
+    }
+
+    pub fn try_chain_of_shape(&self, shape: ShapeId) -> crate::error::ApiResult<Option<ChainId>> {
+        crate::core::debug_checks::check_shape_valid(shape)?;
+        Ok(crate::shapes::shape_parent_chain_id_impl(shape))
+    }
+
     pub fn shape_aabb(&self, shape: ShapeId) -> Aabb {
         crate::core::debug_checks::assert_shape_valid(shape);
         crate::shapes::shape_aabb_impl(shape)
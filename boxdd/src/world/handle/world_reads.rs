@@ -17,6 +17,10 @@ impl WorldHandle {
         self.world_id_raw()
     }
 
+    pub(crate) fn core(&self) -> &WorldCore {
+        &self.core
+    }
+
     pub fn gravity(&self) -> Vec2 {
         world_gravity_checked_impl(self.raw())
     }
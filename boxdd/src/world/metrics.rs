@@ -21,8 +21,104 @@ impl OutstandingOwnedHandles {
     }
 }
 
+/// Forces and impulses applied to one body through the wrapper's API during a step, reported by
+/// [`World::applied_forces`] (`force-tracking` feature).
+///
+/// Off-center forces and impulses contribute their moment about the center of mass to `torque`
+/// and `angular_impulse`. Gravity, contacts, joints, and raw FFI calls are not included.
+#[cfg(feature = "force-tracking")]
+#[cfg_attr(docsrs, doc(cfg(feature = "force-tracking")))]
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct AppliedForces {
+    /// Sum of applied forces (N).
+    pub force: Vec2,
+    /// Sum of applied torques (N·m).
+    pub torque: f32,
+    /// Sum of applied linear impulses (N·s).
+    pub linear_impulse: Vec2,
+    /// Sum of applied angular impulses (N·m·s).
+    pub angular_impulse: f32,
+    /// Number of apply calls, which makes several controllers fighting over a body stand out.
+    pub calls: u32,
+}
+
+/// Entry counts of the side tables a `World` keeps next to Box2D, reported by
+/// [`World::registry_sizes`].
+///
+/// Destroying bodies, shapes, and joints through the wrapper prunes these as it goes; a count that
+/// keeps growing in a long session means ids are being destroyed behind the wrapper's back (raw
+/// FFI calls), which [`World::compact_registries`] cleans up.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct RegistrySizes {
+    /// Body ids recorded for serialization (`serialize` feature only).
+    pub bodies: usize,
+    /// Chain creation records (`serialize` feature only).
+    pub chains: usize,
+    /// Shape flag records (`serialize` feature only).
+    pub shape_flags: usize,
+    pub body_user_data: usize,
+    pub shape_user_data: usize,
+    pub joint_user_data: usize,
+    /// Bodies whose gravity scale is overridden by a category rule.
+    pub gravity_overrides: usize,
+    /// Bodies tracked by the active region.
+    pub active_region_bodies: usize,
+    /// Shapes with non-zero tag bits.
+    pub shape_tags: usize,
+    /// Shape pairs with a contact override.
+    pub contact_overrides: usize,
+    /// Shapes registered as one-way platforms.
+    pub one_way_platforms: usize,
+    /// Cached body bounding radii.
+    pub bounding_radii: usize,
+    /// Joints built by `World::mouse_joint`.
+    pub mouse_joints: usize,
+}
+
+impl RegistrySizes {
+    pub fn total(&self) -> usize {
+        self.bodies
+            + self.chains
+            + self.shape_flags
+            + self.body_user_data
+            + self.shape_user_data
+            + self.joint_user_data
+            + self.gravity_overrides
+            + self.active_region_bodies
+            + self.shape_tags
+            + self.contact_overrides
+            + self.one_way_platforms
+            + self.bounding_radii
+            + self.mouse_joints
+    }
+}
+
+/// Static broad-phase tree shape reported by [`World::static_tree_hint`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub struct StaticTreeHint {
+    /// Shapes on static bodies, i.e. proxies in the static tree.
+    pub static_shape_count: usize,
+    /// Current height of the static tree.
+    pub static_tree_height: i32,
+    /// Height of a perfectly balanced binary tree over `static_shape_count` leaves.
+    pub balanced_height: i32,
+}
+
+impl StaticTreeHint {
+    /// How many levels taller the static tree is than a balanced one.
+    pub fn excess_height(&self) -> i32 {
+        (self.static_tree_height - self.balanced_height).max(0)
+    }
+
+    /// Heuristic: the static tree is more than twice as tall as a balanced tree, so
+    /// [`World::optimize_static_tree`] is likely to make queries and new contacts cheaper.
+    pub fn should_rebuild(&self) -> bool {
+        self.static_shape_count > 1 && self.static_tree_height > 2 * self.balanced_height
+    }
+}
+
 /// Simulation counters providing size and internal stats.
-#[derive(Clone, Debug)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct Counters {
     pub body_count: i32,
     pub shape_count: i32,
@@ -54,6 +150,32 @@ impl Counters {
             color_counts: raw.colorCounts,
         }
     }
+
+    /// Field-wise `self - earlier`. Negative values mean the count went down.
+    pub fn delta(&self, earlier: &Counters) -> Counters {
+        self.zip_with(earlier, i32::wrapping_sub)
+    }
+
+    /// Field-wise maximum of `self` and `other`.
+    pub fn max(&self, other: &Counters) -> Counters {
+        self.zip_with(other, i32::max)
+    }
+
+    fn zip_with(&self, other: &Counters, f: impl Fn(i32, i32) -> i32) -> Counters {
+        Counters {
+            body_count: f(self.body_count, other.body_count),
+            shape_count: f(self.shape_count, other.shape_count),
+            contact_count: f(self.contact_count, other.contact_count),
+            joint_count: f(self.joint_count, other.joint_count),
+            island_count: f(self.island_count, other.island_count),
+            stack_used: f(self.stack_used, other.stack_used),
+            static_tree_height: f(self.static_tree_height, other.static_tree_height),
+            tree_height: f(self.tree_height, other.tree_height),
+            byte_count: f(self.byte_count, other.byte_count),
+            task_count: f(self.task_count, other.task_count),
+            color_counts: std::array::from_fn(|i| f(self.color_counts[i], other.color_counts[i])),
+        }
+    }
 }
 
 /// Simulation profile timings in milliseconds for the last completed world step.
@@ -143,3 +265,98 @@ impl Profile {
         }
     }
 }
+
+/// Broad-phase query families counted by [`World::enable_query_stats`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum QueryKind {
+    /// `cast_ray_*`.
+    Ray,
+    /// `overlap_*` and `collide_mover*`.
+    Overlap,
+    /// `cast_shape*` and `cast_mover`.
+    Cast,
+}
+
+/// Thresholds above which a query is recorded in [`QueryStats::slow_queries`].
+///
+/// A query is slow if it exceeds any budget that is set. With no budget set only counts and
+/// totals are collected.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct QueryBudget {
+    /// Broad-phase tree nodes a single query may visit.
+    pub max_node_visits: Option<i32>,
+    /// Wall-clock time a single query may take, in milliseconds. Not measured on
+    /// `wasm32-unknown-unknown`, which has no clock.
+    pub max_time_ms: Option<f32>,
+    /// Maximum slow queries kept between [`World::take_query_stats`] calls; later ones are only
+    /// counted in [`QueryStats::dropped_slow_queries`].
+    pub max_recorded: usize,
+}
+
+impl Default for QueryBudget {
+    fn default() -> Self {
+        Self {
+            max_node_visits: None,
+            max_time_ms: None,
+            max_recorded: 256,
+        }
+    }
+}
+
+impl QueryBudget {
+    pub fn max_node_visits(mut self, n: i32) -> Self {
+        self.max_node_visits = Some(n);
+        self
+    }
+    pub fn max_time_ms(mut self, ms: f32) -> Self {
+        self.max_time_ms = Some(ms);
+        self
+    }
+    pub fn max_recorded(mut self, n: usize) -> Self {
+        self.max_recorded = n;
+        self
+    }
+}
+
+/// A query that exceeded the [`QueryBudget`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SlowQuery {
+    pub kind: QueryKind,
+    /// Tree traversal counters; `None` for queries Box2D does not report them for
+    /// (`cast_ray_closest`, `cast_mover`, `collide_mover*`).
+    pub tree_stats: Option<TreeStats>,
+    pub elapsed_ms: f32,
+}
+
+/// Query counts collected since [`World::enable_query_stats`] or the last
+/// [`World::take_query_stats`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct QueryStats {
+    pub rays: u32,
+    pub overlaps: u32,
+    pub casts: u32,
+    /// Tree nodes visited, summed over queries that report tree stats.
+    pub node_visits: u64,
+    pub leaf_visits: u64,
+    /// Wall-clock time spent inside Box2D query calls, in milliseconds.
+    pub elapsed_ms: f32,
+    /// Queries over budget, in call order.
+    pub slow_queries: Vec<SlowQuery>,
+    /// Slow queries beyond [`QueryBudget::max_recorded`].
+    pub dropped_slow_queries: u32,
+}
+
+impl QueryStats {
+    /// Total number of queries of every kind.
+    pub fn total(&self) -> u32 {
+        self.rays + self.overlaps + self.casts
+    }
+
+    pub fn count(&self, kind: QueryKind) -> u32 {
+        match kind {
+            QueryKind::Ray => self.rays,
+            QueryKind::Overlap => self.overlaps,
+            QueryKind::Cast => self.casts,
+        }
+    }
+}
@@ -1,10 +1,36 @@
 use super::*;
 
+mod active_region;
+#[cfg(feature = "force-tracking")]
+mod applied_forces;
+mod bounding_radius;
+mod broadphase;
 mod callbacks;
+mod contact_overrides;
 mod control;
+mod counter_tracking;
+mod destroy_hooks;
+mod filter_explain;
+mod gravity_rules;
+mod markers;
+mod query_snapshot;
+mod query_stats;
 mod reads;
+mod registries;
+mod shape_tags;
+mod sleep_tracking;
+mod step_info;
+mod user_data;
+
+/// Query bounds large enough to cover every proxy in the broad-phase.
+const EVERYWHERE: Aabb = Aabb {
+    lower: Vec2::new(-1.0e18, -1.0e18),
+    upper: Vec2::new(1.0e18, 1.0e18),
+};
 
 pub use callbacks::MaterialMixInput;
+pub use contact_overrides::ContactDisable;
+pub use destroy_hooks::DestroyedEntity;
 pub(crate) use reads::{
     try_world_awake_body_count_impl, try_world_counters_impl, try_world_gravity_impl,
     try_world_hit_event_threshold_impl, try_world_is_continuous_enabled_impl,
@@ -17,3 +43,4 @@ pub(crate) use reads::{
     world_maximum_linear_speed_checked_impl, world_profile_checked_impl,
     world_restitution_threshold_checked_impl,
 };
+pub use step_info::StepInfo;
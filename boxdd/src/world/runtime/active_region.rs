@@ -0,0 +1,137 @@
+use super::*;
+
+use crate::core::world_core::ActiveRegionState;
+use crate::error::{ApiError, ApiResult};
+use crate::query::QueryFilter;
+
+/// Collect sorted, deduplicated non-static bodies with a shape overlapping `aabb`.
+fn movable_bodies_in(world: &World, aabb: Aabb, shapes: &mut Vec<ShapeId>) -> Vec<BodyId> {
+    world.overlap_aabb_into(aabb, QueryFilter::default(), shapes);
+    let mut bodies: Vec<BodyId> = shapes
+        .iter()
+        .map(|&s| crate::shapes::shape_body_id_impl(s))
+        .filter(|&b| crate::body::body_type_impl(b) != BodyType::Static)
+        .collect();
+    bodies.sort_unstable();
+    bodies.dedup();
+    bodies
+}
+
+fn contains_sorted(sorted: &[BodyId], id: BodyId) -> bool {
+    sorted.binary_search(&id).is_ok()
+}
+
+impl World {
+    /// Only simulate bodies near `region`, e.g. the camera view of a large level.
+    ///
+    /// Call once per frame before stepping. Disabled bodies inside `region` are re-enabled;
+    /// enabled dynamic/kinematic bodies that leave `region` grown by `margin` are disabled. The
+    /// gap between the two bounds is the hysteresis that keeps bodies near the edge from toggling
+    /// every frame. Static bodies are never disabled.
+    ///
+    /// The first call sweeps the whole broad-phase; later calls only query the outer region and
+    /// the bodies tracked from the previous call, so bodies created far away after the first call
+    /// stay enabled until they pass through the region. Bodies disabled by the caller are left
+    /// alone. Use [`Self::clear_active_region`] to re-enable everything.
+    pub fn set_active_region(&mut self, region: Aabb, margin: f32) {
+        crate::core::callback_state::assert_not_in_callback();
+        assert!(
+            region.is_valid(),
+            "region must be a valid AABB, got {region:?}"
+        );
+        assert!(
+            margin.is_finite() && margin >= 0.0,
+            "margin must be finite and >= 0.0, got {margin}"
+        );
+        self.update_active_region(region, margin);
+    }
+
+    pub fn try_set_active_region(&mut self, region: Aabb, margin: f32) -> ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        if !(region.is_valid() && margin.is_finite() && margin >= 0.0) {
+            return Err(ApiError::InvalidArgument);
+        }
+        self.update_active_region(region, margin);
+        Ok(())
+    }
+
+    /// Re-enable every body disabled by [`Self::set_active_region`] and forget the region.
+    pub fn clear_active_region(&mut self) {
+        crate::core::callback_state::assert_not_in_callback();
+        self.clear_active_region_impl();
+    }
+
+    pub fn try_clear_active_region(&mut self) -> ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        self.clear_active_region_impl();
+        Ok(())
+    }
+
+    /// Bodies currently disabled by [`Self::set_active_region`].
+    pub fn active_region_disabled_bodies(&self) -> Vec<BodyId> {
+        self.core
+            .active_region
+            .lock()
+            .expect("active_region mutex poisoned")
+            .as_ref()
+            .map(|state| state.disabled.clone())
+            .unwrap_or_default()
+    }
+
+    fn clear_active_region_impl(&mut self) {
+        let state = self
+            .core
+            .active_region
+            .lock()
+            .expect("active_region mutex poisoned")
+            .take();
+        for body in state.map(|s| s.disabled).unwrap_or_default() {
+            if crate::body::body_is_valid_impl(body) {
+                crate::body::body_enable_impl(&self.core, body);
+            }
+        }
+    }
+
+    fn update_active_region(&mut self, region: Aabb, margin: f32) {
+        let world = &*self;
+        let mut guard = world
+            .core
+            .active_region
+            .lock()
+            .expect("active_region mutex poisoned");
+        let mut shapes = Vec::new();
+        let state = guard.get_or_insert_with(|| ActiveRegionState {
+            inside: movable_bodies_in(world, EVERYWHERE, &mut shapes),
+            disabled: Vec::new(),
+        });
+
+        // Wake up disabled bodies that entered the inner region.
+        let mut still_disabled = Vec::with_capacity(state.disabled.len());
+        for &body in &state.disabled {
+            if !crate::body::body_is_valid_impl(body) {
+                continue;
+            }
+            if crate::body::body_aabb_impl(body).overlaps(region) {
+                crate::body::body_enable_impl(&self.core, body);
+            } else {
+                still_disabled.push(body);
+            }
+        }
+
+        // Disable tracked bodies that left the outer region.
+        let inside = movable_bodies_in(world, region.expanded(margin), &mut shapes);
+        for &body in &state.inside {
+            if contains_sorted(&inside, body)
+                || !crate::body::body_is_valid_impl(body)
+                || !crate::body::body_is_enabled_impl(body)
+            {
+                continue;
+            }
+            crate::body::body_disable_impl(&self.core, body);
+            still_disabled.push(body);
+        }
+        still_disabled.sort_unstable();
+        state.disabled = still_disabled;
+        state.inside = inside;
+    }
+}
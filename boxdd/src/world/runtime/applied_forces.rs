@@ -0,0 +1,48 @@
+use super::*;
+
+impl World {
+    /// Forces and impulses applied to `body` through the API before the last completed step.
+    ///
+    /// Every `apply_*` call on the world or a body handle is logged; each [`World::step`] moves
+    /// the log into this readout and starts a new one. When two controllers fight over a body,
+    /// `calls` and the opposing sums show it even though the net motion looks calm. Returns zeros
+    /// for bodies nothing was applied to.
+    ///
+    /// ```no_run
+    /// use boxdd::{BodyBuilder, BodyType, World, WorldDef};
+    /// let mut world = World::new(WorldDef::default()).unwrap();
+    /// let body = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+    /// world.body(body).unwrap().apply_force_to_center([10.0, 0.0], true);
+    /// world.body(body).unwrap().apply_force_to_center([-10.0, 0.0], true);
+    /// world.step(1.0 / 60.0, 4);
+    /// let applied = world.applied_forces(body);
+    /// assert_eq!(applied.calls, 2);
+    /// ```
+    pub fn applied_forces(&self, body: BodyId) -> AppliedForces {
+        crate::core::debug_checks::assert_body_valid(body);
+        self.core
+            .applied_forces
+            .lock()
+            .expect("applied_forces mutex poisoned")
+            .last_step(body)
+    }
+
+    pub fn try_applied_forces(&self, body: BodyId) -> crate::error::ApiResult<AppliedForces> {
+        crate::core::debug_checks::check_body_valid(body)?;
+        Ok(self
+            .core
+            .applied_forces
+            .lock()
+            .expect("applied_forces mutex poisoned")
+            .last_step(body))
+    }
+
+    /// [`World::applied_forces`] for every body that had something applied, sorted by id.
+    pub fn all_applied_forces(&self) -> Vec<(BodyId, AppliedForces)> {
+        self.core
+            .applied_forces
+            .lock()
+            .expect("applied_forces mutex poisoned")
+            .last_step_all()
+    }
+}
@@ -0,0 +1,76 @@
+use super::*;
+
+use crate::core::world_core::BoundingRadius;
+use crate::error::ApiResult;
+use crate::shapes::ShapeType;
+
+/// Distance from the body origin to the farthest point of `shape`, radius included.
+fn shape_extent(shape: ShapeId) -> f32 {
+    let farthest = |points: &[Vec2]| points.iter().fold(0.0_f32, |m, p| m.max(p.length()));
+    match crate::shapes::shape_type_impl(shape) {
+        ShapeType::Circle => {
+            let c = crate::shapes::shape_circle_impl(shape);
+            c.center.length() + c.radius
+        }
+        ShapeType::Capsule => {
+            let c = crate::shapes::shape_capsule_impl(shape);
+            farthest(&[c.center1, c.center2]) + c.radius
+        }
+        ShapeType::Segment => {
+            let s = crate::shapes::shape_segment_impl(shape);
+            farthest(&[s.point1, s.point2])
+        }
+        ShapeType::ChainSegment => {
+            let s = crate::shapes::shape_chain_segment_impl(shape).segment;
+            farthest(&[s.point1, s.point2])
+        }
+        ShapeType::Polygon => {
+            let p = crate::shapes::shape_polygon_impl(shape);
+            farthest(p.vertices()) + p.radius()
+        }
+    }
+}
+
+impl World {
+    /// Radius of a circle around the body origin that contains every shape of `body`.
+    ///
+    /// A cheap size metric for distance-based LOD, culling, and network relevance: a body is
+    /// certainly outside a view of radius `r` around `p` when
+    /// `distance(body_position, p) > r + body_bounding_radius`. The value is cached per body and
+    /// recomputed after that body's shapes are created, destroyed, or changed through the safe
+    /// API. Bodies without shapes report `0`.
+    pub fn body_bounding_radius(&self, body: BodyId) -> f32 {
+        crate::core::debug_checks::assert_body_valid(body);
+        self.body_bounding_radius_impl(body)
+    }
+
+    pub fn try_body_bounding_radius(&self, body: BodyId) -> ApiResult<f32> {
+        crate::core::debug_checks::check_body_valid(body)?;
+        Ok(self.body_bounding_radius_impl(body))
+    }
+
+    fn body_bounding_radius_impl(&self, body: BodyId) -> f32 {
+        let shape_count = crate::body::body_shape_count_impl(body);
+        let mut cache = self
+            .core
+            .bounding_radii
+            .lock()
+            .expect("bounding_radii mutex poisoned");
+        if let Some(entry) = cache.get(&body)
+            && entry.shape_count == shape_count
+        {
+            return entry.radius;
+        }
+        let radius = crate::body::body_shapes_impl(body)
+            .into_iter()
+            .fold(0.0_f32, |r, shape| r.max(shape_extent(shape)));
+        cache.insert(
+            body,
+            BoundingRadius {
+                radius,
+                shape_count,
+            },
+        );
+        radius
+    }
+}
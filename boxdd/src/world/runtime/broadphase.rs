@@ -0,0 +1,53 @@
+use super::*;
+
+use crate::query::QueryFilter;
+
+impl World {
+    /// Rebuild the static broad-phase tree from scratch.
+    ///
+    /// Box2D inserts static shapes into its tree one at a time, which can leave the tree
+    /// unbalanced after loading thousands of them. Call this once after level load (check
+    /// [`World::static_tree_hint`] before and after to measure the effect); it is not needed
+    /// after individual inserts at runtime.
+    pub fn optimize_static_tree(&mut self) {
+        crate::core::callback_state::assert_not_in_callback();
+        unsafe { ffi::b2World_RebuildStaticTree(self.raw()) }
+    }
+
+    pub fn try_optimize_static_tree(&mut self) -> crate::error::ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        unsafe { ffi::b2World_RebuildStaticTree(self.raw()) }
+        Ok(())
+    }
+
+    /// Static tree height against the height of a balanced tree over the same shapes.
+    ///
+    /// Counting static shapes walks the whole broad-phase, so this is meant for load-time
+    /// diagnostics rather than every frame.
+    pub fn static_tree_hint(&self) -> StaticTreeHint {
+        crate::core::callback_state::assert_not_in_callback();
+        let mut static_shape_count = 0;
+        let everything = QueryFilter::default().category(u64::MAX).mask(u64::MAX);
+        self.visit_overlap_aabb(EVERYWHERE, everything, |shape| {
+            let body = crate::shapes::shape_body_id_impl(shape);
+            if crate::body::body_type_impl(body) == BodyType::Static {
+                static_shape_count += 1;
+            }
+            true
+        });
+        StaticTreeHint {
+            static_shape_count,
+            static_tree_height: self.counters().static_tree_height,
+            balanced_height: balanced_tree_height(static_shape_count),
+        }
+    }
+}
+
+/// Height of a perfectly balanced binary tree with `leaves` leaves (a single leaf has height 0).
+fn balanced_tree_height(leaves: usize) -> i32 {
+    if leaves <= 1 {
+        0
+    } else {
+        (usize::BITS - (leaves - 1).leading_zeros()) as i32
+    }
+}
@@ -89,14 +89,16 @@ unsafe extern "C" fn pre_solve_callback(
     }
     match std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
         let _g = crate::core::callback_state::CallbackGuard::enter();
+        let (a, b) = (ShapeId::from_raw(a), ShapeId::from_raw(b));
+        let normal = crate::types::Vec2::from_raw(normal);
+        if core.note_contact_override(a, b) || core.one_way_decision(a, b, normal) == Some(false) {
+            return false;
+        }
+        let Some(cb) = ctx.cb.as_ref() else {
+            return true;
+        };
         let cw = CallbackWorld::new(Arc::clone(&core));
-        (ctx.cb)(
-            &cw,
-            ShapeId::from_raw(a),
-            ShapeId::from_raw(b),
-            crate::types::Vec2::from_raw(point),
-            crate::types::Vec2::from_raw(normal),
-        )
+        cb(&cw, a, b, crate::types::Vec2::from_raw(point), normal)
     })) {
         Ok(v) => v,
         Err(payload) => {
@@ -155,7 +157,7 @@ impl World {
     {
         let ctx = Box::new(CustomFilterCtx {
             core: Arc::downgrade(&self.core),
-            cb: Box::new(f),
+            cb: Arc::new(f),
         });
         self.install_custom_filter_ctx(ctx);
     }
@@ -196,11 +198,27 @@ impl World {
     {
         let ctx = Box::new(PreSolveCtx {
             core: Arc::downgrade(&self.core),
-            cb: Box::new(f),
+            cb: Some(Box::new(f)),
         });
         self.install_pre_solve_ctx(ctx);
     }
 
+    /// Install the trampoline without a user callback, unless one is already installed.
+    pub(super) fn ensure_pre_solve_installed(&mut self) {
+        let installed = self
+            .core
+            .pre_solve
+            .lock()
+            .expect("pre_solve mutex poisoned")
+            .is_some();
+        if !installed {
+            self.install_pre_solve_ctx(Box::new(PreSolveCtx {
+                core: Arc::downgrade(&self.core),
+                cb: None,
+            }));
+        }
+    }
+
     fn install_pre_solve_ctx(&mut self, ctx: Box<PreSolveCtx>) {
         let ctx_ptr: *mut core::ffi::c_void = (&*ctx) as *const PreSolveCtx as *mut _;
         unsafe { ffi::b2World_SetPreSolveCallback(self.raw(), Some(pre_solve_callback), ctx_ptr) };
@@ -212,6 +230,26 @@ impl World {
     }
 
     fn clear_pre_solve_impl(&mut self) {
+        let overrides_active = !self
+            .core
+            .contact_overrides
+            .lock()
+            .expect("contact_overrides mutex poisoned")
+            .is_empty()
+            || !self
+                .core
+                .one_way_platforms
+                .lock()
+                .expect("one_way_platforms mutex poisoned")
+                .is_empty();
+        if overrides_active {
+            // Keep the trampoline for `disable_contact`, dropping only the user callback.
+            self.install_pre_solve_ctx(Box::new(PreSolveCtx {
+                core: Arc::downgrade(&self.core),
+                cb: None,
+            }));
+            return;
+        }
         unsafe { ffi::b2World_SetPreSolveCallback(self.raw(), None, core::ptr::null_mut()) };
         *self
             .core
@@ -283,6 +321,9 @@ impl World {
     /// Register a thread-safe pre-solve closure. This is called after contact update (when enabled
     /// on shapes) and before the solver. Return false to disable the contact this step.
     ///
+    /// Pairs disabled with [`World::disable_contact`] or rejected by a one-way platform are
+    /// answered by the crate and never reach the closure.
+    ///
     /// Note: Box2D runs this callback while the world is locked. Use the provided `CallbackWorld`
     /// context for operations that must be safe under this constraint (e.g. typed user data).
     pub fn set_pre_solve_with_ctx<F>(&mut self, f: F)
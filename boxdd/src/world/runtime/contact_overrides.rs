@@ -0,0 +1,250 @@
+use super::*;
+
+use crate::core::world_core::ContactOverride;
+use crate::error::{ApiError, ApiResult};
+
+/// How long [`World::disable_contact`] keeps a shape pair from colliding.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum ContactDisable {
+    /// For the next `n` steps. `Frames(0)` re-enables the pair.
+    Frames(u32),
+    /// Until a step ends with the pair no longer touching.
+    ///
+    /// Sleeping pairs are kept, so a body that falls asleep inside the other shape stays
+    /// disabled until it moves out.
+    UntilSeparation,
+    /// For at least `n` steps, then [`UntilSeparation`](Self::UntilSeparation). Covers a pair
+    /// that is about to touch but does not yet.
+    FramesThenSeparation(u32),
+}
+
+#[inline]
+fn pair_key(a: ShapeId, b: ShapeId) -> (ShapeId, ShapeId) {
+    if a <= b { (a, b) } else { (b, a) }
+}
+
+impl World {
+    /// Keep `shape_a` and `shape_b` from colliding for `duration`, answered by the crate's
+    /// pre-solve layer every step so the game does not have to repeat the decision.
+    ///
+    /// Pre-solve events are enabled on both shapes and left on. A pre-solve closure set with
+    /// [`World::set_pre_solve`] keeps running for every other pair; disabled pairs never reach
+    /// it. Calling again for the same pair replaces its duration. The pair stops touching, so it
+    /// reports no contact data and begins touching again (with a begin event) once re-enabled.
+    ///
+    /// Box2D fixes a contact's pre-solve flag when the contact is created. If neither shape had
+    /// pre-solve events enabled yet, `shape_a`'s existing contacts are destroyed so they pick the
+    /// flag up on the next step: its other touching pairs report an end and a new begin event.
+    /// Enable pre-solve events on the `ShapeDef` of shapes that are disabled often to avoid this.
+    ///
+    /// ```no_run
+    /// use boxdd::{ContactDisable, World, WorldDef};
+    /// # fn drop_through(world: &mut World, feet: boxdd::ShapeId, platform: boxdd::ShapeId) {
+    /// // Drop through a platform while "down" is held, landing normally once clear of it.
+    /// world.disable_contact(feet, platform, ContactDisable::UntilSeparation);
+    /// # }
+    /// ```
+    pub fn disable_contact(
+        &mut self,
+        shape_a: ShapeId,
+        shape_b: ShapeId,
+        duration: ContactDisable,
+    ) {
+        crate::core::callback_state::assert_not_in_callback();
+        crate::core::debug_checks::assert_shape_valid(shape_a);
+        crate::core::debug_checks::assert_shape_valid(shape_b);
+        assert!(shape_a != shape_b, "cannot disable a shape against itself");
+        self.disable_contact_impl(shape_a, shape_b, duration);
+    }
+
+    pub fn try_disable_contact(
+        &mut self,
+        shape_a: ShapeId,
+        shape_b: ShapeId,
+        duration: ContactDisable,
+    ) -> ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        crate::core::debug_checks::check_shape_valid(shape_a)?;
+        crate::core::debug_checks::check_shape_valid(shape_b)?;
+        if shape_a == shape_b {
+            return Err(ApiError::InvalidArgument);
+        }
+        self.disable_contact_impl(shape_a, shape_b, duration);
+        Ok(())
+    }
+
+    /// Drop a [`World::disable_contact`] decision early. Returns whether the pair was disabled.
+    pub fn enable_contact(&mut self, shape_a: ShapeId, shape_b: ShapeId) -> bool {
+        crate::core::callback_state::assert_not_in_callback();
+        self.contact_overrides()
+            .remove(&pair_key(shape_a, shape_b))
+            .is_some()
+    }
+
+    pub fn try_enable_contact(&mut self, shape_a: ShapeId, shape_b: ShapeId) -> ApiResult<bool> {
+        crate::core::callback_state::check_not_in_callback()?;
+        Ok(self
+            .contact_overrides()
+            .remove(&pair_key(shape_a, shape_b))
+            .is_some())
+    }
+
+    /// Drop every [`World::disable_contact`] decision involving `shape`.
+    pub(crate) fn enable_contacts_with(&mut self, shape: ShapeId) {
+        self.contact_overrides()
+            .retain(|&(a, b), _| a != shape && b != shape);
+    }
+
+    /// Remaining [`World::disable_contact`] decision for the pair, in either order.
+    pub fn contact_disabled(&self, shape_a: ShapeId, shape_b: ShapeId) -> Option<ContactDisable> {
+        self.contact_overrides()
+            .get(&pair_key(shape_a, shape_b))
+            .map(|entry| entry.duration)
+    }
+
+    /// Make `shape` a one-way platform: other shapes collide with it only when the contact
+    /// normal points along `up` (world space), so bodies pass through from below and land on top.
+    ///
+    /// Decided by the crate's pre-solve layer, which also enables pre-solve events on `shape`.
+    /// A pre-solve closure set with [`World::set_pre_solve`] still sees contacts the platform
+    /// lets through. See [`OneWayPlatform`](crate::OneWayPlatform) for dropping through.
+    ///
+    /// Box2D marks a contact for pre-solve when it is created and either shape has pre-solve
+    /// events enabled, and runs pre-solve only while a dynamic body in the contact is awake, which
+    /// covers every body that can land on the platform. If `shape` did not have pre-solve events
+    /// enabled yet, its existing contacts are destroyed and recreated on the next step (with end
+    /// and begin events) so bodies already touching it are decided too.
+    pub fn set_one_way_platform<V: Into<Vec2>>(&mut self, shape: ShapeId, up: V) {
+        crate::core::callback_state::assert_not_in_callback();
+        crate::core::debug_checks::assert_shape_valid(shape);
+        let up = up.into();
+        assert!(
+            up.is_valid() && up.length() > 0.0,
+            "up must be a valid non-zero Vec2, got {up:?}"
+        );
+        self.set_one_way_platform_impl(shape, up);
+    }
+
+    pub fn try_set_one_way_platform<V: Into<Vec2>>(
+        &mut self,
+        shape: ShapeId,
+        up: V,
+    ) -> ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        crate::core::debug_checks::check_shape_valid(shape)?;
+        let up = up.into();
+        if !(up.is_valid() && up.length() > 0.0) {
+            return Err(ApiError::InvalidArgument);
+        }
+        self.set_one_way_platform_impl(shape, up);
+        Ok(())
+    }
+
+    /// Make `shape` solid from every side again. Returns whether it was a one-way platform.
+    pub fn clear_one_way_platform(&mut self, shape: ShapeId) -> bool {
+        crate::core::callback_state::assert_not_in_callback();
+        self.one_way_platforms().remove(&shape).is_some()
+    }
+
+    pub fn try_clear_one_way_platform(&mut self, shape: ShapeId) -> ApiResult<bool> {
+        crate::core::callback_state::check_not_in_callback()?;
+        Ok(self.one_way_platforms().remove(&shape).is_some())
+    }
+
+    /// Unit `up` direction of a one-way platform, or `None` for ordinary shapes.
+    pub fn one_way_platform_up(&self, shape: ShapeId) -> Option<Vec2> {
+        self.one_way_platforms().get(&shape).copied()
+    }
+
+    fn one_way_platforms(
+        &self,
+    ) -> std::sync::MutexGuard<'_, std::collections::HashMap<ShapeId, Vec2>> {
+        self.core
+            .one_way_platforms
+            .lock()
+            .expect("one_way_platforms mutex poisoned")
+    }
+
+    fn set_one_way_platform_impl(&mut self, shape: ShapeId, up: Vec2) {
+        let latched = crate::shapes::shape_pre_solve_events_enabled_impl(shape);
+        crate::shapes::shape_enable_pre_solve_events_impl(shape, true);
+        if !latched {
+            crate::shapes::shape_reset_contacts_impl(shape);
+        }
+        self.one_way_platforms()
+            .insert(shape, up.scaled(1.0 / up.length()));
+        self.ensure_pre_solve_installed();
+    }
+
+    fn contact_overrides(
+        &self,
+    ) -> std::sync::MutexGuard<'_, std::collections::HashMap<(ShapeId, ShapeId), ContactOverride>>
+    {
+        self.core
+            .contact_overrides
+            .lock()
+            .expect("contact_overrides mutex poisoned")
+    }
+
+    fn disable_contact_impl(&mut self, a: ShapeId, b: ShapeId, duration: ContactDisable) {
+        let key = pair_key(a, b);
+        if duration == ContactDisable::Frames(0) {
+            self.contact_overrides().remove(&key);
+            return;
+        }
+        let latched = crate::shapes::shape_pre_solve_events_enabled_impl(a)
+            || crate::shapes::shape_pre_solve_events_enabled_impl(b);
+        crate::shapes::shape_enable_pre_solve_events_impl(a, true);
+        crate::shapes::shape_enable_pre_solve_events_impl(b, true);
+        if !latched {
+            // Contacts that already exist would never reach the pre-solve layer.
+            crate::shapes::shape_reset_contacts_impl(a);
+        }
+        self.contact_overrides().insert(
+            key,
+            ContactOverride {
+                duration,
+                seen: false,
+            },
+        );
+        self.ensure_pre_solve_installed();
+    }
+
+    /// Count down timed pairs and release separated ones after a step.
+    pub(super) fn advance_contact_overrides(&self) {
+        {
+            let mut platforms = self.one_way_platforms();
+            if !platforms.is_empty() {
+                platforms.retain(|&shape, _| crate::shapes::shape_is_valid_impl(shape));
+            }
+        }
+        let mut overrides = self.contact_overrides();
+        if overrides.is_empty() {
+            return;
+        }
+        overrides.retain(|&(a, b), entry| {
+            if !crate::shapes::shape_is_valid_impl(a) || !crate::shapes::shape_is_valid_impl(b) {
+                return false;
+            }
+            let seen = core::mem::take(&mut entry.seen);
+            match &mut entry.duration {
+                ContactDisable::Frames(n) => {
+                    *n -= 1;
+                    *n > 0
+                }
+                ContactDisable::FramesThenSeparation(n) => {
+                    *n = n.saturating_sub(1);
+                    if *n == 0 {
+                        entry.duration = ContactDisable::UntilSeparation;
+                    }
+                    true
+                }
+                ContactDisable::UntilSeparation => {
+                    seen || !(crate::body::body_is_awake_impl(crate::shapes::shape_body_id_impl(a))
+                        || crate::body::body_is_awake_impl(crate::shapes::shape_body_id_impl(b)))
+                }
+            }
+        });
+    }
+}
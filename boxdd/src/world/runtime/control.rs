@@ -1,28 +1,102 @@
 use super::*;
+use crate::error::StepError;
 
 #[inline]
 fn assert_world_step_args_valid(time_step: f32, sub_steps: i32) {
     assert!(
-        crate::is_valid_float(time_step),
-        "time_step must be finite, got {time_step}"
+        crate::is_valid_float(time_step) && time_step >= 0.0,
+        "time_step must be finite and >= 0.0, got {time_step}"
     );
     assert!(sub_steps > 0, "sub_steps must be > 0, got {sub_steps}");
 }
 
-#[inline]
-fn check_world_step_args_valid(time_step: f32, sub_steps: i32) -> crate::error::ApiResult<()> {
-    if crate::is_valid_float(time_step) && sub_steps > 0 {
-        Ok(())
+fn panic_message(payload: &(dyn std::any::Any + Send)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        (*s).to_owned()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
     } else {
-        Err(crate::error::ApiError::InvalidArgument)
+        "non-string panic payload".to_owned()
     }
 }
 
+/// `(time_step, sub_steps, clamped)` for [`World::step_scaled`], or `None` when the inputs are
+/// out of range or their product is not finite. Sub-steps are clamped to
+/// [`MAX_SCALED_SUB_STEPS`](crate::limits::MAX_SCALED_SUB_STEPS), and `clamped` is set when that
+/// made them longer than `max_substep_dt`. The small slack keeps float round-off (e.g.
+/// `(1/60) / (1/240)` evaluating just above 4) from adding a sub-step.
+fn scaled_step_args(
+    real_dt: f32,
+    time_scale: f32,
+    max_substep_dt: f32,
+) -> Option<(f32, i32, bool)> {
+    let valid = crate::is_valid_float(real_dt)
+        && real_dt >= 0.0
+        && crate::is_valid_float(time_scale)
+        && time_scale >= 0.0
+        && crate::is_valid_float(max_substep_dt)
+        && max_substep_dt > 0.0;
+    let time_step = real_dt * time_scale;
+    if !valid || !crate::is_valid_float(time_step) {
+        return None;
+    }
+    let max_sub_steps = crate::limits::MAX_SCALED_SUB_STEPS as f32;
+    let needed = (time_step / max_substep_dt - 1.0e-4).ceil();
+    let sub_steps = needed.clamp(1.0, max_sub_steps);
+    Some((time_step, sub_steps as i32, needed > max_sub_steps))
+}
+
 impl World {
     /// Step the simulation by `time_step` seconds using `sub_steps` sub-steps.
     pub fn step(&mut self, time_step: f32, sub_steps: i32) {
         crate::core::callback_state::assert_not_in_callback();
         assert_world_step_args_valid(time_step, sub_steps);
+        if let Some(payload) = self.step_impl(time_step, sub_steps) {
+            std::panic::resume_unwind(payload);
+        }
+    }
+
+    /// Step the simulation without panicking on bad input or panicking callbacks.
+    ///
+    /// Rejects non-finite or negative `time_step`, `sub_steps <= 0`, calls from inside a Box2D
+    /// callback, and a world that is no longer valid, without stepping. A panic in a user
+    /// callback during the step is reported as [`StepError::CallbackPanicked`] instead of being
+    /// resumed; the step still completed. Panics remain for unrecoverable states such as
+    /// poisoned internal locks.
+    ///
+    /// ```no_run
+    /// use boxdd::{StepError, World, WorldDef};
+    /// let mut world = World::new(WorldDef::default()).unwrap();
+    /// match world.try_step(f32::NAN, 4) {
+    ///     Err(StepError::InvalidTimeStep(dt)) => eprintln!("skipping step with dt={dt}"),
+    ///     other => other.unwrap(),
+    /// }
+    /// ```
+    pub fn try_step(&mut self, time_step: f32, sub_steps: i32) -> Result<(), StepError> {
+        if crate::core::callback_state::in_callback() {
+            return Err(StepError::InCallback);
+        }
+        if !(crate::is_valid_float(time_step) && time_step >= 0.0) {
+            return Err(StepError::InvalidTimeStep(time_step));
+        }
+        if sub_steps <= 0 {
+            return Err(StepError::InvalidSubSteps(sub_steps));
+        }
+        if !unsafe { ffi::b2World_IsValid(self.raw()) } {
+            return Err(StepError::InvalidWorld);
+        }
+        match self.step_impl(time_step, sub_steps) {
+            None => Ok(()),
+            Some(payload) => Err(StepError::CallbackPanicked(panic_message(payload.as_ref()))),
+        }
+    }
+
+    /// Run one step, returning the payload of the first callback panic, if any.
+    fn step_impl(
+        &mut self,
+        time_step: f32,
+        sub_steps: i32,
+    ) -> Option<Box<dyn std::any::Any + Send + 'static>> {
         // Prepare panic forwarding for callbacks invoked during the FFI call.
         self.core
             .callback_panicked
@@ -32,8 +106,19 @@ impl World {
             .callback_panic
             .lock()
             .expect("callback_panic mutex poisoned") = None;
+        self.apply_gravity_scale_rules();
         // SAFETY: valid world id managed by RAII
         unsafe { ffi::b2World_Step(self.raw(), time_step, sub_steps) };
+        self.record_step_info(time_step, sub_steps);
+        self.record_counters_high_water();
+        self.record_sleep_changes(time_step);
+        self.advance_contact_overrides();
+        #[cfg(feature = "force-tracking")]
+        self.core
+            .applied_forces
+            .lock()
+            .expect("applied_forces mutex poisoned")
+            .finish_step();
 
         // Flush deferred destroys scheduled from callbacks.
         self.core.process_deferred_destroys();
@@ -43,26 +128,96 @@ impl World {
             .callback_panicked
             .load(std::sync::atomic::Ordering::Relaxed)
         {
-            let payload = self
-                .core
+            self.core
                 .callback_panic
                 .lock()
                 .expect("callback_panic mutex poisoned")
-                .take();
-            if let Some(payload) = payload {
-                std::panic::resume_unwind(payload);
-            }
+                .take()
+        } else {
+            None
         }
     }
 
-    /// Step the simulation by `time_step` seconds using `sub_steps` sub-steps.
+    /// Advance by `real_dt * time_scale` seconds of simulated time in one step, choosing enough
+    /// sub-steps that none is longer than `max_substep_dt`, up to
+    /// [`MAX_SCALED_SUB_STEPS`](crate::limits::MAX_SCALED_SUB_STEPS). Returns the sub-step count
+    /// used.
     ///
-    /// Returns `ApiError::InCallback` if called while Box2D is already executing a callback.
-    pub fn try_step(&mut self, time_step: f32, sub_steps: i32) -> crate::error::ApiResult<()> {
-        crate::core::callback_state::check_not_in_callback()?;
-        check_world_step_args_valid(time_step, sub_steps)?;
-        self.step(time_step, sub_steps);
-        Ok(())
+    /// This is the stable way to do slow motion or fast-forward: scaling the time step keeps
+    /// gravity, joint motor speeds, and other rates in simulated seconds, so a motor at 2 rad/s
+    /// still turns 2 rad per simulated second, while the sub-step cap keeps fast-forward from
+    /// feeding the solver oversized steps. Slow motion uses fewer sub-steps, which is safe because
+    /// each one is already short. `time_scale == 0.0` still runs a zero-length step, which only
+    /// updates contacts.
+    ///
+    /// ```no_run
+    /// use boxdd::{World, WorldDef};
+    /// let mut world = World::new(WorldDef::default()).unwrap();
+    /// // Quarter speed, sub-steps capped at 1/240 s.
+    /// let sub_steps = world.step_scaled(1.0 / 60.0, 0.25, 1.0 / 240.0);
+    /// assert_eq!(sub_steps, 1);
+    /// ```
+    ///
+    /// A step that would need more than
+    /// [`MAX_SCALED_SUB_STEPS`](crate::limits::MAX_SCALED_SUB_STEPS) sub-steps still runs, with
+    /// that many proportionally longer sub-steps, so `max_substep_dt` is exceeded. That step's
+    /// [`StepInfo::clamped`] is set; check [`Self::last_step_info`] to catch it:
+    ///
+    /// ```no_run
+    /// use boxdd::{World, WorldDef};
+    /// let mut world = World::new(WorldDef::default()).unwrap();
+    /// // A one-second hitch at 1/240 s sub-steps would need 240 of them.
+    /// world.step_scaled(1.0, 1.0, 1.0 / 240.0);
+    /// assert!(world.last_step_info().unwrap().clamped);
+    /// ```
+    ///
+    /// Panics unless `real_dt` and `time_scale` are finite and `>= 0.0`, `max_substep_dt` is
+    /// finite and `> 0.0`, and `real_dt * time_scale` is finite.
+    pub fn step_scaled(&mut self, real_dt: f32, time_scale: f32, max_substep_dt: f32) -> i32 {
+        crate::core::callback_state::assert_not_in_callback();
+        let Some((time_step, sub_steps, clamped)) =
+            scaled_step_args(real_dt, time_scale, max_substep_dt)
+        else {
+            panic!(
+                "step_scaled needs finite real_dt >= 0, time_scale >= 0 and max_substep_dt > 0 \
+                 with a finite product, got ({real_dt}, {time_scale}, {max_substep_dt})"
+            );
+        };
+        let panic = self.step_impl(time_step, sub_steps);
+        if clamped {
+            self.mark_last_step_clamped();
+        }
+        if let Some(payload) = panic {
+            std::panic::resume_unwind(payload);
+        }
+        sub_steps
+    }
+
+    /// Recoverable version of [`Self::step_scaled`], reporting errors like [`Self::try_step`].
+    ///
+    /// Hitting the sub-step cap is not an error: the step runs as in [`Self::step_scaled`] and
+    /// [`StepInfo::clamped`] is set.
+    pub fn try_step_scaled(
+        &mut self,
+        real_dt: f32,
+        time_scale: f32,
+        max_substep_dt: f32,
+    ) -> Result<i32, StepError> {
+        if crate::core::callback_state::in_callback() {
+            return Err(StepError::InCallback);
+        }
+        let (time_step, sub_steps, clamped) = scaled_step_args(real_dt, time_scale, max_substep_dt)
+            .ok_or(StepError::InvalidScaledStep {
+                real_dt,
+                time_scale,
+                max_substep_dt,
+            })?;
+        let result = self.try_step(time_step, sub_steps);
+        // The step ran unless it was rejected up front.
+        if clamped && matches!(result, Ok(()) | Err(StepError::CallbackPanicked(_))) {
+            self.mark_last_step_clamped();
+        }
+        result.map(|()| sub_steps)
     }
 
     /// Flush deferred destroys scheduled from Box2D callbacks.
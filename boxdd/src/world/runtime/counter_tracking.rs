@@ -0,0 +1,93 @@
+use super::*;
+
+use crate::error::ApiResult;
+
+impl World {
+    /// Counters change since the previous call (the first call compares against zero).
+    ///
+    /// Useful for per-frame logging such as "+12 contacts" without keeping the previous snapshot
+    /// around yourself.
+    pub fn counters_delta(&self) -> Counters {
+        let current = self.counters();
+        self.counters_delta_impl(current)
+    }
+
+    pub fn try_counters_delta(&self) -> ApiResult<Counters> {
+        let current = self.try_counters()?;
+        Ok(self.counters_delta_impl(current))
+    }
+
+    /// Start or stop sampling the counters at the end of every step for
+    /// [`World::counters_high_water`].
+    ///
+    /// Off by default so steps do not pay for the extra counters query. Disabling keeps the
+    /// maxima gathered so far.
+    pub fn enable_counters_high_water(&mut self, flag: bool) {
+        crate::core::callback_state::assert_not_in_callback();
+        self.tracking().high_water_enabled = flag;
+    }
+
+    pub fn try_enable_counters_high_water(&mut self, flag: bool) -> ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        self.tracking().high_water_enabled = flag;
+        Ok(())
+    }
+
+    pub fn is_counters_high_water_enabled(&self) -> bool {
+        self.tracking().high_water_enabled
+    }
+
+    /// Field-wise maximum of the counters seen at the end of every step, for capacity planning.
+    ///
+    /// Steps are only sampled while [`World::enable_counters_high_water`] is on. Includes the
+    /// current counters, so it is meaningful before the first step too. `stack_used` is only
+    /// filled in by stepping.
+    pub fn counters_high_water(&self) -> Counters {
+        let current = self.counters();
+        self.counters_high_water_impl(current)
+    }
+
+    pub fn try_counters_high_water(&self) -> ApiResult<Counters> {
+        let current = self.try_counters()?;
+        Ok(self.counters_high_water_impl(current))
+    }
+
+    /// Restart high-water tracking from the current counters.
+    pub fn reset_counters_high_water(&self) {
+        let current = self.counters();
+        self.tracking().high_water = current;
+    }
+
+    pub fn try_reset_counters_high_water(&self) -> ApiResult<()> {
+        let current = self.try_counters()?;
+        self.tracking().high_water = current;
+        Ok(())
+    }
+
+    pub(super) fn record_counters_high_water(&self) {
+        let mut tracking = self.tracking();
+        if !tracking.high_water_enabled {
+            return;
+        }
+        let current = Counters::from_raw(unsafe { ffi::b2World_GetCounters(self.raw()) });
+        tracking.high_water = tracking.high_water.max(&current);
+    }
+
+    fn counters_delta_impl(&self, current: Counters) -> Counters {
+        let mut tracking = self.tracking();
+        let delta = current.delta(&tracking.last_sample);
+        tracking.last_sample = current;
+        delta
+    }
+
+    fn counters_high_water_impl(&self, current: Counters) -> Counters {
+        self.tracking().high_water.max(&current)
+    }
+
+    fn tracking(&self) -> std::sync::MutexGuard<'_, crate::core::world_core::CounterTracking> {
+        self.core
+            .counter_tracking
+            .lock()
+            .expect("counter_tracking mutex poisoned")
+    }
+}
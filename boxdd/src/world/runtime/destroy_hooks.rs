@@ -0,0 +1,68 @@
+use super::*;
+
+/// An entity destroyed through the crate's destroy paths, reported to [`World::on_destroyed`]
+/// hooks.
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DestroyedEntity {
+    Body(BodyId),
+    Shape(ShapeId),
+    Joint(JointId),
+    Chain(ChainId),
+}
+
+impl World {
+    /// Register a hook called after a body, shape, joint, or chain is destroyed.
+    ///
+    /// Covers `destroy_*` on the world, owned and scoped handles (including deferred drops), and
+    /// the cascades Box2D performs implicitly: destroying a body reports its joints, then its
+    /// shapes (chain segments included), then the body; destroying a chain reports its segments,
+    /// then the chain. Entities still alive when the world itself is dropped are not reported.
+    ///
+    /// Hooks run outside Box2D callbacks, in registration order, and receive only the id, so they
+    /// cannot reenter the world. Use them to evict ids from game-side maps.
+    ///
+    /// ```no_run
+    /// use boxdd::{DestroyedEntity, World, WorldDef};
+    /// use std::sync::{Arc, Mutex};
+    /// let mut world = World::new(WorldDef::default()).unwrap();
+    /// let dead = Arc::new(Mutex::new(Vec::new()));
+    /// let sink = Arc::clone(&dead);
+    /// world.on_destroyed(move |entity: DestroyedEntity| sink.lock().unwrap().push(entity));
+    /// ```
+    pub fn on_destroyed<F>(&mut self, hook: F)
+    where
+        F: FnMut(DestroyedEntity) + Send + 'static,
+    {
+        crate::core::callback_state::assert_not_in_callback();
+        self.push_destroy_hook(Box::new(hook));
+    }
+
+    pub fn try_on_destroyed<F>(&mut self, hook: F) -> crate::error::ApiResult<()>
+    where
+        F: FnMut(DestroyedEntity) + Send + 'static,
+    {
+        crate::core::callback_state::check_not_in_callback()?;
+        self.push_destroy_hook(Box::new(hook));
+        Ok(())
+    }
+
+    /// Remove every hook registered with [`World::on_destroyed`].
+    pub fn clear_destroy_hooks(&mut self) {
+        let old = core::mem::take(
+            &mut *self
+                .core
+                .destroy_hooks
+                .lock()
+                .expect("destroy_hooks mutex poisoned"),
+        );
+        drop(old);
+    }
+
+    fn push_destroy_hook(&mut self, hook: crate::core::world_core::DestroyHook) {
+        self.core
+            .destroy_hooks
+            .lock()
+            .expect("destroy_hooks mutex poisoned")
+            .push(hook);
+    }
+}
@@ -0,0 +1,80 @@
+use super::*;
+
+use crate::error::ApiResult;
+use crate::filter::FilterDecision;
+
+impl World {
+    /// Explain why `shape_a` and `shape_b` do or don't collide: group, category/mask, sensors,
+    /// same body, body types, joints without `collide_connected`, and the custom filter.
+    ///
+    /// The custom filter callback, if installed, is invoked for the pair even when neither shape
+    /// enables custom filtering, because Box2D does not expose that flag at runtime.
+    ///
+    /// Panics if either shape id is invalid.
+    pub fn debug_explain_filter(&self, shape_a: ShapeId, shape_b: ShapeId) -> FilterDecision {
+        crate::core::callback_state::assert_not_in_callback();
+        crate::core::debug_checks::assert_shape_valid(shape_a);
+        crate::core::debug_checks::assert_shape_valid(shape_b);
+        self.explain_filter_impl(shape_a, shape_b)
+    }
+
+    pub fn try_debug_explain_filter(
+        &self,
+        shape_a: ShapeId,
+        shape_b: ShapeId,
+    ) -> ApiResult<FilterDecision> {
+        crate::core::callback_state::check_not_in_callback()?;
+        crate::core::debug_checks::check_shape_valid(shape_a)?;
+        crate::core::debug_checks::check_shape_valid(shape_b)?;
+        Ok(self.explain_filter_impl(shape_a, shape_b))
+    }
+
+    fn explain_filter_impl(&self, shape_a: ShapeId, shape_b: ShapeId) -> FilterDecision {
+        let filter_a = crate::shapes::shape_filter_impl(shape_a);
+        let filter_b = crate::shapes::shape_filter_impl(shape_b);
+        if let Some(decision) = filter_a.decide(&filter_b) {
+            return decision;
+        }
+        if crate::shapes::shape_is_sensor_impl(shape_a)
+            || crate::shapes::shape_is_sensor_impl(shape_b)
+        {
+            return FilterDecision::Sensor;
+        }
+
+        let body_a = crate::shapes::shape_body_id_impl(shape_a);
+        let body_b = crate::shapes::shape_body_id_impl(shape_b);
+        if body_a == body_b {
+            return FilterDecision::SameBody;
+        }
+        if crate::body::body_type_impl(body_a) != BodyType::Dynamic
+            && crate::body::body_type_impl(body_b) != BodyType::Dynamic
+        {
+            return FilterDecision::NoDynamicBody;
+        }
+        for joint in crate::body::body_joints_impl(body_a) {
+            let a = crate::joints::joint_body_a_id_impl(joint);
+            let b = crate::joints::joint_body_b_id_impl(joint);
+            let connects = (a == body_a && b == body_b) || (a == body_b && b == body_a);
+            if connects && !crate::joints::joint_collide_connected_impl(joint) {
+                return FilterDecision::JointedBodies { joint };
+            }
+        }
+
+        // Clone the callback out so it runs without the `custom_filter` lock held.
+        let custom = self
+            .core
+            .custom_filter
+            .lock()
+            .expect("custom_filter mutex poisoned")
+            .as_ref()
+            .map(|ctx| Arc::clone(&ctx.cb));
+        if let Some(cb) = custom {
+            let _g = crate::core::callback_state::CallbackGuard::enter();
+            let cw = CallbackWorld::new(Arc::clone(&self.core));
+            if !cb(&cw, shape_a, shape_b) {
+                return FilterDecision::CustomFilter;
+            }
+        }
+        FilterDecision::Collide
+    }
+}
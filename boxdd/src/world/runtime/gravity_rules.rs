@@ -0,0 +1,190 @@
+use super::*;
+
+use std::collections::HashMap;
+
+use crate::core::world_core::GravityScaleRules;
+use crate::error::{ApiError, ApiResult};
+
+impl World {
+    /// Scale gravity for bodies with any shape whose filter category intersects `category_bits`,
+    /// e.g. negative gravity for a "balloon" category.
+    ///
+    /// Rules are applied by [`Self::step`] and cached per body: the world's bodies are re-scanned
+    /// only when rules change or a body, shape, or shape filter is created, destroyed, or changed
+    /// through this crate. Disabled bodies keep their rule. When several rules match a body, the
+    /// most recently set one wins; setting a rule again for the same bits replaces it. Static
+    /// bodies are ignored, and the body's own gravity scale is restored when no rule matches
+    /// anymore.
+    ///
+    /// Rules take precedence over per-body gravity scales: a scale written to an overridden body
+    /// (e.g. with `Body::set_gravity_scale`) becomes the body's own scale, restored when the rule
+    /// stops matching, and the rule's scale is back in effect on the next step. Helpers that change
+    /// gravity temporarily, such as [`crate::Ladder`], therefore have no effect on bodies a rule
+    /// matches.
+    pub fn set_gravity_scale_for_category(&mut self, category_bits: u64, scale: f32) {
+        crate::core::callback_state::assert_not_in_callback();
+        assert!(scale.is_finite(), "scale must be finite, got {scale}");
+        self.set_gravity_scale_rule_impl(category_bits, Some(scale));
+    }
+
+    pub fn try_set_gravity_scale_for_category(
+        &mut self,
+        category_bits: u64,
+        scale: f32,
+    ) -> ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        if !scale.is_finite() {
+            return Err(ApiError::InvalidArgument);
+        }
+        self.set_gravity_scale_rule_impl(category_bits, Some(scale));
+        Ok(())
+    }
+
+    /// Remove the rule set for exactly `category_bits`; affected bodies get their own gravity
+    /// scale back on the next step.
+    pub fn clear_gravity_scale_for_category(&mut self, category_bits: u64) {
+        crate::core::callback_state::assert_not_in_callback();
+        self.set_gravity_scale_rule_impl(category_bits, None);
+    }
+
+    pub fn try_clear_gravity_scale_for_category(&mut self, category_bits: u64) -> ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        self.set_gravity_scale_rule_impl(category_bits, None);
+        Ok(())
+    }
+
+    /// Gravity scale rules as `(category_bits, scale)`, in the order they were set.
+    pub fn gravity_scale_rules(&self) -> Vec<(u64, f32)> {
+        self.core
+            .gravity_rules
+            .lock()
+            .expect("gravity_rules mutex poisoned")
+            .rules
+            .clone()
+    }
+
+    /// Re-scan bodies and apply gravity scale rules now instead of on the next step. Also picks up
+    /// filter changes made through raw Box2D calls, which the crate cannot see.
+    pub fn refresh_gravity_scale_rules(&mut self) {
+        crate::core::callback_state::assert_not_in_callback();
+        self.core
+            .gravity_rules
+            .lock()
+            .expect("gravity_rules mutex poisoned")
+            .dirty = true;
+        self.apply_gravity_scale_rules();
+    }
+
+    pub fn try_refresh_gravity_scale_rules(&mut self) -> ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        self.refresh_gravity_scale_rules();
+        Ok(())
+    }
+
+    fn set_gravity_scale_rule_impl(&mut self, category_bits: u64, scale: Option<f32>) {
+        let mut state = self
+            .core
+            .gravity_rules
+            .lock()
+            .expect("gravity_rules mutex poisoned");
+        state.rules.retain(|&(bits, _)| bits != category_bits);
+        if let Some(scale) = scale {
+            state.rules.push((category_bits, scale));
+        }
+        state.dirty = true;
+    }
+
+    /// Called by `step` before stepping Box2D.
+    pub(super) fn apply_gravity_scale_rules(&self) {
+        let mut state = self
+            .core
+            .gravity_rules
+            .lock()
+            .expect("gravity_rules mutex poisoned");
+        if state.rules.is_empty() {
+            // Nothing to match against: hand back any overridden scales without sweeping.
+            for (body, (own, _)) in state.applied.drain() {
+                if crate::body::body_is_valid_impl(body) {
+                    crate::body::body_set_gravity_scale_impl(body, own);
+                }
+            }
+            state.dirty = false;
+            return;
+        }
+        if state.dirty {
+            sweep_gravity_scale_rules(&mut state);
+            state.dirty = false;
+        } else {
+            reassert_gravity_scale_rules(&mut state);
+        }
+    }
+}
+
+/// Adopt scales written to overridden bodies since the last step as their own scale and put the
+/// rule's scale back.
+fn reassert_gravity_scale_rules(state: &mut GravityScaleRules) {
+    for (&body, (own, scale)) in state.applied.iter_mut() {
+        let current = crate::body::body_gravity_scale_impl(body);
+        if current != *scale {
+            *own = current;
+            crate::body::body_set_gravity_scale_impl(body, *scale);
+        }
+    }
+}
+
+fn sweep_gravity_scale_rules(state: &mut GravityScaleRules) {
+    // Union of shape categories per movable body.
+    let mut categories: HashMap<BodyId, u64> = HashMap::new();
+    for &body in &state.bodies {
+        if crate::body::body_type_impl(body) == BodyType::Static {
+            continue;
+        }
+        let bits = crate::body::body_shapes_impl(body)
+            .into_iter()
+            .fold(0, |bits, shape| {
+                bits | crate::shapes::shape_filter_impl(shape).category_bits
+            });
+        if bits != 0 {
+            categories.insert(body, bits);
+        }
+    }
+
+    // Bodies that died, turned static, or lost every shape get their own scale back (if still
+    // alive).
+    state.applied.retain(|&body, &mut (own, _)| {
+        if categories.contains_key(&body) {
+            return true;
+        }
+        if crate::body::body_is_valid_impl(body) {
+            crate::body::body_set_gravity_scale_impl(body, own);
+        }
+        false
+    });
+    for (body, bits) in categories {
+        let rule = state
+            .rules
+            .iter()
+            .rev()
+            .find(|&&(rule_bits, _)| rule_bits & bits != 0);
+        let current = crate::body::body_gravity_scale_impl(body);
+        match rule {
+            Some(&(_, scale)) => {
+                let entry = state.applied.entry(body).or_insert((current, scale));
+                if current != entry.1 {
+                    // Written since the last step: that is the body's own scale now.
+                    entry.0 = current;
+                }
+                entry.1 = scale;
+                crate::body::body_set_gravity_scale_impl(body, scale);
+            }
+            None => {
+                if let Some((own, applied)) = state.applied.remove(&body) {
+                    crate::body::body_set_gravity_scale_impl(
+                        body,
+                        if current != applied { current } else { own },
+                    );
+                }
+            }
+        }
+    }
+}
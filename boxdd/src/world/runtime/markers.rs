@@ -0,0 +1,61 @@
+use super::*;
+use crate::world_extras::Marker;
+
+impl World {
+    /// Add a marker, replacing any existing marker with the same name. Markers keep insertion
+    /// order; a replaced marker keeps its slot.
+    pub fn set_marker(&mut self, marker: Marker) {
+        let mut markers = self.core.markers.lock().expect("markers mutex poisoned");
+        match markers.iter_mut().find(|m| m.name == marker.name) {
+            Some(slot) => *slot = marker,
+            None => markers.push(marker),
+        }
+    }
+
+    /// Marker named `name`.
+    pub fn marker(&self, name: &str) -> Option<Marker> {
+        self.core
+            .markers
+            .lock()
+            .expect("markers mutex poisoned")
+            .iter()
+            .find(|m| m.name == name)
+            .cloned()
+    }
+
+    /// All markers in insertion order.
+    pub fn markers(&self) -> Vec<Marker> {
+        self.core
+            .markers
+            .lock()
+            .expect("markers mutex poisoned")
+            .clone()
+    }
+
+    /// Markers carrying `tag`, in insertion order.
+    pub fn markers_with_tag(&self, tag: &str) -> Vec<Marker> {
+        self.core
+            .markers
+            .lock()
+            .expect("markers mutex poisoned")
+            .iter()
+            .filter(|m| m.has_tag(tag))
+            .cloned()
+            .collect()
+    }
+
+    /// Remove and return the marker named `name`.
+    pub fn remove_marker(&mut self, name: &str) -> Option<Marker> {
+        let mut markers = self.core.markers.lock().expect("markers mutex poisoned");
+        let index = markers.iter().position(|m| m.name == name)?;
+        Some(markers.remove(index))
+    }
+
+    pub fn clear_markers(&mut self) {
+        self.core
+            .markers
+            .lock()
+            .expect("markers mutex poisoned")
+            .clear();
+    }
+}
@@ -0,0 +1,57 @@
+use super::*;
+
+use crate::error::ApiResult;
+use crate::query::{QueryFilter, QuerySnapshot, SnapshotShape};
+use crate::shapes::{Geometry, ShapeType};
+
+impl World {
+    /// Copy the broad-phase into a [`QuerySnapshot`] that other threads can query while the
+    /// world keeps stepping.
+    ///
+    /// Captures every shape regardless of filter, with its body's current transform. The cost is
+    /// linear in the shape count plus a tree build, so take it once per frame, not per query.
+    pub fn query_snapshot(&self) -> QuerySnapshot {
+        crate::core::callback_state::assert_not_in_callback();
+        self.query_snapshot_impl()
+    }
+
+    pub fn try_query_snapshot(&self) -> ApiResult<QuerySnapshot> {
+        crate::core::callback_state::check_not_in_callback()?;
+        Ok(self.query_snapshot_impl())
+    }
+
+    fn query_snapshot_impl(&self) -> QuerySnapshot {
+        let everything = QueryFilter::default().category(u64::MAX).mask(u64::MAX);
+        let mut shapes = Vec::new();
+        self.visit_overlap_aabb(EVERYWHERE, everything, |shape| {
+            shapes.push(snapshot_shape(shape));
+            true
+        });
+        QuerySnapshot::capture(shapes)
+    }
+}
+
+fn snapshot_shape(shape: ShapeId) -> SnapshotShape {
+    let shape_type = crate::shapes::shape_type_impl(shape);
+    let geometry = match shape_type {
+        ShapeType::Circle => Geometry::Circle(crate::shapes::shape_circle_impl(shape)),
+        ShapeType::Segment => Geometry::Segment(crate::shapes::shape_segment_impl(shape)),
+        ShapeType::ChainSegment => {
+            Geometry::Segment(crate::shapes::shape_chain_segment_impl(shape).segment)
+        }
+        ShapeType::Capsule => Geometry::Capsule(crate::shapes::shape_capsule_impl(shape)),
+        ShapeType::Polygon => Geometry::Polygon(crate::shapes::shape_polygon_impl(shape)),
+    };
+    let body_id = crate::shapes::shape_body_id_impl(shape);
+    SnapshotShape {
+        shape_id: shape,
+        body_id,
+        shape_type,
+        aabb: crate::shapes::shape_aabb_impl(shape),
+        filter: crate::shapes::shape_filter_impl(shape),
+        is_sensor: crate::shapes::shape_is_sensor_impl(shape),
+        user_material_id: crate::shapes::shape_user_material_impl(shape),
+        transform: crate::body::body_transform_impl(body_id),
+        geometry,
+    }
+}
@@ -0,0 +1,45 @@
+use super::*;
+
+impl World {
+    /// Start counting broad-phase queries on this world and recording those over `budget`.
+    ///
+    /// Counts every ray, overlap, and cast query made through `World`, `WorldHandle`, or owned
+    /// handles (including those issued by helpers such as active regions), so query spam from
+    /// gameplay code shows up without an external profiler. Calling this again only replaces the
+    /// budget. Instrumentation costs a clock read and a lock of this
+    /// world's stats per query while enabled; other worlds are unaffected.
+    ///
+    /// ```no_run
+    /// use boxdd::{QueryBudget, World, WorldDef};
+    /// let mut world = World::new(WorldDef::default()).unwrap();
+    /// world.enable_query_stats(QueryBudget::default().max_node_visits(200).max_time_ms(0.5));
+    /// // ... gameplay queries, world.step(...) ...
+    /// let stats = world.take_query_stats();
+    /// for slow in &stats.slow_queries {
+    ///     eprintln!("slow {:?} query: {:?}, {:.3} ms", slow.kind, slow.tree_stats, slow.elapsed_ms);
+    /// }
+    /// ```
+    pub fn enable_query_stats(&mut self, budget: QueryBudget) {
+        crate::core::query_stats::enable(self.core(), budget);
+    }
+
+    /// Stop query instrumentation and discard collected stats.
+    pub fn disable_query_stats(&mut self) {
+        crate::core::query_stats::disable(self.core());
+    }
+
+    pub fn is_query_stats_enabled(&self) -> bool {
+        crate::core::query_stats::is_enabled(self.core())
+    }
+
+    /// Stats collected since instrumentation was enabled or last taken. Empty when disabled.
+    pub fn query_stats(&self) -> QueryStats {
+        crate::core::query_stats::snapshot(self.core(), false).unwrap_or_default()
+    }
+
+    /// Like [`World::query_stats`], and restart counting from zero. Call once per frame for
+    /// per-frame counts.
+    pub fn take_query_stats(&mut self) -> QueryStats {
+        crate::core::query_stats::snapshot(self.core(), true).unwrap_or_default()
+    }
+}
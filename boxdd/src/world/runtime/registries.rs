@@ -0,0 +1,26 @@
+use super::*;
+
+impl World {
+    /// Drop bookkeeping for bodies, shapes, joints, and chains that are no longer valid and
+    /// return how many entries were removed.
+    ///
+    /// The wrapper already prunes these tables when objects are destroyed through it (including
+    /// the shapes and joints that go away with a destroyed body). Compaction only matters when
+    /// ids are invalidated some other way, e.g. through raw `boxdd_sys` calls; call it
+    /// periodically in long-running sessions with heavy churn and watch
+    /// [`World::registry_sizes`].
+    pub fn compact_registries(&mut self) -> usize {
+        crate::core::callback_state::assert_not_in_callback();
+        self.core.compact_registries()
+    }
+
+    pub fn try_compact_registries(&mut self) -> crate::error::ApiResult<usize> {
+        crate::core::callback_state::check_not_in_callback()?;
+        Ok(self.core.compact_registries())
+    }
+
+    /// Current entry counts of the wrapper's side tables, for monitoring.
+    pub fn registry_sizes(&self) -> RegistrySizes {
+        self.core.registry_sizes()
+    }
+}
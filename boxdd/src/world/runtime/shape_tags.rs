@@ -0,0 +1,117 @@
+use super::*;
+
+use crate::error::ApiResult;
+
+impl World {
+    /// Replace the gameplay tags of `shape`.
+    ///
+    /// Tags are 32 user-defined bits (flammable, climbable, ...) kept by the crate next to the
+    /// world. They never affect collision, so they can describe semantics that do not fit in
+    /// collision categories. Setting `0` removes the entry, and destroying a shape through the
+    /// wrapper drops its tags.
+    ///
+    /// A tag query matches when the shape carries every bit of the queried `tag`; an empty `tag`
+    /// (`0`) matches no shape, in both [`World::shape_has_tag`] and [`World::shapes_with_tag`].
+    ///
+    /// ```no_run
+    /// use boxdd::{QueryFilter, World, WorldDef};
+    /// const FLAMMABLE: u32 = 1 << 0;
+    /// let world = World::new(WorldDef::default()).unwrap();
+    /// let mut burning = Vec::new();
+    /// world.cast_ray_with([0.0, 1.0], [20.0, 0.0], QueryFilter::default(), |hit| {
+    ///     if world.shape_has_tag(hit.shape_id, FLAMMABLE) {
+    ///         burning.push(hit.shape_id);
+    ///     }
+    ///     1.0
+    /// });
+    /// ```
+    pub fn set_shape_tags(&mut self, shape: ShapeId, tags: u32) {
+        crate::core::debug_checks::assert_shape_valid(shape);
+        self.update_shape_tags(shape, |_| tags);
+    }
+
+    pub fn try_set_shape_tags(&mut self, shape: ShapeId, tags: u32) -> ApiResult<()> {
+        crate::core::debug_checks::check_shape_valid(shape)?;
+        self.update_shape_tags(shape, |_| tags);
+        Ok(())
+    }
+
+    /// Set the bits of `tags` on `shape`, keeping the others.
+    pub fn add_shape_tags(&mut self, shape: ShapeId, tags: u32) {
+        crate::core::debug_checks::assert_shape_valid(shape);
+        self.update_shape_tags(shape, |old| old | tags);
+    }
+
+    pub fn try_add_shape_tags(&mut self, shape: ShapeId, tags: u32) -> ApiResult<()> {
+        crate::core::debug_checks::check_shape_valid(shape)?;
+        self.update_shape_tags(shape, |old| old | tags);
+        Ok(())
+    }
+
+    /// Clear the bits of `tags` on `shape`, keeping the others.
+    pub fn remove_shape_tags(&mut self, shape: ShapeId, tags: u32) {
+        crate::core::debug_checks::assert_shape_valid(shape);
+        self.update_shape_tags(shape, |old| old & !tags);
+    }
+
+    pub fn try_remove_shape_tags(&mut self, shape: ShapeId, tags: u32) -> ApiResult<()> {
+        crate::core::debug_checks::check_shape_valid(shape)?;
+        self.update_shape_tags(shape, |old| old & !tags);
+        Ok(())
+    }
+
+    /// Tags of `shape`; `0` for untagged or destroyed shapes.
+    ///
+    /// Safe to call from query closures such as [`World::cast_ray_with`].
+    pub fn shape_tags(&self, shape: ShapeId) -> u32 {
+        if !unsafe { ffi::b2Shape_IsValid(raw_shape_id(shape)) } {
+            return 0;
+        }
+        self.tags().get(&shape).copied().unwrap_or(0)
+    }
+
+    /// True when `shape` carries every bit of `tag`; always false for `tag == 0`.
+    pub fn shape_has_tag(&self, shape: ShapeId, tag: u32) -> bool {
+        tag != 0 && self.shape_tags(shape) & tag == tag
+    }
+
+    /// Live shapes carrying every bit of `tag`, sorted by id; empty for `tag == 0`.
+    pub fn shapes_with_tag(&self, tag: u32) -> Vec<ShapeId> {
+        let mut out = Vec::new();
+        self.shapes_with_tag_into(tag, &mut out);
+        out
+    }
+
+    /// Reusable-buffer version of [`World::shapes_with_tag`].
+    pub fn shapes_with_tag_into(&self, tag: u32, out: &mut Vec<ShapeId>) {
+        out.clear();
+        if tag == 0 {
+            return;
+        }
+        let mut tags = self.tags();
+        tags.retain(|&shape, _| unsafe { ffi::b2Shape_IsValid(raw_shape_id(shape)) });
+        out.extend(
+            tags.iter()
+                .filter(|&(_, &bits)| bits & tag == tag)
+                .map(|(&shape, _)| shape),
+        );
+        out.sort_unstable();
+    }
+
+    fn update_shape_tags(&self, shape: ShapeId, f: impl FnOnce(u32) -> u32) {
+        let mut tags = self.tags();
+        let bits = f(tags.get(&shape).copied().unwrap_or(0));
+        if bits == 0 {
+            tags.remove(&shape);
+        } else {
+            tags.insert(shape, bits);
+        }
+    }
+
+    fn tags(&self) -> std::sync::MutexGuard<'_, std::collections::HashMap<ShapeId, u32>> {
+        self.core
+            .shape_tags
+            .lock()
+            .expect("shape_tags mutex poisoned")
+    }
+}
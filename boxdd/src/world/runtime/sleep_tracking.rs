@@ -0,0 +1,140 @@
+use super::*;
+
+use crate::core::world_core::SleepTracking;
+use crate::error::ApiResult;
+use crate::events::SleepState;
+
+impl World {
+    /// Start or stop deriving [`World::sleep_state_changes`] from each step's body events.
+    ///
+    /// Box2D reports bodies falling asleep but not waking up. While tracking is on, every step
+    /// compares the bodies that moved against the previous step's awake set, at a cost
+    /// proportional to the number of awake bodies. The first step after enabling reports every
+    /// awake body as [`SleepState::Awake`]. Zero-length steps report no changes.
+    pub fn enable_sleep_tracking(&mut self, flag: bool) {
+        crate::core::callback_state::assert_not_in_callback();
+        self.enable_sleep_tracking_impl(flag);
+    }
+
+    pub fn try_enable_sleep_tracking(&mut self, flag: bool) -> ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        self.enable_sleep_tracking_impl(flag);
+        Ok(())
+    }
+
+    pub fn is_sleep_tracking_enabled(&self) -> bool {
+        self.sleep_tracking().is_some()
+    }
+
+    /// Bodies that woke up or fell asleep during the last step, in Box2D's body event order.
+    ///
+    /// Requires [`World::enable_sleep_tracking`]; empty otherwise. A body counts as woken the
+    /// first step it moves after sleeping (or after being created), and as asleep when Box2D puts
+    /// it to sleep or it stops moving because of `set_body_awake(false)` or being disabled.
+    /// Destroyed bodies are dropped without a transition.
+    ///
+    /// ```no_run
+    /// use boxdd::{SleepState, World, WorldDef};
+    /// let mut world = World::new(WorldDef::default()).unwrap();
+    /// world.enable_sleep_tracking(true);
+    /// world.step(1.0 / 60.0, 4);
+    /// for (body, state) in world.sleep_state_changes() {
+    ///     match state {
+    ///         SleepState::Awake => println!("{body:?} woke up"),
+    ///         SleepState::Asleep => println!("{body:?} fell asleep"),
+    ///     }
+    /// }
+    /// ```
+    pub fn sleep_state_changes(&self) -> Vec<(BodyId, SleepState)> {
+        let mut out = Vec::new();
+        self.sleep_state_changes_into(&mut out);
+        out
+    }
+
+    /// Reusable-buffer version of [`World::sleep_state_changes`].
+    pub fn sleep_state_changes_into(&self, out: &mut Vec<(BodyId, SleepState)>) {
+        out.clear();
+        if let Some(tracking) = self.sleep_tracking().as_ref() {
+            out.extend_from_slice(&tracking.changes);
+        }
+    }
+
+    pub fn try_sleep_state_changes(&self) -> ApiResult<Vec<(BodyId, SleepState)>> {
+        crate::core::callback_state::check_not_in_callback()?;
+        Ok(self.sleep_state_changes())
+    }
+
+    pub fn try_sleep_state_changes_into(
+        &self,
+        out: &mut Vec<(BodyId, SleepState)>,
+    ) -> ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        self.sleep_state_changes_into(out);
+        Ok(())
+    }
+
+    fn enable_sleep_tracking_impl(&mut self, flag: bool) {
+        let mut tracking = self.sleep_tracking();
+        match (flag, tracking.is_some()) {
+            (true, false) => *tracking = Some(SleepTracking::default()),
+            (false, true) => *tracking = None,
+            _ => {}
+        }
+    }
+
+    /// Diff this step's body move events against the previous awake set.
+    ///
+    /// A zero-length step only updates contacts and reports no body moves, so it is skipped
+    /// rather than read as every awake body stopping.
+    pub(super) fn record_sleep_changes(&self, time_step: f32) {
+        let mut guard = self.sleep_tracking();
+        let Some(tracking) = guard.as_mut() else {
+            return;
+        };
+        let SleepTracking {
+            awake,
+            next,
+            changes,
+        } = tracking;
+        changes.clear();
+        if time_step == 0.0 {
+            return;
+        }
+        next.clear();
+        let raw = unsafe { ffi::b2World_GetBodyEvents(self.raw()) };
+        let moves = if raw.moveCount > 0 && !raw.moveEvents.is_null() {
+            unsafe { core::slice::from_raw_parts(raw.moveEvents, raw.moveCount as usize) }
+        } else {
+            &[][..]
+        };
+        for event in moves {
+            let body = BodyId::from_raw(event.bodyId);
+            let was_awake = awake.remove(&body);
+            if event.fellAsleep {
+                if was_awake {
+                    changes.push((body, SleepState::Asleep));
+                }
+            } else {
+                if !was_awake {
+                    changes.push((body, SleepState::Awake));
+                }
+                next.insert(body);
+            }
+        }
+        // Awake last step but no move event: put to sleep directly, disabled, or destroyed.
+        let mut stopped: Vec<BodyId> = awake
+            .drain()
+            .filter(|&body| unsafe { ffi::b2Body_IsValid(raw_body_id(body)) })
+            .collect();
+        stopped.sort_unstable();
+        changes.extend(stopped.into_iter().map(|body| (body, SleepState::Asleep)));
+        core::mem::swap(awake, next);
+    }
+
+    fn sleep_tracking(&self) -> std::sync::MutexGuard<'_, Option<SleepTracking>> {
+        self.core
+            .sleep_tracking
+            .lock()
+            .expect("sleep_tracking mutex poisoned")
+    }
+}
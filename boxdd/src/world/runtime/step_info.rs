@@ -0,0 +1,86 @@
+use super::*;
+
+use crate::error::ApiResult;
+
+/// What the last completed step actually ran with, from [`World::last_step_info`].
+#[derive(Copy, Clone, Debug, Default, PartialEq)]
+pub struct StepInfo {
+    /// Seconds simulated by the step.
+    pub dt_used: f32,
+    /// Sub-steps the solver ran.
+    pub substeps: i32,
+    /// Set when the crate dropped or shortened requested time before stepping, e.g.
+    /// [`FixedTimestep::max_steps`](crate::FixedTimestep::max_steps) discarding a stall, or
+    /// stretched sub-steps past what was asked for, as [`World::step_scaled`] does at
+    /// [`MAX_SCALED_SUB_STEPS`](crate::limits::MAX_SCALED_SUB_STEPS).
+    pub clamped: bool,
+}
+
+impl StepInfo {
+    /// Longest sub-step Box2D is tuned for: 60 Hz with 4 sub-steps, with 2x headroom.
+    pub const RECOMMENDED_MAX_SUBSTEP_DT: f32 = 1.0 / 120.0;
+
+    /// Length of one sub-step; zero for a zero-length step.
+    pub fn substep_dt(&self) -> f32 {
+        if self.substeps > 0 {
+            self.dt_used / self.substeps as f32
+        } else {
+            0.0
+        }
+    }
+
+    /// True when a sub-step was longer than [`Self::RECOMMENDED_MAX_SUBSTEP_DT`], which makes
+    /// stacks jitter and joints stretch. Raise the sub-step count or lower the time step.
+    pub fn exceeds_recommended(&self) -> bool {
+        self.substep_dt() > Self::RECOMMENDED_MAX_SUBSTEP_DT
+    }
+}
+
+impl World {
+    /// Time step and sub-steps used by the last completed step; `None` before the first step.
+    ///
+    /// Surfaces configuration problems that stepping alone hides: an oversized time step still
+    /// runs, and a fixed-rate driver silently drops time after a stall.
+    ///
+    /// ```no_run
+    /// use boxdd::{World, WorldDef};
+    /// let mut world = World::new(WorldDef::default()).unwrap();
+    /// world.step(1.0 / 20.0, 2);
+    /// if let Some(info) = world.last_step_info() {
+    ///     if info.clamped || info.exceeds_recommended() {
+    ///         eprintln!("suspicious step: {info:?}");
+    ///     }
+    /// }
+    /// ```
+    pub fn last_step_info(&self) -> Option<StepInfo> {
+        *self.last_step()
+    }
+
+    pub fn try_last_step_info(&self) -> ApiResult<Option<StepInfo>> {
+        crate::core::callback_state::check_not_in_callback()?;
+        Ok(self.last_step_info())
+    }
+
+    pub(super) fn record_step_info(&self, dt_used: f32, substeps: i32) {
+        *self.last_step() = Some(StepInfo {
+            dt_used,
+            substeps,
+            clamped: false,
+        });
+    }
+
+    /// Flag the last step as having run after the crate dropped requested time or stretched its
+    /// sub-steps.
+    pub(crate) fn mark_last_step_clamped(&self) {
+        if let Some(info) = self.last_step().as_mut() {
+            info.clamped = true;
+        }
+    }
+
+    fn last_step(&self) -> std::sync::MutexGuard<'_, Option<StepInfo>> {
+        self.core
+            .last_step
+            .lock()
+            .expect("last_step mutex poisoned")
+    }
+}
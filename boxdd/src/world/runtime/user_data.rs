@@ -0,0 +1,138 @@
+use super::*;
+use crate::error::ApiResult;
+
+/// Id-style typed user data for one entity kind. Forwards to the store the handle types use, so a
+/// value set through `Body::set_user_data` is visible through `World::with_body_user_data` and
+/// vice versa.
+macro_rules! impl_id_user_data {
+    (
+        $kind:literal, $id:ty,
+        set: $set:ident / $try_set:ident => $set_impl:path, $try_set_impl:path;
+        clear: $clear:ident / $try_clear:ident => $clear_impl:path, $try_clear_impl:path;
+        with: $with:ident / $try_with:ident => $with_impl:path, $try_with_impl:path;
+        with_mut: $with_mut:ident / $try_with_mut:ident
+            => $with_mut_impl:path, $try_with_mut_impl:path;
+        take: $take:ident / $try_take:ident => $take_impl:path, $try_take_impl:path;
+        get: $get:ident / $try_get:ident;
+    ) => {
+        impl World {
+            #[doc = concat!("Attach a typed value to a ", $kind, ", dropping any previous value.")]
+            ///
+            /// The value is boxed and owned by the world. It is dropped when cleared or replaced,
+            /// and when the entity is destroyed through `boxdd`, including cascades from body
+            /// destruction.
+            pub fn $set<T: 'static>(&mut self, id: $id, value: T) {
+                $set_impl(&self.core, id, value)
+            }
+
+            pub fn $try_set<T: 'static>(&mut self, id: $id, value: T) -> ApiResult<()> {
+                $try_set_impl(&self.core, id, value)
+            }
+
+            #[doc = concat!("Drop the ", $kind, "'s typed value. Returns whether one was present.")]
+            pub fn $clear(&mut self, id: $id) -> bool {
+                $clear_impl(&self.core, id)
+            }
+
+            pub fn $try_clear(&mut self, id: $id) -> ApiResult<bool> {
+                $try_clear_impl(&self.core, id)
+            }
+
+            #[doc = concat!("Borrow the ", $kind, "'s typed value.")]
+            ///
+            /// Returns `None` when nothing is stored and panics when a value of another type is
+            /// stored; the `try_` variant returns `ApiError::UserDataTypeMismatch` instead.
+            pub fn $with<T: 'static, R>(&self, id: $id, f: impl FnOnce(&T) -> R) -> Option<R> {
+                $with_impl(&self.core, id, f)
+            }
+
+            pub fn $try_with<T: 'static, R>(
+                &self,
+                id: $id,
+                f: impl FnOnce(&T) -> R,
+            ) -> ApiResult<Option<R>> {
+                $try_with_impl(&self.core, id, f)
+            }
+
+            #[doc = concat!("Mutably borrow the ", $kind, "'s typed value.")]
+            pub fn $with_mut<T: 'static, R>(
+                &mut self,
+                id: $id,
+                f: impl FnOnce(&mut T) -> R,
+            ) -> Option<R> {
+                $with_mut_impl(&self.core, id, f)
+            }
+
+            pub fn $try_with_mut<T: 'static, R>(
+                &mut self,
+                id: $id,
+                f: impl FnOnce(&mut T) -> R,
+            ) -> ApiResult<Option<R>> {
+                $try_with_mut_impl(&self.core, id, f)
+            }
+
+            #[doc = concat!("Remove and return the ", $kind, "'s typed value.")]
+            pub fn $take<T: 'static>(&mut self, id: $id) -> Option<T> {
+                $take_impl(&self.core, id)
+            }
+
+            pub fn $try_take<T: 'static>(&mut self, id: $id) -> ApiResult<Option<T>> {
+                $try_take_impl(&self.core, id)
+            }
+
+            #[doc = concat!("Clone of the ", $kind, "'s typed value.")]
+            pub fn $get<T: Clone + 'static>(&self, id: $id) -> Option<T> {
+                self.$with(id, T::clone)
+            }
+
+            pub fn $try_get<T: Clone + 'static>(&self, id: $id) -> ApiResult<Option<T>> {
+                self.$try_with(id, T::clone)
+            }
+        }
+    };
+}
+
+impl_id_user_data! {
+    "body", BodyId,
+    set: set_body_user_data / try_set_body_user_data
+        => crate::body::body_set_user_data_checked_impl, crate::body::try_body_set_user_data_checked_impl;
+    clear: clear_body_user_data / try_clear_body_user_data
+        => crate::body::body_clear_user_data_checked_impl, crate::body::try_body_clear_user_data_checked_impl;
+    with: with_body_user_data / try_with_body_user_data
+        => crate::body::body_with_user_data_checked_impl, crate::body::try_body_with_user_data_checked_impl;
+    with_mut: with_body_user_data_mut / try_with_body_user_data_mut
+        => crate::body::body_with_user_data_mut_checked_impl, crate::body::try_body_with_user_data_mut_checked_impl;
+    take: take_body_user_data / try_take_body_user_data
+        => crate::body::body_take_user_data_checked_impl, crate::body::try_body_take_user_data_checked_impl;
+    get: body_user_data / try_body_user_data;
+}
+
+impl_id_user_data! {
+    "shape", ShapeId,
+    set: set_shape_user_data / try_set_shape_user_data
+        => crate::shapes::shape_set_user_data_checked_impl, crate::shapes::try_shape_set_user_data_checked_impl;
+    clear: clear_shape_user_data / try_clear_shape_user_data
+        => crate::shapes::shape_clear_user_data_checked_impl, crate::shapes::try_shape_clear_user_data_checked_impl;
+    with: with_shape_user_data / try_with_shape_user_data
+        => crate::shapes::shape_with_user_data_checked_impl, crate::shapes::try_shape_with_user_data_checked_impl;
+    with_mut: with_shape_user_data_mut / try_with_shape_user_data_mut
+        => crate::shapes::shape_with_user_data_mut_checked_impl, crate::shapes::try_shape_with_user_data_mut_checked_impl;
+    take: take_shape_user_data / try_take_shape_user_data
+        => crate::shapes::shape_take_user_data_checked_impl, crate::shapes::try_shape_take_user_data_checked_impl;
+    get: shape_user_data / try_shape_user_data;
+}
+
+impl_id_user_data! {
+    "joint", JointId,
+    set: set_joint_user_data / try_set_joint_user_data
+        => crate::joints::joint_set_user_data_checked_impl, crate::joints::try_joint_set_user_data_checked_impl;
+    clear: clear_joint_user_data / try_clear_joint_user_data
+        => crate::joints::joint_clear_user_data_checked_impl, crate::joints::try_joint_clear_user_data_checked_impl;
+    with: with_joint_user_data / try_with_joint_user_data
+        => crate::joints::joint_with_user_data_checked_impl, crate::joints::try_joint_with_user_data_checked_impl;
+    with_mut: with_joint_user_data_mut / try_with_joint_user_data_mut
+        => crate::joints::joint_with_user_data_mut_checked_impl, crate::joints::try_joint_with_user_data_mut_checked_impl;
+    take: take_joint_user_data / try_take_joint_user_data
+        => crate::joints::joint_take_user_data_checked_impl, crate::joints::try_joint_take_user_data_checked_impl;
+    get: joint_user_data / try_joint_user_data;
+}
@@ -1,5 +1,9 @@
 use super::*;
 
+mod chain;
 mod control;
 mod reads;
+mod resize;
 mod sensor_queries;
+
+pub use resize::CapsuleResizeError;
@@ -0,0 +1,82 @@
+use super::*;
+use crate::error::ApiResult;
+use crate::shapes::chain;
+
+impl World {
+    /// Segment shapes of `chain`, in chain order.
+    pub fn chain_segments(&self, chain: ChainId) -> Vec<ShapeId> {
+        chain::chain_segments_checked_impl(chain)
+    }
+
+    pub fn try_chain_segments(&self, chain: ChainId) -> ApiResult<Vec<ShapeId>> {
+        chain::try_chain_segments_impl(chain)
+    }
+
+    /// Number of runtime-visible material slots on `chain` (`1` for single-material chains).
+    pub fn chain_surface_material_count(&self, chain: ChainId) -> i32 {
+        chain::chain_surface_material_count_checked_impl(chain)
+    }
+
+    pub fn try_chain_surface_material_count(&self, chain: ChainId) -> ApiResult<i32> {
+        chain::try_chain_surface_material_count_impl(chain)
+    }
+
+    /// Material slot `index` of `chain`.
+    pub fn chain_surface_material(&self, chain: ChainId, index: i32) -> SurfaceMaterial {
+        chain::chain_surface_material_checked_impl(chain, index)
+    }
+
+    pub fn try_chain_surface_material(
+        &self,
+        chain: ChainId,
+        index: i32,
+    ) -> ApiResult<SurfaceMaterial> {
+        chain::try_chain_surface_material_impl(chain, index)
+    }
+
+    /// Replace material slot `index` of `chain`, updating the matching segment shapes.
+    pub fn set_chain_surface_material(
+        &mut self,
+        chain: ChainId,
+        index: i32,
+        material: &SurfaceMaterial,
+    ) {
+        chain::chain_set_surface_material_checked_impl(chain, index, material)
+    }
+
+    pub fn try_set_chain_surface_material(
+        &mut self,
+        chain: ChainId,
+        index: i32,
+        material: &SurfaceMaterial,
+    ) -> ApiResult<()> {
+        chain::try_chain_set_surface_material_impl(chain, index, material)
+    }
+
+    /// Set the friction of every segment of `chain`.
+    pub fn set_chain_friction(&mut self, chain: ChainId, friction: f32) {
+        chain::chain_set_friction_checked_impl(chain, friction)
+    }
+
+    pub fn try_set_chain_friction(&mut self, chain: ChainId, friction: f32) -> ApiResult<()> {
+        chain::try_chain_set_friction_impl(chain, friction)
+    }
+
+    /// Set the restitution of every segment of `chain`.
+    pub fn set_chain_restitution(&mut self, chain: ChainId, restitution: f32) {
+        chain::chain_set_restitution_checked_impl(chain, restitution)
+    }
+
+    pub fn try_set_chain_restitution(&mut self, chain: ChainId, restitution: f32) -> ApiResult<()> {
+        chain::try_chain_set_restitution_impl(chain, restitution)
+    }
+
+    /// World AABB enclosing every segment of `chain`.
+    pub fn chain_aabb(&self, chain: ChainId) -> Aabb {
+        chain::chain_aabb_checked_impl(chain)
+    }
+
+    pub fn try_chain_aabb(&self, chain: ChainId) -> ApiResult<Aabb> {
+        chain::try_chain_aabb_impl(chain)
+    }
+}
@@ -1,80 +1,249 @@
 use super::*;
+use crate::core::world_core::WorldCore;
 
-fn world_shape_set_circle_impl(shape: ShapeId, circle: &crate::shapes::Circle) {
+fn world_shape_set_circle_impl(core: &WorldCore, shape: ShapeId, circle: &crate::shapes::Circle) {
     crate::core::debug_checks::assert_shape_valid(shape);
     crate::shapes::assert_circle_geometry_valid(circle);
-    let raw = circle.into_raw();
-    unsafe { ffi::b2Shape_SetCircle(raw_shape_id(shape), &raw) }
+    crate::shapes::shape_set_circle_impl(core, shape, circle)
 }
 
 fn try_world_shape_set_circle_impl(
+    core: &WorldCore,
     shape: ShapeId,
     circle: &crate::shapes::Circle,
 ) -> crate::error::ApiResult<()> {
     crate::core::debug_checks::check_shape_valid(shape)?;
     crate::shapes::check_circle_geometry_valid(circle)?;
-    let raw = circle.into_raw();
-    unsafe { ffi::b2Shape_SetCircle(raw_shape_id(shape), &raw) }
+    crate::shapes::shape_set_circle_impl(core, shape, circle);
     Ok(())
 }
 
-fn world_shape_set_segment_impl(shape: ShapeId, segment: &crate::shapes::Segment) {
+fn world_shape_set_segment_impl(
+    core: &WorldCore,
+    shape: ShapeId,
+    segment: &crate::shapes::Segment,
+) {
     crate::core::debug_checks::assert_shape_valid(shape);
     crate::shapes::assert_segment_geometry_valid(segment);
-    let raw = segment.into_raw();
-    unsafe { ffi::b2Shape_SetSegment(raw_shape_id(shape), &raw) }
+    crate::shapes::shape_set_segment_impl(core, shape, segment)
 }
 
 fn try_world_shape_set_segment_impl(
+    core: &WorldCore,
     shape: ShapeId,
     segment: &crate::shapes::Segment,
 ) -> crate::error::ApiResult<()> {
     crate::core::debug_checks::check_shape_valid(shape)?;
     crate::shapes::check_segment_geometry_valid(segment)?;
-    let raw = segment.into_raw();
-    unsafe { ffi::b2Shape_SetSegment(raw_shape_id(shape), &raw) }
+    crate::shapes::shape_set_segment_impl(core, shape, segment);
     Ok(())
 }
 
-fn world_shape_set_capsule_impl(shape: ShapeId, capsule: &crate::shapes::Capsule) {
+fn world_shape_set_capsule_impl(
+    core: &WorldCore,
+    shape: ShapeId,
+    capsule: &crate::shapes::Capsule,
+) {
     crate::core::debug_checks::assert_shape_valid(shape);
     crate::shapes::assert_capsule_geometry_valid(capsule);
-    let raw = capsule.into_raw();
-    unsafe { ffi::b2Shape_SetCapsule(raw_shape_id(shape), &raw) }
+    crate::shapes::shape_set_capsule_impl(core, shape, capsule)
 }
 
 fn try_world_shape_set_capsule_impl(
+    core: &WorldCore,
     shape: ShapeId,
     capsule: &crate::shapes::Capsule,
 ) -> crate::error::ApiResult<()> {
     crate::core::debug_checks::check_shape_valid(shape)?;
     crate::shapes::check_capsule_geometry_valid(capsule)?;
-    let raw = capsule.into_raw();
-    unsafe { ffi::b2Shape_SetCapsule(raw_shape_id(shape), &raw) }
+    crate::shapes::shape_set_capsule_impl(core, shape, capsule);
     Ok(())
 }
 
-fn world_shape_set_polygon_impl(shape: ShapeId, polygon: &crate::shapes::Polygon) {
+fn world_shape_set_polygon_impl(
+    core: &WorldCore,
+    shape: ShapeId,
+    polygon: &crate::shapes::Polygon,
+) {
     crate::core::debug_checks::assert_shape_valid(shape);
     crate::shapes::assert_polygon_geometry_valid(polygon);
-    let raw = polygon.into_raw();
-    unsafe { ffi::b2Shape_SetPolygon(raw_shape_id(shape), &raw) }
+    crate::shapes::shape_set_polygon_impl(core, shape, polygon)
 }
 
 fn try_world_shape_set_polygon_impl(
+    core: &WorldCore,
     shape: ShapeId,
     polygon: &crate::shapes::Polygon,
 ) -> crate::error::ApiResult<()> {
     crate::core::debug_checks::check_shape_valid(shape)?;
     crate::shapes::check_polygon_geometry_valid(polygon)?;
-    let raw = polygon.into_raw();
-    unsafe { ffi::b2Shape_SetPolygon(raw_shape_id(shape), &raw) }
+    crate::shapes::shape_set_polygon_impl(core, shape, polygon);
     Ok(())
 }
 
+/// Rewrite `shape`'s geometry by `xf` in body space. Returns `false` for chain segments, which
+/// have no setter.
+/// Chain segments cannot be edited in place, so bodies carrying them are rejected up front.
+fn body_has_chain_segments(body: BodyId) -> bool {
+    crate::body::body_shapes_impl(body)
+        .into_iter()
+        .any(|shape| {
+            crate::shapes::shape_type_impl(shape) == crate::shapes::ShapeType::ChainSegment
+        })
+}
+
+fn offset_shape_geometry_impl(core: &WorldCore, shape: ShapeId, xf: Transform) {
+    use crate::shapes::ShapeType;
+    match crate::shapes::shape_type_impl(shape) {
+        ShapeType::Circle => {
+            let mut c = crate::shapes::shape_circle_impl(shape);
+            c.center = xf.transform_point(c.center);
+            crate::shapes::shape_set_circle_impl(core, shape, &c);
+        }
+        ShapeType::Segment => {
+            let mut s = crate::shapes::shape_segment_impl(shape);
+            s.point1 = xf.transform_point(s.point1);
+            s.point2 = xf.transform_point(s.point2);
+            crate::shapes::shape_set_segment_impl(core, shape, &s);
+        }
+        ShapeType::Capsule => {
+            let mut c = crate::shapes::shape_capsule_impl(shape);
+            c.center1 = xf.transform_point(c.center1);
+            c.center2 = xf.transform_point(c.center2);
+            crate::shapes::shape_set_capsule_impl(core, shape, &c);
+        }
+        ShapeType::Polygon => {
+            let p = crate::shapes::shape_polygon_impl(shape).transformed(xf);
+            crate::shapes::shape_set_polygon_impl(core, shape, &p);
+        }
+        ShapeType::ChainSegment => unreachable!("chain segments are rejected before offsetting"),
+    }
+}
+
+fn offset_body_shapes_impl(core: &WorldCore, body: BodyId, xf: Transform) -> usize {
+    let shapes = crate::body::body_shapes_impl(body);
+    for &shape in &shapes {
+        offset_shape_geometry_impl(core, shape, xf);
+    }
+    unsafe { ffi::b2Body_ApplyMassFromShapes(raw_body_id(body)) };
+    shapes.len()
+}
+
+/// Shift the body origin to its center of mass without moving anything in world space.
+fn recenter_body_impl(core: &WorldCore, body: BodyId) -> Vec2 {
+    let mass = crate::body::body_mass_data_impl(body);
+    let c = mass.center;
+    if c == Vec2::ZERO {
+        return c;
+    }
+    let xf = crate::body::body_transform_impl(body);
+    let shift = Transform {
+        p: Vec2::new(-c.x, -c.y),
+        q: crate::Rot::IDENTITY,
+    };
+    offset_body_shapes_impl(core, body, shift);
+    // Keep any mass override; inertia is already about the center.
+    let recentered = MassData {
+        center: Vec2::ZERO,
+        ..mass
+    };
+    unsafe { ffi::b2Body_SetMassData(raw_body_id(body), recentered.into_raw()) };
+    let origin = xf.transform_point(c);
+    crate::body::body_set_transform_impl(core, body, origin, xf.q);
+    for joint in crate::body::body_joints_impl(body) {
+        if crate::joints::joint_body_a_id_impl(joint) == body {
+            let mut frame = crate::joints::joint_local_frame_a_impl(joint);
+            frame.p = frame.p.minus(c);
+            crate::joints::joint_set_local_frame_a_impl(joint, frame);
+        }
+        if crate::joints::joint_body_b_id_impl(joint) == body {
+            let mut frame = crate::joints::joint_local_frame_b_impl(joint);
+            frame.p = frame.p.minus(c);
+            crate::joints::joint_set_local_frame_b_impl(joint, frame);
+        }
+    }
+    c
+}
+
 impl World {
+    /// Move every shape on `body` by `delta` in body space, keeping their relative layout.
+    ///
+    /// Circle centers, segment and capsule endpoints, and polygon vertices (with their normals and
+    /// centroid) are rewritten; the body itself does not move, so the shapes shift in the world.
+    /// The body's mass is then recomputed from its shapes, replacing any
+    /// [`World::set_body_mass_data`] override.
+    ///
+    /// Panics if the body carries chain segments, which Box2D cannot edit in place; nothing is
+    /// changed in that case. Returns the number of shapes moved. Re-center a body on its center of mass by offsetting
+    /// with the negated local center and moving the body by the same amount:
+    ///
+    /// ```no_run
+    /// use boxdd::{BodyBuilder, BodyType, Transform, World, WorldDef, shapes};
+    /// let mut world = World::new(WorldDef::default()).unwrap();
+    /// let body = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+    /// world.create_shape_simple(body, shapes::offset_box_polygon(1.0, 0.5, Transform::from_pos_angle([2.0, 0.0], 0.0)));
+    /// let center = world.body_mass_data(body).center;
+    /// world.offset_body_shapes(body, Transform::from_pos_angle([-center.x, -center.y], 0.0));
+    /// ```
+    pub fn offset_body_shapes(&mut self, body: BodyId, delta: Transform) -> usize {
+        crate::core::callback_state::assert_not_in_callback();
+        crate::core::debug_checks::assert_body_valid(body);
+        assert!(
+            delta.is_valid(),
+            "delta must be a valid transform, got {delta:?}"
+        );
+        assert!(
+            !body_has_chain_segments(body),
+            "offset_body_shapes: body has chain segments, which cannot be moved"
+        );
+        offset_body_shapes_impl(&self.core, body, delta)
+    }
+
+    pub fn try_offset_body_shapes(
+        &mut self,
+        body: BodyId,
+        delta: Transform,
+    ) -> crate::error::ApiResult<usize> {
+        crate::core::callback_state::check_not_in_callback()?;
+        crate::core::debug_checks::check_body_valid(body)?;
+        if !delta.is_valid() || body_has_chain_segments(body) {
+            return Err(crate::error::ApiError::InvalidArgument);
+        }
+        Ok(offset_body_shapes_impl(&self.core, body, delta))
+    }
+
+    /// Move `body`'s origin to its center of mass, keeping everything in place in world space.
+    ///
+    /// Shapes are offset with [`World::offset_body_shapes`], the body transform moves to the old
+    /// center of mass, and the local frames of attached joints are shifted to match. Mass,
+    /// rotational inertia, and velocities are preserved, including mass overrides. An origin away
+    /// from the center of mass makes rotation-about-origin APIs (`set_body_transform`, angular
+    /// impulses at the origin) behave unexpectedly.
+    ///
+    /// Returns the old local center of mass, i.e. how far the origin moved in body space. Panics
+    /// if the body carries chain segments, like [`World::offset_body_shapes`].
+    #[doc(alias = "recentre_body")]
+    pub fn recenter_body(&mut self, body: BodyId) -> Vec2 {
+        crate::core::callback_state::assert_not_in_callback();
+        crate::core::debug_checks::assert_body_valid(body);
+        assert!(
+            !body_has_chain_segments(body),
+            "recenter_body: body has chain segments, which cannot be moved"
+        );
+        recenter_body_impl(&self.core, body)
+    }
+
+    pub fn try_recenter_body(&mut self, body: BodyId) -> crate::error::ApiResult<Vec2> {
+        crate::core::callback_state::check_not_in_callback()?;
+        crate::core::debug_checks::check_body_valid(body)?;
+        if body_has_chain_segments(body) {
+            return Err(crate::error::ApiError::InvalidArgument);
+        }
+        Ok(recenter_body_impl(&self.core, body))
+    }
+
     pub fn shape_set_circle(&mut self, shape: ShapeId, circle: &crate::shapes::Circle) {
-        world_shape_set_circle_impl(shape, circle)
+        world_shape_set_circle_impl(&self.core, shape, circle)
     }
 
     pub fn try_shape_set_circle(
@@ -82,11 +251,11 @@ impl World {
         shape: ShapeId,
         circle: &crate::shapes::Circle,
     ) -> crate::error::ApiResult<()> {
-        try_world_shape_set_circle_impl(shape, circle)
+        try_world_shape_set_circle_impl(&self.core, shape, circle)
     }
 
     pub fn shape_set_segment(&mut self, shape: ShapeId, segment: &crate::shapes::Segment) {
-        world_shape_set_segment_impl(shape, segment)
+        world_shape_set_segment_impl(&self.core, shape, segment)
     }
 
     pub fn try_shape_set_segment(
@@ -94,11 +263,11 @@ impl World {
         shape: ShapeId,
         segment: &crate::shapes::Segment,
     ) -> crate::error::ApiResult<()> {
-        try_world_shape_set_segment_impl(shape, segment)
+        try_world_shape_set_segment_impl(&self.core, shape, segment)
     }
 
     pub fn shape_set_capsule(&mut self, shape: ShapeId, capsule: &crate::shapes::Capsule) {
-        world_shape_set_capsule_impl(shape, capsule)
+        world_shape_set_capsule_impl(&self.core, shape, capsule)
     }
 
     pub fn try_shape_set_capsule(
@@ -106,11 +275,11 @@ impl World {
         shape: ShapeId,
         capsule: &crate::shapes::Capsule,
     ) -> crate::error::ApiResult<()> {
-        try_world_shape_set_capsule_impl(shape, capsule)
+        try_world_shape_set_capsule_impl(&self.core, shape, capsule)
     }
 
     pub fn shape_set_polygon(&mut self, shape: ShapeId, polygon: &crate::shapes::Polygon) {
-        world_shape_set_polygon_impl(shape, polygon)
+        world_shape_set_polygon_impl(&self.core, shape, polygon)
     }
 
     pub fn try_shape_set_polygon(
@@ -118,7 +287,7 @@ impl World {
         shape: ShapeId,
         polygon: &crate::shapes::Polygon,
     ) -> crate::error::ApiResult<()> {
-        try_world_shape_set_polygon_impl(shape, polygon)
+        try_world_shape_set_polygon_impl(&self.core, shape, polygon)
     }
 
     pub fn shape_set_surface_material(&mut self, shape: ShapeId, material: &SurfaceMaterial) {
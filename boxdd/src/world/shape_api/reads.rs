@@ -34,6 +34,19 @@ impl World {
         Ok(crate::shapes::shape_body_id_impl(shape))
     }
 
+    /// Chain that owns `shape`, or `None` if it is not a chain segment.
+    ///
+    /// Use this to map a ray or contact hit on a chain segment back to its chain.
+    pub fn chain_of_shape(&self, shape: ShapeId) -> Option<ChainId> {
+        crate::core::debug_checks::assert_shape_valid(shape);
+        crate::shapes::shape_parent_chain_id_impl(shape)
+    }
+
+    pub fn try_chain_of_shape(&self, shape: ShapeId) -> crate::error::ApiResult<Option<ChainId>> {
+        crate::core::debug_checks::check_shape_valid(shape)?;
+        Ok(crate::shapes::shape_parent_chain_id_impl(shape))
+    }
+
     pub fn shape_aabb(&self, shape: ShapeId) -> Aabb {
         crate::core::debug_checks::assert_shape_valid(shape);
         crate::shapes::shape_aabb_impl(shape)
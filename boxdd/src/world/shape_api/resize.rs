@@ -0,0 +1,150 @@
+use super::*;
+use crate::error::{ApiError, ApiResult};
+use crate::query::QueryFilter;
+use crate::shapes::Capsule;
+
+/// Why [`World::try_resize_capsule`] left a capsule unchanged.
+#[derive(Clone, Debug, PartialEq, Eq, thiserror::Error)]
+pub enum CapsuleResizeError {
+    /// The grown capsule would overlap these shapes (e.g. a ceiling above a crouching character).
+    #[error("capsule resize blocked by {} overlapping shape(s)", .0.len())]
+    Blocked(Vec<ShapeId>),
+    #[error(transparent)]
+    Api(#[from] ApiError),
+}
+
+/// Clearance (in linear slops) the overlap check leaves so resting contacts don't block a resize.
+const RESIZE_SKIN_SLOPS: f32 = 2.0;
+
+/// Resized copy of `capsule` with segment half length `half_height`, keeping the end lower along
+/// gravity in place.
+fn resized_capsule(capsule: Capsule, xf: Transform, gravity: Vec2, half_height: f32) -> Capsule {
+    let (c1, c2) = (capsule.center1, capsule.center2);
+    let d = Vec2::new(c2.x - c1.x, c2.y - c1.y);
+    let len = (d.x * d.x + d.y * d.y).sqrt();
+    let axis = Vec2::new(d.x / len, d.y / len);
+    let along = 2.0 * half_height;
+    let rot = xf.rotation();
+    let world_d = rot.rotate_vec(d);
+    if world_d.x * gravity.x + world_d.y * gravity.y > 0.0 {
+        // center2 is the foot.
+        let start = Vec2::new(c2.x - axis.x * along, c2.y - axis.y * along);
+        Capsule::new(start, c2, capsule.radius)
+    } else {
+        let end = Vec2::new(c1.x + axis.x * along, c1.y + axis.y * along);
+        Capsule::new(c1, end, capsule.radius)
+    }
+}
+
+impl World {
+    /// Change a capsule shape's segment half length (e.g. crouch or stand a character) unless the
+    /// taller capsule would overlap other shapes.
+    ///
+    /// The end of the capsule lower along gravity stays in place (`center1` when gravity is zero).
+    /// Growing checks the new capsule against shapes that pass `filter`, ignoring shapes on the
+    /// same body and sensors; resting contacts within two linear slops don't count. Shrinking is
+    /// never blocked. The body's mass is left unchanged, like [`World::shape_set_capsule`].
+    ///
+    /// Returns the blocking shapes on failure. Panics if `shape` is not a valid capsule or the
+    /// resized capsule would be invalid.
+    ///
+    /// ```no_run
+    /// use boxdd::{BodyBuilder, BodyType, QueryFilter, World, WorldDef, shapes};
+    /// let mut world = World::new(WorldDef::default()).unwrap();
+    /// let player = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+    /// let capsule = world.create_shape_simple(player, shapes::capsule([0.0, 0.3], [0.0, 1.5], 0.3));
+    /// world.resize_capsule(capsule, 0.3, QueryFilter::default()).unwrap(); // crouch
+    /// if world.resize_capsule(capsule, 0.6, QueryFilter::default()).is_err() {
+    ///     // Something is overhead; stay crouched.
+    /// }
+    /// ```
+    pub fn resize_capsule(
+        &mut self,
+        shape: ShapeId,
+        new_half_height: f32,
+        filter: QueryFilter,
+    ) -> Result<(), Vec<ShapeId>> {
+        crate::core::debug_checks::assert_shape_valid(shape);
+        assert_eq!(
+            crate::shapes::shape_type_impl(shape),
+            crate::shapes::ShapeType::Capsule,
+            "resize_capsule requires a capsule shape"
+        );
+        assert!(
+            new_half_height.is_finite() && new_half_height > 0.0,
+            "new_half_height must be finite and > 0.0, got {new_half_height}"
+        );
+        let (current, target) = self.capsule_resize_target(shape, new_half_height);
+        crate::shapes::assert_capsule_geometry_valid(&target);
+        self.apply_capsule_resize(shape, current, target, filter)
+    }
+
+    /// Recoverable version of [`World::resize_capsule`].
+    pub fn try_resize_capsule(
+        &mut self,
+        shape: ShapeId,
+        new_half_height: f32,
+        filter: QueryFilter,
+    ) -> Result<(), CapsuleResizeError> {
+        self.check_capsule_resize(shape, new_half_height)?;
+        let (current, target) = self.capsule_resize_target(shape, new_half_height);
+        crate::shapes::check_capsule_geometry_valid(&target)?;
+        self.apply_capsule_resize(shape, current, target, filter)
+            .map_err(CapsuleResizeError::Blocked)
+    }
+
+    fn check_capsule_resize(&self, shape: ShapeId, new_half_height: f32) -> ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        crate::core::debug_checks::check_shape_valid(shape)?;
+        if crate::shapes::shape_type_impl(shape) != crate::shapes::ShapeType::Capsule
+            || !(new_half_height.is_finite() && new_half_height > 0.0)
+        {
+            return Err(ApiError::InvalidArgument);
+        }
+        Ok(())
+    }
+
+    fn capsule_resize_target(&self, shape: ShapeId, new_half_height: f32) -> (Capsule, Capsule) {
+        let current = crate::shapes::shape_capsule_impl(shape);
+        let body = crate::shapes::shape_body_id_impl(shape);
+        let xf = crate::body::body_transform_impl(body);
+        let target = resized_capsule(current, xf, self.gravity(), new_half_height);
+        (current, target)
+    }
+
+    fn apply_capsule_resize(
+        &mut self,
+        shape: ShapeId,
+        current: Capsule,
+        target: Capsule,
+        filter: QueryFilter,
+    ) -> Result<(), Vec<ShapeId>> {
+        let len = |c: &Capsule| {
+            let (dx, dy) = (c.center2.x - c.center1.x, c.center2.y - c.center1.y);
+            (dx * dx + dy * dy).sqrt()
+        };
+        if len(&target) > len(&current) {
+            let body = crate::shapes::shape_body_id_impl(shape);
+            let xf = crate::body::body_transform_impl(body);
+            let skin = RESIZE_SKIN_SLOPS * crate::tuning::linear_slop();
+            let blockers: Vec<ShapeId> = self
+                .overlap_capsule(
+                    xf.transform_point(target.center1),
+                    xf.transform_point(target.center2),
+                    (target.radius - skin).max(0.0),
+                    filter,
+                )
+                .into_iter()
+                .filter(|&s| {
+                    crate::shapes::shape_body_id_impl(s) != body
+                        && !crate::shapes::shape_is_sensor_impl(s)
+                })
+                .collect();
+            if !blockers.is_empty() {
+                return Err(blockers);
+            }
+        }
+        crate::shapes::shape_set_capsule_impl(&self.core, shape, &target);
+        Ok(())
+    }
+}
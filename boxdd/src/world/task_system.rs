@@ -0,0 +1,330 @@
+//! Safe task-system integration for multithreaded stepping.
+//!
+//! Box2D does not create threads. When a world is created with a [`TaskSystem`], every parallel
+//! stage of `b2World_Step` is handed to [`TaskSystem::parallel_for`], which splits the work items
+//! across the caller's own threads (a rayon pool, a job system, scoped threads, ...).
+
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+use boxdd_sys::ffi;
+
+#[cfg(feature = "parallel")]
+mod rayon_pool;
+#[cfg(feature = "parallel")]
+pub use rayon_pool::RayonTaskSystem;
+
+/// Box2D's upper bound on workers per world (`B2_MAX_WORKERS`).
+pub const MAX_WORKERS: usize = 64;
+
+/// A job system that runs Box2D's parallel-for stages during [`World::step`](crate::World::step).
+///
+/// Install it with [`WorldBuilder::task_system`](crate::WorldBuilder::task_system). Each call to
+/// [`parallel_for`](Self::parallel_for) must run every item in `0..task.item_count()` exactly once
+/// before returning, through [`TaskRange::run`].
+///
+/// Box2D's solver enqueues one long-running task per worker and expects them to run at the same
+/// time. A blocking `parallel_for` still completes them, one after another, so only the other
+/// stages run in parallel. Override [`spawn`](Self::spawn) to start tasks without waiting; the
+/// `parallel` feature's `RayonTaskSystem` does this.
+///
+/// # Safety
+/// Box2D keeps per-worker scratch state indexed by the worker index. Implementations must never
+/// run two ranges with the same `worker_index` at the same time, and every index must be below
+/// [`TaskRange::worker_count`]. Using the index of the executing thread in a fixed-size pool
+/// (for example `rayon::current_thread_index()` on a pool of `worker_count` threads) satisfies
+/// this.
+///
+/// A [`TaskRange`] borrows memory of the current step stage, which Box2D frees once the stage
+/// has finished. Every range of a task must therefore have run to completion before
+/// `parallel_for` returns, before `spawn` returns `None`, or before [`PendingTask::wait`] on the
+/// task returned by `spawn` returns; no range may be run (or kept) after that point.
+///
+/// A panic escaping `parallel_for` aborts the process, since Box2D cannot unwind mid-step.
+///
+/// ```no_run
+/// use boxdd::{TaskRange, TaskSystem, World, WorldDef};
+///
+/// struct ScopedThreads(usize);
+///
+/// unsafe impl TaskSystem for ScopedThreads {
+///     fn worker_count(&self) -> usize {
+///         self.0
+///     }
+///
+///     fn parallel_for(&self, item_count: u32, min_range: u32, task: &TaskRange<'_>) {
+///         let workers = task.worker_count();
+///         let chunk = item_count.div_ceil(workers).max(min_range).max(1);
+///         std::thread::scope(|s| {
+///             for worker in 0..workers {
+///                 let start = worker * chunk;
+///                 if start >= item_count {
+///                     break;
+///                 }
+///                 let end = (start + chunk).min(item_count);
+///                 s.spawn(move || task.run(start, end, worker));
+///             }
+///         });
+///     }
+/// }
+///
+/// let mut world = World::new(WorldDef::builder().task_system(ScopedThreads(4)).build()).unwrap();
+/// world.step(1.0 / 60.0, 4);
+/// ```
+pub unsafe trait TaskSystem: Send + Sync + 'static {
+    /// Number of workers the system can run at once; clamped to `1..=MAX_WORKERS`.
+    fn worker_count(&self) -> usize;
+
+    /// Run `task` over `0..item_count`, blocking until every item has run.
+    ///
+    /// `min_range` is Box2D's hint for the smallest range worth handing to one worker.
+    fn parallel_for(&self, item_count: u32, min_range: u32, task: &TaskRange<'_>);
+
+    /// Start `task` without waiting for it. Return `None` if it already ran to completion, or a
+    /// [`PendingTask`] that Box2D waits on before using the stage's results.
+    ///
+    /// `task` only lives for the current stage; handing it to other threads requires
+    /// [`TaskRange::detach`], whose contract the returned [`PendingTask`] must uphold.
+    ///
+    /// The default runs the task through [`parallel_for`](Self::parallel_for) and returns `None`.
+    fn spawn(&self, task: TaskRange<'_>) -> Option<PendingTask> {
+        self.parallel_for(task.item_count(), task.min_range(), &task);
+        None
+    }
+
+    /// Called when a world is created with this system. Return `false` to refuse the world,
+    /// which makes `World::new` fail with [`Error::TaskSystemInUse`](crate::world::Error::TaskSystemInUse).
+    ///
+    /// Worlds sharing a system may step at the same time, each with its own solver stage that
+    /// needs every worker at once; a system that cannot run several such stages together (a
+    /// fixed pool of `worker_count` threads, say) should accept only one world at a time. The
+    /// default accepts every world.
+    fn attach_world(&self) -> bool {
+        true
+    }
+
+    /// Called when a world accepted by [`attach_world`](Self::attach_world) is dropped.
+    fn detach_world(&self) {}
+}
+
+/// A task started by [`TaskSystem::spawn`] that has not finished yet.
+pub struct PendingTask(Box<dyn FnOnce() + Send>);
+
+impl PendingTask {
+    /// Wrap `wait`, which blocks until every range of the task has run.
+    ///
+    /// # Safety
+    /// `wait` must not return while any range of the spawned task is still running or has not
+    /// run yet: Box2D frees the stage's memory as soon as it returns.
+    pub unsafe fn new(wait: impl FnOnce() + Send + 'static) -> Self {
+        Self(Box::new(wait))
+    }
+
+    /// Block until the task has finished.
+    pub fn wait(self) {
+        (self.0)()
+    }
+}
+
+impl core::fmt::Debug for PendingTask {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PendingTask").finish_non_exhaustive()
+    }
+}
+
+/// One Box2D parallel-for stage, handed to [`TaskSystem::parallel_for`].
+pub struct TaskRange<'a> {
+    callback: unsafe extern "C" fn(i32, i32, u32, *mut core::ffi::c_void),
+    context: *mut core::ffi::c_void,
+    item_count: u32,
+    min_range: u32,
+    worker_count: u32,
+    _stage: PhantomData<&'a ()>,
+}
+
+// SAFETY: Box2D task callbacks are designed to run concurrently from different workers; the
+// worker-index exclusivity they rely on is part of the `unsafe trait TaskSystem` contract.
+unsafe impl Send for TaskRange<'_> {}
+unsafe impl Sync for TaskRange<'_> {}
+
+impl core::fmt::Debug for TaskRange<'_> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TaskRange")
+            .field("item_count", &self.item_count)
+            .field("min_range", &self.min_range)
+            .field("worker_count", &self.worker_count)
+            .finish()
+    }
+}
+
+impl TaskRange<'_> {
+    /// Number of work items in this stage.
+    pub fn item_count(&self) -> u32 {
+        self.item_count
+    }
+
+    /// Box2D's hint for the smallest range worth handing to one worker (at least 1).
+    pub fn min_range(&self) -> u32 {
+        self.min_range
+    }
+
+    /// Number of workers the world was created with; worker indices must be below this.
+    pub fn worker_count(&self) -> u32 {
+        self.worker_count
+    }
+
+    /// Run items `start..end` as worker `worker_index`.
+    ///
+    /// Panics if the range is outside `0..item_count()` or `worker_index >= worker_count()`.
+    pub fn run(&self, start: u32, end: u32, worker_index: u32) {
+        assert!(
+            start <= end && end <= self.item_count,
+            "task range {start}..{end} out of bounds for {} items",
+            self.item_count
+        );
+        assert!(
+            worker_index < self.worker_count,
+            "worker index {worker_index} out of range for {} workers",
+            self.worker_count
+        );
+        if start < end {
+            unsafe { (self.callback)(start as i32, end as i32, worker_index, self.context) }
+        }
+    }
+
+    /// Run every item as worker `worker_index`.
+    pub fn run_all(&self, worker_index: u32) {
+        self.run(0, self.item_count, worker_index);
+    }
+
+    /// Drop the stage lifetime so the range can be moved to worker threads by
+    /// [`TaskSystem::spawn`].
+    ///
+    /// # Safety
+    /// The range (and every copy shared from it) must not be used after the stage has finished:
+    /// each run must complete before the [`PendingTask`] returned from `spawn` finishes waiting,
+    /// and nothing may run it afterwards.
+    pub unsafe fn detach(self) -> TaskRange<'static> {
+        TaskRange {
+            callback: self.callback,
+            context: self.context,
+            item_count: self.item_count,
+            min_range: self.min_range,
+            worker_count: self.worker_count,
+            _stage: PhantomData,
+        }
+    }
+}
+
+/// A task system stored on a `WorldDef` until world creation.
+#[derive(Clone)]
+pub(crate) struct TaskSystemSlot(pub(crate) Arc<dyn TaskSystem>);
+
+impl TaskSystemSlot {
+    pub(crate) fn worker_count(&self) -> i32 {
+        self.0.worker_count().clamp(1, MAX_WORKERS) as i32
+    }
+}
+
+impl core::fmt::Debug for TaskSystemSlot {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("TaskSystem")
+            .field("worker_count", &self.worker_count())
+            .finish()
+    }
+}
+
+/// The task system `World::new` installs: the definition's own, or with the `parallel` feature a
+/// rayon pool when more than one worker is requested without task callbacks.
+pub(crate) fn creation_task_system(def: &crate::WorldDef) -> Option<TaskSystemSlot> {
+    if def.2.is_some() {
+        return def.2.clone();
+    }
+    #[cfg(feature = "parallel")]
+    if def.worker_count() > 1 && !def.has_task_system_raw() {
+        // Fall back to Box2D's serial stepping if the pool cannot be built.
+        return RayonTaskSystem::new(def.worker_count() as usize)
+            .ok()
+            .map(|pool| TaskSystemSlot(Arc::new(pool)));
+    }
+    None
+}
+
+/// Context handed to Box2D as `userTaskContext`; owned by the world's core.
+pub(crate) struct TaskSystemCtx {
+    system: Arc<dyn TaskSystem>,
+    worker_count: u32,
+}
+
+impl TaskSystemCtx {
+    /// Point `raw` at `slot` and return the context that must outlive the created world, or
+    /// `None` if the system refused another world.
+    pub(crate) fn install(slot: TaskSystemSlot, raw: &mut ffi::b2WorldDef) -> Option<Box<Self>> {
+        if !slot.0.attach_world() {
+            return None;
+        }
+        let worker_count = slot.worker_count();
+        let ctx = Box::new(Self {
+            system: slot.0,
+            worker_count: worker_count as u32,
+        });
+        raw.workerCount = worker_count;
+        raw.enqueueTask = Some(enqueue_task_cb);
+        raw.finishTask = Some(finish_task_cb);
+        raw.userTaskContext = (&*ctx as *const Self).cast_mut().cast();
+        Some(ctx)
+    }
+}
+
+impl Drop for TaskSystemCtx {
+    fn drop(&mut self) {
+        self.system.detach_world();
+    }
+}
+
+unsafe extern "C" fn enqueue_task_cb(
+    task: ffi::b2TaskCallback,
+    item_count: i32,
+    min_range: i32,
+    task_context: *mut core::ffi::c_void,
+    user_context: *mut core::ffi::c_void,
+) -> *mut core::ffi::c_void {
+    let Some(callback) = task else {
+        return core::ptr::null_mut();
+    };
+    // SAFETY: `user_context` is the `TaskSystemCtx` installed at creation, kept alive by the core.
+    let ctx = unsafe { &*user_context.cast::<TaskSystemCtx>() };
+    let range = TaskRange {
+        callback,
+        context: task_context,
+        item_count: item_count.max(0) as u32,
+        min_range: min_range.max(1) as u32,
+        worker_count: ctx.worker_count,
+        _stage: PhantomData,
+    };
+    match ctx.system.spawn(range) {
+        Some(pending) => Box::into_raw(Box::new(pending)).cast(),
+        // The stage already ran to completion; null tells Box2D not to call `finishTask`.
+        None => core::ptr::null_mut(),
+    }
+}
+
+unsafe extern "C" fn finish_task_cb(
+    user_task: *mut core::ffi::c_void,
+    _user_context: *mut core::ffi::c_void,
+) {
+    if !user_task.is_null() {
+        // SAFETY: non-null user tasks are the boxed `PendingTask`s returned by `enqueue_task_cb`.
+        unsafe { Box::from_raw(user_task.cast::<PendingTask>()) }.wait();
+    }
+}
+
+impl crate::World {
+    /// Worker count of the world's [`TaskSystem`], or `None` if it was created without one.
+    pub fn task_system_worker_count(&self) -> Option<usize> {
+        self.core
+            .task_system
+            .as_ref()
+            .map(|ctx| ctx.worker_count as usize)
+    }
+}
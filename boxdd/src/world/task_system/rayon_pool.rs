@@ -0,0 +1,141 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+
+use super::{MAX_WORKERS, PendingTask, TaskRange, TaskSystem};
+
+/// [`TaskSystem`] backed by a dedicated rayon thread pool.
+///
+/// With the `parallel` feature, `World::new` installs one automatically when the definition asks
+/// for more than one worker through [`WorldBuilder::worker_count`](crate::WorldBuilder::worker_count)
+/// and no other task system is set.
+///
+/// A pool serves one world at a time: Box2D's solver parks one spinning task on every pool
+/// thread, so two worlds stepping on the same pool at once could deadlock. Creating a second
+/// world through [`WorldBuilder::task_system_arc`](crate::WorldBuilder::task_system_arc) while
+/// the first is alive fails with [`Error::TaskSystemInUse`](crate::world::Error::TaskSystemInUse); build one pool
+/// per world instead. Step the world from outside the pool for the same reason.
+///
+/// ```no_run
+/// use boxdd::{World, WorldDef};
+/// let mut world = World::new(WorldDef::builder().worker_count(4).build()).unwrap();
+/// assert_eq!(world.task_system_worker_count(), Some(4));
+/// world.step(1.0 / 60.0, 4);
+/// ```
+pub struct RayonTaskSystem {
+    pool: rayon::ThreadPool,
+    /// Whether a live world uses the pool.
+    attached: AtomicBool,
+}
+
+impl core::fmt::Debug for RayonTaskSystem {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("RayonTaskSystem")
+            .field("worker_count", &self.pool.current_num_threads())
+            .finish()
+    }
+}
+
+impl RayonTaskSystem {
+    /// Create a pool of `worker_count` threads, clamped to `1..=MAX_WORKERS`.
+    pub fn new(worker_count: usize) -> Result<Self, rayon::ThreadPoolBuildError> {
+        let pool = rayon::ThreadPoolBuilder::new()
+            .num_threads(worker_count.clamp(1, MAX_WORKERS))
+            .thread_name(|i| format!("boxdd-worker-{i}"))
+            .build()?;
+        Ok(Self {
+            pool,
+            attached: AtomicBool::new(false),
+        })
+    }
+
+    /// Create a pool with one thread per available core.
+    pub fn with_available_parallelism() -> Result<Self, rayon::ThreadPoolBuildError> {
+        Self::new(std::thread::available_parallelism().map_or(1, |n| n.get()))
+    }
+}
+
+fn worker_index() -> u32 {
+    rayon::current_thread_index().expect("task ran outside the boxdd worker pool") as u32
+}
+
+fn ranges(task: &TaskRange<'_>) -> impl Iterator<Item = (u32, u32)> + use<> {
+    let item_count = task.item_count();
+    let chunk = item_count
+        .div_ceil(task.worker_count())
+        .max(task.min_range())
+        .max(1);
+    (0..item_count)
+        .step_by(chunk as usize)
+        .map(move |start| (start, (start + chunk).min(item_count)))
+}
+
+/// Counts outstanding ranges of a spawned task.
+struct Latch {
+    remaining: Mutex<usize>,
+    done: Condvar,
+}
+
+impl Latch {
+    fn count_down(&self) {
+        let mut remaining = self.remaining.lock().expect("latch mutex poisoned");
+        *remaining -= 1;
+        if *remaining == 0 {
+            self.done.notify_all();
+        }
+    }
+
+    fn wait(&self) {
+        let mut remaining = self.remaining.lock().expect("latch mutex poisoned");
+        while *remaining > 0 {
+            remaining = self.done.wait(remaining).expect("latch mutex poisoned");
+        }
+    }
+}
+
+// SAFETY: every range runs on a thread of `pool`, which has at most `worker_count` threads, and
+// uses that thread's pool index as its worker index, so no index is in use twice at once.
+unsafe impl TaskSystem for RayonTaskSystem {
+    fn worker_count(&self) -> usize {
+        self.pool.current_num_threads()
+    }
+
+    fn parallel_for(&self, _item_count: u32, _min_range: u32, task: &TaskRange<'_>) {
+        self.pool.scope(|s| {
+            for (start, end) in ranges(task) {
+                s.spawn(move |_| task.run(start, end, worker_index()));
+            }
+        });
+    }
+
+    fn spawn(&self, task: TaskRange<'_>) -> Option<PendingTask> {
+        let ranges: Vec<_> = ranges(&task).collect();
+        if ranges.is_empty() {
+            return None;
+        }
+        // SAFETY: every clone of the range runs inside a job counted by `latch`, and the pending
+        // task only returns once the latch has reached zero, so no run outlives the stage.
+        let task = Arc::new(unsafe { task.detach() });
+        let latch = Arc::new(Latch {
+            remaining: Mutex::new(ranges.len()),
+            done: Condvar::new(),
+        });
+        for (start, end) in ranges {
+            let task = Arc::clone(&task);
+            let latch = Arc::clone(&latch);
+            self.pool.spawn(move || {
+                task.run(start, end, worker_index());
+                latch.count_down();
+            });
+        }
+        // SAFETY: `latch.wait` blocks until every spawned range has run.
+        Some(unsafe { PendingTask::new(move || latch.wait()) })
+    }
+
+    fn attach_world(&self) -> bool {
+        !self.attached.swap(true, Ordering::AcqRel)
+    }
+
+    fn detach_world(&self) {
+        self.attached.store(false, Ordering::Release);
+    }
+}
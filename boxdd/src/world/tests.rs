@@ -76,6 +76,22 @@ fn try_world_runtime_extras_return_in_callback() {
 
 #[test]
 fn try_world_callback_sensitive_entrypoints_return_in_callback() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let _g = crate::core::callback_state::CallbackGuard::enter();
+
+    assert_eq!(
+        world.try_step(1.0 / 60.0, 1).unwrap_err(),
+        crate::StepError::InCallback
+    );
+    assert_eq!(
+        world.try_flush_deferred_destroys().unwrap_err(),
+        crate::ApiError::InCallback
+    );
+}
+
+#[cfg(feature = "debug-draw")]
+#[test]
+fn try_debug_draw_entrypoints_return_in_callback() {
     struct NoopDrawer;
 
     impl crate::DebugDraw for NoopDrawer {}
@@ -90,14 +106,6 @@ fn try_world_callback_sensitive_entrypoints_return_in_callback() {
     let mut raw_drawer = NoopDrawer;
     let _g = crate::core::callback_state::CallbackGuard::enter();
 
-    assert_eq!(
-        world.try_step(1.0 / 60.0, 1).unwrap_err(),
-        crate::ApiError::InCallback
-    );
-    assert_eq!(
-        world.try_flush_deferred_destroys().unwrap_err(),
-        crate::ApiError::InCallback
-    );
     assert_eq!(
         world
             .try_debug_draw_collect(crate::DebugDrawOptions::default())
@@ -0,0 +1,337 @@
+use super::*;
+
+/// Moves bodies along the path of a chain shape, for escalators, conveyors, and assembly lines.
+///
+/// Each rider keeps the distance along the path and the sideways offset it had when it was
+/// [added](Self::add). Call [`ChainRider::update`] once before each [`World::step`]:
+///
+/// - kinematic riders are steered to their next point on the path with
+///   [`World::set_body_target_transform`], advancing [`speed`](Self::speed) meters per second;
+/// - dynamic riders are pushed along the path tangent toward `speed`, with the force capped at
+///   [`max_force`](Self::max_force), so they can still be blocked, knocked off, and stacked.
+///
+/// The path is captured in world space when the rider is created. On a chain loop riders wrap
+/// around; on an open chain kinematic riders stop at the ends. Negative speeds run backwards.
+///
+/// ```no_run
+/// use boxdd::{BodyBuilder, BodyType, ChainDef, ChainRider, World, WorldDef, shapes};
+/// let mut world = World::new(WorldDef::default()).unwrap();
+/// let ground = world.create_body_id(BodyBuilder::new().build());
+/// let track = ChainDef::builder()
+///     .points([[0.0, 0.0], [10.0, 0.0], [10.0, 5.0], [0.0, 5.0]])
+///     .is_loop(true)
+///     .build();
+/// let chain = world.create_chain_for_id(ground, &track);
+/// let cabin = world.create_body_id(BodyBuilder::new().body_type(BodyType::Kinematic).build());
+/// let _ = world.create_shape_simple(cabin, shapes::box_polygon(0.5, 0.5));
+/// let mut ride = ChainRider::new(&world, chain).speed(2.0);
+/// ride.add(&world, cabin);
+/// for _ in 0..60 {
+///     ride.update(&mut world, 1.0 / 60.0);
+///     world.step(1.0 / 60.0, 4);
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct ChainRider {
+    points: Vec<Vec2>,
+    /// Path length at each point.
+    lengths: Vec<f32>,
+    looped: bool,
+    speed: f32,
+    max_force: f32,
+    align: bool,
+    riders: Vec<Rider>,
+}
+
+#[derive(Copy, Clone, Debug, PartialEq)]
+struct Rider {
+    body: BodyId,
+    distance: f32,
+    offset: f32,
+    /// Body angle relative to the path tangent, used by [`ChainRider::align_to_path`].
+    angle_offset: f32,
+}
+
+impl ChainRider {
+    /// Follow the segments of `chain` in chain order.
+    pub fn new(world: &World, chain: ChainId) -> Self {
+        crate::core::debug_checks::assert_chain_valid(chain);
+        Self::from_chain(world, chain)
+    }
+
+    pub fn try_new(world: &World, chain: ChainId) -> ApiResult<Self> {
+        crate::core::debug_checks::check_chain_valid(chain)?;
+        Ok(Self::from_chain(world, chain))
+    }
+
+    fn from_chain(world: &World, chain: ChainId) -> Self {
+        let mut points = Vec::new();
+        for shape in crate::shapes::chain::chain_segments_impl(chain) {
+            let xf = world.body_transform(crate::shapes::shape_body_id_impl(shape));
+            let segment = crate::shapes::shape_chain_segment_impl(shape).segment;
+            if points.is_empty() {
+                points.push(xf.transform_point(segment.point1));
+            }
+            points.push(xf.transform_point(segment.point2));
+        }
+        Self::from_path(points)
+    }
+
+    /// Follow a world-space polyline; it is a loop when the last point repeats the first.
+    ///
+    /// # Panics
+    /// If fewer than two points are given or any point is not finite.
+    pub fn from_points<I, P>(points: I) -> Self
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<Vec2>,
+    {
+        let points: Vec<Vec2> = points.into_iter().map(Into::into).collect();
+        assert!(
+            points.len() >= 2 && points.iter().all(|p| p.is_valid()),
+            "a chain rider path needs at least two valid points"
+        );
+        Self::from_path(points)
+    }
+
+    pub fn try_from_points<I, P>(points: I) -> ApiResult<Self>
+    where
+        I: IntoIterator<Item = P>,
+        P: Into<Vec2>,
+    {
+        let points: Vec<Vec2> = points.into_iter().map(Into::into).collect();
+        if points.len() < 2 || !points.iter().all(|p| p.is_valid()) {
+            return Err(ApiError::InvalidArgument);
+        }
+        Ok(Self::from_path(points))
+    }
+
+    fn from_path(points: Vec<Vec2>) -> Self {
+        let mut lengths = Vec::with_capacity(points.len());
+        let mut total = 0.0;
+        for (i, p) in points.iter().enumerate() {
+            if i > 0 {
+                total += p.minus(points[i - 1]).length();
+            }
+            lengths.push(total);
+        }
+        let looped = points.len() > 2
+            && points[0].minus(points[points.len() - 1]).length() <= CHAIN_RIDER_LOOP_TOLERANCE;
+        Self {
+            points,
+            lengths,
+            looped,
+            speed: 1.0,
+            max_force: 1000.0,
+            align: false,
+            riders: Vec::new(),
+        }
+    }
+
+    /// Speed along the path (m/s); negative runs backwards. Defaults to 1.
+    pub fn speed(mut self, speed: f32) -> Self {
+        self.speed = speed;
+        self
+    }
+
+    /// Force limit (N) for dynamic riders. Defaults to 1000 N, enough to carry a few hundred
+    /// kilograms against gravity; raise it for heavier loads.
+    pub fn max_force(mut self, max_force: f32) -> Self {
+        self.max_force = max_force;
+        self
+    }
+
+    /// Rotate kinematic riders with the path tangent, keeping the angle they were added with.
+    /// Off by default.
+    pub fn align_to_path(mut self, flag: bool) -> Self {
+        self.align = flag;
+        self
+    }
+
+    pub fn set_speed(&mut self, speed: f32) {
+        self.speed = speed;
+    }
+
+    pub fn current_speed(&self) -> f32 {
+        self.speed
+    }
+
+    /// Total path length (m).
+    pub fn path_length(&self) -> f32 {
+        self.lengths.last().copied().unwrap_or(0.0)
+    }
+
+    pub fn is_loop(&self) -> bool {
+        self.looped
+    }
+
+    /// World-space point and unit tangent at `distance` along the path, wrapped on loops and
+    /// clamped on open paths.
+    pub fn point_at(&self, distance: f32) -> (Vec2, Vec2) {
+        let distance = self.wrap(distance);
+        let i = self
+            .lengths
+            .partition_point(|&l| l <= distance)
+            .clamp(1, self.points.len() - 1);
+        let (a, b) = (self.points[i - 1], self.points[i]);
+        let span = self.lengths[i] - self.lengths[i - 1];
+        let tangent = if span > 0.0 {
+            b.minus(a).scaled(1.0 / span)
+        } else {
+            Vec2::new(1.0, 0.0)
+        };
+        (
+            a.plus(tangent.scaled(distance - self.lengths[i - 1])),
+            tangent,
+        )
+    }
+
+    /// Start moving `body` from its closest point on the path.
+    pub fn add(&mut self, world: &World, body: BodyId) {
+        crate::core::debug_checks::assert_body_valid(body);
+        assert!(
+            crate::body::body_type_impl(body) != BodyType::Static,
+            "static bodies cannot ride a chain"
+        );
+        self.add_impl(world, body);
+    }
+
+    pub fn try_add(&mut self, world: &World, body: BodyId) -> ApiResult<()> {
+        crate::core::debug_checks::check_body_valid(body)?;
+        if crate::body::body_type_impl(body) == BodyType::Static {
+            return Err(ApiError::InvalidArgument);
+        }
+        self.add_impl(world, body);
+        Ok(())
+    }
+
+    /// Stop moving `body`. Returns whether it was riding.
+    pub fn remove(&mut self, body: BodyId) -> bool {
+        let before = self.riders.len();
+        self.riders.retain(|r| r.body != body);
+        self.riders.len() != before
+    }
+
+    /// Bodies currently riding, in the order they were added.
+    pub fn riders(&self) -> impl Iterator<Item = BodyId> + '_ {
+        self.riders.iter().map(|r| r.body)
+    }
+
+    /// Distance of `body` along the path, as of the last update.
+    pub fn distance(&self, body: BodyId) -> Option<f32> {
+        self.riders
+            .iter()
+            .find(|r| r.body == body)
+            .map(|r| r.distance)
+    }
+
+    /// Advance every rider by `dt` seconds; destroyed bodies are dropped.
+    pub fn update(&mut self, world: &mut World, dt: f32) {
+        crate::core::callback_state::assert_not_in_callback();
+        assert!(
+            dt.is_finite() && dt > 0.0,
+            "dt must be finite and > 0, got {dt}"
+        );
+        assert!(
+            self.speed.is_finite() && self.max_force >= 0.0,
+            "chain rider speed must be finite and max_force >= 0.0"
+        );
+        self.update_impl(world, dt);
+    }
+
+    pub fn try_update(&mut self, world: &mut World, dt: f32) -> ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        if !(dt.is_finite() && dt > 0.0 && self.speed.is_finite() && self.max_force >= 0.0) {
+            return Err(ApiError::InvalidArgument);
+        }
+        self.update_impl(world, dt);
+        Ok(())
+    }
+
+    fn wrap(&self, distance: f32) -> f32 {
+        let length = self.path_length();
+        if self.looped && length > 0.0 {
+            distance.rem_euclid(length)
+        } else {
+            distance.clamp(0.0, length)
+        }
+    }
+
+    /// Distance along the path and signed sideways offset (left of the tangent) of `p`.
+    fn project(&self, p: Vec2) -> (f32, f32) {
+        let mut best = (f32::MAX, 0.0, 0.0);
+        for i in 1..self.points.len() {
+            let (a, b) = (self.points[i - 1], self.points[i]);
+            let span = self.lengths[i] - self.lengths[i - 1];
+            if span <= 0.0 {
+                continue;
+            }
+            let tangent = b.minus(a).scaled(1.0 / span);
+            let along = p.minus(a).dot(tangent).clamp(0.0, span);
+            let closest = a.plus(tangent.scaled(along));
+            let gap = p.minus(closest);
+            let distance_sq = gap.dot(gap);
+            if distance_sq < best.0 {
+                let side = Vec2::new(-tangent.y, tangent.x);
+                best = (distance_sq, self.lengths[i - 1] + along, gap.dot(side));
+            }
+        }
+        (best.1, best.2)
+    }
+
+    fn add_impl(&mut self, world: &World, body: BodyId) {
+        let xf = world.body_transform(body);
+        let (distance, offset) = self.project(xf.position());
+        let (_, tangent) = self.point_at(distance);
+        let angle_offset = xf.rotation().angle() - tangent.y.atan2(tangent.x);
+        self.remove(body);
+        self.riders.push(Rider {
+            body,
+            distance,
+            offset,
+            angle_offset,
+        });
+    }
+
+    fn update_impl(&mut self, world: &mut World, dt: f32) {
+        self.riders
+            .retain(|r| crate::body::body_is_valid_impl(r.body));
+        for i in 0..self.riders.len() {
+            let rider = self.riders[i];
+            let distance = match crate::body::body_type_impl(rider.body) {
+                BodyType::Kinematic => {
+                    let next = self.wrap(rider.distance + self.speed * dt);
+                    let (point, tangent) = self.point_at(next);
+                    let side = Vec2::new(-tangent.y, tangent.x);
+                    let angle = if self.align {
+                        tangent.y.atan2(tangent.x) + rider.angle_offset
+                    } else {
+                        world.body_transform(rider.body).rotation().angle()
+                    };
+                    let target =
+                        Transform::from_pos_angle(point.plus(side.scaled(rider.offset)), angle);
+                    world.set_body_target_transform(rider.body, target, dt, true);
+                    next
+                }
+                BodyType::Dynamic => {
+                    let (distance, _) = self.project(world.body_position(rider.body));
+                    let (_, tangent) = self.point_at(distance);
+                    let v = crate::body::body_linear_velocity_impl(rider.body);
+                    let mass = crate::body::body_mass_impl(rider.body);
+                    let push = (mass * (self.speed - v.dot(tangent)) / dt)
+                        .clamp(-self.max_force, self.max_force);
+                    if push != 0.0 {
+                        let center = world.body_world_center_of_mass(rider.body);
+                        world.body_apply_force(rider.body, tangent.scaled(push), center, true);
+                    }
+                    distance
+                }
+                BodyType::Static => rider.distance,
+            };
+            self.riders[i].distance = distance;
+        }
+    }
+}
+
+/// Gap (m) between the first and last path points under which a [`ChainRider`] path is a loop.
+const CHAIN_RIDER_LOOP_TOLERANCE: f32 = 1.0e-4;
@@ -0,0 +1,172 @@
+use super::prismatic_drive::*;
+use super::*;
+
+/// Open/close state of a [`Door`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DoorState {
+    Closed,
+    Opening,
+    Open,
+    Closing,
+}
+
+/// Sliding door: a prismatic joint between a frame and a panel with a motor that moves the panel
+/// between its closed position (where it was created) and `open_translation` along `axis`.
+///
+/// Call [`Door::update`] once per step before [`World::step`]; it steers the motor toward the
+/// current target and slows down on arrival. The joint's limits keep the panel between the two
+/// positions. Destroy the joint (or either body) through the world as usual.
+///
+/// ```no_run
+/// use boxdd::{BodyBuilder, BodyType, Door, DoorState, World, WorldDef};
+/// let mut world = World::new(WorldDef::default()).unwrap();
+/// let frame = world.create_body_id(BodyBuilder::new().build());
+/// let panel = world.create_body_id(
+///     BodyBuilder::new().body_type(BodyType::Dynamic).position([0.0, 1.0]).build(),
+/// );
+/// let mut door = Door::new(&mut world, frame, panel, [0.0, 1.0], 2.0, 1.5, 1000.0);
+/// door.open(&mut world);
+/// while door.state(&world) == DoorState::Opening {
+///     door.update(&mut world);
+///     world.step(1.0 / 60.0, 4);
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Door {
+    drive: PrismaticDrive,
+    open_translation: f32,
+}
+
+impl Door {
+    /// Create a closed door. `panel` slides along `axis` (world space) relative to `frame`, from
+    /// its current position to `open_translation` meters along the axis, at up to `speed` m/s with
+    /// the motor force capped at `max_force` (N). Panics if `open_translation` is zero or not
+    /// finite, since the open and closed positions would coincide.
+    pub fn new<V: Into<Vec2>>(
+        world: &mut World,
+        frame: BodyId,
+        panel: BodyId,
+        axis: V,
+        open_translation: f32,
+        speed: f32,
+        max_force: f32,
+    ) -> Self {
+        crate::core::debug_checks::assert_body_valid(frame);
+        crate::core::debug_checks::assert_body_valid(panel);
+        let axis = axis.into();
+        assert_slider_args(axis, speed, max_force);
+        assert!(
+            open_translation_valid(open_translation),
+            "open_translation must be finite and non-zero, got {open_translation}"
+        );
+        let def = door_def(world, frame, panel, axis, open_translation, max_force);
+        Self::from_joint(
+            world.create_prismatic_joint_id(&def),
+            open_translation,
+            speed,
+        )
+    }
+
+    /// Recoverable version of [`Self::new`].
+    pub fn try_new<V: Into<Vec2>>(
+        world: &mut World,
+        frame: BodyId,
+        panel: BodyId,
+        axis: V,
+        open_translation: f32,
+        speed: f32,
+        max_force: f32,
+    ) -> ApiResult<Self> {
+        crate::core::debug_checks::check_body_valid(frame)?;
+        crate::core::debug_checks::check_body_valid(panel)?;
+        let axis = axis.into();
+        if !(slider_args_valid(axis, speed, max_force) && open_translation_valid(open_translation))
+        {
+            return Err(ApiError::InvalidArgument);
+        }
+        let def = door_def(world, frame, panel, axis, open_translation, max_force);
+        Ok(Self::from_joint(
+            world.try_create_prismatic_joint_id(&def)?,
+            open_translation,
+            speed,
+        ))
+    }
+
+    fn from_joint(joint: JointId, open_translation: f32, speed: f32) -> Self {
+        Self {
+            drive: PrismaticDrive {
+                joint,
+                target: 0.0,
+                speed,
+            },
+            open_translation,
+        }
+    }
+
+    /// The underlying prismatic joint.
+    pub fn joint_id(&self) -> JointId {
+        self.drive.joint
+    }
+
+    /// Start opening; wakes the joint's bodies.
+    pub fn open(&mut self, world: &mut World) {
+        self.drive.set_target(world, self.open_translation);
+    }
+
+    /// Start closing; wakes the joint's bodies.
+    pub fn close(&mut self, world: &mut World) {
+        self.drive.set_target(world, 0.0);
+    }
+
+    /// Reverse direction: close if opening or open, open otherwise.
+    pub fn toggle(&mut self, world: &mut World) {
+        if self.is_opening_or_open() {
+            self.close(world);
+        } else {
+            self.open(world);
+        }
+    }
+
+    fn is_opening_or_open(&self) -> bool {
+        self.drive.target == self.open_translation
+    }
+
+    pub fn state(&self, world: &World) -> DoorState {
+        match (self.is_opening_or_open(), self.drive.arrived(world)) {
+            (true, true) => DoorState::Open,
+            (true, false) => DoorState::Opening,
+            (false, true) => DoorState::Closed,
+            (false, false) => DoorState::Closing,
+        }
+    }
+
+    /// Whether the panel is away from its target or still sliding.
+    pub fn is_moving(&self, world: &World) -> bool {
+        self.drive.is_moving(world)
+    }
+
+    /// Steer the motor toward the current target. Call once per step.
+    pub fn update(&self, world: &mut World) {
+        self.drive.update(world);
+    }
+
+    pub fn try_update(&self, world: &mut World) -> ApiResult<()> {
+        self.drive.try_update(world)
+    }
+}
+
+fn open_translation_valid(open_translation: f32) -> bool {
+    open_translation.is_finite() && open_translation != 0.0
+}
+
+fn door_def(
+    world: &World,
+    frame: BodyId,
+    panel: BodyId,
+    axis: Vec2,
+    open_translation: f32,
+    max_force: f32,
+) -> crate::joints::PrismaticJointDef {
+    let range = (open_translation.min(0.0), open_translation.max(0.0));
+    PrismaticDrive::def(world, frame, panel, axis, range, max_force)
+}
@@ -0,0 +1,162 @@
+use super::prismatic_drive::*;
+use super::*;
+
+/// Elevator platform: a prismatic joint with a motor that moves `platform` between stops given as
+/// translations (meters) along `axis` from where it was created.
+///
+/// Call [`Elevator::update`] once per step before [`World::step`]. The joint's limits span the
+/// lowest to highest stop, widened to include the creation pose (translation 0) so the platform
+/// is not pulled onto the range when it starts outside it.
+///
+/// ```no_run
+/// use boxdd::{BodyBuilder, BodyType, Elevator, World, WorldDef};
+/// let mut world = World::new(WorldDef::default()).unwrap();
+/// let ground = world.create_body_id(BodyBuilder::new().build());
+/// let platform = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+/// let mut lift = Elevator::new(&mut world, ground, platform, [0.0, 1.0], &[0.0, 4.0, 8.0], 2.0, 5000.0);
+/// lift.go_to(&mut world, 2);
+/// while lift.is_moving(&world) {
+///     lift.update(&mut world);
+///     world.step(1.0 / 60.0, 4);
+/// }
+/// assert_eq!(lift.current_stop(&world), Some(2));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Elevator {
+    drive: PrismaticDrive,
+    stops: Vec<f32>,
+    target_stop: usize,
+}
+
+impl Elevator {
+    /// Create an elevator heading for stop 0. Panics if `stops` is empty or contains non-finite
+    /// values.
+    pub fn new<V: Into<Vec2>>(
+        world: &mut World,
+        ground: BodyId,
+        platform: BodyId,
+        axis: V,
+        stops: &[f32],
+        speed: f32,
+        max_force: f32,
+    ) -> Self {
+        crate::core::debug_checks::assert_body_valid(ground);
+        crate::core::debug_checks::assert_body_valid(platform);
+        let axis = axis.into();
+        assert_slider_args(axis, speed, max_force);
+        assert!(
+            stops_valid(stops),
+            "stops must be non-empty and finite, got {stops:?}"
+        );
+        let def = elevator_def(world, ground, platform, axis, stops, max_force);
+        Self::from_joint(world.create_prismatic_joint_id(&def), stops, speed)
+    }
+
+    /// Recoverable version of [`Self::new`].
+    pub fn try_new<V: Into<Vec2>>(
+        world: &mut World,
+        ground: BodyId,
+        platform: BodyId,
+        axis: V,
+        stops: &[f32],
+        speed: f32,
+        max_force: f32,
+    ) -> ApiResult<Self> {
+        crate::core::debug_checks::check_body_valid(ground)?;
+        crate::core::debug_checks::check_body_valid(platform)?;
+        let axis = axis.into();
+        if !(slider_args_valid(axis, speed, max_force) && stops_valid(stops)) {
+            return Err(ApiError::InvalidArgument);
+        }
+        let def = elevator_def(world, ground, platform, axis, stops, max_force);
+        Ok(Self::from_joint(
+            world.try_create_prismatic_joint_id(&def)?,
+            stops,
+            speed,
+        ))
+    }
+
+    fn from_joint(joint: JointId, stops: &[f32], speed: f32) -> Self {
+        Self {
+            drive: PrismaticDrive {
+                joint,
+                target: stops[0],
+                speed,
+            },
+            stops: stops.to_vec(),
+            target_stop: 0,
+        }
+    }
+
+    /// The underlying prismatic joint.
+    pub fn joint_id(&self) -> JointId {
+        self.drive.joint
+    }
+
+    /// Stop translations in the order given at creation.
+    pub fn stops(&self) -> &[f32] {
+        &self.stops
+    }
+
+    /// Index of the stop the elevator is heading to (or resting at).
+    pub fn target_stop(&self) -> usize {
+        self.target_stop
+    }
+
+    /// Index of the stop the platform is resting at, or `None` while travelling.
+    pub fn current_stop(&self, world: &World) -> Option<usize> {
+        (!self.drive.is_moving(world)).then_some(self.target_stop)
+    }
+
+    /// Head for stop `index`; wakes the joint's bodies. Panics if `index` is out of range.
+    pub fn go_to(&mut self, world: &mut World, index: usize) {
+        assert!(
+            index < self.stops.len(),
+            "stop index {index} out of range for {} stops",
+            self.stops.len()
+        );
+        self.target_stop = index;
+        self.drive.set_target(world, self.stops[index]);
+    }
+
+    pub fn try_go_to(&mut self, world: &mut World, index: usize) -> ApiResult<()> {
+        let Some(&target) = self.stops.get(index) else {
+            return Err(ApiError::InvalidArgument);
+        };
+        world.try_joint_wake_bodies(self.drive.joint)?;
+        self.target_stop = index;
+        self.drive.target = target;
+        Ok(())
+    }
+
+    /// Whether the platform is away from its target stop or still sliding.
+    pub fn is_moving(&self, world: &World) -> bool {
+        self.drive.is_moving(world)
+    }
+
+    /// Steer the motor toward the target stop. Call once per step.
+    pub fn update(&self, world: &mut World) {
+        self.drive.update(world);
+    }
+
+    pub fn try_update(&self, world: &mut World) -> ApiResult<()> {
+        self.drive.try_update(world)
+    }
+}
+
+fn stops_valid(stops: &[f32]) -> bool {
+    !stops.is_empty() && stops.iter().all(|s| s.is_finite())
+}
+
+fn elevator_def(
+    world: &World,
+    ground: BodyId,
+    platform: BodyId,
+    axis: Vec2,
+    stops: &[f32],
+    max_force: f32,
+) -> crate::joints::PrismaticJointDef {
+    let lower = stops.iter().copied().fold(0.0, f32::min);
+    let upper = stops.iter().copied().fold(0.0, f32::max);
+    PrismaticDrive::def(world, ground, platform, axis, (lower, upper), max_force)
+}
@@ -0,0 +1,139 @@
+use super::*;
+
+/// Explosion configuration (maps to `b2ExplosionDef`).
+#[derive(Copy, Clone, Debug)]
+pub struct ExplosionDef(pub(crate) ffi::b2ExplosionDef);
+
+impl Default for ExplosionDef {
+    fn default() -> Self {
+        Self(unsafe { ffi::b2DefaultExplosionDef() })
+    }
+}
+
+impl ExplosionDef {
+    /// Create a default explosion definition.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    #[inline]
+    pub fn from_raw(raw: ffi::b2ExplosionDef) -> Self {
+        Self(raw)
+    }
+
+    #[inline]
+    pub fn into_raw(self) -> ffi::b2ExplosionDef {
+        self.0
+    }
+
+    /// Mask bits used to filter affected shapes.
+    pub fn affected_mask_bits(&self) -> u64 {
+        self.0.maskBits
+    }
+
+    /// World-space center position.
+    pub fn center(&self) -> Vec2 {
+        Vec2::from_raw(self.0.position)
+    }
+
+    /// Explosion radius in meters.
+    pub fn blast_radius(&self) -> f32 {
+        self.0.radius
+    }
+
+    /// Falloff distance beyond the radius where the impulse decays to zero.
+    pub fn falloff_distance(&self) -> f32 {
+        self.0.falloff
+    }
+
+    /// Impulse per unit length applied to perimeter facing the explosion.
+    pub fn impulse_per_unit_length(&self) -> f32 {
+        self.0.impulsePerLength
+    }
+
+    /// Mask bits used to filter affected shapes.
+    pub fn mask_bits(mut self, bits: u64) -> Self {
+        self.0.maskBits = bits;
+        self
+    }
+
+    /// World-space center position.
+    pub fn position<V: Into<Vec2>>(mut self, p: V) -> Self {
+        self.0.position = p.into().into_raw();
+        self
+    }
+
+    /// Explosion radius in meters.
+    pub fn radius(mut self, r: f32) -> Self {
+        self.0.radius = r;
+        self
+    }
+
+    /// Falloff distance beyond the radius where the impulse decays to zero.
+    pub fn falloff(mut self, f: f32) -> Self {
+        self.0.falloff = f;
+        self
+    }
+
+    /// Impulse per unit length applied to perimeter facing the explosion.
+    pub fn impulse_per_length(mut self, v: f32) -> Self {
+        self.0.impulsePerLength = v;
+        self
+    }
+}
+
+impl ExplosionDef {
+    /// Finite center and impulse, finite non-negative radius and falloff, as Box2D requires.
+    pub fn is_valid(&self) -> bool {
+        let d = &self.0;
+        self.center().is_valid()
+            && d.radius.is_finite()
+            && d.radius >= 0.0
+            && d.falloff.is_finite()
+            && d.falloff >= 0.0
+            && d.impulsePerLength.is_finite()
+    }
+}
+
+impl World {
+    /// Trigger an explosion.
+    ///
+    /// Box2D pushes every shape on a dynamic body whose category matches
+    /// [`mask_bits`](ExplosionDef::mask_bits) and whose closest point lies within
+    /// `radius + falloff` of the center. The impulse scales with the shape's perimeter facing the
+    /// blast and fades linearly to zero across the falloff band. Panics if `def` fails
+    /// [`ExplosionDef::is_valid`].
+    ///
+    /// ```no_run
+    /// use boxdd::{ExplosionDef, World, WorldDef};
+    /// let mut world = World::new(WorldDef::default()).unwrap();
+    /// world.explode(
+    ///     &ExplosionDef::new()
+    ///         .position([0.0, 1.0])
+    ///         .radius(2.0)
+    ///         .falloff(1.0)
+    ///         .impulse_per_length(5.0),
+    /// );
+    /// ```
+    pub fn explode(&mut self, def: &ExplosionDef) {
+        crate::core::callback_state::assert_not_in_callback();
+        assert!(
+            def.is_valid(),
+            "explosion needs a valid center, finite impulse, and finite radius/falloff >= 0, got {def:?}"
+        );
+        self.explode_impl(def)
+    }
+
+    pub fn try_explode(&mut self, def: &ExplosionDef) -> ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        if !def.is_valid() {
+            return Err(ApiError::InvalidArgument);
+        }
+        self.explode_impl(def);
+        Ok(())
+    }
+
+    fn explode_impl(&mut self, def: &ExplosionDef) {
+        unsafe { ffi::b2World_Explode(self.raw(), &def.0) }
+    }
+}
@@ -0,0 +1,173 @@
+use super::*;
+
+/// Climbable area: dynamic bodies inside a sensor lose (or change) gravity and have their speed
+/// clamped, the usual platformer ladder or vine.
+///
+/// Call [`Ladder::update`] once per step, before [`World::step`] and after setting climber
+/// velocities from input, so the clamped speeds are the ones the step integrates. Bodies that
+/// entered the sensor get [`Ladder::gravity_scale`] (default `0.0`, so they hang in place) and
+/// from then on their vertical speed is clamped to [`Ladder::max_climb_speed`] and their
+/// horizontal speed to [`Ladder::max_side_speed`]. Bodies that left get their previous gravity
+/// scale back, and [`Ladder::release`] restores every climber before the ladder is dropped.
+///
+/// Climbers are the dynamic visitors of a [`Zone`] on the sensor, so as with any Box2D sensor the
+/// climber's shapes need sensor events enabled and must pass the sensor shape's filter. Gravity
+/// scale rules ([`World::set_gravity_scale_for_category`]) take precedence over the ladder's
+/// gravity scale on the bodies they match.
+///
+/// ```no_run
+/// use boxdd::{BodyBuilder, Ladder, ShapeDef, World, WorldDef, shapes};
+/// let mut world = World::new(WorldDef::default()).unwrap();
+/// let wall = world.create_body_id(BodyBuilder::new().build());
+/// let sensor = world.create_polygon_shape_for(
+///     wall,
+///     &ShapeDef::builder().sensor(true).build(),
+///     &shapes::box_polygon(0.5, 4.0),
+/// );
+/// let mut ladder = Ladder::new(sensor).max_climb_speed(2.0);
+/// for _ in 0..60 {
+///     ladder.update(&mut world);
+///     world.step(1.0 / 60.0, 4);
+/// }
+/// ladder.release(&mut world);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Ladder {
+    zone: Zone,
+    gravity_scale: f32,
+    max_climb_speed: f32,
+    max_side_speed: f32,
+    /// Bodies inside with the gravity scale they had on entry.
+    climbers: Vec<(BodyId, f32)>,
+}
+
+impl Ladder {
+    /// Track `sensor` with zero gravity, a 3 m/s climb speed, and no side speed limit.
+    pub fn new(sensor: ShapeId) -> Self {
+        Self {
+            zone: Zone::new(sensor).include_dynamic(true),
+            gravity_scale: 0.0,
+            max_climb_speed: 3.0,
+            max_side_speed: f32::INFINITY,
+            climbers: Vec::new(),
+        }
+    }
+
+    /// Gravity scale applied to bodies while they are inside.
+    pub fn gravity_scale(mut self, gravity_scale: f32) -> Self {
+        self.gravity_scale = gravity_scale;
+        self
+    }
+
+    /// Vertical speed limit inside (m/s).
+    pub fn max_climb_speed(mut self, speed: f32) -> Self {
+        self.max_climb_speed = speed;
+        self
+    }
+
+    /// Horizontal speed limit inside (m/s).
+    pub fn max_side_speed(mut self, speed: f32) -> Self {
+        self.max_side_speed = speed;
+        self
+    }
+
+    /// The tracked sensor shape.
+    pub fn sensor_shape(&self) -> ShapeId {
+        self.zone.sensor_shape()
+    }
+
+    /// Bodies inside as of the last [`Ladder::update`].
+    pub fn climbers(&self) -> impl Iterator<Item = BodyId> + '_ {
+        self.climbers.iter().map(|&(body, _)| body)
+    }
+
+    pub fn is_climbing(&self, body: BodyId) -> bool {
+        self.climbers.iter().any(|&(b, _)| b == body)
+    }
+
+    /// Pick up bodies that entered, restore bodies that left, and clamp climber speeds.
+    pub fn update(&mut self, world: &mut World) {
+        crate::core::callback_state::assert_not_in_callback();
+        crate::core::debug_checks::assert_shape_valid(self.zone.sensor_shape());
+        assert!(
+            self.max_climb_speed >= 0.0 && self.max_side_speed >= 0.0,
+            "ladder speed limits must be >= 0.0"
+        );
+        self.update_impl(world);
+    }
+
+    pub fn try_update(&mut self, world: &mut World) -> ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        crate::core::debug_checks::check_shape_valid(self.zone.sensor_shape())?;
+        if !(self.max_climb_speed >= 0.0 && self.max_side_speed >= 0.0) {
+            return Err(ApiError::InvalidArgument);
+        }
+        self.update_impl(world);
+        Ok(())
+    }
+
+    /// Give every climber its gravity scale back and forget them.
+    pub fn release(&mut self, world: &mut World) {
+        crate::core::callback_state::assert_not_in_callback();
+        self.release_impl(world);
+    }
+
+    pub fn try_release(&mut self, world: &mut World) -> ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        self.release_impl(world);
+        Ok(())
+    }
+
+    fn update_impl(&mut self, world: &mut World) {
+        self.zone.update_impl(world);
+        let mut inside: Vec<BodyId> = self
+            .zone
+            .visitors()
+            .iter()
+            .map(|&shape| crate::shapes::shape_body_id_impl(shape))
+            .collect();
+        inside.sort_unstable();
+        inside.dedup();
+
+        let mut left = Vec::new();
+        self.climbers.retain(|&climber| {
+            let stays = inside.contains(&climber.0);
+            if !stays {
+                left.push(climber);
+            }
+            stays
+        });
+        for (body, scale) in left {
+            restore_climber(world, body, scale);
+        }
+        for body in inside {
+            if !self.is_climbing(body) {
+                self.climbers
+                    .push((body, crate::body::body_gravity_scale_impl(body)));
+                crate::body::body_set_gravity_scale_impl(body, self.gravity_scale);
+            }
+            let v = crate::body::body_linear_velocity_impl(body);
+            let clamped = Vec2::new(
+                v.x.clamp(-self.max_side_speed, self.max_side_speed),
+                v.y.clamp(-self.max_climb_speed, self.max_climb_speed),
+            );
+            if clamped != v {
+                world.set_body_linear_velocity(body, clamped);
+            }
+        }
+    }
+
+    fn release_impl(&mut self, world: &mut World) {
+        for (body, scale) in self.climbers.drain(..) {
+            restore_climber(world, body, scale);
+        }
+    }
+}
+
+/// Give a former climber its gravity back and wake it so a resting body starts falling.
+fn restore_climber(world: &mut World, body: BodyId, scale: f32) {
+    if crate::body::body_is_valid_impl(body) {
+        crate::body::body_set_gravity_scale_impl(body, scale);
+        world.set_body_awake(body, true);
+    }
+}
@@ -0,0 +1,58 @@
+use super::*;
+
+/// Named logical position in a level, such as a spawn point or camera anchor.
+///
+/// Markers are stored on the world next to the physics objects and round-trip through scene
+/// snapshots, so level files can carry them alongside bodies and joints. Names are unique per
+/// world; tags group markers for lookup (e.g. every `"enemy_spawn"`).
+///
+/// ```no_run
+/// use boxdd::{Marker, World, WorldDef};
+/// let mut world = World::new(WorldDef::default()).unwrap();
+/// world.set_marker(Marker::at("player_start", [0.0, 2.0]).tag("spawn"));
+/// let start = world.marker("player_start").unwrap().position();
+/// # let _ = start;
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug)]
+pub struct Marker {
+    pub name: String,
+    pub transform: Transform,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Vec::is_empty")
+    )]
+    pub tags: Vec<String>,
+}
+
+impl Marker {
+    pub fn new(name: impl Into<String>, transform: Transform) -> Self {
+        Self {
+            name: name.into(),
+            transform,
+            tags: Vec::new(),
+        }
+    }
+
+    /// Marker at `position` with no rotation.
+    pub fn at<V: Into<Vec2>>(name: impl Into<String>, position: V) -> Self {
+        Self::new(name, Transform::from_pos_angle(position, 0.0))
+    }
+
+    /// Add a tag (ignored if already present).
+    pub fn tag(mut self, tag: impl Into<String>) -> Self {
+        let tag = tag.into();
+        if !self.has_tag(&tag) {
+            self.tags.push(tag);
+        }
+        self
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        self.tags.iter().any(|t| t == tag)
+    }
+
+    pub fn position(&self) -> Vec2 {
+        self.transform.position()
+    }
+}
@@ -0,0 +1,214 @@
+use super::*;
+
+/// Tweens a shape's geometry toward a target over time, e.g. inflating a balloon or shrinking a
+/// character's capsule while crouching.
+///
+/// The start geometry is read from the shape when the morph is created and must be the same kind
+/// as the target; polygons must also have the same vertex count, blended vertex by vertex. Each
+/// [`Self::update`] replaces the geometry in place, which wakes bodies touching the shape, and by
+/// default recomputes the body's mass so density stays constant.
+///
+/// ```no_run
+/// use boxdd::{BodyBuilder, BodyType, ShapeMorph, World, WorldDef, shapes};
+/// let mut world = World::new(WorldDef::default()).unwrap();
+/// let body = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+/// let balloon = world.create_shape_simple(body, shapes::circle([0.0, 0.0], 0.2));
+/// let mut inflate = ShapeMorph::new(balloon, shapes::circle([0.0, 0.0], 1.0), 2.0);
+/// while !inflate.is_finished() {
+///     inflate.update(&mut world, 1.0 / 60.0);
+///     world.step(1.0 / 60.0, 4);
+/// }
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct ShapeMorph {
+    shape: ShapeId,
+    from: Geometry,
+    to: Geometry,
+    duration: f32,
+    elapsed: f32,
+    update_mass: bool,
+}
+
+impl ShapeMorph {
+    /// Morph `shape` from its current geometry to `target` over `duration` seconds.
+    ///
+    /// Panics if the shape is invalid, `duration` is negative or not finite, or `target` is
+    /// invalid or cannot be blended with the current geometry.
+    pub fn new(shape: ShapeId, target: impl Into<Geometry>, duration: f32) -> Self {
+        crate::core::debug_checks::assert_shape_valid(shape);
+        assert!(
+            duration.is_finite() && duration >= 0.0,
+            "duration must be finite and >= 0.0, got {duration}"
+        );
+        let to = target.into();
+        match shape_geometry(shape) {
+            Some(from) if morph_compatible(&from, &to) => {
+                Self::from_parts(shape, from, to, duration)
+            }
+            _ => panic!(
+                "target must be valid geometry of the same kind (and vertex count) as the shape, \
+                 got {to:?}"
+            ),
+        }
+    }
+
+    /// Recoverable version of [`Self::new`].
+    pub fn try_new(shape: ShapeId, target: impl Into<Geometry>, duration: f32) -> ApiResult<Self> {
+        crate::core::debug_checks::check_shape_valid(shape)?;
+        let to = target.into();
+        match shape_geometry(shape) {
+            Some(from)
+                if duration.is_finite() && duration >= 0.0 && morph_compatible(&from, &to) =>
+            {
+                Ok(Self::from_parts(shape, from, to, duration))
+            }
+            _ => Err(ApiError::InvalidArgument),
+        }
+    }
+
+    fn from_parts(shape: ShapeId, from: Geometry, to: Geometry, duration: f32) -> Self {
+        Self {
+            shape,
+            from,
+            to,
+            duration,
+            elapsed: 0.0,
+            update_mass: true,
+        }
+    }
+
+    /// Recompute the body's mass after each change (default `true`).
+    pub fn update_mass(mut self, flag: bool) -> Self {
+        self.update_mass = flag;
+        self
+    }
+
+    pub fn shape(&self) -> ShapeId {
+        self.shape
+    }
+
+    /// Fraction of the duration elapsed, in `[0, 1]`.
+    pub fn progress(&self) -> f32 {
+        if self.duration > 0.0 {
+            (self.elapsed / self.duration).min(1.0)
+        } else {
+            1.0
+        }
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.progress() >= 1.0
+    }
+
+    /// Head back toward the start geometry from the current point, e.g. standing up again
+    /// partway through a crouch.
+    pub fn reverse(&mut self) {
+        core::mem::swap(&mut self.from, &mut self.to);
+        self.elapsed = self.duration - self.elapsed;
+    }
+
+    /// Advance by `dt` seconds and apply the blended geometry. Does nothing once finished.
+    ///
+    /// Panics if the shape was destroyed, `dt` is negative or not finite, or the blended polygon
+    /// is degenerate.
+    pub fn update(&mut self, world: &mut World, dt: f32) {
+        crate::core::debug_checks::assert_shape_valid(self.shape);
+        assert!(
+            dt.is_finite() && dt >= 0.0,
+            "dt must be finite and >= 0.0, got {dt}"
+        );
+        if self.is_finished() {
+            return;
+        }
+        self.elapsed = (self.elapsed + dt).min(self.duration);
+        let geometry = blend_geometry(&self.from, &self.to, self.progress())
+            .expect("blended polygon must be convex and non-degenerate");
+        self.apply(world, &geometry);
+    }
+
+    /// Recoverable version of [`Self::update`].
+    pub fn try_update(&mut self, world: &mut World, dt: f32) -> ApiResult<()> {
+        crate::core::debug_checks::check_shape_valid(self.shape)?;
+        if !(dt.is_finite() && dt >= 0.0) {
+            return Err(ApiError::InvalidArgument);
+        }
+        if self.is_finished() {
+            return Ok(());
+        }
+        let elapsed = (self.elapsed + dt).min(self.duration);
+        let progress = if self.duration > 0.0 {
+            (elapsed / self.duration).min(1.0)
+        } else {
+            1.0
+        };
+        let geometry =
+            blend_geometry(&self.from, &self.to, progress).ok_or(ApiError::InvalidArgument)?;
+        self.elapsed = elapsed;
+        self.apply(world, &geometry);
+        Ok(())
+    }
+
+    fn apply(&self, world: &mut World, geometry: &Geometry) {
+        match geometry {
+            Geometry::Circle(c) => world.shape_set_circle(self.shape, c),
+            Geometry::Segment(s) => world.shape_set_segment(self.shape, s),
+            Geometry::Capsule(c) => world.shape_set_capsule(self.shape, c),
+            Geometry::Polygon(p) => world.shape_set_polygon(self.shape, p),
+        }
+        let body = crate::shapes::shape_body_id_impl(self.shape);
+        if self.update_mass {
+            world.body_apply_mass_from_shapes(body);
+        }
+        world.set_body_awake(body, true);
+    }
+}
+
+fn shape_geometry(shape: ShapeId) -> Option<Geometry> {
+    use crate::shapes::ShapeType;
+    match crate::shapes::shape_type_impl(shape) {
+        ShapeType::Circle => Some(crate::shapes::shape_circle_impl(shape).into()),
+        ShapeType::Segment => Some(crate::shapes::shape_segment_impl(shape).into()),
+        ShapeType::Capsule => Some(crate::shapes::shape_capsule_impl(shape).into()),
+        ShapeType::Polygon => Some(crate::shapes::shape_polygon_impl(shape).into()),
+        ShapeType::ChainSegment => None,
+    }
+}
+
+fn morph_compatible(from: &Geometry, to: &Geometry) -> bool {
+    match (from, to) {
+        (Geometry::Circle(_), Geometry::Circle(b)) => b.is_valid(),
+        (Geometry::Segment(_), Geometry::Segment(b)) => b.is_valid(),
+        (Geometry::Capsule(_), Geometry::Capsule(b)) => b.is_valid(),
+        (Geometry::Polygon(a), Geometry::Polygon(b)) => b.is_valid() && a.count() == b.count(),
+        _ => false,
+    }
+}
+
+fn blend_geometry(from: &Geometry, to: &Geometry, t: f32) -> Option<Geometry> {
+    let mix = |a: f32, b: f32| a + (b - a) * t;
+    let mix_v = |a: Vec2, b: Vec2| Vec2::new(mix(a.x, b.x), mix(a.y, b.y));
+    let geometry = match (from, to) {
+        (Geometry::Circle(a), Geometry::Circle(b)) => {
+            crate::shapes::Circle::new(mix_v(a.center, b.center), mix(a.radius, b.radius)).into()
+        }
+        (Geometry::Segment(a), Geometry::Segment(b)) => {
+            crate::shapes::Segment::new(mix_v(a.point1, b.point1), mix_v(a.point2, b.point2)).into()
+        }
+        (Geometry::Capsule(a), Geometry::Capsule(b)) => crate::shapes::Capsule::new(
+            mix_v(a.center1, b.center1),
+            mix_v(a.center2, b.center2),
+            mix(a.radius, b.radius),
+        )
+        .into(),
+        (Geometry::Polygon(a), Geometry::Polygon(b)) => {
+            let points = a
+                .vertices()
+                .iter()
+                .zip(b.vertices())
+                .map(|(&p, &q)| mix_v(p, q));
+            crate::shapes::Polygon::from_points(points, mix(a.radius(), b.radius()))?.into()
+        }
+        _ => return None,
+    };
+    Some(geometry)
+}
@@ -0,0 +1,102 @@
+use super::*;
+
+/// A one-way platform with "press down to drop through", built on
+/// [`World::set_one_way_platform`] and [`World::disable_contact`].
+///
+/// Bodies jump up through the platform and land on top of it. [`drop_through`](Self::drop_through)
+/// lets one body fall through it: the pair stays disabled for at least the given number of steps,
+/// which covers a body pressed down just before landing, and is re-enabled as soon as the body is
+/// clear of the platform afterwards.
+///
+/// ```no_run
+/// use boxdd::{BodyBuilder, BodyType, OneWayPlatform, ShapeDef, World, WorldDef, shapes};
+/// let mut world = World::new(WorldDef::default()).unwrap();
+/// let ground = world.create_body_id(BodyBuilder::new().build());
+/// let ledge = world.create_polygon_shape_for(ground, &ShapeDef::default(), &shapes::box_polygon(3.0, 0.1));
+/// let platform = OneWayPlatform::new(&mut world, ledge);
+/// let player = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+/// let down_pressed = true;
+/// if down_pressed {
+///     platform.drop_through(&mut world, player, 10);
+/// }
+/// world.step(1.0 / 60.0, 4);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct OneWayPlatform {
+    shape: ShapeId,
+}
+
+impl OneWayPlatform {
+    /// Make `shape` a platform that is solid from above (+y).
+    pub fn new(world: &mut World, shape: ShapeId) -> Self {
+        Self::with_up(world, shape, Vec2::new(0.0, 1.0))
+    }
+
+    pub fn try_new(world: &mut World, shape: ShapeId) -> ApiResult<Self> {
+        Self::try_with_up(world, shape, Vec2::new(0.0, 1.0))
+    }
+
+    /// Make `shape` a platform that is solid from the `up` side (world space).
+    pub fn with_up<V: Into<Vec2>>(world: &mut World, shape: ShapeId, up: V) -> Self {
+        world.set_one_way_platform(shape, up);
+        Self { shape }
+    }
+
+    pub fn try_with_up<V: Into<Vec2>>(world: &mut World, shape: ShapeId, up: V) -> ApiResult<Self> {
+        world.try_set_one_way_platform(shape, up)?;
+        Ok(Self { shape })
+    }
+
+    /// The platform shape.
+    pub fn shape(&self) -> ShapeId {
+        self.shape
+    }
+
+    /// Let `body` fall through the platform: for at least `min_steps` steps, then until none of
+    /// its shapes overlap the platform. Calling again while dropping restarts the minimum.
+    pub fn drop_through(&self, world: &mut World, body: BodyId, min_steps: u32) {
+        crate::core::callback_state::assert_not_in_callback();
+        crate::core::debug_checks::assert_body_valid(body);
+        crate::core::debug_checks::assert_shape_valid(self.shape);
+        self.drop_through_impl(world, body, min_steps);
+    }
+
+    pub fn try_drop_through(
+        &self,
+        world: &mut World,
+        body: BodyId,
+        min_steps: u32,
+    ) -> ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        crate::core::debug_checks::check_body_valid(body)?;
+        crate::core::debug_checks::check_shape_valid(self.shape)?;
+        self.drop_through_impl(world, body, min_steps);
+        Ok(())
+    }
+
+    /// Whether `body` is still dropping through the platform.
+    pub fn is_dropping(&self, world: &World, body: BodyId) -> bool {
+        crate::body::body_is_valid_impl(body)
+            && crate::body::body_shapes_impl(body)
+                .into_iter()
+                .any(|shape| world.contact_disabled(shape, self.shape).is_some())
+    }
+
+    /// Make the shape solid from every side again and cancel pending drops.
+    pub fn remove(self, world: &mut World) {
+        world.clear_one_way_platform(self.shape);
+        world.enable_contacts_with(self.shape);
+    }
+
+    fn drop_through_impl(&self, world: &mut World, body: BodyId, min_steps: u32) {
+        for shape in crate::body::body_shapes_impl(body) {
+            if shape != self.shape {
+                world.disable_contact(
+                    shape,
+                    self.shape,
+                    crate::world::ContactDisable::FramesThenSeparation(min_steps),
+                );
+            }
+        }
+    }
+}
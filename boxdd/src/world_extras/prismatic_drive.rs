@@ -0,0 +1,99 @@
+use super::*;
+
+/// Distance (meters) within which a prismatic helper counts as arrived at its target.
+pub(super) const SLIDER_ARRIVE_TOLERANCE: f32 = 0.005;
+/// Proportional gain (1/s) used to slow down when approaching the target.
+pub(super) const SLIDER_APPROACH_GAIN: f32 = 5.0;
+
+/// Motorized prismatic joint that drives its translation toward a target; shared by [`Door`]
+/// and [`Elevator`].
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub(super) struct PrismaticDrive {
+    pub(super) joint: JointId,
+    pub(super) target: f32,
+    pub(super) speed: f32,
+}
+
+impl PrismaticDrive {
+    pub(super) fn def(
+        world: &World,
+        ground: BodyId,
+        body: BodyId,
+        axis: Vec2,
+        range: (f32, f32),
+        max_force: f32,
+    ) -> crate::joints::PrismaticJointDef {
+        let anchor = world.body_position(body);
+        let axis_angle = axis.y.atan2(axis.x);
+        let angle_a = axis_angle - world.body_transform(ground).rotation().angle();
+        let angle_b = axis_angle - world.body_transform(body).rotation().angle();
+        let base = crate::joints::JointBaseBuilder::new()
+            .bodies_by_id(ground, body)
+            .local_frames(
+                world.body_local_point(ground, anchor),
+                angle_a,
+                world.body_local_point(body, anchor),
+                angle_b,
+            )
+            .build();
+        crate::joints::PrismaticJointDef::new(base)
+            .enable_limit(true)
+            .lower_translation(range.0)
+            .upper_translation(range.1)
+            .enable_motor(true)
+            .max_motor_force(max_force)
+            .motor_speed(0.0)
+    }
+
+    pub(super) fn motor_speed(&self, translation: f32) -> f32 {
+        let error = self.target - translation;
+        if error.abs() <= SLIDER_ARRIVE_TOLERANCE {
+            0.0
+        } else {
+            (error * SLIDER_APPROACH_GAIN).clamp(-self.speed, self.speed)
+        }
+    }
+
+    pub(super) fn arrived(&self, world: &World) -> bool {
+        (self.target - world.prismatic_translation(self.joint)).abs() <= SLIDER_ARRIVE_TOLERANCE
+    }
+
+    pub(super) fn is_moving(&self, world: &World) -> bool {
+        !self.arrived(world) || world.prismatic_speed(self.joint).abs() > SLIDER_ARRIVE_TOLERANCE
+    }
+
+    pub(super) fn set_target(&mut self, world: &mut World, target: f32) {
+        self.target = target;
+        world.joint_wake_bodies(self.joint);
+    }
+
+    pub(super) fn update(&self, world: &mut World) {
+        let translation = world.prismatic_translation(self.joint);
+        world.prismatic_set_motor_speed(self.joint, self.motor_speed(translation));
+    }
+
+    pub(super) fn try_update(&self, world: &mut World) -> ApiResult<()> {
+        let translation = world.try_prismatic_translation(self.joint)?;
+        world.try_prismatic_set_motor_speed(self.joint, self.motor_speed(translation))
+    }
+}
+
+#[inline]
+pub(super) fn slider_args_valid(axis: Vec2, speed: f32, max_force: f32) -> bool {
+    axis.x.is_finite()
+        && axis.y.is_finite()
+        && (axis.x != 0.0 || axis.y != 0.0)
+        && speed.is_finite()
+        && speed > 0.0
+        && max_force.is_finite()
+        && max_force >= 0.0
+}
+
+#[track_caller]
+pub(super) fn assert_slider_args(axis: Vec2, speed: f32, max_force: f32) {
+    assert!(
+        slider_args_valid(axis, speed, max_force),
+        "axis must be finite and non-zero, speed finite and > 0.0, max_force finite and >= 0.0; \
+         got axis={axis:?}, speed={speed}, max_force={max_force}"
+    );
+}
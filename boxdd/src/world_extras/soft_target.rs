@@ -0,0 +1,170 @@
+use super::*;
+
+/// Spring that drags a body toward a target point with forces only, for mouse dragging that
+/// feels softer than a rigid joint.
+///
+/// The spring acts on a grab point fixed in the body and is mass-normalized: `hertz` and
+/// `damping_ratio` behave the same for light and heavy bodies (a ratio of `1.0` is critically
+/// damped). The force is clamped to `max_force` (N), so heavy or blocked bodies simply lag behind
+/// the target. Call [`Self::update`] once per step before [`World::step`]; the frequency is
+/// capped at `0.5 / dt` to keep the explicit spring stable.
+///
+/// ```no_run
+/// use boxdd::{BodyBuilder, BodyType, SoftTarget, World, WorldDef};
+/// let mut world = World::new(WorldDef::default()).unwrap();
+/// let body = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+/// let mut drag = SoftTarget::new(&world, body, [0.0, 0.0], 500.0).hertz(4.0);
+/// drag.set_target([3.0, 2.0]);
+/// for _ in 0..60 {
+///     drag.update(&mut world, 1.0 / 60.0);
+///     world.step(1.0 / 60.0, 4);
+/// }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct SoftTarget {
+    body: BodyId,
+    local_anchor: Vec2,
+    target: Vec2,
+    hertz: f32,
+    damping_ratio: f32,
+    max_force: f32,
+}
+
+impl SoftTarget {
+    /// Grab `body` at `grab_point` (world space) with the target starting there. Defaults to
+    /// 5 Hz, critically damped.
+    pub fn new<V: Into<Vec2>>(world: &World, body: BodyId, grab_point: V, max_force: f32) -> Self {
+        crate::core::debug_checks::assert_body_valid(body);
+        let grab_point = grab_point.into();
+        assert!(grab_point.is_valid(), "grab_point must be finite");
+        assert!(
+            max_force.is_finite() && max_force >= 0.0,
+            "max_force must be finite and >= 0.0, got {max_force}"
+        );
+        Self::new_unchecked(world, body, grab_point, max_force)
+    }
+
+    /// Recoverable version of [`Self::new`].
+    pub fn try_new<V: Into<Vec2>>(
+        world: &World,
+        body: BodyId,
+        grab_point: V,
+        max_force: f32,
+    ) -> ApiResult<Self> {
+        crate::core::debug_checks::check_body_valid(body)?;
+        let grab_point = grab_point.into();
+        if !(grab_point.is_valid() && max_force.is_finite() && max_force >= 0.0) {
+            return Err(ApiError::InvalidArgument);
+        }
+        Ok(Self::new_unchecked(world, body, grab_point, max_force))
+    }
+
+    fn new_unchecked(world: &World, body: BodyId, grab_point: Vec2, max_force: f32) -> Self {
+        Self {
+            body,
+            local_anchor: world.body_local_point(body, grab_point),
+            target: grab_point,
+            hertz: 5.0,
+            damping_ratio: 1.0,
+            max_force,
+        }
+    }
+
+    /// Spring frequency in Hz. Panics unless finite and >= 0.
+    pub fn hertz(mut self, hertz: f32) -> Self {
+        assert!(
+            hertz.is_finite() && hertz >= 0.0,
+            "hertz must be finite and >= 0.0, got {hertz}"
+        );
+        self.hertz = hertz;
+        self
+    }
+
+    /// Damping ratio; `1.0` is critical damping. Panics unless finite and >= 0.
+    pub fn damping_ratio(mut self, damping_ratio: f32) -> Self {
+        assert!(
+            damping_ratio.is_finite() && damping_ratio >= 0.0,
+            "damping_ratio must be finite and >= 0.0, got {damping_ratio}"
+        );
+        self.damping_ratio = damping_ratio;
+        self
+    }
+
+    pub fn body(&self) -> BodyId {
+        self.body
+    }
+
+    /// Grab point in the body's local frame.
+    pub fn local_anchor(&self) -> Vec2 {
+        self.local_anchor
+    }
+
+    pub fn target(&self) -> Vec2 {
+        self.target
+    }
+
+    /// Move the point the body is pulled toward. Panics if `target` is not finite.
+    pub fn set_target<V: Into<Vec2>>(&mut self, target: V) {
+        let target = target.into();
+        assert!(target.is_valid(), "target must be finite");
+        self.target = target;
+    }
+
+    pub fn max_force(&self) -> f32 {
+        self.max_force
+    }
+
+    /// Change the force clamp (N). Panics unless finite and >= 0.
+    pub fn set_max_force(&mut self, max_force: f32) {
+        assert!(
+            max_force.is_finite() && max_force >= 0.0,
+            "max_force must be finite and >= 0.0, got {max_force}"
+        );
+        self.max_force = max_force;
+    }
+
+    /// Apply this step's spring force at the grab point. Does nothing for bodies without mass.
+    pub fn update(&self, world: &mut World, dt: f32) {
+        crate::core::callback_state::assert_not_in_callback();
+        crate::core::debug_checks::assert_body_valid(self.body);
+        assert!(
+            dt.is_finite() && dt > 0.0,
+            "dt must be finite and > 0.0, got {dt}"
+        );
+        if let Some((force, point)) = self.spring_force(world, dt) {
+            world.body_apply_force(self.body, force, point, true);
+        }
+    }
+
+    pub fn try_update(&self, world: &mut World, dt: f32) -> ApiResult<()> {
+        crate::core::callback_state::check_not_in_callback()?;
+        crate::core::debug_checks::check_body_valid(self.body)?;
+        if !(dt.is_finite() && dt > 0.0) {
+            return Err(ApiError::InvalidArgument);
+        }
+        if let Some((force, point)) = self.spring_force(world, dt) {
+            world.try_body_apply_force(self.body, force, point, true)?;
+        }
+        Ok(())
+    }
+
+    fn spring_force(&self, world: &World, dt: f32) -> Option<(Vec2, Vec2)> {
+        let mass = world.body_mass(self.body);
+        if mass <= 0.0 {
+            return None;
+        }
+        let omega = (core::f32::consts::TAU * self.hertz).min(0.5 / dt);
+        let point = world.body_world_point(self.body, self.local_anchor);
+        let velocity = world.body_world_point_velocity(self.body, point);
+        let stretch = self.target.minus(point);
+        let accel = stretch
+            .scaled(omega * omega)
+            .minus(velocity.scaled(2.0 * self.damping_ratio * omega));
+        let mut force = accel.scaled(mass);
+        let magnitude = force.length();
+        if magnitude > self.max_force {
+            force = force.scaled(self.max_force / magnitude);
+        }
+        Some((force, point))
+    }
+}
@@ -0,0 +1,338 @@
+use super::*;
+
+/// Layout and tuning for [`Tracks`].
+///
+/// Wheel offsets are in the chassis' local frame; the suspension axis too, pointing from the
+/// wheels up into the chassis.
+#[derive(Clone, Debug)]
+pub struct TracksDef {
+    wheel_offsets: Vec<Vec2>,
+    wheel_radius: f32,
+    wheel_shape: crate::shapes::ShapeDef,
+    suspension_axis: Vec2,
+    suspension_hertz: f32,
+    suspension_damping_ratio: f32,
+    max_motor_torque: f32,
+}
+
+impl TracksDef {
+    /// Wheels of `wheel_radius` at `wheel_offsets`, with density 1 shapes, a 5 Hz suspension
+    /// damped at 0.7 along local `+y`, and 50 N·m of motor torque per wheel.
+    pub fn new<I, V>(wheel_offsets: I, wheel_radius: f32) -> Self
+    where
+        I: IntoIterator<Item = V>,
+        V: Into<Vec2>,
+    {
+        Self {
+            wheel_offsets: wheel_offsets.into_iter().map(Into::into).collect(),
+            wheel_radius,
+            wheel_shape: crate::shapes::ShapeDef::builder().density(1.0).build(),
+            suspension_axis: Vec2::new(0.0, 1.0),
+            suspension_hertz: 5.0,
+            suspension_damping_ratio: 0.7,
+            max_motor_torque: 50.0,
+        }
+    }
+
+    /// Shape definition used for every wheel (density, friction, filter, ...).
+    pub fn wheel_shape(mut self, def: crate::shapes::ShapeDef) -> Self {
+        self.wheel_shape = def;
+        self
+    }
+
+    /// Suspension direction in the chassis frame.
+    pub fn suspension_axis<V: Into<Vec2>>(mut self, axis: V) -> Self {
+        self.suspension_axis = axis.into();
+        self
+    }
+
+    /// Suspension spring stiffness and damping ratio.
+    pub fn suspension(mut self, hertz: f32, damping_ratio: f32) -> Self {
+        self.suspension_hertz = hertz;
+        self.suspension_damping_ratio = damping_ratio;
+        self
+    }
+
+    /// Motor torque limit of each wheel (N·m).
+    pub fn max_motor_torque(mut self, torque: f32) -> Self {
+        self.max_motor_torque = torque;
+        self
+    }
+
+    fn is_valid(&self) -> bool {
+        let axis = self.suspension_axis;
+        !self.wheel_offsets.is_empty()
+            && self.wheel_offsets.iter().all(|o| o.is_valid())
+            && self.wheel_radius.is_finite()
+            && self.wheel_radius > 0.0
+            && axis.is_valid()
+            && (axis.x != 0.0 || axis.y != 0.0)
+            && self.suspension_hertz.is_finite()
+            && self.suspension_hertz >= 0.0
+            && self.suspension_damping_ratio.is_finite()
+            && self.suspension_damping_ratio >= 0.0
+            && self.max_motor_torque.is_finite()
+            && self.max_motor_torque >= 0.0
+    }
+
+    fn wheel_joint_def(
+        &self,
+        world: &World,
+        chassis: BodyId,
+        wheel: BodyId,
+    ) -> crate::WheelJointDef {
+        let xf = world.body_transform(chassis);
+        let anchor = world.body_position(wheel);
+        let axis = xf.rotation().rotate_vec(self.suspension_axis);
+        let base = world.joint_base_from_world_with_axis(chassis, wheel, anchor, anchor, axis);
+        crate::WheelJointDef::new(base)
+            .enable_spring(true)
+            .hertz(self.suspension_hertz)
+            .damping_ratio(self.suspension_damping_ratio)
+            .enable_motor(true)
+            .max_motor_torque(self.max_motor_torque)
+            .motor_speed(0.0)
+    }
+}
+
+/// Tracked running gear: a row of wheels on sprung wheel joints under one chassis, all driven by
+/// the same motor command, for tanks, excavators, and other vehicles with more than two wheels.
+///
+/// The wheels are created as dynamic bodies at the chassis' current pose. [`Self::tread`] wraps
+/// a closed polyline around them for drawing a track belt.
+///
+/// ```no_run
+/// use boxdd::{BodyBuilder, BodyType, Tracks, TracksDef, World, WorldDef, shapes};
+/// let mut world = World::new(WorldDef::default()).unwrap();
+/// let hull = world.create_body_id(
+///     BodyBuilder::new().body_type(BodyType::Dynamic).position([0.0, 2.0]).build(),
+/// );
+/// let _ = world.create_shape_simple(hull, shapes::box_polygon(2.0, 0.4));
+/// let def = TracksDef::new([[-1.5, -0.6], [-0.5, -0.7], [0.5, -0.7], [1.5, -0.6]], 0.35)
+///     .max_motor_torque(200.0);
+/// let tracks = Tracks::new(&mut world, hull, &def);
+/// tracks.set_motor_speed(&mut world, -6.0);
+/// world.step(1.0 / 60.0, 4);
+/// let belt = tracks.tread(&world, 6);
+/// # let _ = belt;
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Tracks {
+    chassis: BodyId,
+    wheels: Vec<BodyId>,
+    joints: Vec<JointId>,
+    wheel_radius: f32,
+}
+
+impl Tracks {
+    /// Create the wheels and their joints. Panics if `chassis` is invalid or `def` has no wheels,
+    /// a non-positive radius, or non-finite / negative tuning.
+    pub fn new(world: &mut World, chassis: BodyId, def: &TracksDef) -> Self {
+        crate::core::debug_checks::assert_body_valid(chassis);
+        assert!(def.is_valid(), "invalid tracks definition: {def:?}");
+        let xf = world.body_transform(chassis);
+        let circle = crate::shapes::circle([0.0_f32, 0.0], def.wheel_radius);
+        let mut wheels = Vec::with_capacity(def.wheel_offsets.len());
+        let mut joints = Vec::with_capacity(def.wheel_offsets.len());
+        for &offset in &def.wheel_offsets {
+            let wheel = world.create_body_id(wheel_body(xf, offset));
+            world.create_circle_shape_for(wheel, &def.wheel_shape, &circle);
+            joints.push(world.create_wheel_joint_id(&def.wheel_joint_def(world, chassis, wheel)));
+            wheels.push(wheel);
+        }
+        Self {
+            chassis,
+            wheels,
+            joints,
+            wheel_radius: def.wheel_radius,
+        }
+    }
+
+    /// Recoverable version of [`Self::new`]. Wheels created before a failure are destroyed.
+    pub fn try_new(world: &mut World, chassis: BodyId, def: &TracksDef) -> ApiResult<Self> {
+        crate::core::debug_checks::check_body_valid(chassis)?;
+        if !def.is_valid() {
+            return Err(ApiError::InvalidArgument);
+        }
+        let xf = world.try_body_transform(chassis)?;
+        let circle = crate::shapes::circle([0.0_f32, 0.0], def.wheel_radius);
+        let mut tracks = Self {
+            chassis,
+            wheels: Vec::with_capacity(def.wheel_offsets.len()),
+            joints: Vec::with_capacity(def.wheel_offsets.len()),
+            wheel_radius: def.wheel_radius,
+        };
+        for &offset in &def.wheel_offsets {
+            let created = world
+                .try_create_body_id(wheel_body(xf, offset))
+                .and_then(|wheel| {
+                    tracks.wheels.push(wheel);
+                    world.try_create_circle_shape_for(wheel, &def.wheel_shape, &circle)?;
+                    let joint_def = def.wheel_joint_def(world, chassis, wheel);
+                    world.try_create_wheel_joint_id(&joint_def)
+                });
+            match created {
+                Ok(joint) => tracks.joints.push(joint),
+                Err(e) => {
+                    for &wheel in &tracks.wheels {
+                        let _ = world.try_destroy_body_id(wheel);
+                    }
+                    return Err(e);
+                }
+            }
+        }
+        Ok(tracks)
+    }
+
+    pub fn chassis(&self) -> BodyId {
+        self.chassis
+    }
+
+    /// Wheel bodies in the order of [`TracksDef`]'s offsets.
+    pub fn wheels(&self) -> &[BodyId] {
+        &self.wheels
+    }
+
+    /// Wheel joints, one per wheel.
+    pub fn joints(&self) -> &[JointId] {
+        &self.joints
+    }
+
+    pub fn wheel_radius(&self) -> f32 {
+        self.wheel_radius
+    }
+
+    /// Drive every wheel at `speed` rad/s (positive spins counter-clockwise) and wake the vehicle.
+    pub fn set_motor_speed(&self, world: &mut World, speed: f32) {
+        for &joint in &self.joints {
+            world.wheel_set_motor_speed(joint, speed);
+            world.joint_wake_bodies(joint);
+        }
+    }
+
+    pub fn try_set_motor_speed(&self, world: &mut World, speed: f32) -> ApiResult<()> {
+        for &joint in &self.joints {
+            world.try_wheel_set_motor_speed(joint, speed)?;
+            world.try_joint_wake_bodies(joint)?;
+        }
+        Ok(())
+    }
+
+    /// Set every wheel's motor torque limit (N·m); zero lets the tracks roll freely.
+    pub fn set_max_motor_torque(&self, world: &mut World, torque: f32) {
+        for &joint in &self.joints {
+            world.wheel_set_max_motor_torque(joint, torque);
+        }
+    }
+
+    pub fn try_set_max_motor_torque(&self, world: &mut World, torque: f32) -> ApiResult<()> {
+        for &joint in &self.joints {
+            world.try_wheel_set_max_motor_torque(joint, torque)?;
+        }
+        Ok(())
+    }
+
+    /// Closed, counter-clockwise polyline hugging the wheels' current positions: straight runs
+    /// along the convex hull of the wheel centers joined by arcs of `arc_segments` steps around
+    /// the outer wheels. The first point is not repeated at the end.
+    ///
+    /// The hull comes from [`crate::shapes::compute_hull`], so it is limited to 8 input wheels
+    /// unless the `polygon-simplify` feature is enabled or the wheels sit in one straight row;
+    /// panics otherwise.
+    pub fn tread(&self, world: &World, arc_segments: usize) -> Vec<Vec2> {
+        let centers: Vec<Vec2> = self
+            .wheels
+            .iter()
+            .map(|&wheel| world.body_position(wheel))
+            .collect();
+        tread_polyline(&centers, self.wheel_radius, arc_segments.max(1))
+            .unwrap_or_else(|e| panic!("cannot wrap a tread around {} wheels: {e}", centers.len()))
+    }
+
+    pub fn try_tread(&self, world: &World, arc_segments: usize) -> ApiResult<Vec<Vec2>> {
+        let centers = self
+            .wheels
+            .iter()
+            .map(|&wheel| world.try_body_position(wheel))
+            .collect::<ApiResult<Vec<Vec2>>>()?;
+        tread_polyline(&centers, self.wheel_radius, arc_segments.max(1))
+    }
+}
+
+fn wheel_body(chassis: Transform, offset: Vec2) -> crate::BodyDef {
+    crate::BodyBuilder::new()
+        .body_type(BodyType::Dynamic)
+        .position(chassis.transform_point(offset))
+        .build()
+}
+
+/// Corners of the tread outline, counter-clockwise: the two end wheels when the centers lie on
+/// one line (within linear slop), otherwise Box2D's convex hull of the centers.
+fn tread_corners(centers: &[Vec2]) -> ApiResult<Vec<Vec2>> {
+    let Some(&first) = centers.first() else {
+        return Ok(Vec::new());
+    };
+    let farthest = |from: Vec2| {
+        centers
+            .iter()
+            .copied()
+            .max_by(|a, b| {
+                a.minus(from)
+                    .length_squared()
+                    .total_cmp(&b.minus(from).length_squared())
+            })
+            .unwrap_or(from)
+    };
+    let a = farthest(first);
+    let b = farthest(a);
+    let slop = crate::tuning::linear_slop();
+    let ab = b.minus(a);
+    let len = ab.length();
+    if len <= slop {
+        return Ok(vec![a]);
+    }
+    let off_line = |p: Vec2| (ab.x * (p.y - a.y) - ab.y * (p.x - a.x)).abs() / len > slop;
+    if !centers.iter().any(|&p| off_line(p)) {
+        return Ok(vec![a, b]);
+    }
+    Ok(crate::shapes::try_compute_hull(centers.iter().copied())?
+        .points()
+        .to_vec())
+}
+
+fn tread_polyline(centers: &[Vec2], radius: f32, arc_segments: usize) -> ApiResult<Vec<Vec2>> {
+    let hull = tread_corners(centers)?;
+    let around = |c: Vec2, angle: f32| {
+        let (s, co) = angle.sin_cos();
+        Vec2::new(c.x + radius * co, c.y + radius * s)
+    };
+    Ok(match hull.len() {
+        0 => Vec::new(),
+        1 => {
+            let steps = 4 * arc_segments;
+            (0..steps)
+                .map(|i| around(hull[0], core::f32::consts::TAU * i as f32 / steps as f32))
+                .collect()
+        }
+        n => {
+            // Outward normal angle of the edge leaving each hull vertex.
+            let edge_angle = |i: usize| {
+                let (a, b) = (hull[i], hull[(i + 1) % n]);
+                (-(b.x - a.x)).atan2(b.y - a.y)
+            };
+            let mut out = Vec::with_capacity(n * (arc_segments + 1));
+            for (i, &center) in hull.iter().enumerate() {
+                let from = edge_angle((i + n - 1) % n);
+                let mut sweep = edge_angle(i) - from;
+                if sweep < 0.0 {
+                    sweep += core::f32::consts::TAU;
+                }
+                for step in 0..=arc_segments {
+                    let t = step as f32 / arc_segments as f32;
+                    out.push(around(center, from + sweep * t));
+                }
+            }
+            out
+        }
+    })
+}
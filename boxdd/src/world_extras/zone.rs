@@ -0,0 +1,160 @@
+use super::*;
+
+/// Sensor overlap tracking that also reports static and kinematic visitors.
+///
+/// Box2D sensors only detect shapes on dynamic bodies (and kinematic ones if the sensor itself
+/// is on a moving body). `Zone` fills the gap with an explicit overlap query against the sensor
+/// shape: call [`Zone::update`] once after each step and it returns begin/end events for
+/// visitors on the body types enabled with [`Zone::include_static`] and
+/// [`Zone::include_kinematic`]. Dynamic visitors keep coming from [`World::sensor_events`] unless
+/// [`Zone::include_dynamic`] is set, which is useful when the overlaps are needed before a step
+/// rather than after it.
+///
+/// Like Box2D's sensors, the sensor shape's filter must accept the visitor (group index first,
+/// then category and mask, as in [`Filter::should_collide`]), visitor shapes must have sensor
+/// events enabled, and shapes on the sensor's own body are ignored.
+///
+/// ```no_run
+/// use boxdd::{BodyBuilder, ShapeDef, World, WorldDef, Zone, shapes};
+/// let mut world = World::new(WorldDef::default()).unwrap();
+/// let body = world.create_body_id(BodyBuilder::new().build());
+/// let sensor = world.create_polygon_shape_for(
+///     body,
+///     &ShapeDef::builder().sensor(true).build(),
+///     &shapes::box_polygon(1.0, 1.0),
+/// );
+/// let mut zone = Zone::new(sensor).include_static(true);
+/// world.step(1.0 / 60.0, 4);
+/// for begin in zone.update(&world).begin {
+///     let _ = begin.visitor_shape;
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Zone {
+    sensor: ShapeId,
+    include_static: bool,
+    include_kinematic: bool,
+    include_dynamic: bool,
+    visitors: Vec<ShapeId>,
+}
+
+impl Zone {
+    /// Track `sensor`; no body types are included until enabled.
+    pub fn new(sensor: ShapeId) -> Self {
+        Self {
+            sensor,
+            include_static: false,
+            include_kinematic: false,
+            include_dynamic: false,
+            visitors: Vec::new(),
+        }
+    }
+
+    /// Report visitors on static bodies.
+    pub fn include_static(mut self, flag: bool) -> Self {
+        self.include_static = flag;
+        self
+    }
+
+    /// Report visitors on kinematic bodies.
+    pub fn include_kinematic(mut self, flag: bool) -> Self {
+        self.include_kinematic = flag;
+        self
+    }
+
+    /// Report visitors on dynamic bodies too, from the same overlap query.
+    pub fn include_dynamic(mut self, flag: bool) -> Self {
+        self.include_dynamic = flag;
+        self
+    }
+
+    /// The tracked sensor shape.
+    pub fn sensor_shape(&self) -> ShapeId {
+        self.sensor
+    }
+
+    /// Visitors found by the last [`Zone::update`].
+    pub fn visitors(&self) -> &[ShapeId] {
+        &self.visitors
+    }
+
+    /// Re-run the overlap query and return visitors that started or stopped overlapping since the
+    /// previous update. Visitors that were destroyed are reported as ended with `visitor_body`
+    /// set to `None`.
+    pub fn update(&mut self, world: &World) -> SensorEvents {
+        crate::core::callback_state::assert_not_in_callback();
+        crate::core::debug_checks::assert_shape_valid(self.sensor);
+        self.update_impl(world)
+    }
+
+    pub fn try_update(&mut self, world: &World) -> ApiResult<SensorEvents> {
+        crate::core::callback_state::check_not_in_callback()?;
+        crate::core::debug_checks::check_shape_valid(self.sensor)?;
+        Ok(self.update_impl(world))
+    }
+
+    pub(super) fn update_impl(&mut self, world: &World) -> SensorEvents {
+        let sensor_body = crate::shapes::shape_body_id_impl(self.sensor);
+        let mut current = Vec::new();
+        if self.include_static || self.include_kinematic || self.include_dynamic {
+            let proxy = crate::shapes::shape_local_proxy_impl(self.sensor);
+            let xf = crate::body::body_transform_impl(sensor_body);
+            let filter = crate::shapes::shape_filter_impl(self.sensor);
+            // A positive group admits members regardless of category/mask, so only prefilter
+            // by bits when the group cannot override them.
+            let query = if filter.group_index > 0 {
+                QueryFilter::default().category(u64::MAX).mask(u64::MAX)
+            } else {
+                QueryFilter::default()
+                    .category(filter.category_bits)
+                    .mask(filter.mask_bits)
+            };
+            for shape in world.overlap_polygon_points_with_offset(
+                proxy.points().iter().copied(),
+                proxy.radius(),
+                xf.position(),
+                xf.rotation().angle(),
+                query,
+            ) {
+                let body = crate::shapes::shape_body_id_impl(shape);
+                if body == sensor_body
+                    || crate::shapes::shape_is_sensor_impl(shape)
+                    || !world.shape_sensor_events_enabled(shape)
+                    || !filter.should_collide(&crate::shapes::shape_filter_impl(shape))
+                {
+                    continue;
+                }
+                let included = match crate::body::body_type_impl(body) {
+                    BodyType::Static => self.include_static,
+                    BodyType::Kinematic => self.include_kinematic,
+                    BodyType::Dynamic => self.include_dynamic,
+                };
+                if included {
+                    current.push(shape);
+                }
+            }
+        }
+
+        let mut events = SensorEvents::default();
+        for &shape in current.iter().filter(|s| !self.visitors.contains(s)) {
+            events.begin.push(SensorBeginTouchEvent {
+                sensor_shape: self.sensor,
+                visitor_shape: shape,
+                sensor_body: Some(sensor_body),
+                visitor_body: Some(crate::shapes::shape_body_id_impl(shape)),
+            });
+        }
+        for &shape in self.visitors.iter().filter(|s| !current.contains(s)) {
+            let visitor_body = crate::shapes::shape_is_valid_impl(shape)
+                .then(|| crate::shapes::shape_body_id_impl(shape));
+            events.end.push(SensorEndTouchEvent {
+                sensor_shape: self.sensor,
+                visitor_shape: shape,
+                sensor_body: Some(sensor_body),
+                visitor_body,
+            });
+        }
+        self.visitors = current;
+        events
+    }
+}
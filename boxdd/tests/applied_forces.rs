@@ -0,0 +1,59 @@
+#![cfg(feature = "force-tracking")]
+
+use boxdd::prelude::*;
+
+#[test]
+fn applied_forces_report_last_step_per_body() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, 0.0]).build()).unwrap();
+    let body = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([1.0_f32, 0.0])
+            .build(),
+    );
+    world.create_shape_simple(body, boxdd::shapes::box_polygon(0.5, 0.5));
+    let idle = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+
+    // Two controllers pushing against each other, one of them off-center.
+    world
+        .body(body)
+        .unwrap()
+        .apply_force_to_center([10.0, 0.0], true);
+    world
+        .body(body)
+        .unwrap()
+        .apply_force([-10.0, 0.0], [1.0, 1.0], true);
+    world.body_apply_angular_impulse(body, 0.5, true);
+    assert_eq!(world.applied_forces(body), boxdd::AppliedForces::default());
+
+    world.step(1.0 / 60.0, 4);
+    let applied = world.applied_forces(body);
+    assert_eq!(applied.calls, 3);
+    assert_eq!(applied.force, Vec2::ZERO);
+    assert!((applied.torque - 10.0).abs() < 1.0e-4);
+    assert_eq!(applied.angular_impulse, 0.5);
+    assert_eq!(world.applied_forces(idle).calls, 0);
+    assert_eq!(world.all_applied_forces().len(), 1);
+
+    world.step(1.0 / 60.0, 4);
+    assert_eq!(world.applied_forces(body).calls, 0);
+
+    world.destroy_body_id(idle);
+    assert!(world.try_applied_forces(idle).is_err());
+}
+
+#[test]
+fn world_level_apply_force_is_tracked() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, 0.0]).build()).unwrap();
+    let body = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+    world.create_shape_simple(body, boxdd::shapes::box_polygon(0.5, 0.5));
+
+    world.body_apply_force(body, [3.0, 0.0], [0.0, 0.0], true);
+    world
+        .try_body_apply_force(body, [0.0, 2.0], [0.0, 0.0], true)
+        .unwrap();
+    world.step(1.0 / 60.0, 4);
+    let applied = world.applied_forces(body);
+    assert_eq!(applied.calls, 2);
+    assert_eq!(applied.force, Vec2::new(3.0, 2.0));
+}
@@ -360,6 +360,41 @@ fn body_aabb_helpers_match_owned_scoped_and_world_views() {
     assert_eq!(world.try_body_aabb(body_id).unwrap(), expected);
 }
 
+#[test]
+fn body_wrappers_cover_motion_locks_and_wake_touching() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let mut owned_body = world.create_body_owned(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([0.0_f32, 1.0])
+            .build(),
+    );
+    let body_id = owned_body.id();
+    let locks = MotionLocks::new(true, false, true);
+
+    owned_body.set_motion_locks(locks);
+    assert_eq!(owned_body.motion_locks(), locks);
+    assert_eq!(world.body_motion_locks(body_id), locks);
+    owned_body.wake_touching();
+    owned_body.try_wake_touching().unwrap();
+
+    {
+        let mut body = world.body(body_id).expect("body should still be valid");
+        body.try_set_motion_locks(MotionLocks::default()).unwrap();
+        assert_eq!(body.try_motion_locks().unwrap(), MotionLocks::default());
+        body.try_set_position_and_rotation([2.0_f32, 3.0], 0.5)
+            .unwrap();
+        body.wake_touching();
+    }
+
+    let p = world.body_position(body_id);
+    assert!(approx_eq(p.x, 2.0, 1e-6) && approx_eq(p.y, 3.0, 1e-6));
+    assert_eq!(
+        owned_body.try_motion_locks().unwrap(),
+        MotionLocks::default()
+    );
+}
+
 #[test]
 fn world_handle_body_runtime_queries_match_world_queries() {
     let mut world = World::new(WorldDef::default()).unwrap();
@@ -601,3 +636,172 @@ fn world_handle_body_runtime_queries_match_world_queries() {
         Some("handle-body")
     );
 }
+
+#[test]
+fn create_body_with_shapes_builds_all_shapes_and_mass_once() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let def = ShapeDef::builder().density(1.0).build();
+    let (body, ids) = world.create_body_with_shapes(
+        BodyBuilder::new().body_type(BodyType::Dynamic).build(),
+        &[
+            (def.clone(), shapes::box_polygon(1.0, 0.5).into()),
+            (def.clone(), shapes::circle([2.0, 0.0], 0.5).into()),
+            (def, shapes::segment([0.0, 0.0], [1.0, 0.0]).into()),
+        ],
+    );
+    assert_eq!(ids.len(), 3);
+    let listed = world.body_shapes(body);
+    assert!(listed.len() == 3 && ids.iter().all(|id| listed.contains(id)));
+    let expected = 2.0 + std::f32::consts::PI * 0.25;
+    assert!((world.body_mass(body) - expected).abs() < 1.0e-3);
+
+    // Nothing is created when any input is invalid.
+    let bodies_before = world.counters().body_count;
+    let bad = ShapeDef::builder().density(-1.0).build();
+    assert!(matches!(
+        world.try_create_body_with_shapes_owned(
+            BodyBuilder::new().build(),
+            &[(bad, shapes::circle([0.0, 0.0], 1.0).into())],
+        ),
+        Err(ApiError::InvalidArgument)
+    ));
+    assert_eq!(world.counters().body_count, bodies_before);
+
+    let (owned, owned_ids) = world
+        .try_create_body_with_shapes_owned(
+            BodyBuilder::new().build(),
+            &[(ShapeDef::default(), shapes::circle([0.0, 0.0], 1.0).into())],
+        )
+        .unwrap();
+    assert_eq!(owned_ids.len(), 1);
+    drop(owned);
+    assert_eq!(world.counters().body_count, bodies_before);
+}
+
+#[test]
+fn destroy_body_take_returns_state_before_destruction() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let body = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([3.0, 4.0])
+            .linear_velocity([1.0, -2.0])
+            .angular_velocity(0.5)
+            .build(),
+    );
+    let _ =
+        world.create_circle_shape_for(body, &ShapeDef::default(), &shapes::circle([0.0, 0.0], 0.5));
+
+    let state = world.destroy_body_take(body);
+    assert_eq!(state.body_type, BodyType::Dynamic);
+    assert_eq!(state.transform.position(), Vec2::new(3.0, 4.0));
+    assert_eq!(state.linear_velocity, Vec2::new(1.0, -2.0));
+    assert_eq!(state.angular_velocity, 0.5);
+    assert_eq!(
+        world.try_destroy_body_take(body).unwrap_err(),
+        ApiError::InvalidBodyId
+    );
+}
+
+#[test]
+fn set_body_target_transform_reaches_pose_after_one_step() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0, 0.0]).build()).unwrap();
+    let body = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Kinematic)
+            .position([1.0, 2.0])
+            .build(),
+    );
+    let dt = 1.0 / 60.0;
+    world.set_body_target_transform(body, Transform::from_pos_angle([1.5, 1.0], 0.25), dt, true);
+    let v = world.body_linear_velocity(body);
+    assert!(approx_eq(v.x, 0.5 / dt, 1e-2) && approx_eq(v.y, -1.0 / dt, 1e-2));
+    assert!(approx_eq(
+        world.body_angular_velocity(body),
+        0.25 / dt,
+        1e-2
+    ));
+
+    world.step(dt, 4);
+    let p = world.body_position(body);
+    assert!(approx_eq(p.x, 1.5, 1e-4) && approx_eq(p.y, 1.0, 1e-4));
+    assert!(approx_eq(
+        world.body_transform(body).rotation().angle(),
+        0.25,
+        1e-4
+    ));
+
+    assert_eq!(
+        world.try_set_body_target_transform(body, Transform::IDENTITY, 0.0, true),
+        Err(ApiError::InvalidArgument)
+    );
+}
+
+#[test]
+fn soft_target_pulls_body_to_target_within_force_clamp() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, 0.0]).build()).unwrap();
+    let sdef = ShapeDef::builder().density(1.0).build();
+    let body = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+    let _shape = world.create_polygon_shape_for(body, &sdef, &shapes::box_polygon(0.5, 0.5));
+    let pinned = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([0.0_f32, 5.0])
+            .build(),
+    );
+    let _pinned_shape =
+        world.create_polygon_shape_for(pinned, &sdef, &shapes::box_polygon(0.5, 0.5));
+
+    let mut drag = SoftTarget::new(&world, body, [0.0_f32, 0.0], 1000.0).hertz(3.0);
+    drag.set_target([3.0_f32, 0.0]);
+    let mut stuck = SoftTarget::try_new(&world, pinned, [0.0_f32, 5.0], 0.0).unwrap();
+    stuck.set_target([3.0_f32, 5.0]);
+    for _ in 0..180 {
+        drag.update(&mut world, 1.0 / 60.0);
+        stuck.try_update(&mut world, 1.0 / 60.0).unwrap();
+        world.step(1.0 / 60.0, 4);
+    }
+
+    let p = world.body_position(body);
+    assert!((p.x - 3.0).abs() < 0.05, "{p:?}");
+    assert!(world.body_linear_velocity(body).x.abs() < 0.1);
+    assert_eq!(world.body_position(pinned), Vec2::new(0.0, 5.0));
+    assert_eq!(
+        stuck.try_update(&mut world, 0.0).unwrap_err(),
+        ApiError::InvalidArgument
+    );
+    assert!(SoftTarget::try_new(&world, body, [0.0_f32, 0.0], -1.0).is_err());
+}
+
+#[test]
+fn body_bounding_radius_covers_shapes_and_follows_changes() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let body = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([10.0_f32, 0.0])
+            .build(),
+    );
+    assert_eq!(world.body_bounding_radius(body), 0.0);
+
+    let sdef = ShapeDef::builder().density(1.0).build();
+    world.create_polygon_shape_for(body, &sdef, &shapes::box_polygon(3.0, 4.0));
+    assert!((world.body_bounding_radius(body) - 5.0).abs() < 1e-4);
+    // Cached result is reused until shapes change.
+    assert_eq!(
+        world.body_bounding_radius(body),
+        world.body_bounding_radius(body)
+    );
+
+    let circle = world.create_circle_shape_for(body, &sdef, &shapes::circle([6.0_f32, 0.0], 1.0));
+    assert!((world.body_bounding_radius(body) - 7.0).abs() < 1e-4);
+
+    world.shape_set_circle(circle, &shapes::circle([0.0_f32, 8.0], 1.0));
+    assert!((world.body_bounding_radius(body) - 9.0).abs() < 1e-4);
+
+    world.destroy_shape_id(circle, true);
+    assert!((world.body_bounding_radius(body) - 5.0).abs() < 1e-4);
+
+    world.destroy_body_id(body);
+    assert_eq!(world.registry_sizes().bounding_radii, 0);
+}
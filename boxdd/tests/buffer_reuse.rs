@@ -226,6 +226,7 @@ fn chain_segments_into_reuses_buffer() {
     assert_eq!(segments.as_ptr(), segments_ptr);
 }
 
+#[cfg(feature = "debug-draw")]
 #[test]
 fn debug_draw_collect_into_reuses_command_and_vertex_buffers() {
     let mut world = World::new(WorldDef::default()).unwrap();
@@ -0,0 +1,75 @@
+use boxdd::prelude::*;
+
+#[test]
+fn build_checked_accepts_defaults() {
+    assert!(WorldDef::builder().build_checked().is_ok());
+    assert!(BodyBuilder::new().build_checked().is_ok());
+    assert!(ShapeDef::builder().build_checked().is_ok());
+    assert!(JointBase::builder().build_checked().is_ok());
+    assert!(
+        RevoluteJointDef::new(JointBase::default())
+            .build_checked()
+            .is_ok()
+    );
+}
+
+#[test]
+fn build_checked_names_the_bad_field() {
+    let err = WorldDef::builder()
+        .gravity([f32::NAN, -10.0])
+        .build_checked()
+        .unwrap_err();
+    assert_eq!((err.def, err.field), ("WorldDef", "gravity"));
+
+    let err = BodyBuilder::new()
+        .linear_damping(-1.0)
+        .build_checked()
+        .unwrap_err();
+    assert_eq!((err.def, err.field), ("BodyDef", "linear_damping"));
+    assert_eq!(
+        err.to_string(),
+        "invalid BodyDef: linear_damping must be finite and >= 0, got -1"
+    );
+
+    let err = ShapeDef::builder()
+        .density(-2.0)
+        .build_checked()
+        .unwrap_err();
+    assert_eq!((err.def, err.field), ("ShapeDef", "density"));
+
+    let err = RevoluteJointDef::new(JointBase::default())
+        .lower_angle(0.5)
+        .upper_angle(-0.5)
+        .build_checked()
+        .unwrap_err();
+    assert_eq!(
+        err,
+        ValidationError {
+            def: "RevoluteJointDef",
+            field: "lower_angle",
+            message: "(0.5) must be <= upper_angle (-0.5)".to_owned(),
+        }
+    );
+
+    let err = DistanceJointDef::new(JointBase::default())
+        .length(0.0)
+        .build_checked()
+        .unwrap_err();
+    assert_eq!(err.field, "length");
+    assert_eq!(ApiError::from(err), ApiError::InvalidArgument);
+}
+
+#[test]
+fn build_checked_runs_the_creation_checks() {
+    let mut raw = BodyDef::default().into_raw();
+    raw.type_ = 7;
+    let def = unsafe { BodyDef::from_raw(raw) };
+    let err = BodyBuilder::from(def).build_checked().unwrap_err();
+    assert_eq!((err.def, err.field), ("BodyDef", "body_type"));
+
+    let mut raw = BodyDef::default().into_raw();
+    raw.internalValue = 0;
+    let def = unsafe { BodyDef::from_raw(raw) };
+    let err = BodyBuilder::from(def).build_checked().unwrap_err();
+    assert_eq!(err.field, "internal_value");
+}
@@ -0,0 +1,60 @@
+use boxdd::{ApiError, Camera2D, Vec2};
+
+fn approx_vec2(a: Vec2, b: Vec2) -> bool {
+    (a.x - b.x).abs() <= 1e-4 && (a.y - b.y).abs() <= 1e-4
+}
+
+#[test]
+fn camera_round_trips_world_and_screen_points() {
+    let camera = Camera2D::new([2.0, -1.0], 40.0, [800.0, 600.0]);
+    assert!(approx_vec2(
+        camera.world_to_screen([2.0, -1.0]),
+        Vec2::new(400.0, 300.0)
+    ));
+    // World +y is screen up when flipped.
+    assert!(camera.world_to_screen([2.0, 0.0]).y < 300.0);
+    assert!(camera.flip_y(false).world_to_screen([2.0, 0.0]).y > 300.0);
+
+    for p in [[0.0, 0.0], [3.5, -7.25], [-10.0, 4.0]] {
+        let p = Vec2::from(p);
+        assert!(approx_vec2(
+            camera.screen_to_world(camera.world_to_screen(p)),
+            p
+        ));
+    }
+}
+
+#[test]
+fn camera_zoom_keeps_cursor_point_fixed() {
+    let mut camera = Camera2D::new([0.0, 0.0], 30.0, [800.0, 600.0]);
+    let cursor = Vec2::new(650.0, 120.0);
+    let before = camera.screen_to_world(cursor);
+    camera.zoom_at(cursor, 1.5);
+    assert!((camera.pixels_per_meter - 45.0).abs() < 1e-4);
+    assert!(approx_vec2(camera.screen_to_world(cursor), before));
+
+    let drag_from = camera.screen_to_world([100.0, 100.0]);
+    camera.pan_pixels([20.0, -10.0]);
+    assert!(approx_vec2(
+        camera.screen_to_world([120.0, 90.0]),
+        drag_from
+    ));
+
+    assert_eq!(
+        camera.try_zoom_at(cursor, 0.0).unwrap_err(),
+        ApiError::InvalidArgument
+    );
+}
+
+#[cfg(feature = "debug-draw")]
+#[test]
+fn camera_viewport_aabb_bounds_debug_draw() {
+    let camera = Camera2D::new([1.0, 2.0], 10.0, [200.0, 100.0]);
+    let view = camera.viewport_aabb();
+    assert!(approx_vec2(view.lower, Vec2::new(-9.0, -3.0)));
+    assert!(approx_vec2(view.upper, Vec2::new(11.0, 7.0)));
+
+    let opts = boxdd::DebugDrawOptions::default().culled_to(&camera);
+    assert_eq!(opts.drawing_bounds.lowerBound.x, view.lower.x);
+    assert_eq!(opts.drawing_bounds.upperBound.y, view.upper.y);
+}
@@ -86,7 +86,7 @@ fn scene_snapshot_roundtrip_includes_chains() {
     let scene = boxdd::serialize::SceneSnapshot::take(&world);
     assert_eq!(scene.chains.len(), 1);
 
-    let world2 = scene.rebuild();
+    let (world2, _) = scene.rebuild();
     let scene2 = boxdd::serialize::SceneSnapshot::take(&world2);
     assert_eq!(scene2.chains.len(), 1);
 }
@@ -114,7 +114,7 @@ fn scene_snapshot_roundtrip_includes_scoped_chains() {
     let scene = boxdd::serialize::SceneSnapshot::take(&world);
     assert_eq!(scene.chains.len(), 1);
 
-    let world2 = scene.rebuild();
+    let (world2, _) = scene.rebuild();
     let scene2 = boxdd::serialize::SceneSnapshot::take(&world2);
     assert_eq!(scene2.chains.len(), 1);
 }
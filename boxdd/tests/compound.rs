@@ -0,0 +1,110 @@
+use boxdd::{compound, prelude::*};
+
+fn ground(world: &mut World) -> BodyId {
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let _ = world.create_polygon_shape_for(
+        ground,
+        &ShapeDef::default(),
+        &shapes::box_polygon(50.0, 1.0),
+    );
+    ground
+}
+
+#[test]
+fn bridge_pins_planks_between_ground_anchors() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let ground = ground(&mut world);
+    let def = compound::BridgeDef::new([-5.0, 4.0], 10);
+    let bridge = compound::bridge(&mut world, ground, &def);
+    assert_eq!(bridge.planks.len(), 10);
+    assert_eq!(bridge.joints.len(), 11);
+    assert_eq!(world.body_position(bridge.planks[0]), Vec2::new(-4.5, 4.0));
+
+    for _ in 0..120 {
+        world.step(1.0 / 60.0, 4);
+    }
+    // Sags under gravity but stays attached at both ends.
+    let middle = world.body_position(bridge.planks[5]);
+    assert!(middle.y < 4.0 && middle.y > 0.0, "{middle:?}");
+
+    assert_eq!(
+        compound::try_bridge(&mut world, ground, &compound::BridgeDef::new([0.0, 0.0], 0))
+            .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+}
+
+#[test]
+fn donut_doohickey_and_car_settle_on_ground() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let _ground = ground(&mut world);
+
+    let donut = compound::donut(&mut world, [-10.0, 4.0], 1.0, 1);
+    assert_eq!(donut.bodies.len(), compound::DONUT_SIDES);
+    assert_eq!(donut.joints.len(), compound::DONUT_SIDES);
+    let doohickey = compound::doohickey(&mut world, [0.0, 1.0], 0.5);
+    let car = compound::car(&mut world, &compound::CarDef::new([10.0, 2.5], 1.0));
+
+    for _ in 0..180 {
+        world.step(1.0 / 60.0, 4);
+    }
+    for body in donut
+        .bodies
+        .iter()
+        .chain(&doohickey.wheels)
+        .chain(&[car.chassis, car.rear_wheel])
+    {
+        let p = world.body_position(*body);
+        assert!(p.is_valid() && p.y > 0.5 && p.y < 6.0, "{p:?}");
+    }
+
+    assert_eq!(
+        compound::try_donut(&mut world, [0.0, 0.0], 0.0, 1).unwrap_err(),
+        ApiError::InvalidArgument
+    );
+    assert_eq!(
+        compound::try_car(
+            &mut world,
+            &compound::CarDef::default().suspension(-1.0, 0.7)
+        )
+        .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+}
+
+#[test]
+fn ragdoll_bones_are_distinct_and_settle_on_ground() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let _ground = ground(&mut world);
+
+    let def = compound::RagdollDef::new([0.0, 1.0], 2.0).group_index(3);
+    let ragdoll = compound::ragdoll(&mut world, &def);
+    let hip = world.body_position(ragdoll.hip);
+    assert!(
+        hip.x.abs() < 1.0e-6 && (hip.y - 2.9).abs() < 1.0e-5,
+        "{hip:?}"
+    );
+    let mut bodies = ragdoll.bodies().to_vec();
+    bodies.sort();
+    bodies.dedup();
+    assert_eq!(bodies.len(), 11);
+    assert_eq!(ragdoll.joints().len(), 10);
+
+    for _ in 0..240 {
+        world.step(1.0 / 60.0, 4);
+    }
+    for body in ragdoll.bodies() {
+        let p = world.body_position(body);
+        assert!(p.is_valid() && p.y > 1.0 && p.y < 4.5, "{p:?}");
+    }
+
+    assert_eq!(
+        compound::try_ragdoll(&mut world, &compound::RagdollDef::new([0.0, 0.0], 0.0)).unwrap_err(),
+        ApiError::InvalidArgument
+    );
+    assert_eq!(
+        compound::try_ragdoll(&mut world, &compound::RagdollDef::default().density(-1.0))
+            .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+}
@@ -1,4 +1,20 @@
-use boxdd::HexColor;
+#![cfg(feature = "debug-draw")]
+
+use boxdd::{
+    BodyBuilder, CategoryColors, CollisionCategory, DebugDrawCmd, DebugDrawOptions, Filter,
+    HexColor, ShapeDef, SurfaceMaterial, Vec2, World, WorldDef, shapes,
+};
+
+fn solid_polygon_colors(cmds: &[DebugDrawCmd]) -> Vec<(Vec2, HexColor)> {
+    cmds.iter()
+        .filter_map(|cmd| match cmd {
+            DebugDrawCmd::SolidPolygon {
+                transform, color, ..
+            } => Some((transform.position(), *color)),
+            _ => None,
+        })
+        .collect()
+}
 
 #[test]
 fn hex_color_helpers_round_trip_rgb_values() {
@@ -15,3 +31,152 @@ fn hex_color_helpers_round_trip_rgb_values() {
     assert_eq!(HexColor::BOX2D_BLUE.rgb_u32(), 0x30AEBF);
     assert_eq!(HexColor::BOX2D_YELLOW.rgb_u32(), 0xFFEE8C);
 }
+
+#[test]
+fn category_colors_resolve_materials_before_categories() {
+    let triggers = CollisionCategory::bit(1);
+    let terrain = CollisionCategory::bit(2);
+    let colors = CategoryColors::new()
+        .category(triggers, HexColor::BOX2D_YELLOW)
+        .category(terrain, HexColor::BLUE)
+        .material(7, HexColor::RED);
+
+    assert_eq!(colors.color_for(triggers, 0), Some(HexColor::BOX2D_YELLOW));
+    assert_eq!(
+        colors.color_for(terrain | triggers, 0),
+        Some(HexColor::BOX2D_YELLOW)
+    );
+    assert_eq!(colors.color_for(terrain, 7), Some(HexColor::RED));
+    assert_eq!(colors.color_for(CollisionCategory::DEFAULT, 3), None);
+    assert!(CategoryColors::new().is_empty());
+    assert!(!colors.is_empty());
+}
+
+#[test]
+fn color_overrides_tint_matching_shapes_and_restore_materials() {
+    let terrain = CollisionCategory::bit(2);
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().position([0.0, 0.0]).build());
+    let crate_body = world.create_body_id(BodyBuilder::new().position([5.0, 0.0]).build());
+    let ground_shape = world.create_polygon_shape_for(
+        ground,
+        &ShapeDef::builder()
+            .filter(Filter::default().category(terrain))
+            .build(),
+        &shapes::box_polygon(1.0, 1.0),
+    );
+    let crate_shape = world.create_polygon_shape_for(
+        crate_body,
+        &ShapeDef::builder()
+            .material(SurfaceMaterial::default().with_user_material_id(7))
+            .build(),
+        &shapes::box_polygon(0.5, 0.5),
+    );
+
+    let opts = DebugDrawOptions::default().color_overrides(
+        CategoryColors::new()
+            .category(terrain, HexColor::BLUE)
+            .material(7, HexColor::RED),
+    );
+    let colors = solid_polygon_colors(&world.debug_draw_collect(opts));
+    assert_eq!(colors.len(), 2);
+    for (position, color) in colors {
+        let expected = if position.x < 2.5 {
+            HexColor::BLUE
+        } else {
+            HexColor::RED
+        };
+        assert_eq!(color, expected);
+    }
+
+    assert_eq!(
+        world.shape_surface_material(ground_shape).custom_color(),
+        HexColor::BLACK
+    );
+    assert_eq!(
+        world.shape_surface_material(crate_shape).custom_color(),
+        HexColor::BLACK
+    );
+    let plain = solid_polygon_colors(&world.debug_draw_collect(DebugDrawOptions::default()));
+    assert!(plain.iter().all(|(_, color)| *color != HexColor::BLUE));
+}
+
+#[test]
+fn vertex_collector_tessellates_shapes_into_lines_and_triangles() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let body = world.create_body_id(BodyBuilder::new().position([2.0_f32, 3.0]).build());
+    let color = HexColor::from_rgb(0x10, 0x20, 0x30);
+    let sdef = ShapeDef::builder()
+        .material(SurfaceMaterial::default().with_custom_color(color))
+        .build();
+    world.create_polygon_shape_for(body, &sdef, &shapes::box_polygon(0.5, 0.5));
+
+    let mut mesh = boxdd::VertexCollector::new();
+    world.debug_draw_backend(&mut mesh, DebugDrawOptions::default());
+    assert!(!mesh.is_empty());
+
+    // One quad: two fan triangles and a four-line outline.
+    assert_eq!(mesh.triangles.len(), 6);
+    assert_eq!(mesh.lines.len(), 8);
+    assert!(
+        mesh.triangles
+            .iter()
+            .all(|v| v.color == [0x10, 0x20, 0x30, 128])
+    );
+    assert!(
+        mesh.lines
+            .iter()
+            .all(|v| v.color == [0x10, 0x20, 0x30, 255])
+    );
+    for v in mesh.triangles.iter().chain(&mesh.lines) {
+        assert!((v.position.x - 2.0).abs() <= 0.5 + 1e-4);
+        assert!((v.position.y - 3.0).abs() <= 0.5 + 1e-4);
+    }
+
+    world.create_circle_shape_for(body, &sdef, &shapes::circle([3.0_f32, 0.0], 0.5));
+    mesh.clear();
+    world
+        .try_debug_draw_backend(&mut mesh, DebugDrawOptions::default())
+        .unwrap();
+    // The circle adds a 16-segment fan, its outline, and a radius line.
+    assert_eq!(mesh.triangles.len(), 6 + 14 * 3);
+    assert_eq!(mesh.lines.len(), 8 + 17 * 2);
+}
+
+#[test]
+fn svg_export_renders_shapes_inside_a_fitted_view_box() {
+    use boxdd::debug_draw::svg::SvgExport;
+
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().position([0.0, -1.0]).build());
+    let _ = world.create_polygon_shape_for(
+        ground,
+        &ShapeDef::default(),
+        &shapes::box_polygon(4.0, 0.5),
+    );
+    let ball = world.create_body_id(BodyBuilder::new().position([1.0, 2.0]).build());
+    let _ =
+        world.create_circle_shape_for(ball, &ShapeDef::default(), &shapes::circle([0.0, 0.0], 0.5));
+
+    let svg = world.debug_draw_svg(DebugDrawOptions::default());
+    assert!(svg.starts_with("<svg "));
+    assert!(svg.trim_end().ends_with("</svg>"));
+    assert_eq!(svg.matches("<polygon ").count(), 1);
+    assert!(svg.contains(r#"<circle cx="1" cy="-2" r="0.5""#));
+    // x from -4 to 4 and y from -1.5 to 2.5, plus 0.5 m padding, at 50 px per meter.
+    assert!(svg.contains(r#"viewBox="-4.5 -3 9 5""#), "{svg}");
+    assert!(svg.contains(r#"width="450" height="250""#));
+
+    let cmds = world.debug_draw_collect(DebugDrawOptions {
+        draw_bounds: true,
+        ..Default::default()
+    });
+    let mut bytes = Vec::new();
+    SvgExport::new()
+        .background(None)
+        .write(&cmds, &mut bytes)
+        .unwrap();
+    let bounded = String::from_utf8(bytes).unwrap();
+    assert!(!bounded.contains("<rect"));
+    assert!(bounded.matches("<polygon ").count() >= 3);
+}
@@ -1,5 +1,6 @@
 use boxdd::{
-    Aabb, DynamicTree, ShapeProxy, TreeProxyId, TreeRayCastInput, TreeShapeCastInput, Vec2,
+    Aabb, DynamicTree, ShapeProxy, TreeProxyId, TreeRayCastInput, TreeShapeCastInput,
+    TypedDynamicTree, Vec2,
 };
 
 fn aabb(min_x: f32, min_y: f32, max_x: f32, max_y: f32) -> Aabb {
@@ -147,3 +148,41 @@ fn assert_tree_query_finds_proxy(tree: &DynamicTree, expected: TreeProxyId) {
     });
     assert_eq!(hits, vec![(expected, 7)]);
 }
+
+#[test]
+fn typed_tree_hands_values_to_queries_and_ray_casts() {
+    let mut tree = TypedDynamicTree::new();
+    let coin = tree.insert(aabb(-1.0, -1.0, 1.0, 1.0), 0b01, String::from("coin"));
+    let gem = tree.insert(aabb(4.0, -1.0, 6.0, 1.0), 0b10, String::from("gem"));
+    assert_eq!(tree.len(), 2);
+
+    let mut found = Vec::new();
+    tree.query(aabb(-2.0, -2.0, 2.0, 2.0), u64::MAX, &mut |id, name| {
+        found.push((id, name.clone()));
+        true
+    });
+    assert_eq!(found, vec![(coin, String::from("coin"))]);
+
+    tree.move_proxy(coin, aabb(9.0, -1.0, 11.0, 1.0));
+    tree.rebuild(true);
+    let mut first = None;
+    tree.ray_cast(
+        TreeRayCastInput::new([0.0_f32, 0.0], [20.0_f32, 0.0]),
+        0b10,
+        &mut |input, _, name| {
+            first = Some(name.clone());
+            input.max_fraction
+        },
+    );
+    assert_eq!(first.as_deref(), Some("gem"));
+
+    tree.get_mut(gem).unwrap().push('!');
+    assert_eq!(tree.remove(gem).as_deref(), Some("gem!"));
+    assert_eq!(tree.remove(gem), None);
+    assert!(tree.aabb(gem).is_none());
+    assert_eq!(tree.len(), 1);
+    assert!(
+        tree.try_insert(aabb(1.0, 1.0, -1.0, -1.0), 1, String::new())
+            .is_err()
+    );
+}
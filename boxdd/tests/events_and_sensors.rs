@@ -92,7 +92,11 @@ fn contact_event_view_matches_owned_snapshot() {
                 .map(|event| (shape_key(event.shape_a()), shape_key(event.shape_b())))
                 .collect::<Vec<_>>(),
             end.count(),
-            hit.count(),
+            hit.map(|event| {
+                assert_eq!(event.impulse_estimate(2.0), 2.0 * event.approach_speed());
+                event.to_event()
+            })
+            .collect::<Vec<_>>(),
         )
     });
     let owned_begin = owned
@@ -103,7 +107,12 @@ fn contact_event_view_matches_owned_snapshot() {
 
     assert_eq!(view.0, owned_begin);
     assert_eq!(view.1, owned.end.len());
-    assert_eq!(view.2, owned.hit.len());
+    assert_eq!(view.2.len(), owned.hit.len());
+    for (viewed, owned) in view.2.iter().zip(&owned.hit) {
+        assert_eq!(viewed.contact_id, owned.contact_id);
+        assert_eq!(viewed.approach_speed, owned.approach_speed);
+        assert_eq!(viewed.point, owned.point);
+    }
 
     let stored = owned.clone();
     world.step(1.0 / 60.0, 4);
@@ -174,6 +183,13 @@ fn sensor_event_view_matches_owned_snapshot() {
         assert_eq!(view.0, owned_begin);
         assert_eq!(view.1, owned.end.len());
 
+        let view_bodies =
+            world.with_sensor_events_view(|begin, _| begin.bodies().collect::<Vec<_>>());
+        let owned_bodies = owned.begin_bodies().collect::<Vec<_>>();
+        assert_eq!(view_bodies, owned_bodies);
+        assert!(owned_bodies.contains(&(bullet, wall)));
+        assert_eq!(owned.begin[0].sensor_body_id(), Some(bullet));
+
         let stored = owned.clone();
         world.step(1.0 / 60.0, 4);
         assert_eq!(stored.begin.len(), owned_begin.len());
@@ -269,3 +285,404 @@ fn step_until_contact_begin(world: &mut World) -> ContactEvents {
     }
     panic!("expected at least one contact begin event");
 }
+
+#[test]
+fn zone_reports_static_and_kinematic_visitors() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let visitor_def = ShapeDef::builder().enable_sensor_events(true).build();
+
+    let sensor_body = world.create_body_id(BodyBuilder::new().build());
+    let sensor = world.create_polygon_shape_for(
+        sensor_body,
+        &ShapeDef::builder().sensor(true).build(),
+        &shapes::box_polygon(1.0, 1.0),
+    );
+
+    let wall = world.create_body_id(BodyBuilder::new().position([0.5_f32, 0.0]).build());
+    let wall_shape =
+        world.create_polygon_shape_for(wall, &visitor_def, &shapes::box_polygon(0.25, 0.25));
+    let platform = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Kinematic)
+            .position([10.0_f32, 0.0])
+            .build(),
+    );
+    let platform_shape =
+        world.create_polygon_shape_for(platform, &visitor_def, &shapes::box_polygon(0.25, 0.25));
+
+    let mut zone = Zone::new(sensor)
+        .include_static(true)
+        .include_kinematic(true);
+    world.step(1.0 / 60.0, 4);
+    let events = zone.update(&world);
+    assert_eq!(events.begin.len(), 1);
+    assert_eq!(events.begin[0].visitor_shape, wall_shape);
+    assert_eq!(events.begin[0].visitor_body, Some(wall));
+    assert!(events.end.is_empty());
+
+    world.set_body_position_and_rotation(platform, [0.0_f32, 0.5], 0.0);
+    world.step(1.0 / 60.0, 4);
+    let events = zone.update(&world);
+    assert_eq!(events.begin.len(), 1);
+    assert_eq!(events.begin[0].visitor_shape, platform_shape);
+    assert_eq!(zone.visitors().len(), 2);
+
+    world.set_body_position_and_rotation(platform, [10.0_f32, 0.0], 0.0);
+    world.step(1.0 / 60.0, 4);
+    let events = zone.update(&world);
+    assert!(events.begin.is_empty());
+    assert_eq!(events.end.len(), 1);
+    assert_eq!(events.end[0].visitor_shape, platform_shape);
+
+    let mut static_only = Zone::new(sensor).include_static(true);
+    let events = static_only.update(&world);
+    assert_eq!(static_only.visitors(), &[wall_shape]);
+    assert_eq!(events.begin.len(), 1);
+
+    // A shared negative group rejects the visitor, and a shared positive one overrides masks.
+    let mut grouped_zone = |x: f32, filter: Filter| {
+        let def = ShapeDef::builder()
+            .enable_sensor_events(true)
+            .filter(filter);
+        let gate = world.create_body_id(BodyBuilder::new().position([x, 0.0]).build());
+        let sensor = world.create_polygon_shape_for(
+            gate,
+            &def.clone().sensor(true).build(),
+            &shapes::box_polygon(1.0, 1.0),
+        );
+        let post = world.create_body_id(BodyBuilder::new().position([x + 0.5, 0.0]).build());
+        let visitor =
+            world.create_polygon_shape_for(post, &def.build(), &shapes::box_polygon(0.25, 0.25));
+        (Zone::new(sensor).include_static(true), visitor)
+    };
+    let (mut never, _) = grouped_zone(20.0, Filter::new().group(-3));
+    let (mut always, member) = grouped_zone(
+        30.0,
+        Filter {
+            mask_bits: 0,
+            ..Filter::new().group(4)
+        },
+    );
+    never.update(&world);
+    always.update(&world);
+    assert!(never.visitors().is_empty());
+    assert_eq!(always.visitors(), &[member]);
+}
+
+#[test]
+fn ladder_suspends_gravity_inside_and_restores_it_on_exit() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let wall = world.create_body_id(BodyBuilder::new().build());
+    let sensor = world.create_polygon_shape_for(
+        wall,
+        &ShapeDef::builder().sensor(true).build(),
+        &shapes::box_polygon(0.5, 2.0),
+    );
+    let climber = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([0.0_f32, 0.0])
+            .gravity_scale(1.5)
+            .build(),
+    );
+    let _ = world.create_circle_shape_for(
+        climber,
+        &ShapeDef::builder().enable_sensor_events(true).build(),
+        &shapes::circle([0.0, 0.0], 0.25),
+    );
+
+    let mut ladder = Ladder::new(sensor).max_climb_speed(1.0);
+    ladder.update(&mut world);
+    assert!(ladder.is_climbing(climber));
+    assert_eq!(world.body(climber).unwrap().gravity_scale(), 0.0);
+
+    // The clamp runs before the step, so the step itself moves at most at the climb speed.
+    world.set_body_linear_velocity(climber, [0.0_f32, 5.0]);
+    ladder.update(&mut world);
+    assert!((world.body_linear_velocity(climber).y - 1.0).abs() < 1.0e-6);
+    world.step(1.0 / 60.0, 4);
+    assert!(world.body_position(climber).y <= 1.0 / 60.0 + 1.0e-5);
+
+    world.set_body_position_and_rotation(climber, [10.0_f32, 0.0], 0.0);
+    ladder.update(&mut world);
+    assert_eq!(ladder.climbers().count(), 0);
+    assert_eq!(world.body(climber).unwrap().gravity_scale(), 1.5);
+
+    world.set_body_position_and_rotation(climber, [0.0_f32, 0.0], 0.0);
+    ladder.update(&mut world);
+    ladder.release(&mut world);
+    assert_eq!(world.body(climber).unwrap().gravity_scale(), 1.5);
+}
+
+#[test]
+fn collision_table_tracks_body_pairs_through_multiple_shapes() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, -10.0]).build()).unwrap();
+    let sdef = ShapeDef::builder()
+        .density(1.0)
+        .enable_contact_events(true)
+        .build();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let _ = world.create_polygon_shape_for(ground, &sdef, &shapes::box_polygon(5.0, 0.5));
+    let cart = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([0.0_f32, 1.0])
+            .build(),
+    );
+    let _ = world.create_circle_shape_for(cart, &sdef, &shapes::circle([-0.5_f32, 0.0], 0.25));
+    let _ = world.create_circle_shape_for(cart, &sdef, &shapes::circle([0.5_f32, 0.0], 0.25));
+
+    let mut table = CollisionTable::new();
+    for _ in 0..60 {
+        world.step(1.0 / 60.0, 4);
+        table.update(&world);
+    }
+    assert!(table.is_touching(ground, cart));
+    assert!(table.is_touching(cart, ground));
+    assert_eq!(table.touching_bodies(ground), vec![cart]);
+    let pairs: Vec<_> = table.pairs().collect();
+    assert_eq!(pairs.len(), 1);
+    assert_eq!(pairs[0].2, 2);
+    assert_eq!(table.shape_pairs().count(), 2);
+
+    world.destroy_body_id(cart);
+    world.step(1.0 / 60.0, 4);
+    table.update(&world);
+    assert!(!table.is_touching(ground, cart));
+    assert!(table.is_empty());
+}
+
+#[test]
+fn event_summary_matches_snapshot_lengths() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, -10.0]).build()).unwrap();
+    assert!(world.event_summary().is_empty());
+
+    let b1 = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([0.0_f32, 2.0])
+            .build(),
+    );
+    let b2 = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([0.0_f32, 3.5])
+            .build(),
+    );
+    let sdef = ShapeDef::builder()
+        .density(1.0)
+        .enable_contact_events(true)
+        .build();
+    let _s1 = world.create_polygon_shape_for(b1, &sdef, &shapes::box_polygon(0.5, 0.5));
+    let _s2 = world.create_polygon_shape_for(b2, &sdef, &shapes::box_polygon(0.5, 0.5));
+    world.set_body_linear_velocity(b1, [0.0_f32, 2.0]);
+    world.set_body_linear_velocity(b2, [0.0_f32, -2.0]);
+
+    let contacts = step_until_contact_begin(&mut world);
+    let counts = world.event_summary();
+    assert_eq!(counts, world.handle().event_summary());
+    assert_eq!(counts.contacts_begun, contacts.begin.len());
+    assert_eq!(counts.contacts_ended, contacts.end.len());
+    assert_eq!(counts.hits, contacts.hit.len());
+    assert_eq!(counts.moves, world.body_events().len());
+    assert_eq!(counts.joint_events, 0);
+    assert!(counts.total() >= counts.contacts_begun + counts.moves);
+}
+
+#[test]
+fn event_dispatcher_delivers_typed_events_after_step() {
+    use std::cell::RefCell;
+    use std::rc::Rc;
+
+    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, -10.0]).build()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    world.create_polygon_shape_for(
+        ground,
+        &ShapeDef::builder().enable_contact_events(true).build(),
+        &shapes::box_polygon(5.0, 0.5),
+    );
+    let zone = world.create_body_id(BodyBuilder::new().position([0.0_f32, 2.0]).build());
+    world.create_polygon_shape_for(
+        zone,
+        &ShapeDef::builder()
+            .sensor(true)
+            .enable_sensor_events(true)
+            .build(),
+        &shapes::box_polygon(2.0, 0.5),
+    );
+    let ball = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([0.0_f32, 4.0])
+            .build(),
+    );
+    world.create_circle_shape_for(
+        ball,
+        &ShapeDef::builder()
+            .density(1.0)
+            .enable_contact_events(true)
+            .enable_sensor_events(true)
+            .build(),
+        &shapes::circle([0.0_f32, 0.0], 0.25),
+    );
+
+    let log = Rc::new(RefCell::new(Vec::new()));
+    let mut events = EventDispatcher::new()
+        .on_sensor_enter({
+            let log = Rc::clone(&log);
+            move |_, e| {
+                assert_eq!(e.visitor_body, Some(ball));
+                log.borrow_mut().push("enter");
+            }
+        })
+        .on_sensor_exit({
+            let log = Rc::clone(&log);
+            move |_, _| log.borrow_mut().push("exit")
+        })
+        .on_contact_begin({
+            let log = Rc::clone(&log);
+            move |world, e| {
+                log.borrow_mut().push("land");
+                // Handlers get the world mutably.
+                let body = world.shape_body_id(e.shape_b);
+                let other = world.shape_body_id(e.shape_a);
+                world.destroy_body_id(if body == ball { body } else { other });
+            }
+        });
+    assert!(!events.is_empty());
+
+    for _ in 0..240 {
+        world.step_with_events(1.0 / 60.0, 4, &mut events);
+        if log.borrow().contains(&"land") {
+            break;
+        }
+    }
+    assert_eq!(*log.borrow(), ["enter", "exit", "land"]);
+    assert!(world.body(ball).is_none());
+
+    events.clear();
+    assert!(events.is_empty());
+    assert_eq!(world.step_with_events(1.0 / 60.0, 4, &mut events), 0);
+}
+
+#[test]
+fn contact_tracker_reports_enter_stay_and_exit() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, -10.0]).build()).unwrap();
+    let sdef = ShapeDef::builder()
+        .density(1.0)
+        .enable_contact_events(true)
+        .build();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let floor = world.create_polygon_shape_for(ground, &sdef, &shapes::box_polygon(5.0, 0.5));
+    let crate_body = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([0.0_f32, 1.5])
+            .build(),
+    );
+    let lid = world.create_polygon_shape_for(crate_body, &sdef, &shapes::box_polygon(0.5, 0.5));
+
+    let mut tracker = ContactTracker::new();
+    let mut entered_at = None;
+    for step in 0..120 {
+        world.step(1.0 / 60.0, 4);
+        tracker.update(&world);
+        if !tracker.pairs_entered().is_empty() {
+            entered_at = Some(step);
+            break;
+        }
+    }
+    assert!(entered_at.is_some(), "expected the box to land");
+    let pair = tracker.pairs_entered()[0];
+    assert!(pair == (floor, lid) || pair == (lid, floor));
+    assert_eq!(tracker.pairs_staying().count(), 0);
+    assert!(tracker.is_touching(lid, floor));
+
+    world.step(1.0 / 60.0, 4);
+    tracker.update(&world);
+    assert!(tracker.pairs_entered().is_empty());
+    assert_eq!(tracker.pairs_staying().collect::<Vec<_>>(), vec![pair]);
+    assert!(tracker.pairs_exited().is_empty());
+
+    // Destroying without reading the end event still reports the exit once.
+    world.destroy_body_id(crate_body);
+    tracker.retain_valid();
+    assert_eq!(tracker.pairs_exited(), [pair]);
+    assert!(tracker.is_empty());
+    world.step(1.0 / 60.0, 4);
+    tracker.update(&world);
+    assert!(tracker.pairs_exited().is_empty());
+    assert_eq!(tracker.len(), 0);
+}
+
+#[test]
+fn sleep_tracking_reports_wake_and_sleep_transitions() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, -10.0]).build()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let _ = world.create_polygon_shape_for(
+        ground,
+        &ShapeDef::default(),
+        &shapes::box_polygon(5.0, 0.5),
+    );
+    let crate_body = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([0.0_f32, 1.0])
+            .build(),
+    );
+    let _ = world.create_polygon_shape_for(
+        crate_body,
+        &ShapeDef::default(),
+        &shapes::box_polygon(0.5, 0.5),
+    );
+    let dt = 1.0 / 60.0;
+
+    world.step(dt, 4);
+    assert!(world.sleep_state_changes().is_empty());
+
+    world.enable_sleep_tracking(true);
+    assert!(world.is_sleep_tracking_enabled());
+    world.step(dt, 4);
+    assert_eq!(
+        world.sleep_state_changes(),
+        vec![(crate_body, SleepState::Awake)]
+    );
+    world.step(dt, 4);
+    assert!(world.sleep_state_changes().is_empty());
+
+    // A zero-length step moves nothing and must not read as the crate stopping.
+    world.step(0.0, 4);
+    assert!(world.sleep_state_changes().is_empty());
+    world.step(dt, 4);
+    assert!(world.sleep_state_changes().is_empty());
+
+    let mut fell_asleep = false;
+    for _ in 0..600 {
+        world.step(dt, 4);
+        if world.sleep_state_changes() == vec![(crate_body, SleepState::Asleep)] {
+            fell_asleep = true;
+            break;
+        }
+    }
+    assert!(fell_asleep);
+    assert!(!world.body_is_awake(crate_body));
+
+    world.set_body_awake(crate_body, true);
+    world.step(dt, 4);
+    assert_eq!(
+        world.sleep_state_changes(),
+        vec![(crate_body, SleepState::Awake)]
+    );
+
+    // Forced to sleep: no fell-asleep event, still reported.
+    world.set_body_awake(crate_body, false);
+    world.step(dt, 4);
+    let mut changes = Vec::new();
+    world.sleep_state_changes_into(&mut changes);
+    assert_eq!(changes, vec![(crate_body, SleepState::Asleep)]);
+
+    world.enable_sleep_tracking(false);
+    world.set_body_awake(crate_body, true);
+    world.step(dt, 4);
+    assert!(world.sleep_state_changes().is_empty());
+}
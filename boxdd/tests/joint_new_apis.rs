@@ -1949,3 +1949,608 @@ fn motor_joint_runtime_specific_apis_are_available_across_handle_types() {
         1.0e-6
     ));
 }
+
+#[test]
+fn spinner_drives_body_at_target_rpm() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0, 0.0]).build()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let rotor = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([1.0, 2.0])
+            .build(),
+    );
+    let sdef = ShapeDef::builder().density(1.0).build();
+    let _ = world.create_polygon_shape_for(rotor, &sdef, &shapes::box_polygon(1.0, 0.1));
+
+    let mut spinner = Spinner::new(&mut world, ground, rotor, [1.0, 2.0], 60.0, 1000.0);
+    assert_eq!(spinner.rpm(), 60.0);
+    assert!(approx_eq(
+        world.revolute_motor_speed(spinner.joint_id()),
+        core::f32::consts::TAU,
+        1.0e-5
+    ));
+    for _ in 0..30 {
+        world.step(1.0 / 60.0, 4);
+    }
+    assert!(approx_eq(spinner.current_rpm(&world), 60.0, 1.0));
+
+    spinner.set_rpm(&mut world, -30.0);
+    for _ in 0..30 {
+        world.step(1.0 / 60.0, 4);
+    }
+    assert!(approx_eq(spinner.current_rpm(&world), -30.0, 1.0));
+    assert_eq!(
+        spinner.try_set_rpm(&mut world, f32::NAN).unwrap_err(),
+        ApiError::InvalidArgument
+    );
+
+    // Angular impulses still act on the rotor against the motor torque cap.
+    spinner.set_max_torque(&mut world, 0.0);
+    let before = world.body_angular_velocity(rotor);
+    world.body_apply_angular_impulse(rotor, 1.0, true);
+    assert!(world.body_angular_velocity(rotor) > before);
+    assert_eq!(
+        spinner.try_set_max_torque(&mut world, -1.0).unwrap_err(),
+        ApiError::InvalidArgument
+    );
+    assert_eq!(
+        spinner
+            .try_set_max_torque(&mut world, f32::INFINITY)
+            .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+}
+
+#[test]
+fn mirrored_joint_defs_reflect_frames_and_angles() {
+    use boxdd::joints::{MirrorJoint, mirror, mirror_onto, try_mirror};
+
+    let base = JointBaseBuilder::new()
+        .local_frames([0.5, 0.25], 0.0, [-0.5, 0.0], 0.0)
+        .build();
+    let left = RevoluteJointDef::new(base.clone())
+        .limit_deg(-10.0, 45.0)
+        .motor_speed(2.0);
+    let right = mirror(&left, [0.0, 1.0]);
+    assert!(approx_eq(
+        right.minimum_angle(),
+        (-45.0_f32).to_radians(),
+        1e-6
+    ));
+    assert!(approx_eq(
+        right.maximum_angle(),
+        10.0_f32.to_radians(),
+        1e-6
+    ));
+    assert!(approx_eq(right.target_motor_speed(), -2.0, 1e-6));
+    let frame_a = right.base().local_frame_a();
+    assert!(approx_vec2(frame_a.position(), Vec2::new(-0.5, 0.25), 1e-6));
+
+    // Mirroring twice is the identity.
+    let back = right.mirrored(Vec2::new(0.0, 1.0));
+    assert!(approx_eq(back.minimum_angle(), left.minimum_angle(), 1e-6));
+    assert!(approx_transform(
+        back.base().local_frame_a(),
+        left.base().local_frame_a(),
+        1e-5
+    ));
+
+    // Translations along the joint axis keep their sign.
+    let slider = PrismaticJointDef::new(base)
+        .lower_translation(-0.25)
+        .upper_translation(1.0);
+    let mirrored = mirror(&slider, [0.0, 1.0]);
+    assert!(approx_eq(mirrored.minimum_translation(), -0.25, 1e-6));
+    assert!(approx_eq(mirrored.maximum_translation(), 1.0, 1e-6));
+
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let a = world.create_body_id(BodyBuilder::new().build());
+    let b = world.create_body_id(BodyBuilder::new().build());
+    let attached = mirror_onto(&left, [0.0, 1.0], a, b);
+    assert!(same_body_id(attached.base().body_a_id(), a));
+    assert!(same_body_id(attached.base().body_b_id(), b));
+
+    assert_eq!(
+        try_mirror(&left, [0.0, 0.0]).unwrap_err(),
+        ApiError::InvalidArgument
+    );
+}
+
+#[test]
+fn connected_bodies_walks_joints_without_crossing_static_bodies() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let dynamic = || BodyBuilder::new().body_type(BodyType::Dynamic).build();
+    let a = world.create_body_id(dynamic());
+    let b = world.create_body_id(dynamic());
+    let c = world.create_body_id(dynamic());
+    let other = world.create_body_id(dynamic());
+    for (x, y) in [(ground, a), (a, b), (b, c), (ground, other)] {
+        let _ = world
+            .revolute(x, y)
+            .anchor_world([0.0_f32, 0.0])
+            .build()
+            .id();
+    }
+
+    let mechanism = world.connected_bodies(a, usize::MAX);
+    assert!(same_body_id(mechanism[0], a));
+    assert_eq!(mechanism.len(), 4);
+    for body in [ground, b, c] {
+        assert!(mechanism.iter().any(|&x| same_body_id(x, body)));
+    }
+    assert!(!mechanism.iter().any(|&x| same_body_id(x, other)));
+
+    let near = world.connected_bodies(a, 1);
+    assert_eq!(near.len(), 3);
+    assert!(!near.iter().any(|&x| same_body_id(x, c)));
+    assert_eq!(world.connected_bodies(c, 0).len(), 1);
+
+    // Starting on the ground walks into every mechanism attached to it.
+    assert_eq!(world.connected_bodies(ground, usize::MAX).len(), 5);
+}
+
+#[test]
+fn door_and_elevator_drive_prismatic_joints_between_targets() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let sdef = ShapeDef::builder().density(1.0).build();
+    let dynamic = |world: &mut World, x: f32| {
+        let body = world.create_body_id(
+            BodyBuilder::new()
+                .body_type(BodyType::Dynamic)
+                .position([x, 1.0])
+                .build(),
+        );
+        let _ = world.create_polygon_shape_for(body, &sdef, &shapes::box_polygon(0.5, 0.1));
+        body
+    };
+    let panel = dynamic(&mut world, 0.0);
+    let platform = dynamic(&mut world, 5.0);
+
+    let mut door = Door::new(&mut world, ground, panel, [1.0, 0.0], 2.0, 2.0, 1000.0);
+    assert_eq!(door.state(&world), DoorState::Closed);
+    door.open(&mut world);
+    assert_eq!(door.state(&world), DoorState::Opening);
+    for _ in 0..180 {
+        door.update(&mut world);
+        world.step(1.0 / 60.0, 4);
+    }
+    assert_eq!(door.state(&world), DoorState::Open);
+    assert!(!door.is_moving(&world));
+    assert!(approx_eq(world.body_position(panel).x, 2.0, 0.02));
+    door.toggle(&mut world);
+    assert_eq!(door.state(&world), DoorState::Closing);
+
+    let mut lift = Elevator::new(
+        &mut world,
+        ground,
+        platform,
+        [0.0, 1.0],
+        &[0.0, 3.0],
+        2.0,
+        1000.0,
+    );
+    assert_eq!(lift.current_stop(&world), Some(0));
+    lift.go_to(&mut world, 1);
+    assert!(lift.is_moving(&world));
+    for _ in 0..240 {
+        lift.update(&mut world);
+        world.step(1.0 / 60.0, 4);
+    }
+    assert_eq!(lift.current_stop(&world), Some(1));
+    assert!(approx_eq(world.body_position(platform).y, 4.0, 0.02));
+    assert_eq!(
+        lift.try_go_to(&mut world, 2).unwrap_err(),
+        ApiError::InvalidArgument
+    );
+    assert!(Door::try_new(&mut world, ground, panel, [0.0, 0.0], 1.0, 1.0, 1.0).is_err());
+    assert_eq!(
+        Door::try_new(&mut world, ground, panel, [1.0, 0.0], 0.0, 1.0, 1.0).unwrap_err(),
+        ApiError::InvalidArgument
+    );
+
+    // Stops that exclude the creation pose still leave it inside the limits.
+    let high_platform = dynamic(&mut world, 10.0);
+    let raised = Elevator::new(
+        &mut world,
+        ground,
+        high_platform,
+        [0.0, 1.0],
+        &[2.0, 4.0],
+        2.0,
+        1000.0,
+    );
+    assert_eq!(world.prismatic_lower_limit(raised.joint_id()), 0.0);
+    assert_eq!(world.prismatic_upper_limit(raised.joint_id()), 4.0);
+}
+
+#[test]
+fn revolute_soft_limits_enable_limit_with_cushioned_tuning() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let anchor = world.create_body_id(BodyBuilder::new().position([0.0_f32, 5.0]).build());
+    let bob = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([2.0_f32, 5.0])
+            .build(),
+    );
+    let _ = world.create_polygon_shape_for(
+        bob,
+        &ShapeDef::builder().density(10.0).build(),
+        &shapes::box_polygon(0.5, 0.5),
+    );
+    let joint = world
+        .revolute(anchor, bob)
+        .anchor_world([0.0_f32, 5.0])
+        .soft_limits(-0.5, 0.5, 30.0, 2.0)
+        .build()
+        .id();
+
+    assert!(world.revolute_limit_enabled(joint));
+    assert!(approx_eq(world.revolute_lower_limit(joint), -0.5, 1.0e-6));
+    assert!(approx_eq(world.revolute_upper_limit(joint), 0.5, 1.0e-6));
+    assert!(approx_tuning(
+        world.joint_constraint_tuning(joint),
+        ConstraintTuning::new(30.0, 2.0),
+        1.0e-6
+    ));
+
+    for _ in 0..240 {
+        world.step(1.0 / 60.0, 4);
+    }
+    assert!(world.revolute_angle(joint) > -0.7);
+}
+
+#[test]
+fn motor_follow_tracks_a_target_pose() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, 0.0]).build()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let body = create_dynamic_body(&mut world, [1.0_f32, 0.0]);
+    let base = JointBaseBuilder::new().bodies_by_id(ground, body).build();
+    let def = MotorJointDef::new(base)
+        .max_velocity_force(1000.0)
+        .max_velocity_torque(1000.0)
+        .max_spring_force(0.0)
+        .max_spring_torque(0.0);
+    let joint = world.create_motor_joint_id(&def);
+
+    let target = Transform::from_pos_angle([3.0_f32, 1.0], 0.5);
+    let dt = 1.0 / 60.0;
+    for _ in 0..60 {
+        world.motor_follow(joint, target, dt);
+        world.step(dt, 4);
+    }
+    assert!(approx_vec2(
+        world.body_position(body),
+        target.position(),
+        0.05
+    ));
+    assert!(approx_eq(world.body_rotation(body).angle(), 0.5, 0.05));
+
+    // A weak motor cannot keep up.
+    world.motor_set_max_velocity_force(joint, 0.1);
+    let far = Transform::from_pos_angle([30.0_f32, 1.0], 0.5);
+    for _ in 0..10 {
+        world.motor_follow(joint, far, dt);
+        world.step(dt, 4);
+    }
+    assert!(world.body_position(body).x < 10.0);
+
+    assert_eq!(
+        world.try_motor_follow(joint, target, 0.0).unwrap_err(),
+        ApiError::InvalidArgument
+    );
+    let revolute = world.revolute(ground, body).build().id();
+    assert!(world.try_motor_follow(revolute, target, dt).is_err());
+}
+
+#[test]
+fn mouse_joint_drags_body_to_target() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, 0.0]).build()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let body = create_dynamic_body(&mut world, [1.0_f32, 0.0]);
+    let bodies_before = world.counters().body_count;
+    let joint = world.mouse_joint(ground, body).build().id();
+    assert!(approx_vec2(
+        world.mouse_target(joint),
+        Vec2::new(1.0, 0.0),
+        1.0e-5
+    ));
+    assert_eq!(world.joint_body_a_id(joint), ground);
+    // No hidden bodies are created.
+    assert_eq!(world.counters().body_count, bodies_before);
+
+    world.mouse_set_target(joint, [4.0_f32, 2.0]);
+    assert!(approx_vec2(
+        world.mouse_target(joint),
+        Vec2::new(4.0, 2.0),
+        1.0e-5
+    ));
+    let dt = 1.0 / 60.0;
+    for _ in 0..180 {
+        world.step(dt, 4);
+    }
+    assert!(approx_vec2(
+        world.body_position(body),
+        Vec2::new(4.0, 2.0),
+        0.05
+    ));
+
+    // A second mouse joint shares the ground body.
+    let other = create_dynamic_body(&mut world, [-1.0_f32, 0.0]);
+    let second = world
+        .mouse_joint(ground, other)
+        .target([-1.0_f32, 0.5])
+        .build_owned();
+    assert_eq!(world.joint_body_a_id(second.id()), ground);
+
+    assert_eq!(
+        world
+            .try_mouse_set_target(joint, [f32::NAN, 0.0])
+            .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+    let revolute = world.revolute(ground, body).build().id();
+    assert_eq!(
+        world.try_mouse_target(revolute).unwrap_err(),
+        ApiError::InvalidJointType
+    );
+    // Plain motor joints are not mouse joints.
+    let motor = world
+        .create_motor_joint(&MotorJointDef::new(
+            JointBase::builder().bodies_by_id(ground, other).build(),
+        ))
+        .id();
+    assert_eq!(
+        world
+            .try_mouse_set_target(motor, [0.0_f32, 0.0])
+            .unwrap_err(),
+        ApiError::InvalidJointType
+    );
+}
+
+#[test]
+fn tracks_create_sprung_driven_wheels_and_wrap_a_tread() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, -10.0]).build()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let _ = world.create_polygon_shape_for(
+        ground,
+        &ShapeDef::default(),
+        &shapes::box_polygon(50.0, 0.5),
+    );
+    let hull = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([0.0_f32, 2.0])
+            .build(),
+    );
+    let _ =
+        world.create_polygon_shape_for(hull, &ShapeDef::default(), &shapes::box_polygon(2.0, 0.4));
+    let offsets = [[-1.5_f32, -0.8], [-0.5, -0.8], [0.5, -0.8], [1.5, -0.8]];
+    let def = TracksDef::new(offsets, 0.4).max_motor_torque(400.0);
+    let tracks = Tracks::new(&mut world, hull, &def);
+    assert_eq!(tracks.wheels().len(), 4);
+    assert_eq!(tracks.joints().len(), 4);
+    assert!(approx_eq(
+        world.body_position(tracks.wheels()[3]).x,
+        1.5,
+        1e-5
+    ));
+
+    // Two flat runs plus two half-turn arcs around the end wheels.
+    let tread = tracks.tread(&world, 4);
+    assert_eq!(tread.len(), 2 * 5);
+    let top = tread.iter().map(|p| p.y).fold(f32::NEG_INFINITY, f32::max);
+    let right = tread.iter().map(|p| p.x).fold(f32::NEG_INFINITY, f32::max);
+    assert!(approx_eq(top, 1.2 + 0.4, 1e-4));
+    assert!(approx_eq(right, 1.5 + 0.4, 1e-4));
+
+    // A raised idler makes a real hull; the middle road wheel drops out as collinear.
+    let carrier = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([10.0_f32, 5.0])
+            .build(),
+    );
+    let raised = Tracks::new(
+        &mut world,
+        carrier,
+        &TracksDef::new(
+            [[-1.0_f32, -0.5], [0.0, -0.5], [1.0, -0.5], [1.5, 0.5]],
+            0.25,
+        ),
+    );
+    let tread = raised.tread(&world, 2);
+    assert_eq!(tread.len(), 3 * 3);
+    let top = tread.iter().map(|p| p.y).fold(f32::NEG_INFINITY, f32::max);
+    assert!(approx_eq(top, 5.5 + 0.25, 1e-4));
+
+    // Clockwise wheel spin drives the vehicle toward +x.
+    tracks.set_motor_speed(&mut world, -8.0);
+    for _ in 0..120 {
+        world.step(1.0 / 60.0, 4);
+    }
+    assert!(world.body_position(hull).x > 0.5);
+    assert!(
+        tracks
+            .joints()
+            .iter()
+            .all(|&j| approx_eq(world.wheel_motor_speed(j), -8.0, 1e-6))
+    );
+
+    assert_eq!(
+        Tracks::try_new(&mut world, hull, &TracksDef::new([[0.0_f32, 0.0]], -1.0)).unwrap_err(),
+        ApiError::InvalidArgument
+    );
+    assert_eq!(
+        Tracks::try_new(&mut world, hull, &TracksDef::new(Vec::<Vec2>::new(), 0.4)).unwrap_err(),
+        ApiError::InvalidArgument
+    );
+}
+
+#[test]
+fn typed_joint_views_check_the_kind_once() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let body = create_dynamic_body(&mut world, [0.0_f32, 1.0]);
+
+    let joint = world.revolute(ground, body).build();
+    let id = joint.id();
+    let joint = joint.into_prismatic().unwrap_err();
+    let mut hinge = joint.into_revolute().unwrap();
+    hinge.enable_motor(true);
+    hinge.set_motor_speed(2.0);
+    hinge.set_limits(-0.5, 0.5);
+    assert!(hinge.motor_enabled());
+    assert!(approx_eq(hinge.motor_speed(), 2.0, 1.0e-6));
+    assert!(approx_eq(hinge.upper_limit(), 0.5, 1.0e-6));
+    assert_eq!(hinge.id(), id);
+    assert_eq!(hinge.body_b_id(), body);
+    let _ = (hinge.angle(), hinge.constraint_torque());
+    hinge.set_user_data(7_u32);
+    assert_eq!(hinge.with_user_data(|v: &u32| *v), Some(7));
+    assert_eq!(hinge.take_user_data::<u32>(), Some(7));
+    assert!(approx_eq(world.revolute_motor_speed(id), 2.0, 1.0e-6));
+
+    let mut slider = world.prismatic(ground, body).build();
+    assert!(slider.as_wheel().is_none());
+    let mut view = slider.as_prismatic().unwrap();
+    view.try_set_max_motor_force(10.0).unwrap();
+    assert!(approx_eq(view.max_motor_force(), 10.0, 1.0e-6));
+}
+
+#[test]
+fn revolute_and_wheel_report_relative_motion() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, 0.0]).build()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let rotor = create_dynamic_body(&mut world, [0.0_f32, 0.0]);
+    let hinge = world.revolute(ground, rotor).build().id();
+    world.revolute_enable_motor(hinge, true);
+    world.revolute_set_max_motor_torque(hinge, 1000.0);
+    world.revolute_set_motor_speed(hinge, 3.0);
+    for _ in 0..30 {
+        world.step(1.0 / 60.0, 4);
+    }
+    assert!(approx_eq(world.revolute_speed(hinge), 3.0, 1.0e-3));
+
+    let wheel = create_dynamic_body(&mut world, [5.0_f32, -1.0]);
+    let anchor = world.create_body_id(BodyBuilder::new().position([5.0_f32, 0.0]).build());
+    let suspension = world
+        .wheel(anchor, wheel)
+        .axis_world([0.0_f32, 1.0])
+        .build()
+        .id();
+    assert!(approx_eq(world.wheel_translation(suspension), -1.0, 1.0e-5));
+    world.set_body_linear_velocity(wheel, [0.5_f32, 2.0]);
+    assert!(approx_eq(world.wheel_speed(suspension), 2.0, 1.0e-5));
+    assert!(world.try_wheel_speed(hinge).is_err());
+
+    let view = world.joint(hinge).unwrap().into_revolute().unwrap();
+    assert!(approx_eq(view.speed(), 3.0, 1.0e-3));
+}
+
+#[test]
+fn constraint_tuning_can_be_stiffened_at_runtime_and_rejects_invalid_values() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let body = create_dynamic_body(&mut world, [0.0, 2.0]);
+    let base = JointBaseBuilder::new().bodies_by_id(ground, body).build();
+    let mut joint = world.create_revolute_joint_owned(&RevoluteJointDef::new(base));
+    let id = joint.id();
+
+    let stiff = ConstraintTuning::new(240.0, 2.0);
+    world.set_joint_constraint_tuning(id, stiff);
+    assert!(approx_tuning(
+        world.joint_constraint_tuning(id),
+        stiff,
+        1.0e-6
+    ));
+    assert!(!ConstraintTuning::new(-1.0, 0.5).is_valid());
+    assert_eq!(
+        world
+            .try_set_joint_constraint_tuning(id, ConstraintTuning::new(f32::NAN, 0.5))
+            .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+    assert_eq!(
+        joint
+            .try_set_constraint_tuning(ConstraintTuning::new(60.0, -0.1))
+            .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+    assert!(approx_tuning(
+        world.joint_constraint_tuning(id),
+        stiff,
+        1.0e-6
+    ));
+}
+
+#[test]
+fn chain_rider_carries_kinematic_and_dynamic_bodies_along_a_loop() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let track = ChainDef::builder()
+        .points([[0.0_f32, 0.0], [10.0, 0.0], [10.0, 5.0], [0.0, 5.0]])
+        .is_loop(true)
+        .build();
+    let chain = world.create_chain_for_id(ground, &track);
+
+    let mut ride = ChainRider::new(&world, chain).speed(2.0);
+    assert!(ride.is_loop());
+    assert!((ride.path_length() - 30.0).abs() < 1.0e-4);
+    let (p, t) = ride.point_at(12.0);
+    assert!((p.x - 10.0).abs() < 1.0e-4 && (p.y - 2.0).abs() < 1.0e-4);
+    assert!((t.y - 1.0).abs() < 1.0e-4);
+    assert!((ride.point_at(31.0).0.x - 1.0).abs() < 1.0e-4);
+
+    let cabin = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Kinematic)
+            .position([1.0_f32, 0.5])
+            .build(),
+    );
+    let _ = world.create_polygon_shape_for(
+        cabin,
+        &ShapeDef::default(),
+        &shapes::box_polygon(0.25, 0.25),
+    );
+    ride.add(&world, cabin);
+    assert!((ride.distance(cabin).unwrap() - 1.0).abs() < 1.0e-4);
+    assert!(ride.try_add(&world, ground).is_err());
+
+    for _ in 0..60 {
+        ride.update(&mut world, 1.0 / 60.0);
+        world.step(1.0 / 60.0, 4);
+    }
+    let p = world.body_position(cabin);
+    assert!((p.x - 3.0).abs() < 0.05, "cabin x = {}", p.x);
+    assert!(
+        (p.y - 0.5).abs() < 0.05,
+        "cabin keeps its offset, y = {}",
+        p.y
+    );
+
+    let crate_body = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([5.0_f32, 5.0])
+            .gravity_scale(0.0)
+            .build(),
+    );
+    let sdef = ShapeDef::builder().density(1.0).build();
+    let _ = world.create_polygon_shape_for(crate_body, &sdef, &shapes::box_polygon(0.25, 0.25));
+    ride.add(&world, crate_body);
+    for _ in 0..30 {
+        ride.update(&mut world, 1.0 / 60.0);
+        world.step(1.0 / 60.0, 4);
+    }
+    let v = world.body_linear_velocity(crate_body);
+    assert!((v.x + 2.0).abs() < 0.1, "top edge runs -x, v = {v:?}");
+
+    world.destroy_body_id(crate_body);
+    ride.update(&mut world, 1.0 / 60.0);
+    assert_eq!(ride.riders().collect::<Vec<_>>(), vec![cabin]);
+    assert!(ride.remove(cabin));
+}
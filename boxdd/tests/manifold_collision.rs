@@ -1,9 +1,10 @@
 use boxdd::{
-    Manifold, ManifoldPoint, SimplexCache, Transform, Vec2, collide_capsule_and_circle,
-    collide_capsules, collide_chain_segment_and_capsule, collide_chain_segment_and_circle,
-    collide_chain_segment_and_polygon, collide_circles, collide_polygon_and_capsule,
-    collide_polygon_and_circle, collide_polygons, collide_segment_and_capsule,
-    collide_segment_and_circle, collide_segment_and_polygon, shapes,
+    ApiError, Manifold, ManifoldPoint, SimplexCache, Transform, Vec2, collide,
+    collide_capsule_and_circle, collide_capsules, collide_chain_segment_and_capsule,
+    collide_chain_segment_and_circle, collide_chain_segment_and_polygon, collide_circles,
+    collide_polygon_and_capsule, collide_polygon_and_circle, collide_polygons,
+    collide_segment_and_capsule, collide_segment_and_circle, collide_segment_and_polygon,
+    geometry_distance, shapes, try_collide,
 };
 
 #[test]
@@ -134,3 +135,42 @@ fn manifold_value_types_use_explicit_raw_conversions() {
     assert_eq!(raw.pointCount, 1);
     assert_eq!(Manifold::from_raw(raw), manifold);
 }
+
+#[test]
+fn geometry_dispatch_matches_pair_functions_in_either_order() {
+    let circle = shapes::circle([0.0_f32, 0.0], 0.5);
+    let floor = shapes::box_polygon(2.0, 0.5);
+    let above = Transform::from_pos_angle([0.25_f32, 0.9], 0.0);
+
+    let direct = collide_polygon_and_circle(floor, Transform::IDENTITY, circle, above);
+    let forward = collide(floor.into(), Transform::IDENTITY, circle.into(), above);
+    assert_eq!(forward.point_count, direct.point_count);
+    assert_eq!(forward.normal, direct.normal);
+
+    // Circle first: same contact, normal flipped to point from the circle to the floor.
+    let reversed = collide(circle.into(), above, floor.into(), Transform::IDENTITY);
+    assert_eq!(reversed.point_count, 1);
+    assert!((reversed.normal.y + direct.normal.y).abs() < 1e-6);
+    let (r, d) = (reversed.points()[0], direct.points()[0]);
+    assert!((r.point.x - d.point.x).abs() < 1e-5 && (r.point.y - d.point.y).abs() < 1e-5);
+    assert_eq!(r.anchor_a, d.anchor_b);
+
+    let seg = shapes::segment([-1.0_f32, 0.0], [1.0, 0.0]);
+    let m = collide(
+        seg.into(),
+        Transform::IDENTITY,
+        seg.into(),
+        Transform::IDENTITY,
+    );
+    assert_eq!(m.point_count, 0);
+
+    let apart = Transform::from_pos_angle([0.0_f32, 3.0], 0.0);
+    let d = geometry_distance(floor.into(), Transform::IDENTITY, circle.into(), apart);
+    assert!((d.distance - 2.0).abs() < 1e-4);
+
+    let bad = shapes::circle([0.0_f32, 0.0], f32::NAN);
+    assert_eq!(
+        try_collide(bad.into(), Transform::IDENTITY, circle.into(), above).unwrap_err(),
+        ApiError::InvalidArgument
+    );
+}
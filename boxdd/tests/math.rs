@@ -1,7 +1,8 @@
 use boxdd::{
-    HASH_INIT, Plane, Rot, Transform, Vec2, allocated_byte_count, atan2, compute_cos_sin,
-    hash_bytes, is_valid_float, milliseconds_and_reset, milliseconds_since,
-    rotation_between_unit_vectors, ticks, version, yield_now,
+    BodyBuilder, BodyType, HASH_INIT, MassData, Plane, Rot, ShapeDef, Transform, Vec2, World,
+    WorldDef, allocated_byte_count, atan2, compute_cos_sin, hash_bytes, is_valid_float,
+    milliseconds_and_reset, milliseconds_since, rotation_between_unit_vectors, ticks, version,
+    yield_now,
 };
 use boxdd_sys::ffi;
 
@@ -104,6 +105,18 @@ fn public_math_helpers_cover_validity_rotation_and_version() {
     assert!(v.major >= 3);
 }
 
+#[test]
+fn build_info_reports_versions_and_native_build() {
+    let info = boxdd::build_info();
+    assert_eq!(info.crate_version, env!("CARGO_PKG_VERSION"));
+    assert_eq!(info.box2d_version, version());
+    assert_eq!(info.simd_avx2, cfg!(feature = "simd-avx2"));
+    assert_eq!(info.validate, cfg!(feature = "validate"));
+    let text = info.to_string();
+    assert!(text.contains(info.crate_version));
+    assert!(text.contains(info.native_build.as_str()));
+}
+
 #[test]
 fn core_math_types_use_explicit_raw_conversions() {
     let vec = Vec2::from_raw(ffi::b2Vec2 { x: 1.25, y: -2.5 });
@@ -160,3 +173,64 @@ fn foundation_helpers_cover_alloc_timing_and_hash() {
         hash_bytes(HASH_INIT, b"boxdd")
     );
 }
+
+#[test]
+fn rot_integrate_and_transform_step_match_the_solver() {
+    let q = Rot::IDENTITY.integrate(2.0, 0.01);
+    assert!(q.is_valid());
+    assert!(approx(
+        q.cosine() * q.cosine() + q.sine() * q.sine(),
+        1.0,
+        1e-6
+    ));
+    assert!(approx(q.angle(), 0.02_f32.atan(), 1e-6));
+
+    let xf = Transform::from_pos_angle([1.0, 2.0], 0.3).step(Vec2::new(3.0, -1.0), 0.0, 0.5);
+    assert!(approx(xf.position().x, 2.5, 1e-6));
+    assert!(approx(xf.position().y, 1.5, 1e-6));
+    assert!(approx(xf.rotation().angle(), 0.3, 1e-6));
+
+    // One substep of a free body with an offset center of mass.
+    let mut world = World::new(WorldDef::builder().gravity([0.0, 0.0]).build()).unwrap();
+    let body = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([1.0, 2.0])
+            .angle(0.3)
+            .build(),
+    );
+    let _ = world.create_polygon_shape_for(
+        body,
+        &ShapeDef::builder().density(1.0).build(),
+        &boxdd::shapes::box_polygon(0.5, 0.5),
+    );
+    world.set_body_mass_data(
+        body,
+        MassData {
+            mass: 1.0,
+            center: Vec2::new(0.4, -0.2),
+            rotational_inertia: 0.5,
+        },
+    );
+    world.set_body_linear_velocity(body, [1.5, -0.5]);
+    world.set_body_angular_velocity(body, 3.0);
+    let before = world.body_transform(body);
+    let v = world.body_linear_velocity(body);
+    let w = world.body_angular_velocity(body);
+    let dt = 1.0 / 60.0;
+    world.step(dt, 1);
+    let predicted = before.step_about(Vec2::new(0.4, -0.2), v, w, dt);
+    let actual = world.body_transform(body);
+    assert!(approx(predicted.position().x, actual.position().x, 1e-5));
+    assert!(approx(predicted.position().y, actual.position().y, 1e-5));
+    assert!(approx(
+        predicted.rotation().cosine(),
+        actual.rotation().cosine(),
+        1e-5
+    ));
+    assert!(approx(
+        predicted.rotation().sine(),
+        actual.rotation().sine(),
+        1e-5
+    ));
+}
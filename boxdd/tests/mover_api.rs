@@ -1,4 +1,8 @@
-use boxdd::{clip_vector, prelude::*, shapes, solve_planes, try_clip_vector, try_solve_planes};
+use boxdd::character::{CharacterDef, CharacterMover};
+use boxdd::{
+    CapsuleResizeError, clip_vector, prelude::*, shapes, solve_planes, try_clip_vector,
+    try_solve_planes,
+};
 
 #[test]
 fn mover_queries_and_solver_are_safe_and_reusable() {
@@ -115,3 +119,100 @@ fn mover_solver_validation_and_try_paths_are_recoverable() {
         ApiError::InvalidArgument
     );
 }
+
+#[test]
+fn resize_capsule_checks_headroom_before_standing() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, -10.0]).build()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let floor = world.create_polygon_shape_for(
+        ground,
+        &ShapeDef::default(),
+        &shapes::offset_box_polygon(5.0, 0.5, Transform::from_pos_angle([0.0, -0.5], 0.0)),
+    );
+    let ceiling = world.create_polygon_shape_for(
+        ground,
+        &ShapeDef::default(),
+        &shapes::offset_box_polygon(5.0, 0.5, Transform::from_pos_angle([0.0, 2.0], 0.0)),
+    );
+    let player = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([0.0_f32, 0.0])
+            .build(),
+    );
+    let capsule = world.create_capsule_shape_for(
+        player,
+        &ShapeDef::default(),
+        &shapes::capsule([0.0, 0.3], [0.0, 1.1], 0.3),
+    );
+
+    // Crouching is never blocked, and the foot stays on the ground.
+    world
+        .resize_capsule(capsule, 0.2, QueryFilter::default())
+        .unwrap();
+    let c = world.shape(capsule).unwrap().capsule();
+    assert_eq!(c.center1, Vec2::new(0.0, 0.3));
+    assert!((c.center2.y - 0.7).abs() < 1.0e-5);
+
+    // Standing back up to the original height still fits under the ceiling at y = 1.5.
+    world
+        .resize_capsule(capsule, 0.4, QueryFilter::default())
+        .unwrap();
+
+    // Growing into the ceiling is refused and leaves the capsule unchanged.
+    let blocked = world.try_resize_capsule(capsule, 1.0, QueryFilter::default());
+    assert_eq!(blocked, Err(CapsuleResizeError::Blocked(vec![ceiling])));
+    assert!((world.shape(capsule).unwrap().capsule().center2.y - 1.1).abs() < 1.0e-5);
+
+    assert_eq!(
+        world.try_resize_capsule(floor, 1.0, QueryFilter::default()),
+        Err(CapsuleResizeError::Api(ApiError::InvalidArgument))
+    );
+}
+
+#[test]
+fn character_mover_slides_along_ground_and_stops_at_walls() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let ground_shape = world.create_polygon_shape_for(
+        ground,
+        &ShapeDef::default(),
+        &shapes::box_polygon(20.0, 0.5),
+    );
+    let wall = world.create_body_id(BodyBuilder::new().position([3.0_f32, 2.0]).build());
+    let _wall_shape =
+        world.create_polygon_shape_for(wall, &ShapeDef::default(), &shapes::box_polygon(0.5, 2.0));
+
+    let capsule = shapes::capsule([0.0_f32, -0.5], [0.0, 0.5], 0.3);
+    let mut mover = CharacterMover::new(CharacterDef::new(capsule).position([0.0_f32, 1.4]));
+
+    let result = mover.move_and_slide(&world, [1.0_f32, -1.0]);
+    assert!((result.position.x - 1.0).abs() < 0.05, "{result:?}");
+    assert!(
+        result.position.y > 1.25 && result.position.y < 1.4,
+        "{result:?}"
+    );
+    assert!(result.on_ground(Vec2::new(0.0, 1.0), 0.7));
+    assert!(result.touched_shapes().any(|s| s == ground_shape));
+    let clipped = result.clip([0.0_f32, -5.0]);
+    assert!(clipped.y.abs() < 1.0e-3);
+
+    let result = mover.try_move_and_slide(&world, [5.0_f32, 0.0]).unwrap();
+    assert!(result.position.x <= 2.2 + 0.05, "{result:?}");
+    assert_eq!(mover.position(), result.position);
+
+    assert_eq!(
+        mover
+            .try_move_and_slide(&world, [f32::NAN, 0.0])
+            .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+    assert!(
+        CharacterMover::try_new(CharacterDef::new(shapes::capsule(
+            [0.0_f32, 0.0],
+            [0.0, 1.0],
+            0.0
+        )))
+        .is_err()
+    );
+}
@@ -0,0 +1,80 @@
+use boxdd::net::{
+    QuantizedTransform, dequantize_angle, dequantize_delta, dequantize_transform, quantize_angle,
+    quantize_delta, quantize_transform,
+};
+use boxdd::{BodyBuilder, BodyType, Transform, World, WorldDef};
+
+fn approx(a: f32, b: f32, tol: f32) -> bool {
+    (a - b).abs() <= tol
+}
+
+#[test]
+fn quantized_transforms_round_trip_exactly() {
+    let precision = 0.01;
+    let t = Transform::from_pos_angle([12.34, -6.789], 1.0);
+    let q = quantize_transform(t, precision).unwrap();
+    assert_eq!((q.x, q.y), (1234, -679));
+    let back = dequantize_transform(q, precision);
+    assert!(approx(back.position().x, 12.34, 1e-4));
+    assert!(approx(
+        back.rotation().angle(),
+        1.0,
+        core::f32::consts::TAU / 512.0
+    ));
+    // Quantizing a dequantized pose is stable.
+    assert_eq!(quantize_transform(back, precision), Some(q));
+
+    assert_eq!(quantize_angle(0.0), 0);
+    assert_eq!(quantize_angle(-core::f32::consts::FRAC_PI_2), 192);
+    assert!(approx(
+        dequantize_angle(192),
+        -core::f32::consts::FRAC_PI_2,
+        1e-6
+    ));
+    assert!(approx(dequantize_angle(128), core::f32::consts::PI, 1e-6));
+
+    // Far from the origin: a full state does not fit, a delta does.
+    let far = Transform::from_pos_angle([1000.0, 0.0], 0.0);
+    assert_eq!(quantize_transform(far, precision), None);
+    let base = Transform::from_pos_angle([999.0, 0.5], 3.0);
+    let delta = quantize_delta(base, far, precision).unwrap();
+    assert_eq!(
+        delta,
+        QuantizedTransform {
+            x: 100,
+            y: -50,
+            angle: quantize_angle(-3.0),
+        }
+    );
+    let restored = dequantize_delta(base, delta, precision);
+    assert!(approx(restored.position().x, 1000.0, 1e-3));
+    assert!(approx(restored.rotation().sine(), 0.0, 0.02));
+}
+
+#[test]
+fn apply_network_state_smooths_toward_the_corrected_pose() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let body = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+    let precision = 0.05;
+    let target = Transform::from_pos_angle([2.0, -1.0], 0.0);
+    let state = quantize_transform(target, precision).unwrap();
+
+    world.apply_network_state(body, state, precision, 0.5);
+    let p = world.body_position(body);
+    assert!(approx(p.x, 1.0, 1e-5) && approx(p.y, -0.5, 1e-5));
+
+    world.apply_network_state(body, state, precision, 0.0);
+    let p = world.body_position(body);
+    assert!(approx(p.x, 2.0, 1e-5) && approx(p.y, -1.0, 1e-5));
+
+    assert!(
+        world
+            .try_apply_network_state(body, state, 0.0, 0.5)
+            .is_err()
+    );
+    assert!(
+        world
+            .try_apply_network_transform(body, target, 1.5)
+            .is_err()
+    );
+}
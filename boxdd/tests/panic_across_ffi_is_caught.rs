@@ -1,4 +1,5 @@
 use boxdd::prelude::*;
+#[cfg(feature = "debug-draw")]
 use boxdd_sys::ffi;
 
 #[test]
@@ -29,6 +30,32 @@ fn custom_filter_panic_is_caught_and_resumed_after_step() {
     assert!(world.try_body_position(b).is_ok());
 }
 
+#[test]
+fn try_step_reports_callback_panic_without_unwinding() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0, 0.0]).build()).unwrap();
+    world.set_custom_filter(|_, _| -> bool {
+        panic!("boom in custom filter");
+    });
+
+    let a = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+    let b = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+    let sdef = ShapeDef::builder()
+        .density(1.0)
+        .enable_custom_filtering(true)
+        .build();
+    let poly = shapes::box_polygon(0.5, 0.5);
+    let _ = world.create_polygon_shape_for(a, &sdef, &poly);
+    let _ = world.create_polygon_shape_for(b, &sdef, &poly);
+
+    assert_eq!(
+        world.try_step(1.0 / 60.0, 1),
+        Err(StepError::CallbackPanicked("boom in custom filter".into()))
+    );
+
+    world.clear_custom_filter();
+    assert_eq!(world.try_step(1.0 / 60.0, 1), Ok(()));
+}
+
 #[test]
 fn pre_solve_panic_is_caught_and_resumed_after_step() {
     let mut world = World::new(WorldDef::builder().gravity([0.0, 0.0]).build()).unwrap();
@@ -52,6 +79,7 @@ fn pre_solve_panic_is_caught_and_resumed_after_step() {
     assert!(r.is_err());
 }
 
+#[cfg(feature = "debug-draw")]
 #[test]
 fn debug_draw_panic_is_caught_and_resumed() {
     struct Panicker;
@@ -79,6 +107,7 @@ fn debug_draw_panic_is_caught_and_resumed() {
     assert!(r.is_err());
 }
 
+#[cfg(feature = "debug-draw")]
 #[test]
 fn debug_draw_raw_panic_is_caught_and_resumed() {
     struct Panicker;
@@ -106,6 +135,7 @@ fn debug_draw_raw_panic_is_caught_and_resumed() {
     assert!(r.is_err());
 }
 
+#[cfg(feature = "debug-draw")]
 #[test]
 fn debug_draw_reentrant_boxdd_call_panics() {
     struct Reenter {
@@ -137,6 +167,7 @@ fn debug_draw_reentrant_boxdd_call_panics() {
     assert!(r.is_err());
 }
 
+#[cfg(feature = "debug-draw")]
 #[test]
 fn debug_draw_reentrant_try_boxdd_call_returns_in_callback() {
     struct ReenterTry {
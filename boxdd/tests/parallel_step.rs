@@ -0,0 +1,166 @@
+#![cfg(feature = "parallel")]
+
+use boxdd::world::Error as WorldError;
+use boxdd::{
+    BodyBuilder, BodyId, BodyType, PendingTask, RayonTaskSystem, ShapeDef, TaskRange, TaskSystem,
+    Vec2, World, WorldDef, shapes,
+};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// The `benchmark` example's box stack.
+fn benchmark_scene(def: WorldDef) -> (World, Vec<BodyId>) {
+    let mut world = World::new(def).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    world.create_segment_shape_for(
+        ground,
+        &ShapeDef::default(),
+        &shapes::segment([-100.0_f32, 0.0], [100.0, 0.0]),
+    );
+    let box_poly = shapes::box_polygon(0.5, 0.5);
+    let sdef = ShapeDef::builder().density(1.0).build();
+    let (cols, rows) = (25, 15);
+    let mut bodies = Vec::new();
+    for i in 0..rows {
+        for j in 0..cols {
+            let body = world.create_body_id(
+                BodyBuilder::new()
+                    .body_type(BodyType::Dynamic)
+                    .position([
+                        -(cols as f32) * 0.55 + j as f32 * 1.1,
+                        2.5 + i as f32 * 1.05,
+                    ])
+                    .build(),
+            );
+            world.create_polygon_shape_for(body, &sdef, &box_poly);
+            bodies.push(body);
+        }
+    }
+    (world, bodies)
+}
+
+fn run(def: WorldDef, steps: usize) -> Vec<Vec2> {
+    let (mut world, bodies) = benchmark_scene(def);
+    for _ in 0..steps {
+        world.step(1.0 / 60.0, 8);
+    }
+    bodies.iter().map(|&b| world.body_position(b)).collect()
+}
+
+/// Wall time of `steps` steps of the benchmark scene, excluding setup.
+fn time_steps(def: WorldDef, steps: usize) -> Duration {
+    let (mut world, _) = benchmark_scene(def);
+    let start = Instant::now();
+    for _ in 0..steps {
+        world.step(1.0 / 60.0, 8);
+    }
+    start.elapsed()
+}
+
+/// Forwards to a rayon pool and counts the stages it was handed.
+struct CountingPool {
+    pool: RayonTaskSystem,
+    stages: AtomicUsize,
+}
+
+unsafe impl TaskSystem for CountingPool {
+    fn worker_count(&self) -> usize {
+        self.pool.worker_count()
+    }
+
+    fn parallel_for(&self, item_count: u32, min_range: u32, task: &TaskRange<'_>) {
+        self.stages.fetch_add(1, Ordering::Relaxed);
+        self.pool.parallel_for(item_count, min_range, task);
+    }
+
+    fn spawn(&self, task: TaskRange<'_>) -> Option<PendingTask> {
+        self.stages.fetch_add(1, Ordering::Relaxed);
+        self.pool.spawn(task)
+    }
+
+    fn attach_world(&self) -> bool {
+        self.pool.attach_world()
+    }
+
+    fn detach_world(&self) {
+        self.pool.detach_world();
+    }
+}
+
+#[test]
+fn worker_count_installs_a_rayon_pool() {
+    let world = World::new(WorldDef::builder().worker_count(3).build()).unwrap();
+    assert_eq!(world.task_system_worker_count(), Some(3));
+
+    let serial = World::new(WorldDef::default()).unwrap();
+    assert_eq!(serial.task_system_worker_count(), None);
+}
+
+#[test]
+fn parallel_stepping_matches_serial_stepping() {
+    // Box2D's results do not depend on the worker count.
+    let serial = run(WorldDef::default(), 120);
+    let parallel = run(WorldDef::builder().worker_count(4).build(), 120);
+    assert_eq!(serial, parallel);
+
+    let counting = Arc::new(CountingPool {
+        pool: RayonTaskSystem::new(4).unwrap(),
+        stages: AtomicUsize::new(0),
+    });
+    let counted = run(
+        WorldDef::builder()
+            .task_system_arc(counting.clone())
+            .build(),
+        120,
+    );
+    assert_eq!(serial, counted);
+    assert!(counting.stages.load(Ordering::Relaxed) > 0);
+}
+
+#[test]
+fn rayon_pool_serves_one_world_at_a_time() {
+    let shared = Arc::new(RayonTaskSystem::new(2).unwrap());
+    let first = World::new(WorldDef::builder().task_system_arc(shared.clone()).build()).unwrap();
+    assert!(matches!(
+        World::new(WorldDef::builder().task_system_arc(shared.clone()).build()),
+        Err(WorldError::TaskSystemInUse)
+    ));
+    drop(first);
+
+    // Reusable once the first world is gone.
+    let a = run(
+        WorldDef::builder().task_system_arc(shared.clone()).build(),
+        60,
+    );
+    let b = run(WorldDef::builder().task_system_arc(shared).build(), 60);
+    assert_eq!(a, b);
+}
+
+/// Timing-sensitive, so opt-in and best run in release:
+/// `cargo test --release --features parallel --test parallel_step -- --ignored --nocapture`.
+#[test]
+#[ignore]
+fn parallel_stepping_speeds_up_the_benchmark_scene() {
+    let workers = std::thread::available_parallelism()
+        .map_or(1, |n| n.get())
+        .min(8);
+    if workers < 2 {
+        eprintln!("skipping: a single hardware thread cannot show a speedup");
+        return;
+    }
+    let steps = 600;
+    // Warm up caches and the rayon pool's threads before measuring.
+    time_steps(WorldDef::builder().worker_count(workers as i32).build(), 60);
+    let serial = time_steps(WorldDef::builder().worker_count(1).build(), steps);
+    let parallel = time_steps(
+        WorldDef::builder().worker_count(workers as i32).build(),
+        steps,
+    );
+    let speedup = serial.as_secs_f64() / parallel.as_secs_f64();
+    eprintln!("{steps} steps: serial {serial:?}, {workers} workers {parallel:?} ({speedup:.2}x)");
+    assert!(
+        speedup > 1.0,
+        "{workers} workers were no faster than one: {speedup:.2}x"
+    );
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "particles")]
+
+use boxdd::particles::{ParticleSystem, ParticleSystemDef};
+use boxdd::{Aabb, ApiError, BodyBuilder, BodyType, ShapeDef, Vec2, World, WorldDef, shapes};
+
+fn world_with_ground() -> World {
+    let mut world = World::new(WorldDef::builder().gravity([0.0, -10.0]).build()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let _ = world.create_polygon_shape_for(
+        ground,
+        &ShapeDef::default(),
+        &shapes::box_polygon(5.0, 0.5),
+    );
+    world
+}
+
+#[test]
+fn particles_settle_on_ground() {
+    let mut world = world_with_ground();
+    let mut water = ParticleSystem::new(ParticleSystemDef::default());
+    let spawned = water.spawn_grid(Aabb::new([-0.5, 1.0], [0.5, 1.5]), [0.0, 0.0]);
+    assert!(spawned > 0);
+    assert_eq!(water.len(), spawned);
+
+    let dt = 1.0 / 60.0;
+    for _ in 0..180 {
+        world.step(dt, 4);
+        water.step(&mut world, dt);
+    }
+    let radius = water.def().radius;
+    for p in water.positions() {
+        assert!(p.x.is_finite() && p.y.is_finite());
+        // Ground top is at y = 0.5; allow a little solver slop.
+        assert!(
+            p.y > 0.5 + radius * 0.5,
+            "particle fell through ground: {p:?}"
+        );
+    }
+}
+
+#[test]
+fn particles_push_dynamic_bodies() {
+    let mut world = world_with_ground();
+    let body = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([0.0, 1.0])
+            .gravity_scale(0.0)
+            .build(),
+    );
+    let _ = world.create_polygon_shape_for(
+        body,
+        &ShapeDef::builder().density(1.0).build(),
+        &shapes::box_polygon(0.25, 0.25),
+    );
+
+    let mut water = ParticleSystem::new(ParticleSystemDef::default());
+    for i in 0..10 {
+        water.spawn([-0.6, 0.9 + i as f32 * 0.02], [5.0, 0.0]);
+    }
+    let dt = 1.0 / 60.0;
+    for _ in 0..10 {
+        world.step(dt, 4);
+        water.step(&mut world, dt);
+    }
+    assert!(world.body_linear_velocity(body).x > 0.0);
+}
+
+#[cfg(feature = "force-tracking")]
+#[test]
+fn particle_pushes_show_up_in_applied_forces() {
+    let mut world = world_with_ground();
+    let body = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([0.0, 1.0])
+            .gravity_scale(0.0)
+            .build(),
+    );
+    let _ = world.create_polygon_shape_for(
+        body,
+        &ShapeDef::builder().density(1.0).build(),
+        &shapes::box_polygon(0.25, 0.25),
+    );
+
+    let mut water = ParticleSystem::new(ParticleSystemDef::default());
+    for i in 0..10 {
+        water.spawn([-0.6, 0.9 + i as f32 * 0.02], [5.0, 0.0]);
+    }
+    let dt = 1.0 / 60.0;
+    let mut pushed = 0.0;
+    for _ in 0..10 {
+        water.step(&mut world, dt);
+        world.step(dt, 4);
+        pushed += world.applied_forces(body).linear_impulse.x;
+    }
+    assert!(pushed > 0.0);
+}
+
+#[test]
+fn particle_system_validates_inputs() {
+    let bad = ParticleSystemDef::default().radius(0.0);
+    assert_eq!(
+        ParticleSystem::try_new(bad).unwrap_err(),
+        ApiError::InvalidArgument
+    );
+    let too_small_kernel = ParticleSystemDef::default()
+        .radius(0.1)
+        .smoothing_radius(0.15);
+    assert!(too_small_kernel.validate().is_err());
+
+    let mut world = world_with_ground();
+    let mut water = ParticleSystem::new(ParticleSystemDef::default());
+    water.spawn(Vec2::new(0.0, 2.0), [0.0, 0.0]);
+    assert_eq!(
+        water.try_step(&mut world, f32::NAN).unwrap_err(),
+        ApiError::InvalidArgument
+    );
+    water.retain(|p, _| p.y < 0.0);
+    assert!(water.is_empty());
+}
@@ -4,14 +4,23 @@ use boxdd::prelude::*;
 fn try_step_invalid_values_return_err() {
     let mut world = World::new(WorldDef::default()).unwrap();
 
-    assert_eq!(
+    assert!(matches!(
         world.try_step(f32::NAN, 4).unwrap_err(),
-        ApiError::InvalidArgument
+        StepError::InvalidTimeStep(dt) if dt.is_nan()
+    ));
+    assert_eq!(
+        world.try_step(-1.0, 4).unwrap_err(),
+        StepError::InvalidTimeStep(-1.0)
     );
     assert_eq!(
         world.try_step(1.0 / 60.0, 0).unwrap_err(),
+        StepError::InvalidSubSteps(0)
+    );
+    assert_eq!(
+        ApiError::from(StepError::InvalidSubSteps(0)),
         ApiError::InvalidArgument
     );
+    world.try_step(0.0, 1).unwrap();
 }
 
 #[test]
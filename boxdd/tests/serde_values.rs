@@ -1,6 +1,9 @@
 #![cfg(feature = "serde")]
 
-use boxdd::{Aabb, QueryFilter, Vec2};
+use boxdd::{
+    Aabb, BodyId, CollisionCategory, Filter, JointBase, MotorJointDef, QueryFilter,
+    RevoluteJointDef, Vec2, WeldJointDef, WheelJointDef,
+};
 
 #[test]
 fn aabb_serde_roundtrip() {
@@ -18,3 +21,64 @@ fn query_filter_serde_roundtrip() {
     assert_eq!(q.category_bits(), q2.category_bits());
     assert_eq!(q.mask_bits(), q2.mask_bits());
 }
+
+#[test]
+fn collision_category_serde_is_plain_bits() {
+    let c = CollisionCategory::bit(0) | CollisionCategory::bit(4);
+    assert_eq!(serde_json::to_string(&c).unwrap(), "17");
+    let back: CollisionCategory = serde_json::from_str("17").unwrap();
+    assert_eq!(back, c);
+
+    let f = Filter::new().category(c).mask(!c).group(-2);
+    let s = serde_json::to_string(&f).unwrap();
+    let g: Filter = serde_json::from_str(&s).unwrap();
+    assert_eq!(f, g);
+}
+
+#[test]
+fn joint_defs_serde_roundtrip() {
+    let a = BodyId {
+        index1: 3,
+        world0: 0,
+        generation: 1,
+    };
+    let b = BodyId {
+        index1: 7,
+        world0: 0,
+        generation: 2,
+    };
+    let base = JointBase::builder()
+        .bodies_by_id(a, b)
+        .local_frames([0.5, 0.0], 0.25, [-0.5, 0.0], 0.0)
+        .collide_connected(true)
+        .force_threshold(100.0)
+        .build();
+
+    let revolute = RevoluteJointDef::new(base.clone())
+        .enable_limit(true)
+        .lower_angle(-0.5)
+        .upper_angle(0.75)
+        .enable_motor(true)
+        .motor_speed(2.0);
+    let s = serde_json::to_string(&revolute).unwrap();
+    let back: RevoluteJointDef = serde_json::from_str(&s).unwrap();
+    assert_eq!(serde_json::to_string(&back).unwrap(), s);
+    assert_eq!(back.base().body_b_id(), b);
+    assert!(back.base().collide_connected());
+    assert!(back.validate().is_ok());
+
+    let weld = WeldJointDef::new(base.clone()).linear_hertz(5.0);
+    let s = serde_json::to_string(&weld).unwrap();
+    let back: WeldJointDef = serde_json::from_str(&s).unwrap();
+    assert_eq!(back.configured_linear_hertz(), 5.0);
+
+    let motor = MotorJointDef::new(base.clone()).linear_velocity([1.0, -2.0]);
+    let s = serde_json::to_string(&motor).unwrap();
+    let back: MotorJointDef = serde_json::from_str(&s).unwrap();
+    assert_eq!(serde_json::to_string(&back).unwrap(), s);
+
+    let wheel = WheelJointDef::new(base).enable_spring(true).hertz(4.0);
+    let s = serde_json::to_string(&wheel).unwrap();
+    let back: WheelJointDef = serde_json::from_str(&s).unwrap();
+    assert_eq!(serde_json::to_string(&back).unwrap(), s);
+}
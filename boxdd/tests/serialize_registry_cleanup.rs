@@ -163,3 +163,54 @@ fn chain_records_use_crate_owned_values_and_material_variants() {
         other => panic!("expected multiple materials record, got {other:?}"),
     }
 }
+
+#[test]
+fn body_ids_are_sorted_after_slot_reuse() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let bodies: Vec<BodyId> = (0..4)
+        .map(|_| world.create_body_id(BodyBuilder::new().build()))
+        .collect();
+    world.destroy_body_id(bodies[1]);
+    // The freed slot is reused, so creation order no longer matches slot order.
+    let reused = world.create_body_id(BodyBuilder::new().build());
+    let late = world.create_body_id(BodyBuilder::new().build());
+
+    let ids = world.sorted_body_ids();
+    assert_eq!(ids.len(), 5);
+    assert!(ids.windows(2).all(|w| w[0] < w[1]));
+    assert_eq!(ids, world.body_ids());
+    assert!(ids.contains(&reused) && ids.contains(&late));
+    assert!(!ids.contains(&bodies[1]));
+}
+
+#[test]
+fn chain_ids_and_chain_of_shape_track_live_chains() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let body = world.create_body_id(BodyBuilder::new().build());
+    let chain_def = boxdd::shapes::chain::ChainDef::builder()
+        .points([[0.0, 0.0], [1.0, 0.0], [2.0, 0.0], [3.0, 0.0]])
+        .build();
+    let first = world.create_chain_for_id(body, &chain_def);
+    let second = world.create_chain_for_id(body, &chain_def);
+    let circle = world.create_circle_shape_for(
+        body,
+        &ShapeDef::default(),
+        &shapes::circle([0.0_f32, 5.0], 0.25),
+    );
+
+    let mut ids = world.chain_ids();
+    assert_eq!(ids.len(), 2);
+    assert!(ids.contains(&first) && ids.contains(&second));
+    assert!(ids.windows(2).all(|w| w[0] < w[1]));
+
+    let hit = world.cast_ray_closest([2.5, 1.0], [0.0, -2.0], QueryFilter::default());
+    assert!(hit.hit);
+    let owner = world.chain_of_shape(hit.shape_id).unwrap();
+    assert!(owner == first || owner == second);
+    assert_eq!(world.chain_of_shape(circle), None);
+
+    world.destroy_chain_id(first);
+    world.chain_ids_into(&mut ids);
+    assert_eq!(ids, vec![second]);
+    assert_eq!(world.try_chain_ids().unwrap(), vec![second]);
+}
@@ -24,7 +24,7 @@ fn scene_roundtrip_basic() {
     let json = serde_json::to_string(&scene).expect("serialize scene");
     let back: boxdd::serialize::SceneSnapshot =
         serde_json::from_str(&json).expect("deserialize scene");
-    let world2 = back.rebuild();
+    let (world2, _) = back.rebuild();
     let round = boxdd::serialize::SceneSnapshot::take(&world2);
 
     assert_eq!(scene.bodies.len(), round.bodies.len(), "bodies len");
@@ -116,3 +116,272 @@ fn shape_flags_snapshot_recorded_for_scoped_shapes() {
     }
     assert!(found, "did not find circle shape with expected flags");
 }
+
+#[test]
+fn scene_extensions_roundtrip_through_take_and_rebuild() {
+    use boxdd::serialize::{RebuildEntry, SceneSnapshot, SnapshotEntry};
+
+    let mut world = World::new(WorldDef::default()).expect("create world");
+    let player = world.create_body_id(BodyBuilder::new().position([0.0, 1.0]).build());
+    let sdef = ShapeDef::builder().density(1.0).build();
+    let hitbox = world.create_polygon_shape_for(player, &sdef, &shapes::box_polygon(0.5, 0.5));
+
+    let scene = SceneSnapshot::take_with(&world, |entry| match entry {
+        SnapshotEntry::Body { id, extensions } if id == player => {
+            extensions.insert("entity".into(), serde_json::json!("player"));
+        }
+        SnapshotEntry::Shape { id, extensions, .. } if id == hitbox => {
+            extensions.insert("health".into(), serde_json::json!(42));
+        }
+        SnapshotEntry::Scene { extensions } => {
+            extensions.insert("level".into(), serde_json::json!(3));
+        }
+        _ => {}
+    });
+
+    let json = serde_json::to_string(&scene).expect("serialize scene");
+    let back: SceneSnapshot = serde_json::from_str(&json).expect("deserialize scene");
+    assert_eq!(back.extensions["level"], 3);
+
+    let mut entity_body = None;
+    let mut health = None;
+    let _ = back.rebuild_with(|entry| match entry {
+        RebuildEntry::Body { id, record, .. } => {
+            if record.extensions.get("entity") == Some(&serde_json::json!("player")) {
+                entity_body = Some(id);
+            }
+        }
+        RebuildEntry::Shape { record, .. } => {
+            health = record.extensions.get("health").and_then(|v| v.as_i64());
+        }
+        RebuildEntry::Scene { .. } => {}
+    });
+    assert!(entity_body.is_some());
+    assert_eq!(health, Some(42));
+}
+
+#[test]
+fn rebuild_map_links_snapshot_indices_to_new_ids() {
+    let mut world = World::new(WorldDef::default()).expect("create world");
+    let a = world.create_body_id(BodyBuilder::new().position([-1.0, 2.0]).build());
+    let b = world.create_body_id(BodyBuilder::new().position([1.0, 2.0]).build());
+    let _ = world.create_revolute_joint_world_id(a, b, [0.0, 2.0]);
+    let chain_def = boxdd::shapes::chain::ChainDef::builder()
+        .points([[-1.0_f32, 0.0], [0.0, 0.5], [1.0, 0.0], [2.0, 0.0]])
+        .build();
+    let _ = world.create_chain_for_id(a, &chain_def);
+
+    let scene = boxdd::serialize::SceneSnapshot::take(&world);
+    let (world2, map) = scene.rebuild();
+
+    assert_eq!(map.bodies.len(), scene.bodies.len());
+    assert_eq!(map.joints.len(), scene.joints.len());
+    assert_eq!(map.chains.len(), scene.chains.len());
+    for (i, record) in scene.bodies.iter().enumerate() {
+        let id = map.body(i).expect("body rebuilt");
+        assert_eq!(map.body_index(id), Some(i));
+        assert_eq!(world2.body_position(id), record.def.position());
+    }
+    let joint = map.joint(0).expect("joint rebuilt");
+    let jr = &scene.joints[0];
+    assert_eq!(
+        world2.joint_body_a_id(joint),
+        map.body(jr.body_a as usize).unwrap()
+    );
+    assert!(map.chain(0).is_some());
+    assert!(map.joint(99).is_none());
+}
+
+#[test]
+fn snapshot_options_capture_touching_contacts() {
+    use boxdd::serialize::{SceneSnapshot, SnapshotOptions};
+
+    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, -10.0]).build()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let sdef = ShapeDef::builder().density(1.0).build();
+    world.create_polygon_shape_for(ground, &sdef, &shapes::box_polygon(5.0, 0.5));
+    let boxb = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(boxdd::BodyType::Dynamic)
+            .position([0.0, 1.0])
+            .build(),
+    );
+    world.create_polygon_shape_for(boxb, &sdef, &shapes::box_polygon(0.5, 0.5));
+    for _ in 0..60 {
+        world.step(1.0 / 60.0, 4);
+    }
+
+    let plain = SceneSnapshot::take(&world);
+    assert!(plain.contacts.is_empty());
+
+    let scene = SceneSnapshot::take_with_options(
+        &world,
+        SnapshotOptions::new().include_contacts(true),
+        |_| {},
+    );
+    assert_eq!(scene.contacts.len(), 1);
+    let contact = &scene.contacts[0];
+    assert_ne!(contact.shape_a.body, contact.shape_b.body);
+    assert!(contact.manifold.point_count > 0);
+
+    let json = serde_json::to_string(&scene).unwrap();
+    let back: SceneSnapshot = serde_json::from_str(&json).unwrap();
+    assert_eq!(back.contacts.len(), 1);
+}
+
+#[test]
+fn markers_roundtrip_through_scene_snapshot() {
+    let mut world = World::new(WorldDef::default()).expect("create world");
+    world.set_marker(boxdd::Marker::at("player_start", [1.0, 2.0]).tag("spawn"));
+    world.set_marker(
+        boxdd::Marker::new("exit", boxdd::Transform::from_pos_angle([10.0, 0.0], 0.5))
+            .tag("door")
+            .tag("door"),
+    );
+    world.set_marker(boxdd::Marker::at("enemy_1", [5.0, 0.0]).tag("spawn"));
+    // Replacing by name keeps the slot.
+    world.set_marker(boxdd::Marker::at("player_start", [0.0, 3.0]).tag("spawn"));
+    assert_eq!(world.markers().len(), 3);
+    assert_eq!(world.marker("exit").unwrap().tags, vec!["door".to_string()]);
+
+    let scene = boxdd::serialize::SceneSnapshot::take(&world);
+    let json = serde_json::to_string(&scene).expect("serialize scene");
+    let back: boxdd::serialize::SceneSnapshot =
+        serde_json::from_str(&json).expect("deserialize scene");
+    let (mut rebuilt, _) = back.rebuild();
+
+    let names: Vec<String> = rebuilt.markers().into_iter().map(|m| m.name).collect();
+    assert_eq!(names, ["player_start", "exit", "enemy_1"]);
+    assert_eq!(
+        rebuilt.marker("player_start").unwrap().position(),
+        Vec2::new(0.0, 3.0)
+    );
+    let exit = rebuilt.marker("exit").unwrap();
+    assert!((exit.transform.rotation().angle() - 0.5).abs() < 1.0e-6);
+    assert_eq!(rebuilt.markers_with_tag("spawn").len(), 2);
+
+    assert!(rebuilt.remove_marker("exit").is_some());
+    assert!(rebuilt.marker("exit").is_none());
+    rebuilt.clear_markers();
+    assert!(rebuilt.markers().is_empty());
+}
+
+#[test]
+fn scene_snapshot_restores_sleep_locks_mass_and_sensor_overlaps() {
+    use boxdd::serialize::{SceneSnapshot, SnapshotOptions};
+
+    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, 0.0]).build()).unwrap();
+    let sdef = ShapeDef::builder().density(1.0).build();
+    let sleeper = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(boxdd::BodyType::Dynamic)
+            .position([-5.0, 0.0])
+            .bullet(true)
+            .build(),
+    );
+    world.create_polygon_shape_for(sleeper, &sdef, &shapes::box_polygon(0.5, 0.5));
+    world.set_body_sleep_threshold(sleeper, 0.25);
+    world.set_body_motion_locks(sleeper, boxdd::MotionLocks::new(false, false, true));
+    let mut mass = world.body_mass_data(sleeper);
+    mass.mass = 7.0;
+    world.set_body_mass_data(sleeper, mass);
+    world.set_body_awake(sleeper, false);
+
+    let sensor_body = world.create_body_id(BodyBuilder::new().position([5.0, 0.0]).build());
+    let sensor = world.create_circle_shape_for(
+        sensor_body,
+        &ShapeDef::builder().sensor(true).build(),
+        &shapes::circle([0.0_f32, 0.0], 2.0),
+    );
+    let visitor = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(boxdd::BodyType::Dynamic)
+            .position([5.0, 0.0])
+            .build(),
+    );
+    world.create_circle_shape_for(
+        visitor,
+        &ShapeDef::builder()
+            .density(1.0)
+            .enable_sensor_events(true)
+            .build(),
+        &shapes::circle([0.0_f32, 0.0], 0.5),
+    );
+    world.step(1.0 / 60.0, 4);
+    assert_eq!(world.shape_sensor_overlaps(sensor).len(), 1);
+
+    let scene = SceneSnapshot::take_with_options(&world, SnapshotOptions::full(), |_| {});
+    assert_eq!(scene.sensor_overlaps.len(), 1);
+    assert_eq!(scene.sensor_overlaps[0].visitors.len(), 1);
+
+    let json = serde_json::to_string(&scene).unwrap();
+    let back: SceneSnapshot = serde_json::from_str(&json).unwrap();
+    let (rebuilt, map) = back.rebuild();
+    let id = map.body(0).unwrap();
+    assert!(!rebuilt.body_is_awake(id));
+    assert!(rebuilt.body_is_bullet(id));
+    assert_eq!(rebuilt.body_sleep_threshold(id), 0.25);
+    assert_eq!(
+        rebuilt.body_motion_locks(id),
+        boxdd::MotionLocks::new(false, false, true)
+    );
+    assert_eq!(rebuilt.body_mass_data(id).mass, 7.0);
+    assert_eq!(back.sensor_overlaps, scene.sensor_overlaps);
+}
+
+#[test]
+fn scene_snapshot_binary_roundtrip_is_versioned_and_smaller_than_json() {
+    use boxdd::serialize::{SNAPSHOT_FORMAT_VERSION, SceneSnapshot, SnapshotFormatError};
+
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let sdef = ShapeDef::builder().density(1.0).build();
+    for i in 0..50 {
+        let body = world.create_body_id(
+            BodyBuilder::new()
+                .body_type(boxdd::BodyType::Dynamic)
+                .position([i as f32, 0.0])
+                .build(),
+        );
+        world.create_polygon_shape_for(body, &sdef, &shapes::box_polygon(0.5, 0.5));
+    }
+    let scene = SceneSnapshot::take_with(&world, |entry| {
+        if let boxdd::serialize::SnapshotEntry::Scene { extensions } = entry {
+            extensions.insert(
+                "level".into(),
+                serde_json::json!({ "name": "dock", "wave": 3 }),
+            );
+        }
+    });
+
+    let bytes = scene.to_bytes();
+    assert_eq!(&bytes[..4], b"BXDS");
+    assert!(bytes.len() < serde_json::to_vec(&scene).unwrap().len());
+
+    let back = SceneSnapshot::from_bytes(&bytes).unwrap();
+    assert_eq!(back.bodies.len(), 50);
+    assert_eq!(back.extensions["level"]["wave"], 3);
+    assert_eq!(
+        back.bodies[7].def.position(),
+        scene.bodies[7].def.position()
+    );
+    let (rebuilt, map) = back.rebuild();
+    assert_eq!(
+        rebuilt.body_position(map.body(49).unwrap()),
+        Vec2::new(49.0, 0.0)
+    );
+
+    assert!(matches!(
+        SceneSnapshot::from_bytes(b"{}"),
+        Err(SnapshotFormatError::BadMagic)
+    ));
+    let mut future = bytes.clone();
+    future[4..6].copy_from_slice(&(SNAPSHOT_FORMAT_VERSION + 1).to_le_bytes());
+    assert!(matches!(
+        SceneSnapshot::from_bytes(&future),
+        Err(SnapshotFormatError::UnsupportedVersion(v)) if v == SNAPSHOT_FORMAT_VERSION + 1
+    ));
+    assert!(matches!(
+        SceneSnapshot::from_bytes(&bytes[..bytes.len() / 2]),
+        Err(SnapshotFormatError::Decode(_))
+    ));
+}
@@ -99,3 +99,29 @@ fn shape_mass_aabb_point_raycast() {
     assert!(approx(out_p.normal.y, 0.0, f32::EPSILON));
     assert!(approx(out_p.fraction, 3.0 / 8.0, f32::EPSILON));
 }
+
+#[test]
+fn compute_hull_drops_interior_points_and_builds_polygons() {
+    let points = [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(2.0, 0.0),
+        Vec2::new(1.0, 1.0),
+        Vec2::new(2.0, 2.0),
+        Vec2::new(0.0, 2.0),
+    ];
+    let hull = shapes::compute_hull(points).expect("square with an interior point");
+    assert_eq!(hull.points().len(), 4);
+    assert!(hull.is_valid());
+    assert!(!hull.points().contains(&Vec2::new(1.0, 1.0)));
+
+    let poly = hull.to_polygon(0.1);
+    assert_eq!(poly.count(), 4);
+    assert!(approx(poly.radius(), 0.1, 1e-6));
+    assert!(shapes::validate_polygon(&poly).is_ok());
+
+    let moved = hull.to_offset_polygon(0.0, Transform::from_pos_angle([10.0_f32, 0.0], 0.0));
+    assert!(approx(moved.centroid().x, 11.0, 1e-4));
+    assert!(hull.try_to_polygon(-1.0).is_err());
+
+    assert!(shapes::compute_hull([[0.0_f32, 0.0], [1.0, 0.0], [2.0, 0.0]]).is_none());
+}
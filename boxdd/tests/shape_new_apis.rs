@@ -195,6 +195,15 @@ fn polygon_helpers_cover_square_offset_and_hull_workflows() {
     let offset_box = shapes::offset_box_polygon(1.5, 0.75, transform);
     let expected_box = shapes::box_polygon(1.5, 0.75).transformed(transform);
     assert!(approx_polygon(&offset_box, &expected_box, 1.0e-5));
+    let short_box = shapes::offset_box(1.5, 0.75, [2.5_f32, -1.25], 0.35);
+    assert!(approx_polygon(&short_box, &expected_box, 1.0e-5));
+    assert_eq!(
+        shapes::try_offset_box(-1.0, 0.75, [0.0_f32, 0.0], 0.0).unwrap_err(),
+        ApiError::InvalidArgument
+    );
+    let bumper = shapes::offset_circle([2.0_f32, 0.5], 0.25);
+    assert_eq!(bumper.center, Vec2::new(2.0, 0.5));
+    assert_eq!(bumper.radius, 0.25);
 
     let offset_rounded = shapes::offset_rounded_box_polygon(1.5, 0.75, 0.2, transform);
     let expected_rounded = shapes::rounded_box_polygon(1.5, 0.75, 0.2).transformed(transform);
@@ -224,15 +233,48 @@ fn polygon_helpers_cover_square_offset_and_hull_workflows() {
     assert!(shapes::polygon_from_points(collinear, 0.0).is_none());
     assert!(shapes::offset_polygon_from_points(collinear, 0.0, transform).is_none());
 
-    let too_many_points: Vec<Vec2> = (0..=MAX_POLYGON_VERTICES)
-        .map(|i| Vec2::new(i as f32, (i % 2) as f32))
+    #[cfg(not(feature = "polygon-simplify"))]
+    {
+        let too_many_points: Vec<Vec2> = (0..=MAX_POLYGON_VERTICES)
+            .map(|i| Vec2::new(i as f32, (i % 2) as f32))
+            .collect();
+        assert!(!shapes::polygon_hull_is_valid(
+            too_many_points.iter().copied()
+        ));
+        assert!(
+            shapes::offset_polygon_from_points(too_many_points.iter().copied(), 0.0, transform)
+                .is_none()
+        );
+        assert_eq!(
+            shapes::try_polygon_from_points(too_many_points.iter().copied(), 0.0).unwrap_err(),
+            ApiError::TooManyPolygonVertices
+        );
+    }
+}
+
+#[cfg(feature = "polygon-simplify")]
+#[test]
+fn polygon_from_points_reduces_large_outlines_with_polygon_simplify() {
+    let circle: Vec<Vec2> = (0..64)
+        .map(|i| {
+            let a = i as f32 * core::f32::consts::TAU / 64.0;
+            Vec2::new(a.cos() * 2.0, a.sin() * 2.0)
+        })
         .collect();
-    assert!(!shapes::polygon_hull_is_valid(
-        too_many_points.iter().copied()
-    ));
-    assert!(
-        shapes::offset_polygon_from_points(too_many_points.iter().copied(), 0.0, transform)
-            .is_none()
+    let polygon = shapes::try_polygon_from_points(circle.iter().copied(), 0.0).unwrap();
+    assert!(polygon.vertices().len() <= MAX_POLYGON_VERTICES);
+    assert!(polygon.vertices().len() >= 3);
+    for v in polygon.vertices() {
+        assert!(approx_eq(v.x.hypot(v.y), 2.0, 1.0e-4));
+    }
+    assert!(shapes::polygon_hull_is_valid(circle.iter().copied()));
+    assert_eq!(
+        shapes::try_polygon_from_points(
+            circle.iter().copied().chain([Vec2::new(f32::NAN, 0.0)]),
+            0.0
+        )
+        .unwrap_err(),
+        ApiError::InvalidArgument
     );
 }
 
@@ -1166,3 +1208,463 @@ fn shape_runtime_event_toggles_are_visible_across_owned_scoped_and_world_apis()
     assert!(!world.shape_pre_solve_events_enabled(contact_shape_id));
     assert!(!world.shape_hit_events_enabled(contact_shape_id));
 }
+
+#[test]
+fn simplify_and_weld_preprocess_outlines() {
+    let noisy = [
+        Vec2::new(0.0, 0.0),
+        Vec2::new(1.0, 0.02),
+        Vec2::new(2.0, -0.01),
+        Vec2::new(3.0, 0.0),
+        Vec2::new(3.0, 2.0),
+    ];
+    assert_eq!(
+        shapes::simplify(noisy, 0.05),
+        vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(3.0, 0.0),
+            Vec2::new(3.0, 2.0)
+        ]
+    );
+    assert_eq!(shapes::simplify(noisy, 0.0).len(), noisy.len());
+
+    let duplicated = [
+        [0.0_f32, 0.0],
+        [0.0005, 0.0],
+        [1.0, 0.0],
+        [1.0, 0.0],
+        [1.0, 1.0],
+    ];
+    assert_eq!(
+        shapes::weld_close_points(duplicated, 0.001),
+        vec![
+            Vec2::new(0.0, 0.0),
+            Vec2::new(1.0, 0.0),
+            Vec2::new(1.0, 1.0)
+        ]
+    );
+
+    assert_eq!(
+        shapes::try_simplify(noisy, -1.0).unwrap_err(),
+        ApiError::InvalidArgument
+    );
+    assert_eq!(
+        shapes::try_weld_close_points([[f32::NAN, 0.0]], 0.1).unwrap_err(),
+        ApiError::InvalidArgument
+    );
+}
+
+#[test]
+fn filter_builder_uses_collision_categories() {
+    const PLAYER: CollisionCategory = CollisionCategory::bit(1);
+    const ENEMY: CollisionCategory = CollisionCategory::bit(2);
+    const DEBRIS: CollisionCategory = CollisionCategory::bit(3);
+
+    let player = Filter::new()
+        .category(PLAYER)
+        .mask(CollisionCategory::ALL ^ DEBRIS);
+    let enemy = Filter::new().category(ENEMY);
+    let debris = Filter::new().category(DEBRIS);
+    assert_eq!(player.category_bits, 1 << 1);
+    assert_eq!(player.mask_bits, !(1 << 3));
+    assert!(player.should_collide(&enemy));
+    assert!(!player.should_collide(&debris));
+    assert!(!player.group(-1).should_collide(&enemy.group(-1)));
+
+    assert_eq!(format!("{:?}", PLAYER | ENEMY), "CollisionCategory(1 | 2)");
+    assert_eq!(
+        format!("{:?}", CollisionCategory::ALL),
+        "CollisionCategory(ALL)"
+    );
+    assert!(format!("{player:?}").contains("category: CollisionCategory(1)"));
+
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let body = world.create_body_id(BodyBuilder::new().build());
+    let shape = world.create_circle_shape_for(
+        body,
+        &ShapeDef::builder().filter(player).build(),
+        &shapes::circle([0.0, 0.0], 0.5),
+    );
+    assert_eq!(world.shape(shape).unwrap().filter().categories(), PLAYER);
+}
+
+#[test]
+fn debug_explain_filter_reports_first_rejecting_rule() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let dynamic = |world: &mut World, x: f32| {
+        world.create_body_id(
+            BodyBuilder::new()
+                .body_type(BodyType::Dynamic)
+                .position([x, 0.0])
+                .build(),
+        )
+    };
+    let circle = shapes::circle([0.0, 0.0], 0.5);
+    let a = dynamic(&mut world, 0.0);
+    let b = dynamic(&mut world, 0.5);
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let wall = world.create_body_id(BodyBuilder::new().build());
+
+    let limb = ShapeDef::builder()
+        .filter(Filter::same_group_never_collide(3))
+        .build();
+    let sa = world.create_circle_shape_for(a, &limb, &circle);
+    let sb = world.create_circle_shape_for(b, &limb, &circle);
+    assert_eq!(
+        world.debug_explain_filter(sa, sb),
+        FilterDecision::NegativeGroup { group_index: -3 }
+    );
+
+    let forced = ShapeDef::builder()
+        .filter(Filter::always_collide(4).mask(CollisionCategory::NONE))
+        .build();
+    let fa = world.create_circle_shape_for(a, &forced, &circle);
+    let fb = world.create_circle_shape_for(b, &forced, &circle);
+    assert!(world.debug_explain_filter(fa, fb).collides());
+
+    let g1 = world.create_circle_shape_for(ground, &ShapeDef::default(), &circle);
+    let g2 = world.create_circle_shape_for(wall, &ShapeDef::default(), &circle);
+    assert_eq!(
+        world.debug_explain_filter(g1, g2),
+        FilterDecision::NoDynamicBody
+    );
+
+    let lonely = ShapeDef::builder()
+        .filter(Filter::new().mask(CollisionCategory::bit(5)))
+        .build();
+    let c = dynamic(&mut world, 2.0);
+    let sc = world.create_circle_shape_for(c, &lonely, &circle);
+    assert_eq!(
+        world.debug_explain_filter(sc, g1),
+        FilterDecision::CategoryMask {
+            a_accepts_b: false,
+            b_accepts_a: true
+        }
+    );
+
+    let joint = world.create_revolute_joint_id(&RevoluteJointDef::new(
+        JointBase::builder().bodies_by_id(ground, a).build(),
+    ));
+    let ga = world.create_circle_shape_for(a, &ShapeDef::default(), &circle);
+    assert_eq!(
+        world.debug_explain_filter(g1, ga),
+        FilterDecision::JointedBodies { joint }
+    );
+    assert_eq!(world.debug_explain_filter(sa, ga), FilterDecision::SameBody);
+
+    let d = dynamic(&mut world, 4.0);
+    let sd = world.create_circle_shape_for(d, &ShapeDef::default(), &circle);
+    assert!(world.debug_explain_filter(g1, sd).collides());
+    world.set_custom_filter(move |x, y| x != sd && y != sd);
+    assert_eq!(
+        world.debug_explain_filter(g1, sd),
+        FilterDecision::CustomFilter
+    );
+
+    assert_eq!(
+        Filter::try_always_collide(0).unwrap_err(),
+        ApiError::InvalidArgument
+    );
+}
+
+#[test]
+fn shape_def_target_mass_derives_density_from_geometry() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let def = ShapeDef::builder().density(3.0).mass(10.0).build();
+    assert_eq!(def.target_mass(), Some(10.0));
+
+    let small = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+    let large = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+    let a = world.create_polygon_shape_for(small, &def, &shapes::box_polygon(0.5, 0.5));
+    let _ = world.create_circle_shape_for(large, &def, &shapes::circle([0.0, 0.0], 2.0));
+    assert!(approx_eq(world.body_mass(small), 10.0, 1.0e-4));
+    assert!(approx_eq(world.body_mass(large), 10.0, 1.0e-3));
+    assert!(approx_eq(world.shape(a).unwrap().density(), 10.0, 1.0e-4));
+
+    // Density set afterwards wins.
+    let by_density = ShapeDef::builder().mass(10.0).density(2.0).build();
+    assert_eq!(by_density.target_mass(), None);
+
+    let bad = ShapeDef::builder().mass(-1.0).build();
+    assert_eq!(
+        world
+            .try_create_polygon_shape_for(small, &bad, &shapes::box_polygon(0.5, 0.5))
+            .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+}
+
+#[test]
+fn shape_morph_tweens_geometry_and_mass() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, 0.0]).build()).unwrap();
+    let body = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([0.0_f32, 0.0])
+            .build(),
+    );
+    let sdef = ShapeDef::builder().density(1.0).build();
+    let capsule =
+        world.create_capsule_shape_for(body, &sdef, &shapes::capsule([0.0, -0.5], [0.0, 0.5], 0.2));
+    let start_mass = world.body_mass(body);
+
+    let mut crouch = ShapeMorph::new(capsule, shapes::capsule([0.0, -0.5], [0.0, 0.5], 0.4), 1.0);
+    crouch.update(&mut world, 0.5);
+    assert!((crouch.progress() - 0.5).abs() < 1.0e-6);
+    assert!((world.shape(capsule).unwrap().capsule().radius - 0.3).abs() < 1.0e-5);
+    assert!(world.body_mass(body) > start_mass);
+
+    crouch.reverse();
+    crouch.update(&mut world, 0.5);
+    assert!(crouch.is_finished());
+    assert!((world.shape(capsule).unwrap().capsule().radius - 0.2).abs() < 1.0e-5);
+
+    let boxed = world.create_polygon_shape_for(body, &sdef, &shapes::box_polygon(0.5, 0.5));
+    let mut grow = ShapeMorph::new(boxed, shapes::box_polygon(1.0, 0.25), 0.5).update_mass(false);
+    let mass = world.body_mass(body);
+    for _ in 0..40 {
+        grow.update(&mut world, 1.0 / 60.0);
+    }
+    assert!(grow.is_finished());
+    let aabb = world.shape_aabb(boxed);
+    assert!((aabb.upper.x - aabb.lower.x - 2.0).abs() < 0.05);
+    assert_eq!(world.body_mass(body), mass);
+
+    assert_eq!(
+        ShapeMorph::try_new(boxed, shapes::circle([0.0, 0.0], 1.0), 1.0).unwrap_err(),
+        ApiError::InvalidArgument
+    );
+    assert_eq!(
+        ShapeMorph::try_new(capsule, shapes::capsule([0.0, 0.0], [0.0, 1.0], 0.1), -1.0)
+            .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+}
+
+#[test]
+fn offset_body_shapes_rebases_geometry_and_mass() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let body = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([1.0, 0.0])
+            .build(),
+    );
+    let sdef = ShapeDef::builder().density(1.0).build();
+    let circle = world.create_circle_shape_for(body, &sdef, &shapes::circle([2.0, 0.0], 0.5));
+    let capsule = world.create_capsule_shape_for(
+        body,
+        &sdef,
+        &shapes::capsule([2.0, -1.0], [2.0, 1.0], 0.25),
+    );
+    let boxed = world.create_polygon_shape_for(
+        body,
+        &sdef,
+        &shapes::offset_box_polygon(0.5, 0.5, Transform::from_pos_angle([2.0, 0.0], 0.0)),
+    );
+    let center = world.body_mass_data(body).center;
+    assert!(approx_vec2(center, Vec2::new(2.0, 0.0), 1.0e-4));
+
+    let moved = world.offset_body_shapes(body, Transform::from_pos_angle([-2.0, 0.0], 0.0));
+    assert_eq!(moved, 3);
+    assert!(approx_vec2(
+        world.shape(circle).unwrap().circle().center,
+        Vec2::new(0.0, 0.0),
+        1.0e-5
+    ));
+    let cap = world.shape(capsule).unwrap().capsule();
+    assert!(approx_vec2(cap.center1, Vec2::new(0.0, -1.0), 1.0e-5));
+    assert!(approx_vec2(cap.center2, Vec2::new(0.0, 1.0), 1.0e-5));
+    let aabb = world.shape_aabb(boxed);
+    assert!(approx_vec2(aabb.lower, Vec2::new(0.5, -0.5), 1.0e-4));
+    assert!(approx_vec2(
+        world.body_mass_data(body).center,
+        Vec2::ZERO,
+        1.0e-4
+    ));
+    assert!(approx_vec2(
+        world.body_position(body),
+        Vec2::new(1.0, 0.0),
+        1.0e-6
+    ));
+
+    assert_eq!(
+        world
+            .try_offset_body_shapes(body, Transform::from_pos_angle([f32::NAN, 0.0], 0.0))
+            .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+}
+
+#[test]
+fn offset_and_recenter_reject_bodies_with_chains() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let body = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+    let circle =
+        world.create_circle_shape_for(body, &ShapeDef::default(), &shapes::circle([1.0, 0.0], 0.5));
+    let _ = world.create_chain_for_id(
+        body,
+        &boxdd::shapes::chain::ChainDef::builder()
+            .points([
+                Vec2::new(-2.0, 0.0),
+                Vec2::new(-1.0, 0.0),
+                Vec2::new(1.0, 0.0),
+                Vec2::new(2.0, 0.0),
+            ])
+            .build(),
+    );
+
+    assert_eq!(
+        world
+            .try_offset_body_shapes(body, Transform::from_pos_angle([1.0, 0.0], 0.0))
+            .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+    assert_eq!(
+        world.try_recenter_body(body).unwrap_err(),
+        ApiError::InvalidArgument
+    );
+    // Nothing moved.
+    assert_eq!(
+        world.shape(circle).unwrap().circle().center,
+        Vec2::new(1.0, 0.0)
+    );
+}
+
+#[test]
+fn recenter_body_moves_origin_to_center_of_mass_in_place() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0, 0.0]).build()).unwrap();
+    let body = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([1.0, 1.0])
+            .angle(core::f32::consts::FRAC_PI_2)
+            .build(),
+    );
+    let sdef = ShapeDef::builder().density(1.0).build();
+    let boxed = world.create_polygon_shape_for(
+        body,
+        &sdef,
+        &shapes::offset_box_polygon(0.5, 0.5, Transform::from_pos_angle([2.0, 0.0], 0.0)),
+    );
+    let anchor = world.create_body_id(BodyBuilder::new().position([1.0, 3.0]).build());
+    let joint = world
+        .revolute(anchor, body)
+        .anchor_world([1.0_f32, 3.0])
+        .build()
+        .id();
+    let aabb_before = world.shape_aabb(boxed);
+    let com_before = world.body_world_center_of_mass(body);
+    let mass_before = world.body_mass_data(body);
+
+    let shift = world.recenter_body(body);
+    assert!(approx_vec2(shift, Vec2::new(2.0, 0.0), 1.0e-4));
+    // Rotated 90 degrees: local +x is world +y.
+    assert!(approx_vec2(
+        world.body_position(body),
+        Vec2::new(1.0, 3.0),
+        1.0e-4
+    ));
+    assert!(approx_vec2(
+        world.body_local_center_of_mass(body),
+        Vec2::ZERO,
+        1.0e-5
+    ));
+    assert!(approx_vec2(
+        world.body_world_center_of_mass(body),
+        com_before,
+        1.0e-4
+    ));
+    let aabb = world.shape_aabb(boxed);
+    assert!(approx_vec2(aabb.lower, aabb_before.lower, 1.0e-4));
+    assert!(approx_vec2(aabb.upper, aabb_before.upper, 1.0e-4));
+    let mass = world.body_mass_data(body);
+    assert!(approx_eq(mass.mass, mass_before.mass, 1.0e-5));
+    assert!(approx_eq(
+        mass.rotational_inertia,
+        mass_before.rotational_inertia,
+        1.0e-4
+    ));
+    assert!(approx_vec2(
+        world.joint_local_frame_b(joint).position(),
+        Vec2::ZERO,
+        1.0e-4
+    ));
+
+    assert!(approx_vec2(world.recenter_body(body), Vec2::ZERO, 1.0e-6));
+}
+
+#[test]
+fn chain_friction_restitution_and_aabb_cover_every_segment() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let body = world.create_body_id(BodyBuilder::new().position([1.0_f32, 2.0]).build());
+    let materials = [
+        SurfaceMaterial::default().with_friction(0.1),
+        SurfaceMaterial::default().with_friction(0.2),
+        SurfaceMaterial::default().with_friction(0.3),
+        SurfaceMaterial::default().with_friction(0.4),
+        SurfaceMaterial::default().with_friction(0.5),
+    ];
+    let mut chain = world.create_chain_for_owned(
+        body,
+        &ChainDef::builder()
+            .points([
+                [-2.0_f32, 0.0],
+                [-1.0, 0.0],
+                [0.0, 1.0],
+                [1.0, 0.0],
+                [2.0, 0.0],
+            ])
+            .materials(&materials)
+            .build(),
+    );
+    let chain_id = chain.id();
+    let segments = world.chain_segments(chain_id);
+    assert_eq!(segments, chain.segments());
+
+    chain.set_friction(0.9);
+    for index in 0..chain.surface_material_count() {
+        assert!(approx_eq(
+            chain.surface_material(index).friction(),
+            0.9,
+            1e-6
+        ));
+    }
+    for &segment in &segments {
+        assert!(approx_eq(
+            world.shape_surface_material(segment).friction(),
+            0.9,
+            1e-6
+        ));
+    }
+
+    world.set_chain_restitution(chain_id, 0.4);
+    for index in 0..world.chain_surface_material_count(chain_id) {
+        let material = world.chain_surface_material(chain_id, index);
+        assert!(approx_eq(material.restitution(), 0.4, 1e-6));
+        assert!(approx_eq(material.friction(), 0.9, 1e-6));
+    }
+
+    let bouncy = SurfaceMaterial::default().with_restitution(1.0);
+    world.set_chain_surface_material(chain_id, 0, &bouncy);
+    assert_eq!(world.chain_surface_material(chain_id, 0), bouncy);
+
+    assert_eq!(
+        world.try_set_chain_friction(chain_id, -1.0),
+        Err(ApiError::InvalidArgument)
+    );
+    assert_eq!(
+        world.try_set_chain_surface_material(chain_id, 99, &bouncy),
+        Err(ApiError::IndexOutOfRange)
+    );
+
+    let aabb = world.chain_aabb(chain_id);
+    assert_eq!(aabb, chain.aabb());
+    for &segment in &segments {
+        let s = world.shape_aabb(segment);
+        assert!(aabb.lower.x <= s.lower.x && aabb.lower.y <= s.lower.y);
+        assert!(aabb.upper.x >= s.upper.x && aabb.upper.y >= s.upper.y);
+    }
+    assert!(aabb.lower.x < 1.0 && aabb.upper.x > 1.0);
+    assert!(aabb.upper.y >= 3.0 - 1e-3);
+}
@@ -32,6 +32,7 @@ fn try_set_body_name_rejects_interior_nul() {
     assert_eq!(err, ApiError::NulByteInString);
 }
 
+#[cfg(feature = "debug-draw")]
 #[test]
 fn try_calls_from_debug_draw_return_in_callback() {
     struct Drawer {
@@ -62,6 +63,7 @@ fn try_calls_from_debug_draw_return_in_callback() {
     assert_eq!(drawer.err, Some(ApiError::InCallback));
 }
 
+#[cfg(feature = "debug-draw")]
 #[test]
 fn try_query_calls_from_debug_draw_return_in_callback() {
     struct Drawer {
@@ -187,6 +189,7 @@ fn try_query_calls_from_debug_draw_return_in_callback() {
     );
 }
 
+#[cfg(feature = "debug-draw")]
 #[test]
 fn try_calls_from_debug_draw_raw_return_in_callback() {
     struct Drawer {
@@ -217,6 +220,7 @@ fn try_calls_from_debug_draw_raw_return_in_callback() {
     assert_eq!(drawer.err, Some(ApiError::InCallback));
 }
 
+#[cfg(feature = "debug-draw")]
 #[test]
 fn owned_body_try_create_shape_helpers_return_in_callback() {
     struct Drawer {
@@ -375,6 +379,7 @@ fn try_scoped_handle_borrows_return_err_for_invalid_ids() {
     assert_eq!(err, ApiError::InvalidChainId);
 }
 
+#[cfg(feature = "debug-draw")]
 #[test]
 fn try_body_mutations_from_debug_draw_return_in_callback() {
     struct Drawer {
@@ -228,3 +228,139 @@ fn nested_raw_event_views_delay_destroy_until_outermost_scope() {
 
     assert!(world.body(id).is_none());
 }
+
+#[test]
+fn destroying_body_drops_attached_user_data_and_compaction_prunes_raw_destroys() {
+    let drops = Arc::new(AtomicUsize::new(0));
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let body = world.create_body_id(BodyBuilder::new().build());
+    let other = world.create_body_id(BodyBuilder::new().build());
+    let shape = world.create_circle_shape_for(
+        body,
+        &ShapeDef::default(),
+        &shapes::circle([0.0_f32, 0.0], 0.5),
+    );
+    let joint = world
+        .revolute(body, other)
+        .anchor_world([0.0_f32, 0.0])
+        .build()
+        .id();
+    world
+        .shape(shape)
+        .unwrap()
+        .set_user_data(DropCounter(Arc::clone(&drops)));
+    world
+        .joint(joint)
+        .unwrap()
+        .set_user_data(DropCounter(Arc::clone(&drops)));
+    world
+        .body(body)
+        .unwrap()
+        .set_user_data(DropCounter(Arc::clone(&drops)));
+    assert_eq!(world.registry_sizes().shape_user_data, 1);
+    assert_eq!(world.registry_sizes().joint_user_data, 1);
+
+    world.destroy_body_id(body);
+    assert_eq!(drops.load(Ordering::SeqCst), 3);
+    let sizes = world.registry_sizes();
+    assert_eq!(
+        (
+            sizes.body_user_data,
+            sizes.shape_user_data,
+            sizes.joint_user_data
+        ),
+        (0, 0, 0)
+    );
+
+    // Destroyed behind the wrapper's back: the entry stays until compaction.
+    world
+        .body(other)
+        .unwrap()
+        .set_user_data(DropCounter(Arc::clone(&drops)));
+    unsafe { ffi::b2DestroyBody(other.into_raw()) };
+    assert_eq!(world.registry_sizes().body_user_data, 1);
+    assert!(world.compact_registries() >= 1);
+    assert_eq!(world.registry_sizes().body_user_data, 0);
+    assert_eq!(drops.load(Ordering::SeqCst), 4);
+}
+
+#[test]
+fn world_id_user_data_round_trips_and_drops_on_destroy() {
+    let drops = Arc::new(AtomicUsize::new(0));
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let body = world.create_body_id(BodyBuilder::new().build());
+    let other = world.create_body_id(BodyBuilder::new().build());
+    let shape = world.create_circle_shape_for(
+        body,
+        &ShapeDef::default(),
+        &shapes::circle([0.0_f32, 0.0], 0.5),
+    );
+    let joint = world
+        .revolute(body, other)
+        .anchor_world([0.0_f32, 0.0])
+        .build()
+        .id();
+
+    world.set_body_user_data(body, 7_u32);
+    assert_eq!(world.body_user_data::<u32>(body), Some(7));
+    assert_eq!(
+        world.with_body_user_data_mut(body, |v: &mut u32| *v += 1),
+        Some(())
+    );
+    // Shared with the handle API.
+    assert_eq!(
+        world.body(body).unwrap().with_user_data(|v: &u32| *v),
+        Some(8)
+    );
+    assert!(matches!(
+        world.try_body_user_data::<String>(body),
+        Err(ApiError::UserDataTypeMismatch)
+    ));
+    assert_eq!(world.take_body_user_data::<u32>(body), Some(8));
+    assert!(!world.clear_body_user_data(body));
+
+    world.set_shape_user_data(shape, String::from("hull"));
+    assert_eq!(
+        world.shape_user_data::<String>(shape).as_deref(),
+        Some("hull")
+    );
+    assert!(world.clear_shape_user_data(shape));
+    assert_eq!(world.shape_user_data::<String>(shape), None);
+
+    world.set_body_user_data(body, DropCounter(Arc::clone(&drops)));
+    world.set_shape_user_data(shape, DropCounter(Arc::clone(&drops)));
+    world.set_joint_user_data(joint, DropCounter(Arc::clone(&drops)));
+    world.destroy_body_id(body);
+    assert_eq!(drops.load(Ordering::SeqCst), 3);
+    assert!(matches!(
+        world.try_set_body_user_data(body, 1_u32),
+        Err(ApiError::InvalidBodyId)
+    ));
+}
+
+#[test]
+fn compact_registries_prunes_extras_side_tables() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let body = world.create_body_id(BodyBuilder::new().build());
+    let shape = world.create_circle_shape_for(
+        body,
+        &ShapeDef::default(),
+        &shapes::circle([0.0_f32, 0.0], 0.5),
+    );
+    world.set_shape_tags(shape, 0b10);
+    world.set_one_way_platform(shape, [0.0_f32, 1.0]);
+    let _ = world.body_bounding_radius(body);
+    let sizes = world.registry_sizes();
+    assert_eq!(
+        (
+            sizes.shape_tags,
+            sizes.one_way_platforms,
+            sizes.bounding_radii
+        ),
+        (1, 1, 1)
+    );
+
+    unsafe { ffi::b2DestroyBody(body.into_raw()) };
+    assert!(world.compact_registries() >= 3);
+    assert_eq!(world.registry_sizes().total(), 0);
+}
@@ -0,0 +1,78 @@
+use boxdd::verlet::{Pin, VerletDef, VerletSystem};
+use boxdd::{ApiError, BodyBuilder, BodyType, ShapeDef, World, WorldDef, shapes};
+
+fn world_with_ground() -> World {
+    let mut world = World::new(WorldDef::builder().gravity([0.0, -10.0]).build()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let _ = world.create_polygon_shape_for(
+        ground,
+        &ShapeDef::default(),
+        &shapes::box_polygon(5.0, 0.5),
+    );
+    world
+}
+
+#[test]
+fn pinned_rope_hangs_and_keeps_length() {
+    let mut world = world_with_ground();
+    let mut rope = VerletSystem::new(VerletDef::default().iterations(20));
+    let points = rope.add_rope([0.0, 4.0], [2.0, 4.0], 10);
+    assert_eq!(points.len(), 11);
+    assert_eq!(rope.constraints().len(), 10);
+    rope.pin(points.start, [0.0, 4.0]);
+
+    let dt = 1.0 / 60.0;
+    for _ in 0..240 {
+        world.step(dt, 4);
+        rope.step(&world, dt);
+    }
+    let p = rope.positions();
+    assert_eq!(p[points.start].x, 0.0);
+    assert_eq!(p[points.start].y, 4.0);
+    let tail = p[points.end - 1];
+    // Hangs roughly straight down below the pin, above the ground.
+    assert!(tail.x.abs() < 0.5, "tail {tail:?}");
+    assert!(tail.y < 3.0 && tail.y > 0.5, "tail {tail:?}");
+    for c in rope.constraints() {
+        let d = (p[c.a].x - p[c.b].x).hypot(p[c.a].y - p[c.b].y);
+        assert!((d - c.length).abs() < 0.05 * c.length + 0.01);
+    }
+}
+
+#[test]
+fn cloth_rests_on_ground_and_follows_body_pins() {
+    let mut world = world_with_ground();
+    let anchor = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Kinematic)
+            .position([0.0, 3.0])
+            .linear_velocity([1.0, 0.0])
+            .build(),
+    );
+    let mut cloth = VerletSystem::new(VerletDef::default());
+    let points = cloth.add_cloth([-0.5, 3.0], 5, 5, 0.25);
+    assert_eq!(points.len(), 25);
+    assert_eq!(cloth.constraints().len(), 40);
+    cloth.pin_to_body(points.start, anchor, [-0.5, 0.0]);
+    assert!(matches!(cloth.pin_of(points.start), Some(Pin::Body { .. })));
+
+    let dt = 1.0 / 60.0;
+    for _ in 0..60 {
+        world.step(dt, 4);
+        cloth.step(&world, dt);
+    }
+    let pinned = cloth.positions()[points.start];
+    let expected = world.body_position(anchor).x - 0.5;
+    assert!((pinned.x - expected).abs() < 1e-4);
+    for p in cloth.positions() {
+        assert!(p.y > 0.5, "point fell through ground: {p:?}");
+    }
+
+    world.destroy_body_id(anchor);
+    assert_eq!(
+        cloth.try_step(&world, dt).unwrap_err(),
+        ApiError::InvalidBodyId
+    );
+    cloth.unpin(points.start);
+    cloth.try_step(&world, dt).unwrap();
+}
@@ -1,4 +1,4 @@
-use boxdd::{prelude::*, shapes};
+use boxdd::{QueryBudget, QueryKind, QueryStats, ShapeProxy, prelude::*, shapes};
 
 fn approx_eq(a: f32, b: f32, eps: f32) -> bool {
     (a - b).abs() <= eps
@@ -562,3 +562,831 @@ fn handle_try_overlap_query_callback_panic_is_caught_and_resumed() {
     assert!(completed);
     assert_eq!(visited, 1);
 }
+
+#[test]
+fn ray_result_helpers_resolve_body_sensor_and_material() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let material = SurfaceMaterial::default().with_friction(0.25);
+    world.create_polygon_shape_for(
+        ground,
+        &ShapeDef::builder().material(material).build(),
+        &shapes::box_polygon(5.0, 0.5),
+    );
+
+    let origin = Vec2::new(0.0, 4.0);
+    let hit = world.cast_ray_closest(origin, [0.0_f32, -10.0], QueryFilter::default());
+    assert!(hit.hit);
+    assert_eq!(hit.body(), ground);
+    assert_eq!(hit.try_body().unwrap(), ground);
+    assert!(!hit.is_sensor());
+    assert!(approx_eq(hit.material().friction(), 0.25, 1e-6));
+    assert!(approx_eq(hit.distance(origin), 3.5, 1e-4));
+
+    let miss = world.cast_ray_closest([50.0_f32, 4.0], [0.0, -1.0], QueryFilter::default());
+    assert!(!miss.hit);
+    assert_eq!(miss.try_body().unwrap_err(), ApiError::InvalidShapeId);
+    assert_eq!(miss.try_material().unwrap_err(), ApiError::InvalidShapeId);
+}
+
+#[test]
+fn occlusion_attenuates_per_crossed_shape() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let walls = world.create_body_id(BodyBuilder::new().build());
+    let brick = SurfaceMaterial::default().with_user_material_id(7);
+    world.create_polygon_shape_for(
+        walls,
+        &ShapeDef::builder().material(brick).build(),
+        &shapes::offset_box_polygon(0.1, 2.0, Transform::from_pos_angle([2.0, 0.0], 0.0)),
+    );
+    world.create_polygon_shape_for(
+        walls,
+        &ShapeDef::default(),
+        &shapes::offset_box_polygon(0.1, 2.0, Transform::from_pos_angle([4.0, 0.0], 0.0)),
+    );
+    world.create_polygon_shape_for(
+        walls,
+        &ShapeDef::builder().sensor(true).build(),
+        &shapes::offset_box_polygon(0.1, 2.0, Transform::from_pos_angle([6.0, 0.0], 0.0)),
+    );
+
+    let filter = QueryFilter::default();
+    assert!(approx_eq(
+        world.occlusion([0.0, 0.0], [3.0, 0.0], filter),
+        0.5,
+        1e-6
+    ));
+    assert!(approx_eq(
+        world.occlusion([0.0, 0.0], [8.0, 0.0], filter),
+        0.25,
+        1e-6
+    ));
+    assert!(approx_eq(
+        world.occlusion([0.0, 5.0], [8.0, 5.0], filter),
+        1.0,
+        1e-6
+    ));
+
+    let gain = world.occlusion_with([0.0, 0.0], [8.0, 0.0], filter, |hit| {
+        if hit.material().user_material_id() == 7 {
+            0.1
+        } else {
+            0.8
+        }
+    });
+    assert!(approx_eq(gain, 0.08, 1e-6));
+    assert!(
+        world
+            .try_occlusion([f32::NAN, 0.0], [1.0, 0.0], filter)
+            .is_err()
+    );
+}
+
+#[test]
+fn active_region_disables_far_bodies_with_hysteresis() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0, 0.0]).build()).unwrap();
+    let sdef = ShapeDef::builder().density(1.0).build();
+    let ground = world.create_body_id(BodyBuilder::new().position([100.0, 0.0]).build());
+    world.create_polygon_shape_for(ground, &sdef, &shapes::box_polygon(1.0, 1.0));
+    let bodies: Vec<BodyId> = [0.0_f32, 50.0, 200.0]
+        .into_iter()
+        .map(|x| {
+            let id = world.create_body_id(
+                BodyBuilder::new()
+                    .body_type(BodyType::Dynamic)
+                    .position([x, 0.0])
+                    .build(),
+            );
+            world.create_polygon_shape_for(id, &sdef, &shapes::box_polygon(0.5, 0.5));
+            id
+        })
+        .collect();
+
+    let view = |x: f32| Aabb::from_center_half_extents([x, 0.0], [10.0, 10.0]);
+    world.set_active_region(view(0.0), 5.0);
+    assert!(world.body_is_enabled(bodies[0]));
+    assert!(!world.body_is_enabled(bodies[1]));
+    assert!(!world.body_is_enabled(bodies[2]));
+    assert!(world.body_is_enabled(ground));
+    assert_eq!(world.active_region_disabled_bodies().len(), 2);
+
+    // Inside the margin: nothing toggles.
+    world.set_active_region(view(12.0), 5.0);
+    assert!(world.body_is_enabled(bodies[0]));
+
+    world.set_active_region(view(50.0), 5.0);
+    assert!(!world.body_is_enabled(bodies[0]));
+    assert!(world.body_is_enabled(bodies[1]));
+    assert!(!world.body_is_enabled(bodies[2]));
+
+    world.clear_active_region();
+    assert!(bodies.iter().all(|&b| world.body_is_enabled(b)));
+    assert!(world.active_region_disabled_bodies().is_empty());
+    assert_eq!(
+        world
+            .try_set_active_region(view(0.0), f32::NAN)
+            .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+}
+
+#[test]
+fn gravity_scale_rules_apply_per_category_at_step() {
+    const BALLOON: u64 = 0x2;
+    let mut world = World::new(WorldDef::builder().gravity([0.0, -10.0]).build()).unwrap();
+    let make = |world: &mut World, x: f32, category: u64| {
+        let id = world.create_body_id(
+            BodyBuilder::new()
+                .body_type(BodyType::Dynamic)
+                .position([x, 0.0])
+                .build(),
+        );
+        let def = ShapeDef::builder()
+            .density(1.0)
+            .filter(Filter {
+                category_bits: category,
+                ..Default::default()
+            })
+            .build();
+        world.create_circle_shape_for(id, &def, &shapes::circle([0.0, 0.0], 0.25));
+        id
+    };
+    let rock = make(&mut world, 0.0, 0x1);
+    let balloon = make(&mut world, 2.0, BALLOON);
+
+    world.set_gravity_scale_for_category(BALLOON, -0.5);
+    assert_eq!(world.gravity_scale_rules(), vec![(BALLOON, -0.5)]);
+    world.step(1.0 / 60.0, 4);
+    assert_eq!(world.body(balloon).unwrap().gravity_scale(), -0.5);
+    assert_eq!(world.body(rock).unwrap().gravity_scale(), 1.0);
+    assert!(world.body_linear_velocity(balloon).y > 0.0);
+    assert!(world.body_linear_velocity(rock).y < 0.0);
+
+    // Bodies created later are picked up on the next step.
+    let late = make(&mut world, 4.0, BALLOON);
+    world.step(1.0 / 60.0, 4);
+    assert_eq!(world.body(late).unwrap().gravity_scale(), -0.5);
+
+    world.clear_gravity_scale_for_category(BALLOON);
+    world.refresh_gravity_scale_rules();
+    assert_eq!(world.body(balloon).unwrap().gravity_scale(), 1.0);
+    assert_eq!(world.body(late).unwrap().gravity_scale(), 1.0);
+    assert_eq!(
+        world
+            .try_set_gravity_scale_for_category(BALLOON, f32::INFINITY)
+            .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+}
+
+#[test]
+fn gravity_scale_rules_follow_churn_disabled_bodies_and_writes() {
+    const BALLOON: u64 = 0x2;
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let make = |world: &mut World, x: f32| {
+        let id = world.create_body_id(
+            BodyBuilder::new()
+                .body_type(BodyType::Dynamic)
+                .position([x, 0.0])
+                .build(),
+        );
+        let def = ShapeDef::builder()
+            .density(1.0)
+            .filter(Filter {
+                category_bits: BALLOON,
+                ..Default::default()
+            })
+            .build();
+        world.create_circle_shape_for(id, &def, &shapes::circle([0.0, 0.0], 0.25));
+        id
+    };
+    world.set_gravity_scale_for_category(BALLOON, -0.5);
+    let first = make(&mut world, 0.0);
+    world.step(1.0 / 60.0, 4);
+
+    // Despawn and spawn in the same frame: counts stay the same, the new body still gets the rule.
+    world.destroy_body_id(first);
+    let second = make(&mut world, 2.0);
+    world.step(1.0 / 60.0, 4);
+    assert_eq!(world.body(second).unwrap().gravity_scale(), -0.5);
+
+    // Disabled bodies have no broadphase proxies but keep their rule across sweeps.
+    world.disable_body(second);
+    let third = make(&mut world, 4.0);
+    world.step(1.0 / 60.0, 4);
+    assert_eq!(world.body(second).unwrap().gravity_scale(), -0.5);
+    assert_eq!(world.body(third).unwrap().gravity_scale(), -0.5);
+
+    // A write to an overridden body becomes its own scale; the rule stays in effect.
+    world.body(third).unwrap().set_gravity_scale(2.0);
+    world.step(1.0 / 60.0, 4);
+    assert_eq!(world.body(third).unwrap().gravity_scale(), -0.5);
+    world.clear_gravity_scale_for_category(BALLOON);
+    world.step(1.0 / 60.0, 4);
+    assert_eq!(world.body(third).unwrap().gravity_scale(), 2.0);
+    assert_eq!(world.body(second).unwrap().gravity_scale(), 1.0);
+}
+
+#[test]
+fn pick_finds_shape_under_screen_position() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let body = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([2.0, 1.0])
+            .build(),
+    );
+    let shape = world.create_polygon_shape_for(
+        body,
+        &ShapeDef::builder().density(1.0).build(),
+        &shapes::box_polygon(0.5, 0.5),
+    );
+
+    let camera = Camera2D::new([0.0, 0.0], 20.0, [800.0, 600.0]);
+    let screen = camera.world_to_screen([2.2, 1.3]);
+    let hit = world
+        .pick(&camera, screen, QueryFilter::default())
+        .expect("box under cursor");
+    assert_eq!(hit.body, body);
+    assert_eq!(shape_id_fields(hit.shape), shape_id_fields(shape));
+    assert!(approx_eq(hit.world_point.x, 2.2, 1e-4));
+    assert!(approx_eq(hit.world_point.y, 1.3, 1e-4));
+
+    // Inside the broadphase AABB but outside the shape itself.
+    let miss = camera.world_to_screen([2.55, 1.0]);
+    assert!(world.pick(&camera, miss, QueryFilter::default()).is_none());
+
+    let bad_camera = Camera2D::new([0.0, 0.0], 0.0, [800.0, 600.0]);
+    assert_eq!(
+        world
+            .try_pick(&bad_camera, screen, QueryFilter::default())
+            .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+}
+
+#[test]
+fn overlap_polygon_circle_and_capsule_check_clearance() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let block = world.create_polygon_shape_for(
+        ground,
+        &ShapeDef::default(),
+        &shapes::offset_box_polygon(1.0, 1.0, Transform::from_pos_angle([5.0, 0.0], 0.0)),
+    );
+    let filter = QueryFilter::default();
+
+    let probe = shapes::box_polygon(0.5, 0.5);
+    let hits = world.overlap_polygon(&probe, Transform::from_pos_angle([4.0, 0.5], 0.3), filter);
+    assert_eq!(hits.len(), 1);
+    assert_eq!(shape_id_fields(hits[0]), shape_id_fields(block));
+    assert!(
+        world
+            .overlap_polygon(&probe, Transform::from_pos_angle([0.0, 0.0], 0.0), filter)
+            .is_empty()
+    );
+
+    assert_eq!(world.overlap_circle([3.6, 0.0], 0.5, filter).len(), 1);
+    assert!(world.overlap_circle([3.0, 0.0], 0.5, filter).is_empty());
+
+    assert_eq!(
+        world
+            .overlap_capsule([0.0, 0.0], [3.8, 0.0], 0.25, filter)
+            .len(),
+        1
+    );
+    assert!(
+        world
+            .overlap_capsule([0.0, 3.0], [8.0, 3.0], 0.25, filter)
+            .is_empty()
+    );
+
+    assert_eq!(
+        world
+            .try_overlap_circle([0.0, 0.0], -1.0, filter)
+            .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+    assert_eq!(
+        world
+            .try_overlap_capsule([f32::NAN, 0.0], [1.0, 0.0], 0.1, filter)
+            .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+}
+
+#[test]
+fn casts_against_body_ignore_other_bodies() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let blocker = world.create_body_id(BodyBuilder::new().position([2.0, 1.0]).build());
+    let blocker_shape = world.create_polygon_shape_for(
+        blocker,
+        &ShapeDef::default(),
+        &shapes::box_polygon(0.5, 0.5),
+    );
+    let target = world.create_body_id(
+        BodyBuilder::new()
+            .position([5.0, 1.0])
+            .angle(core::f32::consts::FRAC_PI_2)
+            .build(),
+    );
+    let target_shape = world.create_polygon_shape_for(
+        target,
+        &ShapeDef::default(),
+        &shapes::box_polygon(1.0, 0.5),
+    );
+
+    let closest = world.cast_ray_closest([0.0, 1.0], [10.0, 0.0], QueryFilter::default());
+    assert_eq!(
+        shape_id_fields(closest.shape_id),
+        shape_id_fields(blocker_shape)
+    );
+
+    let hit = world.cast_ray_against_body(target, [0.0, 1.0], [10.0, 0.0]);
+    assert!(hit.hit);
+    assert_eq!(shape_id_fields(hit.shape_id), shape_id_fields(target_shape));
+    assert!(approx_eq(hit.point.x, 4.5, 1.0e-3));
+    assert!(approx_eq(hit.point.y, 1.0, 1.0e-3));
+    assert!(approx_eq(hit.normal.x, -1.0, 1.0e-3));
+    assert!(approx_eq(hit.fraction, 0.45, 1.0e-3));
+    assert!(
+        !world
+            .cast_ray_against_body(target, [0.0, 3.0], [10.0, 0.0])
+            .hit
+    );
+
+    let proxy = ShapeProxy::new([[0.0_f32, 0.0]], 0.25).unwrap();
+    let cast = world.cast_shape_against_body(
+        target,
+        &proxy,
+        Transform::from_pos_angle([0.0, 1.0], 0.0),
+        [10.0, 0.0],
+    );
+    assert!(cast.hit);
+    assert_eq!(
+        shape_id_fields(cast.shape_id),
+        shape_id_fields(target_shape)
+    );
+    assert!(approx_eq(cast.fraction, 0.425, 1.0e-2));
+    assert!(approx_eq(cast.point.x, 4.5, 1.0e-2));
+    assert!(approx_eq(cast.normal.x, -1.0, 1.0e-2));
+
+    assert_eq!(
+        world
+            .try_cast_ray_against_body(target, [f32::NAN, 0.0], [1.0, 0.0])
+            .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+    world.destroy_body_id(blocker);
+    assert_eq!(
+        world
+            .try_cast_shape_against_body(blocker, &proxy, Transform::IDENTITY, [1.0, 0.0])
+            .unwrap_err(),
+        ApiError::InvalidBodyId
+    );
+}
+
+#[test]
+fn query_stats_count_queries_and_record_slow_ones() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    for i in 0..20 {
+        let body = world.create_body_id(BodyBuilder::new().position([i as f32, 0.0]).build());
+        let _ = world.create_polygon_shape_for(
+            body,
+            &ShapeDef::default(),
+            &shapes::box_polygon(0.4, 0.4),
+        );
+    }
+    let everywhere = Aabb::new([-100.0, -100.0], [100.0, 100.0]);
+
+    // Disabled: nothing is collected.
+    let _ = world.overlap_aabb(everywhere, QueryFilter::default());
+    assert!(!world.is_query_stats_enabled());
+    assert_eq!(world.query_stats(), QueryStats::default());
+
+    world.enable_query_stats(QueryBudget::default().max_node_visits(4).max_recorded(1));
+    let _ = world.overlap_aabb(everywhere, QueryFilter::default());
+    let _ = world.overlap_aabb(everywhere, QueryFilter::default());
+    let _ = world.cast_ray_closest([0.0, 5.0], [0.0, -10.0], QueryFilter::default());
+    let _ = world.cast_ray_all([0.0, 5.0], [0.0, -10.0], QueryFilter::default());
+    let _ = world.cast_shape_points(
+        [Vec2::new(0.0, 5.0)],
+        0.25,
+        [0.0, -10.0],
+        QueryFilter::default(),
+    );
+
+    let stats = world.take_query_stats();
+    assert_eq!((stats.overlaps, stats.rays, stats.casts), (2, 2, 1));
+    assert_eq!(stats.total(), 5);
+    assert!(stats.node_visits > 0);
+    // Both full overlaps exceed the node budget; only one is kept.
+    assert_eq!(stats.slow_queries.len(), 1);
+    assert_eq!(stats.slow_queries[0].kind, QueryKind::Overlap);
+    assert!(stats.dropped_slow_queries >= 1);
+    assert_eq!(world.query_stats().total(), 0);
+
+    // Queries on another world do not land in this one's stats.
+    let other = World::new(WorldDef::default()).unwrap();
+    let _ = other.overlap_aabb(everywhere, QueryFilter::default());
+    assert!(!other.is_query_stats_enabled());
+    assert_eq!(world.query_stats().total(), 0);
+
+    world.disable_query_stats();
+    let _ = world.overlap_aabb(everywhere, QueryFilter::default());
+    assert_eq!(world.query_stats().total(), 0);
+}
+
+#[test]
+fn cast_ray_hits_are_sorted_capped_and_carry_body_and_material() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    // Create the walls far-to-near so broad-phase order differs from distance order.
+    let mut walls = Vec::new();
+    for (i, x) in [6.0_f32, 4.0, 2.0].into_iter().enumerate() {
+        let body = world.create_body_id(BodyBuilder::new().position([x, 0.0]).build());
+        let material = SurfaceMaterial::default().with_user_material_id(10 + i as u64);
+        let shape = world.create_polygon_shape_for(
+            body,
+            &ShapeDef::builder().material(material).build(),
+            &shapes::box_polygon(0.25, 1.0),
+        );
+        walls.push((body, shape, 10 + i as u64));
+    }
+    walls.reverse();
+
+    let all = world.cast_ray_all([0.0_f32, 0.0], [10.0, 0.0], QueryFilter::default());
+    assert_eq!(all.len(), 3);
+    assert!(all.windows(2).all(|w| w[0].fraction <= w[1].fraction));
+
+    let hits = world.cast_ray_hits(
+        [0.0_f32, 0.0],
+        [10.0, 0.0],
+        QueryFilter::default(),
+        usize::MAX,
+    );
+    assert_eq!(hits.len(), 3);
+    for (hit, &(body, shape, material)) in hits.iter().zip(&walls) {
+        assert_eq!(hit.body_id, body);
+        assert_eq!(hit.shape_id, shape);
+        assert_eq!(hit.user_material_id, material);
+        assert!(approx_eq(hit.normal.x, -1.0, 1e-5));
+    }
+    assert!(approx_eq(hits[0].distance([0.0_f32, 0.0]), 1.75, 1e-4));
+    assert_eq!(hits[0].try_body().unwrap(), walls[0].0);
+    assert!(!hits[0].try_is_sensor().unwrap());
+    assert_eq!(hits[0].material().user_material_id(), walls[0].2);
+    assert!(approx_eq(hits[0].fraction, 0.175, 1e-5));
+
+    let mut capped = Vec::new();
+    world.handle().cast_ray_hits_into(
+        [0.0_f32, 0.0],
+        [10.0, 0.0],
+        QueryFilter::default(),
+        2,
+        &mut capped,
+    );
+    assert_eq!(capped, hits[..2]);
+    assert_eq!(
+        world.cast_ray_hits([0.0_f32, 0.0], [10.0, 0.0], QueryFilter::default(), 1),
+        hits[..1]
+    );
+    assert!(
+        world
+            .cast_ray_hits([0.0_f32, 0.0], [10.0, 0.0], QueryFilter::default(), 0)
+            .is_empty()
+    );
+    assert_eq!(
+        world
+            .try_cast_ray_hits([f32::NAN, 0.0], [10.0, 0.0], QueryFilter::default(), 1)
+            .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+
+    // Hits outlive their shapes; the helpers that read the shape then report it.
+    world.destroy_body_id(walls[0].0);
+    assert_eq!(hits[0].try_body().unwrap_err(), ApiError::InvalidBodyId);
+    assert_eq!(
+        hits[0].try_material().unwrap_err(),
+        ApiError::InvalidShapeId
+    );
+}
+
+#[test]
+fn query_snapshot_answers_from_other_threads_while_the_world_steps() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, -10.0]).build()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let floor = world.create_polygon_shape_for(
+        ground,
+        &ShapeDef::default(),
+        &shapes::box_polygon(10.0, 0.5),
+    );
+    let ball_body = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([0.0_f32, 5.0])
+            .build(),
+    );
+    let ball = world.create_circle_shape_for(
+        ball_body,
+        &ShapeDef::default(),
+        &shapes::circle([0.0_f32, 0.0], 0.5),
+    );
+
+    let snapshot = world.query_snapshot();
+    assert_eq!(snapshot.len(), 2);
+    assert_eq!(snapshot.shape(ball).unwrap().body_id, ball_body);
+
+    std::thread::scope(|s| {
+        let reader = s.spawn(|| {
+            let down = snapshot
+                .cast_ray_closest([0.0_f32, 10.0], [0.0, -20.0], QueryFilter::default())
+                .unwrap();
+            let all = snapshot.cast_ray_all([0.0_f32, 10.0], [0.0, -20.0], QueryFilter::default());
+            let near_ball = snapshot.overlap_aabb(
+                Aabb::new([-1.0_f32, 4.0], [1.0, 6.0]),
+                QueryFilter::default(),
+            );
+            (down, all, near_ball)
+        });
+        for _ in 0..30 {
+            world.step(1.0 / 60.0, 4);
+        }
+        let (down, all, near_ball) = reader.join().unwrap();
+        assert_eq!(down.shape_id, ball);
+        assert!(approx_eq(down.point.y, 5.5, 1e-4));
+        assert!(approx_eq(down.normal.y, 1.0, 1e-5));
+        assert_eq!(
+            all.iter().map(|h| h.shape_id).collect::<Vec<_>>(),
+            [ball, floor]
+        );
+        assert_eq!(near_ball, [ball]);
+    });
+
+    // The snapshot still sees the ball where it was captured.
+    assert!(world.body_position(ball_body).y < 5.0);
+    assert_eq!(
+        snapshot
+            .cast_ray_closest([0.0_f32, 10.0], [0.0, -20.0], QueryFilter::default())
+            .map(|h| h.shape_id),
+        Some(ball)
+    );
+    assert_eq!(
+        snapshot
+            .try_overlap_aabb(
+                Aabb::new([1.0_f32, 0.0], [0.0, 1.0]),
+                QueryFilter::default()
+            )
+            .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+}
+
+#[test]
+fn cast_shape_closest_and_all_report_sorted_hits_with_ids() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let mut walls = Vec::new();
+    for x in [6.0_f32, 3.0] {
+        let body = world.create_body_id(BodyBuilder::new().position([x, 0.0]).build());
+        let shape = world.create_polygon_shape_for(
+            body,
+            &ShapeDef::default(),
+            &shapes::box_polygon(0.5, 2.0),
+        );
+        walls.push((body, shape));
+    }
+    walls.reverse();
+    let square = ShapeProxy::new(
+        [[-0.5_f32, -0.5], [0.5, -0.5], [0.5, 0.5], [-0.5, 0.5]],
+        0.0,
+    )
+    .unwrap();
+
+    let closest = world
+        .cast_shape_closest(&square, [10.0_f32, 0.0], QueryFilter::default())
+        .unwrap();
+    assert_eq!((closest.body_id, closest.shape_id), walls[0]);
+    // The square's right edge (x = 0.5) meets the near wall's left face (x = 2.5).
+    assert!(approx_eq(closest.fraction, 0.2, 1e-3));
+    assert!(approx_eq(closest.normal.x, -1.0, 1e-4));
+    assert_eq!(closest.body(), walls[0].0);
+    assert!(!closest.is_sensor());
+    assert_eq!(closest.try_material().unwrap(), SurfaceMaterial::default());
+    assert!(approx_eq(closest.distance([0.0_f32, 0.0]), 2.5, 0.1));
+
+    let all = world
+        .handle()
+        .cast_shape_all(&square, [10.0_f32, 0.0], QueryFilter::default());
+    assert_eq!(
+        all.iter()
+            .map(|h| (h.body_id, h.shape_id))
+            .collect::<Vec<_>>(),
+        walls
+    );
+    assert!(all.windows(2).all(|w| w[0].fraction <= w[1].fraction));
+
+    assert!(
+        world
+            .cast_shape_closest(&square, [0.0_f32, 10.0], QueryFilter::default())
+            .is_none()
+    );
+    assert_eq!(
+        world
+            .try_cast_shape_all(&square, [f32::NAN, 0.0], QueryFilter::default())
+            .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+}
+
+#[test]
+fn overlap_geometry_accepts_every_geometry_kind() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let body = world.create_body_id(BodyBuilder::new().position([4.0_f32, 0.0]).build());
+    let target =
+        world.create_polygon_shape_for(body, &ShapeDef::default(), &shapes::box_polygon(0.5, 0.5));
+    let filter = QueryFilter::default();
+    let near = Transform::from_pos_angle([3.0_f32, 0.0], 0.0);
+    let far = Transform::from_pos_angle([-3.0_f32, 0.0], 0.0);
+
+    assert_eq!(
+        world.overlap_geometry(shapes::circle([0.0_f32, 0.0], 0.75), near, filter),
+        [target]
+    );
+    assert!(
+        world
+            .overlap_geometry(shapes::circle([0.0_f32, 0.0], 0.75), far, filter)
+            .is_empty()
+    );
+    let capsule = Capsule {
+        center1: Vec2::new(0.0, -2.0),
+        center2: Vec2::new(0.0, 2.0),
+        radius: 0.6,
+    };
+    assert_eq!(world.overlap_geometry(capsule, near, filter), [target]);
+    let segment = Segment {
+        point1: Vec2::new(0.0, 0.0),
+        point2: Vec2::new(2.0, 0.0),
+    };
+    assert_eq!(
+        world.handle().overlap_geometry(segment, near, filter),
+        [target]
+    );
+    assert_eq!(
+        world.overlap_geometry(shapes::box_polygon(0.6, 0.6), near, filter),
+        [target]
+    );
+    assert_eq!(
+        world
+            .try_overlap_geometry(shapes::circle([f32::NAN, 0.0], 1.0), near, filter)
+            .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+}
+
+#[test]
+fn streaming_query_variants_visit_hits_and_stop_early() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let mut bodies = Vec::new();
+    for x in [2.0_f32, 4.0, 6.0] {
+        let body = world.create_body_id(BodyBuilder::new().position([x, 0.0]).build());
+        let _ = world.create_polygon_shape_for(
+            body,
+            &ShapeDef::default(),
+            &shapes::box_polygon(0.5, 0.5),
+        );
+        bodies.push(body);
+    }
+    let filter = QueryFilter::default();
+
+    let mut seen = Vec::new();
+    world.cast_ray_with([0.0_f32, 0.0], [10.0, 0.0], filter, |hit| {
+        seen.push(hit.body_id);
+        1.0
+    });
+    seen.sort_unstable();
+    assert_eq!(seen, bodies);
+
+    // Clipping to each hit leaves the closest one last.
+    let mut last = None;
+    world
+        .handle()
+        .cast_ray_with([0.0_f32, 0.0], [10.0, 0.0], filter, |hit| {
+            last = Some(hit);
+            hit.fraction
+        });
+    assert_eq!(last.unwrap().body_id, bodies[0]);
+
+    let mut calls = 0;
+    world.cast_ray_with([0.0_f32, 0.0], [10.0, 0.0], filter, |_| {
+        calls += 1;
+        0.0
+    });
+    assert_eq!(calls, 1);
+
+    let square = ShapeProxy::new(
+        [[-0.2_f32, -0.2], [0.2, -0.2], [0.2, 0.2], [-0.2, 0.2]],
+        0.0,
+    )
+    .unwrap();
+    let mut swept = 0;
+    world.cast_shape_with(&square, [10.0_f32, 0.0], filter, |_| {
+        swept += 1;
+        1.0
+    });
+    assert_eq!(swept, 3);
+
+    let mut visited = 0;
+    assert!(
+        world.visit_overlap_circle([4.0_f32, 0.0], 3.0, filter, |_| {
+            visited += 1;
+            true
+        })
+    );
+    assert_eq!(visited, 3);
+    assert!(!world.visit_overlap_capsule([2.0_f32, 0.0], [6.0, 0.0], 0.1, filter, |_| false));
+    let mut inside = Vec::new();
+    assert!(world.visit_overlap_polygon(
+        &shapes::box_polygon(0.25, 0.25),
+        Transform::from_pos_angle([6.0_f32, 0.0], 0.0),
+        filter,
+        |shape| {
+            inside.push(shape);
+            true
+        }
+    ));
+    assert_eq!(inside.len(), 1);
+    assert_eq!(world.shape_body_id(inside[0]), bodies[2]);
+    assert_eq!(
+        world
+            .try_cast_ray_with([f32::NAN, 0.0], [1.0, 0.0], filter, |_| 1.0)
+            .unwrap_err(),
+        ApiError::InvalidArgument
+    );
+}
+
+#[test]
+fn static_query_cache_reuses_results_until_static_geometry_changes() {
+    const TERRAIN: u64 = 0x1;
+    const ACTORS: u64 = 0x2;
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let terrain = ShapeDef::builder()
+        .filter(Filter {
+            category_bits: TERRAIN,
+            ..Default::default()
+        })
+        .build();
+    world.create_polygon_shape_for(ground, &terrain, &shapes::box_polygon(5.0, 0.5));
+
+    let mut cache = StaticQueryCache::new(TERRAIN);
+    let filter = QueryFilter::default().mask(TERRAIN);
+    let probe = |cache: &mut StaticQueryCache, world: &World| {
+        cache.cast_ray_closest(world, [0.0_f32, 5.0], [0.0_f32, -10.0], filter)
+    };
+    let first = probe(&mut cache, &world);
+    assert!(first.hit);
+    assert!((first.point.y - 0.5).abs() < 1e-4);
+    let again = probe(&mut cache, &world);
+    assert_eq!(again.point, first.point);
+    assert_eq!((cache.hits(), cache.misses(), cache.len()), (1, 1, 1));
+
+    // Moving the static ground invalidates the cached hit.
+    world.set_body_position_and_rotation(ground, [0.0_f32, 1.0], 0.0);
+    let moved = probe(&mut cache, &world);
+    assert!((moved.point.y - 1.5).abs() < 1e-4);
+    assert_eq!(cache.misses(), 2);
+
+    // Dynamic bodies do not flush the cache.
+    let actor = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([0.0_f32, 3.0])
+            .build(),
+    );
+    world.create_circle_shape_for(
+        actor,
+        &ShapeDef::default(),
+        &shapes::circle([0.0_f32, 0.0], 0.5),
+    );
+    let _ = probe(&mut cache, &world);
+    assert_eq!(cache.hits(), 2);
+
+    // Queries that can see non-static categories are never cached.
+    let mixed = QueryFilter::default().mask(TERRAIN | ACTORS);
+    let _ = cache.overlap_aabb(&world, Aabb::new([-1.0_f32, -1.0], [1.0, 4.0]), mixed);
+    assert_eq!(cache.len(), 1);
+
+    // Type changes through the unchecked API flush it too.
+    #[cfg(feature = "unchecked")]
+    {
+        let misses = cache.misses();
+        unsafe {
+            boxdd::unchecked::WorldUncheckedExt::set_body_type_unchecked(
+                &mut world,
+                ground,
+                BodyType::Kinematic,
+            )
+        };
+        let _ = probe(&mut cache, &world);
+        assert_eq!(cache.misses(), misses + 1);
+    }
+}
@@ -96,3 +96,173 @@ fn pre_solve_closure_blocks_contact_this_step() {
     assert!(PRESOLVE_CALLS.load(Ordering::SeqCst) > 0);
     world.clear_pre_solve();
 }
+
+#[test]
+fn disable_contact_persists_until_separation_or_frames() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, -10.0]).build()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let platform = world.create_polygon_shape_for(
+        ground,
+        &ShapeDef::default(),
+        &shapes::box_polygon(5.0, 0.25),
+    );
+    let body = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([0.0_f32, 0.5])
+            .build(),
+    );
+    let feet = world.create_polygon_shape_for(
+        body,
+        &ShapeDef::builder().density(1.0).build(),
+        &shapes::box_polygon(0.25, 0.25),
+    );
+
+    world.disable_contact(platform, feet, ContactDisable::Frames(3));
+    assert_eq!(
+        world.contact_disabled(feet, platform),
+        Some(ContactDisable::Frames(3))
+    );
+    for _ in 0..3 {
+        world.step(1.0 / 60.0, 4);
+    }
+    assert_eq!(world.contact_disabled(feet, platform), None);
+
+    // Drop through: the pair stays disabled while overlapping and is released once clear.
+    world.disable_contact(feet, platform, ContactDisable::UntilSeparation);
+    for _ in 0..120 {
+        world.step(1.0 / 60.0, 4);
+    }
+    assert!(world.body_position(body).y < -1.0);
+    assert_eq!(world.contact_disabled(feet, platform), None);
+    assert!(!world.enable_contact(feet, platform));
+    assert_eq!(
+        world.try_disable_contact(feet, feet, ContactDisable::UntilSeparation),
+        Err(ApiError::InvalidArgument)
+    );
+}
+
+#[test]
+fn disable_contact_applies_to_a_pair_already_at_rest() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, -10.0]).build()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let platform = world.create_polygon_shape_for(
+        ground,
+        &ShapeDef::default(),
+        &shapes::box_polygon(5.0, 0.25),
+    );
+    let body = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([0.0_f32, 0.5])
+            .build(),
+    );
+    let feet = world.create_polygon_shape_for(
+        body,
+        &ShapeDef::builder().density(1.0).build(),
+        &shapes::box_polygon(0.25, 0.25),
+    );
+    // Let the contact form and settle before pre-solve is enabled on either shape.
+    for _ in 0..30 {
+        world.step(1.0 / 60.0, 4);
+    }
+    let rest = world.body_position(body).y;
+    assert!(
+        rest > 0.4 && rest < 0.6,
+        "expected to rest on the platform, y = {rest}"
+    );
+
+    world.disable_contact(feet, platform, ContactDisable::UntilSeparation);
+    for _ in 0..120 {
+        world.step(1.0 / 60.0, 4);
+    }
+    assert!(world.body_position(body).y < -1.0);
+    assert_eq!(world.contact_disabled(feet, platform), None);
+}
+
+#[test]
+fn one_way_platform_lets_bodies_up_and_drops_them_through() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, -10.0]).build()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let ledge = world.create_polygon_shape_for(
+        ground,
+        &ShapeDef::default(),
+        &shapes::box_polygon(5.0, 0.1),
+    );
+    let platform = OneWayPlatform::new(&mut world, ledge);
+    assert_eq!(world.one_way_platform_up(ledge), Some(Vec2::new(0.0, 1.0)));
+
+    let body = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([0.0_f32, -1.0])
+            .linear_velocity([0.0_f32, 8.0])
+            .build(),
+    );
+    let _feet = world.create_polygon_shape_for(
+        body,
+        &ShapeDef::builder().density(1.0).build(),
+        &shapes::box_polygon(0.25, 0.25),
+    );
+    for _ in 0..120 {
+        world.step(1.0 / 60.0, 4);
+    }
+    let y = world.body_position(body).y;
+    assert!(
+        y > 0.2 && y < 0.5,
+        "expected to rest on the platform, y = {y}"
+    );
+
+    platform.drop_through(&mut world, body, 5);
+    assert!(platform.is_dropping(&world, body));
+    for _ in 0..60 {
+        world.step(1.0 / 60.0, 4);
+    }
+    assert!(world.body_position(body).y < -1.0);
+    assert!(!platform.is_dropping(&world, body));
+
+    platform.remove(&mut world);
+    assert_eq!(world.one_way_platform_up(ledge), None);
+}
+
+#[test]
+fn one_way_platform_applies_to_bodies_already_on_the_shape() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, -10.0]).build()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let ledge = world.create_polygon_shape_for(
+        ground,
+        &ShapeDef::default(),
+        &shapes::box_polygon(5.0, 0.1),
+    );
+    let body = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([0.0_f32, 0.35])
+            .build(),
+    );
+    let _feet = world.create_polygon_shape_for(
+        body,
+        &ShapeDef::builder().density(1.0).build(),
+        &shapes::box_polygon(0.25, 0.25),
+    );
+    for _ in 0..30 {
+        world.step(1.0 / 60.0, 4);
+    }
+
+    // Converted while the contact already exists: still solid from above, and drop-through
+    // still applies to the resting body.
+    let platform = OneWayPlatform::new(&mut world, ledge);
+    for _ in 0..30 {
+        world.step(1.0 / 60.0, 4);
+    }
+    let y = world.body_position(body).y;
+    assert!(
+        y > 0.2 && y < 0.5,
+        "expected to rest on the platform, y = {y}"
+    );
+    platform.drop_through(&mut world, body, 5);
+    for _ in 0..60 {
+        world.step(1.0 / 60.0, 4);
+    }
+    assert!(world.body_position(body).y < -1.0);
+}
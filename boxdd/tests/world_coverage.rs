@@ -1,6 +1,7 @@
 use boxdd::prelude::*;
 use boxdd::shapes;
 use boxdd::world::{Counters, Profile};
+use boxdd::{TaskRange, TaskSystem};
 use std::collections::HashSet;
 
 fn same_world_id(a: boxdd_sys::ffi::b2WorldId, b: boxdd_sys::ffi::b2WorldId) -> bool {
@@ -78,6 +79,32 @@ fn world_def_validation_rejects_invalid_numeric_values() {
     );
 }
 
+#[test]
+fn world_profiles_prefill_valid_definitions() {
+    let platformer = WorldDef::from_profile(WorldProfile::Platformer2D);
+    assert_eq!(platformer.gravity(), Vec2::new(0.0, -20.0));
+    assert!(platformer.is_continuous_enabled() && platformer.is_sleep_enabled());
+
+    let top_down = WorldDef::from_profile(WorldProfile::TopDown);
+    assert_eq!(top_down.gravity(), Vec2::ZERO);
+
+    let simulation = WorldDef::from_profile(WorldProfile::Simulation);
+    assert!(!simulation.is_sleep_enabled());
+    assert_eq!(simulation.contact_hertz(), 60.0);
+    assert!(WorldProfile::Simulation.substeps() > WorldProfile::TopDown.substeps());
+
+    for profile in [
+        WorldProfile::Platformer2D,
+        WorldProfile::TopDown,
+        WorldProfile::Simulation,
+    ] {
+        let def = WorldDef::from_profile(profile);
+        def.validate().unwrap();
+        let mut world = World::new(def).unwrap();
+        world.step(1.0 / 60.0, profile.substeps());
+    }
+}
+
 unsafe extern "C" fn serial_enqueue_task(
     task: boxdd_sys::ffi::b2TaskCallback,
     item_count: i32,
@@ -146,6 +173,82 @@ fn world_builder_can_install_raw_task_system_callbacks() {
     world.step(1.0 / 60.0, 4);
 }
 
+struct ScopedThreads {
+    workers: usize,
+    stages: std::sync::atomic::AtomicUsize,
+}
+
+unsafe impl TaskSystem for ScopedThreads {
+    fn worker_count(&self) -> usize {
+        self.workers
+    }
+
+    fn parallel_for(&self, item_count: u32, min_range: u32, task: &TaskRange<'_>) {
+        self.stages
+            .fetch_add(1, std::sync::atomic::Ordering::Relaxed);
+        assert_eq!(task.item_count(), item_count);
+        let workers = task.worker_count();
+        let chunk = item_count.div_ceil(workers).max(min_range).max(1);
+        std::thread::scope(|s| {
+            for worker in 0..workers {
+                let start = worker * chunk;
+                if start >= item_count {
+                    break;
+                }
+                let end = (start + chunk).min(item_count);
+                s.spawn(move || task.run(start, end, worker));
+            }
+        });
+    }
+}
+
+#[test]
+fn world_task_system_runs_step_stages_on_worker_threads() {
+    let threads = std::sync::Arc::new(ScopedThreads {
+        workers: 4,
+        stages: std::sync::atomic::AtomicUsize::new(0),
+    });
+    let def = WorldDef::builder().task_system_arc(threads.clone()).build();
+    assert!(def.has_task_system());
+    assert!(!def.has_task_system_raw());
+    assert_eq!(def.worker_count(), 4);
+
+    let mut world = World::new(def.clone()).unwrap();
+    assert_eq!(world.task_system_worker_count(), Some(4));
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    world.create_polygon_shape_for(
+        ground,
+        &ShapeDef::default(),
+        &shapes::box_polygon(20.0, 0.5),
+    );
+    let mut boxes = Vec::new();
+    for i in 0..40 {
+        let body = world.create_body_id(
+            BodyBuilder::new()
+                .body_type(BodyType::Dynamic)
+                .position([(i % 8) as f32 - 4.0, 1.0 + (i / 8) as f32])
+                .build(),
+        );
+        world.create_polygon_shape_for(body, &ShapeDef::default(), &shapes::box_polygon(0.4, 0.4));
+        boxes.push(body);
+    }
+    for _ in 0..60 {
+        world.step(1.0 / 60.0, 4);
+    }
+    assert!(threads.stages.load(std::sync::atomic::Ordering::Relaxed) > 0);
+    for body in boxes {
+        let p = world.body_position(body);
+        assert!(p.x.is_finite() && p.y > 0.0);
+    }
+
+    let cleared = WorldBuilder::from(def).clear_task_system_raw().build();
+    assert!(!cleared.has_task_system());
+    assert_eq!(
+        World::new(cleared).unwrap().task_system_worker_count(),
+        None
+    );
+}
+
 #[test]
 fn world_runtime_coverage_safe_api() {
     let mut world = World::new(WorldDef::builder().build()).unwrap();
@@ -489,3 +592,435 @@ fn explosion_def_is_a_readable_value_type() {
     assert_eq!(roundtrip.falloff_distance(), 1.25);
     assert_eq!(roundtrip.impulse_per_unit_length(), 6.0);
 }
+
+#[test]
+fn counters_delta_and_high_water_track_world_size() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    assert!(!world.is_counters_high_water_enabled());
+    world.enable_counters_high_water(true);
+    let first = world.counters_delta();
+    assert_eq!(first, world.counters());
+
+    let mut bodies = Vec::new();
+    for i in 0..5 {
+        let body = world.create_body_id(
+            BodyBuilder::new()
+                .body_type(BodyType::Dynamic)
+                .position([i as f32 * 2.0, 0.0])
+                .build(),
+        );
+        world.create_circle_shape_for(
+            body,
+            &ShapeDef::builder().density(1.0).build(),
+            &shapes::circle([0.0, 0.0], 0.5),
+        );
+        bodies.push(body);
+    }
+    world.step(1.0 / 60.0, 4);
+    let grew = world.counters_delta();
+    assert_eq!(grew.body_count, 5);
+    assert_eq!(grew.shape_count, 5);
+    assert_eq!(world.counters_delta().body_count, 0);
+
+    for body in bodies.drain(..3) {
+        world.destroy_body_id(body);
+    }
+    world.step(1.0 / 60.0, 4);
+    assert_eq!(world.counters_delta().body_count, -3);
+
+    let high = world.counters_high_water();
+    assert_eq!(high.body_count, 5);
+    assert!(high.stack_used >= world.counters().stack_used);
+
+    world.reset_counters_high_water();
+    assert_eq!(world.counters_high_water().body_count, 2);
+
+    world.enable_counters_high_water(false);
+    let body = world.create_body_id(BodyBuilder::new().build());
+    world.step(1.0 / 60.0, 4);
+    world.destroy_body_id(body);
+    assert_eq!(world.counters_high_water().body_count, 2);
+    assert_eq!(Counters::default().max(&world.counters()), world.counters());
+}
+
+#[test]
+fn step_scaled_caps_substep_length_and_keeps_simulated_rates() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let body = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+    let _ =
+        world.create_circle_shape_for(body, &ShapeDef::default(), &shapes::circle([0.0, 0.0], 0.5));
+
+    let dt = 1.0 / 60.0;
+    assert_eq!(world.step_scaled(dt, 1.0, 1.0 / 240.0), 4);
+    assert_eq!(world.step_scaled(dt, 2.0, 1.0 / 240.0), 8);
+    assert_eq!(world.step_scaled(dt, 0.25, 1.0 / 240.0), 1);
+    assert_eq!(world.step_scaled(dt, 0.0, 1.0 / 240.0), 1);
+    assert!(!world.last_step_info().unwrap().clamped);
+
+    // 120 frames at half speed is one simulated second of free fall.
+    let before = world.body_linear_velocity(body).y;
+    for _ in 0..120 {
+        world.step_scaled(dt, 0.5, 1.0 / 240.0);
+    }
+    let gained = world.body_linear_velocity(body).y - before;
+    assert!((gained + 10.0).abs() < 1.0e-2, "{gained}");
+
+    // Past the sub-step cap each sub-step is longer than asked for, and the step says so.
+    assert_eq!(
+        world.step_scaled(1.0, 1.0, 1.0 / 240.0),
+        boxdd::limits::MAX_SCALED_SUB_STEPS
+    );
+    let info = world.last_step_info().unwrap();
+    assert!(info.clamped);
+    assert!(info.substep_dt() > 1.0 / 240.0);
+    assert_eq!(
+        world.try_step_scaled(0.5, 2.0, 1.0 / 240.0),
+        Ok(boxdd::limits::MAX_SCALED_SUB_STEPS)
+    );
+    assert!(world.last_step_info().unwrap().clamped);
+    world.step_scaled(dt, 1.0, 1.0 / 240.0);
+    assert!(!world.last_step_info().unwrap().clamped);
+
+    for (real_dt, time_scale, max_substep_dt) in [
+        (-dt, 1.0, dt),
+        (dt, f32::NAN, dt),
+        (dt, 1.0, 0.0),
+        (f32::MAX, 2.0, dt),
+    ] {
+        let err = world
+            .try_step_scaled(real_dt, time_scale, max_substep_dt)
+            .unwrap_err();
+        assert!(
+            matches!(err, boxdd::StepError::InvalidScaledStep { .. }),
+            "{err:?}"
+        );
+        assert_eq!(ApiError::from(err), ApiError::InvalidArgument);
+    }
+}
+
+#[test]
+#[should_panic(expected = "time_step must be finite and >= 0.0")]
+fn step_rejects_negative_time_step() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    world.step(-1.0 / 60.0, 4);
+}
+
+#[test]
+fn fixed_timestep_accumulates_wall_clock_time_exactly() {
+    use std::time::Duration;
+
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let body = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+    let _ =
+        world.create_circle_shape_for(body, &ShapeDef::default(), &shapes::circle([0.0, 0.0], 0.5));
+
+    let mut default_cap = FixedTimestep::from_hz(60, 4);
+    assert_eq!(default_cap.advance(&mut world, Duration::from_secs(1)), 8);
+
+    let mut clock = FixedTimestep::new(Duration::from_millis(10), 4);
+    assert_eq!(clock.advance(&mut world, Duration::from_millis(25)), 2);
+    assert_eq!(clock.pending(), Duration::from_millis(5));
+    assert!((clock.alpha() - 0.5).abs() < 1.0e-6);
+    assert_eq!(clock.advance(&mut world, Duration::from_millis(5)), 1);
+    assert_eq!(clock.pending(), Duration::ZERO);
+
+    // 100_000 frames of 1 ms add up to exactly 10_000 steps with nothing left over.
+    let mut idle = World::new(WorldDef::default()).unwrap();
+    let mut total = 0;
+    for _ in 0..100_000 {
+        total += clock.advance(&mut idle, Duration::from_millis(1));
+    }
+    assert_eq!(total, 10_000);
+    assert_eq!(clock.pending(), Duration::ZERO);
+    assert_eq!(clock.steps(), 10_003);
+    assert_eq!(clock.simulated_time(), Duration::from_millis(100_030));
+
+    let mut capped = FixedTimestep::from_hz(60, 4).max_steps(3);
+    assert_eq!(capped.step(), Duration::from_nanos(16_666_667));
+    assert_eq!(capped.advance(&mut world, Duration::from_secs(1)), 3);
+    assert!(capped.pending() < capped.step());
+
+    let before = world.body_linear_velocity(body).y;
+    world.step_duration(Duration::from_millis(100), 4);
+    assert!(world.body_linear_velocity(body).y < before);
+    assert_eq!(
+        FixedTimestep::try_new(Duration::ZERO, 4).unwrap_err(),
+        ApiError::InvalidArgument
+    );
+    assert_eq!(
+        world
+            .try_step_duration(Duration::from_millis(16), 0)
+            .unwrap_err(),
+        StepError::InvalidSubSteps(0)
+    );
+}
+
+#[test]
+fn last_step_info_reports_step_args_and_dropped_time() {
+    use std::time::Duration;
+
+    let mut world = World::new(WorldDef::default()).unwrap();
+    assert_eq!(world.last_step_info(), None);
+
+    world.step(1.0 / 60.0, 4);
+    let info = world.last_step_info().unwrap();
+    assert_eq!(info.substeps, 4);
+    assert!((info.dt_used - 1.0 / 60.0).abs() < 1.0e-7);
+    assert!(!info.clamped);
+    assert!(!info.exceeds_recommended());
+
+    world.step(0.1, 2);
+    assert!(world.last_step_info().unwrap().exceeds_recommended());
+
+    let mut clock = FixedTimestep::from_hz(60, 4).max_steps(2);
+    assert_eq!(clock.advance(&mut world, Duration::from_millis(20)), 1);
+    assert!(!world.last_step_info().unwrap().clamped);
+    assert_eq!(clock.advance(&mut world, Duration::from_secs(1)), 2);
+    assert!(world.try_last_step_info().unwrap().unwrap().clamped);
+}
+
+#[test]
+fn simple_creation_uses_world_default_defs() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    assert_eq!(world.default_body_def().body_type(), BodyType::Static);
+
+    world.set_default_body_def(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+    world.set_default_shape_def(
+        ShapeDef::builder()
+            .density(2.0)
+            .material(SurfaceMaterial::default().with_friction(0.9))
+            .build(),
+    );
+    let body = world.create_body_simple([1.0_f32, 2.0]);
+    let shape = world.create_shape_simple(body, shapes::box_polygon(0.5, 0.5));
+
+    assert_eq!(world.body(body).unwrap().body_type(), BodyType::Dynamic);
+    assert_eq!(world.body_position(body), Vec2::new(1.0, 2.0));
+    let shape = world.shape(shape).unwrap();
+    assert_eq!(shape.density(), 2.0);
+    assert_eq!(shape.friction(), 0.9);
+    drop(shape);
+    assert!((world.body_mass(body) - 2.0).abs() < 1.0e-4);
+
+    assert!(
+        world
+            .try_set_default_shape_def(ShapeDef::builder().density(-1.0).build())
+            .is_err()
+    );
+    assert_eq!(world.default_shape_def().density(), 2.0);
+}
+
+#[test]
+fn world_builder_event_defaults_apply_to_unset_shape_defs() {
+    let def = WorldDef::builder()
+        .default_enable_hit_events(true)
+        .default_enable_contact_events(false)
+        .build();
+    assert!(def.default_hit_events_enabled());
+    assert!(!def.default_contact_events_enabled());
+
+    let mut world = World::new(def).unwrap();
+    let shape_def = world.default_shape_def();
+    assert!(shape_def.hit_events_enabled());
+    assert!(!shape_def.contact_events_enabled());
+
+    let body = world.create_body_simple([0.0_f32, 0.0]);
+    let shape = world.create_shape_simple(body, shapes::box_polygon(0.5, 0.5));
+    let shape = world.shape(shape).unwrap();
+    assert!(shape.hit_events_enabled());
+    assert!(!shape.contact_events_enabled());
+    drop(shape);
+
+    // Definitions that leave the flags unset get the world's defaults; explicit flags win.
+    let from_default =
+        world.create_polygon_shape_for(body, &ShapeDef::default(), &shapes::box_polygon(0.5, 0.5));
+    assert!(world.shape_hit_events_enabled(from_default));
+    assert!(!world.shape_contact_events_enabled(from_default));
+    let explicit = ShapeDef::builder().enable_hit_events(false).build();
+    let explicit = world.create_polygon_shape_for(body, &explicit, &shapes::box_polygon(0.5, 0.5));
+    assert!(!world.shape_hit_events_enabled(explicit));
+    assert!(!world.shape_contact_events_enabled(explicit));
+
+    // Replacing the default definition keeps the world's flags.
+    world.set_default_shape_def(ShapeDef::builder().density(2.0).build());
+    assert!(world.default_shape_def().hit_events_enabled());
+    assert!(!world.default_shape_def().contact_events_enabled());
+
+    let plain = World::new(WorldDef::default()).unwrap();
+    assert_eq!(
+        plain.default_shape_def().hit_events_enabled(),
+        ShapeDef::default().hit_events_enabled()
+    );
+}
+
+#[test]
+fn static_tree_hint_counts_static_shapes_and_rebuild_keeps_tree_valid() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let sdef = ShapeDef::default();
+    for i in 0..1000 {
+        let (x, y) = ((i % 50) as f32, (i / 50) as f32);
+        let tile = shapes::offset_box_polygon(0.5, 0.5, Transform::from_pos_angle([x, y], 0.0));
+        let _ = world.create_polygon_shape_for(ground, &sdef, &tile);
+    }
+    let mover = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+    let _ = world.create_circle_shape_for(mover, &sdef, &shapes::circle([0.0_f32, 30.0], 0.5));
+
+    let before = world.static_tree_hint();
+    assert_eq!(before.static_shape_count, 1000);
+    assert_eq!(before.balanced_height, 10);
+    assert!(before.static_tree_height >= before.balanced_height);
+
+    world.optimize_static_tree();
+    let after = world.static_tree_hint();
+    assert_eq!(after.static_shape_count, 1000);
+    assert!(after.static_tree_height >= after.balanced_height);
+    assert!(
+        !world
+            .overlap_aabb(Aabb::new([0.0, 0.0], [0.1, 0.1]), QueryFilter::default())
+            .is_empty()
+    );
+}
+
+#[test]
+fn capacity_remaining_reports_headroom_against_limits() {
+    use boxdd::limits;
+
+    let mut world = World::new(WorldDef::default()).unwrap();
+    assert!(limits::live_worlds() >= 1);
+    assert!(limits::worlds_remaining() < limits::MAX_WORLDS);
+    assert_eq!(limits::MAX_POLYGON_VERTICES, 8);
+
+    let before = world.capacity_remaining();
+    let body = world.create_body_id(BodyBuilder::new().build());
+    let _shape =
+        world.create_polygon_shape_for(body, &ShapeDef::default(), &shapes::box_polygon(0.5, 0.5));
+    let after = world.try_capacity_remaining().unwrap();
+    assert_eq!(before.bodies, limits::MAX_IDS_PER_WORLD);
+    assert_eq!(after.bodies, before.bodies - 1);
+    assert_eq!(after.shapes, before.shapes - 1);
+    assert_eq!(after.joints, before.joints);
+    assert!(after.worlds < limits::MAX_WORLDS);
+}
+
+#[test]
+fn tuning_distances_scale_with_length_units() {
+    let units = boxdd::length_units_per_meter();
+    let slop = boxdd::tuning::linear_slop();
+    assert!((slop - 0.005 * units).abs() <= 1e-7 * units);
+    assert!((boxdd::tuning::speculative_distance() - 4.0 * slop).abs() <= 1e-7 * units);
+    assert!((boxdd::tuning::aabb_margin() - 0.1 * units).abs() <= 1e-7 * units);
+}
+
+#[test]
+fn shape_tags_combine_bits_and_drop_with_the_shape() {
+    const FLAMMABLE: u32 = 1 << 0;
+    const CLIMBABLE: u32 = 1 << 1;
+
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let body = world.create_body_id(BodyBuilder::new().build());
+    let circle = shapes::circle([0.0, 0.0], 0.5);
+    let a = world.create_circle_shape_for(body, &ShapeDef::default(), &circle);
+    let b = world.create_circle_shape_for(body, &ShapeDef::default(), &circle);
+
+    world.set_shape_tags(a, FLAMMABLE | CLIMBABLE);
+    world.add_shape_tags(b, FLAMMABLE);
+    assert_eq!(world.shapes_with_tag(FLAMMABLE), {
+        let mut both = vec![a, b];
+        both.sort_unstable();
+        both
+    });
+    assert_eq!(world.shapes_with_tag(FLAMMABLE | CLIMBABLE), vec![a]);
+
+    world.remove_shape_tags(a, FLAMMABLE);
+    assert_eq!(world.shape_tags(a), CLIMBABLE);
+    assert!(!world.shape_has_tag(a, FLAMMABLE));
+
+    assert!(!world.shape_has_tag(a, 0));
+    assert!(world.shapes_with_tag(0).is_empty());
+
+    world.destroy_shape_id(b, false);
+    assert_eq!(world.shape_tags(b), 0);
+    assert_eq!(world.registry_sizes().shape_tags, 1);
+    assert!(world.shapes_with_tag(FLAMMABLE).is_empty());
+
+    world.destroy_body_id(body);
+    assert_eq!(world.registry_sizes().shape_tags, 0);
+    assert_eq!(
+        world.try_set_shape_tags(b, FLAMMABLE).unwrap_err(),
+        ApiError::InvalidShapeId
+    );
+}
+
+#[test]
+fn explode_pushes_dynamic_shapes_in_reach_and_validates_def() {
+    let mut world = World::new(WorldDef::builder().gravity([0.0_f32, 0.0]).build()).unwrap();
+    let sdef = ShapeDef::builder().density(1.0).build();
+    let spawn = |world: &mut World, body_type: BodyType, x: f32| {
+        let body = world.create_body_id(
+            BodyBuilder::new()
+                .body_type(body_type)
+                .position([x, 0.0])
+                .build(),
+        );
+        world.create_circle_shape_for(body, &sdef, &shapes::circle([0.0_f32, 0.0], 0.5));
+        body
+    };
+    let near = spawn(&mut world, BodyType::Dynamic, 1.5);
+    let faded = spawn(&mut world, BodyType::Dynamic, 3.4);
+    let far = spawn(&mut world, BodyType::Dynamic, 8.0);
+    let _wall = spawn(&mut world, BodyType::Static, -1.5);
+
+    let def = ExplosionDef::new()
+        .position([0.0_f32, 0.0])
+        .radius(2.0)
+        .falloff(1.0)
+        .impulse_per_length(5.0);
+    world.explode(&def);
+    let pushed = world.body_linear_velocity(near);
+    assert!(pushed.x > 0.0);
+    assert!(world.body_linear_velocity(faded).x > 0.0);
+    assert_eq!(world.body_linear_velocity(far), Vec2::ZERO);
+
+    world.try_explode(&def.mask_bits(0)).unwrap();
+    assert_eq!(
+        world.body_linear_velocity(near),
+        pushed,
+        "masked-out shapes are not pushed"
+    );
+    assert!(world.try_explode(&def.radius(-1.0)).is_err());
+    assert!(!def.falloff(f32::NAN).is_valid());
+}
+
+#[test]
+fn world_contact_data_reports_each_touching_contact_once() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let sdef = ShapeDef::builder().density(1.0).build();
+    let ground = world.create_body_id(BodyBuilder::new().build());
+    let floor = world.create_polygon_shape_for(ground, &sdef, &shapes::box_polygon(5.0, 0.5));
+    let body = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([0.0_f32, 1.0])
+            .build(),
+    );
+    let crate_shape = world.create_polygon_shape_for(body, &sdef, &shapes::box_polygon(0.5, 0.5));
+    for _ in 0..30 {
+        world.step(1.0 / 60.0, 4);
+    }
+
+    let contacts = world.contact_data();
+    assert_eq!(contacts.len(), 1);
+    let contact = contacts[0];
+    let pair = [contact.shape_id_a, contact.shape_id_b];
+    assert!(pair.contains(&floor) && pair.contains(&crate_shape));
+    assert_eq!(contact.manifold.points().len(), 2);
+    assert!(
+        contact
+            .manifold
+            .points()
+            .iter()
+            .all(|p| p.separation < 0.05)
+    );
+    assert_eq!(world.try_contact_data().unwrap().len(), 1);
+}
@@ -70,3 +70,48 @@ fn recycle_many_worlds_smoke() {
         drop(worlds);
     }
 }
+
+#[test]
+fn destroy_hooks_report_explicit_and_cascaded_destruction() {
+    let mut world = World::new(WorldDef::default()).unwrap();
+    let seen = std::sync::Arc::new(Mutex::new(Vec::new()));
+    let sink = std::sync::Arc::clone(&seen);
+    world.on_destroyed(move |entity| sink.lock().unwrap().push(entity));
+
+    let sdef = ShapeDef::builder().density(1.0).build();
+    let a = world.create_body_id(BodyBuilder::new().body_type(BodyType::Dynamic).build());
+    let b = world.create_body_id(
+        BodyBuilder::new()
+            .body_type(BodyType::Dynamic)
+            .position([1.0, 0.0])
+            .build(),
+    );
+    let a_shape = world.create_polygon_shape_for(a, &sdef, &shapes::box_polygon(0.5, 0.5));
+    let _b_shape = world.create_polygon_shape_for(b, &sdef, &shapes::box_polygon(0.5, 0.5));
+    let extra = world.create_circle_shape_for(b, &sdef, &shapes::circle([0.0, 0.5], 0.25));
+    let joint = world
+        .revolute(a, b)
+        .anchor_world([0.5_f32, 0.0])
+        .build()
+        .id();
+
+    world.destroy_shape_id(extra, true);
+    assert_eq!(
+        std::mem::take(&mut *seen.lock().unwrap()),
+        vec![DestroyedEntity::Shape(extra)]
+    );
+
+    world.destroy_body_id(a);
+    assert_eq!(
+        std::mem::take(&mut *seen.lock().unwrap()),
+        vec![
+            DestroyedEntity::Joint(joint),
+            DestroyedEntity::Shape(a_shape),
+            DestroyedEntity::Body(a),
+        ]
+    );
+
+    world.clear_destroy_hooks();
+    world.destroy_body_id(b);
+    assert!(seen.lock().unwrap().is_empty());
+}